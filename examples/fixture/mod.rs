@@ -4,6 +4,10 @@ mod dummy_extern_uses {
     use anyhow as _;
     #[cfg(feature = "bigdecimal")]
     use bigdecimal as _;
+    #[cfg(feature = "bitflags")]
+    use bitflags as _;
+    #[cfg(feature = "chrono")]
+    use chrono as _;
     use fakeenv as _;
     #[cfg(feature = "float-cmp")]
     use float_cmp as _;
@@ -27,6 +31,13 @@ mod dummy_extern_uses {
     use serde_bytes as _;
     #[cfg(feature = "recursive")]
     use serde_core as _;
+    use serde_test as _;
     use time as _;
+    #[cfg(feature = "unicode")]
+    use unicode_segmentation as _;
+    #[cfg(feature = "url")]
+    use url as _;
+    #[cfg(feature = "uuid")]
+    use uuid as _;
     use version_sync as _;
 }
@@ -0,0 +1,119 @@
+//! Tracking of heap allocations for asserting allocation budgets of code.
+//!
+//! The assertions [`allocates_at_most`] and [`does_not_allocate`] count the
+//! heap allocations done by a closure. Counting requires the
+//! [`TrackingAllocator`] to be installed as the global allocator of the test
+//! binary, e.g., in the root module of an integration test:
+//!
+//! ```
+//! use asserting::alloc_track::TrackingAllocator;
+//! use asserting::prelude::*;
+//! use std::alloc::System;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: TrackingAllocator = TrackingAllocator::new(System);
+//!
+//! # fn main() {
+//! assert_that_code!(|| {
+//!     let sum: u64 = (1..=100).sum();
+//!     assert_eq!(sum, 5050);
+//! })
+//! .does_not_allocate();
+//!
+//! assert_that_code!(|| {
+//!     let numbers: Vec<u64> = (1..=100).collect();
+//!     assert_eq!(numbers.len(), 100);
+//! })
+//! .allocates_at_most(1);
+//! # }
+//! ```
+//!
+//! Allocations are counted per thread. Tests running in parallel do not
+//! affect each other, but allocations done by threads spawned within the
+//! closure are not counted. Each reallocation, like growing a `Vec`, counts as
+//! an allocation.
+//!
+//! [`allocates_at_most`]: crate::assertions::AssertCodeAllocations::allocates_at_most
+//! [`does_not_allocate`]: crate::assertions::AssertCodeAllocations::does_not_allocate
+
+use crate::std::alloc::{GlobalAlloc, Layout, System};
+use crate::std::cell::Cell;
+use crate::std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the [`TrackingAllocator`] is used as the global allocator.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// The number of allocations done by the current thread.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A global allocator that counts the allocations of each thread.
+///
+/// It delegates all allocations to the wrapped allocator, which is usually
+/// [`System`]. See the [module documentation](self) for how to install it.
+#[derive(Debug, Default)]
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Constructs a new `TrackingAllocator` that delegates to the given
+    /// allocator.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+#[allow(unsafe_code)]
+// SAFETY: all methods delegate to the wrapped allocator with unchanged
+// arguments and only increment a thread-local counter in addition.
+unsafe impl<A> GlobalAlloc for TrackingAllocator<A>
+where
+    A: GlobalAlloc,
+{
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { self.inner.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::dealloc`.
+        unsafe { self.inner.dealloc(ptr, layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc_zeroed`.
+        unsafe { self.inner.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation();
+        // SAFETY: the caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { self.inner.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Increments the number of allocations of the current thread.
+fn count_allocation() {
+    INSTALLED.store(true, Ordering::Relaxed);
+    // The counter is not available while the thread is being torn down.
+    _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get().wrapping_add(1)));
+}
+
+/// Returns the number of allocations done by the current thread so far.
+///
+/// Returns `None` if the [`TrackingAllocator`] is not installed as the global
+/// allocator.
+pub(crate) fn allocation_count() -> Option<usize> {
+    if INSTALLED.load(Ordering::Relaxed) {
+        ALLOCATIONS.try_with(Cell::get).ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,140 @@
+use super::*;
+use crate::expectations::allocates_at_most;
+use crate::prelude::*;
+use crate::spec::{Code, Expectation, Expression};
+use crate::std::hint::black_box;
+use crate::std::vec::Vec;
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator::new(System);
+
+#[test]
+fn allocation_count_increases_with_each_allocation() {
+    let before = allocation_count();
+
+    let numbers = black_box(Vec::<u64>::with_capacity(10));
+    let after = allocation_count();
+    drop(numbers);
+
+    assert_that(before).is_some();
+    assert_that(after.zip(before).map(|(after, before)| after - before)).has_value(1);
+}
+
+#[test]
+fn code_does_not_allocate() {
+    assert_that_code(|| {
+        let sum: u64 = black_box(1..=100).sum();
+        assert_eq!(sum, 5050);
+    })
+    .does_not_allocate();
+}
+
+#[test]
+fn code_allocates_at_most_the_given_number_of_times() {
+    assert_that_code(|| {
+        let numbers: Vec<u64> = black_box(1..=100).collect();
+        black_box(numbers);
+    })
+    .allocates_at_most(1);
+}
+
+#[test]
+fn assertion_id_of_allocates_at_most_zero_is_does_not_allocate() {
+    let does_not_allocate = allocates_at_most(0);
+    let allocates_at_most_one = allocates_at_most(1);
+
+    assert_eq!(
+        Expectation::<Code<fn()>>::assertion_id(&does_not_allocate),
+        "asserting::does_not_allocate"
+    );
+    assert_eq!(
+        Expectation::<Code<fn()>>::assertion_id(&allocates_at_most_one),
+        "asserting::allocates_at_most"
+    );
+}
+
+#[test]
+fn verify_code_does_not_allocate_fails() {
+    let failures = verify_that_code(|| {
+        let numbers: Vec<u64> = black_box(1..=100).collect();
+        black_box(numbers);
+    })
+    .named("my_closure")
+    .does_not_allocate()
+    .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_closure to not allocate
+   but was: 1 allocations
+  expected: <= 0 allocations
+"]
+    );
+}
+
+#[test]
+fn verify_code_allocates_at_most_fails_if_budget_is_exceeded() {
+    let failures = verify_that_code(|| {
+        let first = black_box(Vec::<u8>::with_capacity(8));
+        let second = black_box(Vec::<u8>::with_capacity(8));
+        let third = black_box(Vec::<u8>::with_capacity(8));
+        drop((first, second, third));
+    })
+    .named("my_closure")
+    .allocates_at_most(2)
+    .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_closure to allocate at most 2 times
+   but was: 3 allocations
+  expected: <= 2 allocations
+"]
+    );
+}
+
+#[test]
+fn verify_code_allocates_at_most_fails_if_code_panics() {
+    let failures = verify_that_code(|| panic!("invidunt sadipscing"))
+        .named("my_closure")
+        .allocates_at_most(10)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_closure to allocate at most 10 times, but did panic
+  with message: "invidunt sadipscing"
+"#
+        ]
+    );
+}
+
+#[test]
+fn allocates_at_most_message_if_allocations_can_not_be_counted() {
+    let expectation = allocates_at_most(0);
+    let code = Code::from(|| {});
+
+    let message = expectation.message(
+        &Expression::from("my_closure"),
+        &code,
+        false,
+        &DIFF_FORMAT_NO_HIGHLIGHT,
+    );
+
+    assert_that(message).is_equal_to(
+        "expected my_closure to not allocate, but allocations can not be counted
+  hint: install the `TrackingAllocator` as global allocator",
+    );
+}
+
+#[test]
+#[should_panic = "expected my_closure to not allocate\n   but was: 1 allocations\n"]
+fn code_does_not_allocate_panics_if_code_allocates() {
+    assert_that_code(|| {
+        black_box(Vec::<u8>::with_capacity(8));
+    })
+    .named("my_closure")
+    .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+    .does_not_allocate();
+}
@@ -0,0 +1,170 @@
+//! Implementation of assertions for type-erased values, like `Box<dyn Any>`
+//! and `&dyn Any`.
+
+use crate::assertions::{AssertDowncast, AssertInstanceOf};
+use crate::colored::{mark_missing_string, mark_unexpected_string};
+use crate::expectations::{IsInstanceOf, is_instance_of, not};
+use crate::properties::AnyTypeProperty;
+use crate::spec::{
+    DiffFormat, DoFail, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::any::{Any, type_name};
+use crate::std::boxed::Box;
+use crate::std::{format, string::String};
+
+impl AnyTypeProperty for dyn Any {
+    fn is_instance_of_property<T>(&self) -> bool
+    where
+        T: Any,
+    {
+        self.is::<T>()
+    }
+}
+
+impl AnyTypeProperty for dyn Any + Send {
+    fn is_instance_of_property<T>(&self) -> bool
+    where
+        T: Any,
+    {
+        self.is::<T>()
+    }
+}
+
+impl AnyTypeProperty for dyn Any + Send + Sync {
+    fn is_instance_of_property<T>(&self) -> bool
+    where
+        T: Any,
+    {
+        self.is::<T>()
+    }
+}
+
+impl<D> AnyTypeProperty for Box<D>
+where
+    D: AnyTypeProperty + ?Sized,
+{
+    fn is_instance_of_property<T>(&self) -> bool
+    where
+        T: Any,
+    {
+        <D as AnyTypeProperty>::is_instance_of_property::<T>(self)
+    }
+}
+
+impl<S, R> AssertInstanceOf for Spec<'_, S, R>
+where
+    S: AnyTypeProperty,
+    R: FailingStrategy,
+{
+    fn is_instance_of<T>(self) -> Self
+    where
+        T: Any,
+    {
+        self.expecting(is_instance_of::<T>())
+    }
+
+    fn is_not_instance_of<T>(self) -> Self
+    where
+        T: Any,
+    {
+        self.expecting(not(is_instance_of::<T>()))
+    }
+}
+
+impl<S, T> Expectation<S> for IsInstanceOf<T>
+where
+    S: AnyTypeProperty,
+    T: Any,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_instance_of";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.is_instance_of_property::<T>()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected_type = type_name::<T>();
+        let actual_type = if actual.is_instance_of_property::<T>() {
+            format!("<{expected_type}>")
+        } else {
+            String::from("<other type>")
+        };
+        let marked_actual = mark_unexpected_string(&actual_type, format);
+        let marked_expected = mark_missing_string(&format!("<{expected_type}>"), format);
+        format!(
+            "expected {expression} to be {not}an instance of {expected_type}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl<T> Invertible for IsInstanceOf<T> {}
+
+macro_rules! impl_downcast_for_dyn_any {
+    ($any:ty) => {
+        impl<'a, R> AssertDowncast for Spec<'a, Box<$any>, R>
+        where
+            R: FailingStrategy,
+        {
+            type Downcast<T>
+                = Spec<'a, T, R>
+            where
+                T: Any;
+
+            fn downcasting<T>(mut self) -> Self::Downcast<T>
+            where
+                T: Any,
+            {
+                if !self.is_skipped() && !self.subject().is_instance_of_property::<T>() {
+                    let message = is_instance_of::<T>().message(
+                        self.expression(),
+                        self.subject(),
+                        false,
+                        self.diff_format(),
+                    );
+                    self.do_fail_with_message(message);
+                }
+                self.narrowing(|subject| subject.downcast::<T>().ok().map(|value| *value))
+            }
+        }
+
+        impl<'a, 'b, R> AssertDowncast for Spec<'a, &'b $any, R>
+        where
+            R: FailingStrategy,
+        {
+            type Downcast<T>
+                = Spec<'a, &'b T, R>
+            where
+                T: Any;
+
+            fn downcasting<T>(mut self) -> Self::Downcast<T>
+            where
+                T: Any,
+            {
+                if !self.is_skipped() && !self.subject().is_instance_of_property::<T>() {
+                    let message = is_instance_of::<T>().message(
+                        self.expression(),
+                        self.subject(),
+                        false,
+                        self.diff_format(),
+                    );
+                    self.do_fail_with_message(message);
+                }
+                self.narrowing(|subject| subject.downcast_ref::<T>())
+            }
+        }
+    };
+}
+
+impl_downcast_for_dyn_any!(dyn Any);
+impl_downcast_for_dyn_any!(dyn Any + Send);
+impl_downcast_for_dyn_any!(dyn Any + Send + Sync);
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,141 @@
+use crate::prelude::*;
+use crate::std::any::Any;
+use crate::std::boxed::Box;
+use crate::std::string::String;
+use crate::std::vec;
+use crate::std::vec::Vec;
+
+#[derive(Debug, PartialEq)]
+struct Token {
+    id: u32,
+}
+
+#[test]
+fn boxed_any_is_instance_of() {
+    let subject: Box<dyn Any> = Box::new(Token { id: 3 });
+
+    assert_that(subject).is_instance_of::<Token>();
+}
+
+#[test]
+fn boxed_any_send_is_not_instance_of() {
+    let subject: Box<dyn Any + Send> = Box::new(42_u8);
+
+    assert_that(subject).is_not_instance_of::<u16>();
+}
+
+#[test]
+fn borrowed_any_send_sync_is_instance_of() {
+    let value = String::from("eleifend possim");
+    let subject: &(dyn Any + Send + Sync) = &value;
+
+    assert_that(subject).is_instance_of::<String>();
+}
+
+#[test]
+fn borrowed_boxed_any_is_instance_of() {
+    let subject: Box<dyn Any> = Box::new(1.5_f64);
+
+    assert_that(&subject).is_instance_of::<f64>();
+}
+
+#[test]
+fn verify_boxed_any_is_instance_of_fails() {
+    let subject: Box<dyn Any> = Box::new("commodo");
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .is_instance_of::<Token>()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be an instance of asserting::any::tests::Token
+   but was: <other type>
+  expected: <asserting::any::tests::Token>
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_boxed_any_is_not_instance_of_fails() {
+    let subject: Box<dyn Any> = Box::new(7_i32);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .is_not_instance_of::<i32>()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to be not an instance of i32
+   but was: <i32>
+  expected: not <i32>
+"]
+    );
+}
+
+#[test]
+fn boxed_any_downcasting_to_concrete_type() {
+    let subject: Box<dyn Any> = Box::new(Token { id: 12 });
+
+    assert_that(subject)
+        .downcasting::<Token>()
+        .is_equal_to(Token { id: 12 });
+}
+
+#[test]
+fn boxed_any_send_downcasting_to_vec() {
+    let subject: Box<dyn Any + Send> = Box::new(vec![2, 4, 6]);
+
+    assert_that(subject)
+        .downcasting::<Vec<i32>>()
+        .contains_exactly([2, 4, 6]);
+}
+
+#[test]
+fn borrowed_any_downcasting_to_reference_of_concrete_type() {
+    let value = Token { id: 99 };
+    let subject: &dyn Any = &value;
+
+    assert_that(subject)
+        .downcasting::<Token>()
+        .is_equal_to(&Token { id: 99 });
+}
+
+#[test]
+fn derived_boxed_any_downcasting_to_concrete_type() {
+    struct Event {
+        payload: Box<dyn Any>,
+    }
+
+    let subject = Event {
+        payload: Box::new(17_u64),
+    };
+
+    assert_that(subject)
+        .extracting("payload", |event| event.payload)
+        .is_instance_of::<u64>()
+        .downcasting::<u64>()
+        .is_greater_than(10);
+}
+
+#[test]
+fn verify_boxed_any_downcasting_to_other_type_fails() {
+    let subject: Box<dyn Any> = Box::new(12_i8);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .downcasting::<u8>()
+        .is_equal_to(12)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            "expected my_thing to be an instance of u8\n   but was: <other type>\n  expected: <u8>\n"
+        ]
+    );
+}
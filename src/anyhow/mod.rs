@@ -0,0 +1,183 @@
+//! Implementation of assertions for `anyhow::Error`.
+
+use crate::assertions::AssertAnyhowError;
+use crate::colored::{
+    mark_diff_str, mark_missing_string, mark_unexpected_string, mark_unexpected_substring_in_string,
+};
+use crate::expectations::{
+    HasContextContaining, HasRootCauseMessage, IsDowncastableTo, has_context_containing,
+    has_root_cause_message, is_downcastable_to,
+};
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::any::type_name;
+use crate::std::borrow::Borrow;
+use crate::std::fmt::{Debug, Display};
+use crate::std::format;
+use crate::std::string::{String, ToString};
+use crate::std::vec::Vec;
+use anyhow::Error;
+
+impl<S, R> AssertAnyhowError for Spec<'_, S, R>
+where
+    S: Borrow<Error>,
+    R: FailingStrategy,
+{
+    fn has_root_cause_message(self, expected_message: impl Into<String>) -> Self {
+        self.expecting(has_root_cause_message(expected_message))
+    }
+
+    fn is_downcastable_to<E>(self) -> Self
+    where
+        E: Display + Debug + Send + Sync + 'static,
+    {
+        self.expecting(is_downcastable_to::<E>())
+    }
+
+    fn has_context_containing(self, expected_substring: impl Into<String>) -> Self {
+        self.expecting(has_context_containing(expected_substring))
+    }
+}
+
+/// Formats the chain of the given error with one line per level, starting
+/// with the outermost context and ending with the root cause.
+fn format_chain(error: &Error) -> String {
+    error
+        .chain()
+        .enumerate()
+        .map(|(level, cause)| {
+            if level == 0 {
+                format!("{level}: {cause}")
+            } else {
+                format!("\n            {level}: {cause}")
+            }
+        })
+        .collect()
+}
+
+impl<S> Expectation<S> for HasRootCauseMessage
+where
+    S: Borrow<Error>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_root_cause_message";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.borrow().root_cause().to_string() == self.expected_message
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let error = actual.borrow();
+        let expected = &self.expected_message;
+        let root_cause = error.root_cause().to_string();
+        let (not, marked_actual, marked_expected) = if inverted {
+            let marked_actual = mark_unexpected_string(&root_cause, format);
+            let marked_expected = mark_missing_string(expected, format);
+            ("not ", marked_actual, marked_expected)
+        } else {
+            let (marked_actual, marked_expected) = mark_diff_str(&root_cause, expected, format);
+            ("", marked_actual, marked_expected)
+        };
+        let chain = format_chain(error);
+        format!(
+            "expected {expression} to have a root cause message {not}equal to \"{expected}\"\n   but was: \"{marked_actual}\"\n  expected: {not}\"{marked_expected}\"\n     chain: {chain}"
+        )
+    }
+}
+
+impl Invertible for HasRootCauseMessage {}
+
+impl<S, E> Expectation<S> for IsDowncastableTo<E>
+where
+    S: Borrow<Error>,
+    E: Display + Debug + Send + Sync + 'static,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_downcastable_to";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.borrow().downcast_ref::<E>().is_some()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let error = actual.borrow();
+        let (not, which_is) = if inverted {
+            ("not ", "which is downcastable")
+        } else {
+            ("", "which is not downcastable")
+        };
+        let expected_type = type_name::<E>();
+        let marked_actual = mark_unexpected_string(&error.to_string(), format);
+        let marked_expected = mark_missing_string(&format!("<{expected_type}>"), format);
+        let chain = format_chain(error);
+        format!(
+            "expected {expression} to be {not}downcastable to {expected_type}\n   but was: \"{marked_actual}\" - {which_is}\n  expected: {not}{marked_expected}\n     chain: {chain}"
+        )
+    }
+}
+
+impl<E> Invertible for IsDowncastableTo<E> {}
+
+impl<S> Expectation<S> for HasContextContaining
+where
+    S: Borrow<Error>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_context_containing";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let error = subject.borrow();
+        let context_levels = error.chain().count() - 1;
+        error
+            .chain()
+            .take(context_levels)
+            .any(|context| context.to_string().contains(&self.expected_substring))
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let error = actual.borrow();
+        let expected = &self.expected_substring;
+        let not = if inverted { "not " } else { "" };
+        let context_levels = error.chain().count() - 1;
+        let marked_contexts = error
+            .chain()
+            .take(context_levels)
+            .map(|context| {
+                let context = context.to_string();
+                let marked_context = if inverted {
+                    mark_unexpected_substring_in_string(&context, expected, format)
+                } else {
+                    mark_unexpected_string(&context, format)
+                };
+                format!("\"{marked_context}\"")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let marked_expected = mark_missing_string(expected, format);
+        let chain = format_chain(error);
+        format!(
+            "expected {expression} to have a context {not}containing \"{expected}\"\n   but was: [{marked_contexts}]\n  expected: {not}\"{marked_expected}\"\n     chain: {chain}"
+        )
+    }
+}
+
+impl Invertible for HasContextContaining {}
+
+#[cfg(test)]
+mod tests;
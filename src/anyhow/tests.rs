@@ -0,0 +1,296 @@
+use crate::expectations::{
+    has_context_containing, has_root_cause_message, is_downcastable_to, not,
+};
+use crate::prelude::*;
+use crate::std::error::Error;
+use crate::std::fmt::{self, Display};
+use crate::std::string::String;
+use anyhow::{Context, anyhow};
+
+#[derive(Debug)]
+struct ConfigError {
+    path: String,
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid config file `{}`", self.path)
+    }
+}
+
+impl Error for ConfigError {}
+
+#[derive(Debug)]
+struct NetworkError;
+
+impl Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("network unreachable")
+    }
+}
+
+impl Error for NetworkError {}
+
+fn load_config_error() -> anyhow::Error {
+    anyhow::Error::new(ConfigError {
+        path: "config.toml".into(),
+    })
+    .context("failed to read settings")
+    .context("failed to start server")
+}
+
+#[test]
+fn error_has_root_cause_message() {
+    let error = load_config_error();
+
+    assert_that(error).has_root_cause_message("invalid config file `config.toml`");
+}
+
+#[test]
+fn error_without_context_has_root_cause_message() {
+    let error = anyhow!("permission denied");
+
+    assert_that(&error).has_root_cause_message("permission denied");
+}
+
+#[test]
+fn verify_error_has_root_cause_message_fails() {
+    let error = load_config_error();
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .has_root_cause_message("failed to read settings")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to have a root cause message equal to "failed to read settings"
+   but was: "invalid config file `config.toml`"
+  expected: "failed to read settings"
+     chain: 0: failed to start server
+            1: failed to read settings
+            2: invalid config file `config.toml`
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_error_has_root_cause_message_not_equal_to_fails() {
+    let error = anyhow!("permission denied");
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .expecting(not(has_root_cause_message("permission denied")))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to have a root cause message not equal to "permission denied"
+   but was: "permission denied"
+  expected: not "permission denied"
+     chain: 0: permission denied
+"#
+        ]
+    );
+}
+
+#[test]
+fn error_is_downcastable_to_type_of_root_cause() {
+    let error = load_config_error();
+
+    assert_that(error).is_downcastable_to::<ConfigError>();
+}
+
+#[test]
+fn error_is_downcastable_to_type_of_context() {
+    let error = anyhow::Error::new(NetworkError).context(ConfigError {
+        path: "remote.toml".into(),
+    });
+
+    assert_that(error)
+        .is_downcastable_to::<ConfigError>()
+        .is_downcastable_to::<NetworkError>();
+}
+
+#[test]
+fn verify_error_is_downcastable_to_fails() {
+    let error = load_config_error();
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .is_downcastable_to::<NetworkError>()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to be downcastable to asserting::anyhow::tests::NetworkError
+   but was: "failed to start server" - which is not downcastable
+  expected: <asserting::anyhow::tests::NetworkError>
+     chain: 0: failed to start server
+            1: failed to read settings
+            2: invalid config file `config.toml`
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_error_is_not_downcastable_to_fails() {
+    let error = anyhow::Error::new(NetworkError);
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .expecting(not(is_downcastable_to::<NetworkError>()))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to be not downcastable to asserting::anyhow::tests::NetworkError
+   but was: "network unreachable" - which is downcastable
+  expected: not <asserting::anyhow::tests::NetworkError>
+     chain: 0: network unreachable
+"#
+        ]
+    );
+}
+
+#[test]
+fn error_has_context_containing() {
+    let error = load_config_error();
+
+    assert_that(error)
+        .has_context_containing("settings")
+        .has_context_containing("start server");
+}
+
+#[test]
+fn result_with_error_has_context_containing() {
+    let result: anyhow::Result<()> = Err(anyhow!("timed out")).context("failed to connect to db");
+
+    assert_that(result)
+        .err()
+        .has_context_containing("connect")
+        .has_root_cause_message("timed out");
+}
+
+#[test]
+fn verify_error_has_context_containing_fails() {
+    let error = load_config_error();
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .has_context_containing("config.toml")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to have a context containing "config.toml"
+   but was: ["failed to start server", "failed to read settings"]
+  expected: "config.toml"
+     chain: 0: failed to start server
+            1: failed to read settings
+            2: invalid config file `config.toml`
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_error_without_context_has_context_containing_fails() {
+    let error = anyhow!("permission denied");
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .has_context_containing("permission")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to have a context containing "permission"
+   but was: []
+  expected: "permission"
+     chain: 0: permission denied
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_error_has_context_not_containing_fails() {
+    let error = load_config_error();
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .expecting(not(has_context_containing("settings")))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to have a context not containing "settings"
+   but was: ["failed to start server", "failed to read settings"]
+  expected: not "settings"
+     chain: 0: failed to start server
+            1: failed to read settings
+            2: invalid config file `config.toml`
+"#
+        ]
+    );
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use super::*;
+
+    #[test]
+    fn highlight_diffs_error_has_root_cause_message() {
+        let error = anyhow!("bar error").context("operation failed");
+
+        let failures = verify_that(error)
+            .with_diff_format(DIFF_FORMAT_RED_YELLOW)
+            .has_root_cause_message("baz error")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to have a root cause message equal to \"baz error\"\n   \
+                but was: \"ba\u{1b}[31mr\u{1b}[0m error\"\n  \
+               expected: \"ba\u{1b}[33mz\u{1b}[0m error\"\n     \
+                  chain: 0: operation failed\n            \
+                         1: bar error\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_error_has_context_not_containing() {
+        let error = load_config_error();
+
+        let failures = verify_that(error)
+            .with_diff_format(DIFF_FORMAT_RED_YELLOW)
+            .expecting(not(has_context_containing("settings")))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to have a context not containing \"settings\"\n   \
+                but was: [\"failed to start server\", \"failed to read \u{1b}[31msettings\u{1b}[0m\"]\n  \
+               expected: not \"\u{1b}[33msettings\u{1b}[0m\"\n     \
+                  chain: 0: failed to start server\n            \
+                         1: failed to read settings\n            \
+                         2: invalid config file `config.toml`\n\
+            "
+            ]
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Stable identifiers of assertions for tagged failure messages.
+//!
+//! With the output format [`OutputFormat::Tagged`] every failure message
+//! starts with a line that identifies the failed assertion and lists the
+//! metadata of the assertion as structured fields. This line can be parsed by
+//! log scrapers or analyzers of flaky tests to categorize failures.
+
+use crate::spec::{OutputFormat, SpecMetadata};
+use crate::std::fmt::Write;
+use crate::std::format;
+use crate::std::string::{String, ToString};
+
+/// Prepends the tag line to the given failure message if the output format
+/// of the spec is [`OutputFormat::Tagged`].
+///
+/// The failure message is returned unchanged for any other output format.
+pub fn tag_failure_message(
+    message: String,
+    assertion_id: &str,
+    metadata: &SpecMetadata<'_>,
+) -> String {
+    if metadata.diff_format.output_format() != OutputFormat::Tagged {
+        return message;
+    }
+    let mut tagged = tag_line(assertion_id, metadata);
+    tagged.push('\n');
+    tagged.push_str(&message);
+    tagged
+}
+
+/// Formats the tag line for the assertion with the given identifier.
+pub fn tag_line(assertion_id: &str, metadata: &SpecMetadata<'_>) -> String {
+    let mut line = String::with_capacity(96);
+    line.push('[');
+    line.push_str(assertion_id);
+    line.push(']');
+    push_field(&mut line, "expression", &metadata.expression.to_string());
+    if let Some(description) = metadata.description {
+        push_field(&mut line, "description", description);
+    }
+    push_field(&mut line, "subject_type", metadata.subject_type_name);
+    if let Some(location) = metadata.location {
+        push_field(&mut line, "location", &location.to_string());
+    }
+    line
+}
+
+fn push_field(line: &mut String, name: &str, value: &str) {
+    _ = write!(line, " {name}={value:?}");
+}
+
+/// Composes the identifier of an expectation that wraps another expectation.
+///
+/// The name of the wrapper is put in front of the name of the wrapped
+/// assertion, keeping the crate name of the wrapped assertion, e.g.,
+/// wrapping `asserting::is_equal_to` in `not` gives
+/// `asserting::not(is_equal_to)`.
+pub fn wrap_assertion_id(wrapper: &str, inner: &str) -> String {
+    match inner.split_once("::") {
+        Some((crate_name, name)) => format!("{crate_name}::{wrapper}({name})"),
+        None => format!("{wrapper}({inner})"),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,155 @@
+use super::*;
+use crate::expectations::{has_debug_string, not};
+use crate::prelude::*;
+use crate::spec::{DiffFormat, Expectation, Expression, Location};
+use crate::std::vec;
+
+#[test]
+fn wrap_assertion_id_keeps_the_crate_name_of_the_wrapped_assertion() {
+    assert_that(wrap_assertion_id("not", "asserting::has_debug_string"))
+        .is_equal_to("asserting::not(has_debug_string)");
+    assert_that(wrap_assertion_id("not", "custom")).is_equal_to("not(custom)");
+}
+
+#[test]
+fn tag_line_contains_all_known_metadata() {
+    let expression = Expression::from("my_value");
+    let diff_format = DiffFormat::builder().build();
+    let metadata = SpecMetadata {
+        expression: &expression,
+        description: Some("the \"answer\""),
+        location: Some(Location::new("tests/answer.rs", 12, 5)),
+        subject_type_name: "i32",
+        diff_format: &diff_format,
+    };
+
+    let line = tag_line("asserting::is_greater_than", &metadata);
+
+    assert_that(line).is_equal_to(
+        r#"[asserting::is_greater_than] expression="my_value" description="the \"answer\"" subject_type="i32" location="tests/answer.rs:12:5""#,
+    );
+}
+
+#[test]
+fn verify_failure_message_is_not_tagged_by_default() {
+    let failures = verify_that(41)
+        .named("answer")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .is_equal_to(42)
+        .display_failures();
+
+    assert_that(failures).is_equal_to(vec![
+        r"expected answer to be equal to 42
+   but was: 41
+  expected: 42
+"
+        .to_string(),
+    ]);
+}
+
+#[test]
+fn verify_failure_messages_are_tagged_in_tagged_output_format() {
+    let failures = verify_that(41)
+        .named("answer")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_output_format(OutputFormat::Tagged))
+        .is_equal_to(42)
+        .is_not_equal_to(41)
+        .display_failures();
+
+    assert_that(failures).is_equal_to(vec![
+        r#"[asserting::is_equal_to] expression="answer" subject_type="i32"
+expected answer to be equal to 42
+   but was: 41
+  expected: 42
+"#
+        .to_string(),
+        r#"[asserting::not(is_equal_to)] expression="answer" subject_type="i32"
+expected answer to be not equal to 41
+   but was: 41
+  expected: not 41
+"#
+        .to_string(),
+    ]);
+}
+
+#[test]
+fn verify_failure_message_of_derived_spec_is_tagged_in_tagged_output_format() {
+    let failures = verify_that((41, "ipsum"))
+        .named("answer")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_output_format(OutputFormat::Tagged))
+        .extracting_ref("number", |pair| &pair.0)
+        .is_equal_to(42)
+        .and()
+        .display_failures();
+
+    assert_that(failures).is_equal_to(vec![
+        r#"[asserting::is_equal_to] expression="answer.number" subject_type="i32"
+expected answer.number to be equal to 42
+   but was: 41
+  expected: 42
+"#
+        .to_string(),
+    ]);
+}
+
+#[test]
+fn tagged_failure_message_contains_the_location_of_the_assertion() {
+    let failures = verify_that!(41)
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_output_format(OutputFormat::Tagged))
+        .is_at_least(42)
+        .display_failures();
+
+    assert_that(&failures[0])
+        .starts_with(r#"[asserting::is_at_least] expression="41" subject_type="i32" location="src/assertion_id/tests.rs:"#);
+}
+
+#[test]
+fn tagged_failure_message_names_the_assertion_method() {
+    let failures = verify_that(vec![1, 2, 3])
+        .named("numbers")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_output_format(OutputFormat::Tagged))
+        .contains_exactly([1, 3, 2])
+        .display_failures();
+
+    assert_that(&failures[0]).starts_with("[asserting::contains_exactly] ");
+}
+
+#[test]
+fn tagged_failure_message_of_inverted_expectation() {
+    let failures = verify_that("lorem")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_output_format(OutputFormat::Tagged))
+        .expecting(not(has_debug_string("\"lorem\"")))
+        .display_failures();
+
+    assert_that(&failures[0]).starts_with("[asserting::not(has_debug_string)] ");
+}
+
+struct IsEven;
+
+impl Expectation<i32> for IsEven {
+    const ASSERTION_ID: &'static str = "my_crate::is_even";
+
+    fn test(&mut self, subject: &i32) -> bool {
+        subject % 2 == 0
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &i32,
+        _inverted: bool,
+        _format: &DiffFormat,
+    ) -> String {
+        format!("expected {expression} to be even\n   but was: {actual}")
+    }
+}
+
+#[test]
+fn tagged_failure_message_of_custom_expectation_with_its_own_identifier() {
+    let failures = verify_that(41)
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_output_format(OutputFormat::Tagged))
+        .expecting(IsEven)
+        .display_failures();
+
+    assert_that(&failures[0]).starts_with("[my_crate::is_even] ");
+}
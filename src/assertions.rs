@@ -10,10 +10,16 @@
 //! assertions.
 #![allow(clippy::wrong_self_convention, clippy::return_self_not_must_use)]
 
+use crate::properties::{
+    BitsProperty, CheckedArithmeticProperty, CheckedDifferenceProperty, TupleElementProperty,
+};
 use crate::spec::{CollectFailures, GetFailures, Spec};
-use crate::std::fmt::Debug;
+use crate::std::any::Any;
+use crate::std::error::Error as StdError;
+use crate::std::fmt::{Debug, Display};
 use crate::std::ops::RangeBounds;
 use crate::std::string::String;
+use crate::std::time::Duration;
 
 /// Assert whether two values are equal or not.
 ///
@@ -118,6 +124,194 @@ pub trait AssertSameAs<E> {
     fn is_not_same_as(self, expected: E) -> Self;
 }
 
+/// Assert whether the subject is the very same instance as some other value.
+///
+/// In contrast to [`AssertSameAs`], which compares values, these assertions
+/// compare memory addresses. They are implemented for references, where the
+/// addresses are compared with [`std::ptr::eq`], and for [`Rc`] and [`Arc`],
+/// where the addresses of the shared allocations are compared with
+/// [`Rc::ptr_eq`] and [`Arc::ptr_eq`].
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::rc::Rc;
+/// use std::sync::Arc;
+///
+/// let value = String::from("dolore ipsum");
+/// let other = String::from("dolore ipsum");
+///
+/// assert_that!(&value).is_same_instance_as(&value);
+/// assert_that!(&value).is_not_same_instance_as(&other);
+///
+/// let shared = Rc::new(42);
+/// assert_that!(Rc::clone(&shared)).is_same_instance_as(&shared);
+/// assert_that!(Rc::new(42)).is_not_same_instance_as(&shared);
+///
+/// let shared = Arc::new(42);
+/// assert_that!(Arc::clone(&shared)).is_same_instance_as(&shared);
+/// assert_that!(Arc::new(42)).is_not_same_instance_as(&shared);
+/// ```
+///
+/// [`Arc`]: std::sync::Arc
+/// [`Arc::ptr_eq`]: std::sync::Arc::ptr_eq
+/// [`Rc`]: std::rc::Rc
+/// [`Rc::ptr_eq`]: std::rc::Rc::ptr_eq
+pub trait AssertSameInstance<E> {
+    /// Verifies that the subject is the same instance as the other value,
+    /// meaning both are located at the same memory address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::rc::Rc;
+    ///
+    /// let value = vec![1, 2, 3];
+    /// let borrowed = &value;
+    /// assert_that!(borrowed).is_same_instance_as(&value);
+    ///
+    /// let shared = Rc::new(vec![1, 2, 3]);
+    /// assert_that!(Rc::clone(&shared)).is_same_instance_as(&shared);
+    /// ```
+    #[track_caller]
+    fn is_same_instance_as(self, other: E) -> Self;
+
+    /// Verifies that the subject is not the same instance as the other value,
+    /// meaning both are located at different memory addresses, even if they
+    /// are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::rc::Rc;
+    ///
+    /// let value = vec![1, 2, 3];
+    /// let copy = value.clone();
+    /// assert_that!(&copy).is_not_same_instance_as(&value);
+    ///
+    /// let shared = Rc::new(vec![1, 2, 3]);
+    /// assert_that!(Rc::new(vec![1, 2, 3])).is_not_same_instance_as(&shared);
+    /// ```
+    #[track_caller]
+    fn is_not_same_instance_as(self, other: E) -> Self;
+}
+
+/// Assert whether a value is equal to the default value of its type.
+///
+/// These assertions are implemented for all types that implement
+/// [`Default`], [`PartialEq`] and [`Debug`]. In case the subject is not equal
+/// to the default value, the failure message highlights the parts of the
+/// subject that deviate from the default value.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Settings {
+///     verbose: bool,
+///     retries: u8,
+/// }
+///
+/// assert_that!(Settings::default()).is_default();
+/// assert_that!(Settings { verbose: true, retries: 0 }).is_not_default();
+///
+/// assert_that!(0).is_default();
+/// assert_that!(String::from("nisl")).is_not_default();
+/// ```
+///
+/// A failing assertion prints a message like:
+///
+/// ```console
+/// expected subject to be the default value Settings { verbose: false, retries: 0 }
+///    but was: Settings { verbose: true, retries: 0 }
+///   expected: Settings { verbose: false, retries: 0 }
+/// ```
+pub trait AssertDefault {
+    /// Verifies that the subject is equal to the default value of its type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(0_u64).is_default();
+    /// assert_that!(Vec::<i32>::new()).is_default();
+    /// assert_that!(None::<char>).is_default();
+    /// ```
+    #[track_caller]
+    fn is_default(self) -> Self;
+
+    /// Verifies that the subject is not equal to the default value of its
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(42_u64).is_not_default();
+    /// assert_that!(vec![1, 2, 3]).is_not_default();
+    /// assert_that!(Some('a')).is_not_default();
+    /// ```
+    #[track_caller]
+    fn is_not_default(self) -> Self;
+}
+
+/// Assert whether two sets contain the same elements.
+///
+/// This assertion is like [`AssertEquality::is_equal_to`] but specialized for
+/// sets. In case the sets are not equal, the failure message lists the
+/// elements that are only in the actual set and the elements that are only in
+/// the expected set. The listed elements are sorted, which requires the
+/// elements to implement `Ord`.
+///
+/// These assertions are implemented for `BTreeSet`, `HashSet` and
+/// `hashbrown::HashSet`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::collections::{BTreeSet, HashSet};
+///
+/// let subject = BTreeSet::from([1, 3, 5, 7]);
+/// assert_that!(subject).is_equal_to_set(BTreeSet::from([7, 5, 3, 1]));
+///
+/// let subject = HashSet::from(["one", "two", "three"]);
+/// assert_that!(subject).is_equal_to_set(HashSet::from(["three", "two", "one"]));
+/// ```
+///
+/// A failing assertion prints a message like:
+///
+/// ```console
+/// expected subject to be equal to {1, 2, 3}
+///            but was: {1, 3, 4}
+///           expected: {1, 2, 3}
+///     only in actual: [4]
+///   only in expected: [2]
+/// ```
+pub trait AssertSetEquality<E> {
+    /// Verifies that the subject set contains the same elements as the
+    /// expected set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let subject = BTreeSet::from(['a', 'b', 'c']);
+    /// assert_that!(subject).is_equal_to_set(BTreeSet::from(['c', 'b', 'a']));
+    /// ```
+    #[track_caller]
+    fn is_equal_to_set(self, expected: E) -> Self;
+}
+
 /// Assert whether a value is equivalent to a value of type [`Value`] using
 /// field-by-field recursive comparison.
 ///
@@ -384,6 +578,99 @@ pub trait AssertIsCloseToWithDefaultMargin<E> {
     fn is_not_close_to(self, expected: E) -> Self;
 }
 
+/// Assert approximate equality for sequences of floating point numbers.
+///
+/// The elements of the sequence are compared one by one. Each element must be
+/// approximately equal to the expected value at the same position.
+///
+/// These assertions are implemented for slices, arrays and `Vec`s of `f32`
+/// and `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let values: Vec<f32> = vec![1.0 / 3.0, 2.0 / 3.0, 3.0 / 3.0];
+/// assert_that!(&values).contains_exactly_close_to([0.333_333_34, 0.666_666_7, 1.0]);
+/// assert_that!(&values).contains_exactly_close_to_with_margin([0.333, 0.667, 1.0], (0.001, 5));
+///
+/// let values: [f64; 3] = [0.1 + 0.2, 0.3, 0.15 * 2.];
+/// assert_that!(values).is_close_to_each(0.3);
+/// assert_that!(values).is_close_to_each_with_margin(0.3001, (0.001, 5));
+/// ```
+#[cfg(feature = "float-cmp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float-cmp")))]
+pub trait AssertElementsCloseTo<E, M> {
+    /// Verifies that the actual sequence has the same number of elements as
+    /// the expected values and that each element is approximately equal to
+    /// the expected value at the same position.
+    ///
+    /// For the approximation, a default margin with 4 * epsilon and 4 * ULPS is
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let values: Vec<f64> = vec![0.1 + 0.2, 0.7 * 3.0];
+    /// assert_that!(values).contains_exactly_close_to([0.3, 2.1]);
+    /// ```
+    #[track_caller]
+    fn contains_exactly_close_to(self, expected: impl IntoIterator<Item = E>) -> Self;
+
+    /// Verifies that the actual sequence has the same number of elements as
+    /// the expected values and that each element is approximately equal to
+    /// the expected value at the same position within the given margin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let values: &[f32] = &[10.0 / 3.0, 20.0 / 3.0];
+    /// assert_that!(values).contains_exactly_close_to_with_margin([3.333, 6.667], (0.001, 5));
+    /// ```
+    #[track_caller]
+    fn contains_exactly_close_to_with_margin(
+        self,
+        expected: impl IntoIterator<Item = E>,
+        margin: impl Into<M>,
+    ) -> Self;
+
+    /// Verifies that each element of the actual sequence is approximately
+    /// equal to the expected value.
+    ///
+    /// For the approximation, a default margin with 4 * epsilon and 4 * ULPS is
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let values: [f64; 2] = [0.1 + 0.2, 0.15 * 2.0];
+    /// assert_that!(values).is_close_to_each(0.3);
+    /// ```
+    #[track_caller]
+    fn is_close_to_each(self, expected: E) -> Self;
+
+    /// Verifies that each element of the actual sequence is approximately
+    /// equal to the expected value within the given margin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let values: Vec<f32> = vec![0.2995, 0.3, 0.3005];
+    /// assert_that!(values).is_close_to_each_with_margin(0.3, (0.001, 5));
+    /// ```
+    #[track_caller]
+    fn is_close_to_each_with_margin(self, expected: E, margin: impl Into<M>) -> Self;
+}
+
 /// Assert whether a value is greater than or less than another value, as well
 /// as at most as big or at least as big as another value.
 ///
@@ -620,6 +907,182 @@ pub trait AssertInRange<E> {
         R: RangeBounds<E> + Debug;
 }
 
+/// Assert whether a numeric value lies within an exclusive range or within a
+/// tolerance around an expected value.
+///
+/// In contrast to [`AssertInRange`], the failure message of these assertions
+/// reports how far outside the bounds the actual value lies.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// assert_that!(3).is_in_range_exclusive(2, 4);
+/// assert_that!(103).is_within(5, 100);
+/// assert_that!(195).is_within_percent(5.0, 200);
+///
+/// assert_that!(0.5).is_in_range_exclusive(0.0, 1.0);
+/// assert_that!(9.8).is_within(0.25, 10.0);
+/// assert_that!(-10.4).is_within_percent(5.0, -10.0);
+/// ```
+pub trait AssertWithin<E> {
+    /// Verifies that the subject is within the range between `start` and
+    /// `end`, whereby both bounds are excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(7).is_in_range_exclusive(6, 8);
+    /// assert_that!(0.1).is_in_range_exclusive(0.0, 1.0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(8).is_in_range_exclusive(6, 8);
+    /// ```
+    #[track_caller]
+    fn is_in_range_exclusive(self, start: E, end: E) -> Self;
+
+    /// Verifies that the subject differs from the value `center` by at most
+    /// the given `offset` (inclusive).
+    ///
+    /// The sign of the `offset` is ignored, so an offset of `-5` is the same
+    /// as an offset of `5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(105).is_within(5, 100);
+    /// assert_that!(95).is_within(5, 100);
+    /// assert_that!(0.95).is_within(0.1, 1.0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(106).is_within(5, 100);
+    /// ```
+    #[track_caller]
+    fn is_within(self, offset: E, center: E) -> Self;
+
+    /// Verifies that the subject differs from the `expected` value by at most
+    /// the given percentage of the expected value (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(210).is_within_percent(5.0, 200);
+    /// assert_that!(190).is_within_percent(5.0, 200);
+    /// assert_that!(0.98).is_within_percent(2.5, 1.0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(211).is_within_percent(5.0, 200);
+    /// ```
+    #[track_caller]
+    fn is_within_percent(self, percent: f64, expected: E) -> Self;
+}
+
+/// Assert properties of a range like `Range` or `RangeInclusive` as the
+/// subject itself.
+///
+/// This is useful when the code under test produces ranges, like pages of a
+/// paginated list, spans or offsets within a text.
+///
+/// The length of a range is verified with
+/// [`has_length`](AssertHasLength::has_length) and whether a range is empty
+/// with [`is_empty`](AssertEmptiness::is_empty).
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let page = 20..30;
+///
+/// assert_that!(page.clone())
+///     .contains_value(25)
+///     .overlaps_with(25..35)
+///     .is_sub_range_of(0..100);
+///
+/// assert_that!(page).has_length(10);
+/// ```
+pub trait AssertRange<E> {
+    /// Verifies that the subject range contains the expected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(1..5).contains_value(4);
+    /// assert_that!(1..=5).contains_value(5);
+    /// assert_that!(0.5..).contains_value(99.9);
+    /// ```
+    #[track_caller]
+    fn contains_value(self, expected: E) -> Self;
+
+    /// Verifies that the subject range and the other range have at least one
+    /// value in common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(1..5).overlaps_with(4..8);
+    /// assert_that!(1..=5).overlaps_with(5..=8);
+    /// assert_that!('a'..='k').overlaps_with('f'..);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(1..5).overlaps_with(5..8);
+    /// ```
+    #[track_caller]
+    fn overlaps_with<R>(self, other: R) -> Self
+    where
+        R: RangeBounds<E> + Debug;
+
+    /// Verifies that all values of the subject range are also contained in
+    /// the other range.
+    ///
+    /// An empty range is a sub-range of every range. The bounds are compared
+    /// without assuming the values to be discrete, e.g. `2..5` is not a
+    /// sub-range of `2..=4`, as the subject range contains values like `4.5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(2..5).is_sub_range_of(0..10);
+    /// assert_that!(2..=4).is_sub_range_of(2..5);
+    /// assert_that!(2..=5).is_sub_range_of(..);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(2..=5).is_sub_range_of(2..5);
+    /// ```
+    #[track_caller]
+    fn is_sub_range_of<R>(self, other: R) -> Self
+    where
+        R: RangeBounds<E> + Debug;
+}
+
 /// Assert whether a numeric value is negative or positive.
 ///
 /// # Examples
@@ -857,10 +1320,159 @@ pub trait AssertNotANumber {
     fn is_a_number(self) -> Self;
 }
 
-/// Assert decimal number specific properties.
-pub trait AssertDecimalNumber {
-    /// Verifies the scale of a decimal number.
-    ///
+/// Assert whether specific bits of an integer value are set or not.
+///
+/// These assertions are implemented for all primitive integer types. The
+/// failure messages render the subject in binary and hexadecimal notation and
+/// highlight the bits that differ from the expected bit pattern.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let permissions: u16 = 0o644;
+///
+/// assert_that!(permissions).has_bit_set(8);
+/// assert_that!(permissions).has_bits_set(0o600);
+/// assert_that!(permissions).has_no_bits_set(0o111);
+/// ```
+///
+/// A failing assertion prints a message like:
+///
+/// ```console
+/// expected permissions to have the bits 0b00001010 set
+///    but was: 0b00000010 (0x02)
+///   expected: 0b00001010 (0x0a)
+/// ```
+pub trait AssertBits {
+    /// Verifies that the bit at the given index is set, where index 0 is the
+    /// least significant bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(0b0100_u8).has_bit_set(2);
+    /// assert_that!(i32::MIN).has_bit_set(31);
+    /// ```
+    #[track_caller]
+    fn has_bit_set(self, index: u32) -> Self;
+
+    /// Verifies that all bits of the given mask are set.
+    ///
+    /// The assertion fails if the mask has bits set beyond the bit width of
+    /// the subject, as these bits can never be set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(0b1110_u8).has_bits_set(0b0110);
+    /// assert_that!(0xff00_u16).has_bits_set(0x0f00);
+    /// ```
+    #[track_caller]
+    fn has_bits_set<M>(self, mask: M) -> Self
+    where
+        M: BitsProperty;
+
+    /// Verifies that none of the bits of the given mask are set.
+    ///
+    /// Bits of the mask beyond the bit width of the subject are never set in
+    /// the subject and therefore do not cause the assertion to fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(0b1110_u8).has_no_bits_set(0b0001);
+    /// assert_that!(0xff00_u16).has_no_bits_set(0x00f0);
+    /// ```
+    #[track_caller]
+    fn has_no_bits_set<M>(self, mask: M) -> Self
+    where
+        M: BitsProperty;
+}
+
+/// Assert whether a set of flags contains specific flags.
+///
+/// These assertions are implemented for all types that implement the
+/// [`bitflags::Flags`] trait, which includes all types generated by the
+/// [`bitflags!`](bitflags::bitflags) macro.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "bitflags"))]
+/// # fn main() {}
+/// # #[cfg(feature = "bitflags")]
+/// # fn main() {
+/// use asserting::prelude::*;
+/// use bitflags::bitflags;
+///
+/// bitflags! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     struct Permissions: u8 {
+///         const READ = 0b001;
+///         const WRITE = 0b010;
+///         const EXECUTE = 0b100;
+///     }
+/// }
+///
+/// let permissions = Permissions::READ | Permissions::WRITE;
+///
+/// assert_that!(permissions).contains_flags(Permissions::READ | Permissions::WRITE);
+/// # }
+/// ```
+///
+/// A failing assertion prints a message like:
+///
+/// ```console
+/// expected permissions to contain the flags Permissions(READ | EXECUTE)
+///    but was: 0b00000011 (0x03)
+///   expected: 0b00000111 (0x07)
+///    missing: Permissions(EXECUTE)
+/// ```
+#[cfg(feature = "bitflags")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bitflags")))]
+pub trait AssertBitFlags<E> {
+    /// Verifies that the subject contains all the given flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "bitflags"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "bitflags")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use bitflags::bitflags;
+    ///
+    /// bitflags! {
+    ///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    ///     struct Permissions: u8 {
+    ///         const READ = 0b001;
+    ///         const WRITE = 0b010;
+    ///         const EXECUTE = 0b100;
+    ///     }
+    /// }
+    ///
+    /// let permissions = Permissions::all();
+    ///
+    /// assert_that!(permissions).contains_flags(Permissions::READ | Permissions::EXECUTE);
+    /// # }
+    /// ```
+    #[track_caller]
+    fn contains_flags(self, flags: E) -> Self;
+}
+
+/// Assert decimal number specific properties.
+pub trait AssertDecimalNumber {
+    /// Verifies the scale of a decimal number.
+    ///
     /// It compares the scale, the total number of digits to the right of the
     /// decimal point (including insignificant leading zeros), to the expected
     /// scale.
@@ -993,182 +1605,505 @@ pub trait AssertDecimalNumber {
     fn is_integer(self) -> Self;
 }
 
-/// Assert whether some value or expression is true or false.
+/// Assert the value of a `rust_decimal::Decimal` against decimal numbers given
+/// as strings.
+///
+/// Giving the expected values as strings avoids the rounding errors of
+/// floating point literals and keeps the scale of the expected value.
+///
+/// These assertions are implemented for `rust_decimal::Decimal` (requires
+/// crate feature `rust-decimal`).
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(not(feature = "rust-decimal"))]
+/// # fn main() {}
+/// # #[cfg(feature = "rust-decimal")]
+/// # fn main() {
 /// use asserting::prelude::*;
+/// use rust_decimal::Decimal;
 ///
-/// let subject = 42 > 41;
-/// assert_that!(subject).is_true();
-///
-/// assert_that!(12 == 12).is_true();
+/// let subject = Decimal::new(12_300, 4);
 ///
-/// assert_that!(42 < 42).is_false();
+/// assert_that!(subject)
+///     .is_equal_to_decimal("1.2300")
+///     .is_close_to_decimal("1.23005", "0.0001");
+/// # }
 /// ```
-pub trait AssertBoolean {
-    /// Verifies that the subject is true.
+#[cfg(feature = "rust-decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust-decimal")))]
+pub trait AssertRustDecimal {
+    /// Verifies that the subject is equal to the decimal number given as
+    /// string, including its scale.
+    ///
+    /// In contrast to comparing two `Decimal`s with `is_equal_to`, which
+    /// considers `1.23` and `1.2300` equal, this assertion also compares the
+    /// number of digits to the right of the decimal point.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "rust-decimal"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "rust-decimal")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use rust_decimal::Decimal;
     ///
-    /// let subject = 42 > 41;
-    /// assert_that!(subject).is_true();
+    /// assert_that!(Decimal::new(12_300, 4)).is_equal_to_decimal("1.2300");
+    /// assert_that!(Decimal::new(-42, 0)).is_equal_to_decimal("-42");
+    /// # }
+    /// ```
     ///
-    /// assert_that!(12 == 12).is_true();
+    /// ```should_panic
+    /// # #[cfg(not(feature = "rust-decimal"))]
+    /// # fn main() { panic!() }
+    /// # #[cfg(feature = "rust-decimal")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use rust_decimal::Decimal;
     ///
-    /// assert_that!(41 < 42).is_true();
+    /// assert_that!(Decimal::new(123, 2)).is_equal_to_decimal("1.2300");
+    /// # }
     /// ```
     #[track_caller]
-    fn is_true(self) -> Self;
+    fn is_equal_to_decimal(self, expected: impl AsRef<str>) -> Self;
 
-    /// Verifies that the subject is false.
+    /// Verifies that the subject differs from the decimal number given as
+    /// string by at most the given margin (inclusive).
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "rust-decimal"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "rust-decimal")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use rust_decimal::Decimal;
     ///
-    /// let subject = 42 > 43;
-    /// assert_that!(subject).is_false();
-    ///
-    /// assert_that!(12 == 13).is_false();
-    ///
-    /// assert_that!(42 < 42).is_false();
+    /// assert_that!(Decimal::new(10_001, 4)).is_close_to_decimal("1.00", "0.0001");
+    /// assert_that!(Decimal::new(9_999, 4)).is_close_to_decimal("1.00", "0.0001");
+    /// # }
     /// ```
     #[track_caller]
-    fn is_false(self) -> Self;
+    fn is_close_to_decimal(self, expected: impl AsRef<str>, margin: impl AsRef<str>) -> Self;
 }
 
-/// Assert properties or classifications of a character.
+/// Assert the point in time of a date or a date and time.
+///
+/// These assertions are implemented for all types that implement the
+/// [`DateTimeProperties`](crate::properties::DateTimeProperties), which are:
+///
+/// * `chrono::NaiveDate`, `chrono::NaiveDateTime` and `chrono::DateTime`
+///   (requires crate feature `chrono`)
+/// * `time::Date`, `time::PrimitiveDateTime` and `time::OffsetDateTime`
+///   (requires crate feature `time`)
+///
+/// The subject and the expected value can be of different types, e.g., a
+/// `chrono::DateTime` can be compared to a `chrono::NaiveDate`. A date
+/// without a time of day is treated as midnight UTC of that date.
+///
+/// On failure, the date and time components that differ between the actual
+/// and the expected value are highlighted.
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(not(feature = "chrono"))]
+/// # fn main() {}
+/// # #[cfg(feature = "chrono")]
+/// # fn main() {
 /// use asserting::prelude::*;
+/// use chrono::{DateTime, NaiveDate};
+/// use std::time::Duration;
 ///
-/// assert_that!('b').is_lowercase();
-/// assert_that!('G').is_uppercase();
-/// assert_that!('H').is_alphabetic();
-/// assert_that!('z').is_alphanumeric();
-/// assert_that!('9').is_alphanumeric();
-/// assert_that!('7').is_digit(8);
-/// assert_that!('9').is_digit(10);
-/// assert_that!('F').is_digit(16);
-/// assert_that!('\t').is_whitespace();
-/// assert_that!('\u{1b}').is_control_char();
-/// assert_that!('@').is_ascii();
-/// ```
+/// let released_on: NaiveDate = "2024-03-15".parse().unwrap();
 ///
-/// To assert ASCII uppercase or lowercase, we combine the assertions `is_ascii`
-/// and `is_uppercase` or `is_lowercase` in chained assertions.
+/// assert_that!(released_on).is_before_date("2024-04-01".parse::<NaiveDate>().unwrap());
+/// assert_that!(released_on).is_after_date("2024-03-14".parse::<NaiveDate>().unwrap());
+///
+/// let published_at = DateTime::parse_from_rfc3339("2024-03-15T10:30:00+01:00").unwrap();
+///
+/// assert_that!(published_at).is_same_day_as(released_on);
+/// assert_that!(published_at).is_within_duration_of(
+///     DateTime::parse_from_rfc3339("2024-03-15T10:29:58+01:00").unwrap(),
+///     Duration::from_secs(5),
+/// );
+/// # }
+/// ```
 ///
 /// ```
+/// # #[cfg(not(feature = "time"))]
+/// # fn main() {}
+/// # #[cfg(feature = "time")]
+/// # fn main() {
 /// use asserting::prelude::*;
+/// use std::time::Duration;
+/// use time::macros::{date, datetime};
 ///
-/// assert_that('c').is_ascii().is_lowercase();
-/// assert_that('M').is_ascii().is_uppercase();
-/// assert_that('a').is_ascii().is_alphabetic();
-/// assert_that('\t').is_ascii().is_control_char();
+/// let released_on = date!(2024-03-15);
+///
+/// assert_that!(released_on).is_before_date(date!(2024-04-01));
+/// assert_that!(released_on).is_after_date(date!(2024-03-14));
+///
+/// let published_at = datetime!(2024-03-15 10:30:00 +01:00);
+///
+/// assert_that!(published_at).is_same_day_as(released_on);
+/// assert_that!(published_at).is_within_duration_of(
+///     datetime!(2024-03-15 10:29:58 +01:00),
+///     Duration::from_secs(5),
+/// );
+/// # }
 /// ```
-pub trait AssertChar {
-    /// Verify that a character is lowercase.
+pub trait AssertDateTime<E> {
+    /// Verifies that the subject is a point in time before the expected date
+    /// or date and time.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "time"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "time")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use time::macros::{date, datetime};
     ///
-    /// assert_that!('b').is_lowercase();
-    /// assert_that!('n').is_lowercase();
+    /// assert_that!(date!(2024-03-15)).is_before_date(date!(2024-03-16));
+    /// assert_that!(datetime!(2024-03-15 23:59:59)).is_before_date(date!(2024-03-16));
+    /// # }
     /// ```
     #[track_caller]
-    fn is_lowercase(self) -> Self;
+    fn is_before_date(self, expected: E) -> Self;
 
-    /// Verify that a character is uppercase.
+    /// Verifies that the subject is a point in time after the expected date
+    /// or date and time.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "time"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "time")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use time::macros::{date, datetime};
     ///
-    /// assert_that!('G').is_uppercase();
-    /// assert_that!('N').is_uppercase();
+    /// assert_that!(date!(2024-03-16)).is_after_date(date!(2024-03-15));
+    /// assert_that!(datetime!(2024-03-15 00:00:01)).is_after_date(date!(2024-03-15));
+    /// # }
     /// ```
     #[track_caller]
-    fn is_uppercase(self) -> Self;
+    fn is_after_date(self, expected: E) -> Self;
 
-    /// Verify that a character is an ASCII character.
+    /// Verifies that the subject is on the same calendar day as the expected
+    /// date or date and time.
+    ///
+    /// Only the year, month and day are compared. The time of day is ignored.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "chrono"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "chrono")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use chrono::{NaiveDate, NaiveDateTime};
     ///
-    /// assert_that!('a').is_ascii();
-    /// assert_that!('5').is_ascii();
-    /// assert_that!('#').is_ascii();
-    /// assert_that!('@').is_ascii();
+    /// let subject: NaiveDateTime = "2024-03-15T10:30:00".parse().unwrap();
+    ///
+    /// assert_that!(subject).is_same_day_as("2024-03-15".parse::<NaiveDate>().unwrap());
+    /// assert_that!(subject).is_same_day_as("2024-03-15T23:59:59".parse::<NaiveDateTime>().unwrap());
+    /// # }
     /// ```
     #[track_caller]
-    fn is_ascii(self) -> Self;
+    fn is_same_day_as(self, expected: E) -> Self;
 
-    /// Verify that a character is an alphabetic character.
+    /// Verifies that the subject is a point in time that differs from the
+    /// expected date or date and time by at most the given tolerance.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "time"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "time")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use std::time::Duration;
+    /// use time::macros::datetime;
     ///
-    /// assert_that!('b').is_alphabetic();
-    /// assert_that!('Z').is_alphabetic();
+    /// let subject = datetime!(2024-03-15 10:30:00 UTC);
+    ///
+    /// assert_that!(subject).is_within_duration_of(datetime!(2024-03-15 10:30:04 UTC), Duration::from_secs(5));
+    /// assert_that!(subject).is_within_duration_of(datetime!(2024-03-15 11:30:00 +01:00), Duration::ZERO);
+    /// # }
     /// ```
     #[track_caller]
-    fn is_alphabetic(self) -> Self;
+    fn is_within_duration_of(self, expected: E, tolerance: Duration) -> Self;
+}
 
-    /// Verify that a character is an alphabetic character or a digit.
+/// Assert the calendar components of a date or a date and time.
+///
+/// These assertions are implemented for all types that implement the
+/// [`DateTimeProperties`](crate::properties::DateTimeProperties).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "time"))]
+/// # fn main() {}
+/// # #[cfg(feature = "time")]
+/// # fn main() {
+/// use asserting::prelude::*;
+/// use time::macros::{date, datetime};
+///
+/// assert_that!(date!(2024-03-15)).is_in_year(2024);
+/// assert_that!(datetime!(2023-12-31 23:59:59)).is_in_year(2023);
+/// # }
+/// ```
+pub trait AssertCalendarDate {
+    /// Verifies that the subject is a date within the expected year.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "chrono"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "chrono")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use chrono::NaiveDate;
     ///
-    /// assert_that!('z').is_alphanumeric();
-    /// assert_that!('9').is_alphanumeric();
+    /// let subject: NaiveDate = "2024-03-15".parse().unwrap();
+    ///
+    /// assert_that!(subject).is_in_year(2024);
+    /// # }
     /// ```
     #[track_caller]
-    fn is_alphanumeric(self) -> Self;
+    fn is_in_year(self, expected_year: i32) -> Self;
+}
 
-    /// Verify that a character is a control character.
+/// Assert whether some value or expression is true or false.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject = 42 > 41;
+/// assert_that!(subject).is_true();
+///
+/// assert_that!(12 == 12).is_true();
+///
+/// assert_that!(42 < 42).is_false();
+/// ```
+pub trait AssertBoolean {
+    /// Verifies that the subject is true.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
     ///
-    /// assert_that!('\t').is_control_char();
-    /// assert_that!('\u{1b}').is_control_char();
+    /// let subject = 42 > 41;
+    /// assert_that!(subject).is_true();
+    ///
+    /// assert_that!(12 == 12).is_true();
+    ///
+    /// assert_that!(41 < 42).is_true();
     /// ```
     #[track_caller]
-    fn is_control_char(self) -> Self;
+    fn is_true(self) -> Self;
 
-    /// Verify that a character is a digit within the given radix.
+    /// Verifies that the subject is false.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
     ///
-    /// assert_that!('7').is_digit(8);
-    /// assert_that!('9').is_digit(10);
-    /// assert_that!('F').is_digit(16);
-    /// ```
-    #[track_caller]
-    fn is_digit(self, radix: u32) -> Self;
-
+    /// let subject = 42 > 43;
+    /// assert_that!(subject).is_false();
+    ///
+    /// assert_that!(12 == 13).is_false();
+    ///
+    /// assert_that!(42 < 42).is_false();
+    /// ```
+    #[track_caller]
+    fn is_false(self) -> Self;
+
+    /// Verifies that the subject is true and states the reason why it is
+    /// expected to be true.
+    ///
+    /// The reason is included in the failure message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let cache_warmed = true;
+    /// assert_that!(cache_warmed).is_true_because("the cache must be warmed by now");
+    ///
+    /// let failures = verify_that(false)
+    ///     .named("cache_warmed")
+    ///     .is_true_because("the cache must be warmed by now")
+    ///     .display_failures();
+    ///
+    /// assert_that!(failures).contains_exactly([
+    ///     "expected cache_warmed to be true because the cache must be warmed by now\n   but was: false\n  expected: true\n",
+    /// ]);
+    /// ```
+    #[track_caller]
+    fn is_true_because(self, reason: impl Into<String>) -> Self;
+
+    /// Verifies that the subject is false and states the reason why it is
+    /// expected to be false.
+    ///
+    /// The reason is included in the failure message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let queue_is_empty = false;
+    /// assert_that!(queue_is_empty).is_false_because("two jobs have been scheduled");
+    /// ```
+    #[track_caller]
+    fn is_false_because(self, reason: impl Into<String>) -> Self;
+}
+
+/// Assert properties or classifications of a character.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// assert_that!('b').is_lowercase();
+/// assert_that!('G').is_uppercase();
+/// assert_that!('H').is_alphabetic();
+/// assert_that!('z').is_alphanumeric();
+/// assert_that!('9').is_alphanumeric();
+/// assert_that!('7').is_digit(8);
+/// assert_that!('9').is_digit(10);
+/// assert_that!('F').is_digit(16);
+/// assert_that!('\t').is_whitespace();
+/// assert_that!('\u{1b}').is_control_char();
+/// assert_that!('@').is_ascii();
+/// ```
+///
+/// To assert ASCII uppercase or lowercase, we combine the assertions `is_ascii`
+/// and `is_uppercase` or `is_lowercase` in chained assertions.
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// assert_that('c').is_ascii().is_lowercase();
+/// assert_that('M').is_ascii().is_uppercase();
+/// assert_that('a').is_ascii().is_alphabetic();
+/// assert_that('\t').is_ascii().is_control_char();
+/// ```
+pub trait AssertChar {
+    /// Verify that a character is lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!('b').is_lowercase();
+    /// assert_that!('n').is_lowercase();
+    /// ```
+    #[track_caller]
+    fn is_lowercase(self) -> Self;
+
+    /// Verify that a character is uppercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!('G').is_uppercase();
+    /// assert_that!('N').is_uppercase();
+    /// ```
+    #[track_caller]
+    fn is_uppercase(self) -> Self;
+
+    /// Verify that a character is an ASCII character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!('a').is_ascii();
+    /// assert_that!('5').is_ascii();
+    /// assert_that!('#').is_ascii();
+    /// assert_that!('@').is_ascii();
+    /// ```
+    #[track_caller]
+    fn is_ascii(self) -> Self;
+
+    /// Verify that a character is an alphabetic character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!('b').is_alphabetic();
+    /// assert_that!('Z').is_alphabetic();
+    /// ```
+    #[track_caller]
+    fn is_alphabetic(self) -> Self;
+
+    /// Verify that a character is an alphabetic character or a digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!('z').is_alphanumeric();
+    /// assert_that!('9').is_alphanumeric();
+    /// ```
+    #[track_caller]
+    fn is_alphanumeric(self) -> Self;
+
+    /// Verify that a character is a control character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!('\t').is_control_char();
+    /// assert_that!('\u{1b}').is_control_char();
+    /// ```
+    #[track_caller]
+    fn is_control_char(self) -> Self;
+
+    /// Verify that a character is a digit within the given radix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!('7').is_digit(8);
+    /// assert_that!('9').is_digit(10);
+    /// assert_that!('F').is_digit(16);
+    /// ```
+    #[track_caller]
+    fn is_digit(self, radix: u32) -> Self;
+
     /// Verify that a character is whitespace.
     ///
     /// # Examples
@@ -1811,6 +2746,101 @@ pub trait AssertHasCharCount<E> {
     fn has_at_least_char_count(self, expected: E) -> Self;
 }
 
+/// Assert the capacity of a collection-like subject that preallocates memory,
+/// like `Vec`, `String` or `HashMap`.
+///
+/// These assertions are useful for verifying the preallocation behavior of
+/// performance-sensitive code.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let mut subject: Vec<u32> = Vec::with_capacity(16);
+/// subject.extend([1, 2, 3, 4, 5]);
+///
+/// assert_that!(&subject).has_capacity(16);
+/// assert_that!(&subject).has_capacity_at_least(10);
+/// assert_that!(&subject).has_spare_capacity_of(11);
+///
+/// let mut subject = String::with_capacity(32);
+/// subject.push_str("vero eos");
+///
+/// assert_that!(&subject).has_capacity(32);
+/// assert_that!(&subject).has_capacity_at_least(32);
+/// assert_that!(&subject).has_spare_capacity_of(24);
+/// ```
+pub trait AssertHasCapacity {
+    /// Verifies that the subject has exactly the expected capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Vec<char> = Vec::with_capacity(8);
+    /// assert_that!(subject).has_capacity(8);
+    ///
+    /// let subject = String::with_capacity(20);
+    /// assert_that!(subject).has_capacity(20);
+    /// ```
+    #[track_caller]
+    fn has_capacity(self, expected_capacity: usize) -> Self;
+
+    /// Verifies that the subject has a capacity that is greater than or equal
+    /// to the expected capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Vec<char> = Vec::with_capacity(8);
+    /// assert_that!(&subject).has_capacity_at_least(5);
+    /// assert_that!(&subject).has_capacity_at_least(8);
+    /// ```
+    ///
+    /// with crate feature `std` enabled:
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {
+    /// # }
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use std::collections::HashMap;
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: HashMap<u64, String> = HashMap::with_capacity(100);
+    /// assert_that!(subject).has_capacity_at_least(100);
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_capacity_at_least(self, expected_capacity: usize) -> Self;
+
+    /// Verifies that the subject has the expected spare capacity.
+    ///
+    /// The spare capacity is the number of elements that can be added to the
+    /// subject without reallocating, i.e. the capacity minus the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let mut subject: Vec<i16> = Vec::with_capacity(10);
+    /// subject.extend([-3, 0, 7]);
+    /// assert_that!(subject).has_spare_capacity_of(7);
+    ///
+    /// let mut subject = String::with_capacity(12);
+    /// subject.push_str("sed");
+    /// assert_that!(subject).has_spare_capacity_of(9);
+    /// ```
+    #[track_caller]
+    fn has_spare_capacity_of(self, expected_spare_capacity: usize) -> Self;
+}
+
 /// Assert whether a subject of the `Option` type holds some value or has none.
 ///
 /// # Examples
@@ -1892,6 +2922,13 @@ pub trait AssertOptionValue {
     /// Maps the subject to the option's value if it has some. Otherwise, this
     /// assertion fails.
     ///
+    /// The failure is reported according to the failing strategy. As there is
+    /// no value to continue the assertion chain with, all following assertions
+    /// of the chain are skipped.
+    ///
+    /// Failures of the following assertions refer to the subject as
+    /// `"{expression} unwrapped Some value"`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1907,310 +2944,1184 @@ pub trait AssertOptionValue {
     fn some(self) -> Self::Some;
 }
 
-/// Assert whether a subject of the `Result` type holds some value or an error.
+/// Assert the value of a nested option by mapping the subject.
 ///
 /// # Examples
 ///
 /// ```
 /// use asserting::prelude::*;
 ///
-/// let subject: Result<f64, String> = Ok(-3.14);
-/// assert_that!(subject).is_ok();
-///
-/// let subject: Result<(), String> = Err("consequat sanctus ea exercitation".to_string());
-/// assert_that!(subject).is_err();
+/// let subject: Option<Option<&str>> = Some(Some("tempor"));
+/// assert_that!(subject).flattened().is_equal_to("tempor");
 /// ```
-pub trait AssertResult {
-    /// Verifies that the subject has an ok value.
+pub trait AssertNestedOptionValue {
+    /// A spec-like type that contains the value of the inner option as the
+    /// subject, which is returned by the mapping assertion method.
+    ///
+    /// If the subject is an `Option<Option<T>>`, this is usually
+    /// `Spec<'a, T, R>`.
+    type Flattened;
+
+    /// Maps the subject to the value of the inner option if both the outer and
+    /// the inner option have some value. Otherwise, this assertion fails with a
+    /// message stating which of the options is `None`.
+    ///
+    /// The failure is reported according to the failing strategy. As there is
+    /// no value to continue the assertion chain with, all following assertions
+    /// of the chain are skipped.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
     ///
-    /// let subject: Result<f64, String> = Ok(-3.14);
-    /// assert_that!(subject).is_ok();
+    /// let subject: Option<Option<Vec<usize>>> = Some(Some(vec![1, 2, 3]));
+    /// assert_that!(subject).flattened().contains_exactly([1, 2, 3]);
     /// ```
     #[track_caller]
-    fn is_ok(self) -> Self;
+    fn flattened(self) -> Self::Flattened;
+}
 
-    /// Verifies that the subject has an err value.
+/// Assert the value wrapped by a smart pointer or a cell by mapping the
+/// subject.
+///
+/// This assertion is implemented for `Box`, `Rc`, `Arc`, `Cell` and `RefCell`.
+/// It makes all assertions available for the wrapped value, without the need
+/// to map the subject manually.
+///
+/// Only assertions on properties, like `is_empty`, `has_length` or
+/// `is_positive`, are forwarded to the wrapped value directly. Assertions that
+/// compare the subject with an expected value, like `is_equal_to` or
+/// `is_greater_than`, require an expected value of the same smart pointer
+/// type, as the standard library implements `PartialEq` and `PartialOrd` for
+/// smart pointers only in this way. To compare the wrapped value with a plain
+/// value, the subject must be mapped with `inner()`:
+///
+/// ```compile_fail
+/// use asserting::prelude::*;
+/// use std::sync::Arc;
+///
+/// assert_that!(Arc::new(5)).is_greater_than(3);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use std::sync::Arc;
+///
+/// assert_that!(Arc::new(5)).inner().is_greater_than(3);
+/// assert_that!(Arc::new(5)).is_greater_than(Arc::new(3));
+/// assert_that!(Rc::new("aute possim")).inner().starts_with("aute");
+/// assert_that!(RefCell::new(vec![1, 2, 3])).inner().contains_exactly([1, 2, 3]);
+/// ```
+pub trait AssertInnerValue {
+    /// A spec-like type that contains the wrapped value as the subject, which
+    /// is returned by the mapping assertion method.
+    ///
+    /// If the subject is an `Arc<T>`, this is usually `Spec<'a, T, R>`.
+    type Inner;
+
+    /// Maps the subject to the value it wraps.
+    ///
+    /// For `Rc` and `Arc` the wrapped value is cloned, if the subject is not
+    /// the only reference to it.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
+    /// use std::cell::{Cell, RefCell};
+    /// use std::rc::Rc;
+    /// use std::sync::Arc;
     ///
-    /// let subject: Result<(), String> = Err("consequat sanctus ea exercitation".to_string());
-    /// assert_that!(subject).is_err();
+    /// assert_that!(Box::new(-2)).inner().is_negative();
+    /// assert_that!(Arc::new(5)).inner().is_greater_than(3);
+    /// assert_that!(Rc::new(String::from("aute possim"))).inner().is_equal_to("aute possim");
+    /// assert_that!(Cell::new(0.5)).inner().is_in_range(0.0..=1.0);
+    /// assert_that!(RefCell::new(vec![1, 2, 3])).inner().contains_exactly([1, 2, 3]);
+    ///
+    /// let shared = Arc::new(vec![1, 2, 3]);
+    /// assert_that!(&shared).inner().contains(&2);
     /// ```
     #[track_caller]
-    fn is_err(self) -> Self;
+    fn inner(self) -> Self::Inner;
 }
 
-/// Assert the ok-value or error of a result by mapping the subject.
+/// Assert the concrete type of a type-erased value, like `Box<dyn Any>` or
+/// `&dyn Any`.
+///
+/// The type of the value is determined by downcasting it.
 ///
 /// # Examples
 ///
 /// ```
 /// use asserting::prelude::*;
+/// use std::any::Any;
 ///
-/// let subject: Result<Vec<usize>, String> = Ok(vec![1, 2, 3]);
-/// assert_that!(subject).ok().is_not_empty();
+/// let subject: Box<dyn Any> = Box::new(42_u64);
 ///
-/// let subject: Result<u64, String> = Err("te anim adipisici mollit".to_string());
-/// assert_that!(subject).err().is_equal_to("te anim adipisici mollit");
+/// assert_that!(&subject).is_instance_of::<u64>();
+/// assert_that!(subject).is_not_instance_of::<i64>();
 /// ```
-pub trait AssertResultValue {
-    /// A spec-like type that contains the result's ok value as the subject,
-    /// which is returned by the mapping assertion method.
-    ///
-    /// If the subject is a `Result<T, E>`, this is usually `Spec<'a, T, R>`.
-    type Ok;
-    /// A spec-like type that contains the result's error value as the subject,
-    /// which is returned by the mapping assertion method.
-    ///
-    /// If the subject is a `Result<T, E>`, this is usually `Spec<'a, E, R>`.
-    type Err;
-
-    /// Maps the subject to the result's ok value.
-    ///
-    /// If the result is an error, this method panics.
+pub trait AssertInstanceOf {
+    /// Verifies that the subject is an instance of the type `T`.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
+    /// use std::any::Any;
     ///
-    /// let subject: Result<Vec<usize>, String> = Ok(vec![1, 2, 3]);
-    /// assert_that!(subject).ok().is_not_empty();
+    /// let subject: Box<dyn Any + Send> = Box::new(String::from("qui exerci"));
+    /// assert_that!(subject).is_instance_of::<String>();
+    ///
+    /// let value = 3.5_f32;
+    /// let subject: &dyn Any = &value;
+    /// assert_that!(subject).is_instance_of::<f32>();
     /// ```
     #[track_caller]
-    fn ok(self) -> Self::Ok;
+    fn is_instance_of<T>(self) -> Self
+    where
+        T: Any;
 
-    /// Maps the subject to the result's err value.
-    ///
-    /// If the result is an ok value, this method panics.
+    /// Verifies that the subject is not an instance of the type `T`.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
+    /// use std::any::Any;
     ///
-    /// let subject: Result<u64, String> = Err("te anim adipisici mollit".to_string());
-    /// assert_that!(subject).err().is_equal_to("te anim adipisici mollit");
+    /// let subject: Box<dyn Any + Send> = Box::new(String::from("qui exerci"));
+    /// assert_that!(subject).is_not_instance_of::<&str>();
     /// ```
     #[track_caller]
-    fn err(self) -> Self::Err;
+    fn is_not_instance_of<T>(self) -> Self
+    where
+        T: Any;
 }
 
-/// Assert that a subject of some container type holds a value that is equal to
-/// the expected one.
-///
-/// This assertion is implemented for the `Option` type and the `Result` type.
-/// For `Option` it compares the value to the expected one if it has some or
-/// fails if it holds none. For `Result` it compares the ok value to the
-/// expected one if it is an ok or fails if it holds an error.
-///
-/// The value type of the `Option` or `Result` must implement `PartialEq<E>`
-/// where `E` is the type of the expected value.
+/// Assert the concrete type of a type-erased value, like `Box<dyn Any>` or
+/// `&dyn Any`, and map the subject to the downcast value.
 ///
-/// To assert the error value of a `Result` use [`AssertHasError::has_error`].
+/// If the value is not of the expected type, the assertion fails.
 ///
 /// # Examples
 ///
 /// ```
 /// use asserting::prelude::*;
+/// use std::any::Any;
 ///
-/// let subject = Some(-3.14);
-/// assert_that!(subject).has_value(-3.14);
+/// let subject: Box<dyn Any> = Box::new(vec![1, 2, 3]);
 ///
-/// let subject: Result<f64, String> = Ok(6.28);
-/// assert_that!(subject).has_value(6.28);
+/// assert_that!(subject).downcasting::<Vec<i32>>().contains_exactly([1, 2, 3]);
 /// ```
-pub trait AssertHasValue<E> {
-    /// Verifies that the subject holds a value that is equal to the expected
-    /// one.
+pub trait AssertDowncast {
+    /// A spec-like type that contains the downcast value as the subject,
+    /// which is returned by the mapping assertion method.
     ///
-    /// For `Option` it compares the value in `Some(value)` and for `Result`
-    /// it compares the value in `Ok(value)`. If an `Option` is `None` or a
-    /// `Result` is `Err(error)` then the assertion fails.
+    /// If the subject is a `Box<dyn Any>`, this is usually `Spec<'a, T, R>`.
+    /// If the subject is a `&'b dyn Any`, this is usually
+    /// `Spec<'a, &'b T, R>`.
+    type Downcast<T>
+    where
+        T: Any;
+
+    /// Maps the subject to the downcast value if it is an instance of the
+    /// type `T`. Otherwise, this assertion fails.
+    ///
+    /// The failure is reported according to the failing strategy. As there is
+    /// no value to continue the assertion chain with, all following assertions
+    /// of the chain are skipped.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
+    /// use std::any::Any;
     ///
-    /// let subject = Some(-3.14);
-    /// assert_that!(subject).has_value(-3.14);
+    /// let subject: Box<dyn Any> = Box::new(vec![1, 2, 3]);
+    /// assert_that!(subject).downcasting::<Vec<i32>>().contains_exactly([1, 2, 3]);
     ///
-    /// let subject: Result<f64, String> = Ok(6.28);
-    /// assert_that!(subject).has_value(6.28);
+    /// let value = String::from("congue autem");
+    /// let subject: &dyn Any = &value;
+    /// assert_that!(subject).downcasting::<String>().starts_with("congue");
     /// ```
     #[track_caller]
-    fn has_value(self, expected: E) -> Self;
+    fn downcasting<T>(self) -> Self::Downcast<T>
+    where
+        T: Any;
 }
 
-/// Assert that a subject of some container type holds an error value that is
-/// equal to the expected one.
-///
-/// This assertion is implemented for the `Result` type. It compares the value
-/// in `Err(value)` with the expected one. The error type in the `Result` must
-/// implement `PartialEq<E>` where `E` is the type of the expected error value.
+/// Assert the elements of a tuple by mapping the subject to one of its
+/// elements.
 ///
-/// To assert the ok value of a `Result` use [`AssertHasValue::has_value`].
+/// This assertion is implemented for tuples with up to 12 elements and for
+/// references to such tuples. The expression of the returned spec is built
+/// from the expression of the original subject and the index of the element,
+/// e.g. `pair.1`.
 ///
 /// # Examples
 ///
 /// ```
 /// use asserting::prelude::*;
 ///
-/// let subject: Result<(), String> = Err("labore gubergren ut ipsum".to_string());
-/// assert_that!(subject).has_error("labore gubergren ut ipsum");
+/// let pair = ("consequat", 42);
+///
+/// assert_that!(pair).first().starts_with("conse");
+/// assert_that!(pair).second().is_greater_than(0);
+/// assert_that!(&pair).element::<1>().is_equal_to(&42);
 /// ```
-pub trait AssertHasError<E> {
-    /// Verifies that the subject holds an error value that is equal to the
-    /// expected one.
+pub trait AssertTupleElements<S> {
+    /// A spec-like type that contains the element of the tuple as the subject,
+    /// which is returned by the mapping assertion methods.
     ///
-    /// For `Result` it compares the value in `Err(value)`. If the `Result`
-    /// holds an `Ok(value)`, the assertion fails.
+    /// If the subject is a `Spec<'a, S, R>`, this is usually
+    /// `Spec<'a, T, R>` where `T` is the type of the selected element.
+    type Element<T>;
+
+    /// Maps the subject to the first element of the tuple.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
     ///
-    /// let subject: Result<(), String> = Err("labore gubergren ut ipsum".to_string());
-    /// assert_that!(subject).has_error("labore gubergren ut ipsum");
+    /// let subject = (-3, "sea");
+    /// assert_that!(subject).first().is_negative();
     /// ```
     #[track_caller]
-    fn has_error(self, expected: E) -> Self;
+    fn first(self) -> Self::Element<<S as TupleElementProperty<0>>::Element>
+    where
+        S: TupleElementProperty<0>;
+
+    /// Maps the subject to the second element of the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = (-3, "sea");
+    /// assert_that!(subject).second().is_equal_to("sea");
+    /// ```
+    #[track_caller]
+    fn second(self) -> Self::Element<<S as TupleElementProperty<1>>::Element>
+    where
+        S: TupleElementProperty<1>;
+
+    /// Maps the subject to the element of the tuple at index `N`.
+    ///
+    /// The index is 0-based, so `element::<0>()` is equivalent to `first()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = ('x', 1.5, vec![1, 2, 3]);
+    /// assert_that!(subject).element::<2>().contains_exactly([1, 2, 3]);
+    /// ```
+    #[track_caller]
+    fn element<const N: usize>(self) -> Self::Element<<S as TupleElementProperty<N>>::Element>
+    where
+        S: TupleElementProperty<N>;
 }
 
-/// Assert that a subject of some container type holds an error value that has
-/// a message equal to the expected message.
-///
-/// This is useful for opaque error types that do not implement
-/// `PartialEq`. Since the `std::error::Error` trait requires that error
-/// types implement `Display`, the string representation of the error value
-/// is compared to an expected message string.
+/// Assert how the two values of a pair relate to each other.
 ///
-/// This assertion is implemented for the `Result` type. It compares the string
-/// representation of the error value with the expected message.
+/// These assertions are implemented for tuples of two values of the same type.
+/// They are handy when a test compares two computed values rather than a
+/// value against a literal. The macros [`assert_that!`](crate::assert_that)
+/// and [`verify_that!`](crate::verify_that) accept two expressions and start an
+/// assertion on the pair of their values, naming the subject after both
+/// expressions.
 ///
-/// To assert the ok value of a `Result` use [`AssertHasValue::has_value`].
+/// In failure messages, the first value of the pair is labeled `left` and the
+/// second value is labeled `right`.
 ///
 /// # Examples
 ///
 /// ```
-/// use anyhow::anyhow;
 /// use asserting::prelude::*;
 ///
-/// let subject: Result<(), anyhow::Error> = Err(anyhow!("mollit in ullamcorper no".to_string()));
-/// assert_that!(subject).has_error_message("mollit in ullamcorper no");
+/// let subtotal = 40 + 2;
+/// let total = 6 * 7;
+///
+/// assert_that!(subtotal, total).are_equal();
+/// assert_that!(subtotal - 1, total).are_ordered_ascending();
+/// assert_that!(subtotal, total + 2).differ_by_at_most(2);
 /// ```
-pub trait AssertHasErrorMessage<E> {
-    /// A spec-like type that contains the error message as the subject,
-    /// which is returned by the mapping assertion method.
+pub trait AssertPair<T> {
+    /// Verifies that both values of the pair are equal.
     ///
-    /// This is usually a `Spec<'a, String, R>`.
-    type ErrorMessage;
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let words = ["lorem", "ipsum"];
+    ///
+    /// assert_that!(words[0].len(), words[1].len()).are_equal();
+    /// ```
+    #[track_caller]
+    fn are_equal(self) -> Self
+    where
+        T: PartialEq;
 
-    /// Verifies that the subject is an error value with the expected message.
+    /// Verifies that the values of the pair are not equal.
     ///
-    /// This is useful for opaque error types that do not implement
-    /// `PartialEq`. Since the `std::error::Error` trait requires that error
-    /// types implement `Display`, the string representation of the error value
-    /// is compared to an expected message string.
+    /// # Examples
     ///
-    /// This method panics if the actual subject is not an error value.
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let words = ["lorem", "dolor"];
+    ///
+    /// assert_that!(words[0], words[1]).are_not_equal();
+    /// ```
+    #[track_caller]
+    fn are_not_equal(self) -> Self
+    where
+        T: PartialEq;
+
+    /// Verifies that the first value of the pair is less than or equal to the
+    /// second value.
     ///
     /// # Examples
     ///
     /// ```
-    /// use anyhow::anyhow;
     /// use asserting::prelude::*;
     ///
-    /// let subject: Result<(), anyhow::Error> = Err(anyhow!("mollit in ullamcorper no".to_string()));
-    /// assert_that!(subject).has_error_message("mollit in ullamcorper no");
+    /// let started_at = 1_200;
+    /// let finished_at = 1_350;
+    ///
+    /// assert_that!(started_at, finished_at).are_ordered_ascending();
     /// ```
     #[track_caller]
-    fn has_error_message(self, expected_message: E) -> Self::ErrorMessage;
+    fn are_ordered_ascending(self) -> Self
+    where
+        T: PartialOrd;
+
+    /// Verifies that the values of the pair differ by at most the given
+    /// amount, in either direction.
+    ///
+    /// The difference is computed without overflowing, so an assertion on
+    /// `(i8::MIN, i8::MAX)` fails, stating that the difference exceeds the
+    /// range of `i8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let measured = 98;
+    /// let calculated = 100;
+    ///
+    /// assert_that!(measured, calculated).differ_by_at_most(2);
+    /// assert_that!(calculated, measured).differ_by_at_most(2);
+    /// ```
+    #[track_caller]
+    fn differ_by_at_most(self, max_difference: T) -> Self
+    where
+        T: PartialOrd + CheckedDifferenceProperty;
 }
 
-/// Assert the source of any type that implements `std::error::Error`.
+/// Assert that an `Option` holds a value equal to the default value of its
+/// type.
+///
+/// This is useful to verify that an optional configuration value falls back
+/// to its default value.
 ///
 /// # Examples
 ///
 /// ```
 /// use asserting::prelude::*;
-/// use std::error::Error;
-/// use std::fmt::{self, Display};
 ///
-/// #[derive(Debug)]
-/// struct SuperError {
-///     source: SourceError,
-/// }
+/// let timeout: Option<u64> = Some(0);
+/// assert_that!(timeout).has_value_equal_to_default();
 ///
-/// impl Display for SuperError {
-///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-///         write!(f, "super-error caused by {}", self.source)
-///     }
-/// }
+/// let name: Option<String> = Some(String::new());
+/// assert_that!(name).has_value_equal_to_default();
+/// ```
+pub trait AssertOptionHasDefaultValue {
+    /// Verifies that the subject is `Some(value)` and the value is equal to
+    /// the default value of its type.
+    ///
+    /// The failure message states whether the subject is `None` or holds a
+    /// value that is different from the default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let retries: Option<u8> = Some(0);
+    /// assert_that!(retries).has_value_equal_to_default();
+    /// ```
+    #[track_caller]
+    fn has_value_equal_to_default(self) -> Self;
+}
+
+/// Assert that an `Option` is either `None` or holds a value that satisfies a
+/// predicate.
 ///
-/// impl Error for SuperError {
-///     fn source(&self) -> Option<&(dyn Error + 'static)> {
-///         Some(&self.source)
-///     }
-/// }
+/// # Examples
 ///
-/// #[derive(Debug, PartialEq)]
-/// enum SourceError {
-///     Foo,
-///     Bar,
-/// }
+/// ```
+/// use asserting::prelude::*;
 ///
-/// impl Display for SourceError {
-///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-///         match self {
-///             Self::Foo => f.write_str("foo error"),
-///             Self::Bar => f.write_str("bar error"),
-///         }
-///     }
-/// }
+/// let port: Option<u16> = None;
+/// assert_that!(port).is_none_or(|port| *port >= 1024);
 ///
-/// impl Error for SourceError {}
+/// let port: Option<u16> = Some(8080);
+/// assert_that!(port).is_none_or(|port| *port >= 1024);
+/// ```
+pub trait AssertOptionIsNoneOr<T> {
+    /// Verifies that the subject is `None` or holds a value that satisfies
+    /// the given predicate.
+    ///
+    /// The failure message states that the subject holds a value that does
+    /// not satisfy the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let max_connections: Option<usize> = Some(16);
+    /// assert_that!(max_connections).is_none_or(|value| *value > 0);
+    ///
+    /// let max_connections: Option<usize> = None;
+    /// assert_that!(max_connections).is_none_or(|value| *value > 0);
+    /// ```
+    #[track_caller]
+    fn is_none_or<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&T) -> bool;
+}
+
+/// Assert whether a subject of the `Poll` type is ready or pending.
 ///
-/// assert_that!(&SourceError::Foo).has_no_source();
+/// # Examples
 ///
-/// let error = SuperError {
-///     source: SourceError::Foo,
-/// };
+/// ```
+/// use asserting::prelude::*;
+/// use std::task::Poll;
 ///
-/// assert_that!(&error).has_source();
-/// assert_that!(&error).has_source_message("foo error");
+/// let subject: Poll<u32> = Poll::Ready(42);
+/// assert_that!(subject).is_ready();
+///
+/// let subject: Poll<u32> = Poll::Pending;
+/// assert_that!(subject).is_pending();
 /// ```
-pub trait AssertErrorHasSource {
-    /// A spec-like type that contains the source message as the subject,
-    /// which is returned by the mapping assertion method.
+pub trait AssertPoll {
+    /// Verifies that the subject is ready.
     ///
-    /// This is usually a `Spec<'a, String, R>`.
-    type SourceMessage;
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::task::Poll;
+    ///
+    /// let subject: Poll<&str> = Poll::Ready("tempor");
+    /// assert_that!(subject).is_ready();
+    /// ```
+    #[track_caller]
+    fn is_ready(self) -> Self;
 
-    /// Verifies that an error has no source.
+    /// Verifies that the subject is pending.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
-    /// use std::error::Error;
-    /// use std::fmt::{self, Display};
-    ///
-    /// #[derive(Debug, PartialEq)]
-    /// enum SimpleError {
-    ///     Foo,
-    ///     Bar,
-    /// }
+    /// use std::task::Poll;
+    ///
+    /// let subject: Poll<&str> = Poll::Pending;
+    /// assert_that!(subject).is_pending();
+    /// ```
+    #[track_caller]
+    fn is_pending(self) -> Self;
+}
+
+/// Assert that a subject of the `Poll` type is ready with an expected value.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::task::Poll;
+///
+/// let subject: Poll<Vec<i32>> = Poll::Ready(vec![1, 2, 3]);
+/// assert_that!(subject).is_ready_with(vec![1, 2, 3]);
+/// ```
+pub trait AssertPollValue<E> {
+    /// Verifies that the subject is ready with a value that is equal to the
+    /// expected one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::task::Poll;
+    ///
+    /// let subject: Poll<f64> = Poll::Ready(-1.5);
+    /// assert_that!(subject).is_ready_with(-1.5);
+    /// ```
+    #[track_caller]
+    fn is_ready_with(self, expected: E) -> Self;
+}
+
+/// Poll a future once and continue with asserting the returned `Poll` value.
+///
+/// The future is polled with a waker that does nothing when it is woken. This
+/// allows for unit testing the state of a future without an async runtime.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::future::{pending, ready};
+///
+/// assert_that!(ready(42)).poll_once().is_ready_with(42);
+///
+/// assert_that!(pending::<()>()).poll_once().is_pending();
+/// ```
+pub trait AssertFuturePoll {
+    /// The type of the spec for the `Poll` value returned by the future.
+    type Polled;
+
+    /// Polls the subject future exactly once and maps the subject to the
+    /// returned `Poll` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let future = async { "ipsum".len() };
+    ///
+    /// assert_that!(future).poll_once().is_ready_with(5);
+    /// ```
+    #[track_caller]
+    fn poll_once(self) -> Self::Polled;
+}
+
+/// Assert whether a subject of the `Result` type holds some value or an error.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject: Result<f64, String> = Ok(-3.14);
+/// assert_that!(subject).is_ok();
+///
+/// let subject: Result<(), String> = Err("consequat sanctus ea exercitation".to_string());
+/// assert_that!(subject).is_err();
+/// ```
+pub trait AssertResult {
+    /// Verifies that the subject has an ok value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Result<f64, String> = Ok(-3.14);
+    /// assert_that!(subject).is_ok();
+    /// ```
+    #[track_caller]
+    fn is_ok(self) -> Self;
+
+    /// Verifies that the subject has an err value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Result<(), String> = Err("consequat sanctus ea exercitation".to_string());
+    /// assert_that!(subject).is_err();
+    /// ```
+    #[track_caller]
+    fn is_err(self) -> Self;
+}
+
+/// Assert the ok-value or error of a result by mapping the subject.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject: Result<Vec<usize>, String> = Ok(vec![1, 2, 3]);
+/// assert_that!(subject).ok().is_not_empty();
+///
+/// let subject: Result<u64, String> = Err("te anim adipisici mollit".to_string());
+/// assert_that!(subject).err().is_equal_to("te anim adipisici mollit");
+/// ```
+pub trait AssertResultValue {
+    /// A spec-like type that contains the result's ok value as the subject,
+    /// which is returned by the mapping assertion method.
+    ///
+    /// If the subject is a `Result<T, E>`, this is usually `Spec<'a, T, R>`.
+    type Ok;
+    /// A spec-like type that contains the result's error value as the subject,
+    /// which is returned by the mapping assertion method.
+    ///
+    /// If the subject is a `Result<T, E>`, this is usually `Spec<'a, E, R>`.
+    type Err;
+
+    /// Maps the subject to the result's ok value.
+    ///
+    /// If the result is an error, this assertion fails. The failure is
+    /// reported according to the failing strategy. As there is no value to
+    /// continue the assertion chain with, all following assertions of the
+    /// chain are skipped.
+    ///
+    /// Failures of the following assertions refer to the subject as
+    /// `"{expression} unwrapped Ok value"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Result<Vec<usize>, String> = Ok(vec![1, 2, 3]);
+    /// assert_that!(subject).ok().is_not_empty();
+    /// ```
+    #[track_caller]
+    fn ok(self) -> Self::Ok;
+
+    /// Maps the subject to the result's err value.
+    ///
+    /// If the result is an ok value, this assertion fails. The failure is
+    /// reported according to the failing strategy. As there is no value to
+    /// continue the assertion chain with, all following assertions of the
+    /// chain are skipped.
+    ///
+    /// Failures of the following assertions refer to the subject as
+    /// `"{expression} unwrapped Err value"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Result<u64, String> = Err("te anim adipisici mollit".to_string());
+    /// assert_that!(subject).err().is_equal_to("te anim adipisici mollit");
+    /// ```
+    #[track_caller]
+    fn err(self) -> Self::Err;
+}
+
+/// Assert the elements of a collection or an iterator of `Result`s.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject: Vec<Result<u8, String>> = vec![Ok(1), Ok(2), Ok(3)];
+/// assert_that!(subject).all_ok();
+///
+/// let subject: Vec<Result<u8, String>> = vec![Ok(1), Err("overflow".into()), Ok(3)];
+/// assert_that!(subject).any_err();
+///
+/// let subject: Vec<Result<u8, String>> = vec![Ok(1), Err("overflow".into()), Ok(3)];
+/// assert_that!(subject).ok_values().contains_exactly([1, 3]);
+/// ```
+pub trait AssertResultElements {
+    /// A spec-like type that contains all the results of the iterator as the
+    /// subject.
+    ///
+    /// Usually this is a `Spec<'a, Vec<Result<T, E>>, R>`.
+    type Results;
+    /// A spec-like type that contains the ok values of the results as the
+    /// subject.
+    ///
+    /// Usually this is a `Spec<'a, Vec<T>, R>`.
+    type OkValues;
+    /// A spec-like type that contains the error values of the results as the
+    /// subject.
+    ///
+    /// Usually this is a `Spec<'a, Vec<E>, R>`.
+    type ErrValues;
+
+    /// Verifies that all elements of a collection or an iterator are ok values.
+    ///
+    /// The failure message lists the indices of the elements that are errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Vec<Result<u8, String>> = vec![Ok(1), Ok(2), Ok(3)];
+    /// assert_that!(subject).all_ok();
+    /// ```
+    #[track_caller]
+    fn all_ok(self) -> Self::Results;
+
+    /// Verifies that all elements of a collection or an iterator are errors.
+    ///
+    /// The failure message lists the indices of the elements that are ok
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Vec<Result<u8, &str>> = vec![Err("overflow"), Err("underflow")];
+    /// assert_that!(subject).all_err();
+    /// ```
+    #[track_caller]
+    fn all_err(self) -> Self::Results;
+
+    /// Verifies that at least one element of a collection or an iterator is an
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Vec<Result<u8, &str>> = vec![Ok(1), Err("overflow"), Ok(3)];
+    /// assert_that!(subject).any_err();
+    /// ```
+    #[track_caller]
+    fn any_err(self) -> Self::Results;
+
+    /// Maps the subject to the ok values of the results, dropping all errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Vec<Result<u8, &str>> = vec![Ok(1), Err("overflow"), Ok(3)];
+    /// assert_that!(subject).ok_values().contains_exactly([1, 3]);
+    /// ```
+    #[track_caller]
+    fn ok_values(self) -> Self::OkValues;
+
+    /// Maps the subject to the error values of the results, dropping all ok
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Vec<Result<u8, &str>> = vec![Ok(1), Err("overflow"), Ok(3)];
+    /// assert_that!(subject).err_values().contains_exactly(["overflow"]);
+    /// ```
+    #[track_caller]
+    fn err_values(self) -> Self::ErrValues;
+}
+
+/// Assert the value of an option that is wrapped in the ok value of a result by
+/// mapping the subject.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject: Result<Option<u64>, String> = Ok(Some(42));
+/// assert_that!(subject).some_ok().is_equal_to(42);
+/// ```
+pub trait AssertNestedResultValue {
+    /// A spec-like type that contains the value of the option as the subject,
+    /// which is returned by the mapping assertion method.
+    ///
+    /// If the subject is a `Result<Option<T>, E>`, this is usually
+    /// `Spec<'a, T, R>`.
+    type SomeOk;
+
+    /// Maps the subject to the value of the option inside the result's ok
+    /// value. If the result is an error or the option is `None`, this
+    /// assertion fails with a message stating which of them was the wrong
+    /// variant.
+    ///
+    /// The failure is reported according to the failing strategy. As there is
+    /// no value to continue the assertion chain with, all following assertions
+    /// of the chain are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Result<Option<&str>, String> = Ok(Some("facilisis"));
+    /// assert_that!(subject).some_ok().starts_with("facil");
+    /// ```
+    #[track_caller]
+    fn some_ok(self) -> Self::SomeOk;
+}
+
+/// Assert that a subject of some container type holds a value that is equal to
+/// the expected one.
+///
+/// This assertion is implemented for the `Option` type and the `Result` type.
+/// For `Option` it compares the value to the expected one if it has some or
+/// fails if it holds none. For `Result` it compares the ok value to the
+/// expected one if it is an ok or fails if it holds an error.
+///
+/// The value type of the `Option` or `Result` must implement `PartialEq<E>`
+/// where `E` is the type of the expected value.
+///
+/// To assert the error value of a `Result` use [`AssertHasError::has_error`].
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject = Some(-3.14);
+/// assert_that!(subject).has_value(-3.14);
+///
+/// let subject: Result<f64, String> = Ok(6.28);
+/// assert_that!(subject).has_value(6.28);
+/// ```
+pub trait AssertHasValue<E> {
+    /// Verifies that the subject holds a value that is equal to the expected
+    /// one.
+    ///
+    /// For `Option` it compares the value in `Some(value)` and for `Result`
+    /// it compares the value in `Ok(value)`. If an `Option` is `None` or a
+    /// `Result` is `Err(error)` then the assertion fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = Some(-3.14);
+    /// assert_that!(subject).has_value(-3.14);
+    ///
+    /// let subject: Result<f64, String> = Ok(6.28);
+    /// assert_that!(subject).has_value(6.28);
+    /// ```
+    #[track_caller]
+    fn has_value(self, expected: E) -> Self;
+}
+
+/// Assert that a subject of some container type holds an error value that is
+/// equal to the expected one.
+///
+/// This assertion is implemented for the `Result` type. It compares the value
+/// in `Err(value)` with the expected one. The error type in the `Result` must
+/// implement `PartialEq<E>` where `E` is the type of the expected error value.
+///
+/// To assert the ok value of a `Result` use [`AssertHasValue::has_value`].
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject: Result<(), String> = Err("labore gubergren ut ipsum".to_string());
+/// assert_that!(subject).has_error("labore gubergren ut ipsum");
+/// ```
+pub trait AssertHasError<E> {
+    /// Verifies that the subject holds an error value that is equal to the
+    /// expected one.
+    ///
+    /// For `Result` it compares the value in `Err(value)`. If the `Result`
+    /// holds an `Ok(value)`, the assertion fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Result<(), String> = Err("labore gubergren ut ipsum".to_string());
+    /// assert_that!(subject).has_error("labore gubergren ut ipsum");
+    /// ```
+    #[track_caller]
+    fn has_error(self, expected: E) -> Self;
+}
+
+/// Assert that a subject of some container type holds an error value that
+/// matches a predicate.
+///
+/// This is useful for error types that do not implement `PartialEq`, e.g., to
+/// assert the variant of an error enum.
+///
+/// This assertion is implemented for the `Result` type. It calls the predicate
+/// with the value in `Err(value)`. If the `Result` holds an `Ok(value)`, the
+/// assertion fails.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// #[derive(Debug)]
+/// enum MyError {
+///     NotFound(String),
+///     PermissionDenied,
+/// }
+///
+/// let subject: Result<(), MyError> = Err(MyError::NotFound("config.toml".into()));
+///
+/// assert_that!(&subject).has_error_matching(|err| matches!(err, MyError::NotFound(_)));
+///
+/// assert_that!(subject).has_error_satisfying(|err| match err {
+///     MyError::NotFound(file) if file.ends_with(".toml") => Ok(()),
+///     other => Err(format!("unexpected error {other:?}")),
+/// });
+/// ```
+pub trait AssertHasErrorMatching<E> {
+    /// Verifies that the subject holds an error value for which the given
+    /// predicate returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyError {
+    ///     NotFound(String),
+    ///     PermissionDenied,
+    /// }
+    ///
+    /// let subject: Result<(), MyError> = Err(MyError::PermissionDenied);
+    ///
+    /// assert_that!(subject).has_error_matching(|err| matches!(err, MyError::PermissionDenied));
+    /// ```
+    #[track_caller]
+    fn has_error_matching<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> bool;
+
+    /// Verifies that the subject holds an error value that satisfies the given
+    /// predicate, where the predicate explains why the error value does not
+    /// satisfy it.
+    ///
+    /// The predicate returns `Ok(())` if the error value meets the criteria.
+    /// Otherwise, it returns a reason that becomes part of the failure message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyError {
+    ///     NotFound(String),
+    ///     PermissionDenied,
+    /// }
+    ///
+    /// let subject: Result<(), MyError> = Err(MyError::NotFound("config.toml".into()));
+    ///
+    /// assert_that!(subject).has_error_satisfying(|err| match err {
+    ///     MyError::NotFound(file) if file == "config.toml" => Ok(()),
+    ///     other => Err(format!("unexpected error {other:?}")),
+    /// });
+    /// ```
+    #[track_caller]
+    fn has_error_satisfying<P, M>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> Result<(), M>,
+        M: Display;
+}
+
+/// Assert that a subject of some container type holds an error value of a
+/// specific type.
+///
+/// This is useful for opaque error types like `Box<dyn Error>`, which hide the
+/// concrete type of the error. The type of the error is determined by
+/// downcasting the error value.
+///
+/// This assertion is implemented for the `Result` type, if the error type
+/// implements the [`ErrorTypeProperty`](crate::properties::ErrorTypeProperty).
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::error::Error;
+/// use std::fmt::{self, Display};
+///
+/// #[derive(Debug)]
+/// struct MyError;
+///
+/// impl Display for MyError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "my error")
+///     }
+/// }
+///
+/// impl Error for MyError {}
+///
+/// let subject: Result<(), Box<dyn Error>> = Err(Box::new(MyError));
+///
+/// assert_that!(subject).has_error_of_type::<MyError>();
+/// ```
+pub trait AssertHasErrorOfType {
+    /// Verifies that the subject holds an error value of type `X`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::error::Error;
+    /// use std::fmt::{self, Display};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyError;
+    ///
+    /// impl Display for MyError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "my error")
+    ///     }
+    /// }
+    ///
+    /// impl Error for MyError {}
+    ///
+    /// let subject: Result<(), Box<dyn Error + Send + Sync>> = Err(Box::new(MyError));
+    ///
+    /// assert_that!(subject).has_error_of_type::<MyError>();
+    /// ```
+    #[track_caller]
+    fn has_error_of_type<X>(self) -> Self
+    where
+        X: StdError + 'static;
+}
+
+/// Assert that a subject of some container type holds an error value that has
+/// a message equal to the expected message.
+///
+/// This is useful for opaque error types that do not implement
+/// `PartialEq`. Since the `std::error::Error` trait requires that error
+/// types implement `Display`, the string representation of the error value
+/// is compared to an expected message string.
+///
+/// This assertion is implemented for the `Result` type. It compares the string
+/// representation of the error value with the expected message.
+///
+/// To assert the ok value of a `Result` use [`AssertHasValue::has_value`].
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::anyhow;
+/// use asserting::prelude::*;
+///
+/// let subject: Result<(), anyhow::Error> = Err(anyhow!("mollit in ullamcorper no".to_string()));
+/// assert_that!(subject).has_error_message("mollit in ullamcorper no");
+/// ```
+pub trait AssertHasErrorMessage<E> {
+    /// A spec-like type that contains the error message as the subject,
+    /// which is returned by the mapping assertion method.
+    ///
+    /// This is usually a `Spec<'a, String, R>`.
+    type ErrorMessage;
+
+    /// Verifies that the subject is an error value with the expected message.
+    ///
+    /// This is useful for opaque error types that do not implement
+    /// `PartialEq`. Since the `std::error::Error` trait requires that error
+    /// types implement `Display`, the string representation of the error value
+    /// is compared to an expected message string.
+    ///
+    /// This method panics if the actual subject is not an error value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anyhow::anyhow;
+    /// use asserting::prelude::*;
+    ///
+    /// let subject: Result<(), anyhow::Error> = Err(anyhow!("mollit in ullamcorper no".to_string()));
+    /// assert_that!(subject).has_error_message("mollit in ullamcorper no");
+    /// ```
+    #[track_caller]
+    fn has_error_message(self, expected_message: E) -> Self::ErrorMessage;
+}
+
+/// Assert the source of any type that implements `std::error::Error`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::error::Error;
+/// use std::fmt::{self, Display};
+///
+/// #[derive(Debug)]
+/// struct SuperError {
+///     source: SourceError,
+/// }
+///
+/// impl Display for SuperError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "super-error caused by {}", self.source)
+///     }
+/// }
+///
+/// impl Error for SuperError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.source)
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum SourceError {
+///     Foo,
+///     Bar,
+/// }
+///
+/// impl Display for SourceError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         match self {
+///             Self::Foo => f.write_str("foo error"),
+///             Self::Bar => f.write_str("bar error"),
+///         }
+///     }
+/// }
+///
+/// impl Error for SourceError {}
+///
+/// assert_that!(&SourceError::Foo).has_no_source();
+///
+/// let error = SuperError {
+///     source: SourceError::Foo,
+/// };
+///
+/// assert_that!(&error).has_source();
+/// assert_that!(&error).has_source_message("foo error");
+/// ```
+pub trait AssertErrorHasSource {
+    /// A spec-like type that contains the source message as the subject,
+    /// which is returned by the mapping assertion method.
+    ///
+    /// This is usually a `Spec<'a, String, R>`.
+    type SourceMessage;
+
+    /// Verifies that an error has no source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::error::Error;
+    /// use std::fmt::{self, Display};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum SimpleError {
+    ///     Foo,
+    ///     Bar,
+    /// }
     ///
     /// impl Display for SimpleError {
     ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2221,384 +4132,1418 @@ pub trait AssertErrorHasSource {
     ///     }
     /// }
     ///
-    /// impl Error for SimpleError {}
+    /// impl Error for SimpleError {}
+    ///
+    ///
+    /// let error = SimpleError::Bar;
+    ///
+    /// assert_that!(&error).has_no_source();
+    ///
+    /// // error in result
+    /// let result: Result<Vec<i32>, SimpleError> = Err(SimpleError::Foo);
+    ///
+    /// assert_that!(&result).err().has_no_source();
+    /// ```
+    #[track_caller]
+    fn has_no_source(self) -> Self;
+
+    /// Verifies that an error has some source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::error::Error;
+    /// use std::fmt::{self, Display};
+    ///
+    /// #[derive(Debug)]
+    /// struct SuperError {
+    ///     source: SourceError,
+    /// }
+    ///
+    /// impl Display for SuperError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "super-error caused by {}", self.source)
+    ///     }
+    /// }
+    ///
+    /// impl Error for SuperError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.source)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum SourceError {
+    ///     Foo,
+    ///     Bar,
+    /// }
+    ///
+    /// impl Display for SourceError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         match self {
+    ///             Self::Foo => f.write_str("foo error"),
+    ///             Self::Bar => f.write_str("bar error"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl Error for SourceError {}
+    ///
+    ///
+    /// let error = SuperError {
+    ///     source: SourceError::Foo,
+    /// };
+    ///
+    /// assert_that!(&error).has_source();
+    ///
+    /// // error in result
+    /// let result: Result<Vec<i32>, SuperError> = Err(SuperError {
+    ///     source: SourceError::Bar,
+    /// });
+    ///
+    /// assert_that!(result).err().has_source();
+    /// ```
+    #[track_caller]
+    fn has_source(self) -> Self;
+
+    /// Verifies that an error has some source which converted to a string
+    /// equals the expected message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::error::Error;
+    /// use std::fmt::{self, Display};
+    ///
+    /// #[derive(Debug)]
+    /// struct SuperError {
+    ///     source: SourceError,
+    /// }
+    ///
+    /// impl Display for SuperError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "super-error caused by {}", self.source)
+    ///     }
+    /// }
+    ///
+    /// impl Error for SuperError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.source)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum SourceError {
+    ///     Foo,
+    ///     Bar,
+    /// }
+    ///
+    /// impl Display for SourceError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         match self {
+    ///             Self::Foo => f.write_str("foo error"),
+    ///             Self::Bar => f.write_str("bar error"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl Error for SourceError {}
+    ///
+    ///
+    /// let error = SuperError {
+    ///     source: SourceError::Bar,
+    /// };
+    ///
+    /// assert_that!(&error).has_source_message("bar error");
+    ///
+    /// // error in result
+    /// let result: Result<Vec<i32>, SuperError> = Err(SuperError {
+    ///     source: SourceError::Foo,
+    /// });
+    ///
+    /// assert_that!(result).err().has_source_message("foo error");
+    /// ```
+    #[track_caller]
+    fn has_source_message(self, expected_source_message: impl Into<String>) -> Self::SourceMessage;
+}
+
+/// Assert the message of an error.
+///
+/// The message of an error is its string representation as provided by its
+/// `Display` implementation. These assertions are available for any subject
+/// that implements `Display`, which includes all types implementing
+/// `std::error::Error` as well as boxed errors like `Box<dyn Error>` and
+/// error types like `anyhow::Error`.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::anyhow;
+/// use asserting::prelude::*;
+/// use std::error::Error;
+///
+/// let error = anyhow!("connection refused by peer");
+///
+/// assert_that!(&error).has_message("connection refused by peer");
+/// assert_that!(&error).has_message_containing("refused");
+///
+/// let error: Box<dyn Error> = "invalid digit found in string".into();
+///
+/// assert_that!(&error).has_message("invalid digit found in string");
+/// assert_that!(&error).has_message_containing("invalid digit");
+/// ```
+pub trait AssertErrorMessage {
+    /// Verifies that the message of an error is equal to the expected message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use anyhow::anyhow;
+    /// use asserting::prelude::*;
+    ///
+    /// let error = anyhow!("connection refused by peer");
+    ///
+    /// assert_that!(error).has_message("connection refused by peer");
+    ///
+    /// // error in result
+    /// let result: Result<(), anyhow::Error> = Err(anyhow!("file not found"));
+    ///
+    /// assert_that!(result).err().has_message("file not found");
+    /// ```
+    #[track_caller]
+    fn has_message(self, expected_message: impl Into<String>) -> Self;
+
+    /// Verifies that the message of an error contains the expected substring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::error::Error;
+    ///
+    /// let error: Box<dyn Error> = "invalid digit found in string".into();
+    ///
+    /// assert_that!(error).has_message_containing("invalid digit");
+    /// ```
+    #[track_caller]
+    fn has_message_containing(self, expected_substring: impl Into<String>) -> Self;
+}
+
+/// Assert that the message of an error matches a regex pattern.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(not(feature = "regex"))]
+/// # fn main() {}
+/// # #[cfg(feature = "regex")]
+/// # fn main() {
+/// use anyhow::anyhow;
+/// use asserting::prelude::*;
+///
+/// let error = anyhow!("request timed out after 30s");
+///
+/// assert_that!(error).has_message_matching(r"timed out after \d+s");
+/// # }
+/// ```
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+pub trait AssertErrorMessageMatches {
+    /// Verifies that the message of an error matches a regex pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "regex"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "regex")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::error::Error;
+    ///
+    /// let error: Box<dyn Error> = "invalid digit found in string".into();
+    ///
+    /// assert_that!(error).has_message_matching(r"^invalid \w+ found");
+    /// # }
+    /// ```
+    ///
+    /// If the given regex pattern is invalid or exceeds the size limit, the
+    /// assertion fails stating that it could not be evaluated.
+    #[track_caller]
+    fn has_message_matching(self, regex_pattern: &str) -> Self;
+}
+
+/// Assert properties of an `anyhow::Error`.
+///
+/// These assertions are implemented for `anyhow::Error` (requires crate
+/// feature `anyhow`). Failure messages render the chain of the error with one
+/// line per context level.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "anyhow"))]
+/// # fn main() {}
+/// # #[cfg(feature = "anyhow")]
+/// # fn main() {
+/// use anyhow::{Context, anyhow};
+/// use asserting::prelude::*;
+///
+/// let result: anyhow::Result<()> = Err(anyhow!("permission denied"))
+///     .context("failed to read file `config.toml`")
+///     .context("failed to load configuration");
+///
+/// assert_that!(result)
+///     .err()
+///     .has_root_cause_message("permission denied")
+///     .has_context_containing("config.toml");
+/// # }
+/// ```
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+pub trait AssertAnyhowError {
+    /// Verifies that the root cause of an error converted to a string equals
+    /// the expected message.
+    ///
+    /// The root cause is the innermost error of the chain, the one that has no
+    /// further source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "anyhow"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "anyhow")]
+    /// # fn main() {
+    /// use anyhow::{Context, anyhow};
+    /// use asserting::prelude::*;
+    ///
+    /// let error = anyhow!("permission denied").context("failed to load configuration");
+    ///
+    /// assert_that!(error).has_root_cause_message("permission denied");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_root_cause_message(self, expected_message: impl Into<String>) -> Self;
+
+    /// Verifies that an error can be downcast to the given error type.
+    ///
+    /// An `anyhow::Error` can be downcast to the type of the error it was
+    /// created from as well as to the type of any context that was attached
+    /// to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "anyhow"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "anyhow")]
+    /// # fn main() {
+    /// use anyhow::Context;
+    /// use asserting::prelude::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// let error = anyhow::Error::from("12a".parse::<u8>().unwrap_err())
+    ///     .context("invalid port number");
+    ///
+    /// assert_that!(error).is_downcastable_to::<ParseIntError>();
+    /// # }
+    /// ```
+    #[track_caller]
+    fn is_downcastable_to<E>(self) -> Self
+    where
+        E: Display + Debug + Send + Sync + 'static;
+
+    /// Verifies that some context attached to an error contains the expected
+    /// substring.
+    ///
+    /// The context levels are all errors of the chain except the root cause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "anyhow"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "anyhow")]
+    /// # fn main() {
+    /// use anyhow::{Context, anyhow};
+    /// use asserting::prelude::*;
+    ///
+    /// let error = anyhow!("permission denied")
+    ///     .context("failed to read file `config.toml`")
+    ///     .context("failed to load configuration");
+    ///
+    /// assert_that!(error).has_context_containing("config.toml");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_context_containing(self, expected_substring: impl Into<String>) -> Self;
+}
+
+/// Assert a type formatted into a debug string.
+///
+/// The subject's type must implement `Debug` and the expected type must
+/// implement `AsRef<str>`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// #[derive(Debug)]
+/// struct Foo {
+///     hello: String,
+/// }
+///
+/// let subject = Foo { hello: "World".into() };
+///
+/// assert_that!(&subject).has_debug_string("Foo { hello: \"World\" }");
+/// assert_that!(&subject).does_not_have_debug_string("Bar { hello: \"World\" }");
+/// ```
+pub trait AssertHasDebugString<E> {
+    /// Verifies that a subject formatted for debugging results in the expected
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug)]
+    /// struct Foo {
+    ///     hello: String,
+    /// }
+    ///
+    /// let subject = Foo { hello: "World".into() };
+    ///
+    /// assert_that!(subject).has_debug_string("Foo { hello: \"World\" }");
+    /// ```
+    #[track_caller]
+    fn has_debug_string(self, expected: E) -> Self;
+
+    /// Verifies that a subject formatted for debugging does not result in the
+    /// expected string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug)]
+    /// struct Foo {
+    ///     hello: String,
+    /// }
+    ///
+    /// let subject = Foo { hello: "World".into() };
+    ///
+    /// assert_that!(subject).does_not_have_debug_string("Hello World");
+    /// ```
+    #[track_caller]
+    fn does_not_have_debug_string(self, expected: E) -> Self;
+}
+
+/// Mapping the subject into its debug string representation to do assertions on
+/// its debug string.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// #[derive(Debug)]
+/// struct Foo {
+///     hello: String,
+/// }
+///
+/// let subject = Foo { hello: "World".into() };
+///
+/// assert_that!(&subject).debug_string().contains("World");
+/// assert_that!(&subject).debug_string().does_not_start_with("Bar");
+/// ```
+pub trait AssertDebugString {
+    /// A spec-like type that contains the debug string as the subject,
+    /// which is returned by the mapping assertion method.
+    ///
+    /// This is usually a `Spec<'a, String, R>`.
+    type DebugString;
+
+    /// Maps the subject into its debug string representation to do assertions
+    /// on its debug string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug)]
+    /// struct Foo {
+    ///     hello: String,
+    /// }
+    ///
+    /// let subject = Foo { hello: "World".into() };
+    ///
+    /// assert_that!(&subject).debug_string().contains("World");
+    /// assert_that!(&subject).debug_string().does_not_start_with("Bar");
+    /// ```
+    #[track_caller]
+    fn debug_string(self) -> Self::DebugString;
+}
+
+/// Assert a type formatted into a display string.
+///
+/// The subject's type must implement `Display` and the expected type must
+/// implement `AsRef<str>`.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::{self, Display};
+/// use asserting::prelude::*;
+///
+/// struct Foo {
+///     hello: String,
+/// }
+///
+/// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "Hello {}", self.hello)
+///     }
+/// }
+///
+/// let subject = Foo { hello: "World".into() };
+///
+/// assert_that!(&subject).has_display_string("Hello World");
+/// assert_that!(&subject).does_not_have_display_string("Foo { hello: \"World\" }");
+/// ```
+pub trait AssertHasDisplayString<E> {
+    /// Verifies that a subject formatted for display results in the expected
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::{self, Display};
+    /// use asserting::prelude::*;
+    ///
+    /// struct Foo {
+    ///     hello: String,
+    /// }
+    ///
+    /// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "Hello {}", self.hello)
+    ///     }
+    /// }
+    ///
+    /// let subject = Foo { hello: "World".into() };
+    ///
+    /// assert_that!(&subject).has_display_string("Hello World");
+    /// ```
+    #[track_caller]
+    fn has_display_string(self, expected: E) -> Self;
+
+    /// Verifies that a subject formatted for display does not result in the
+    /// expected string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::{self, Display};
+    /// use asserting::prelude::*;
+    ///
+    /// struct Foo {
+    ///     hello: String,
+    /// }
+    ///
+    /// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "Hello {}", self.hello)
+    ///     }
+    /// }
+    ///
+    /// let subject = Foo { hello: "World".into() };
+    ///
+    /// assert_that!(&subject).does_not_have_display_string("Foo { hello: \"World\" }");
+    /// ```
+    #[track_caller]
+    fn does_not_have_display_string(self, expected: E) -> Self;
+}
+
+/// Mapping the subject into its display string representation to do assertions
+/// on its display string.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::{self, Display};
+/// use asserting::prelude::*;
+///
+/// struct Foo {
+///     hello: String,
+/// }
+///
+/// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "Hello, {}", self.hello)
+///     }
+/// }
+///
+/// let subject = Foo { hello: "World".into() };
+///
+/// assert_that!(&subject).display_string().is_equal_to("Hello, World");
+/// assert_that!(&subject).display_string().does_not_end_with('!');
+/// ```
+pub trait AssertDisplayString {
+    /// A spec-like type that contains the display string as the subject,
+    /// which is returned by the mapping assertion method.
+    ///
+    /// This is usually a `Spec<'a, String, R>`.
+    type DisplayString;
+
+    /// Maps the subject into its display string representation to do assertions
+    /// on its display string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::{self, Display};
+    /// use asserting::prelude::*;
+    ///
+    /// struct Foo {
+    ///     hello: String,
+    /// }
+    ///
+    /// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "Hello, {}", self.hello)
+    ///     }
+    /// }
+    ///
+    /// let subject = Foo { hello: "World".into() };
     ///
+    /// assert_that!(&subject).display_string().is_equal_to("Hello, World");
+    /// assert_that!(&subject).display_string().does_not_end_with('!');
+    /// ```
+    #[track_caller]
+    fn display_string(self) -> Self::DisplayString;
+}
+
+/// Assert that a subject of bytes, like `Vec<u8>` or `&[u8]`, contains the
+/// expected text encoded as UTF-8.
+///
+/// A failing assertion shows the decoded text of the subject with highlighted
+/// differences instead of a list of bytes.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let body: Vec<u8> = b"{\"status\":\"ok\"}".to_vec();
+///
+/// assert_that!(&body).has_utf8_content(r#"{"status":"ok"}"#);
+/// assert_that!(&body).does_not_have_utf8_content(r#"{"status":"error"}"#);
+/// ```
+pub trait AssertUtf8Content<E> {
+    /// Verifies that the subject contains the expected text encoded as UTF-8.
     ///
-    /// let error = SimpleError::Bar;
+    /// # Examples
     ///
-    /// assert_that!(&error).has_no_source();
+    /// ```
+    /// use asserting::prelude::*;
     ///
-    /// // error in result
-    /// let result: Result<Vec<i32>, SimpleError> = Err(SimpleError::Foo);
+    /// let body: &[u8] = b"Hello, World!";
     ///
-    /// assert_that!(&result).err().has_no_source();
+    /// assert_that!(body).has_utf8_content("Hello, World!");
     /// ```
     #[track_caller]
-    fn has_no_source(self) -> Self;
+    fn has_utf8_content(self, expected: E) -> Self;
 
-    /// Verifies that an error has some source.
+    /// Verifies that the subject does not contain the expected text encoded
+    /// as UTF-8.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
-    /// use std::error::Error;
-    /// use std::fmt::{self, Display};
     ///
-    /// #[derive(Debug)]
-    /// struct SuperError {
-    ///     source: SourceError,
-    /// }
+    /// let body: &[u8] = b"Hello, World!";
     ///
-    /// impl Display for SuperError {
-    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    ///         write!(f, "super-error caused by {}", self.source)
-    ///     }
-    /// }
+    /// assert_that!(body).does_not_have_utf8_content("Hello, Rust!");
+    /// ```
+    #[track_caller]
+    fn does_not_have_utf8_content(self, expected: E) -> Self;
+}
+
+/// Mapping a subject of bytes, like `Vec<u8>` or `&[u8]`, into the text it
+/// contains encoded as UTF-8 to do assertions on the text.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let body: Vec<u8> = b"{\"status\":\"ok\"}".to_vec();
+///
+/// assert_that!(body).as_utf8_text().starts_with('{').contains("\"ok\"");
+/// ```
+pub trait AssertUtf8Text {
+    /// A spec-like type that contains the decoded text as the subject, which
+    /// is returned by the mapping assertion method.
     ///
-    /// impl Error for SuperError {
-    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
-    ///         Some(&self.source)
-    ///     }
-    /// }
+    /// This is usually a `Spec<'a, String, R>`.
+    type Utf8Text;
+
+    /// Maps the subject into the text it contains encoded as UTF-8.
     ///
-    /// #[derive(Debug, PartialEq)]
-    /// enum SourceError {
-    ///     Foo,
-    ///     Bar,
-    /// }
+    /// Invalid UTF-8 sequences are replaced with the replacement character
+    /// `U+FFFD`.
     ///
-    /// impl Display for SourceError {
-    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    ///         match self {
-    ///             Self::Foo => f.write_str("foo error"),
-    ///             Self::Bar => f.write_str("bar error"),
-    ///         }
-    ///     }
-    /// }
+    /// # Examples
     ///
-    /// impl Error for SourceError {}
+    /// ```
+    /// use asserting::prelude::*;
     ///
+    /// let body: Vec<u8> = b"Hello, World!".to_vec();
     ///
-    /// let error = SuperError {
-    ///     source: SourceError::Foo,
-    /// };
+    /// assert_that!(body).as_utf8_text().is_equal_to("Hello, World!");
     ///
-    /// assert_that!(&error).has_source();
+    /// let body: &[u8] = b"Hello, \xF0\x90\x80World!";
     ///
-    /// // error in result
-    /// let result: Result<Vec<i32>, SuperError> = Err(SuperError {
-    ///     source: SourceError::Bar,
-    /// });
+    /// assert_that!(body).as_utf8_text().is_equal_to("Hello, \u{FFFD}World!");
+    /// ```
+    #[track_caller]
+    fn as_utf8_text(self) -> Self::Utf8Text;
+}
+
+/// Assert the content of byte sequences like `Vec<u8>` and `&[u8]` as binary
+/// data.
+///
+/// The failure messages of these assertions render the actual and the
+/// expected bytes as a side-by-side hexdump with the differing bytes
+/// highlighted.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let data: Vec<u8> = vec![0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00];
+///
+/// assert_that!(&data).is_equal_to_bytes([0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00]);
+/// assert_that!(&data).starts_with_bytes(b"\x89PNG");
+/// assert_that!(&data).contains_subsequence([0x1a, 0x0a]);
+/// ```
+pub trait AssertBytes<E> {
+    /// Verifies that the subject is equal to the expected bytes.
     ///
-    /// assert_that!(result).err().has_source();
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let data: &[u8] = &[0xca, 0xfe, 0xba, 0xbe];
+    ///
+    /// assert_that!(data).is_equal_to_bytes([0xca, 0xfe, 0xba, 0xbe]);
     /// ```
     #[track_caller]
-    fn has_source(self) -> Self;
+    fn is_equal_to_bytes(self, expected: E) -> Self;
 
-    /// Verifies that an error has some source which converted to a string
-    /// equals the expected message.
+    /// Verifies that the subject starts with the expected bytes.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
-    /// use std::error::Error;
-    /// use std::fmt::{self, Display};
     ///
-    /// #[derive(Debug)]
-    /// struct SuperError {
-    ///     source: SourceError,
-    /// }
+    /// let data: &[u8] = &[0xca, 0xfe, 0xba, 0xbe];
+    ///
+    /// assert_that!(data).starts_with_bytes([0xca, 0xfe]);
+    /// ```
+    #[track_caller]
+    fn starts_with_bytes(self, expected: E) -> Self;
+
+    /// Verifies that the subject contains the expected bytes as a contiguous
+    /// subsequence.
+    ///
+    /// The failure message renders an excerpt of the subject around the
+    /// closest match of the expected bytes and the expected bytes below it,
+    /// with the differing bytes highlighted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let data: &[u8] = &[0xca, 0xfe, 0xba, 0xbe];
+    ///
+    /// assert_that!(data).contains_subsequence([0xfe, 0xba]);
+    /// ```
+    #[track_caller]
+    fn contains_subsequence(self, expected: E) -> Self;
+}
+
+/// Assert the exit status of a finished process.
+///
+/// These assertions are implemented for `std::process::ExitStatus` and
+/// `std::process::Output`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::process::Command;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let output = Command::new("rustc").arg("--version").output()?;
+///
+/// assert_that!(&output).succeeded().has_exit_code(0);
+/// assert_that!(output.status).succeeded();
+///
+/// let output = Command::new("rustc").arg("--no-such-flag").output()?;
+///
+/// assert_that!(output).failed();
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait AssertExitStatus {
+    /// Verifies that the process exited with the expected exit code.
+    ///
+    /// A process that has been terminated by a signal has no exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::process::Command;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let output = Command::new("rustc").arg("--version").output()?;
+    ///
+    /// assert_that!(output).has_exit_code(0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_exit_code(self, expected: i32) -> Self;
+
+    /// Verifies that the process did not exit with the given exit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::process::Command;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let output = Command::new("rustc").arg("--version").output()?;
+    ///
+    /// assert_that!(output).does_not_have_exit_code(1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    fn does_not_have_exit_code(self, expected: i32) -> Self;
+
+    /// Verifies that the process exited successfully.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::process::Command;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let output = Command::new("rustc").arg("--version").output()?;
+    ///
+    /// assert_that!(output).succeeded();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    fn succeeded(self) -> Self;
+
+    /// Verifies that the process did not exit successfully.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::process::Command;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let output = Command::new("rustc").arg("--no-such-flag").output()?;
+    ///
+    /// assert_that!(output).failed();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    fn failed(self) -> Self;
+}
+
+/// Assert the text a process has written to stdout and stderr.
+///
+/// The captured bytes of stdout and stderr are decoded as UTF-8. Invalid UTF-8
+/// sequences are replaced with the replacement character `U+FFFD`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::process::Command;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let output = Command::new("rustc").arg("--version").output()?;
+///
+/// assert_that!(&output)
+///     .succeeded()
+///     .stdout_contains("rustc ")
+///     .stderr_does_not_contain("error");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait AssertProcessOutput<'a> {
+    /// Verifies that stdout contains the expected text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::process::Command;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let output = Command::new("rustc").arg("--version").output()?;
+    ///
+    /// assert_that!(output).stdout_contains("rustc ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    fn stdout_contains(self, expected: &'a str) -> Self;
+
+    /// Verifies that stdout does not contain the given text.
+    #[track_caller]
+    fn stdout_does_not_contain(self, expected: &'a str) -> Self;
+
+    /// Verifies that stderr contains the expected text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::process::Command;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let output = Command::new("rustc").arg("--no-such-flag").output()?;
+    ///
+    /// assert_that!(output).stderr_contains("no-such-flag");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[track_caller]
+    fn stderr_contains(self, expected: &'a str) -> Self;
+
+    /// Verifies that stderr does not contain the given text.
+    #[track_caller]
+    fn stderr_does_not_contain(self, expected: &'a str) -> Self;
+}
+
+/// Assert that the text a process has written to stdout or stderr matches a
+/// regex pattern.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "regex"))]
+/// # fn main() {}
+/// # #[cfg(feature = "regex")]
+/// # fn main() -> std::io::Result<()> {
+/// use asserting::prelude::*;
+/// use std::process::Command;
+///
+/// let output = Command::new("rustc").arg("--version").output()?;
+///
+/// assert_that!(&output).stdout_matches(r"^rustc \d+\.\d+\.\d+");
+/// assert_that!(&output).stderr_matches(r"^$");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(all(feature = "std", feature = "regex"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "regex"))))]
+pub trait AssertProcessOutputMatches {
+    /// Verifies that stdout matches a regex pattern.
+    ///
+    /// If the given regex pattern is invalid or exceeds the size limit, the
+    /// assertion fails stating that it could not be evaluated.
+    #[track_caller]
+    fn stdout_matches(self, regex_pattern: &str) -> Self;
+
+    /// Verifies that stderr matches a regex pattern.
+    ///
+    /// If the given regex pattern is invalid or exceeds the size limit, the
+    /// assertion fails stating that it could not be evaluated.
+    #[track_caller]
+    fn stderr_matches(self, regex_pattern: &str) -> Self;
+}
+
+/// Assert whether an environment variable is set and what its value is.
+///
+/// These assertions are implemented for [`EnvVar`], which is the subject of
+/// assertions started with [`assert_that_env!`](crate::assert_that_env) or
+/// [`verify_that_env!`](crate::verify_that_env). The environment variable is
+/// read once when the assertion is started.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// assert_that_env!("PATH").is_set().has_value_containing("bin");
+///
+/// assert_that_env!("ASSERTING_SURELY_UNDEFINED_VARIABLE").is_not_set();
+/// ```
+///
+/// [`EnvVar`]: crate::spec::EnvVar
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait AssertEnvVar {
+    /// Verifies that the environment variable is set.
     ///
-    /// impl Display for SuperError {
-    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    ///         write!(f, "super-error caused by {}", self.source)
-    ///     }
-    /// }
+    /// A variable whose value is not valid unicode is considered to be set.
     ///
-    /// impl Error for SuperError {
-    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
-    ///         Some(&self.source)
-    ///     }
-    /// }
+    /// # Examples
     ///
-    /// #[derive(Debug, PartialEq)]
-    /// enum SourceError {
-    ///     Foo,
-    ///     Bar,
-    /// }
+    /// ```
+    /// use asserting::prelude::*;
     ///
-    /// impl Display for SourceError {
-    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    ///         match self {
-    ///             Self::Foo => f.write_str("foo error"),
-    ///             Self::Bar => f.write_str("bar error"),
-    ///         }
-    ///     }
-    /// }
+    /// assert_that_env!("PATH").is_set();
+    /// ```
+    #[track_caller]
+    fn is_set(self) -> Self;
+
+    /// Verifies that the environment variable is not set.
     ///
-    /// impl Error for SourceError {}
+    /// # Examples
     ///
+    /// ```
+    /// use asserting::prelude::*;
     ///
-    /// let error = SuperError {
-    ///     source: SourceError::Bar,
-    /// };
+    /// assert_that_env!("ASSERTING_SURELY_UNDEFINED_VARIABLE").is_not_set();
+    /// ```
+    #[track_caller]
+    fn is_not_set(self) -> Self;
+
+    /// Verifies that the environment variable is set and its value contains
+    /// the given text.
     ///
-    /// assert_that!(&error).has_source_message("bar error");
+    /// # Examples
     ///
-    /// // error in result
-    /// let result: Result<Vec<i32>, SuperError> = Err(SuperError {
-    ///     source: SourceError::Foo,
-    /// });
+    /// ```
+    /// use asserting::prelude::*;
     ///
-    /// assert_that!(result).err().has_source_message("foo error");
+    /// assert_that_env!("PATH").has_value_containing("bin");
     /// ```
     #[track_caller]
-    fn has_source_message(self, expected_source_message: impl Into<String>) -> Self::SourceMessage;
+    fn has_value_containing(self, expected: &str) -> Self;
 }
 
-/// Assert a type formatted into a debug string.
+/// Assert properties of a UUID.
 ///
-/// The subject's type must implement `Debug` and the expected type must
-/// implement `AsRef<str>`.
+/// These assertions are implemented for `uuid::Uuid` (requires crate feature
+/// `uuid`).
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(not(feature = "uuid"))]
+/// # fn main() {}
+/// # #[cfg(feature = "uuid")]
+/// # fn main() {
 /// use asserting::prelude::*;
+/// use uuid::{Uuid, Version};
 ///
-/// #[derive(Debug)]
-/// struct Foo {
-///     hello: String,
-/// }
+/// let subject = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
 ///
-/// let subject = Foo { hello: "World".into() };
+/// assert_that!(subject)
+///     .is_not_nil()
+///     .has_version(Version::Random)
+///     .is_equal_to_uuid_string("67e55044-10b1-426f-9247-bb680e5fe0c8");
 ///
-/// assert_that!(&subject).has_debug_string("Foo { hello: \"World\" }");
-/// assert_that!(&subject).does_not_have_debug_string("Bar { hello: \"World\" }");
+/// assert_that!(Uuid::nil()).is_nil();
+/// # }
 /// ```
-pub trait AssertHasDebugString<E> {
-    /// Verifies that a subject formatted for debugging results in the expected
-    /// string.
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+pub trait AssertUuid {
+    /// Verifies that the subject is the nil UUID (all bits set to zero).
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "uuid"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "uuid")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use uuid::Uuid;
     ///
-    /// #[derive(Debug)]
-    /// struct Foo {
-    ///     hello: String,
-    /// }
+    /// assert_that!(Uuid::nil()).is_nil();
+    /// # }
+    /// ```
+    #[track_caller]
+    fn is_nil(self) -> Self;
+
+    /// Verifies that the subject is not the nil UUID.
     ///
-    /// let subject = Foo { hello: "World".into() };
+    /// # Examples
     ///
-    /// assert_that!(subject).has_debug_string("Foo { hello: \"World\" }");
+    /// ```
+    /// # #[cfg(not(feature = "uuid"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "uuid")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use uuid::Uuid;
+    ///
+    /// let subject = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    ///
+    /// assert_that!(subject).is_not_nil();
+    /// # }
     /// ```
     #[track_caller]
-    fn has_debug_string(self, expected: E) -> Self;
+    fn is_not_nil(self) -> Self;
 
-    /// Verifies that a subject formatted for debugging does not result in the
-    /// expected string.
+    /// Verifies that the subject is a UUID of the expected version.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "uuid"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "uuid")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use uuid::{Uuid, Version};
     ///
-    /// #[derive(Debug)]
-    /// struct Foo {
-    ///     hello: String,
-    /// }
+    /// let subject = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
     ///
-    /// let subject = Foo { hello: "World".into() };
+    /// assert_that!(subject).has_version(Version::SortRand);
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_version(self, expected: uuid::Version) -> Self;
+
+    /// Verifies that the subject is equal to the UUID represented by the
+    /// expected string.
     ///
-    /// assert_that!(subject).does_not_have_debug_string("Hello World");
+    /// The expected string can be in any format that is accepted by
+    /// `uuid::Uuid::parse_str`, e.g., hyphenated, simple, braced or as URN.
+    /// If the expected string is not a valid UUID, the assertion can not be
+    /// evaluated and fails, whether it is negated or not.
+    ///
+    /// On failure, the segments of the hyphenated form that differ between
+    /// the actual and the expected UUID are highlighted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "uuid"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "uuid")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use uuid::Uuid;
+    ///
+    /// let subject = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    ///
+    /// assert_that!(subject).is_equal_to_uuid_string("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// assert_that!(subject).is_equal_to_uuid_string("67E5504410B1426F9247BB680E5FE0C8");
+    /// # }
     /// ```
     #[track_caller]
-    fn does_not_have_debug_string(self, expected: E) -> Self;
+    fn is_equal_to_uuid_string(self, expected: impl AsRef<str>) -> Self;
 }
 
-/// Mapping the subject into its debug string representation to do assertions on
-/// its debug string.
+/// Assert the components of a URL.
+///
+/// These assertions are implemented for `url::Url` (requires crate feature
+/// `url`).
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(not(feature = "url"))]
+/// # fn main() {}
+/// # #[cfg(feature = "url")]
+/// # fn main() {
 /// use asserting::prelude::*;
+/// use url::Url;
 ///
-/// #[derive(Debug)]
-/// struct Foo {
-///     hello: String,
-/// }
+/// let subject = Url::parse("https://example.com/api/v1?page=2&size=20").unwrap();
 ///
-/// let subject = Foo { hello: "World".into() };
-///
-/// assert_that!(&subject).debug_string().contains("World");
-/// assert_that!(&subject).debug_string().does_not_start_with("Bar");
+/// assert_that!(&subject)
+///     .has_scheme("https")
+///     .has_host("example.com")
+///     .has_path("/api/v1")
+///     .has_query_parameter("page", "2")
+///     .is_equal_to_url_ignoring_query_order("https://example.com/api/v1?size=20&page=2");
+/// # }
 /// ```
-pub trait AssertDebugString {
-    /// A spec-like type that contains the debug string as the subject,
-    /// which is returned by the mapping assertion method.
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub trait AssertUrl {
+    /// Verifies that the URL has the expected scheme.
     ///
-    /// This is usually a `Spec<'a, String, R>`.
-    type DebugString;
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "url"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "url")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use url::Url;
+    ///
+    /// let subject = Url::parse("https://example.com/api/v1").unwrap();
+    ///
+    /// assert_that!(subject).has_scheme("https");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_scheme(self, expected: impl AsRef<str>) -> Self;
 
-    /// Maps the subject into its debug string representation to do assertions
-    /// on its debug string.
+    /// Verifies that the URL has the expected host.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(not(feature = "url"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "url")]
+    /// # fn main() {
     /// use asserting::prelude::*;
+    /// use url::Url;
     ///
-    /// #[derive(Debug)]
-    /// struct Foo {
-    ///     hello: String,
-    /// }
+    /// let subject = Url::parse("https://example.com:8443/api/v1").unwrap();
     ///
-    /// let subject = Foo { hello: "World".into() };
+    /// assert_that!(subject).has_host("example.com");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_host(self, expected: impl AsRef<str>) -> Self;
+
+    /// Verifies that the URL has the expected path.
     ///
-    /// assert_that!(&subject).debug_string().contains("World");
-    /// assert_that!(&subject).debug_string().does_not_start_with("Bar");
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "url"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "url")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use url::Url;
+    ///
+    /// let subject = Url::parse("https://example.com/api/v1?page=2").unwrap();
+    ///
+    /// assert_that!(subject).has_path("/api/v1");
+    /// # }
     /// ```
     #[track_caller]
-    fn debug_string(self) -> Self::DebugString;
+    fn has_path(self, expected: impl AsRef<str>) -> Self;
+
+    /// Verifies that the URL has a query parameter with the given name and
+    /// value.
+    ///
+    /// Name and value are compared in their percent-decoded form. If the
+    /// query contains several parameters with the given name, at least one of
+    /// them must have the expected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "url"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "url")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use url::Url;
+    ///
+    /// let subject = Url::parse("https://example.com/search?q=hello%20world&page=2").unwrap();
+    ///
+    /// assert_that!(&subject).has_query_parameter("page", "2");
+    /// assert_that!(&subject).has_query_parameter("q", "hello world");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_query_parameter(self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self;
+
+    /// Verifies that the URL is equal to the expected URL regardless of the
+    /// order of the query parameters.
+    ///
+    /// The expected URL can be given as `url::Url` or as string. The assertion
+    /// fails if the expected string is not a valid URL.
+    ///
+    /// On failure, the query parameters of both URLs are shown sorted, so
+    /// that only the real differences are highlighted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "url"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "url")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use url::Url;
+    ///
+    /// let subject = Url::parse("https://example.com/api/v1?page=2&size=20").unwrap();
+    ///
+    /// assert_that!(&subject)
+    ///     .is_equal_to_url_ignoring_query_order("https://example.com/api/v1?size=20&page=2");
+    /// assert_that!(&subject).is_equal_to_url_ignoring_query_order(
+    ///     Url::parse("https://example.com/api/v1?size=20&page=2").unwrap(),
+    /// );
+    /// # }
+    /// ```
+    #[track_caller]
+    fn is_equal_to_url_ignoring_query_order(self, expected: impl AsRef<str>) -> Self;
 }
 
-/// Assert a type formatted into a display string.
+/// Assert properties of an IP address.
 ///
-/// The subject's type must implement `Display` and the expected type must
-/// implement `AsRef<str>`.
+/// These assertions are implemented for `IpAddr`, `Ipv4Addr` and `Ipv6Addr`
+/// as well as for the socket addresses `SocketAddr`, `SocketAddrV4` and
+/// `SocketAddrV6`, for which the IP address of the socket is asserted.
 ///
 /// # Examples
 ///
 /// ```
-/// use core::fmt::{self, Display};
 /// use asserting::prelude::*;
+/// use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 ///
-/// struct Foo {
-///     hello: String,
-/// }
-///
-/// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-///         write!(f, "Hello {}", self.hello)
-///     }
-/// }
+/// let subject = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+/// assert_that!(subject).is_ipv4().is_private().has_ip("10.0.0.1");
 ///
-/// let subject = Foo { hello: "World".into() };
+/// let subject = Ipv6Addr::LOCALHOST;
+/// assert_that!(subject).is_ipv6().is_loopback();
 ///
-/// assert_that!(&subject).has_display_string("Hello World");
-/// assert_that!(&subject).does_not_have_display_string("Foo { hello: \"World\" }");
+/// let subject: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+/// assert_that!(subject).is_loopback().has_ip("127.0.0.1").has_port(8080);
 /// ```
-pub trait AssertHasDisplayString<E> {
-    /// Verifies that a subject formatted for display results in the expected
-    /// string.
+pub trait AssertIpAddr {
+    /// Verifies that the subject is an IPv4 address.
     ///
     /// # Examples
     ///
     /// ```
-    /// use core::fmt::{self, Display};
     /// use asserting::prelude::*;
+    /// use std::net::{IpAddr, Ipv4Addr};
     ///
-    /// struct Foo {
-    ///     hello: String,
-    /// }
+    /// let subject = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20));
+    /// assert_that!(subject).is_ipv4();
+    /// ```
+    #[track_caller]
+    fn is_ipv4(self) -> Self;
+
+    /// Verifies that the subject is an IPv6 address.
     ///
-    /// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    ///         write!(f, "Hello {}", self.hello)
-    ///     }
-    /// }
+    /// # Examples
     ///
-    /// let subject = Foo { hello: "World".into() };
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::net::{IpAddr, Ipv6Addr};
     ///
-    /// assert_that!(&subject).has_display_string("Hello World");
+    /// let subject = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    /// assert_that!(subject).is_ipv6();
     /// ```
     #[track_caller]
-    fn has_display_string(self, expected: E) -> Self;
+    fn is_ipv6(self) -> Self;
 
-    /// Verifies that a subject formatted for display does not result in the
-    /// expected string.
+    /// Verifies that the subject is a loopback address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::net::{Ipv4Addr, Ipv6Addr};
+    ///
+    /// assert_that!(Ipv4Addr::new(127, 0, 0, 1)).is_loopback();
+    /// assert_that!(Ipv6Addr::LOCALHOST).is_loopback();
+    /// ```
+    #[track_caller]
+    fn is_loopback(self) -> Self;
+
+    /// Verifies that the subject is an address of a private network.
+    ///
+    /// IPv4 addresses are private if they are in one of the ranges
+    /// `10.0.0.0/8`, `172.16.0.0/12` or `192.168.0.0/16`. IPv6 addresses are
+    /// considered private if they are unique local addresses (`fc00::/7`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::net::{Ipv4Addr, Ipv6Addr};
+    ///
+    /// assert_that!(Ipv4Addr::new(172, 16, 8, 1)).is_private();
+    /// assert_that!(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1)).is_private();
+    /// ```
+    #[track_caller]
+    fn is_private(self) -> Self;
+
+    /// Verifies that the subject has the expected IP address.
+    ///
+    /// The expected IP address is given as string. The assertion fails if the
+    /// expected string is not a valid IP address, even if it is negated.
     ///
     /// # Examples
     ///
     /// ```
-    /// use core::fmt::{self, Display};
     /// use asserting::prelude::*;
+    /// use std::net::{Ipv6Addr, SocketAddr};
     ///
-    /// struct Foo {
-    ///     hello: String,
-    /// }
-    ///
-    /// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    ///         write!(f, "Hello {}", self.hello)
-    ///     }
-    /// }
-    ///
-    /// let subject = Foo { hello: "World".into() };
+    /// assert_that!(Ipv6Addr::LOCALHOST).has_ip("::1");
     ///
-    /// assert_that!(&subject).does_not_have_display_string("Foo { hello: \"World\" }");
+    /// let subject: SocketAddr = "10.0.0.1:443".parse().unwrap();
+    /// assert_that!(subject).has_ip("10.0.0.1");
     /// ```
     #[track_caller]
-    fn does_not_have_display_string(self, expected: E) -> Self;
+    fn has_ip(self, expected: impl AsRef<str>) -> Self;
 }
 
-/// Mapping the subject into its display string representation to do assertions
-/// on its display string.
+/// Assert properties of a socket address.
+///
+/// These assertions are implemented for `SocketAddr`, `SocketAddrV4` and
+/// `SocketAddrV6`.
 ///
 /// # Examples
 ///
 /// ```
-/// use core::fmt::{self, Display};
 /// use asserting::prelude::*;
+/// use std::net::SocketAddr;
 ///
-/// struct Foo {
-///     hello: String,
-/// }
-///
-/// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-///         write!(f, "Hello, {}", self.hello)
-///     }
-/// }
-///
-/// let subject = Foo { hello: "World".into() };
+/// let subject: SocketAddr = "[::1]:8080".parse().unwrap();
 ///
-/// assert_that!(&subject).display_string().is_equal_to("Hello, World");
-/// assert_that!(&subject).display_string().does_not_end_with('!');
+/// assert_that!(subject).has_port(8080);
 /// ```
-pub trait AssertDisplayString {
-    /// A spec-like type that contains the display string as the subject,
-    /// which is returned by the mapping assertion method.
-    ///
-    /// This is usually a `Spec<'a, String, R>`.
-    type DisplayString;
-
-    /// Maps the subject into its display string representation to do assertions
-    /// on its display string.
+pub trait AssertSocketAddr {
+    /// Verifies that the socket address has the expected port number.
     ///
     /// # Examples
     ///
     /// ```
-    /// use core::fmt::{self, Display};
     /// use asserting::prelude::*;
+    /// use std::net::{Ipv4Addr, SocketAddrV4};
     ///
-    /// struct Foo {
-    ///     hello: String,
-    /// }
-    ///
-    /// impl Display for Foo {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    ///         write!(f, "Hello, {}", self.hello)
-    ///     }
-    /// }
-    ///
-    /// let subject = Foo { hello: "World".into() };
+    /// let subject = SocketAddrV4::new(Ipv4Addr::LOCALHOST, 5432);
     ///
-    /// assert_that!(&subject).display_string().is_equal_to("Hello, World");
-    /// assert_that!(&subject).display_string().does_not_end_with('!');
+    /// assert_that!(subject).has_port(5432);
     /// ```
     #[track_caller]
-    fn display_string(self) -> Self::DisplayString;
+    fn has_port(self, expected: u16) -> Self;
 }
 
 /// Assert that a string contains a substring or character.
@@ -2778,56 +5723,362 @@ pub trait AssertStringContainsAnyOf<E> {
 /// # fn main() {
 /// use asserting::prelude::*;
 ///
-/// assert_that("tation odio placerat in").matches(r"\b\w{8}\b");
-/// assert_that("tation odio placerat in").does_not_match(r"^[A-Z0-9 ]+$");
-/// # }
+/// assert_that("tation odio placerat in").matches(r"\b\w{8}\b");
+/// assert_that("tation odio placerat in").does_not_match(r"^[A-Z0-9 ]+$");
+/// # }
+/// ```
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+pub trait AssertStringMatches {
+    /// Verifies that a string matches a regex pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "regex"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "regex")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that("tation odio placerat in").matches(r"\b\w{8}\b");
+    /// # }
+    /// ```
+    ///
+    /// If the given regex pattern is invalid or exceeds the size limit, the
+    /// assertion fails stating that it could not be evaluated.
+    #[track_caller]
+    fn matches(self, regex_pattern: &str) -> Self;
+
+    /// Verifies that a string does not match a regex pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "regex"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "regex")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that("tation odio placerat in").does_not_match(r"^[A-Z0-9 ]+$");
+    /// # }
+    /// ```
+    ///
+    /// If the given regex pattern is invalid or exceeds the size limit, the
+    /// assertion fails stating that it could not be evaluated.
+    #[track_caller]
+    fn does_not_match(self, regex_pattern: &str) -> Self;
+}
+
+/// Assert that a string matches a glob-style wildcard pattern.
+///
+/// The pattern supports two wildcards: `*` matches any sequence of
+/// characters including the empty one, and `?` matches exactly one character.
+/// All other characters of the pattern must match literally. The pattern must
+/// match the whole string.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let filename = "report_2024-03.csv";
+///
+/// assert_that!(filename).matches_wildcard("report_*.csv");
+/// assert_that!(filename).matches_wildcard("report_????-??.*");
+/// assert_that!(filename).does_not_match_wildcard("*.txt");
+/// ```
+pub trait AssertStringWildcard {
+    /// Verifies that a string matches a glob-style wildcard pattern.
+    ///
+    /// `*` matches any sequence of characters, `?` matches exactly one
+    /// character. The failure message lists the literal segments of the
+    /// pattern that could not be matched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!("report_2024-03.csv").matches_wildcard("report_*.csv");
+    /// ```
+    #[track_caller]
+    fn matches_wildcard(self, pattern: &str) -> Self;
+
+    /// Verifies that a string does not match a glob-style wildcard pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!("report_2024-03.csv").does_not_match_wildcard("summary_*.csv");
+    /// ```
+    #[track_caller]
+    fn does_not_match_wildcard(self, pattern: &str) -> Self;
+}
+
+/// Assert that a string is similar to an expected string.
+///
+/// The similarity of two strings is measured by their Levenshtein edit
+/// distance, which is the minimum number of single-character insertions,
+/// deletions, and substitutions needed to change one string into the other.
+/// The distance is calculated on chars, not bytes.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject = "The quick brown fox jumps over the lazy dog";
+///
+/// assert_that!(subject).is_similar_to("The quick brown fox jumped over the lazy dog", 2);
+/// assert_that!(subject).is_similar_to(String::from("the quick brown fox jumps over the lazy dog"), 1);
+/// ```
+pub trait AssertStringSimilarity<E> {
+    /// Verifies that the edit distance between the actual string and the
+    /// expected string is at most `max_distance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "Hello, wrold!";
+    ///
+    /// assert_that!(subject).is_similar_to("Hello, world!", 2);
+    /// assert_that!(subject).is_similar_to("Hello, wrold!", 0);
+    /// ```
+    #[track_caller]
+    fn is_similar_to(self, expected: E, max_distance: usize) -> Self;
+}
+
+/// Assert how often a substring occurs in a string.
+///
+/// Occurrences are counted without overlapping, like the [`str::matches`]
+/// method does. Failure messages highlight each occurrence found in the
+/// actual string.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject = "sed diam sed voluptua diam sed";
+///
+/// assert_that!(subject).contains_exactly_once("voluptua");
+/// assert_that!(subject).contains_n_times("sed", 3);
+/// assert_that!(subject).does_not_contain_more_than("diam", 2);
+/// ```
+///
+/// Occurrences are counted without overlapping. As the number of occurrences
+/// of an empty substring is not meaningful, the assertions fail with an error
+/// if the given substring is empty.
+pub trait AssertStringOccurrences<E> {
+    /// Verifies that a string contains the given substring exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "takimata lorem dolores";
+    ///
+    /// assert_that!(subject).contains_exactly_once("lorem");
+    /// assert_that!(subject).contains_exactly_once(String::from("ta "));
+    /// ```
+    #[track_caller]
+    fn contains_exactly_once(self, needle: E) -> Self;
+
+    /// Verifies that a string contains the given substring exactly `times`
+    /// times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "takimata lorem dolores";
+    ///
+    /// assert_that!(subject).contains_n_times("ta", 2);
+    /// assert_that!(subject).contains_n_times("o", 3);
+    /// assert_that!(subject).contains_n_times("ipsum", 0);
+    /// ```
+    #[track_caller]
+    fn contains_n_times(self, needle: E, times: usize) -> Self;
+
+    /// Verifies that a string contains the given substring at most
+    /// `max_times` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "takimata lorem dolores";
+    ///
+    /// assert_that!(subject).does_not_contain_more_than("lorem", 1);
+    /// assert_that!(subject).does_not_contain_more_than("o", 3);
+    /// assert_that!(subject).does_not_contain_more_than("ipsum", 1);
+    /// ```
+    #[track_caller]
+    fn does_not_contain_more_than(self, needle: E, max_times: usize) -> Self;
+}
+
+/// Assert the extended grapheme clusters of a string.
+///
+/// An extended grapheme cluster is what a user perceives as a single
+/// character, like an emoji with a skin tone modifier or a letter followed by
+/// combining diacritical marks. It may consist of several `char`s.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(not(feature = "unicode"))]
+/// # fn main() {}
+/// # #[cfg(feature = "unicode")]
+/// # fn main() {
+/// use asserting::prelude::*;
+///
+/// let subject = "thumbs up: \u{1f44d}\u{1f3fd}";
+///
+/// assert_that!(subject).has_char_count(13);
+/// assert_that!(subject).has_grapheme_count(12);
+/// assert_that!(subject).contains_grapheme("\u{1f44d}\u{1f3fd}");
+/// assert_that!(subject).does_not_contain_grapheme("\u{1f44d}");
+/// # }
+/// ```
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+pub trait AssertGraphemes<E> {
+    /// Verifies that a string consists of the expected number of extended
+    /// grapheme clusters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "unicode"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "unicode")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "cafe\u{301} \u{1f1e8}\u{1f1ed}";
+    ///
+    /// assert_that!(subject).has_char_count(8);
+    /// assert_that!(subject).has_grapheme_count(6);
+    /// # }
+    /// ```
+    #[track_caller]
+    fn has_grapheme_count(self, expected_grapheme_count: usize) -> Self;
+
+    /// Verifies that a string contains the expected extended grapheme cluster.
+    ///
+    /// A grapheme is only found if it is not part of a bigger grapheme
+    /// cluster. For example, the thumbs up emoji is not contained in a string
+    /// that contains it only with a skin tone modifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "unicode"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "unicode")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "looks good \u{1f44d}\u{1f3fd}";
+    ///
+    /// assert_that!(subject).contains_grapheme("\u{1f44d}\u{1f3fd}");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn contains_grapheme(self, expected: E) -> Self;
+
+    /// Verifies that a string does not contain the given extended grapheme
+    /// cluster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "unicode"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "unicode")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "looks good \u{1f44d}\u{1f3fd}";
+    ///
+    /// assert_that!(subject).does_not_contain_grapheme("\u{1f44d}");
+    /// assert_that!(subject).does_not_contain_grapheme("\u{1f3fd}");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn does_not_contain_grapheme(self, expected: E) -> Self;
+}
+
+/// Assert that a value matches a pattern like in a `match` expression.
+///
+/// The pattern is specified using the [`pattern!`] macro. Patterns can match
+/// enum variants, destructure structs and tuples, and contain ranges and
+/// guards. As the value is matched against the pattern, the type of the
+/// value does not need to implement `PartialEq`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// #[derive(Debug)]
+/// enum Message {
+///     Ping { seq: u32 },
+///     Text(String),
+///     Quit,
+/// }
+///
+/// let message = Message::Ping { seq: 3 };
+///
+/// assert_that!(message).matches_pattern(pattern!(Message::Ping { seq: 1.. }));
+///
+/// let message = Message::Text("hello".into());
+///
+/// assert_that!(message).matches_pattern(pattern!(Message::Text(text) if text.starts_with("he")));
+///
+/// let message = Message::Quit;
+///
+/// assert_that!(message).does_not_match_pattern(pattern!(Message::Ping { .. }));
 /// ```
-#[cfg(feature = "regex")]
-#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
-pub trait AssertStringMatches {
-    /// Verifies that a string matches a regex pattern.
+///
+/// [`pattern!`]: crate::pattern
+pub trait AssertMatchesPattern<P> {
+    /// Verifies that the subject matches the given pattern.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```
-    /// # #[cfg(not(feature = "regex"))]
-    /// # fn main() {}
-    /// # #[cfg(feature = "regex")]
-    /// # fn main() {
     /// use asserting::prelude::*;
     ///
-    /// assert_that("tation odio placerat in").matches(r"\b\w{8}\b");
-    /// # }
-    /// ```
-    ///
-    /// # Panics
+    /// let some_value: Option<(i32, char)> = Some((42, 'x'));
     ///
-    /// This method panics if the given regex pattern is invalid or exceeds the
-    /// size limit.
+    /// assert_that!(some_value).matches_pattern(pattern!(Some((40..=50, 'a'..='z'))));
+    /// ```
     #[track_caller]
-    fn matches(self, regex_pattern: &str) -> Self;
+    fn matches_pattern(self, pattern: P) -> Self;
 
-    /// Verifies that a string does not match a regex pattern.
+    /// Verifies that the subject does not match the given pattern.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```
-    /// # #[cfg(not(feature = "regex"))]
-    /// # fn main() {}
-    /// # #[cfg(feature = "regex")]
-    /// # fn main() {
     /// use asserting::prelude::*;
     ///
-    /// assert_that("tation odio placerat in").does_not_match(r"^[A-Z0-9 ]+$");
-    /// # }
-    /// ```
-    ///
-    /// # Panics
+    /// let some_value: Option<(i32, char)> = Some((42, 'x'));
     ///
-    /// This method panics if the given regex pattern is invalid or exceeds the
-    /// size limit.
+    /// assert_that!(some_value).does_not_match_pattern(pattern!(None | Some((0, _))));
+    /// ```
     #[track_caller]
-    fn does_not_match(self, regex_pattern: &str) -> Self;
+    fn does_not_match_pattern(self, pattern: P) -> Self;
 }
 
 /// Assert that an iterator or collection contains the expected value.
@@ -2948,6 +6199,41 @@ pub trait AssertIteratorContainsInAnyOrder<E> {
     #[track_caller]
     fn contains_exactly_in_any_order(self, expected: E) -> Self::Sequence;
 
+    /// Shuffles the actual collection/iterator with a random number generator
+    /// initialized with the given `seed` and verifies that it contains exactly
+    /// the given values and nothing else in any order.
+    ///
+    /// The same seed always results in the same order of the elements. The
+    /// seed is printed in the failure message, which makes a failing
+    /// order-randomized test reproducible from the failure message alone.
+    ///
+    /// The shuffled collection is the subject of any assertion chained to
+    /// this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = vec![1, 2, 3, 4, 5];
+    ///
+    /// assert_that!(subject).contains_exactly_shuffled([5, 4, 3, 2, 1], 42);
+    /// ```
+    ///
+    /// The assertion `assert_that!(vec![1, 2, 3, 4, 6]).contains_exactly_shuffled([1, 2, 3, 4, 5], 42)`
+    /// fails with a message that contains the seed:
+    ///
+    /// ```console
+    /// expected subject to contain exactly in any order [1, 2, 3, 4, 5]
+    ///    but was: [2, 3, 1, 6, 4]
+    ///   expected: [1, 2, 3, 4, 5]
+    ///    missing: [5]
+    ///      extra: [6]
+    ///       seed: 42
+    /// ```
+    #[track_caller]
+    fn contains_exactly_shuffled(self, expected: E, seed: u64) -> Self::Sequence;
+
     /// Verifies that the actual collection/iterator contains at least one of
     /// the specified values.
     ///
@@ -3073,6 +6359,92 @@ pub trait AssertIteratorContainsInAnyOrder<E> {
     fn contains_only_once(self, expected: E) -> Self::Sequence;
 }
 
+/// Assert the number of elements an iterator yields.
+///
+/// In contrast to [`AssertHasLength`], these assertions do not require the
+/// subject to know its length. They consume the iterator and count its
+/// elements lazily without collecting them. Counting stops as soon as the
+/// result is known, e.g. when the count exceeds the expected maximum. Thus,
+/// these assertions can also be used with infinite iterators, as long as an
+/// upper bound is expected.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let subject = (1..=20).filter(|n| n % 3 == 0);
+/// assert_that!(subject).has_count(6);
+///
+/// let subject = (1..).map(|n| n * n);
+/// assert_that!(subject).has_count_at_least(100);
+///
+/// let subject = "lorem ipsum dolor sit amet".split_whitespace();
+/// assert_that!(subject).has_count_in_range(3..=5);
+/// ```
+pub trait AssertIteratorCount {
+    /// The type of the spec after the iterator has been consumed.
+    type Counted;
+
+    /// Verifies that the iterator yields exactly the expected number of
+    /// elements.
+    ///
+    /// Counting stops after one element more than expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = [1, 2, 3, 4, 5].into_iter().filter(|n| n % 2 == 1);
+    ///
+    /// assert_that!(subject).has_count(3);
+    /// ```
+    #[track_caller]
+    fn has_count(self, expected_count: usize) -> Self::Counted;
+
+    /// Verifies that the iterator yields at least the expected number of
+    /// elements.
+    ///
+    /// Counting stops as soon as the expected number of elements is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = (0..).step_by(7);
+    ///
+    /// assert_that!(subject).has_count_at_least(1_000);
+    /// ```
+    #[track_caller]
+    fn has_count_at_least(self, expected_count: usize) -> Self::Counted;
+
+    /// Verifies that the iterator yields a number of elements within the
+    /// expected range.
+    ///
+    /// The expected range can be any type of range. Counting stops as soon as
+    /// the count exceeds the upper bound of the range, or, for ranges without
+    /// an upper bound, as soon as the lower bound is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "lorem ipsum dolor sit amet";
+    ///
+    /// assert_that!(subject.split(' ')).has_count_in_range(4..8);
+    /// assert_that!(subject.split(' ')).has_count_in_range(5..=5);
+    /// assert_that!(subject.chars()).has_count_in_range(20..);
+    /// assert_that!(subject.chars()).has_count_in_range(..=30);
+    /// ```
+    #[track_caller]
+    fn has_count_in_range<U>(self, expected_range: U) -> Self::Counted
+    where
+        U: RangeBounds<usize> + Debug;
+}
+
 /// Assert values in an ordered collection.
 ///
 /// These assertions are applicable to collections which iterate over their
@@ -3155,72 +6527,301 @@ pub trait AssertIteratorContainsInOrder<E> {
     /// let some_vec = vec![12, 4, 6, 10, 8];
     /// assert_that!(some_vec).contains_all_in_order([12, 4, 10, 8]);
     ///
-    /// let some_btree_map = BTreeMap::from_iter([('a', 3), ('b', 0), ('c', 8)]);
-    /// assert_that!(some_btree_map).contains_all_in_order([('a', 3), ('c', 8)]);
-    /// ```
-    #[track_caller]
-    fn contains_all_in_order(self, expected: E) -> Self::Sequence;
+    /// let some_btree_map = BTreeMap::from_iter([('a', 3), ('b', 0), ('c', 8)]);
+    /// assert_that!(some_btree_map).contains_all_in_order([('a', 3), ('c', 8)]);
+    /// ```
+    #[track_caller]
+    fn contains_all_in_order(self, expected: E) -> Self::Sequence;
+
+    /// Verifies that the actual collection/iterator contains the given values
+    /// as the first elements in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let some_array = [1, 3, 5, 7];
+    /// assert_that!(some_array).starts_with([1, 3, 5]);
+    ///
+    /// let some_slice = &['b', 'X', 'k', 'G'][..];
+    /// assert_that!(some_slice).starts_with(&['b', 'X']);
+    ///
+    /// let some_vec = vec![12, 4, 6, 10, 8];
+    /// assert_that!(some_vec).starts_with([12, 4, 6]);
+    ///
+    /// let some_btree_map = BTreeMap::from_iter([('a', 3), ('b', 0), ('c', 8)]);
+    /// assert_that!(some_btree_map).starts_with([('a', 3), ('b', 0)]);
+    /// ```
+    #[track_caller]
+    fn starts_with(self, expected: E) -> Self::Sequence;
+
+    /// Verifies that the actual collection/iterator contains the given values
+    /// as the last elements in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let some_array = [1, 3, 5, 7];
+    /// assert_that!(some_array).ends_with([3, 5, 7]);
+    ///
+    /// let some_slice = &['b', 'X', 'k', 'G'][..];
+    /// assert_that!(some_slice).ends_with(&['k', 'G']);
+    ///
+    /// let some_vec = vec![12, 4, 6, 10, 8];
+    /// assert_that!(some_vec).ends_with([10, 8]);
+    ///
+    /// let some_btree_map = BTreeMap::from_iter([('a', 3), ('b', 0), ('c', 8)]);
+    /// assert_that!(some_btree_map).ends_with([('b', 0), ('c', 8)]);
+    /// ```
+    #[track_caller]
+    fn ends_with(self, expected: E) -> Self::Sequence;
+}
+
+/// Assert the sequence of items in a custom container.
+///
+/// These assertions are applicable to types that implement the
+/// [`SequenceProperty`](crate::properties::SequenceProperty). Other than the
+/// assertions of [`AssertIteratorContainsInOrder`], they do not collect the
+/// items of the container into a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use asserting::properties::SequenceProperty;
+///
+/// #[derive(Debug)]
+/// struct Playlist {
+///     titles: Vec<&'static str>,
+/// }
+///
+/// impl SequenceProperty for Playlist {
+///     type Item = &'static str;
+///
+///     fn sequence_property(&self) -> impl Iterator<Item = &Self::Item> {
+///         self.titles.iter()
+///     }
+/// }
+///
+/// let playlist = Playlist {
+///     titles: vec!["intro", "verse", "chorus", "outro"],
+/// };
+///
+/// assert_that!(playlist)
+///     .starts_with(["intro", "verse"])
+///     .contains_sequence(["verse", "chorus"])
+///     .ends_with(["outro"]);
+/// ```
+pub trait AssertSequence<E> {
+    /// Verifies that the actual container contains the given sequence of
+    /// values in the given order and without extra values between the
+    /// sequence values.
+    #[track_caller]
+    fn contains_sequence(self, expected: E) -> Self;
+
+    /// Verifies that the actual container contains the given values as the
+    /// first items in order.
+    #[track_caller]
+    fn starts_with(self, expected: E) -> Self;
+
+    /// Verifies that the actual container contains the given values as the
+    /// last items in order.
+    #[track_caller]
+    fn ends_with(self, expected: E) -> Self;
+}
+
+/// Assert the order of the values within a collection.
+///
+/// These assertions are applicable to ordered collections only.
+pub trait AssertIsSorted {
+    /// Verifies that the actual collection is sorted in ascending order.
+    #[track_caller]
+    fn is_sorted_ascending(self) -> Self;
+
+    /// Verifies that the actual collection is sorted in descending order.
+    #[track_caller]
+    fn is_sorted_descending(self) -> Self;
+}
+
+/// Assert that the values in an ordered collection or iterator are monotonic.
+///
+/// These assertions are applicable to collections which iterate over their
+/// values in a defined order and whose values are partially ordered, like
+/// sequences of numbers or timestamps. On failure, the first pair of adjacent
+/// values that violates the expected order is reported along with their
+/// indices.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let timestamps = [1_700_000_000, 1_700_000_015, 1_700_000_042];
+/// assert_that!(timestamps).is_strictly_increasing();
+///
+/// let countdown = vec![10, 8, 8, 3, 0];
+/// assert_that!(countdown).is_non_increasing();
+/// ```
+pub trait AssertMonotonicity {
+    /// A spec-like type that contains the collected values from the iterator as
+    /// the subject, which is returned by the mapping assertion methods.
+    ///
+    /// Usually this a `Spec<'a, Vec<T>, R>` with T as the type of the items
+    /// yielded by the iterator.
+    type Sequence;
+
+    /// Verifies that each value in the actual collection/iterator is greater
+    /// than its predecessor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let some_vec = vec![-3, 0, 4, 12, 29];
+    /// assert_that!(some_vec).is_strictly_increasing();
+    ///
+    /// let some_slice = &[0.5, 1.25, 2.0][..];
+    /// assert_that!(some_slice).is_strictly_increasing();
+    /// ```
+    #[track_caller]
+    fn is_strictly_increasing(self) -> Self::Sequence;
+
+    /// Verifies that each value in the actual collection/iterator is less
+    /// than its predecessor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let some_vec = vec![29, 12, 4, 0, -3];
+    /// assert_that!(some_vec).is_strictly_decreasing();
+    ///
+    /// let some_array = ['z', 'q', 'b'];
+    /// assert_that!(some_array).is_strictly_decreasing();
+    /// ```
+    #[track_caller]
+    fn is_strictly_decreasing(self) -> Self::Sequence;
+
+    /// Verifies that each value in the actual collection/iterator is greater
+    /// than or equal to its predecessor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let some_vec = vec![-3, 0, 0, 12, 12, 29];
+    /// assert_that!(some_vec).is_non_decreasing();
+    /// ```
+    #[track_caller]
+    fn is_non_decreasing(self) -> Self::Sequence;
+
+    /// Verifies that each value in the actual collection/iterator is less than
+    /// or equal to its predecessor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let some_vec = vec![29, 12, 12, 0, -3, -3];
+    /// assert_that!(some_vec).is_non_increasing();
+    /// ```
+    #[track_caller]
+    fn is_non_increasing(self) -> Self::Sequence;
+}
+
+/// Assert that the values in an ordered collection or iterator are sorted by
+/// a custom order.
+///
+/// These assertions do not require the values to implement `Ord` or
+/// `PartialOrd`. Instead, the order is defined by a comparison function or by
+/// keys that are extracted from the values. On failure, the first pair of
+/// adjacent values that is not sorted is reported along with their indices.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// #[derive(Debug)]
+/// struct Event {
+///     name: &'static str,
+///     timestamp: u64,
+/// }
+///
+/// let events = [
+///     Event { name: "started", timestamp: 1_700_000_000 },
+///     Event { name: "progress", timestamp: 1_700_000_015 },
+///     Event { name: "finished", timestamp: 1_700_000_042 },
+/// ];
+///
+/// assert_that!(&events).is_sorted_by_key(|event| event.timestamp);
+/// assert_that!(&events).is_sorted_by(|a, b| a.name.len() <= b.name.len() + 1);
+/// ```
+pub trait AssertIsSortedBy<T> {
+    /// A spec-like type that contains the collected values from the iterator as
+    /// the subject, which is returned by the mapping assertion methods.
+    ///
+    /// Usually this a `Spec<'a, Vec<T>, R>` with T as the type of the items
+    /// yielded by the iterator.
+    type Sequence;
 
-    /// Verifies that the actual collection/iterator contains the given values
-    /// as the first elements in order.
+    /// Verifies that the actual collection/iterator is sorted according to
+    /// the given comparison function.
+    ///
+    /// The comparison function is called with each pair of adjacent values
+    /// and must return `true` if the two values are in order. This is the
+    /// same contract as for [`slice::is_sorted_by`].
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
-    /// use std::collections::BTreeMap;
-    ///
-    /// let some_array = [1, 3, 5, 7];
-    /// assert_that!(some_array).starts_with([1, 3, 5]);
-    ///
-    /// let some_slice = &['b', 'X', 'k', 'G'][..];
-    /// assert_that!(some_slice).starts_with(&['b', 'X']);
     ///
-    /// let some_vec = vec![12, 4, 6, 10, 8];
-    /// assert_that!(some_vec).starts_with([12, 4, 6]);
+    /// let subject = vec!["a", "bb", "cc", "ddd"];
     ///
-    /// let some_btree_map = BTreeMap::from_iter([('a', 3), ('b', 0), ('c', 8)]);
-    /// assert_that!(some_btree_map).starts_with([('a', 3), ('b', 0)]);
+    /// assert_that!(subject).is_sorted_by(|a, b| a.len() <= b.len());
     /// ```
     #[track_caller]
-    fn starts_with(self, expected: E) -> Self::Sequence;
+    fn is_sorted_by<C>(self, compare: C) -> Self::Sequence
+    where
+        C: FnMut(&T, &T) -> bool;
 
-    /// Verifies that the actual collection/iterator contains the given values
-    /// as the last elements in order.
+    /// Verifies that the actual collection/iterator is sorted in
+    /// non-decreasing order of the keys extracted by the given function.
     ///
     /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
-    /// use std::collections::BTreeMap;
-    ///
-    /// let some_array = [1, 3, 5, 7];
-    /// assert_that!(some_array).ends_with([3, 5, 7]);
     ///
-    /// let some_slice = &['b', 'X', 'k', 'G'][..];
-    /// assert_that!(some_slice).ends_with(&['k', 'G']);
+    /// #[derive(Debug)]
+    /// struct Order {
+    ///     id: u32,
+    ///     amount: f64,
+    /// }
     ///
-    /// let some_vec = vec![12, 4, 6, 10, 8];
-    /// assert_that!(some_vec).ends_with([10, 8]);
+    /// let subject = vec![
+    ///     Order { id: 7, amount: 12.5 },
+    ///     Order { id: 3, amount: 12.5 },
+    ///     Order { id: 5, amount: 99.0 },
+    /// ];
     ///
-    /// let some_btree_map = BTreeMap::from_iter([('a', 3), ('b', 0), ('c', 8)]);
-    /// assert_that!(some_btree_map).ends_with([('b', 0), ('c', 8)]);
+    /// assert_that!(subject).is_sorted_by_key(|order| order.amount);
     /// ```
     #[track_caller]
-    fn ends_with(self, expected: E) -> Self::Sequence;
-}
-
-/// Assert the order of the values within a collection.
-///
-/// These assertions are applicable to ordered collections only.
-pub trait AssertIsSorted {
-    /// Verifies that the actual collection is sorted in ascending order.
-    #[track_caller]
-    fn is_sorted_ascending(self) -> Self;
-
-    /// Verifies that the actual collection is sorted in descending order.
-    #[track_caller]
-    fn is_sorted_descending(self) -> Self;
+    fn is_sorted_by_key<K, F>(self, key: F) -> Self::Sequence
+    where
+        F: FnMut(&T) -> K,
+        K: PartialOrd + Debug;
 }
 
 /// Assert that the code under test panics, panics with a certain message or
@@ -3303,23 +6904,350 @@ pub trait AssertCodePanics {
     /// Verifies that the actual code under test panics with the given
     /// message.
     ///
-    /// # Example
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// fn do_something(input: &str) {
+    ///     if input.is_empty() {
+    ///         panic!("input is empty");
+    ///     }
+    /// }
+    ///
+    /// assert_that_code!(|| {
+    ///     do_something("");
+    /// }).panics_with_message("input is empty");
+    /// ```
+    #[track_caller]
+    fn panics_with_message(self, message: impl Into<String>) -> Self::Mapped;
+
+    /// Verifies that the actual code under test panics with a payload of the
+    /// given type.
+    ///
+    /// This is useful for code that panics with a structured payload using
+    /// [`std::panic::panic_any`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::panic::panic_any;
+    ///
+    /// #[derive(Debug)]
+    /// struct ErrorCode(u16);
+    ///
+    /// fn do_something(input: &str) {
+    ///     if input.is_empty() {
+    ///         panic_any(ErrorCode(400));
+    ///     }
+    /// }
+    ///
+    /// assert_that_code!(|| {
+    ///     do_something("");
+    /// }).panics_with_payload_of_type::<ErrorCode>();
+    /// ```
+    #[track_caller]
+    fn panics_with_payload_of_type<P>(self) -> Self::Mapped
+    where
+        P: Any;
+
+    /// Verifies that the actual code under test panics with a payload that
+    /// satisfies the given predicate.
+    ///
+    /// The predicate gets the panic payload as `&dyn Any`, which can be
+    /// downcast to the expected payload type to assert its content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::panic::panic_any;
+    ///
+    /// #[derive(Debug)]
+    /// struct ErrorCode(u16);
+    ///
+    /// fn do_something(input: &str) {
+    ///     if input.is_empty() {
+    ///         panic_any(ErrorCode(400));
+    ///     }
+    /// }
+    ///
+    /// assert_that_code!(|| {
+    ///     do_something("");
+    /// }).panics_satisfying(|payload| {
+    ///     payload.downcast_ref::<ErrorCode>().is_some_and(|code| code.0 == 400)
+    /// });
+    /// ```
+    #[track_caller]
+    fn panics_satisfying<F>(self, predicate: F) -> Self::Mapped
+    where
+        F: Fn(&dyn Any) -> bool;
+}
+
+/// Assert that the code under test completes within a time budget.
+///
+/// The wall-clock time it takes to execute the closure is measured. This is
+/// useful for coarse performance regression tests. It is not a replacement for
+/// benchmarks.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use std::time::Duration;
+///
+/// fn sum_of_squares(n: u64) -> u64 {
+///     (1..=n).map(|i| i * i).sum()
+/// }
+///
+/// assert_that_code!(|| {
+///     sum_of_squares(1_000);
+/// }).completes_within(Duration::from_secs(1));
+/// ```
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+pub trait AssertCodeCompletesWithin {
+    /// The type of the spec after the closure has been executed.
+    type Mapped;
+
+    /// Verifies that some code completes within the given time budget.
+    ///
+    /// The assertion fails with the actual elapsed time if the execution of
+    /// the closure exceeds the budget. It also fails if the closure panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// fn sum_of_squares(n: u64) -> u64 {
+    ///     (1..=n).map(|i| i * i).sum()
+    /// }
+    ///
+    /// assert_that_code!(|| {
+    ///     sum_of_squares(1_000);
+    /// }).completes_within(Duration::from_millis(50));
+    /// ```
+    #[track_caller]
+    fn completes_within(self, budget: Duration) -> Self::Mapped;
+}
+
+/// Assert that the code under test stays within an allocation budget.
+///
+/// The heap allocations done by the closure on the current thread are counted.
+/// This requires the [`TrackingAllocator`] to be installed as the global
+/// allocator. Otherwise, these assertions fail. See the module
+/// [`alloc_track`](crate::alloc_track) for details.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::alloc_track::TrackingAllocator;
+/// use asserting::prelude::*;
+/// use std::alloc::System;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator::new(System);
+///
+/// # fn main() {
+/// fn checksum(bytes: &[u8]) -> u8 {
+///     bytes.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
+/// }
+///
+/// assert_that_code!(|| {
+///     checksum(b"lorem ipsum");
+/// }).does_not_allocate();
+///
+/// assert_that_code!(|| {
+///     let text = String::from("lorem ipsum");
+///     checksum(text.as_bytes());
+/// }).allocates_at_most(1);
+/// # }
+/// ```
+///
+/// [`TrackingAllocator`]: crate::alloc_track::TrackingAllocator
+#[cfg(feature = "alloc-track")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc-track")))]
+pub trait AssertCodeAllocations {
+    /// The type of the spec after the closure has been executed.
+    type Mapped;
+
+    /// Verifies that some code allocates at most the given number of times.
+    ///
+    /// Each allocation and each reallocation counts. The assertion also fails
+    /// if the closure panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::alloc_track::TrackingAllocator;
+    /// use asserting::prelude::*;
+    /// use std::alloc::System;
+    ///
+    /// #[global_allocator]
+    /// static ALLOCATOR: TrackingAllocator = TrackingAllocator::new(System);
+    ///
+    /// # fn main() {
+    /// assert_that_code!(|| {
+    ///     let mut numbers = Vec::with_capacity(3);
+    ///     numbers.extend([1, 2, 3]);
+    /// }).allocates_at_most(1);
+    /// # }
+    /// ```
+    #[track_caller]
+    fn allocates_at_most(self, max: usize) -> Self::Mapped;
+
+    /// Verifies that some code does not allocate at all.
+    ///
+    /// The assertion also fails if the closure panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::alloc_track::TrackingAllocator;
+    /// use asserting::prelude::*;
+    /// use std::alloc::System;
+    ///
+    /// #[global_allocator]
+    /// static ALLOCATOR: TrackingAllocator = TrackingAllocator::new(System);
+    ///
+    /// # fn main() {
+    /// assert_that_code!(|| {
+    ///     let numbers = [3, 1, 2];
+    ///     assert_eq!(numbers.iter().max(), Some(&3));
+    /// }).does_not_allocate();
+    /// # }
+    /// ```
+    #[track_caller]
+    fn does_not_allocate(self) -> Self::Mapped;
+}
+
+/// Assert whether a checked arithmetic operation on the subject overflows.
+///
+/// The subject holds the operands of the operation, e.g., a tuple of two
+/// integers. The operation is a closure that gets the operands and returns
+/// the result of a checked arithmetic operation, like the `Option` returned by
+/// [`i32::checked_add`] or a `Result` of a custom checked operation. A result
+/// of `None` or `Err(_)` indicates an overflow.
+///
+/// The failure message contains the values of the operands and the result of
+/// the operation.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let a: u8 = 200;
+/// let b: u8 = 100;
+///
+/// assert_that!((a, b)).overflows(|(a, b)| a.checked_add(*b));
+/// assert_that!((a, b)).does_not_overflow(|(a, b)| a.checked_sub(*b));
+/// ```
+///
+/// To assert a checked operation inside a closure, see
+/// [`AssertCodeOverflows`].
+pub trait AssertOverflow<S> {
+    /// Verifies that the checked arithmetic operation on the subject
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let a = i32::MAX;
+    ///
+    /// assert_that!(a).overflows(|a| a.checked_mul(2));
+    /// assert_that!((a, 1)).overflows(|(a, b)| a.checked_add(*b).ok_or("overflow"));
+    /// ```
+    #[track_caller]
+    fn overflows<F, O>(self, operation: F) -> Self
+    where
+        F: FnOnce(&S) -> O,
+        O: CheckedArithmeticProperty + Debug;
+
+    /// Verifies that the checked arithmetic operation on the subject does not
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let a = i32::MAX;
+    ///
+    /// assert_that!(a).does_not_overflow(|a| a.checked_sub(1));
+    /// assert_that!((a, 2)).does_not_overflow(|(a, b)| a.checked_div(*b).ok_or("overflow"));
+    /// ```
+    #[track_caller]
+    fn does_not_overflow<F, O>(self, operation: F) -> Self
+    where
+        F: FnOnce(&S) -> O,
+        O: CheckedArithmeticProperty + Debug;
+}
+
+/// Assert whether checked arithmetic inside a closure overflows.
+///
+/// The closure must return the result of a checked arithmetic operation, like
+/// the `Option` returned by [`i32::checked_add`] or a `Result` of a custom
+/// checked operation. A result of `None` or `Err(_)` indicates an overflow.
+///
+/// The failure message contains the result returned by the closure. When
+/// using the [`assert_that_code!`](crate::assert_that_code) macro, it also
+/// contains the code of the closure, and with it the operands of the
+/// operation. To print the values of the operands, use [`AssertOverflow`].
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let a: u8 = 200;
+/// let b: u8 = 100;
+///
+/// assert_that_code!(|| a.checked_add(b)).overflows();
+/// assert_that_code!(|| a.checked_sub(b)).does_not_overflow();
+/// ```
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+pub trait AssertCodeOverflows {
+    /// The type of the spec after the closure has been executed.
+    type Mapped;
+
+    /// Verifies that the checked arithmetic operation in the closure
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let a = i32::MAX;
+    ///
+    /// assert_that_code!(|| a.checked_mul(2)).overflows();
+    /// assert_that_code!(|| a.checked_add(1).ok_or("overflow")).overflows();
+    /// ```
+    #[track_caller]
+    fn overflows(self) -> Self::Mapped;
+
+    /// Verifies that the checked arithmetic operation in the closure does
+    /// not overflow.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
     ///
-    /// fn do_something(input: &str) {
-    ///     if input.is_empty() {
-    ///         panic!("input is empty");
-    ///     }
-    /// }
+    /// let a = i32::MAX;
     ///
-    /// assert_that_code!(|| {
-    ///     do_something("");
-    /// }).panics_with_message("input is empty");
+    /// assert_that_code!(|| a.checked_sub(1)).does_not_overflow();
+    /// assert_that_code!(|| a.checked_div(2).ok_or("overflow")).does_not_overflow();
     /// ```
     #[track_caller]
-    fn panics_with_message(self, message: impl Into<String>) -> Self::Mapped;
+    fn does_not_overflow(self) -> Self::Mapped;
 }
 
 /// Assertions for the keys of a map.
@@ -3701,56 +7629,422 @@ pub trait AssertMapContainsValue<E> {
     ///
     /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
     ///
-    /// assert_that!(&subject).contains_value("one");
-    /// assert_that!(subject).contains_value("eight");
-    /// ```
+    /// assert_that!(&subject).contains_value("one");
+    /// assert_that!(subject).contains_value("eight");
+    /// ```
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let subject: BTreeMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).contains_value("four");
+    /// assert_that!(subject).contains_value("five");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn contains_value(self, expected_value: E) -> Self;
+
+    /// Verify that the actual map does not contain any mapping where the value
+    /// is equal to the expected one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).does_not_contain_value("two");
+    /// assert_that!(subject).does_not_contain_value("three");
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use hashbrown::HashMap;
+    ///
+    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).does_not_contain_value("six");
+    /// assert_that!(subject).does_not_contain_value("seven");
+    /// ```
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let subject: BTreeMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).does_not_contain_value("three");
+    /// assert_that!(subject).does_not_contain_value("nine");
+    /// # }
+    /// ```
+    #[track_caller]
+    fn does_not_contain_value(self, expected_value: E) -> Self;
+
+    /// Verify that the actual map contains at least one mapping for each of the
+    /// given values, where the mapping contains one of the expected values.
+    ///
+    /// The order of the values is not relevant and duplicates are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).contains_values(["four", "five"]);
+    /// assert_that!(&subject).contains_values(["eight", "one", "five"]);
+    /// assert_that!(&subject).contains_values(["eight", "one", "one"]);
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use hashbrown::HashMap;
+    ///
+    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).contains_values(["one", "five"]);
+    /// assert_that!(&subject).contains_values(["eight", "one", "four"]);
+    /// assert_that!(&subject).contains_values(["eight", "four", "four"]);
+    /// ```
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let subject: BTreeMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).contains_values(["one", "four", "five", "eight"]);
+    /// assert_that!(&subject).contains_values(["five", "four", "eight"]);
+    /// assert_that!(&subject).contains_values(["five", "five", "eight"]);
+    /// # }
+    /// ```
+    #[track_caller]
+    fn contains_values(self, expected_values: impl IntoIterator<Item = E>) -> Self;
+
+    /// Verify that the actual map does not contain any mapping where the value
+    /// is one of the given values.
+    ///
+    /// The order of the values is not relevant and duplicates are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).does_not_contain_values(["two", "three"]);
+    /// assert_that!(&subject).does_not_contain_values(["six", "three", "seven"]);
+    /// assert_that!(&subject).does_not_contain_values(["three", "six", "three"]);
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use hashbrown::HashMap;
+    ///
+    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).does_not_contain_values(["six", "seven"]);
+    /// assert_that!(&subject).does_not_contain_values(["three", "two", "six"]);
+    /// assert_that!(&subject).does_not_contain_values(["seven", "two", "seven"]);
+    /// ```
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let subject: BTreeMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    ///
+    /// assert_that!(&subject).does_not_contain_values(["two", "three", "six", "seven"]);
+    /// assert_that!(&subject).does_not_contain_values(["seven", "three", "six"]);
+    /// assert_that!(&subject).does_not_contain_values(["two", "two", "nine"]);
+    /// # }
+    /// ```
+    #[track_caller]
+    fn does_not_contain_values(self, expected_values: impl IntoIterator<Item = E>) -> Self;
+}
+
+/// Assert the value mapped to a key of a map by mapping the subject.
+///
+/// If the map does not contain a mapping for the key, the assertion fails.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use asserting::prelude::*;
+/// use std::collections::HashMap;
+///
+/// let headers: HashMap<&str, String> = [
+///     ("content-type", "application/json".to_string()),
+///     ("content-length", "42".to_string()),
+/// ].into();
+///
+/// assert_that!(&headers)
+///     .value_of("content-type")
+///     .is_equal_to("application/json");
+///
+/// assert_that!(headers)
+///     .value_of("content-length")
+///     .is_equal_to("42");
+/// # }
+/// ```
+pub trait AssertMapValue<E> {
+    /// A spec-like type that contains the value mapped to the key as the
+    /// subject, which is returned by the mapping assertion method.
+    ///
+    /// If the subject is a `&'a M` where `M` is a map, this is usually
+    /// `Spec<'a, &'a V, R>` with `V` being the type of the values in the map.
+    /// If the subject is an owned map, it is `Spec<'a, V, R>`.
+    type Value;
+
+    /// Maps the subject to the value that is mapped to the given key.
+    /// Otherwise, if the map does not contain a mapping for the key, this
+    /// assertion fails.
+    ///
+    /// The failure is reported according to the failing strategy. As there is
+    /// no value to continue the assertion chain with, all following assertions
+    /// of the chain are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let subject: BTreeMap<String, Vec<u8>> = [
+    ///     ("alpha".to_string(), vec![1, 2, 3]),
+    ///     ("beta".to_string(), vec![]),
+    /// ].into();
+    ///
+    /// assert_that!(&subject).value_of("alpha").has_length(3);
+    /// assert_that!(&subject).value_of("beta").is_empty();
+    /// # }
+    /// ```
+    #[track_caller]
+    fn value_of(self, key: E) -> Self::Value;
+}
+
+/// Assert the key/value-pairs of a sequence of pairs, like a `Vec<(K, V)>`,
+/// without wrapping it into a map first.
+///
+/// In contrast to a map, a sequence of pairs may contain several pairs with
+/// the same key.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let lookup = vec![("apple", 3), ("banana", 5), ("cherry", 7)];
+///
+/// assert_that!(&lookup).contains_pair("banana", 5);
+/// assert_that!(&lookup).does_not_contain_pair("banana", 3);
+/// ```
+pub trait AssertKeyValuePairs<K, V> {
+    /// Verifies that the subject contains a pair with the given key and the
+    /// given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+    ///
+    /// assert_that!(&subject).contains_pair(2, "two");
+    /// assert_that!(subject).contains_pair(3, "three");
+    ///
+    /// let subject: &[(char, u8)] = &[('a', 1), ('b', 2)];
+    ///
+    /// assert_that!(subject).contains_pair('b', 2);
+    /// ```
+    #[track_caller]
+    fn contains_pair(self, key: K, value: V) -> Self;
+
+    /// Verifies that the subject does not contain a pair with the given key
+    /// and the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+    ///
+    /// assert_that!(&subject).does_not_contain_pair(2, "three");
+    /// assert_that!(subject).does_not_contain_pair(4, "four");
+    /// ```
+    #[track_caller]
+    fn does_not_contain_pair(self, key: K, value: V) -> Self;
+}
+
+/// Assert the keys of a sequence of key/value-pairs, like a `Vec<(K, V)>`,
+/// without wrapping it into a map first.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let lookup = vec![("apple", 3), ("banana", 5), ("cherry", 7)];
+///
+/// assert_that!(&lookup).keys_contain("cherry");
+/// assert_that!(&lookup).keys_do_not_contain("date");
+/// ```
+pub trait AssertPairKeys<K> {
+    /// Verifies that the subject contains at least one pair with the given
+    /// key.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// # #[cfg(not(feature = "std"))]
-    /// # fn main() {}
-    /// # #[cfg(feature = "std")]
-    /// # fn main() {
     /// use asserting::prelude::*;
-    /// use std::collections::BTreeMap;
     ///
-    /// let subject: BTreeMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    /// let subject = vec![(1, "one"), (2, "two"), (3, "three")];
     ///
-    /// assert_that!(&subject).contains_value("four");
-    /// assert_that!(subject).contains_value("five");
-    /// # }
+    /// assert_that!(&subject).keys_contain(2);
+    /// assert_that!(subject).keys_contain(3);
     /// ```
     #[track_caller]
-    fn contains_value(self, expected_value: E) -> Self;
+    fn keys_contain(self, key: K) -> Self;
 
-    /// Verify that the actual map does not contain any mapping where the value
-    /// is equal to the expected one.
+    /// Verifies that the subject does not contain any pair with the given
+    /// key.
     ///
     /// # Examples
     ///
     /// ```
-    /// # #[cfg(not(feature = "std"))]
-    /// # fn main() {}
-    /// # #[cfg(feature = "std")]
-    /// # fn main() {
     /// use asserting::prelude::*;
-    /// use std::collections::HashMap;
     ///
-    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    /// let subject = vec![(1, "one"), (2, "two"), (3, "three")];
     ///
-    /// assert_that!(&subject).does_not_contain_value("two");
-    /// assert_that!(subject).does_not_contain_value("three");
-    /// # }
+    /// assert_that!(&subject).keys_do_not_contain(4);
+    /// assert_that!(subject).keys_do_not_contain(0);
     /// ```
+    #[track_caller]
+    fn keys_do_not_contain(self, key: K) -> Self;
+}
+
+/// Access the values of all pairs with a given key in a sequence of
+/// key/value-pairs, like a `Vec<(K, V)>`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let headers = vec![
+///     ("accept", "text/html"),
+///     ("cookie", "theme=dark"),
+///     ("accept", "application/json"),
+/// ];
+///
+/// assert_that!(&headers)
+///     .values_for_key("accept")
+///     .contains_exactly([&"text/html", &"application/json"]);
+/// ```
+pub trait AssertValuesForKey<E> {
+    /// A spec-like type that contains the values of the pairs with the given
+    /// key as the subject, which is returned by the mapping assertion method.
+    ///
+    /// If the subject is a `&'a P` where `P` is a sequence of pairs, this is
+    /// usually `Spec<'a, Vec<&'a V>, R>` with `V` being the type of the values.
+    /// If the subject is an owned `Vec<(K, V)>`, this is usually
+    /// `Spec<'a, Vec<V>, R>`.
+    type Values;
+
+    /// Maps the subject to the values of all pairs with the given key in the
+    /// order of the pairs.
+    ///
+    /// If the subject does not contain any pair with the given key, the new
+    /// subject is an empty vector.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use asserting::prelude::*;
-    /// use hashbrown::HashMap;
     ///
-    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    /// let subject = vec![("a", 1), ("b", 2), ("a", 3)];
     ///
-    /// assert_that!(&subject).does_not_contain_value("six");
-    /// assert_that!(subject).does_not_contain_value("seven");
+    /// assert_that!(&subject).values_for_key("a").contains_exactly([&1, &3]);
+    /// assert_that!(&subject).values_for_key("b").contains_exactly([&2]);
+    /// assert_that!(&subject).values_for_key("c").is_empty();
+    /// assert_that!(subject).values_for_key("a").contains_exactly([1, 3]);
     /// ```
+    fn values_for_key(self, key: E) -> Self::Values;
+}
+
+/// Assert the order of the entries of a map that iterates over its entries in
+/// a well-defined order, like a `BTreeMap`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use asserting::prelude::*;
+/// use std::collections::BTreeMap;
+///
+/// let subject = BTreeMap::from([(1, "one"), (2, "two"), (3, "three"), (4, "four")]);
+///
+/// assert_that!(&subject).first_entry_is((1, "one"));
+/// assert_that!(&subject).last_entry_is((4, "four"));
+/// assert_that!(&subject).contains_entries_in_order([(1, "one"), (3, "three")]);
+/// # }
+/// ```
+pub trait AssertOrderedMapEntries<E, F> {
+    /// Verifies that the first entry of the map is equal to the expected
+    /// key/value-pair.
+    ///
+    /// # Examples
     ///
     /// ```
     /// # #[cfg(not(feature = "std"))]
@@ -3760,19 +8054,16 @@ pub trait AssertMapContainsValue<E> {
     /// use asserting::prelude::*;
     /// use std::collections::BTreeMap;
     ///
-    /// let subject: BTreeMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    /// let subject = BTreeMap::from([("beta", 2), ("alpha", 1), ("gamma", 3)]);
     ///
-    /// assert_that!(&subject).does_not_contain_value("three");
-    /// assert_that!(subject).does_not_contain_value("nine");
+    /// assert_that!(subject).first_entry_is(("alpha", 1));
     /// # }
     /// ```
     #[track_caller]
-    fn does_not_contain_value(self, expected_value: E) -> Self;
+    fn first_entry_is(self, expected_entry: (E, F)) -> Self;
 
-    /// Verify that the actual map contains at least one mapping for each of the
-    /// given values, where the mapping contains one of the expected values.
-    ///
-    /// The order of the values is not relevant and duplicates are ignored.
+    /// Verifies that the last entry of the map is equal to the expected
+    /// key/value-pair.
     ///
     /// # Examples
     ///
@@ -3782,26 +8073,21 @@ pub trait AssertMapContainsValue<E> {
     /// # #[cfg(feature = "std")]
     /// # fn main() {
     /// use asserting::prelude::*;
-    /// use std::collections::HashMap;
+    /// use std::collections::BTreeMap;
     ///
-    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    /// let subject = BTreeMap::from([("beta", 2), ("alpha", 1), ("gamma", 3)]);
     ///
-    /// assert_that!(&subject).contains_values(["four", "five"]);
-    /// assert_that!(&subject).contains_values(["eight", "one", "five"]);
-    /// assert_that!(&subject).contains_values(["eight", "one", "one"]);
+    /// assert_that!(subject).last_entry_is(("gamma", 3));
     /// # }
     /// ```
+    #[track_caller]
+    fn last_entry_is(self, expected_entry: (E, F)) -> Self;
+
+    /// Verifies that the map contains the expected key/value-pairs in the
+    /// given order. There may be other entries before, between, or after the
+    /// expected entries.
     ///
-    /// ```
-    /// use asserting::prelude::*;
-    /// use hashbrown::HashMap;
-    ///
-    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
-    ///
-    /// assert_that!(&subject).contains_values(["one", "five"]);
-    /// assert_that!(&subject).contains_values(["eight", "one", "four"]);
-    /// assert_that!(&subject).contains_values(["eight", "four", "four"]);
-    /// ```
+    /// # Examples
     ///
     /// ```
     /// # #[cfg(not(feature = "std"))]
@@ -3811,20 +8097,35 @@ pub trait AssertMapContainsValue<E> {
     /// use asserting::prelude::*;
     /// use std::collections::BTreeMap;
     ///
-    /// let subject: BTreeMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    /// let subject = BTreeMap::from([("beta", 2), ("alpha", 1), ("gamma", 3)]);
     ///
-    /// assert_that!(&subject).contains_values(["one", "four", "five", "eight"]);
-    /// assert_that!(&subject).contains_values(["five", "four", "eight"]);
-    /// assert_that!(&subject).contains_values(["five", "five", "eight"]);
+    /// assert_that!(subject).contains_entries_in_order([("alpha", 1), ("gamma", 3)]);
     /// # }
     /// ```
     #[track_caller]
-    fn contains_values(self, expected_values: impl IntoIterator<Item = E>) -> Self;
+    fn contains_entries_in_order(self, expected_entries: impl IntoIterator<Item = (E, F)>) -> Self;
+}
 
-    /// Verify that the actual map does not contain any mapping where the value
-    /// is one of the given values.
-    ///
-    /// The order of the values is not relevant and duplicates are ignored.
+/// Assert that the keys of a map, which iterates over its entries in a
+/// well-defined order, are sorted in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use asserting::prelude::*;
+/// use std::collections::BTreeMap;
+///
+/// let subject = BTreeMap::from([("beta", 2), ("alpha", 1), ("gamma", 3)]);
+///
+/// assert_that!(subject).keys_are_sorted();
+/// # }
+/// ```
+pub trait AssertMapKeysSorted {
+    /// Verifies that the keys of the map are sorted in ascending order.
     ///
     /// # Examples
     ///
@@ -3834,26 +8135,48 @@ pub trait AssertMapContainsValue<E> {
     /// # #[cfg(feature = "std")]
     /// # fn main() {
     /// use asserting::prelude::*;
-    /// use std::collections::HashMap;
+    /// use std::collections::BTreeMap;
     ///
-    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    /// let subject = BTreeMap::from([(3, 'c'), (1, 'a'), (2, 'b')]);
     ///
-    /// assert_that!(&subject).does_not_contain_values(["two", "three"]);
-    /// assert_that!(&subject).does_not_contain_values(["six", "three", "seven"]);
-    /// assert_that!(&subject).does_not_contain_values(["three", "six", "three"]);
+    /// assert_that!(subject).keys_are_sorted();
     /// # }
     /// ```
+    #[track_caller]
+    fn keys_are_sorted(self) -> Self;
+}
+
+/// Assert that a map is equal to another map, regardless of the order of the
+/// entries.
+///
+/// The expected map can be of any type that implements [`MapProperties`],
+/// like a `HashMap` or a `BTreeMap`. The failure
+/// message lists the entries that exist only in one of the maps as well as
+/// the differences of the values for keys that exist in both maps.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use asserting::prelude::*;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let subject = HashMap::from([("beta", 2), ("alpha", 1)]);
+///
+/// assert_that!(&subject).is_equal_to_map(BTreeMap::from([("alpha", 1), ("beta", 2)]));
+/// assert_that!(&subject).is_equal_to_map(HashMap::from([("beta", 2), ("alpha", 1)]));
+/// # }
+/// ```
+///
+/// [`MapProperties`]: crate::properties::MapProperties
+pub trait AssertMapEquality<E> {
+    /// Verifies that the map contains exactly the entries of the expected map
+    /// in any order.
     ///
-    /// ```
-    /// use asserting::prelude::*;
-    /// use hashbrown::HashMap;
-    ///
-    /// let subject: HashMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
-    ///
-    /// assert_that!(&subject).does_not_contain_values(["six", "seven"]);
-    /// assert_that!(&subject).does_not_contain_values(["three", "two", "six"]);
-    /// assert_that!(&subject).does_not_contain_values(["seven", "two", "seven"]);
-    /// ```
+    /// # Examples
     ///
     /// ```
     /// # #[cfg(not(feature = "std"))]
@@ -3861,17 +8184,15 @@ pub trait AssertMapContainsValue<E> {
     /// # #[cfg(feature = "std")]
     /// # fn main() {
     /// use asserting::prelude::*;
-    /// use std::collections::BTreeMap;
+    /// use std::collections::{BTreeMap, HashMap};
     ///
-    /// let subject: BTreeMap<_, _> = [(4, "four"), (1, "one"), (5, "five"), (8, "eight")].into();
+    /// let subject = HashMap::from([(1, "one"), (2, "two"), (3, "three")]);
     ///
-    /// assert_that!(&subject).does_not_contain_values(["two", "three", "six", "seven"]);
-    /// assert_that!(&subject).does_not_contain_values(["seven", "three", "six"]);
-    /// assert_that!(&subject).does_not_contain_values(["two", "two", "nine"]);
+    /// assert_that!(subject).is_equal_to_map(BTreeMap::from([(3, "three"), (1, "one"), (2, "two")]));
     /// # }
     /// ```
     #[track_caller]
-    fn does_not_contain_values(self, expected_values: impl IntoIterator<Item = E>) -> Self;
+    fn is_equal_to_map(self, expected: E) -> Self;
 }
 
 /// Execute assertions on every element of a collection or iterator.
@@ -3900,6 +8221,10 @@ where
     /// collection or iterator. The position is 0-based. So a failure message
     /// for the first element contains `[0]`, the second `[1]`, and so on.
     ///
+    /// When `each_element` is called for nested collections, the positions of
+    /// all levels are kept in the failure messages. A failure for the second
+    /// element of the first row of a `matrix` is reported for `matrix [0] [1]`.
+    ///
     /// # Example
     ///
     /// The following assertion:
@@ -3986,6 +8311,121 @@ where
         B: GetFailures;
 }
 
+/// Assert sliding windows or chunks of a slice.
+///
+/// Splits a slice into sliding windows or into chunks of the given size and
+/// executes one or multiple assertions on each window or chunk. The failure
+/// report contains detailed information for each window or chunk for which one
+/// or multiple assertions failed.
+///
+/// These assertions are implemented for references to slices, arrays and
+/// vectors, e.g. `&[T]`, `&[T; N]` and `&Vec<T>`.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let readings = [3, 5, 8, 13, 21];
+///
+/// assert_that!(&readings).each_window(2, |window| window.satisfies(|w| w[0] < w[1]));
+///
+/// let packets = vec![0xca, 0xfe, 0xba, 0xbe, 0x00];
+///
+/// assert_that!(&packets).each_chunk(2, |chunk| chunk.has_length_in_range(1..=2));
+/// ```
+pub trait AssertWindows<'a, W> {
+    /// A spec-like type that is returned by the methods of this trait.
+    type Output;
+
+    /// Iterates over all contiguous windows of the given size, overlapping
+    /// each other, and executes the given assertions for each of those
+    /// windows. If all windows are asserted successfully, the whole assertion
+    /// succeeds.
+    ///
+    /// If the slice is shorter than the given size, there are no windows and
+    /// the assertion succeeds.
+    ///
+    /// The failure messages contain the position of the window. The position
+    /// is 0-based. So a failure message for the first window contains
+    /// `window [0]`, the second `window [1]`, and so on. The elements of the
+    /// window are listed as context of the failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given size is 0.
+    ///
+    /// # Example
+    ///
+    /// The following assertion:
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// let readings = [3, 5, 8, 7, 21];
+    ///
+    /// assert_that!(&readings).each_window(2, |window| window.satisfies(|w| w[0] < w[1]));
+    /// ```
+    ///
+    /// will print:
+    ///
+    /// ```console
+    /// expected &readings window [2] to satisfy the given predicate, but returned false
+    ///    context:
+    ///      window: [8, 7]
+    /// ```
+    #[allow(clippy::return_self_not_must_use)]
+    #[track_caller]
+    fn each_window<A, B>(self, size: usize, assert: A) -> Self::Output
+    where
+        A: Fn(Spec<'a, W, CollectFailures>) -> B,
+        B: GetFailures;
+
+    /// Iterates over chunks of the given size, not overlapping each other,
+    /// and executes the given assertions for each of those chunks. If all
+    /// chunks are asserted successfully, the whole assertion succeeds.
+    ///
+    /// If the length of the slice is not divisible by the given size, the
+    /// last chunk is shorter than the given size.
+    ///
+    /// The failure messages contain the position of the chunk. The position is
+    /// 0-based. So a failure message for the first chunk contains `chunk [0]`,
+    /// the second `chunk [1]`, and so on. The elements of the chunk are listed
+    /// as context of the failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given size is 0.
+    ///
+    /// # Example
+    ///
+    /// The following assertion:
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// let pairs = vec![1, 2, 3, 4, 5];
+    ///
+    /// assert_that!(&pairs).each_chunk(2, |chunk| chunk.has_length(2));
+    /// ```
+    ///
+    /// will print:
+    ///
+    /// ```console
+    /// expected &pairs chunk [2] to have a length of 2
+    ///    but was: 1
+    ///   expected: 2
+    ///    context:
+    ///      chunk: [5]
+    /// ```
+    #[allow(clippy::return_self_not_must_use)]
+    #[track_caller]
+    fn each_chunk<A, B>(self, size: usize, assert: A) -> Self::Output
+    where
+        A: Fn(Spec<'a, W, CollectFailures>) -> B,
+        B: GetFailures;
+}
+
 /// Filter assertions for elements of a collection or an iterator.
 ///
 /// Filtering is used to target the assertions on specific elements of a
@@ -4071,6 +8511,56 @@ pub trait AssertFilteredElements<T> {
     where
         C: FnMut(&T) -> bool;
 
+    /// Filter the elements of a collection or an iterator on a predicate and
+    /// return a [`Spec`] only containing the elements that match the predicate.
+    ///
+    /// In contrast to [`filtered_on`](AssertFilteredElements::filtered_on),
+    /// the subject of the returned [`Spec`] is renamed, so that failure
+    /// messages of subsequent assertions mention that the elements have been
+    /// filtered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Level {
+    ///     Info,
+    ///     Error,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Event {
+    ///     level: Level,
+    ///     code: u16,
+    /// }
+    ///
+    /// let events = [
+    ///     Event { level: Level::Info, code: 100 },
+    ///     Event { level: Level::Error, code: 503 },
+    ///     Event { level: Level::Info, code: 200 },
+    ///     Event { level: Level::Error, code: 500 },
+    /// ];
+    ///
+    /// assert_that!(events)
+    ///     .filtering(|e| e.level == Level::Error)
+    ///     .has_length(2)
+    ///     .each_element(|e| e.extracting("code", |e| e.code).is_at_least(500));
+    /// ```
+    ///
+    /// The failure message of a subsequent assertion reads like:
+    ///
+    /// ```console
+    /// expected events filtered on the given predicate to have a length of 3
+    ///    but was: 2
+    ///   expected: 3
+    /// ```
+    #[track_caller]
+    fn filtering<P>(self, predicate: P) -> Self::MultipleElements
+    where
+        P: FnMut(&T) -> bool;
+
     /// Verify that any element of a collection or an iterator satisfies a given
     /// predicate.
     ///
@@ -0,0 +1,62 @@
+//! Implementation of assertions for types generated by the `bitflags` crate.
+
+use crate::assertions::AssertBitFlags;
+use crate::bits::mark_diff_bits;
+use crate::expectations::{ContainsFlags, contains_flags};
+use crate::properties::BitsProperty;
+use crate::spec::{DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Spec};
+use crate::std::fmt::Debug;
+use crate::std::format;
+use crate::std::string::String;
+use bitflags::Flags;
+
+impl<S, R> AssertBitFlags<S> for Spec<'_, S, R>
+where
+    S: Flags + Debug,
+    S::Bits: BitsProperty,
+    R: FailingStrategy,
+{
+    fn contains_flags(self, flags: S) -> Self {
+        self.expecting(contains_flags(flags))
+    }
+}
+
+impl<S> Expectation<S> for ContainsFlags<S>
+where
+    S: Flags + Debug,
+    S::Bits: BitsProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::contains_flags";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.contains(S::from_bits_retain(self.flags.bits()))
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let flags = &self.flags;
+        let actual_bits = actual.bits();
+        let expected_bits = S::from_bits_retain(actual_bits)
+            .union(S::from_bits_retain(flags.bits()))
+            .bits();
+        let missing =
+            S::from_bits_retain(flags.bits()).difference(S::from_bits_retain(actual_bits));
+        let (marked_actual, marked_expected) = mark_diff_bits(
+            actual_bits.bits_property(),
+            expected_bits.bits_property(),
+            actual_bits.bit_width_property(),
+            format,
+        );
+        format!(
+            "expected {expression} to contain the flags {flags:?}\n   but was: {marked_actual}\n  expected: {marked_expected}\n   missing: {missing:?}"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+use crate::prelude::*;
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Permissions: u8 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXECUTE = 0b100;
+    }
+}
+
+#[test]
+fn permissions_contains_flags() {
+    let subject = Permissions::READ | Permissions::WRITE;
+
+    assert_that(subject)
+        .contains_flags(Permissions::READ)
+        .contains_flags(Permissions::READ | Permissions::WRITE);
+}
+
+#[test]
+fn permissions_contains_empty_flags() {
+    assert_that(Permissions::empty()).contains_flags(Permissions::empty());
+}
+
+#[test]
+fn verify_permissions_contains_flags_fails() {
+    let subject = Permissions::READ | Permissions::WRITE;
+
+    let failures = verify_that(subject)
+        .named("permissions")
+        .contains_flags(Permissions::READ | Permissions::EXECUTE)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected permissions to contain the flags Permissions(READ | EXECUTE)
+   but was: 0b00000011 (0x03)
+  expected: 0b00000111 (0x07)
+   missing: Permissions(EXECUTE)
+"
+        ]
+    );
+}
+
+#[test]
+fn extracted_permissions_contains_flags() {
+    struct File {
+        permissions: Permissions,
+    }
+
+    let file = File {
+        permissions: Permissions::all(),
+    };
+
+    assert_that(file)
+        .extracting_ref("permissions", |f| &f.permissions)
+        .contains_flags(Permissions::WRITE | Permissions::EXECUTE);
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use super::Permissions;
+    use crate::prelude::*;
+
+    #[test]
+    fn highlight_diffs_permissions_contains_flags() {
+        let subject = Permissions::READ;
+
+        let failures = verify_that(subject)
+            .named("permissions")
+            .with_diff_format(DIFF_FORMAT_RED_YELLOW)
+            .contains_flags(Permissions::WRITE)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected permissions to contain the flags Permissions(WRITE)\n   \
+                but was: 0b000000\u{1b}[31m0\u{1b}[0m1 (0x01)\n  \
+               expected: 0b000000\u{1b}[33m1\u{1b}[0m1 (0x03)\n   \
+                missing: Permissions(WRITE)\n\
+            "
+            ]
+        );
+    }
+}
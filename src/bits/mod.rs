@@ -0,0 +1,189 @@
+//! Implementation of assertions for the bits of integer values.
+
+use crate::assertions::AssertBits;
+use crate::colored::{
+    mark_selected_chars_in_string_as_missing, mark_selected_chars_in_string_as_unexpected,
+};
+use crate::expectations::{
+    HasBitSet, HasBitsSet, HasNoBitsSet, has_bit_set, has_bits_set, has_no_bits_set,
+};
+use crate::properties::BitsProperty;
+use crate::spec::{DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Spec};
+use crate::std::format;
+use crate::std::string::String;
+use hashbrown::HashSet;
+
+impl<S, R> AssertBits for Spec<'_, S, R>
+where
+    S: BitsProperty,
+    R: FailingStrategy,
+{
+    fn has_bit_set(self, index: u32) -> Self {
+        self.expecting(has_bit_set(index))
+    }
+
+    fn has_bits_set<M>(self, mask: M) -> Self
+    where
+        M: BitsProperty,
+    {
+        self.expecting(has_bits_set(mask))
+    }
+
+    fn has_no_bits_set<M>(self, mask: M) -> Self
+    where
+        M: BitsProperty,
+    {
+        self.expecting(has_no_bits_set(mask))
+    }
+}
+
+impl<S> Expectation<S> for HasBitSet
+where
+    S: BitsProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_bit_set";
+
+    fn test(&mut self, subject: &S) -> bool {
+        self.index < subject.bit_width_property()
+            && subject.bits_property() & (1 << self.index) != 0
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let index = self.index;
+        let width = actual.bit_width_property();
+        let actual_bits = actual.bits_property();
+        if index >= width {
+            return format!(
+                "expected {expression} to have bit {index} set, which is out of range for {width} bits\n   but was: {}\n  expected: bit index < {width}",
+                format_bits(actual_bits, width)
+            );
+        }
+        let mask = 1 << index;
+        let (marked_actual, marked_expected) =
+            mark_diff_bits(actual_bits, actual_bits | mask, width, format);
+        format!(
+            "expected {expression} to have bit {index} set\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<S, E> Expectation<S> for HasBitsSet<E>
+where
+    S: BitsProperty,
+    E: BitsProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_bits_set";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let mask = self.mask.bits_property();
+        subject.bits_property() & mask == mask
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let width = actual.bit_width_property();
+        let actual_bits = actual.bits_property();
+        let mask = self.mask.bits_property();
+        if mask & !width_mask(width) != 0 {
+            return format!(
+                "expected {expression} to have the bits {mask:#x} set, which are out of range for {width} bits\n   but was: {}\n  expected: mask <= {:#x}",
+                format_bits(actual_bits, width),
+                width_mask(width),
+            );
+        }
+        let (marked_actual, marked_expected) =
+            mark_diff_bits(actual_bits, actual_bits | mask, width, format);
+        let mask = format_binary(mask, width);
+        format!(
+            "expected {expression} to have the bits {mask} set\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<S, E> Expectation<S> for HasNoBitsSet<E>
+where
+    S: BitsProperty,
+    E: BitsProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_no_bits_set";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.bits_property() & self.mask.bits_property() == 0
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let width = actual.bit_width_property();
+        let actual_bits = actual.bits_property();
+        let mask = self.mask.bits_property() & width_mask(width);
+        let (marked_actual, marked_expected) =
+            mark_diff_bits(actual_bits, actual_bits & !mask, width, format);
+        let mask = format_binary(mask, width);
+        format!(
+            "expected {expression} to have none of the bits {mask} set\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+fn width_mask(width: u32) -> u128 {
+    u128::MAX >> (u128::BITS - width)
+}
+
+fn format_binary(bits: u128, width: u32) -> String {
+    format!("{bits:#0digits$b}", digits = width as usize + 2)
+}
+
+/// Formats the given bits in binary and hexadecimal notation.
+fn format_bits(bits: u128, width: u32) -> String {
+    let hex_digits = width as usize / 4 + 2;
+    format!("{} ({bits:#0hex_digits$x})", format_binary(bits, width))
+}
+
+/// Formats the actual and the expected bits and highlights the bits that
+/// differ between them.
+pub fn mark_diff_bits(
+    actual: u128,
+    expected: u128,
+    width: u32,
+    format: &DiffFormat,
+) -> (String, String) {
+    let differing = actual ^ expected;
+    let selected = (0..width)
+        .filter(|bit| differing & (1 << bit) != 0)
+        .map(|bit| 2 + (width - 1 - bit) as usize)
+        .collect::<HashSet<_>>();
+    let hex_digits = width as usize / 4 + 2;
+    let marked_actual = mark_selected_chars_in_string_as_unexpected(
+        &format_binary(actual, width),
+        &selected,
+        format,
+    );
+    let marked_expected = mark_selected_chars_in_string_as_missing(
+        &format_binary(expected, width),
+        &selected,
+        format,
+    );
+    (
+        format!("{marked_actual} ({actual:#0hex_digits$x})"),
+        format!("{marked_expected} ({expected:#0hex_digits$x})"),
+    )
+}
+
+#[cfg(test)]
+mod tests;
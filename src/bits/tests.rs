@@ -0,0 +1,199 @@
+use crate::prelude::*;
+
+#[test]
+fn u8_has_bit_set() {
+    let subject: u8 = 0b0010_0100;
+
+    assert_that(subject).has_bit_set(2).has_bit_set(5);
+}
+
+#[test]
+fn i32_min_has_most_significant_bit_set() {
+    assert_that(i32::MIN).has_bit_set(31);
+}
+
+#[test]
+fn borrowed_u64_has_bit_set() {
+    let subject: u64 = 1 << 40;
+
+    assert_that(&subject).has_bit_set(40);
+}
+
+#[test]
+fn verify_u8_has_bit_set_fails() {
+    let subject: u8 = 0b0000_0010;
+
+    let failures = verify_that(subject)
+        .named("flags")
+        .has_bit_set(3)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected flags to have bit 3 set
+   but was: 0b00000010 (0x02)
+  expected: 0b00001010 (0x0a)
+"]
+    );
+}
+
+#[test]
+fn verify_u8_has_bit_set_fails_for_index_out_of_range() {
+    let subject: u8 = 0xff;
+
+    let failures = verify_that(subject)
+        .named("flags")
+        .has_bit_set(8)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected flags to have bit 8 set, which is out of range for 8 bits
+   but was: 0b11111111 (0xff)
+  expected: bit index < 8
+"
+        ]
+    );
+}
+
+#[test]
+fn u16_has_bits_set() {
+    let subject: u16 = 0o644;
+
+    assert_that(subject).has_bits_set(0o600).has_bits_set(0o044);
+}
+
+#[test]
+fn verify_u8_has_bits_set_fails() {
+    let subject: u8 = 0b0000_0010;
+
+    let failures = verify_that(subject)
+        .named("flags")
+        .has_bits_set(0b0000_1010)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected flags to have the bits 0b00001010 set
+   but was: 0b00000010 (0x02)
+  expected: 0b00001010 (0x0a)
+"]
+    );
+}
+
+#[test]
+fn verify_u8_has_bits_set_fails_for_mask_out_of_range() {
+    let subject: u8 = 0xff;
+
+    let failures = verify_that(subject)
+        .named("flags")
+        .has_bits_set(0x1ff)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected flags to have the bits 0x1ff set, which are out of range for 8 bits
+   but was: 0b11111111 (0xff)
+  expected: mask <= 0xff
+"
+        ]
+    );
+}
+
+#[test]
+fn u8_has_no_bits_set_of_mask_out_of_range() {
+    let subject: u8 = 0x0f;
+
+    assert_that(subject).has_no_bits_set(0xff00_u16);
+}
+
+#[test]
+fn i16_has_no_bits_set() {
+    let subject: i16 = 0x0f0f;
+
+    assert_that(subject).has_no_bits_set(0x70f0);
+}
+
+#[test]
+fn verify_u16_has_no_bits_set_fails() {
+    let subject: u16 = 0x0ff0;
+
+    let failures = verify_that(subject)
+        .named("register")
+        .has_no_bits_set(0x0180)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected register to have none of the bits 0b0000000110000000 set
+   but was: 0b0000111111110000 (0x0ff0)
+  expected: 0b0000111001110000 (0x0e70)
+"
+        ]
+    );
+}
+
+#[test]
+fn negative_i8_has_bits_set_in_twos_complement() {
+    let subject: i8 = -2;
+
+    assert_that(subject)
+        .has_bits_set(0b0111_1110)
+        .has_no_bits_set(0b0000_0001);
+}
+
+#[test]
+#[should_panic = "expected subject to have bit 0 set\n   but was: 0b00000000000000000000000000000000 (0x00000000)\n  expected: 0b00000000000000000000000000000001 (0x00000001)\n"]
+fn u32_zero_has_bit_set_panics() {
+    assert_that(0_u32)
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .has_bit_set(0);
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use crate::prelude::*;
+
+    #[test]
+    fn highlight_diffs_u8_has_bits_set() {
+        let subject: u8 = 0b0000_0010;
+
+        let failures = verify_that(subject)
+            .named("flags")
+            .with_diff_format(DIFF_FORMAT_RED_YELLOW)
+            .has_bits_set(0b0000_1010)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected flags to have the bits 0b00001010 set\n   \
+                but was: 0b0000\u{1b}[31m0\u{1b}[0m010 (0x02)\n  \
+               expected: 0b0000\u{1b}[33m1\u{1b}[0m010 (0x0a)\n\
+            "]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_u8_has_no_bits_set() {
+        let subject: u8 = 0b1100_0011;
+
+        let failures = verify_that(subject)
+            .named("flags")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .has_no_bits_set(0b1000_0001)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected flags to have none of the bits 0b10000001 set\n   \
+                but was: 0b\u{1b}[31m1\u{1b}[0m100001\u{1b}[31m1\u{1b}[0m (0xc3)\n  \
+               expected: 0b\u{1b}[34m0\u{1b}[0m100001\u{1b}[34m0\u{1b}[0m (0x42)\n\
+            "
+            ]
+        );
+    }
+}
@@ -2,7 +2,10 @@
 
 use crate::assertions::AssertBoolean;
 use crate::colored::{mark_missing, mark_unexpected};
-use crate::expectations::{IsFalse, IsTrue, is_false, is_true};
+use crate::expectations::{
+    IsFalse, IsFalseBecause, IsTrue, IsTrueBecause, is_false, is_false_because, is_true,
+    is_true_because,
+};
 use crate::spec::{
     DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
 };
@@ -20,9 +23,19 @@ where
     fn is_false(self) -> Self {
         self.expecting(is_false())
     }
+
+    fn is_true_because(self, reason: impl Into<String>) -> Self {
+        self.expecting(is_true_because(reason))
+    }
+
+    fn is_false_because(self, reason: impl Into<String>) -> Self {
+        self.expecting(is_false_because(reason))
+    }
 }
 
 impl Expectation<bool> for IsTrue {
+    const ASSERTION_ID: &'static str = "asserting::is_true";
+
     fn test(&mut self, subject: &bool) -> bool {
         *subject
     }
@@ -46,6 +59,8 @@ impl Expectation<bool> for IsTrue {
 impl Invertible for IsTrue {}
 
 impl Expectation<bool> for IsFalse {
+    const ASSERTION_ID: &'static str = "asserting::is_false";
+
     fn test(&mut self, subject: &bool) -> bool {
         !*subject
     }
@@ -68,5 +83,51 @@ impl Expectation<bool> for IsFalse {
 
 impl Invertible for IsFalse {}
 
+impl Expectation<bool> for IsTrueBecause {
+    const ASSERTION_ID: &'static str = "asserting::is_true_because";
+
+    fn test(&mut self, subject: &bool) -> bool {
+        *subject
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &bool,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing(&true, format);
+        format!(
+            "expected {expression} to be {:?} because {}\n   but was: {marked_actual}\n  expected: {marked_expected}",
+            true, self.reason
+        )
+    }
+}
+
+impl Expectation<bool> for IsFalseBecause {
+    const ASSERTION_ID: &'static str = "asserting::is_false_because";
+
+    fn test(&mut self, subject: &bool) -> bool {
+        !*subject
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &bool,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing(&false, format);
+        format!(
+            "expected {expression} to be {:?} because {}\n   but was: {marked_actual}\n  expected: {marked_expected}",
+            false, self.reason
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -70,6 +70,81 @@ fn verify_bool_is_false_fails() {
     );
 }
 
+#[test]
+fn bool_is_true_because() {
+    assert_that(42 > 41).is_true_because("42 is greater than 41");
+}
+
+#[test]
+fn bool_is_false_because() {
+    assert_that(42 < 41).is_false_because("42 is not less than 41");
+}
+
+#[test]
+fn verify_bool_is_true_because_fails() {
+    let failures = verify_that(false)
+        .named("cache_warmed")
+        .is_true_because("the cache must be warmed by now")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected cache_warmed to be true because the cache must be warmed by now
+   but was: false
+  expected: true
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_bool_is_false_because_fails() {
+    let failures = verify_that(true)
+        .named("queue_is_empty")
+        .is_false_because("two jobs have been scheduled")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected queue_is_empty to be false because two jobs have been scheduled
+   but was: true
+  expected: false
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_extracted_bool_is_true_because_fails() {
+    let failures = verify_that((41, false))
+        .named("response")
+        .extracting_ref("cached", |response| &response.1)
+        .is_true_because("the second request is served from the cache")
+        .and()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected response.cached to be true because the second request is served from the cache
+   but was: false
+  expected: true
+"
+        ]
+    );
+}
+
+#[test]
+#[should_panic = "expected my_flag to be true because the flag must be set\n   but was: false\n"]
+fn bool_is_true_because_panics_if_false() {
+    assert_that(false)
+        .named("my_flag")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .is_true_because("the flag must be set");
+}
+
 #[cfg(feature = "colored")]
 mod colored {
     use crate::prelude::*;
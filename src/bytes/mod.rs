@@ -0,0 +1,388 @@
+//! Implementation of assertions for byte sequences like `Vec<u8>` and
+//! `&[u8]`, either as binary data or as text encoded as UTF-8.
+
+use crate::assertions::{AssertBytes, AssertUtf8Content, AssertUtf8Text};
+use crate::colored::{mark_diff_str, mark_missing_string, mark_unexpected_string};
+use crate::expectations::{
+    ContainsSubsequence, HasUtf8Content, IsEqualToBytes, StartsWithBytes, contains_subsequence,
+    has_utf8_content, is_equal_to_bytes, not, starts_with_bytes,
+};
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::format;
+use crate::std::str;
+use crate::std::string::{String, ToString};
+use crate::std::vec;
+use crate::std::vec::Vec;
+
+impl<S, E, R> AssertUtf8Content<E> for Spec<'_, S, R>
+where
+    S: AsRef<[u8]>,
+    E: AsRef<str>,
+    R: FailingStrategy,
+{
+    fn has_utf8_content(self, expected: E) -> Self {
+        self.expecting(has_utf8_content(expected))
+    }
+
+    fn does_not_have_utf8_content(self, expected: E) -> Self {
+        self.expecting(not(has_utf8_content(expected)))
+    }
+}
+
+impl<S, E> Expectation<S> for HasUtf8Content<E>
+where
+    S: AsRef<[u8]>,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_utf8_content";
+
+    fn test(&mut self, subject: &S) -> bool {
+        str::from_utf8(subject.as_ref()).is_ok_and(|text| text == self.expected.as_ref())
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected.as_ref();
+        let actual_text = String::from_utf8_lossy(actual.as_ref());
+        let (marked_actual, marked_expected) = mark_diff_str(&actual_text, expected, format);
+        format!(
+            "expected {expression} to {not}have UTF-8 content equal to {expected:?}\n   but was: \"{marked_actual}\"\n  expected: {not}\"{marked_expected}\"",
+        )
+    }
+}
+
+impl<E> Invertible for HasUtf8Content<E> {}
+
+impl<'a, S, R> AssertUtf8Text for Spec<'a, S, R>
+where
+    S: AsRef<[u8]>,
+    R: FailingStrategy,
+{
+    type Utf8Text = Spec<'a, String, R>;
+
+    fn as_utf8_text(self) -> Self::Utf8Text {
+        let expression_utf8_text = format!("{}'s UTF-8 text", self.expression());
+        self.mapping(|subject| String::from_utf8_lossy(subject.as_ref()).into_owned())
+            .named(expression_utf8_text)
+    }
+}
+
+impl<S, E, R> AssertBytes<E> for Spec<'_, S, R>
+where
+    S: AsRef<[u8]>,
+    E: AsRef<[u8]>,
+    R: FailingStrategy,
+{
+    fn is_equal_to_bytes(self, expected: E) -> Self {
+        self.expecting(is_equal_to_bytes(expected))
+    }
+
+    fn starts_with_bytes(self, expected: E) -> Self {
+        self.expecting(starts_with_bytes(expected))
+    }
+
+    fn contains_subsequence(self, expected: E) -> Self {
+        self.expecting(contains_subsequence(expected))
+    }
+}
+
+impl<S, E> Expectation<S> for IsEqualToBytes<E>
+where
+    S: AsRef<[u8]>,
+    E: AsRef<[u8]>,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_equal_to_bytes";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.as_ref() == self.expected.as_ref()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual = actual.as_ref();
+        let expected = self.expected.as_ref();
+        let hexdump = hexdump_side_by_side(
+            actual,
+            expected,
+            |index| actual.get(index) != expected.get(index),
+            format,
+        );
+        format!(
+            "expected {expression} to be equal to the expected bytes\n   but was: {} bytes\n  expected: {} bytes\n{hexdump}",
+            actual.len(),
+            expected.len(),
+        )
+    }
+}
+
+impl<S, E> Expectation<S> for StartsWithBytes<E>
+where
+    S: AsRef<[u8]>,
+    E: AsRef<[u8]>,
+{
+    const ASSERTION_ID: &'static str = "asserting::starts_with_bytes";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.as_ref().starts_with(self.expected.as_ref())
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual = actual.as_ref();
+        let expected = self.expected.as_ref();
+        let hexdump = hexdump_side_by_side(
+            actual,
+            expected,
+            |index| index < expected.len() && actual.get(index) != expected.get(index),
+            format,
+        );
+        format!(
+            "expected {expression} to start with the expected bytes\n   but was: {} bytes\n  expected: {} bytes\n{hexdump}",
+            actual.len(),
+            expected.len(),
+        )
+    }
+}
+
+impl<S, E> Expectation<S> for ContainsSubsequence<E>
+where
+    S: AsRef<[u8]>,
+    E: AsRef<[u8]>,
+{
+    const ASSERTION_ID: &'static str = "asserting::contains_subsequence";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let expected = self.expected.as_ref();
+        expected.is_empty()
+            || subject
+                .as_ref()
+                .windows(expected.len())
+                .any(|window| window == expected)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual = actual.as_ref();
+        let expected = self.expected.as_ref();
+        let (start, matching) = closest_match(actual, expected);
+        let end = start + expected.len();
+        let first_row = (start / HEXDUMP_ROW_LENGTH).saturating_sub(1);
+        let last_row = (end.saturating_sub(1) / HEXDUMP_ROW_LENGTH + 1)
+            .min(first_row + HEXDUMP_EXCERPT_MAX_ROWS - 1);
+        let differs_in_actual = |index: usize| {
+            (start..end).contains(&index) && actual.get(index) != expected.get(index - start)
+        };
+        let differs_in_expected = |index: usize| actual.get(start + index) != expected.get(index);
+        let actual_hexdump = hexdump_excerpt(
+            actual,
+            |row| (first_row..=last_row).contains(&row),
+            differs_in_actual,
+            mark_unexpected_string,
+            format,
+        );
+        let expected_hexdump = hexdump_excerpt(
+            expected,
+            |row| row < HEXDUMP_EXCERPT_MAX_ROWS,
+            differs_in_expected,
+            mark_missing_string,
+            format,
+        );
+        format!(
+            "expected {expression} to contain the expected subsequence of bytes\n   but was: {} bytes\n  expected: {} bytes\n  closest match at offset {start:08x} with {matching} of {} bytes equal:\n{actual_hexdump}\n  expected subsequence:\n{expected_hexdump}",
+            actual.len(),
+            expected.len(),
+            expected.len(),
+        )
+    }
+}
+
+/// Finds the position in the actual bytes where the most bytes are equal to
+/// the expected subsequence.
+///
+/// Returns the offset of the closest match and the number of equal bytes. The
+/// first position wins if there are several positions with the same number
+/// of equal bytes.
+fn closest_match(actual: &[u8], expected: &[u8]) -> (usize, usize) {
+    let last_start = actual.len().saturating_sub(expected.len());
+    (0..=last_start)
+        .map(|start| {
+            let matching = actual[start..]
+                .iter()
+                .zip(expected)
+                .filter(|(actual, expected)| actual == expected)
+                .count();
+            (start, matching)
+        })
+        .fold((0, 0), |closest, candidate| {
+            if candidate.1 > closest.1 {
+                candidate
+            } else {
+                closest
+            }
+        })
+}
+
+/// Number of bytes rendered in one row of a hexdump.
+const HEXDUMP_ROW_LENGTH: usize = 8;
+
+/// Maximum number of rows rendered in an excerpt of a hexdump.
+const HEXDUMP_EXCERPT_MAX_ROWS: usize = 8;
+
+/// Renders the actual and the expected bytes as a side-by-side hexdump.
+///
+/// Bytes at indices for which `differs` returns true are highlighted as
+/// unexpected in the actual bytes and as missing in the expected bytes. If
+/// there are any differences, runs of more than one row that are not next to
+/// a row with differences are elided.
+fn hexdump_side_by_side(
+    actual: &[u8],
+    expected: &[u8],
+    differs: impl Fn(usize) -> bool,
+    format: &DiffFormat,
+) -> String {
+    let length = actual.len().max(expected.len());
+    let rows = length.div_ceil(HEXDUMP_ROW_LENGTH);
+    let differing_rows = (0..rows)
+        .map(|row| {
+            (row * HEXDUMP_ROW_LENGTH..((row + 1) * HEXDUMP_ROW_LENGTH).min(length)).any(&differs)
+        })
+        .collect::<Vec<_>>();
+    let has_differences = differing_rows.iter().any(|differing| *differing);
+    let keep = |row: usize| {
+        !has_differences
+            || (row.saturating_sub(1)..=row + 1)
+                .any(|neighbor| differing_rows.get(neighbor) == Some(&true))
+    };
+    let hexdump_row = |row: usize| {
+        format!(
+            "  {:08x}  {}  {}",
+            row * HEXDUMP_ROW_LENGTH,
+            hexdump_row_of(actual, row, &differs, mark_unexpected_string, format),
+            hexdump_row_of(expected, row, &differs, mark_missing_string, format),
+        )
+    };
+    let mut lines = vec![format!(
+        "  offset    {:<width$}  expected",
+        "actual",
+        width = HEXDUMP_ROW_LENGTH * 4 + 3
+    )];
+    let mut row = 0;
+    while row < rows {
+        if keep(row) {
+            lines.push(hexdump_row(row));
+            row += 1;
+        } else {
+            let start = row;
+            while row < rows && !keep(row) {
+                row += 1;
+            }
+            if row - start == 1 {
+                lines.push(hexdump_row(start));
+            } else {
+                lines.push(format!("  … {} more rows …", row - start));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+/// Renders a hexdump of the rows of the given bytes for which `keep` returns
+/// true.
+///
+/// Runs of more than one row that are not kept are elided. Bytes at indices
+/// for which `differs` returns true are highlighted using the given `mark`
+/// function.
+fn hexdump_excerpt(
+    bytes: &[u8],
+    keep: impl Fn(usize) -> bool,
+    differs: impl Fn(usize) -> bool,
+    mark: fn(&str, &DiffFormat) -> String,
+    format: &DiffFormat,
+) -> String {
+    let rows = bytes.len().div_ceil(HEXDUMP_ROW_LENGTH);
+    let hexdump_row = |row: usize| {
+        format!(
+            "  {:08x}  {}",
+            row * HEXDUMP_ROW_LENGTH,
+            hexdump_row_of(bytes, row, &differs, mark, format),
+        )
+    };
+    let mut lines = Vec::new();
+    let mut row = 0;
+    while row < rows {
+        if keep(row) {
+            lines.push(hexdump_row(row));
+            row += 1;
+        } else {
+            let start = row;
+            while row < rows && !keep(row) {
+                row += 1;
+            }
+            if row - start == 1 {
+                lines.push(hexdump_row(start));
+            } else {
+                lines.push(format!("  … {} more rows …", row - start));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+/// Renders one row of a hexdump for the given bytes.
+fn hexdump_row_of(
+    bytes: &[u8],
+    row: usize,
+    differs: impl Fn(usize) -> bool,
+    mark: fn(&str, &DiffFormat) -> String,
+    format: &DiffFormat,
+) -> String {
+    let mut hex = Vec::with_capacity(HEXDUMP_ROW_LENGTH);
+    let mut ascii = String::with_capacity(HEXDUMP_ROW_LENGTH);
+    for index in row * HEXDUMP_ROW_LENGTH..(row + 1) * HEXDUMP_ROW_LENGTH {
+        if let Some(byte) = bytes.get(index) {
+            let byte_hex = format!("{byte:02x}");
+            let byte_char = if byte.is_ascii_graphic() || *byte == b' ' {
+                char::from(*byte).to_string()
+            } else {
+                ".".to_string()
+            };
+            if differs(index) {
+                hex.push(mark(&byte_hex, format));
+                ascii.push_str(&mark(&byte_char, format));
+            } else {
+                hex.push(byte_hex);
+                ascii.push_str(&byte_char);
+            }
+        } else {
+            hex.push("  ".to_string());
+            ascii.push(' ');
+        }
+    }
+    format!("{}  |{ascii}|", hex.join(" "))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,336 @@
+use crate::prelude::*;
+use crate::std::{string::ToString, vec, vec::Vec};
+
+#[test]
+fn vec_of_bytes_has_utf8_content() {
+    let subject: Vec<u8> = b"{\"status\":\"ok\"}".to_vec();
+
+    assert_that(subject).has_utf8_content(r#"{"status":"ok"}"#);
+}
+
+#[test]
+fn borrowed_vec_of_bytes_has_utf8_content() {
+    let subject: Vec<u8> = "Grüße, Welt!".as_bytes().to_vec();
+
+    assert_that(&subject).has_utf8_content("Grüße, Welt!".to_string());
+}
+
+#[test]
+fn slice_of_bytes_does_not_have_utf8_content() {
+    let subject: &[u8] = b"Hello, World!";
+
+    assert_that(subject).does_not_have_utf8_content("Hello, Rust!");
+}
+
+#[test]
+fn invalid_utf8_bytes_do_not_have_utf8_content_of_the_replacement_text() {
+    let subject: &[u8] = b"Hello, \xF0\x90\x80World!";
+
+    assert_that(subject).does_not_have_utf8_content("Hello, \u{FFFD}World!");
+}
+
+#[test]
+fn verify_vec_of_bytes_has_utf8_content_fails() {
+    let subject: Vec<u8> = b"{\"status\":\"error\"}".to_vec();
+
+    let failures = verify_that(subject)
+        .named("body")
+        .has_utf8_content(r#"{"status":"ok"}"#)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected body to have UTF-8 content equal to "{\"status\":\"ok\"}"
+   but was: "{"status":"error"}"
+  expected: "{"status":"ok"}"
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_slice_of_bytes_does_not_have_utf8_content_fails() {
+    let subject: &[u8] = b"Hello, World!";
+
+    let failures = verify_that(subject)
+        .named("body")
+        .does_not_have_utf8_content("Hello, World!")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected body to not have UTF-8 content equal to "Hello, World!"
+   but was: "Hello, World!"
+  expected: not "Hello, World!"
+"#
+        ]
+    );
+}
+
+#[test]
+fn vec_of_bytes_as_utf8_text() {
+    let subject: Vec<u8> = b"Hello, World!".to_vec();
+
+    assert_that(subject)
+        .as_utf8_text()
+        .starts_with("Hello")
+        .is_equal_to("Hello, World!");
+}
+
+#[test]
+fn invalid_utf8_bytes_as_utf8_text_are_replaced_with_replacement_character() {
+    let subject: &[u8] = b"Hello, \xF0\x90\x80World!";
+
+    assert_that(subject)
+        .as_utf8_text()
+        .is_equal_to("Hello, \u{FFFD}World!");
+}
+
+#[test]
+fn verify_vec_of_bytes_as_utf8_text_is_equal_to_fails() {
+    let subject: Vec<u8> = b"Hello, World!".to_vec();
+
+    let failures = verify_that(subject)
+        .named("body")
+        .as_utf8_text()
+        .is_equal_to("Hello, Rust!")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected body's UTF-8 text to be equal to "Hello, Rust!"
+   but was: "Hello, World!"
+  expected: "Hello, Rust!"
+"#]
+    );
+}
+
+#[test]
+fn some_vec_of_bytes_has_utf8_content() {
+    let subject: Option<Vec<u8>> = Some(b"Hello, World!".to_vec());
+
+    assert_that(subject)
+        .some()
+        .has_utf8_content("Hello, World!")
+        .as_utf8_text()
+        .ends_with('!');
+}
+
+#[test]
+fn vec_of_bytes_is_equal_to_bytes() {
+    let subject: Vec<u8> = vec![0xca, 0xfe, 0xba, 0xbe];
+
+    assert_that(subject).is_equal_to_bytes([0xca, 0xfe, 0xba, 0xbe]);
+}
+
+#[test]
+fn slice_of_bytes_starts_with_bytes() {
+    let subject: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    assert_that(subject)
+        .starts_with_bytes(b"\x89PNG")
+        .starts_with_bytes([])
+        .starts_with_bytes(subject);
+}
+
+#[test]
+fn slice_of_bytes_contains_subsequence() {
+    let subject: &[u8] = &[0x00, 0x01, 0x02, 0x03, 0x04];
+
+    assert_that(subject)
+        .contains_subsequence([0x02, 0x03])
+        .contains_subsequence([0x00])
+        .contains_subsequence([])
+        .contains_subsequence(subject);
+}
+
+#[test]
+fn verify_slice_of_bytes_is_equal_to_bytes_fails() {
+    let subject: &[u8] = b"Hello, World!";
+
+    let failures = verify_that(subject)
+        .named("data")
+        .is_equal_to_bytes(b"Hello, Rust!")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected data to be equal to the expected bytes
+   but was: 13 bytes
+  expected: 12 bytes
+  offset    actual                               expected
+  00000000  48 65 6c 6c 6f 2c 20 57  |Hello, W|  48 65 6c 6c 6f 2c 20 52  |Hello, R|
+  00000008  6f 72 6c 64 21           |orld!   |  75 73 74 21              |ust!    |
+"]
+    );
+}
+
+#[test]
+fn verify_large_vec_of_bytes_is_equal_to_bytes_fails_and_elides_equal_rows() {
+    let subject: Vec<u8> = (0..=255).collect();
+    let mut expected = subject.clone();
+    expected[100] = 0xff;
+
+    let failures = verify_that(subject)
+        .named("data")
+        .is_equal_to_bytes(expected)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected data to be equal to the expected bytes
+   but was: 256 bytes
+  expected: 256 bytes
+  offset    actual                               expected
+  … 11 more rows …
+  00000058  58 59 5a 5b 5c 5d 5e 5f  |XYZ[\]^_|  58 59 5a 5b 5c 5d 5e 5f  |XYZ[\]^_|
+  00000060  60 61 62 63 64 65 66 67  |`abcdefg|  60 61 62 63 ff 65 66 67  |`abc.efg|
+  00000068  68 69 6a 6b 6c 6d 6e 6f  |hijklmno|  68 69 6a 6b 6c 6d 6e 6f  |hijklmno|
+  … 18 more rows …
+"]
+    );
+}
+
+#[test]
+fn verify_slice_of_bytes_starts_with_bytes_fails() {
+    let subject: &[u8] = &[0x00, 0x01, 0x02, 0x03, 0x04];
+
+    let failures = verify_that(subject)
+        .named("data")
+        .starts_with_bytes([0x00, 0x02])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected data to start with the expected bytes
+   but was: 5 bytes
+  expected: 2 bytes
+  offset    actual                               expected
+  00000000  00 01 02 03 04           |.....   |  00 02                    |..      |
+"]
+    );
+}
+
+#[test]
+fn verify_slice_of_bytes_contains_subsequence_fails() {
+    let subject: &[u8] = &[0x00, 0x01, 0x02, 0x03, 0x04];
+
+    let failures = verify_that(subject)
+        .named("data")
+        .contains_subsequence([0x01, 0x03])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected data to contain the expected subsequence of bytes
+   but was: 5 bytes
+  expected: 2 bytes
+  closest match at offset 00000001 with 1 of 2 bytes equal:
+  00000000  00 01 02 03 04           |.....   |
+  expected subsequence:
+  00000000  01 03                    |..      |
+"]
+    );
+}
+
+#[test]
+fn verify_large_vec_of_bytes_contains_subsequence_fails_and_shows_excerpt_around_closest_match() {
+    let subject: Vec<u8> = (0..=255).collect();
+
+    let failures = verify_that(subject)
+        .named("data")
+        .contains_subsequence([0x61, 0x62, 0x00, 0x64])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected data to contain the expected subsequence of bytes
+   but was: 256 bytes
+  expected: 4 bytes
+  closest match at offset 00000061 with 3 of 4 bytes equal:
+  … 11 more rows …
+  00000058  58 59 5a 5b 5c 5d 5e 5f  |XYZ[\]^_|
+  00000060  60 61 62 63 64 65 66 67  |`abcdefg|
+  00000068  68 69 6a 6b 6c 6d 6e 6f  |hijklmno|
+  … 18 more rows …
+  expected subsequence:
+  00000000  61 62 00 64              |ab.d    |
+"]
+    );
+}
+
+#[test]
+fn verify_slice_of_bytes_contains_subsequence_longer_than_the_subject_fails() {
+    let subject: &[u8] = &[0x01, 0x02];
+
+    let failures = verify_that(subject)
+        .named("data")
+        .contains_subsequence([0x01, 0x02, 0x03])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected data to contain the expected subsequence of bytes
+   but was: 2 bytes
+  expected: 3 bytes
+  closest match at offset 00000000 with 2 of 3 bytes equal:
+  00000000  01 02                    |..      |
+  expected subsequence:
+  00000000  01 02 03                 |...     |
+"]
+    );
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use super::*;
+
+    #[test]
+    fn highlight_diffs_slice_of_bytes_contains_subsequence() {
+        let subject: &[u8] = &[0x00, 0x01, 0x02, 0x03, 0x04];
+
+        let failures = verify_that(subject)
+            .named("data")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .contains_subsequence([0x01, 0x03])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected data to contain the expected subsequence of bytes\n   \
+                but was: 5 bytes\n  \
+               expected: 2 bytes\n  \
+               closest match at offset 00000001 with 1 of 2 bytes equal:\n  \
+               00000000  00 01 \u{1b}[31m02\u{1b}[0m 03 04           |..\u{1b}[31m.\u{1b}[0m..   |\n  \
+               expected subsequence:\n  \
+               00000000  01 \u{1b}[34m03\u{1b}[0m                    |.\u{1b}[34m.\u{1b}[0m      |\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_slice_of_bytes_is_equal_to_bytes() {
+        let subject: &[u8] = &[0xca, 0xfe, 0xba, 0xbe];
+
+        let failures = verify_that(subject)
+            .named("data")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_equal_to_bytes([0xca, 0xfe, 0xba, 0xbf])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected data to be equal to the expected bytes\n   \
+                but was: 4 bytes\n  \
+               expected: 4 bytes\n  \
+               offset    actual                               expected\n  \
+               00000000  ca fe ba \u{1b}[31mbe\u{1b}[0m              |...\u{1b}[31m.\u{1b}[0m    |  \
+               ca fe ba \u{1b}[34mbf\u{1b}[0m              |...\u{1b}[34m.\u{1b}[0m    |\n\
+            "]
+        );
+    }
+}
@@ -1,6 +1,11 @@
 //! Implementation of assertions for `CString` and `CStr` values.
+//!
+//! The length of a C string is the number of bytes without the trailing nul
+//! byte. The character count is determined on the lossy UTF-8 conversion of
+//! the string, where each invalid sequence counts as one replacement
+//! character.
 
-use crate::properties::{IsEmptyProperty, LengthProperty};
+use crate::properties::{CharCountProperty, IsEmptyProperty, LengthProperty};
 use crate::std::ffi::{CStr, CString};
 
 impl IsEmptyProperty for CString {
@@ -27,5 +32,17 @@ impl LengthProperty for &CStr {
     }
 }
 
+impl CharCountProperty for CString {
+    fn char_count_property(&self) -> usize {
+        self.to_string_lossy().chars().count()
+    }
+}
+
+impl CharCountProperty for &CStr {
+    fn char_count_property(&self) -> usize {
+        self.to_string_lossy().chars().count()
+    }
+}
+
 #[cfg(test)]
 mod tests;
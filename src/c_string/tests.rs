@@ -48,3 +48,19 @@ fn c_string_has_length() {
 
     assert_that(subject).has_length(27);
 }
+
+#[test]
+fn c_str_has_char_count() {
+    let subject: &CStr = CStr::from_bytes_until_nul("Grüße\0".as_bytes())
+        .unwrap_or_else(|err| panic!("could not create CStr: {err}"));
+
+    assert_that(subject).has_length(7).has_char_count(5);
+}
+
+#[test]
+fn c_string_with_invalid_utf8_has_char_count() {
+    let subject: CString =
+        CString::new(b"ab\xffcd").unwrap_or_else(|err| panic!("could not create a CString: {err}"));
+
+    assert_that(subject).has_length(5).has_char_count(5);
+}
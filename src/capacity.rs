@@ -0,0 +1,128 @@
+//! Implementations of the capacity assertions.
+
+use crate::assertions::AssertHasCapacity;
+use crate::colored::{mark_missing, mark_unexpected};
+use crate::expectations::{
+    HasCapacity, HasCapacityAtLeast, HasSpareCapacity, has_capacity, has_capacity_at_least,
+    has_spare_capacity_of,
+};
+use crate::properties::{CapacityProperty, LengthProperty};
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::fmt::Debug;
+use crate::std::{format, string::String};
+
+impl<S, R> AssertHasCapacity for Spec<'_, S, R>
+where
+    S: CapacityProperty + LengthProperty + Debug,
+    R: FailingStrategy,
+{
+    fn has_capacity(self, expected_capacity: usize) -> Self {
+        self.expecting(has_capacity(expected_capacity))
+    }
+
+    fn has_capacity_at_least(self, expected_capacity: usize) -> Self {
+        self.expecting(has_capacity_at_least(expected_capacity))
+    }
+
+    fn has_spare_capacity_of(self, expected_spare_capacity: usize) -> Self {
+        self.expecting(has_spare_capacity_of(expected_spare_capacity))
+    }
+}
+
+impl<S> Expectation<S> for HasCapacity
+where
+    S: CapacityProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_capacity";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.capacity_property() == self.expected_capacity
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let marked_actual = mark_unexpected(&actual.capacity_property(), format);
+        let marked_expected = mark_missing(&self.expected_capacity, format);
+        format!(
+            "expected {expression} to {not}have a capacity of {}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}",
+            self.expected_capacity,
+        )
+    }
+}
+
+impl Invertible for HasCapacity {}
+
+impl<S> Expectation<S> for HasCapacityAtLeast
+where
+    S: CapacityProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_capacity_at_least";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.capacity_property() >= self.expected_capacity
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let (not, cmp) = if inverted { ("not ", "<") } else { ("", ">=") };
+        let marked_actual = mark_unexpected(&actual.capacity_property(), format);
+        let marked_expected = mark_missing(&self.expected_capacity, format);
+        format!(
+            "expected {expression} to {not}have a capacity of at least {}\n   but was: {marked_actual}\n  expected: {cmp} {marked_expected}",
+            self.expected_capacity,
+        )
+    }
+}
+
+impl Invertible for HasCapacityAtLeast {}
+
+impl<S> Expectation<S> for HasSpareCapacity
+where
+    S: CapacityProperty + LengthProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_spare_capacity_of";
+
+    fn test(&mut self, subject: &S) -> bool {
+        spare_capacity(subject) == self.expected_spare_capacity
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let marked_actual = mark_unexpected(&spare_capacity(actual), format);
+        let marked_expected = mark_missing(&self.expected_spare_capacity, format);
+        format!(
+            "expected {expression} to {not}have a spare capacity of {}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}",
+            self.expected_spare_capacity,
+        )
+    }
+}
+
+impl Invertible for HasSpareCapacity {}
+
+fn spare_capacity<S>(subject: &S) -> usize
+where
+    S: CapacityProperty + LengthProperty,
+{
+    subject
+        .capacity_property()
+        .saturating_sub(subject.length_property())
+}
@@ -86,6 +86,8 @@ where
 }
 
 impl Expectation<char> for IsLowerCase {
+    const ASSERTION_ID: &'static str = "asserting::is_lowercase";
+
     fn test(&mut self, subject: &char) -> bool {
         subject.is_lowercase()
     }
@@ -113,6 +115,8 @@ impl Expectation<char> for IsLowerCase {
 impl Invertible for IsLowerCase {}
 
 impl Expectation<&char> for IsLowerCase {
+    const ASSERTION_ID: &'static str = "asserting::is_lowercase";
+
     fn test(&mut self, subject: &&char) -> bool {
         <Self as Expectation<char>>::test(self, subject)
     }
@@ -129,6 +133,8 @@ impl Expectation<&char> for IsLowerCase {
 }
 
 impl Expectation<char> for IsUpperCase {
+    const ASSERTION_ID: &'static str = "asserting::is_uppercase";
+
     fn test(&mut self, subject: &char) -> bool {
         subject.is_uppercase()
     }
@@ -156,6 +162,8 @@ impl Expectation<char> for IsUpperCase {
 impl Invertible for IsUpperCase {}
 
 impl Expectation<&char> for IsUpperCase {
+    const ASSERTION_ID: &'static str = "asserting::is_uppercase";
+
     fn test(&mut self, subject: &&char) -> bool {
         <Self as Expectation<char>>::test(self, subject)
     }
@@ -172,6 +180,8 @@ impl Expectation<&char> for IsUpperCase {
 }
 
 impl Expectation<char> for IsAscii {
+    const ASSERTION_ID: &'static str = "asserting::is_ascii";
+
     fn test(&mut self, subject: &char) -> bool {
         subject.is_ascii()
     }
@@ -194,6 +204,8 @@ impl Expectation<char> for IsAscii {
 impl Invertible for IsAscii {}
 
 impl Expectation<&char> for IsAscii {
+    const ASSERTION_ID: &'static str = "asserting::is_ascii";
+
     fn test(&mut self, subject: &&char) -> bool {
         <Self as Expectation<char>>::test(self, subject)
     }
@@ -210,6 +222,8 @@ impl Expectation<&char> for IsAscii {
 }
 
 impl Expectation<char> for IsAlphabetic {
+    const ASSERTION_ID: &'static str = "asserting::is_alphabetic";
+
     fn test(&mut self, subject: &char) -> bool {
         subject.is_alphabetic()
     }
@@ -232,6 +246,8 @@ impl Expectation<char> for IsAlphabetic {
 impl Invertible for IsAlphabetic {}
 
 impl Expectation<&char> for IsAlphabetic {
+    const ASSERTION_ID: &'static str = "asserting::is_alphabetic";
+
     fn test(&mut self, subject: &&char) -> bool {
         <Self as Expectation<char>>::test(self, subject)
     }
@@ -248,6 +264,8 @@ impl Expectation<&char> for IsAlphabetic {
 }
 
 impl Expectation<char> for IsAlphanumeric {
+    const ASSERTION_ID: &'static str = "asserting::is_alphanumeric";
+
     fn test(&mut self, subject: &char) -> bool {
         subject.is_alphanumeric()
     }
@@ -270,6 +288,8 @@ impl Expectation<char> for IsAlphanumeric {
 impl Invertible for IsAlphanumeric {}
 
 impl Expectation<&char> for IsAlphanumeric {
+    const ASSERTION_ID: &'static str = "asserting::is_alphanumeric";
+
     fn test(&mut self, subject: &&char) -> bool {
         <Self as Expectation<char>>::test(self, subject)
     }
@@ -286,6 +306,8 @@ impl Expectation<&char> for IsAlphanumeric {
 }
 
 impl Expectation<char> for IsControlChar {
+    const ASSERTION_ID: &'static str = "asserting::is_control_char";
+
     fn test(&mut self, subject: &char) -> bool {
         subject.is_control()
     }
@@ -308,6 +330,8 @@ impl Expectation<char> for IsControlChar {
 impl Invertible for IsControlChar {}
 
 impl Expectation<&char> for IsControlChar {
+    const ASSERTION_ID: &'static str = "asserting::is_control_char";
+
     fn test(&mut self, subject: &&char) -> bool {
         <Self as Expectation<char>>::test(self, subject)
     }
@@ -324,6 +348,8 @@ impl Expectation<&char> for IsControlChar {
 }
 
 impl Expectation<char> for IsDigit {
+    const ASSERTION_ID: &'static str = "asserting::is_digit";
+
     fn test(&mut self, subject: &char) -> bool {
         subject.is_digit(self.radix)
     }
@@ -347,6 +373,8 @@ impl Expectation<char> for IsDigit {
 impl Invertible for IsDigit {}
 
 impl Expectation<&char> for IsDigit {
+    const ASSERTION_ID: &'static str = "asserting::is_digit";
+
     fn test(&mut self, subject: &&char) -> bool {
         <Self as Expectation<char>>::test(self, subject)
     }
@@ -363,6 +391,8 @@ impl Expectation<&char> for IsDigit {
 }
 
 impl Expectation<char> for IsWhitespace {
+    const ASSERTION_ID: &'static str = "asserting::is_whitespace";
+
     fn test(&mut self, subject: &char) -> bool {
         subject.is_whitespace()
     }
@@ -385,6 +415,8 @@ impl Expectation<char> for IsWhitespace {
 impl Invertible for IsWhitespace {}
 
 impl Expectation<&char> for IsWhitespace {
+    const ASSERTION_ID: &'static str = "asserting::is_whitespace";
+
     fn test(&mut self, subject: &&char) -> bool {
         <Self as Expectation<char>>::test(self, subject)
     }
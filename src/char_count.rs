@@ -51,6 +51,8 @@ impl<S> Expectation<S> for HasCharCount<usize>
 where
     S: CharCountProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_char_count";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.char_count_property() == self.expected_char_count
     }
@@ -77,6 +79,8 @@ where
     S: CharCountProperty + Debug,
     R: RangeBounds<usize> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_char_count_in_range";
+
     fn test(&mut self, subject: &S) -> bool {
         self.expected_range.contains(&subject.char_count_property())
     }
@@ -102,6 +106,8 @@ impl<S> Expectation<S> for HasCharCountLessThan<usize>
 where
     S: CharCountProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_char_count_less_than";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.char_count_property() < self.expected_char_count
     }
@@ -127,6 +133,8 @@ impl<S> Expectation<S> for HasCharCountGreaterThan<usize>
 where
     S: CharCountProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_char_count_greater_than";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.char_count_property() > self.expected_char_count
     }
@@ -152,6 +160,8 @@ impl<S> Expectation<S> for HasAtMostCharCount<usize>
 where
     S: CharCountProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_at_most_char_count";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.char_count_property() <= self.expected_char_count
     }
@@ -177,6 +187,8 @@ impl<S> Expectation<S> for HasAtLeastCharCount<usize>
 where
     S: CharCountProperty,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_at_least_char_count";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.char_count_property() >= self.expected_char_count
     }
@@ -0,0 +1,72 @@
+//! Implementation of the [`DateTimeProperties`] for the date and date-time
+//! types of the `chrono` crate.
+
+use crate::properties::DateTimeProperties;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+
+fn date_of(value: &impl Datelike) -> (i32, u8, u8) {
+    #[allow(clippy::cast_possible_truncation)]
+    (value.year(), value.month() as u8, value.day() as u8)
+}
+
+fn time_of_day_of(value: &impl Timelike) -> (u8, u8, u8, u32) {
+    #[allow(clippy::cast_possible_truncation)]
+    (
+        value.hour() as u8,
+        value.minute() as u8,
+        value.second() as u8,
+        value.nanosecond(),
+    )
+}
+
+fn unix_timestamp_nanos_of<Tz: TimeZone>(value: &DateTime<Tz>) -> i128 {
+    i128::from(value.timestamp()) * 1_000_000_000 + i128::from(value.timestamp_subsec_nanos())
+}
+
+impl DateTimeProperties for NaiveDate {
+    fn date_property(&self) -> (i32, u8, u8) {
+        date_of(self)
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        None
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        unix_timestamp_nanos_of(&self.and_time(NaiveTime::MIN).and_utc())
+    }
+}
+
+impl DateTimeProperties for NaiveDateTime {
+    fn date_property(&self) -> (i32, u8, u8) {
+        date_of(self)
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        Some(time_of_day_of(self))
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        unix_timestamp_nanos_of(&self.and_utc())
+    }
+}
+
+impl<Tz> DateTimeProperties for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn date_property(&self) -> (i32, u8, u8) {
+        date_of(self)
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        Some(time_of_day_of(self))
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        unix_timestamp_nanos_of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,118 @@
+use crate::prelude::*;
+use crate::std::time::Duration;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+
+fn naive_date(value: &str) -> NaiveDate {
+    value
+        .parse()
+        .unwrap_or_else(|err| panic!("invalid date {value:?}: {err:?}"))
+}
+
+fn naive_date_time(value: &str) -> NaiveDateTime {
+    value
+        .parse()
+        .unwrap_or_else(|err| panic!("invalid date-time {value:?}: {err:?}"))
+}
+
+fn date_time(value: &str) -> DateTime<FixedOffset> {
+    DateTime::parse_from_rfc3339(value)
+        .unwrap_or_else(|err| panic!("invalid date-time {value:?}: {err:?}"))
+}
+
+#[test]
+fn naive_date_properties() {
+    let subject = naive_date("2024-03-15");
+
+    assert_eq!(subject.date_property(), (2024, 3, 15));
+    assert_eq!(subject.time_of_day_property(), None);
+    assert_eq!(
+        subject.unix_timestamp_nanos_property(),
+        1_710_460_800_000_000_000
+    );
+}
+
+#[test]
+fn naive_date_time_properties() {
+    let subject = naive_date_time("2024-03-15T10:30:15.25");
+
+    assert_eq!(subject.date_property(), (2024, 3, 15));
+    assert_eq!(
+        subject.time_of_day_property(),
+        Some((10, 30, 15, 250_000_000))
+    );
+    assert_eq!(
+        subject.unix_timestamp_nanos_property(),
+        1_710_498_615_250_000_000
+    );
+}
+
+#[test]
+fn date_time_with_offset_properties() {
+    let subject = date_time("2024-03-15T10:30:15+01:00");
+
+    assert_eq!(subject.date_property(), (2024, 3, 15));
+    assert_eq!(subject.time_of_day_property(), Some((10, 30, 15, 0)));
+    assert_eq!(
+        subject.unix_timestamp_nanos_property(),
+        1_710_495_015_000_000_000
+    );
+}
+
+#[test]
+fn chrono_date_assertions() {
+    let released_on = naive_date("2024-03-15");
+    let published_at = date_time("2024-03-15T10:30:00+01:00");
+
+    assert_that(released_on)
+        .is_before_date(naive_date("2024-03-16"))
+        .is_after_date(naive_date_time("2024-03-14T23:59:59"))
+        .is_same_day_as(published_at)
+        .is_in_year(2024);
+
+    assert_that(published_at)
+        .is_after_date(released_on)
+        .is_within_duration_of(
+            naive_date_time("2024-03-15T09:30:02").and_utc(),
+            Duration::from_secs(2),
+        );
+}
+
+#[test]
+fn verify_chrono_date_time_is_within_duration_of_other_fails() {
+    let failures = verify_that(date_time("2024-03-15T10:30:00+01:00"))
+        .named("published_at")
+        .is_within_duration_of(
+            date_time("2024-03-15T10:30:00+02:00"),
+            Duration::from_secs(60),
+        )
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected published_at to be within 60s of 2024-03-15 10:30:00
+   but was: 2024-03-15 10:30:00
+  expected: 2024-03-15 10:30:00
+difference: 3600s
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_chrono_naive_date_is_same_day_as_fails() {
+    let failures = verify_that(naive_date("2024-03-15"))
+        .named("released_on")
+        .is_same_day_as(naive_date_time("2025-03-16T08:00:00"))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected released_on to be on the same day as 2025-03-16 08:00:00
+   but was: 2024-03-15
+  expected: 2025-03-16 08:00:00
+"
+        ]
+    );
+}
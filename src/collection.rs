@@ -1,6 +1,7 @@
 //! Implementation of assertions for collections and iterators.
 
 use crate::properties::{DefinedOrderProperty, IsEmptyProperty, LengthProperty};
+use crate::std::boxed::Box;
 use crate::std::collections::{
     BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque, btree_map, btree_set, linked_list,
     vec_deque,
@@ -29,6 +30,20 @@ impl<T> DefinedOrderProperty for vec_deque::IntoIter<T> {}
 impl<T> DefinedOrderProperty for vec_deque::Iter<'_, T> {}
 impl<T> DefinedOrderProperty for vec_deque::IterMut<'_, T> {}
 
+impl<I> DefinedOrderProperty for Box<I> where I: DefinedOrderProperty + ?Sized {}
+
+// Iterator trait objects hide the concrete iterator type. We treat the sequence
+// in which they yield their elements as well-defined order.
+impl<T> DefinedOrderProperty for dyn Iterator<Item = T> + '_ {}
+impl<T> DefinedOrderProperty for dyn Iterator<Item = T> + Send + '_ {}
+impl<T> DefinedOrderProperty for dyn Iterator<Item = T> + Send + Sync + '_ {}
+impl<T> DefinedOrderProperty for dyn DoubleEndedIterator<Item = T> + '_ {}
+impl<T> DefinedOrderProperty for dyn DoubleEndedIterator<Item = T> + Send + '_ {}
+impl<T> DefinedOrderProperty for dyn DoubleEndedIterator<Item = T> + Send + Sync + '_ {}
+impl<T> DefinedOrderProperty for dyn ExactSizeIterator<Item = T> + '_ {}
+impl<T> DefinedOrderProperty for dyn ExactSizeIterator<Item = T> + Send + '_ {}
+impl<T> DefinedOrderProperty for dyn ExactSizeIterator<Item = T> + Send + Sync + '_ {}
+
 impl<T> IsEmptyProperty for BinaryHeap<T> {
     fn is_empty_property(&self) -> bool {
         self.is_empty()
@@ -97,7 +112,7 @@ impl<T, const N: usize> IsEmptyProperty for [T; N] {
 
 #[cfg(feature = "std")]
 mod std {
-    use crate::properties::{IsEmptyProperty, LengthProperty};
+    use crate::properties::{CapacityProperty, IsEmptyProperty, LengthProperty};
     use crate::std::collections::{HashMap, HashSet};
 
     impl<K, V, S> IsEmptyProperty for HashMap<K, V, S> {
@@ -123,10 +138,16 @@ mod std {
             self.len()
         }
     }
+
+    impl<K, V, S> CapacityProperty for HashMap<K, V, S> {
+        fn capacity_property(&self) -> usize {
+            self.capacity()
+        }
+    }
 }
 
 mod hashbrown {
-    use crate::properties::{IsEmptyProperty, LengthProperty};
+    use crate::properties::{CapacityProperty, IsEmptyProperty, LengthProperty};
     use hashbrown::{HashMap, HashSet};
 
     impl<K, V, S> IsEmptyProperty for HashMap<K, V, S> {
@@ -158,4 +179,10 @@ mod hashbrown {
             self.len()
         }
     }
+
+    impl<K, V, S> CapacityProperty for HashMap<K, V, S> {
+        fn capacity_property(&self) -> usize {
+            self.capacity()
+        }
+    }
 }
@@ -39,22 +39,93 @@
 //! is set to a non-empty string, no colors are used, regardless of the mode set
 //! with the `ASSERTING_HIGHLIGHT_DIFFS` environment variable.
 //!
+//! Highlighting is switched off automatically if stderr is not a terminal,
+//! e.g., when the test output is piped into a file, or if the environment
+//! variable [`CLICOLOR`] is set to `0`. Setting the environment variable
+//! `CLICOLOR_FORCE` to a value other than `0` enables highlighting regardless
+//! of whether stderr is a terminal. Users that capture the output themselves
+//! can replace the terminal detection with the function
+//! [`set_terminal_detector`] (requires crate feature `std`).
+//!
+//! Some IDE test consoles do not support ANSI escape sequences. For them, the
+//! highlight mode `plain` marks differences with plain text markers, like
+//! `»unexpected«` and `«missing»`. As it does not write any ANSI escape
+//! sequences, this mode is neither affected by `NO_COLOR` nor by the terminal
+//! detection. For tools that display rich output, the highlight mode `html`
+//! renders each failure as an HTML fragment (see [`DIFF_FORMAT_HTML`]).
+//!
+//! Appending the suffix `+vertical` to the highlight mode, like
+//! `red-blue+vertical`, prints the actual and the expected value on their own
+//! lines, marked with `<` and `>` in the left gutter. See
+//! [`DiffFormat::with_vertical_layout()`](crate::spec::DiffFormat::with_vertical_layout)
+//! for details.
+//!
+//! Differences are highlighted character by character by default. For prose
+//! or multi-line strings, highlighting whole words or lines is often easier to
+//! read. The granularity can be configured via the environment variable
+//! `ASSERTING_DIFF_GRANULARITY`, which can be set to `char`, `word` or `line`.
+//! See the documentation of the function [`diff_granularity_for_name`] for
+//! details. Alternatively, the granularity can be set for a single assertion
+//! with [`DiffFormat::with_granularity()`](crate::spec::DiffFormat::with_granularity).
+//!
+//! Log scrapers and analyzers of flaky tests can categorize failures more
+//! easily if each failure message starts with a stable identifier of the
+//! failed assertion. Setting the environment variable `ASSERTING_OUTPUT_FORMAT`
+//! to `tagged` prefixes every failure message with a line like
+//! `[asserting::is_equal_to] expression="answer" subject_type="i32"`. See
+//! [`OutputFormat::Tagged`] for the fields of this line.
+//!
+//! All settings can also be committed to a configuration file named
+//! `asserting.toml` in the root directory of the package or to the
+//! `[package.metadata.asserting]` section of its `Cargo.toml`. The setting
+//! `highlight-diffs` corresponds to `ASSERTING_HIGHLIGHT_DIFFS`,
+//! `diff-granularity` to `ASSERTING_DIFF_GRANULARITY` and `output-format` to
+//! `ASSERTING_OUTPUT_FORMAT`. The settings
+//! `truncation-max-length` and `truncation-context` (or the environment
+//! variables `ASSERTING_TRUNCATION_MAX_LENGTH` and
+//! `ASSERTING_TRUNCATION_CONTEXT`) configure a
+//! [`Truncation`] for large values. The setting
+//! `pretty-debug` (or the environment variable `ASSERTING_PRETTY_DEBUG`) set
+//! to `true` switches on the alternate debug format (`{:#?}`) for values in
+//! failure messages. Environment variables take precedence over the
+//! configuration file.
+//!
+//! On targets without environment variables, like `wasm32-unknown-unknown`
+//! used with `wasm-bindgen-test`, the diff format can be configured
+//! programmatically with the function [`set_configured_diff_format`]
+//! (requires crate feature `std`). A diff format set this way takes precedence
+//! over the environment variables.
+//!
 //! The functions provided by this module help with highlighting missing and
 //! unexpected parts when composing the failure message for an assertion.
 //!
 //! [`NO_COLOR`]: https://no-color.org/
+//! [`CLICOLOR`]: https://bixense.com/clicolors/
 
+#[cfg(all(feature = "colored", feature = "std"))]
+pub(crate) use with_colored_feature::programmatic_diff_format;
 #[cfg(feature = "colored")]
 #[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
 pub use with_colored_feature::{
-    DIFF_FORMAT_BOLD, DIFF_FORMAT_RED_BLUE, DIFF_FORMAT_RED_GREEN, DIFF_FORMAT_RED_YELLOW,
-    diff_format_for_mode,
+    DIFF_FORMAT_BOLD, DIFF_FORMAT_HTML, DIFF_FORMAT_PLAIN, DIFF_FORMAT_RED_BLUE,
+    DIFF_FORMAT_RED_GREEN, DIFF_FORMAT_RED_YELLOW, DiffResult, diff_format_for_mode,
+    diff_granularity_for_name, diff_str, output_format_for_name,
+};
+#[cfg(all(feature = "colored", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "colored", feature = "std"))))]
+pub use with_colored_feature::{
+    reset_configured_diff_format, reset_terminal_detector, set_configured_diff_format,
+    set_terminal_detector,
 };
 
-use crate::spec::{DiffFormat, Highlight};
-use crate::std::fmt::Debug;
+use crate::spec::{
+    DiffFormat, DiffGranularity, Highlight, HighlightKind, HighlightSpan, OutputFormat, Truncation,
+};
+use crate::std::fmt::{Debug, Display, Write};
 use crate::std::format;
+use crate::std::ops::Range;
 use crate::std::string::{String, ToString};
+use crate::std::vec;
 use crate::std::vec::Vec;
 use hashbrown::HashSet;
 #[cfg(feature = "colored")]
@@ -72,12 +143,60 @@ use without_colored_feature::{
 
 const NO_HIGHLIGHT: Highlight = Highlight { start: "", end: "" };
 
+/// Marker put before unexpected parts of the actual value in HTML mode.
+///
+/// The markers of the HTML mode are characters of the Unicode private use
+/// area. They are removed from the failure message by
+/// [`extract_highlight_spans`] and never shown to the user.
+pub(crate) const SPAN_UNEXPECTED_START: &str = "\u{e000}";
+/// Marker put after unexpected parts of the actual value in HTML mode.
+pub(crate) const SPAN_UNEXPECTED_END: &str = "\u{e001}";
+/// Marker put before missing parts of the expected value in HTML mode.
+pub(crate) const SPAN_MISSING_START: &str = "\u{e002}";
+/// Marker put after missing parts of the expected value in HTML mode.
+pub(crate) const SPAN_MISSING_END: &str = "\u{e003}";
+
+/// Removes the markers of the HTML mode from the given failure message.
+///
+/// It returns the message without markers and the spans of the message that
+/// have been highlighted.
+pub(crate) fn extract_highlight_spans(message: &str) -> (String, Vec<HighlightSpan>) {
+    let mut text = String::with_capacity(message.len());
+    let mut spans = Vec::new();
+    let mut open_span: Option<(usize, HighlightKind)> = None;
+    for character in message.chars() {
+        let mut marker = [0; 4];
+        match &*character.encode_utf8(&mut marker) {
+            SPAN_UNEXPECTED_START => open_span = Some((text.len(), HighlightKind::Unexpected)),
+            SPAN_MISSING_START => open_span = Some((text.len(), HighlightKind::Missing)),
+            SPAN_UNEXPECTED_END | SPAN_MISSING_END => {
+                if let Some((start, kind)) = open_span.take() {
+                    if start < text.len() {
+                        spans.push(HighlightSpan {
+                            range: start..text.len(),
+                            kind,
+                        });
+                    }
+                }
+            },
+            _ => text.push(character),
+        }
+    }
+    (text, spans)
+}
+
 /// Diff format that does not highlight anything.
 ///
 /// Setting this format effectively switches off highlighting.
 pub const DIFF_FORMAT_NO_HIGHLIGHT: DiffFormat = DiffFormat {
     unexpected: NO_HIGHLIGHT,
     missing: NO_HIGHLIGHT,
+    granularity: DiffGranularity::Char,
+    truncation: None,
+    pretty_debug: false,
+    display_formatting: false,
+    vertical_layout: false,
+    output_format: OutputFormat::Default,
 };
 
 /// Default diff format.
@@ -115,6 +234,16 @@ pub const DEFAULT_DIFF_FORMAT: DiffFormat = {
 /// and `ASSERTING_HIGHLIGHT_DIFFS` is set to a color-mode, then
 /// [`DIFF_FORMAT_NO_HIGHLIGHT`] is returned, which switches off colors.
 ///
+/// If stderr is not a terminal or the environment variable `CLICOLOR` is set
+/// to `0`, [`DIFF_FORMAT_NO_HIGHLIGHT`] is returned, unless the environment
+/// variable `CLICOLOR_FORCE` is set to a value other than `0`. The detection
+/// whether stderr is a terminal can be replaced with
+/// [`set_terminal_detector`]. This does not apply to the highlight modes
+/// `plain` and `html`, which do not use ANSI escape sequences.
+///
+/// If a diff format has been set with [`set_configured_diff_format`], this diff
+/// format is returned regardless of the environment variables.
+///
 /// When in a no-std environment with the feature `std` not enabled and the
 /// `colored` feature is enabled. The default diff format
 /// [`DEFAULT_DIFF_FORMAT`] is returned.
@@ -193,9 +322,13 @@ where
     S: Debug + ?Sized,
     E: Debug + ?Sized,
 {
-    let actual = format!("{actual:?}");
-    let expected = format!("{expected:?}");
-    mark_diff_impl(&actual, &expected, format)
+    let actual = debug_string(actual, format);
+    let expected = debug_string(expected, format);
+    let (marked_actual, marked_expected) = mark_diff_impl(&actual, &expected, format);
+    (
+        indent_continuation_lines(marked_actual, format),
+        indent_continuation_lines(marked_expected, format),
+    )
 }
 
 /// Highlights differences between the expected and the actual string and
@@ -216,13 +349,57 @@ pub fn mark_diff_str(actual: &str, expected: &str, format: &DiffFormat) -> (Stri
     mark_diff_impl(actual, expected, format)
 }
 
+/// Highlights differences between the expected and the actual value using
+/// their `Display` format and returns new strings with marked differences.
+///
+/// In contrast to [`mark_diff`], the values are formatted using the `Display`
+/// trait instead of the `Debug` trait. Thus, the returned strings do not
+/// contain quotes and escaped characters as they appear in debug formatted
+/// strings.
+///
+/// It returns a tuple of two `String`s. The first string contains the actual
+/// value, and the second one contains the expected value.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "colored"))]
+/// # fn main() {}
+/// # #[cfg(feature = "colored")]
+/// # fn main() {
+/// use asserting::colored::{mark_diff_display, DIFF_FORMAT_RED_GREEN};
+///
+/// let actual = "Hello \"Welt\"!";
+/// let expected = "Hello \"World\"!";
+///
+/// let (marked_actual, marked_expected) = mark_diff_display(&actual, &expected, &DIFF_FORMAT_RED_GREEN);
+///
+/// assert_eq!(marked_actual, "Hello \"W\u{1b}[31me\u{1b}[0ml\u{1b}[31mt\u{1b}[0m\"!");
+/// assert_eq!(marked_expected, "Hello \"W\u{1b}[32mor\u{1b}[0ml\u{1b}[32md\u{1b}[0m\"!");
+/// # }
+/// ```
+pub fn mark_diff_display<S, E>(actual: &S, expected: &E, format: &DiffFormat) -> (String, String)
+where
+    S: Display + ?Sized,
+    E: Display + ?Sized,
+{
+    mark_diff_impl(&actual.to_string(), &expected.to_string(), format)
+}
+
 /// Highlights the given value as "unexpected value" using the color for
 /// unexpected values or bold as specified by the given [`DiffFormat`].
 pub fn mark_unexpected<T>(value: &T, format: &DiffFormat) -> String
 where
     T: Debug + ?Sized,
 {
-    mark_unexpected_impl(value, format)
+    if format.pretty_debug {
+        let marked = mark_lines(&format!("{value:#?}"), |line| {
+            mark_unexpected_string_impl(line, format)
+        });
+        indent_continuation_lines(marked, format)
+    } else {
+        mark_unexpected_impl(value, format)
+    }
 }
 
 /// Highlights the given value as "missing value" using the color for
@@ -231,7 +408,14 @@ pub fn mark_missing<T>(value: &T, format: &DiffFormat) -> String
 where
     T: Debug + ?Sized,
 {
-    mark_missing_impl(value, format)
+    if format.pretty_debug {
+        let marked = mark_lines(&format!("{value:#?}"), |line| {
+            mark_missing_string_impl(line, format)
+        });
+        indent_continuation_lines(marked, format)
+    } else {
+        mark_missing_impl(value, format)
+    }
 }
 
 /// Highlights the given string as "unexpected value" using the color for
@@ -588,28 +772,24 @@ where
     T: Debug,
     F: Fn(&T, &DiffFormat) -> String,
 {
-    let mut marked_collection = String::with_capacity(collection.len() + 2);
-    marked_collection.push('[');
-    collection
-        .iter()
-        .enumerate()
-        .map(|(index, item)| {
-            if selected_indices.contains(&index) {
+    let highlighted = (0..collection.len())
+        .map(|index| selected_indices.contains(&index))
+        .collect::<Vec<_>>();
+    let marked_items = join_truncated(
+        &highlighted,
+        format.truncation,
+        ", ",
+        |index| {
+            let item = &collection[index];
+            if highlighted[index] {
                 mark(item, format)
             } else {
                 format!("{item:?}")
             }
-        })
-        .for_each(|item| {
-            marked_collection.push_str(&item);
-            marked_collection.push_str(", ");
-        });
-    if marked_collection.len() >= 3 {
-        marked_collection.pop();
-        marked_collection.pop();
-    }
-    marked_collection.push(']');
-    marked_collection
+        },
+        |elided| format!("… {} more items …", elided.len()),
+    );
+    format!("[{marked_items}]")
 }
 
 /// Highlights all items of a collection using the given [`DiffFormat`].
@@ -646,21 +826,15 @@ where
     T: Debug,
     F: Fn(&T, &DiffFormat) -> String,
 {
-    let mut marked_collection = String::with_capacity(collection.len() + 2);
-    marked_collection.push('[');
-    collection
-        .iter()
-        .map(|item| mark(item, format))
-        .for_each(|item| {
-            marked_collection.push_str(&item);
-            marked_collection.push_str(", ");
-        });
-    if marked_collection.len() >= 3 {
-        marked_collection.pop();
-        marked_collection.pop();
-    }
-    marked_collection.push(']');
-    marked_collection
+    let highlighted = vec![true; collection.len()];
+    let marked_items = join_truncated(
+        &highlighted,
+        format.truncation,
+        ", ",
+        |index| mark(&collection[index], format),
+        |elided| format!("… {} more items …", elided.len()),
+    );
+    format!("[{marked_items}]")
 }
 
 /// Highlights selected entries in a map using the given [`DiffFormat`].
@@ -707,29 +881,25 @@ where
     V: Debug,
     F: Fn(&str, &DiffFormat) -> String,
 {
-    let mut marked_map_entries = String::with_capacity(map_entries.len() + 2);
-    marked_map_entries.push('{');
-    map_entries
-        .iter()
-        .enumerate()
-        .map(|(index, entry)| {
+    let highlighted = (0..map_entries.len())
+        .map(|index| selected_indices.contains(&index))
+        .collect::<Vec<_>>();
+    let marked_entries = join_truncated(
+        &highlighted,
+        format.truncation,
+        ", ",
+        |index| {
+            let entry = &map_entries[index];
             let key_value_pair = format!("{:?}: {:?}", entry.0, entry.1);
-            if selected_indices.contains(&index) {
+            if highlighted[index] {
                 mark(&key_value_pair, format)
             } else {
                 key_value_pair
             }
-        })
-        .for_each(|entry| {
-            marked_map_entries.push_str(&entry);
-            marked_map_entries.push_str(", ");
-        });
-    if marked_map_entries.len() >= 3 {
-        marked_map_entries.pop();
-        marked_map_entries.pop();
-    }
-    marked_map_entries.push('}');
-    marked_map_entries
+        },
+        |elided| format!("… {} more entries …", elided.len()),
+    );
+    format!("{{{marked_entries}}}")
 }
 
 /// Highlights all entries in a map using the given [`DiffFormat`].
@@ -772,34 +942,290 @@ where
     V: Debug,
     F: Fn(&str, &DiffFormat) -> String,
 {
-    let mut marked_map_entries = String::with_capacity(map_entries.len() + 2);
-    marked_map_entries.push('{');
-    map_entries
-        .iter()
-        .map(|entry| {
-            let key_value_pair = format!("{:?}: {:?}", entry.0, entry.1);
-            mark(&key_value_pair, format)
+    let highlighted = vec![true; map_entries.len()];
+    let marked_entries = join_truncated(
+        &highlighted,
+        format.truncation,
+        ", ",
+        |index| {
+            let entry = &map_entries[index];
+            mark(&format!("{:?}: {:?}", entry.0, entry.1), format)
+        },
+        |elided| format!("… {} more entries …", elided.len()),
+    );
+    format!("{{{marked_entries}}}")
+}
+
+/// A range of items of a value that is either kept or elided when truncating
+/// the value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Keep(Range<usize>),
+    Elide(Range<usize>),
+}
+
+/// Determines which items of a value are kept and which are elided according
+/// to the given [`Truncation`].
+///
+/// The `highlighted` slice contains a flag for each item of the value, which
+/// specifies whether the item is highlighted. A single item between kept items
+/// is never elided, as the marker would not be shorter than the item itself.
+fn truncate(highlighted: &[bool], truncation: Option<Truncation>) -> Vec<Segment> {
+    let length = highlighted.len();
+    let Some(truncation) = truncation.filter(|truncation| length > truncation.max_length()) else {
+        return vec![Segment::Keep(0..length)];
+    };
+    let context = truncation.context();
+    // if all items are highlighted, only the first and the last items are kept
+    let all_highlighted = highlighted.iter().all(|is_highlighted| *is_highlighted);
+    let mut keep = (0..length)
+        .map(|index| index < context || index + context >= length)
+        .collect::<Vec<_>>();
+    if !all_highlighted {
+        let mut distance = usize::MAX;
+        for (keep_item, is_highlighted) in keep.iter_mut().zip(highlighted) {
+            distance = if *is_highlighted {
+                0
+            } else {
+                distance.saturating_add(1)
+            };
+            *keep_item |= distance <= context;
+        }
+        let mut distance = usize::MAX;
+        for (keep_item, is_highlighted) in keep.iter_mut().zip(highlighted).rev() {
+            distance = if *is_highlighted {
+                0
+            } else {
+                distance.saturating_add(1)
+            };
+            *keep_item |= distance <= context;
+        }
+    }
+    for index in 1..length.saturating_sub(1) {
+        if keep[index - 1] && keep[index + 1] {
+            keep[index] = true;
+        }
+    }
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for index in 1..=length {
+        if index == length || keep[index] != keep[start] {
+            segments.push(if keep[start] {
+                Segment::Keep(start..index)
+            } else {
+                Segment::Elide(start..index)
+            });
+            start = index;
+        }
+    }
+    segments
+}
+
+/// Formats the items of a value and joins them with the given separator,
+/// eliding items according to the given [`Truncation`].
+///
+/// The function `item` formats the item at the given index. The function
+/// `elided` formats the marker that replaces the given range of elided items.
+fn join_truncated<I, E>(
+    highlighted: &[bool],
+    truncation: Option<Truncation>,
+    separator: &str,
+    item: I,
+    elided: E,
+) -> String
+where
+    I: Fn(usize) -> String,
+    E: Fn(Range<usize>) -> String,
+{
+    truncate(highlighted, truncation)
+        .into_iter()
+        .flat_map(|segment| match segment {
+            Segment::Keep(range) => range.map(&item).collect::<Vec<_>>(),
+            Segment::Elide(range) => vec![elided(range)],
         })
-        .for_each(|entry| {
-            marked_map_entries.push_str(&entry);
-            marked_map_entries.push_str(", ");
-        });
-    if marked_map_entries.len() >= 3 {
-        marked_map_entries.pop();
-        marked_map_entries.pop();
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Joins the given parts of a formatted string, eliding parts according to
+/// the given [`Truncation`].
+///
+/// Each part is a string and a flag whether the part differs from the other
+/// string. Consecutive differing parts that are not elided are joined and
+/// marked as a whole with the function `mark`.
+fn join_truncated_str<M>(
+    parts: &[(String, bool)],
+    truncation: Option<Truncation>,
+    mark: M,
+) -> String
+where
+    M: Fn(&str) -> String,
+{
+    let highlighted = parts
+        .iter()
+        .map(|(_, is_highlighted)| *is_highlighted)
+        .collect::<Vec<_>>();
+    let mut joined = String::new();
+    for segment in truncate(&highlighted, truncation) {
+        match segment {
+            Segment::Keep(range) => {
+                let mut start = range.start;
+                while start < range.end {
+                    let differs = highlighted[start];
+                    let end = (start..range.end)
+                        .find(|index| highlighted[*index] != differs)
+                        .unwrap_or(range.end);
+                    let text = parts[start..end]
+                        .iter()
+                        .map(|(part, _)| part.as_str())
+                        .collect::<String>();
+                    if differs {
+                        joined.push_str(&mark(&text));
+                    } else {
+                        joined.push_str(&text);
+                    }
+                    start = end;
+                }
+            },
+            Segment::Elide(range) => {
+                let elided_chars = parts[range]
+                    .iter()
+                    .map(|(part, _)| part.chars().count())
+                    .sum::<usize>();
+                _ = write!(joined, "… {elided_chars} more chars …");
+            },
+        }
+    }
+    joined
+}
+
+/// Indentation of the continuation lines of pretty formatted values, which
+/// aligns them with the first line following the labels `   but was: ` and
+/// `  expected: ` in failure messages.
+const PRETTY_DEBUG_INDENT: &str = "            ";
+
+/// Formats the given value using the alternate debug format (`{:#?}`) if
+/// pretty debug is switched on in the given [`DiffFormat`].
+fn debug_string<T>(value: &T, format: &DiffFormat) -> String
+where
+    T: Debug + ?Sized,
+{
+    if format.pretty_debug {
+        format!("{value:#?}")
+    } else {
+        format!("{value:?}")
+    }
+}
+
+/// Applies the given marking function to each line of the given text
+/// separately, so that highlighting does not span line breaks.
+fn mark_lines(text: &str, mut mark: impl FnMut(&str) -> String) -> String {
+    let mut marked = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let (content, line_break) = line
+            .strip_suffix('\n')
+            .map_or((line, ""), |content| (content, "\n"));
+        if !content.is_empty() {
+            marked.push_str(&mark(content));
+        }
+        marked.push_str(line_break);
+    }
+    marked
+}
+
+/// Indents the continuation lines of a pretty formatted value.
+fn indent_continuation_lines(text: String, format: &DiffFormat) -> String {
+    if format.pretty_debug {
+        text.replace('\n', &format!("\n{PRETTY_DEBUG_INDENT}"))
+    } else {
+        text
     }
-    marked_map_entries.push('}');
-    marked_map_entries
+}
+
+/// Width of the labels in failure messages, including the colon.
+///
+/// Labels are right-aligned on the colon, like `   but was:` and
+/// `  expected:`.
+const LABEL_WIDTH: usize = 11;
+
+/// Label of the actual value in failure messages.
+const ACTUAL_LABEL: &str = "   but was:";
+
+/// Label of the expected value in failure messages.
+const EXPECTED_LABEL: &str = "  expected:";
+
+/// Gutter marker of the lines of the actual value in the vertical layout.
+const ACTUAL_GUTTER: &str = "< ";
+
+/// Gutter marker of the lines of the expected value in the vertical layout.
+const EXPECTED_GUTTER: &str = "> ";
+
+/// Gutter of the lines of any other labeled value in the vertical layout.
+const OTHER_GUTTER: &str = "  ";
+
+/// Splits a line of a failure message into its label and its value, if the
+/// line starts with a label that is right-aligned on the colon.
+fn split_label(line: &str) -> Option<(&str, &str)> {
+    let label = line.get(..LABEL_WIDTH)?;
+    let value = line[LABEL_WIDTH..].strip_prefix(' ')?;
+    let name = label.strip_suffix(':')?.trim_start();
+    let is_label = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c == ' ');
+    is_label.then_some((label, value))
+}
+
+/// Lays out the labeled values of a failure message vertically if the vertical
+/// layout is switched on in the given [`DiffFormat`].
+///
+/// The value following a label that is right-aligned on the colon, like
+/// `   but was:` or `  expected:`, is moved to the next line. Each line of a
+/// value, including the continuation lines of pretty formatted values, is
+/// prefixed with a gutter marker: `<` for the actual value, `>` for the
+/// expected value and blanks for any other labeled value. All other lines of
+/// the message are kept as they are.
+///
+/// The layout is applied to the message as formed by an assertion, before the
+/// context of the spec is appended. As the message is plain text, a line of a
+/// multi-line value that is not indented like a pretty formatted value ends
+/// the value. If such a line starts with a label, it is laid out as well.
+pub(crate) fn layout_failure_message(message: String, format: &DiffFormat) -> String {
+    if !format.vertical_layout {
+        return message;
+    }
+    let mut laid_out = String::with_capacity(message.len() + 16);
+    let mut gutter = None;
+    for line in message.split_inclusive('\n') {
+        if let Some((gutter, value)) = gutter.zip(line.strip_prefix(PRETTY_DEBUG_INDENT)) {
+            laid_out.push_str(gutter);
+            laid_out.push_str(value);
+        } else if let Some((label, value)) = split_label(line) {
+            let value_gutter = match label {
+                ACTUAL_LABEL => ACTUAL_GUTTER,
+                EXPECTED_LABEL => EXPECTED_GUTTER,
+                _ => OTHER_GUTTER,
+            };
+            laid_out.push_str(label);
+            laid_out.push('\n');
+            laid_out.push_str(value_gutter);
+            laid_out.push_str(value);
+            gutter = Some(value_gutter);
+        } else {
+            gutter = None;
+            laid_out.push_str(line);
+        }
+    }
+    laid_out
 }
 
 #[cfg(not(feature = "colored"))]
 mod without_colored_feature {
-    use super::DIFF_FORMAT_NO_HIGHLIGHT;
+    use super::{DIFF_FORMAT_NO_HIGHLIGHT, join_truncated_str};
     use crate::spec::DiffFormat;
     use crate::std::{
         fmt::Debug,
         format,
         string::{String, ToString},
+        vec::Vec,
     };
 
     /// Default diff format.
@@ -811,9 +1237,46 @@ mod without_colored_feature {
         DEFAULT_DIFF_FORMAT
     }
 
+    /// Returns the actual and the expected string, truncated according to the
+    /// given diff format.
+    ///
+    /// Without the `colored` feature, the differences are approximated by the
+    /// characters between the common prefix and the common suffix of both
+    /// strings, which are always kept visible when truncating.
     #[inline]
-    pub fn mark_diff_impl(actual: &str, expected: &str, _format: &DiffFormat) -> (String, String) {
-        (actual.to_string(), expected.to_string())
+    pub fn mark_diff_impl(actual: &str, expected: &str, format: &DiffFormat) -> (String, String) {
+        if format.truncation.is_none() {
+            return (actual.to_string(), expected.to_string());
+        }
+        let actual = actual.chars().collect::<Vec<_>>();
+        let expected = expected.chars().collect::<Vec<_>>();
+        let prefix = actual
+            .iter()
+            .zip(&expected)
+            .take_while(|(actual, expected)| actual == expected)
+            .count();
+        let suffix = actual[prefix..]
+            .iter()
+            .rev()
+            .zip(expected[prefix..].iter().rev())
+            .take_while(|(actual, expected)| actual == expected)
+            .count();
+        let parts = |chars: &[char]| {
+            chars
+                .iter()
+                .enumerate()
+                .map(|(index, character)| {
+                    (
+                        character.to_string(),
+                        index >= prefix && index < chars.len() - suffix,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        (
+            join_truncated_str(&parts(&actual), format.truncation, ToString::to_string),
+            join_truncated_str(&parts(&expected), format.truncation, ToString::to_string),
+        )
     }
 
     #[inline]
@@ -855,19 +1318,85 @@ mod without_colored_feature {
 
 #[cfg(feature = "colored")]
 mod with_colored_feature {
-    use super::DIFF_FORMAT_NO_HIGHLIGHT;
-    use crate::spec::{DiffFormat, Highlight};
-    use crate::std::{fmt::Debug, format, string::String};
+    use super::{
+        DIFF_FORMAT_NO_HIGHLIGHT, SPAN_MISSING_END, SPAN_MISSING_START, SPAN_UNEXPECTED_END,
+        SPAN_UNEXPECTED_START, join_truncated_str, mark_lines,
+    };
+    #[cfg(feature = "std")]
+    use crate::spec::Truncation;
+    use crate::spec::{DiffFormat, DiffGranularity, Highlight, OutputFormat};
+    #[cfg(feature = "std")]
+    use crate::std::sync::{PoisonError, RwLock};
+    use crate::std::{
+        fmt::Debug,
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+    #[cfg(feature = "unicode")]
+    use unicode_segmentation::UnicodeSegmentation;
 
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     const ENV_VAR_NO_COLOR: &str = "NO_COLOR";
 
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    const ENV_VAR_CLICOLOR: &str = "CLICOLOR";
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    const ENV_VAR_CLICOLOR_FORCE: &str = "CLICOLOR_FORCE";
+
     /// Environment variable to set the highlight mode.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub const ENV_VAR_HIGHLIGHT_DIFFS: &str = "ASSERTING_HIGHLIGHT_DIFFS";
 
+    /// Environment variable to set the granularity of highlighted differences.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const ENV_VAR_DIFF_GRANULARITY: &str = "ASSERTING_DIFF_GRANULARITY";
+
+    /// Environment variable to set the output format of failure messages.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const ENV_VAR_OUTPUT_FORMAT: &str = "ASSERTING_OUTPUT_FORMAT";
+
+    /// Environment variable to set the length of values above which they are
+    /// truncated.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const ENV_VAR_TRUNCATION_MAX_LENGTH: &str = "ASSERTING_TRUNCATION_MAX_LENGTH";
+
+    /// Environment variable to set the number of items kept around
+    /// differences in truncated values.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const ENV_VAR_TRUNCATION_CONTEXT: &str = "ASSERTING_TRUNCATION_CONTEXT";
+
+    /// Environment variable to switch on pretty debug formatting (`{:#?}`) of
+    /// values in failure messages.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const ENV_VAR_PRETTY_DEBUG: &str = "ASSERTING_PRETTY_DEBUG";
+
+    /// Number of items kept around differences if no context is configured.
+    #[cfg(feature = "std")]
+    const DEFAULT_TRUNCATION_CONTEXT: usize = 3;
+
+    /// Granularity comparing values character by character.
+    const DIFF_GRANULARITY_CHAR: &str = "char";
+    /// Granularity comparing values word by word.
+    const DIFF_GRANULARITY_WORD: &str = "word";
+    /// Granularity comparing values line by line.
+    const DIFF_GRANULARITY_LINE: &str = "line";
+
+    /// Output format of failure messages meant to be read by humans.
+    const OUTPUT_FORMAT_DEFAULT: &str = "default";
+    /// Output format prefixing failure messages with an assertion identifier.
+    const OUTPUT_FORMAT_TAGGED: &str = "tagged";
+
     /// Highlight mode using the CVD-friendly colors red and blue.
     const HIGHLIGHT_MODE_RED_BLUE: &str = "red-blue";
     /// Highlight mode using the colors red and green.
@@ -876,8 +1405,23 @@ mod with_colored_feature {
     const HIGHLIGHT_MODE_RED_YELLOW: &str = "red-yellow";
     /// Highlight mode using bold font.
     const HIGHLIGHT_MODE_BOLD: &str = "bold";
+    /// Highlight mode using plain text markers instead of ANSI escape sequences.
+    const HIGHLIGHT_MODE_PLAIN: &str = "plain";
+    /// Highlight mode rendering failures as HTML fragments.
+    const HIGHLIGHT_MODE_HTML: &str = "html";
     /// Highlight mode for no highlight at all.
     const HIGHLIGHT_MODE_OFF: &str = "off";
+    /// Suffix of a highlight mode that switches on the vertical layout.
+    const LAYOUT_SUFFIX_VERTICAL: &str = "+vertical";
+
+    /// Marker put before unexpected parts of the actual value in plain text mode.
+    const PLAIN_UNEXPECTED_START: &str = "»";
+    /// Marker put after unexpected parts of the actual value in plain text mode.
+    const PLAIN_UNEXPECTED_END: &str = "«";
+    /// Marker put before missing parts of the expected value in plain text mode.
+    const PLAIN_MISSING_START: &str = "«";
+    /// Marker put after missing parts of the expected value in plain text mode.
+    const PLAIN_MISSING_END: &str = "»";
 
     const TERM_FONT_BOLD: &str = "\u{1b}[1m";
     const TERM_COLOR_RED: &str = "\u{1b}[31m";
@@ -914,6 +1458,22 @@ mod with_colored_feature {
         end: TERM_RESET,
     };
     const TERM_NO_HIGHLIGHT: Highlight = Highlight { start: "", end: "" };
+    const PLAIN_HIGHLIGHT_UNEXPECTED: Highlight = Highlight {
+        start: PLAIN_UNEXPECTED_START,
+        end: PLAIN_UNEXPECTED_END,
+    };
+    const PLAIN_HIGHLIGHT_MISSING: Highlight = Highlight {
+        start: PLAIN_MISSING_START,
+        end: PLAIN_MISSING_END,
+    };
+    const SPAN_HIGHLIGHT_UNEXPECTED: Highlight = Highlight {
+        start: SPAN_UNEXPECTED_START,
+        end: SPAN_UNEXPECTED_END,
+    };
+    const SPAN_HIGHLIGHT_MISSING: Highlight = Highlight {
+        start: SPAN_MISSING_START,
+        end: SPAN_MISSING_END,
+    };
 
     /// A diff format that highlights differences in the colors red and blue.
     ///
@@ -923,6 +1483,12 @@ mod with_colored_feature {
     pub const DIFF_FORMAT_RED_BLUE: DiffFormat = DiffFormat {
         unexpected: TERM_HIGHLIGHT_RED,
         missing: TERM_HIGHLIGHT_BLUE,
+        granularity: DiffGranularity::Char,
+        truncation: None,
+        pretty_debug: false,
+        display_formatting: false,
+        vertical_layout: false,
+        output_format: OutputFormat::Default,
     };
 
     /// A diff format that highlights differences in the colors red and green.
@@ -933,6 +1499,12 @@ mod with_colored_feature {
     pub const DIFF_FORMAT_RED_GREEN: DiffFormat = DiffFormat {
         unexpected: TERM_HIGHLIGHT_RED,
         missing: TERM_HIGHLIGHT_GREEN,
+        granularity: DiffGranularity::Char,
+        truncation: None,
+        pretty_debug: false,
+        display_formatting: false,
+        vertical_layout: false,
+        output_format: OutputFormat::Default,
     };
 
     /// A diff format that highlights differences in the colors red and yellow.
@@ -943,12 +1515,67 @@ mod with_colored_feature {
     pub const DIFF_FORMAT_RED_YELLOW: DiffFormat = DiffFormat {
         unexpected: TERM_HIGHLIGHT_RED,
         missing: TERM_HIGHLIGHT_YELLOW,
+        granularity: DiffGranularity::Char,
+        truncation: None,
+        pretty_debug: false,
+        display_formatting: false,
+        vertical_layout: false,
+        output_format: OutputFormat::Default,
     };
 
     /// A diff format that highlights differences in the actual value in bold.
     pub const DIFF_FORMAT_BOLD: DiffFormat = DiffFormat {
         unexpected: TERM_HIGHLIGHT_BOLD,
         missing: TERM_NO_HIGHLIGHT,
+        granularity: DiffGranularity::Char,
+        truncation: None,
+        pretty_debug: false,
+        display_formatting: false,
+        vertical_layout: false,
+        output_format: OutputFormat::Default,
+    };
+
+    /// A diff format that marks differences with plain text markers.
+    ///
+    /// Unexpected parts of the actual value are enclosed in `»` and `«`, like
+    /// `»unexpected«`. Missing parts of the expected value are enclosed in `«`
+    /// and `»`, like `«missing»`. This format does not use any ANSI escape
+    /// sequences and is meant for IDE test consoles and logs that do not
+    /// support them.
+    pub const DIFF_FORMAT_PLAIN: DiffFormat = DiffFormat {
+        unexpected: PLAIN_HIGHLIGHT_UNEXPECTED,
+        missing: PLAIN_HIGHLIGHT_MISSING,
+        granularity: DiffGranularity::Char,
+        truncation: None,
+        pretty_debug: false,
+        display_formatting: false,
+        vertical_layout: false,
+        output_format: OutputFormat::Default,
+    };
+
+    /// A diff format that renders each failure as an HTML fragment.
+    ///
+    /// The differences are recorded as highlighted spans of the failure
+    /// message. A failure is displayed as the HTML fragment returned by
+    /// [`AssertFailure::to_html()`], in which unexpected parts of the actual
+    /// value are rendered as `<del>` elements and missing parts of the
+    /// expected value as `<ins>` elements. The plain failure message without
+    /// any markers is returned by [`AssertFailure::message()`].
+    ///
+    /// This format is meant for tools that display rich output. It does not
+    /// use any ANSI escape sequences.
+    ///
+    /// [`AssertFailure::message()`]: crate::spec::AssertFailure::message
+    /// [`AssertFailure::to_html()`]: crate::spec::AssertFailure::to_html
+    pub const DIFF_FORMAT_HTML: DiffFormat = DiffFormat {
+        unexpected: SPAN_HIGHLIGHT_UNEXPECTED,
+        missing: SPAN_HIGHLIGHT_MISSING,
+        granularity: DiffGranularity::Char,
+        truncation: None,
+        pretty_debug: false,
+        display_formatting: false,
+        vertical_layout: false,
+        output_format: OutputFormat::Default,
     };
 
     /// Returns a [`DiffFormat`] for the given highlight mode.
@@ -961,27 +1588,194 @@ mod with_colored_feature {
     /// | `"red-blue"`   | [`DIFF_FORMAT_RED_BLUE`]     |
     /// | `"red-yellow"` | [`DIFF_FORMAT_RED_YELLOW`]   |
     /// | `"bold"`       | [`DIFF_FORMAT_BOLD`]         |
+    /// | `"plain"`      | [`DIFF_FORMAT_PLAIN`]        |
+    /// | `"html"`       | [`DIFF_FORMAT_HTML`]         |
     /// | `"off"`        | [`DIFF_FORMAT_NO_HIGHLIGHT`] |
     ///
+    /// Each mode can be followed by the suffix `+vertical`, like
+    /// `"red-blue+vertical"`, which switches on the vertical layout of the
+    /// actual and the expected value (see
+    /// [`DiffFormat::with_vertical_layout()`]).
+    ///
     /// The mode string is case-insensitive.
     #[must_use]
     pub fn diff_format_for_mode(mode: &str) -> Option<DiffFormat> {
-        match mode.to_lowercase().as_str() {
-            HIGHLIGHT_MODE_RED_BLUE => Some(DIFF_FORMAT_RED_BLUE),
-            HIGHLIGHT_MODE_RED_GREEN => Some(DIFF_FORMAT_RED_GREEN),
-            HIGHLIGHT_MODE_RED_YELLOW => Some(DIFF_FORMAT_RED_YELLOW),
-            HIGHLIGHT_MODE_BOLD => Some(DIFF_FORMAT_BOLD),
-            HIGHLIGHT_MODE_OFF => Some(DIFF_FORMAT_NO_HIGHLIGHT),
+        let mode = mode.to_lowercase();
+        let (highlight_mode, vertical_layout) = split_layout(&mode);
+        let diff_format = match highlight_mode {
+            HIGHLIGHT_MODE_RED_BLUE => DIFF_FORMAT_RED_BLUE,
+            HIGHLIGHT_MODE_RED_GREEN => DIFF_FORMAT_RED_GREEN,
+            HIGHLIGHT_MODE_RED_YELLOW => DIFF_FORMAT_RED_YELLOW,
+            HIGHLIGHT_MODE_BOLD => DIFF_FORMAT_BOLD,
+            HIGHLIGHT_MODE_PLAIN => DIFF_FORMAT_PLAIN,
+            HIGHLIGHT_MODE_HTML => DIFF_FORMAT_HTML,
+            HIGHLIGHT_MODE_OFF => DIFF_FORMAT_NO_HIGHLIGHT,
+            _ => return None,
+        };
+        if vertical_layout {
+            Some(diff_format.with_vertical_layout())
+        } else {
+            Some(diff_format)
+        }
+    }
+
+    /// Splits the given lowercase mode into the highlight mode and whether
+    /// the vertical layout is switched on by the suffix `+vertical`.
+    fn split_layout(mode: &str) -> (&str, bool) {
+        mode.strip_suffix(LAYOUT_SUFFIX_VERTICAL)
+            .map_or((mode, false), |highlight_mode| (highlight_mode, true))
+    }
+
+    /// Returns the [`DiffGranularity`] for the given granularity name.
+    ///
+    /// Supported granularities are:
+    ///
+    /// | name     | granularity                |
+    /// |----------|----------------------------|
+    /// | `"char"` | [`DiffGranularity::Char`]  |
+    /// | `"word"` | [`DiffGranularity::Word`]  |
+    /// | `"line"` | [`DiffGranularity::Line`]  |
+    ///
+    /// The name is case-insensitive.
+    #[must_use]
+    pub fn diff_granularity_for_name(name: &str) -> Option<DiffGranularity> {
+        match name.to_lowercase().as_str() {
+            DIFF_GRANULARITY_CHAR => Some(DiffGranularity::Char),
+            DIFF_GRANULARITY_WORD => Some(DiffGranularity::Word),
+            DIFF_GRANULARITY_LINE => Some(DiffGranularity::Line),
+            _ => None,
+        }
+    }
+
+    /// Reads the granularity from the environment variable
+    /// `ASSERTING_DIFF_GRANULARITY` or the configuration file.
+    #[cfg(feature = "std")]
+    #[allow(clippy::print_stderr)]
+    fn configured_diff_granularity() -> DiffGranularity {
+        use crate::{config, env};
+
+        match config::var(ENV_VAR_DIFF_GRANULARITY) {
+            Ok(value) => diff_granularity_for_name(&value).unwrap_or_else(|| {
+                eprintln!(
+                    "WARNING: the environment variable `{ENV_VAR_DIFF_GRANULARITY}` is set to the unrecognized value {value:?}.\n\t=> Default granularity \"{DIFF_GRANULARITY_CHAR}\" is used."
+                );
+                DiffGranularity::Char
+            }),
+            Err(env::VarError::NotPresent) => DiffGranularity::Char,
+            Err(env::VarError::NotUnicode(value)) => {
+                eprintln!(
+                    "WARNING: the environment variable `{ENV_VAR_DIFF_GRANULARITY}` is set to the unrecognized value {value:?}.\n\t=> Default granularity \"{DIFF_GRANULARITY_CHAR}\" is used."
+                );
+                DiffGranularity::Char
+            },
+        }
+    }
+
+    /// Returns the [`OutputFormat`] for the given name.
+    ///
+    /// Supported output formats are:
+    ///
+    /// | name        | output format             |
+    /// |-------------|---------------------------|
+    /// | `"default"` | [`OutputFormat::Default`] |
+    /// | `"tagged"`  | [`OutputFormat::Tagged`]  |
+    ///
+    /// The name is case-insensitive.
+    #[must_use]
+    pub fn output_format_for_name(name: &str) -> Option<OutputFormat> {
+        match name.to_lowercase().as_str() {
+            OUTPUT_FORMAT_DEFAULT => Some(OutputFormat::Default),
+            OUTPUT_FORMAT_TAGGED => Some(OutputFormat::Tagged),
             _ => None,
         }
     }
 
-    /// Returns true if the mode is a color mode and not "bold" or "off".
+    /// Reads the output format from the environment variable
+    /// `ASSERTING_OUTPUT_FORMAT` or the configuration file.
+    #[cfg(feature = "std")]
+    #[allow(clippy::print_stderr)]
+    fn configured_output_format() -> OutputFormat {
+        use crate::{config, env};
+
+        match config::var(ENV_VAR_OUTPUT_FORMAT) {
+            Ok(value) => output_format_for_name(&value).unwrap_or_else(|| {
+                eprintln!(
+                    "WARNING: the environment variable `{ENV_VAR_OUTPUT_FORMAT}` is set to the unrecognized value {value:?}.\n\t=> Default output format \"{OUTPUT_FORMAT_DEFAULT}\" is used."
+                );
+                OutputFormat::Default
+            }),
+            Err(env::VarError::NotPresent) => OutputFormat::Default,
+            Err(env::VarError::NotUnicode(value)) => {
+                eprintln!(
+                    "WARNING: the environment variable `{ENV_VAR_OUTPUT_FORMAT}` is set to the unrecognized value {value:?}.\n\t=> Default output format \"{OUTPUT_FORMAT_DEFAULT}\" is used."
+                );
+                OutputFormat::Default
+            },
+        }
+    }
+
+    /// Reads the truncation from the environment variables
+    /// `ASSERTING_TRUNCATION_MAX_LENGTH` and `ASSERTING_TRUNCATION_CONTEXT` or
+    /// the configuration file.
+    ///
+    /// Values are only truncated if a maximum length is configured. The
+    /// context defaults to 3 items.
+    #[cfg(feature = "std")]
+    #[allow(clippy::print_stderr)]
+    fn configured_truncation() -> Option<Truncation> {
+        use crate::config;
+
+        let setting = |name: &str| {
+            let value = config::var(name).ok()?;
+            value.trim().parse::<usize>().map_or_else(
+                |_| {
+                    eprintln!(
+                        "WARNING: the environment variable `{name}` is set to the unrecognized value {value:?}.\n\t=> The setting is ignored."
+                    );
+                    None
+                },
+                Some,
+            )
+        };
+        let max_length = setting(ENV_VAR_TRUNCATION_MAX_LENGTH)?;
+        let context = setting(ENV_VAR_TRUNCATION_CONTEXT).unwrap_or(DEFAULT_TRUNCATION_CONTEXT);
+        Some(Truncation::new(max_length, context))
+    }
+
+    /// Reads whether pretty debug formatting is switched on from the
+    /// environment variable `ASSERTING_PRETTY_DEBUG` or the configuration
+    /// file.
+    ///
+    /// The values `true`, `yes`, `on` and `1` switch pretty debug formatting
+    /// on, the values `false`, `no`, `off` and `0` switch it off.
+    #[cfg(feature = "std")]
+    #[allow(clippy::print_stderr)]
+    fn configured_pretty_debug() -> bool {
+        use crate::config;
+
+        let Ok(value) = config::var(ENV_VAR_PRETTY_DEBUG) else {
+            return false;
+        };
+        match value.trim().to_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => true,
+            "false" | "no" | "off" | "0" => false,
+            _ => {
+                eprintln!(
+                    "WARNING: the environment variable `{ENV_VAR_PRETTY_DEBUG}` is set to the unrecognized value {value:?}.\n\t=> The setting is ignored."
+                );
+                false
+            },
+        }
+    }
+
+    /// Returns true if the mode is a color mode and not "bold", "plain",
+    /// "html" or "off".
     #[cfg(feature = "std")]
     fn is_color_mode(mode: &str) -> bool {
+        let mode = mode.to_lowercase();
         !matches!(
-            mode.to_lowercase().as_str(),
-            HIGHLIGHT_MODE_BOLD | HIGHLIGHT_MODE_OFF
+            split_layout(&mode).0,
+            HIGHLIGHT_MODE_BOLD | HIGHLIGHT_MODE_PLAIN | HIGHLIGHT_MODE_HTML | HIGHLIGHT_MODE_OFF
         )
     }
 
@@ -997,6 +1791,151 @@ mod with_colored_feature {
         }
     }
 
+    /// Returns true if no ANSI escape sequences shall be written, because
+    /// stderr is not a terminal or `CLICOLOR` is set to `0`.
+    ///
+    /// Setting `CLICOLOR_FORCE` to a value other than `0` forces ANSI escape
+    /// sequences to be written.
+    #[cfg(feature = "std")]
+    fn is_ansi_output_disabled() -> bool {
+        use crate::env;
+
+        match env::var(ENV_VAR_CLICOLOR_FORCE) {
+            Ok(value) if !value.is_empty() && value != "0" => return false,
+            Err(env::VarError::NotUnicode(_)) => return false,
+            _ => {},
+        }
+        if env::var(ENV_VAR_CLICOLOR).is_ok_and(|value| value == "0") {
+            return true;
+        }
+        !is_terminal()
+    }
+
+    /// Function that detects whether the output is a terminal, which has been
+    /// set programmatically.
+    #[cfg(feature = "std")]
+    static TERMINAL_DETECTOR: RwLock<Option<fn() -> bool>> = RwLock::new(None);
+
+    /// Replaces the detection whether stderr is a terminal with the given
+    /// function.
+    ///
+    /// Differences are only highlighted if the output is a terminal, unless
+    /// the environment variable `CLICOLOR_FORCE` is set. This function is
+    /// useful for test frameworks that capture the output themselves and know
+    /// whether it is finally printed to a terminal.
+    ///
+    /// The configured diff format is determined each time an assertion is
+    /// started. The terminal detector is therefore used by all assertions that
+    /// are started after it has been set, while assertions that are already
+    /// running keep their diff format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::colored::{configured_diff_format, set_terminal_detector};
+    /// use asserting::prelude::*;
+    ///
+    /// set_terminal_detector(|| false);
+    ///
+    /// assert_eq!(configured_diff_format(), DIFF_FORMAT_NO_HIGHLIGHT);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn set_terminal_detector(detector: fn() -> bool) {
+        *TERMINAL_DETECTOR
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(detector);
+    }
+
+    /// Removes a terminal detector previously set with
+    /// [`set_terminal_detector`], so that it is detected again whether stderr
+    /// is a terminal.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn reset_terminal_detector() {
+        *TERMINAL_DETECTOR
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Returns true if the output is a terminal.
+    #[cfg(feature = "std")]
+    fn is_terminal() -> bool {
+        use crate::env;
+
+        let detector = *TERMINAL_DETECTOR
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        detector.map_or_else(env::is_stderr_terminal, |detector| detector())
+    }
+
+    /// Diff format that has been set programmatically.
+    #[cfg(feature = "std")]
+    static PROGRAMMATIC_DIFF_FORMAT: RwLock<Option<DiffFormat>> = RwLock::new(None);
+
+    /// Sets the [`DiffFormat`] that is used by assertions started with the
+    /// [`assert_that!`](crate::assert_that) and
+    /// [`verify_that!`](crate::verify_that) macros.
+    ///
+    /// A diff format set by this function takes precedence over the highlight
+    /// mode configured via environment variables. This is useful on targets
+    /// that do not support environment variables, like
+    /// `wasm32-unknown-unknown`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::colored::{configured_diff_format, set_configured_diff_format};
+    /// use asserting::prelude::*;
+    ///
+    /// set_configured_diff_format(DIFF_FORMAT_NO_HIGHLIGHT);
+    ///
+    /// assert_eq!(configured_diff_format(), DIFF_FORMAT_NO_HIGHLIGHT);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn set_configured_diff_format(diff_format: DiffFormat) {
+        *PROGRAMMATIC_DIFF_FORMAT
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(diff_format);
+    }
+
+    /// Removes a [`DiffFormat`] previously set with
+    /// [`set_configured_diff_format`], so that the highlight mode is read from
+    /// the environment variables again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::colored::{
+    ///     configured_diff_format, reset_configured_diff_format, set_configured_diff_format,
+    /// };
+    /// use asserting::prelude::*;
+    ///
+    /// let diff_format_from_env = configured_diff_format();
+    ///
+    /// set_configured_diff_format(DIFF_FORMAT_BOLD);
+    /// reset_configured_diff_format();
+    ///
+    /// assert_eq!(configured_diff_format(), diff_format_from_env);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn reset_configured_diff_format() {
+        *PROGRAMMATIC_DIFF_FORMAT
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Returns the diff format that has been set programmatically, if any.
+    #[cfg(feature = "std")]
+    pub fn programmatic_diff_format() -> Option<DiffFormat> {
+        PROGRAMMATIC_DIFF_FORMAT
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
     #[cfg(not(feature = "std"))]
     pub const fn configured_diff_format_impl() -> DiffFormat {
         DEFAULT_DIFF_FORMAT
@@ -1007,12 +1946,19 @@ mod with_colored_feature {
     #[must_use]
     #[inline]
     pub fn configured_diff_format_impl() -> DiffFormat {
-        use crate::env;
+        use crate::{config, env};
+
+        if let Some(diff_format) = programmatic_diff_format() {
+            return diff_format;
+        }
 
-        match env::var(ENV_VAR_HIGHLIGHT_DIFFS) {
+        let diff_format = match config::var(ENV_VAR_HIGHLIGHT_DIFFS) {
             Ok(value) => {
                 if is_color_mode(&value) && is_no_color_env_var_set() {
-                    DIFF_FORMAT_NO_HIGHLIGHT
+                    DiffFormat {
+                        vertical_layout: split_layout(&value.to_lowercase()).1,
+                        ..DIFF_FORMAT_NO_HIGHLIGHT
+                    }
                 } else {
                     diff_format_for_mode(&value).unwrap_or_else(|| {
                         #[cfg(feature = "std")]
@@ -1037,45 +1983,235 @@ mod with_colored_feature {
                 );
                 DEFAULT_DIFF_FORMAT
             },
+        };
+        let diff_format = if diff_format.unexpected != DIFF_FORMAT_PLAIN.unexpected
+            && diff_format.unexpected != DIFF_FORMAT_HTML.unexpected
+            && is_ansi_output_disabled()
+        {
+            DiffFormat {
+                vertical_layout: diff_format.vertical_layout,
+                ..DIFF_FORMAT_NO_HIGHLIGHT
+            }
+        } else {
+            diff_format
+        };
+        let diff_format = diff_format
+            .with_granularity(configured_diff_granularity())
+            .with_output_format(configured_output_format());
+        let diff_format = if configured_pretty_debug() {
+            diff_format.with_pretty_debug()
+        } else {
+            diff_format
+        };
+        match configured_truncation() {
+            Some(truncation) => diff_format.with_truncation(truncation),
+            None => diff_format,
         }
     }
 
-    #[inline]
-    pub fn mark_diff_impl(actual: &str, expected: &str, format: &DiffFormat) -> (String, String) {
-        use crate::std::vec::Vec;
+    /// Splits the given text into the tokens that are compared when
+    /// highlighting differences in the given granularity.
+    fn tokenize(text: &str, granularity: DiffGranularity) -> Vec<&str> {
+        match granularity {
+            #[cfg(not(feature = "unicode"))]
+            DiffGranularity::Char => text
+                .char_indices()
+                .map(|(index, character)| &text[index..index + character.len_utf8()])
+                .collect(),
+            #[cfg(feature = "unicode")]
+            DiffGranularity::Char => text.graphemes(true).collect(),
+            DiffGranularity::Word => {
+                let mut tokens = Vec::new();
+                let mut token_start = 0;
+                let mut previous_class = None;
+                for (index, character) in text.char_indices() {
+                    let class = CharClass::of(character);
+                    let continues_token =
+                        previous_class == Some(class) && class != CharClass::Other;
+                    if !continues_token && index > token_start {
+                        tokens.push(&text[token_start..index]);
+                        token_start = index;
+                    }
+                    previous_class = Some(class);
+                }
+                if token_start < text.len() {
+                    tokens.push(&text[token_start..]);
+                }
+                tokens
+            },
+            DiffGranularity::Line => text.split_inclusive('\n').collect(),
+        }
+    }
+
+    /// Classes of characters used to split a text into words.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum CharClass {
+        Word,
+        Whitespace,
+        Other,
+    }
+
+    impl CharClass {
+        fn of(character: char) -> Self {
+            if character.is_alphanumeric() || character == '_' {
+                Self::Word
+            } else if character.is_whitespace() {
+                Self::Whitespace
+            } else {
+                Self::Other
+            }
+        }
+    }
+
+    /// Surrounds the given text with the given highlight markers. With pretty
+    /// debug switched on, each line is highlighted separately.
+    fn highlight(text: &str, highlight: &Highlight, format: &DiffFormat) -> String {
+        if format.pretty_debug {
+            mark_lines(text, |line| {
+                format!("{}{line}{}", highlight.start, highlight.end)
+            })
+        } else {
+            format!("{}{text}{}", highlight.start, highlight.end)
+        }
+    }
+
+    /// The differences between an actual and an expected string.
+    ///
+    /// It holds the tokens of both strings, each flagged whether it differs
+    /// from the other string. A `DiffResult` is computed once with
+    /// [`diff_str`] and can then be marked in any number of [`DiffFormat`]s
+    /// with [`DiffResult::mark`] without running the diff algorithm again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "colored"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "colored")]
+    /// # fn main() {
+    /// use asserting::colored::{DIFF_FORMAT_PLAIN, DIFF_FORMAT_RED_BLUE, diff_str};
+    /// use asserting::spec::DiffGranularity;
+    ///
+    /// let diff = diff_str("lorem ipsum", "lorem ipsam", DiffGranularity::Char);
+    ///
+    /// assert!(diff.has_differences());
+    /// assert_eq!(
+    ///     diff.mark(&DIFF_FORMAT_PLAIN),
+    ///     ("lorem ips»u«m".to_string(), "lorem ips«a»m".to_string())
+    /// );
+    /// assert_eq!(
+    ///     diff.mark(&DIFF_FORMAT_RED_BLUE),
+    ///     (
+    ///         "lorem ips\u{1b}[31mu\u{1b}[0mm".to_string(),
+    ///         "lorem ips\u{1b}[34ma\u{1b}[0mm".to_string()
+    ///     )
+    /// );
+    /// # }
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct DiffResult {
+        granularity: DiffGranularity,
+        actual_parts: Vec<(String, bool)>,
+        expected_parts: Vec<(String, bool)>,
+    }
+
+    impl DiffResult {
+        /// Returns the granularity in which the strings have been compared.
+        #[must_use]
+        pub const fn granularity(&self) -> DiffGranularity {
+            self.granularity
+        }
+
+        /// Returns whether the actual and the expected string differ.
+        #[must_use]
+        pub fn has_differences(&self) -> bool {
+            self.actual_parts
+                .iter()
+                .chain(&self.expected_parts)
+                .any(|(_, differs)| *differs)
+        }
+
+        /// Highlights the differences according to the given [`DiffFormat`].
+        ///
+        /// It returns a tuple of two `String`s. The first string contains the
+        /// actual string, and the second one contains the expected string,
+        /// each with differences highlighted.
+        ///
+        /// The granularity of the given diff format is ignored, as the
+        /// differences have already been determined in the granularity of
+        /// this `DiffResult`.
+        #[must_use]
+        pub fn mark(&self, format: &DiffFormat) -> (String, String) {
+            let mark_parts = |parts: &[(String, bool)], style: &Highlight| {
+                join_truncated_str(parts, format.truncation, |text| {
+                    highlight(text, style, format)
+                })
+            };
+            (
+                mark_parts(&self.actual_parts, &format.unexpected),
+                mark_parts(&self.expected_parts, &format.missing),
+            )
+        }
+    }
+
+    /// Determines the differences between the actual and the expected string
+    /// in the given granularity.
+    ///
+    /// The returned [`DiffResult`] can be marked in different [`DiffFormat`]s
+    /// without running the diff algorithm again.
+    #[must_use]
+    pub fn diff_str(actual: &str, expected: &str, granularity: DiffGranularity) -> DiffResult {
         use sdiff::Diff;
 
-        let actual = actual.chars().collect::<Vec<_>>();
-        let expected = expected.chars().collect::<Vec<_>>();
-        let mut marked_actual = Vec::with_capacity(actual.len());
-        let mut marked_expected = Vec::with_capacity(expected.len());
+        let actual = tokenize(actual, granularity);
+        let expected = tokenize(expected, granularity);
+        let mut actual_parts = Vec::with_capacity(actual.len());
+        let mut expected_parts = Vec::with_capacity(expected.len());
         let diffs = sdiff::diff(&actual, &expected);
         for diff in diffs {
             match diff {
                 Diff::Left { index, length } => {
-                    marked_actual.extend(format.unexpected.start.chars());
-                    marked_actual.extend_from_slice(&actual[index..(index + length)]);
-                    marked_actual.extend(format.unexpected.end.chars());
+                    actual_parts.extend(
+                        actual[index..index + length]
+                            .iter()
+                            .map(|token| ((*token).to_string(), true)),
+                    );
                 },
                 Diff::Both {
                     left_index,
                     right_index,
                     length,
                 } => {
-                    marked_actual.extend_from_slice(&actual[left_index..left_index + length]);
-                    marked_expected.extend_from_slice(&expected[right_index..right_index + length]);
+                    actual_parts.extend(
+                        actual[left_index..left_index + length]
+                            .iter()
+                            .map(|token| ((*token).to_string(), false)),
+                    );
+                    expected_parts.extend(
+                        expected[right_index..right_index + length]
+                            .iter()
+                            .map(|token| ((*token).to_string(), false)),
+                    );
                 },
                 Diff::Right { index, length } => {
-                    marked_expected.extend(format.missing.start.chars());
-                    marked_expected.extend_from_slice(&expected[index..(index + length)]);
-                    marked_expected.extend(format.missing.end.chars());
+                    expected_parts.extend(
+                        expected[index..index + length]
+                            .iter()
+                            .map(|token| ((*token).to_string(), true)),
+                    );
                 },
             }
         }
-        (
-            String::from_iter(marked_actual),
-            String::from_iter(marked_expected),
-        )
+        DiffResult {
+            granularity,
+            actual_parts,
+            expected_parts,
+        }
+    }
+
+    #[inline]
+    pub fn mark_diff_impl(actual: &str, expected: &str, format: &DiffFormat) -> (String, String) {
+        diff_str(actual, expected, format.granularity).mark(format)
     }
 
     #[inline]
@@ -1,6 +1,685 @@
 use super::*;
 use crate::prelude::*;
 
+mod truncation {
+    use super::*;
+    use crate::spec::Truncation;
+
+    #[test]
+    fn truncate_keeps_everything_if_no_truncation_is_configured() {
+        let highlighted = [false; 20];
+
+        let segments = truncate(&highlighted, None);
+
+        assert_eq!(segments, [Segment::Keep(0..20)]);
+    }
+
+    #[test]
+    fn truncate_keeps_everything_if_value_is_not_longer_than_max_length() {
+        let highlighted = [false; 20];
+
+        let segments = truncate(&highlighted, Some(Truncation::new(20, 2)));
+
+        assert_eq!(segments, [Segment::Keep(0..20)]);
+    }
+
+    #[test]
+    fn truncate_keeps_highlighted_items_with_context_and_first_and_last_items() {
+        let mut highlighted = [false; 30];
+        highlighted[10] = true;
+        highlighted[11] = true;
+
+        let segments = truncate(&highlighted, Some(Truncation::new(20, 2)));
+
+        assert_eq!(
+            segments,
+            [
+                Segment::Keep(0..2),
+                Segment::Elide(2..8),
+                Segment::Keep(8..14),
+                Segment::Elide(14..28),
+                Segment::Keep(28..30),
+            ]
+        );
+    }
+
+    #[test]
+    fn truncate_keeps_first_and_last_items_if_all_items_are_highlighted() {
+        let highlighted = [true; 30];
+
+        let segments = truncate(&highlighted, Some(Truncation::new(20, 3)));
+
+        assert_eq!(
+            segments,
+            [
+                Segment::Keep(0..3),
+                Segment::Elide(3..27),
+                Segment::Keep(27..30),
+            ]
+        );
+    }
+
+    #[test]
+    fn truncate_keeps_first_and_last_items_if_no_item_is_highlighted() {
+        let highlighted = [false; 30];
+
+        let segments = truncate(&highlighted, Some(Truncation::new(20, 3)));
+
+        assert_eq!(
+            segments,
+            [
+                Segment::Keep(0..3),
+                Segment::Elide(3..27),
+                Segment::Keep(27..30),
+            ]
+        );
+    }
+
+    #[test]
+    fn truncate_keeps_a_single_item_between_kept_items() {
+        let mut highlighted = [false; 30];
+        highlighted[5] = true;
+        highlighted[16] = true;
+
+        let segments = truncate(&highlighted, Some(Truncation::new(20, 2)));
+
+        assert_eq!(
+            segments,
+            [
+                Segment::Keep(0..8),
+                Segment::Elide(8..14),
+                Segment::Keep(14..19),
+                Segment::Elide(19..28),
+                Segment::Keep(28..30),
+            ]
+        );
+    }
+
+    #[test]
+    fn mark_selected_items_in_collection_does_not_elide_a_single_item() {
+        let collection: Vec<usize> = (0..20).collect();
+        let selected: HashSet<usize> = [5, 11].into();
+
+        let marked_collection = mark_selected_items_in_collection(
+            &collection,
+            &selected,
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(10, 2)),
+            mark_missing,
+        );
+
+        assert_that(marked_collection).is_equal_to(
+            "[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, … 4 more items …, 18, 19]",
+        );
+    }
+
+    #[test]
+    fn mark_selected_items_in_collection_elides_equal_items_of_large_collection() {
+        let collection: Vec<usize> = (0..100).collect();
+        let selected: HashSet<usize> = [50].into();
+
+        let marked_collection = mark_selected_items_in_collection(
+            &collection,
+            &selected,
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(10, 2)),
+            mark_missing,
+        );
+
+        assert_that(marked_collection).is_equal_to(
+            "[0, 1, … 46 more items …, 48, 49, 50, 51, 52, … 45 more items …, 98, 99]",
+        );
+    }
+
+    #[test]
+    fn mark_all_items_in_collection_elides_the_middle_of_large_collection() {
+        let collection: Vec<usize> = (0..100).collect();
+
+        let marked_collection = mark_all_items_in_collection(
+            &collection,
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(10, 2)),
+            mark_missing,
+        );
+
+        assert_that(marked_collection).is_equal_to("[0, 1, … 96 more items …, 98, 99]");
+    }
+
+    #[test]
+    fn mark_selected_entries_in_map_elides_equal_entries_of_large_map() {
+        let map_entries: Vec<(usize, char)> = (0..20).map(|key| (key, 'x')).collect();
+        let selected: HashSet<usize> = [0].into();
+
+        let marked_map = mark_selected_entries_in_map(
+            &map_entries,
+            &selected,
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(10, 1)),
+            mark_missing_string,
+        );
+
+        assert_that(marked_map).is_equal_to("{0: 'x', 1: 'x', … 17 more entries …, 19: 'x'}");
+    }
+
+    #[test]
+    fn mark_all_entries_in_map_elides_the_middle_of_large_map() {
+        let map_entries: Vec<(usize, char)> = (0..20).map(|key| (key, 'x')).collect();
+
+        let marked_map = mark_all_entries_in_map(
+            &map_entries,
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(10, 1)),
+            mark_missing_string,
+        );
+
+        assert_that(marked_map).is_equal_to("{0: 'x', … 18 more entries …, 19: 'x'}");
+    }
+
+    #[test]
+    fn mark_diff_str_keeps_the_differing_part_of_long_strings_visible() {
+        let actual = "a".repeat(40) + "x" + &"b".repeat(40);
+        let expected = "a".repeat(40) + "y" + &"b".repeat(40);
+
+        let (marked_actual, marked_expected) = mark_diff_str(
+            &actual,
+            &expected,
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(50, 3)),
+        );
+
+        assert_that(marked_actual).is_equal_to("aaa… 34 more chars …aaaxbbb… 34 more chars …bbb");
+        assert_that(marked_expected).is_equal_to("aaa… 34 more chars …aaaybbb… 34 more chars …bbb");
+    }
+
+    #[test]
+    fn mark_diff_str_truncates_completely_different_long_strings() {
+        let actual = "a".repeat(60);
+        let expected = "b".repeat(60);
+
+        let (marked_actual, marked_expected) = mark_diff_str(
+            &actual,
+            &expected,
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(10, 3)),
+        );
+
+        assert_that(marked_actual).is_equal_to("aaa… 54 more chars …aaa");
+        assert_that(marked_expected).is_equal_to("bbb… 54 more chars …bbb");
+    }
+
+    #[test]
+    fn mark_diff_str_does_not_truncate_short_strings() {
+        let (marked_actual, marked_expected) = mark_diff_str(
+            "short string",
+            "short strung",
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(20, 3)),
+        );
+
+        assert_that(marked_actual).is_equal_to("short string");
+        assert_that(marked_expected).is_equal_to("short strung");
+    }
+}
+
+#[test]
+fn diff_format_builder_without_markers_builds_no_highlight_format() {
+    let diff_format = DiffFormat::builder().build();
+
+    assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT);
+}
+
+#[test]
+fn diff_format_builder_sets_granularity_and_truncation() {
+    let diff_format = DiffFormat::builder()
+        .granularity(DiffGranularity::Line)
+        .truncation(Truncation::new(80, 4))
+        .build();
+
+    assert_that(diff_format).is_equal_to(
+        DIFF_FORMAT_NO_HIGHLIGHT
+            .with_granularity(DiffGranularity::Line)
+            .with_truncation(Truncation::new(80, 4)),
+    );
+}
+
+#[test]
+fn diff_format_builder_sets_output_format() {
+    let diff_format = DiffFormat::builder()
+        .output_format(OutputFormat::Tagged)
+        .build();
+
+    assert_that(diff_format.output_format()).is_equal_to(OutputFormat::Tagged);
+    assert_that(diff_format)
+        .is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT.with_output_format(OutputFormat::Tagged));
+}
+
+#[test]
+fn diff_format_builder_sets_pretty_debug() {
+    let diff_format = DiffFormat::builder().pretty_debug().build();
+
+    assert_that(diff_format.is_pretty_debug()).is_true();
+    assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT.with_pretty_debug());
+}
+
+mod pretty_debug {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn mark_diff_formats_values_on_multiple_lines_with_aligned_continuation_lines() {
+        let (marked_actual, marked_expected) = mark_diff(
+            &Point { x: 1, y: 2 },
+            &Point { x: 1, y: 3 },
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_pretty_debug(),
+        );
+
+        assert_that(marked_actual)
+            .is_equal_to("Point {\n                x: 1,\n                y: 2,\n            }");
+        assert_that(marked_expected)
+            .is_equal_to("Point {\n                x: 1,\n                y: 3,\n            }");
+    }
+
+    #[test]
+    fn mark_unexpected_formats_value_on_multiple_lines() {
+        let marked = mark_unexpected(&[1, 2], &DIFF_FORMAT_NO_HIGHLIGHT.with_pretty_debug());
+
+        assert_that(marked).is_equal_to("[\n                1,\n                2,\n            ]");
+    }
+
+    #[test]
+    fn verify_is_equal_to_with_pretty_debug_fails() {
+        let failures = verify_that(vec![Point { x: 1, y: 2 }])
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_pretty_debug()
+            .is_equal_to(vec![Point { x: 1, y: -2 }])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject to be equal to [Point { x: 1, y: -2 }]
+   but was: [
+                Point {
+                    x: 1,
+                    y: 2,
+                },
+            ]
+  expected: [
+                Point {
+                    x: 1,
+                    y: -2,
+                },
+            ]
+  differences:
+    [0].y: but was 2, expected -2
+"]
+        );
+    }
+}
+
+#[test]
+fn diff_format_builder_sets_display_formatting() {
+    let diff_format = DiffFormat::builder().display_formatting().build();
+
+    assert_that(diff_format.is_display_formatting()).is_true();
+    assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT.with_display_formatting());
+}
+
+mod display_formatting {
+    use super::*;
+    use crate::std::string::String;
+    use crate::std::vec;
+
+    #[test]
+    fn mark_diff_display_formats_values_without_quotes_and_escapes() {
+        let (marked_actual, marked_expected) = mark_diff_display(
+            "tab\there \"a\"",
+            "tab\there \"b\"",
+            &DIFF_FORMAT_NO_HIGHLIGHT,
+        );
+
+        assert_that(marked_actual).is_equal_to("tab\there \"a\"");
+        assert_that(marked_expected).is_equal_to("tab\there \"b\"");
+    }
+
+    #[test]
+    fn spec_with_display_formatting_switches_on_display_formatting() {
+        let assertion = verify_that("foo")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_display_formatting();
+
+        assert_that(assertion.diff_format())
+            .is_equal_to(&DIFF_FORMAT_NO_HIGHLIGHT.with_display_formatting());
+    }
+
+    #[test]
+    fn verify_has_display_string_with_display_formatting_fails() {
+        let failures = verify_that(42)
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_display_formatting()
+            .has_display_string("43")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected subject to have a display string equal to "43"
+   but was: 42
+  expected: 43
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_is_similar_to_with_display_formatting_fails() {
+        let failures = verify_that("line one\nline two")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_display_formatting()
+            .is_similar_to("line one\nline 2", 1)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected subject to be similar to "line one\nline 2"
+  within an edit distance of 1
+   but was: line one
+line two
+  expected: line one
+line 2
+  distance: 3
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_str_is_equal_to_with_display_formatting_fails() {
+        let failures = verify_that("say \"foo\"")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_display_formatting()
+            .is_equal_to("say \"bar\"")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected subject to be equal to "say \"bar\""
+   but was: say "foo"
+  expected: say "bar"
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_string_is_equal_to_with_display_formatting_fails() {
+        let failures = verify_that(String::from("tab\there"))
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_display_formatting()
+            .is_equal_to("tab\tthere")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected subject to be equal to \"tab\\tthere\"\n   \
+                but was: tab\there\n  \
+               expected: tab\tthere\n\
+            "]
+        );
+    }
+
+    #[test]
+    fn is_equal_to_on_non_string_values_is_not_affected_by_display_formatting() {
+        let failures = verify_that(vec!["foo"])
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_display_formatting()
+            .is_equal_to(vec!["bar"])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected subject to be equal to ["bar"]
+   but was: ["foo"]
+  expected: ["bar"]
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_str_contains_with_display_formatting_fails() {
+        let failures = verify_that("say \"hello\"")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_display_formatting()
+            .contains("\"bye\"")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected subject to contain "\"bye\""
+   but was: say "hello"
+  expected: "bye"
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_str_ends_with_char_with_display_formatting_fails() {
+        let failures = verify_that("hello")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_display_formatting()
+            .ends_with('!')
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject to end with '!'
+   but was: hello
+  expected: !
+"]
+        );
+    }
+}
+
+#[test]
+fn diff_format_builder_sets_vertical_layout() {
+    let diff_format = DiffFormat::builder().vertical_layout().build();
+
+    assert_that(diff_format.is_vertical_layout()).is_true();
+    assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT.with_vertical_layout());
+}
+
+mod vertical_layout {
+    use super::*;
+    use crate::std::vec;
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn layout_failure_message_keeps_the_message_if_vertical_layout_is_off() {
+        let message = "expected subject to be equal to 2\n   but was: 1\n  expected: 2".to_string();
+
+        let laid_out = layout_failure_message(message.clone(), &DIFF_FORMAT_NO_HIGHLIGHT);
+
+        assert_that(laid_out).is_equal_to(message);
+    }
+
+    #[test]
+    fn layout_failure_message_lays_out_the_values_of_other_labels_without_gutter_marker() {
+        let laid_out = layout_failure_message(
+            "expected subject to be similar to \"x\"\n   but was: \"y\"\n  expected: \"x\"\n  distance: 1\n".to_string(),
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_vertical_layout(),
+        );
+
+        assert_that(laid_out).is_equal_to(
+            "expected subject to be similar to \"x\"\n   but was:\n< \"y\"\n  expected:\n> \"x\"\n  distance:\n  1\n",
+        );
+    }
+
+    #[test]
+    fn layout_failure_message_keeps_lines_with_labels_that_are_not_aligned() {
+        let message =
+            "expected subject to contain 1\n  expected subsequence: 01\n  error: invalid\n"
+                .to_string();
+
+        let laid_out = layout_failure_message(
+            message.clone(),
+            &DIFF_FORMAT_NO_HIGHLIGHT.with_vertical_layout(),
+        );
+
+        assert_that(laid_out).is_equal_to(message);
+    }
+
+    #[test]
+    fn layout_failure_message_is_idempotent() {
+        let format = DIFF_FORMAT_NO_HIGHLIGHT.with_vertical_layout();
+        let laid_out = layout_failure_message(
+            "expected subject to be 2\n   but was: 1\n  expected: 2\n".to_string(),
+            &format,
+        );
+
+        let laid_out_twice = layout_failure_message(laid_out.clone(), &format);
+
+        assert_that(laid_out_twice).is_equal_to(laid_out);
+    }
+
+    #[test]
+    fn spec_with_vertical_layout_switches_on_vertical_layout() {
+        let assertion = verify_that(1)
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_vertical_layout();
+
+        assert_that(assertion.diff_format())
+            .is_equal_to(&DIFF_FORMAT_NO_HIGHLIGHT.with_vertical_layout());
+    }
+
+    #[test]
+    fn verify_is_equal_to_with_vertical_layout_fails() {
+        let failures = verify_that(41)
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_vertical_layout()
+            .is_equal_to(42)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject to be equal to 42
+   but was:
+< 41
+  expected:
+> 42
+"]
+        );
+    }
+
+    #[test]
+    fn verify_is_not_equal_to_with_vertical_layout_fails() {
+        let failures = verify_that(42)
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_vertical_layout()
+            .is_not_equal_to(42)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject to be not equal to 42
+   but was:
+< 42
+  expected:
+> not 42
+"]
+        );
+    }
+
+    #[test]
+    fn verify_is_equal_to_with_vertical_layout_and_pretty_debug_fails() {
+        let failures = verify_that(vec![Point { x: 1, y: 2 }])
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_pretty_debug()
+            .with_vertical_layout()
+            .is_equal_to(vec![Point { x: 1, y: -2 }])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject to be equal to [Point { x: 1, y: -2 }]
+   but was:
+< [
+<     Point {
+<         x: 1,
+<         y: 2,
+<     },
+< ]
+  expected:
+> [
+>     Point {
+>         x: 1,
+>         y: -2,
+>     },
+> ]
+  differences:
+    [0].y: but was 2, expected -2
+"]
+        );
+    }
+
+    #[test]
+    fn failure_of_assertion_not_using_an_expectation_with_vertical_layout() {
+        let failures = verify_that(None::<i32>)
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_vertical_layout()
+            .some()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject to be Some(_)
+   but was:
+< None
+  expected:
+> Some(_)
+"]
+        );
+    }
+
+    #[test]
+    fn context_is_not_laid_out_vertically() {
+        let failures = verify_that(41)
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .with_vertical_layout()
+            .with_context("   but was", 7)
+            .is_equal_to(42)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject to be equal to 42
+   but was:
+< 41
+  expected:
+> 42
+   context:
+        but was: 7
+"]
+        );
+    }
+
+    #[test]
+    fn derived_spec_with_vertical_layout() {
+        let failures = verify_that(Some(41))
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .some()
+            .with_vertical_layout()
+            .is_equal_to(42)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject unwrapped Some value to be equal to 42
+   but was:
+< 41
+  expected:
+> 42
+"]
+        );
+    }
+}
+
 #[cfg(not(feature = "colored"))]
 mod without_colored_feature {
     use super::*;
@@ -28,6 +707,8 @@ mod without_colored_feature {
 #[cfg(feature = "colored")]
 mod with_colored_feature {
     use super::*;
+    use crate::spec::{DiffGranularity, Truncation};
+    use crate::std::fmt::{self, Display};
     use hashbrown::HashMap;
 
     #[test]
@@ -36,38 +717,289 @@ mod with_colored_feature {
     }
 
     #[test]
-    fn highlight_diffs_is_equal_to_for_custom_struct() {
-        #[derive(Debug, PartialEq)]
-        struct Foo {
-            lorem: String,
-            ipsum: i32,
-            dolor: Option<String>,
-        }
+    fn highlight_diffs_with_plain_text_markers() {
+        let failures = verify_that(vec![1, 2, 3])
+            .with_diff_format(DIFF_FORMAT_PLAIN)
+            .is_equal_to(vec![1, 2, 4])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected subject to be equal to [1, 2, 4]\n   \
+                 but was: [1, 2, »3«]\n  \
+                expected: [1, 2, «4»]\n\
+            "]
+        );
+    }
+
+    #[test]
+    fn diff_format_for_mode_html() {
+        assert_that(diff_format_for_mode("html")).has_value(DIFF_FORMAT_HTML);
+        assert_that(diff_format_for_mode("HTML")).has_value(DIFF_FORMAT_HTML);
+    }
+
+    #[test]
+    fn diff_format_for_mode_plain() {
+        assert_that(diff_format_for_mode("plain")).has_value(DIFF_FORMAT_PLAIN);
+        assert_that(diff_format_for_mode("Plain")).has_value(DIFF_FORMAT_PLAIN);
+    }
+
+    #[test]
+    fn diff_format_for_mode_with_vertical_layout_suffix() {
+        assert_that(diff_format_for_mode("red-blue+vertical"))
+            .has_value(DIFF_FORMAT_RED_BLUE.with_vertical_layout());
+        assert_that(diff_format_for_mode("Off+Vertical"))
+            .has_value(DIFF_FORMAT_NO_HIGHLIGHT.with_vertical_layout());
+        assert_that(diff_format_for_mode("vertical")).is_none();
+        assert_that(diff_format_for_mode("red-blue+horizontal")).is_none();
+    }
+
+    #[test]
+    fn highlight_diffs_is_equal_to_for_custom_struct() {
+        #[derive(Debug, PartialEq)]
+        struct Foo {
+            lorem: String,
+            ipsum: i32,
+            dolor: Option<String>,
+        }
+
+        let subject = Some(Foo {
+            lorem: "¡Hola, Welt!".into(),
+            ipsum: 42,
+            dolor: Some("hey".into()),
+        });
+
+        let failures = verify_that(subject)
+            .with_diff_format(DIFF_FORMAT_RED_GREEN)
+            .is_equal_to(Some(Foo {
+                lorem: "Hello World!".into(),
+                ipsum: 42,
+                dolor: Some("hey ho!".into()),
+            }))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to be equal to Some(Foo { lorem: \"Hello World!\", ipsum: 42, dolor: Some(\"hey ho!\") })\n   \
+                 but was: Some(Foo { lorem: \"\u{1b}[31m¡\u{1b}[0mH\u{1b}[31mo\u{1b}[0ml\u{1b}[31ma,\u{1b}[0m W\u{1b}[31me\u{1b}[0ml\u{1b}[31mt\u{1b}[0m!\", ipsum: 42, dolor: Some(\"hey\") })\n  \
+               expected: Some(Foo { lorem: \"H\u{1b}[32me\u{1b}[0ml\u{1b}[32mlo\u{1b}[0m W\u{1b}[32mor\u{1b}[0ml\u{1b}[32md\u{1b}[0m!\", ipsum: 42, dolor: Some(\"hey\u{1b}[32m ho!\u{1b}[0m\") })\n\
+            ",
+            ]
+        );
+    }
+
+    #[test]
+    fn mark_diff_with_pretty_debug_highlights_differences_per_line() {
+        let (marked_actual, marked_expected) = mark_diff(
+            &["alpha", "beta"],
+            &["gamma", "delta"],
+            &DIFF_FORMAT_RED_BLUE
+                .with_granularity(DiffGranularity::Line)
+                .with_pretty_debug(),
+        );
+
+        assert_that(marked_actual).is_equal_to(
+            "[\n            \u{1b}[31m    \"alpha\",\u{1b}[0m\n            \u{1b}[31m    \"beta\",\u{1b}[0m\n            ]",
+        );
+        assert_that(marked_expected).is_equal_to(
+            "[\n            \u{1b}[34m    \"gamma\",\u{1b}[0m\n            \u{1b}[34m    \"delta\",\u{1b}[0m\n            ]",
+        );
+    }
+
+    #[test]
+    fn mark_unexpected_with_pretty_debug_highlights_each_line() {
+        let marked = mark_unexpected(&Some(7), &DIFF_FORMAT_RED_BLUE.with_pretty_debug());
+
+        assert_that(marked).is_equal_to(
+            "\u{1b}[31mSome(\u{1b}[0m\n            \u{1b}[31m    7,\u{1b}[0m\n            \u{1b}[31m)\u{1b}[0m",
+        );
+    }
+
+    #[test]
+    fn verify_has_debug_string_of_custom_debug_impl_highlights_differences() {
+        struct Celsius(f64);
+
+        impl Debug for Celsius {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "Celsius({:.1})", self.0)
+            }
+        }
+
+        let failures = verify_that(Celsius(21.5))
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .has_debug_string("Celsius(21.0)")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to have a debug string equal to \"Celsius(21.0)\"\n   \
+                    but was: Celsius(21.\u{1b}[31m5\u{1b}[0m)\n  \
+                   expected: Celsius(21.\u{1b}[34m0\u{1b}[0m)\n\
+                "
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_has_display_string_of_custom_display_impl_highlights_differences() {
+        struct Celsius(f64);
+
+        impl Display for Celsius {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:.1} °C", self.0)
+            }
+        }
+
+        let failures = verify_that(Celsius(-3.0))
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .has_display_string("-3.0 °F")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to have a display string equal to \"-3.0 °F\"\n   \
+                    but was: \"-3.0 °\u{1b}[31mC\u{1b}[0m\"\n  \
+                   expected: \"-3.0 °\u{1b}[34mF\u{1b}[0m\"\n\
+                "
+            ]
+        );
+    }
+
+    #[test]
+    fn mark_diff_str_in_word_granularity_highlights_whole_words() {
+        let diff_format = DIFF_FORMAT_RED_GREEN.with_granularity(DiffGranularity::Word);
+
+        let (marked_actual, marked_expected) = mark_diff_str(
+            "the quick brown fox jumps over the lazy dog",
+            "the quick red fox jumped over the dog",
+            &diff_format,
+        );
+
+        assert_eq!(
+            marked_actual,
+            "the quick \u{1b}[31mbrown\u{1b}[0m fox \u{1b}[31mjumps\u{1b}[0m over the \u{1b}[31mlazy \u{1b}[0mdog"
+        );
+        assert_eq!(
+            marked_expected,
+            "the quick \u{1b}[32mred\u{1b}[0m fox \u{1b}[32mjumped\u{1b}[0m over the dog"
+        );
+    }
+
+    #[test]
+    fn mark_diff_str_in_word_granularity_treats_punctuation_as_separate_tokens() {
+        let diff_format = DIFF_FORMAT_RED_GREEN.with_granularity(DiffGranularity::Word);
+
+        let (marked_actual, marked_expected) =
+            mark_diff_str("Hello, World!", "Hello; World!", &diff_format);
+
+        assert_eq!(marked_actual, "Hello\u{1b}[31m,\u{1b}[0m World!");
+        assert_eq!(marked_expected, "Hello\u{1b}[32m;\u{1b}[0m World!");
+    }
+
+    #[test]
+    fn mark_diff_str_in_line_granularity_highlights_whole_lines() {
+        let diff_format = DIFF_FORMAT_RED_GREEN.with_granularity(DiffGranularity::Line);
+
+        let (marked_actual, marked_expected) =
+            mark_diff_str("first\nsecond\nthird", "first\nsecont\nthird", &diff_format);
+
+        assert_eq!(marked_actual, "first\n\u{1b}[31msecond\n\u{1b}[0mthird");
+        assert_eq!(marked_expected, "first\n\u{1b}[32msecont\n\u{1b}[0mthird");
+    }
+
+    #[test]
+    fn verify_is_equal_to_highlights_words_in_word_granularity() {
+        let failures = verify_that("lorem ipsum dolor sit amet")
+            .with_diff_format(DIFF_FORMAT_RED_GREEN.with_granularity(DiffGranularity::Word))
+            .is_equal_to("lorem ipsum color sit amet")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to be equal to \"lorem ipsum color sit amet\"\n   \
+                but was: \"lorem ipsum \u{1b}[31mdolor\u{1b}[0m sit amet\"\n  \
+               expected: \"lorem ipsum \u{1b}[32mcolor\u{1b}[0m sit amet\"\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn mark_diff_str_elides_equal_parts_of_long_strings() {
+        let actual = "a".repeat(40) + "x" + &"b".repeat(40);
+        let expected = "a".repeat(40) + "y" + &"b".repeat(40);
+
+        let (marked_actual, marked_expected) = mark_diff_str(
+            &actual,
+            &expected,
+            &DIFF_FORMAT_RED_GREEN.with_truncation(Truncation::new(50, 3)),
+        );
+
+        assert_that(marked_actual)
+            .is_equal_to("aaa… 34 more chars …aaa\u{1b}[31mx\u{1b}[0mbbb… 34 more chars …bbb");
+        assert_that(marked_expected)
+            .is_equal_to("aaa… 34 more chars …aaa\u{1b}[32my\u{1b}[0mbbb… 34 more chars …bbb");
+    }
+
+    #[test]
+    fn verify_is_equal_to_highlights_differences_with_custom_markers() {
+        let diff_format = DiffFormat::builder()
+            .unexpected("⟦", "⟧")
+            .missing("⟨", "⟩")
+            .build();
+
+        let failures = verify_that("retries: 13")
+            .with_diff_format(diff_format)
+            .is_equal_to("retries: 5")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected subject to be equal to "retries: 5"
+   but was: "retries: ⟦13⟧"
+  expected: "retries: ⟨5⟩"
+"#]
+        );
+    }
+
+    #[test]
+    fn mark_all_items_in_collection_with_custom_markers() {
+        let diff_format = DiffFormat::builder().missing("<<", ">>").build();
 
-        let subject = Some(Foo {
-            lorem: "¡Hola, Welt!".into(),
-            ipsum: 42,
-            dolor: Some("hey".into()),
-        });
+        let marked_collection = mark_all_items_in_collection(&[1, 2], &diff_format, mark_missing);
 
-        let failures = verify_that(subject)
-            .with_diff_format(DIFF_FORMAT_RED_GREEN)
-            .is_equal_to(Some(Foo {
-                lorem: "Hello World!".into(),
-                ipsum: 42,
-                dolor: Some("hey ho!".into()),
-            }))
-            .display_failures();
+        assert_that(marked_collection).is_equal_to("[<<1>>, <<2>>]");
+    }
 
+    #[test]
+    fn diff_granularity_for_name_is_case_insensitive() {
         assert_eq!(
-            failures,
-            &[
-                "expected subject to be equal to Some(Foo { lorem: \"Hello World!\", ipsum: 42, dolor: Some(\"hey ho!\") })\n   \
-                 but was: Some(Foo { lorem: \"\u{1b}[31m¡\u{1b}[0mH\u{1b}[31mo\u{1b}[0ml\u{1b}[31ma,\u{1b}[0m W\u{1b}[31me\u{1b}[0ml\u{1b}[31mt\u{1b}[0m!\", ipsum: 42, dolor: Some(\"hey\") })\n  \
-               expected: Some(Foo { lorem: \"H\u{1b}[32me\u{1b}[0ml\u{1b}[32mlo\u{1b}[0m W\u{1b}[32mor\u{1b}[0ml\u{1b}[32md\u{1b}[0m!\", ipsum: 42, dolor: Some(\"hey\u{1b}[32m ho!\u{1b}[0m\") })\n\
-            ",
-            ]
+            diff_granularity_for_name("Char"),
+            Some(DiffGranularity::Char)
+        );
+        assert_eq!(
+            diff_granularity_for_name("WORD"),
+            Some(DiffGranularity::Word)
+        );
+        assert_eq!(
+            diff_granularity_for_name("line"),
+            Some(DiffGranularity::Line)
+        );
+        assert_eq!(diff_granularity_for_name("sentence"), None);
+    }
+
+    #[test]
+    fn output_format_for_name_is_case_insensitive() {
+        assert_eq!(
+            output_format_for_name("Default"),
+            Some(OutputFormat::Default)
         );
+        assert_eq!(output_format_for_name("TAGGED"), Some(OutputFormat::Tagged));
+        assert_eq!(output_format_for_name("json"), None);
     }
 
     #[test]
@@ -310,6 +1242,99 @@ mod with_colored_feature {
 
         assert_that(marked_map).is_equal_to("{}");
     }
+
+    #[test]
+    fn diff_str_of_equal_strings_has_no_differences() {
+        let diff = diff_str("lorem ipsum", "lorem ipsum", DiffGranularity::Char);
+
+        assert!(!diff.has_differences());
+        assert_eq!(
+            diff.mark(&DIFF_FORMAT_RED_BLUE),
+            ("lorem ipsum".to_string(), "lorem ipsum".to_string())
+        );
+    }
+
+    #[test]
+    fn diff_result_marked_in_different_formats_equals_mark_diff_str() {
+        let diff = diff_str("abcdef", "abXdef", DiffGranularity::Char);
+
+        assert_eq!(diff.granularity(), DiffGranularity::Char);
+        assert!(diff.has_differences());
+        for format in [
+            DIFF_FORMAT_RED_BLUE,
+            DIFF_FORMAT_RED_YELLOW,
+            DIFF_FORMAT_PLAIN,
+            DIFF_FORMAT_NO_HIGHLIGHT,
+        ] {
+            assert_eq!(
+                diff.mark(&format),
+                mark_diff_str("abcdef", "abXdef", &format)
+            );
+        }
+        assert_eq!(
+            diff.mark(&DIFF_FORMAT_PLAIN),
+            ("ab»c«def".to_string(), "ab«X»def".to_string())
+        );
+    }
+
+    #[test]
+    fn diff_result_is_marked_in_its_own_granularity() {
+        let diff = diff_str("one two three", "one tvo three", DiffGranularity::Word);
+
+        let (marked_actual, marked_expected) =
+            diff.mark(&DIFF_FORMAT_PLAIN.with_granularity(DiffGranularity::Char));
+
+        assert_eq!(marked_actual, "one »two« three");
+        assert_eq!(marked_expected, "one «tvo» three");
+    }
+
+    #[test]
+    fn diff_result_is_truncated_according_to_the_diff_format() {
+        let diff = diff_str(
+            "abcdefghijXlmnopqrst",
+            "abcdefghijklmnopqrst",
+            DiffGranularity::Char,
+        );
+
+        let (marked_actual, marked_expected) =
+            diff.mark(&DIFF_FORMAT_PLAIN.with_truncation(Truncation::new(10, 2)));
+
+        assert_eq!(marked_actual, "ab… 6 more chars …ij»X«lm… 5 more chars …st");
+        assert_eq!(
+            marked_expected,
+            "ab… 6 more chars …ij«k»lm… 5 more chars …st"
+        );
+    }
+
+    #[test]
+    fn mark_diff_str_with_same_strings_in_different_formats_and_granularities() {
+        let word_format = DIFF_FORMAT_PLAIN.with_granularity(DiffGranularity::Word);
+
+        let char_diff = mark_diff_str("one two three", "one tvo three", &DIFF_FORMAT_PLAIN);
+        let word_diff = mark_diff_str("one two three", "one tvo three", &word_format);
+        let colored_diff = mark_diff_str("one two three", "one tvo three", &DIFF_FORMAT_RED_BLUE);
+        let other_diff = mark_diff_str("one two", "one tvo", &DIFF_FORMAT_PLAIN);
+
+        assert_eq!(
+            char_diff,
+            ("one t»w«o three".to_string(), "one t«v»o three".to_string())
+        );
+        assert_eq!(
+            word_diff,
+            ("one »two« three".to_string(), "one «tvo» three".to_string())
+        );
+        assert_eq!(
+            colored_diff,
+            (
+                "one t\u{1b}[31mw\u{1b}[0mo three".to_string(),
+                "one t\u{1b}[34mv\u{1b}[0mo three".to_string()
+            )
+        );
+        assert_eq!(
+            other_diff,
+            ("one t»w«o".to_string(), "one t«v»o".to_string())
+        );
+    }
 }
 
 #[cfg(all(feature = "colored", not(feature = "std")))]
@@ -334,8 +1359,12 @@ mod with_colored_but_not_std_feature {
 #[cfg(all(feature = "colored", feature = "std"))]
 mod with_colored_and_std_features {
     use super::*;
-    use crate::colored::with_colored_feature::ENV_VAR_HIGHLIGHT_DIFFS;
+    use crate::colored::with_colored_feature::{
+        ENV_VAR_DIFF_GRANULARITY, ENV_VAR_HIGHLIGHT_DIFFS, ENV_VAR_PRETTY_DEBUG,
+        ENV_VAR_TRUNCATION_CONTEXT, ENV_VAR_TRUNCATION_MAX_LENGTH,
+    };
     use crate::env;
+    use crate::spec::{DiffGranularity, Truncation};
     use proptest::prelude::*;
 
     #[test]
@@ -359,6 +1388,212 @@ mod with_colored_and_std_features {
         assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT);
     }
 
+    #[test]
+    fn get_configured_diff_format_when_stderr_is_not_a_terminal() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_stderr_terminal(false);
+
+        let diff_format = configured_diff_format();
+
+        env::set_stderr_terminal(true);
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_stderr_is_not_a_terminal_and_bold_mode() {
+        env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "bold");
+        env::set_stderr_terminal(false);
+
+        let diff_format = configured_diff_format();
+
+        env::set_stderr_terminal(true);
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_stderr_is_not_a_terminal_and_clicolor_force_set() {
+        env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "red-blue");
+        env::set_var("CLICOLOR_FORCE", "1");
+        env::set_stderr_terminal(false);
+
+        let diff_format = configured_diff_format();
+
+        env::set_stderr_terminal(true);
+        env::remove_var("CLICOLOR_FORCE");
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_RED_BLUE);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_clicolor_force_set_to_zero_and_stderr_is_not_a_terminal() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var("CLICOLOR_FORCE", "0");
+        env::set_stderr_terminal(false);
+
+        let diff_format = configured_diff_format();
+
+        env::set_stderr_terminal(true);
+        env::remove_var("CLICOLOR_FORCE");
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_clicolor_force_and_no_color_env_var_set() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var("CLICOLOR_FORCE", "1");
+        env::set_var("NO_COLOR", "1");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var("NO_COLOR");
+        env::remove_var("CLICOLOR_FORCE");
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_clicolor_env_var_set_to_zero() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var("CLICOLOR", "0");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var("CLICOLOR");
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_NO_HIGHLIGHT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_clicolor_env_var_set_to_one() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var("CLICOLOR", "1");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var("CLICOLOR");
+
+        assert_that(diff_format).is_equal_to(DEFAULT_DIFF_FORMAT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_granularity_env_var_set_to_word() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var(ENV_VAR_DIFF_GRANULARITY, "word");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_DIFF_GRANULARITY);
+
+        assert_that(diff_format)
+            .is_equal_to(DEFAULT_DIFF_FORMAT.with_granularity(DiffGranularity::Word));
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_granularity_env_var_set_to_line_and_bold_mode() {
+        env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "bold");
+        env::set_var(ENV_VAR_DIFF_GRANULARITY, "Line");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_DIFF_GRANULARITY);
+
+        assert_that(diff_format)
+            .is_equal_to(DIFF_FORMAT_BOLD.with_granularity(DiffGranularity::Line));
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_granularity_env_var_set_to_unknown_granularity() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var(ENV_VAR_DIFF_GRANULARITY, "sentence");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_DIFF_GRANULARITY);
+
+        assert_that(diff_format).is_equal_to(DEFAULT_DIFF_FORMAT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_truncation_max_length_env_var_set() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var(ENV_VAR_TRUNCATION_MAX_LENGTH, "200");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_TRUNCATION_MAX_LENGTH);
+
+        assert_that(diff_format)
+            .is_equal_to(DEFAULT_DIFF_FORMAT.with_truncation(Truncation::new(200, 3)));
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_truncation_max_length_and_context_env_vars_set() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var(ENV_VAR_TRUNCATION_MAX_LENGTH, "80");
+        env::set_var(ENV_VAR_TRUNCATION_CONTEXT, "5");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_TRUNCATION_CONTEXT);
+        env::remove_var(ENV_VAR_TRUNCATION_MAX_LENGTH);
+
+        assert_that(diff_format)
+            .is_equal_to(DEFAULT_DIFF_FORMAT.with_truncation(Truncation::new(80, 5)));
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_only_truncation_context_env_var_set() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var(ENV_VAR_TRUNCATION_CONTEXT, "5");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_TRUNCATION_CONTEXT);
+
+        assert_that(diff_format).is_equal_to(DEFAULT_DIFF_FORMAT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_truncation_max_length_env_var_set_to_invalid_value() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var(ENV_VAR_TRUNCATION_MAX_LENGTH, "many");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_TRUNCATION_MAX_LENGTH);
+
+        assert_that(diff_format).is_equal_to(DEFAULT_DIFF_FORMAT);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_pretty_debug_env_var_set_to_true() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var(ENV_VAR_PRETTY_DEBUG, "true");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_PRETTY_DEBUG);
+
+        assert_that(diff_format).is_equal_to(DEFAULT_DIFF_FORMAT.with_pretty_debug());
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_pretty_debug_env_var_set_to_invalid_value() {
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+        env::set_var(ENV_VAR_PRETTY_DEBUG, "sometimes");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var(ENV_VAR_PRETTY_DEBUG);
+
+        assert_that(diff_format).is_equal_to(DEFAULT_DIFF_FORMAT);
+    }
+
     #[test]
     fn get_configured_diff_format_when_env_var_set_to_unknown_mode() {
         env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "not-valid");
@@ -389,6 +1624,52 @@ mod with_colored_and_std_features {
         assert_that(diff_format).is_equal_to(DIFF_FORMAT_BOLD);
     }
 
+    #[test]
+    fn get_configured_diff_format_when_env_var_set_to_plain_mode() {
+        env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "plain");
+
+        let diff_format = configured_diff_format();
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_PLAIN);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_env_var_set_to_plain_mode_and_no_color_env_var_set() {
+        env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "plain");
+        env::set_var("NO_COLOR", "1");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var("NO_COLOR");
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_PLAIN);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_env_var_set_to_html_mode_and_no_color_env_var_set() {
+        env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "html");
+        env::set_var("NO_COLOR", "1");
+
+        let diff_format = configured_diff_format();
+
+        env::remove_var("NO_COLOR");
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_HTML);
+    }
+
+    #[test]
+    fn get_configured_diff_format_when_stderr_is_not_a_terminal_and_plain_mode() {
+        env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "plain");
+        env::set_stderr_terminal(false);
+
+        let diff_format = configured_diff_format();
+
+        env::set_stderr_terminal(true);
+        env::remove_var(ENV_VAR_HIGHLIGHT_DIFFS);
+
+        assert_that(diff_format).is_equal_to(DIFF_FORMAT_PLAIN);
+    }
+
     #[test]
     fn get_configured_diff_format_when_env_var_set_to_red_green_mode() {
         env::set_var(ENV_VAR_HIGHLIGHT_DIFFS, "red-green");
@@ -0,0 +1,271 @@
+//! Reading the configuration of `asserting` from a configuration file.
+//!
+//! Settings can be shared within a repository by committing them to a file
+//! named `asserting.toml` in the root directory of the package, like:
+//!
+//! ```toml
+//! highlight-diffs = "red-blue"
+//! diff-granularity = "word"
+//! output-format = "default"
+//! truncation-max-length = 200
+//! truncation-context = 3
+//! pretty-debug = false
+//! trace = false
+//! ```
+//!
+//! If there is no `asserting.toml` file, the settings are read from the
+//! `[package.metadata.asserting]` section of the package's `Cargo.toml`.
+//!
+//! Each setting corresponds to an environment variable. The name of the
+//! environment variable is the name of the setting in upper case, with `-`
+//! replaced by `_` and prefixed with `ASSERTING_`. For example, the setting
+//! `highlight-diffs` corresponds to the environment variable
+//! `ASSERTING_HIGHLIGHT_DIFFS`. An environment variable takes precedence over
+//! the setting in the configuration file, so that developers can override
+//! shared settings with their personal preferences.
+//!
+//! The configuration file is located via the environment variable
+//! `CARGO_MANIFEST_DIR`, which is set by cargo when running tests. It is read
+//! once, on first use.
+
+use crate::env;
+use crate::env::VarError;
+use crate::std::str::Chars;
+use crate::std::string::String;
+use crate::std::vec::Vec;
+use hashbrown::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Name of the configuration file in the root directory of a package.
+const CONFIG_FILE_NAME: &str = "asserting.toml";
+
+/// The table in the `Cargo.toml` that contains the settings.
+const CARGO_METADATA_TABLE: &str = "package.metadata.asserting";
+
+/// Prefix of the environment variables that correspond to settings.
+const ENV_VAR_PREFIX: &str = "ASSERTING_";
+
+/// Returns the value of the given environment variable, or if it is not set,
+/// the value of the corresponding setting in the configuration file.
+pub fn var(name: &str) -> Result<String, VarError> {
+    match env::var(name) {
+        Err(VarError::NotPresent) => file_settings()
+            .get(name)
+            .cloned()
+            .ok_or(VarError::NotPresent),
+        result => result,
+    }
+}
+
+/// Returns the settings read from the configuration file, keyed by the name
+/// of the corresponding environment variable.
+fn file_settings() -> &'static HashMap<String, String> {
+    static FILE_SETTINGS: OnceLock<HashMap<String, String>> = OnceLock::new();
+    FILE_SETTINGS.get_or_init(read_file_settings)
+}
+
+fn read_file_settings() -> HashMap<String, String> {
+    let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") else {
+        return HashMap::new();
+    };
+    let manifest_dir = Path::new(&manifest_dir);
+    if let Ok(content) = fs::read_to_string(manifest_dir.join(CONFIG_FILE_NAME)) {
+        return parse_settings(&content, "");
+    }
+    fs::read_to_string(manifest_dir.join("Cargo.toml"))
+        .map(|content| parse_settings(&content, CARGO_METADATA_TABLE))
+        .unwrap_or_default()
+}
+
+/// Parses the settings in the given table of a TOML document.
+///
+/// The empty string denotes the top level of the document. Only the subset
+/// of TOML needed for settings is supported: `key = value` pairs, where the
+/// value is a string, an integer or a boolean. Lines that cannot be parsed
+/// are ignored. Values that span multiple lines, like arrays or multi-line
+/// strings, are skipped.
+///
+/// The keys of the returned map are the names of the environment variables
+/// that correspond to the settings.
+pub fn parse_settings(content: &str, table: &str) -> HashMap<String, String> {
+    let mut settings = HashMap::new();
+    let mut current_table = String::new();
+    let mut open_string = None;
+    let mut open_brackets = 0;
+    for line in content.lines() {
+        if let Some(delimiter) = open_string {
+            if line.contains(delimiter) {
+                open_string = None;
+            }
+            continue;
+        }
+        let line = line.trim();
+        if open_brackets > 0 {
+            open_brackets = count_open_brackets(line, open_brackets);
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = parse_table_header(line) {
+            current_table = header;
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        if let Some(delimiter) = ["\"\"\"", "'''"]
+            .into_iter()
+            .find(|delimiter| value.starts_with(delimiter))
+        {
+            if !value[delimiter.len()..].contains(delimiter) {
+                open_string = Some(delimiter);
+            }
+            continue;
+        }
+        if value.starts_with('[') {
+            open_brackets = count_open_brackets(value, 0);
+            if open_brackets > 0 {
+                continue;
+            }
+        }
+        if current_table != table {
+            continue;
+        }
+        let key = key.trim().trim_matches('"');
+        if let Some(value) = parse_value(value) {
+            settings.insert(env_var_name(key), value);
+        }
+    }
+    settings
+}
+
+/// Parses a table header, like `[package.metadata.asserting]`, which may be
+/// followed by a comment, and returns the name of the table.
+///
+/// Returns `None` if the line is not a table header. The segments of the name
+/// must be bare keys or quoted keys, so that an element of an array, like
+/// `["a", "b"],`, is not mistaken for a table header.
+fn parse_table_header(line: &str) -> Option<String> {
+    let (name, rest) = if let Some(name) = line.strip_prefix("[[") {
+        name.split_once("]]")?
+    } else {
+        line.strip_prefix('[')?.split_once(']')?
+    };
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return None;
+    }
+    name.split('.')
+        .map(|segment| {
+            let segment = segment.trim();
+            if let Some(quoted) = segment
+                .strip_prefix('"')
+                .and_then(|segment| segment.strip_suffix('"'))
+            {
+                (!quoted.contains('"')).then_some(quoted)
+            } else {
+                (!segment.is_empty()
+                    && segment.chars().all(|character| {
+                        character.is_ascii_alphanumeric() || character == '_' || character == '-'
+                    }))
+                .then_some(segment)
+            }
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|segments| segments.join("."))
+}
+
+/// Returns the number of brackets of an array value that are still open
+/// after the given line, starting with the given number of open brackets.
+///
+/// Brackets inside strings and comments are not counted.
+fn count_open_brackets(line: &str, mut open_brackets: usize) -> usize {
+    let mut chars = line.chars();
+    while let Some(character) = chars.next() {
+        match character {
+            '[' => open_brackets += 1,
+            ']' => open_brackets = open_brackets.saturating_sub(1),
+            '"' => {
+                while let Some(character) = chars.next() {
+                    match character {
+                        '\\' => _ = chars.next(),
+                        '"' => break,
+                        _ => {},
+                    }
+                }
+            },
+            '\'' => _ = chars.by_ref().find(|character| *character == '\''),
+            '#' => break,
+            _ => {},
+        }
+    }
+    open_brackets
+}
+
+/// Parses a string, integer or boolean value, which may be followed by a
+/// comment.
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let mut unquoted = String::new();
+        let mut chars = quoted.chars();
+        while let Some(character) = chars.next() {
+            match character {
+                '"' => return Some(unquoted),
+                '\\' => unquoted.push(parse_escape(&mut chars)?),
+                _ => unquoted.push(character),
+            }
+        }
+        return None;
+    }
+    if let Some(literal) = value.strip_prefix('\'') {
+        return literal
+            .split_once('\'')
+            .map(|(unquoted, _)| unquoted.into());
+    }
+    let value = value.split('#').next().unwrap_or_default().trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.into())
+    }
+}
+
+/// Parses the escape sequence following a backslash in a basic string.
+///
+/// Returns `None` if the escape sequence is not valid in TOML.
+fn parse_escape(chars: &mut Chars<'_>) -> Option<char> {
+    let unicode_digits = match chars.next()? {
+        'b' => return Some('\u{8}'),
+        't' => return Some('\t'),
+        'n' => return Some('\n'),
+        'f' => return Some('\u{c}'),
+        'r' => return Some('\r'),
+        '"' => return Some('"'),
+        '\\' => return Some('\\'),
+        'u' => 4,
+        'U' => 8,
+        _ => return None,
+    };
+    let hex_digits = chars.as_str().get(..unicode_digits)?;
+    let code_point = u32::from_str_radix(hex_digits, 16).ok()?;
+    chars.nth(unicode_digits - 1);
+    char::from_u32(code_point)
+}
+
+/// Returns the name of the environment variable that corresponds to the
+/// setting with the given key.
+fn env_var_name(key: &str) -> String {
+    let mut name = String::from(ENV_VAR_PREFIX);
+    name.extend(key.chars().map(|character| match character {
+        '-' => '_',
+        _ => character.to_ascii_uppercase(),
+    }));
+    name
+}
+
+#[cfg(test)]
+mod tests;
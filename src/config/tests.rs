@@ -0,0 +1,172 @@
+use super::*;
+use crate::prelude::*;
+
+#[test]
+fn parse_settings_at_top_level() {
+    let content = r#"
+highlight-diffs = "red-blue"
+diff-granularity = 'word'
+truncation-max-length = 200
+trace = true
+"#;
+
+    let settings = parse_settings(content, "");
+
+    assert_that(settings).contains_exactly_in_any_order([
+        ("ASSERTING_HIGHLIGHT_DIFFS".into(), "red-blue".into()),
+        ("ASSERTING_DIFF_GRANULARITY".into(), "word".into()),
+        ("ASSERTING_TRUNCATION_MAX_LENGTH".into(), "200".into()),
+        ("ASSERTING_TRACE".into(), "true".into()),
+    ]);
+}
+
+#[test]
+fn parse_settings_in_package_metadata_table_of_cargo_toml() {
+    let content = r#"
+[package]
+name = "some-crate"
+version = "0.1.0"
+
+[package.metadata.asserting]
+highlight-diffs = "bold"
+
+[dependencies]
+asserting = "0.15"
+"#;
+
+    let settings = parse_settings(content, CARGO_METADATA_TABLE);
+
+    assert_that(settings)
+        .contains_exactly_in_any_order([("ASSERTING_HIGHLIGHT_DIFFS".into(), "bold".into())]);
+}
+
+#[test]
+fn parse_settings_ignores_settings_in_other_tables() {
+    let content = r#"
+[package]
+name = "some-crate"
+
+[package.metadata.other]
+highlight-diffs = "bold"
+"#;
+
+    let settings = parse_settings(content, CARGO_METADATA_TABLE);
+
+    assert_that(settings).is_empty();
+}
+
+#[test]
+fn parse_settings_ignores_comments_and_invalid_lines() {
+    let content = r#"
+# shared settings for all developers
+highlight-diffs = "red-yellow" # CVD-friendly
+truncation-context = 5 # items
+this line is not a setting
+trace =
+"#;
+
+    let settings = parse_settings(content, "");
+
+    assert_that(settings).contains_exactly_in_any_order([
+        ("ASSERTING_HIGHLIGHT_DIFFS".into(), "red-yellow".into()),
+        ("ASSERTING_TRUNCATION_CONTEXT".into(), "5".into()),
+    ]);
+}
+
+#[test]
+fn parse_settings_with_quoted_table_names_and_escaped_strings() {
+    let content = r#"
+[ package . "metadata" . asserting ]
+"highlight-diffs" = "re\"d-blue"
+"#;
+
+    let settings = parse_settings(content, CARGO_METADATA_TABLE);
+
+    assert_that(settings)
+        .contains_exactly_in_any_order([("ASSERTING_HIGHLIGHT_DIFFS".into(), "re\"d-blue".into())]);
+}
+
+#[test]
+fn parse_settings_with_escape_sequences_in_strings() {
+    let content = r#"
+[package.metadata.asserting]
+first = "a\nb\tc\\d"
+second = "\u00e9\U0001F600"
+"#;
+
+    let settings = parse_settings(content, CARGO_METADATA_TABLE);
+
+    assert_that(settings).contains_exactly_in_any_order([
+        ("ASSERTING_FIRST".into(), "a\nb\tc\\d".into()),
+        ("ASSERTING_SECOND".into(), "\u{e9}\u{1f600}".into()),
+    ]);
+}
+
+#[test]
+fn parse_settings_ignores_strings_with_invalid_escape_sequences() {
+    let content = r#"
+[package.metadata.asserting]
+first = "a\qb"
+second = "\u00"
+"#;
+
+    let settings = parse_settings(content, CARGO_METADATA_TABLE);
+
+    assert_that(settings).is_empty();
+}
+
+#[test]
+fn parse_settings_skips_multi_line_arrays() {
+    let content = r#"
+[package.metadata.asserting]
+matrix = [
+    [1, 2], # first row
+    ["]", "[x]"],
+    [3, 4],
+]
+highlight-diffs = "red-blue"
+
+[other]
+truncation-context = 5
+"#;
+
+    let settings = parse_settings(content, CARGO_METADATA_TABLE);
+
+    assert_that(settings)
+        .contains_exactly_in_any_order([("ASSERTING_HIGHLIGHT_DIFFS".into(), "red-blue".into())]);
+}
+
+#[test]
+fn parse_settings_skips_multi_line_strings() {
+    let content = r#"
+[package.metadata.asserting]
+description = """
+[other]
+truncation-context = 5
+"""
+highlight-diffs = "red-blue"
+"#;
+
+    let settings = parse_settings(content, CARGO_METADATA_TABLE);
+
+    assert_that(settings)
+        .contains_exactly_in_any_order([("ASSERTING_HIGHLIGHT_DIFFS".into(), "red-blue".into())]);
+}
+
+#[test]
+fn env_var_takes_precedence_over_setting_in_configuration_file() {
+    env::set_var("ASSERTING_SOME_SETTING", "from-env");
+
+    let value = var("ASSERTING_SOME_SETTING");
+
+    env::remove_var("ASSERTING_SOME_SETTING");
+
+    assert_that(value).has_value(String::from("from-env"));
+}
+
+#[test]
+fn var_for_setting_that_is_neither_in_env_nor_in_configuration_file() {
+    let value = var("ASSERTING_NOT_EXISTING_SETTING");
+
+    assert_that(value).is_err();
+}
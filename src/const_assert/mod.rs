@@ -0,0 +1,119 @@
+//! Assertions that are evaluated at compile time.
+
+/// Asserts an invariant at compile time.
+///
+/// The subject must be a constant expression. The assertion is evaluated
+/// during compilation and fails the build with a readable message if the
+/// invariant does not hold. This is useful for invariants that should never
+/// reach runtime, like the relation between configuration constants or the
+/// length of lookup tables.
+///
+/// The available checks use the same names as the corresponding assertion
+/// methods of the runtime API:
+///
+/// | check                        | subject                                    |
+/// |------------------------------|--------------------------------------------|
+/// | `is_equal_to(expected)`      | integers, `bool`, `char`                   |
+/// | `is_not_equal_to(expected)`  | integers, `bool`, `char`                   |
+/// | `is_less_than(expected)`     | integers, `char`                           |
+/// | `is_greater_than(expected)`  | integers, `char`                           |
+/// | `is_at_most(expected)`       | integers, `char`                           |
+/// | `is_at_least(expected)`      | integers, `char`                           |
+/// | `is_true()`                  | `bool`                                     |
+/// | `is_false()`                 | `bool`                                     |
+/// | `has_length(expected)`       | arrays, slices, `&str`                     |
+/// | `is_empty()`                 | arrays, slices, `&str`                     |
+/// | `is_not_empty()`             | arrays, slices, `&str`                     |
+///
+/// As the failure message is composed at compile time, it contains the
+/// expressions as written in the source code but not their values.
+///
+/// The macro can be used wherever items are allowed, e.g., at module level or
+/// inside a function body.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// const MAX_RETRIES: u32 = 5;
+/// const MIN_RETRIES: u32 = 1;
+/// const LOOKUP_TABLE: [u8; 4] = [0, 1, 4, 9];
+///
+/// const_assert_that!(MAX_RETRIES, is_greater_than(MIN_RETRIES));
+/// const_assert_that!(LOOKUP_TABLE, has_length(4));
+/// const_assert_that!(cfg!(debug_assertions) || MAX_RETRIES > 0, is_true());
+/// ```
+///
+/// A failing assertion fails the compilation with the message
+/// `expected MAX_RETRIES to be at most 3`:
+///
+/// ```compile_fail
+/// use asserting::prelude::*;
+///
+/// const MAX_RETRIES: u32 = 5;
+///
+/// const_assert_that!(MAX_RETRIES, is_at_most(3));
+/// ```
+#[macro_export]
+macro_rules! const_assert_that {
+    ($subject:expr, is_equal_to($expected:expr)) => {
+        $crate::const_assert_that!(@check $subject == $expected,
+            $subject, " to be equal to ", stringify!($expected));
+    };
+    ($subject:expr, is_not_equal_to($expected:expr)) => {
+        $crate::const_assert_that!(@check $subject != $expected,
+            $subject, " to be not equal to ", stringify!($expected));
+    };
+    ($subject:expr, is_less_than($expected:expr)) => {
+        $crate::const_assert_that!(@check $subject < $expected,
+            $subject, " to be less than ", stringify!($expected));
+    };
+    ($subject:expr, is_greater_than($expected:expr)) => {
+        $crate::const_assert_that!(@check $subject > $expected,
+            $subject, " to be greater than ", stringify!($expected));
+    };
+    ($subject:expr, is_at_most($expected:expr)) => {
+        $crate::const_assert_that!(@check $subject <= $expected,
+            $subject, " to be at most ", stringify!($expected));
+    };
+    ($subject:expr, is_at_least($expected:expr)) => {
+        $crate::const_assert_that!(@check $subject >= $expected,
+            $subject, " to be at least ", stringify!($expected));
+    };
+    ($subject:expr, is_true()) => {
+        $crate::const_assert_that!(@check $subject,
+            $subject, " to be true", "");
+    };
+    ($subject:expr, is_false()) => {
+        $crate::const_assert_that!(@check !$subject,
+            $subject, " to be false", "");
+    };
+    ($subject:expr, has_length($expected:expr)) => {
+        $crate::const_assert_that!(@check $subject.len() == $expected,
+            $subject, " to have a length of ", stringify!($expected));
+    };
+    ($subject:expr, is_empty()) => {
+        $crate::const_assert_that!(@check $subject.is_empty(),
+            $subject, " to be empty", "");
+    };
+    ($subject:expr, is_not_empty()) => {
+        $crate::const_assert_that!(@check !$subject.is_empty(),
+            $subject, " to be not empty", "");
+    };
+    (@check $condition:expr, $subject:expr, $description:literal, $expected:expr) => {
+        const _: () = {
+            if !($condition) {
+                panic!(concat!(
+                    "expected ",
+                    stringify!($subject),
+                    $description,
+                    $expected
+                ));
+            }
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,32 @@
+const ANSWER: i32 = 42;
+const LIMIT: usize = 64;
+const ENABLED: bool = true;
+const LETTER: char = 'k';
+const TABLE: [u16; 4] = [1, 2, 4, 8];
+const EMPTY_TABLE: [u16; 0] = [];
+const GREETING: &str = "hello";
+
+const_assert_that!(ANSWER, is_equal_to(42));
+const_assert_that!(ANSWER, is_not_equal_to(41));
+const_assert_that!(ANSWER, is_less_than(43));
+const_assert_that!(ANSWER, is_greater_than(41));
+const_assert_that!(ANSWER, is_at_most(42));
+const_assert_that!(ANSWER, is_at_least(42));
+const_assert_that!(LIMIT, is_at_least(TABLE.len()));
+const_assert_that!(LETTER, is_less_than('z'));
+const_assert_that!(ENABLED, is_true());
+const_assert_that!(ENABLED, is_equal_to(true));
+const_assert_that!(LIMIT < 32, is_false());
+const_assert_that!(TABLE, has_length(4));
+const_assert_that!(TABLE, is_not_empty());
+const_assert_that!(EMPTY_TABLE, is_empty());
+const_assert_that!(GREETING, has_length(5));
+const_assert_that!(GREETING, is_not_empty());
+const_assert_that!("", is_empty());
+
+#[test]
+fn const_assert_that_can_be_used_inside_a_function_body() {
+    const SIZE: usize = 3;
+    const_assert_that!([0_u8; SIZE], has_length(SIZE));
+    const_assert_that!(SIZE * 2, is_equal_to(6));
+}
@@ -0,0 +1,353 @@
+//! Implementation of assertions for dates and date-times.
+//!
+//! The assertions are implemented for all types that implement the
+//! [`DateTimeProperties`]. Implementations of the properties for the types of
+//! the `chrono` and the `time` crate are provided in separate modules behind
+//! the crate features of the same name.
+
+use crate::assertions::{AssertCalendarDate, AssertDateTime};
+use crate::colored::{DIFF_FORMAT_NO_HIGHLIGHT, mark_missing_string, mark_unexpected_string};
+use crate::expectations::{
+    IsAfterDate, IsBeforeDate, IsInYear, IsSameDayAs, IsWithinDurationOf, is_after_date,
+    is_before_date, is_in_year, is_same_day_as, is_within_duration_of,
+};
+use crate::properties::DateTimeProperties;
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::format;
+use crate::std::string::String;
+use crate::std::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+impl<S, E, R> AssertDateTime<E> for Spec<'_, S, R>
+where
+    S: DateTimeProperties,
+    E: DateTimeProperties,
+    R: FailingStrategy,
+{
+    fn is_before_date(self, expected: E) -> Self {
+        self.expecting(is_before_date(expected))
+    }
+
+    fn is_after_date(self, expected: E) -> Self {
+        self.expecting(is_after_date(expected))
+    }
+
+    fn is_same_day_as(self, expected: E) -> Self {
+        self.expecting(is_same_day_as(expected))
+    }
+
+    fn is_within_duration_of(self, expected: E, tolerance: Duration) -> Self {
+        self.expecting(is_within_duration_of(expected, tolerance))
+    }
+}
+
+impl<S, R> AssertCalendarDate for Spec<'_, S, R>
+where
+    S: DateTimeProperties,
+    R: FailingStrategy,
+{
+    fn is_in_year(self, expected_year: i32) -> Self {
+        self.expecting(is_in_year(expected_year))
+    }
+}
+
+impl<S, E> Expectation<S> for IsBeforeDate<E>
+where
+    S: DateTimeProperties,
+    E: DateTimeProperties,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_before_date";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.unix_timestamp_nanos_property() < self.expected.unix_timestamp_nanos_property()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let (not, cmp) = if inverted { ("not ", ">=") } else { ("", "<") };
+        let expected = DateTimeComponents::of(&self.expected);
+        let actual = DateTimeComponents::of(actual);
+        let marked_actual =
+            actual.mark_differences(&expected, Compare::All, mark_unexpected_string, format);
+        let marked_expected =
+            expected.mark_differences(&actual, Compare::All, mark_missing_string, format);
+        format!(
+            "expected {expression} to be {not}before {}\n   but was: {marked_actual}\n  expected: {cmp} {marked_expected}",
+            expected.render(&actual),
+        )
+    }
+}
+
+impl<E> Invertible for IsBeforeDate<E> {}
+
+impl<S, E> Expectation<S> for IsAfterDate<E>
+where
+    S: DateTimeProperties,
+    E: DateTimeProperties,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_after_date";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.unix_timestamp_nanos_property() > self.expected.unix_timestamp_nanos_property()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let (not, cmp) = if inverted { ("not ", "<=") } else { ("", ">") };
+        let expected = DateTimeComponents::of(&self.expected);
+        let actual = DateTimeComponents::of(actual);
+        let marked_actual =
+            actual.mark_differences(&expected, Compare::All, mark_unexpected_string, format);
+        let marked_expected =
+            expected.mark_differences(&actual, Compare::All, mark_missing_string, format);
+        format!(
+            "expected {expression} to be {not}after {}\n   but was: {marked_actual}\n  expected: {cmp} {marked_expected}",
+            expected.render(&actual),
+        )
+    }
+}
+
+impl<E> Invertible for IsAfterDate<E> {}
+
+impl<S, E> Expectation<S> for IsSameDayAs<E>
+where
+    S: DateTimeProperties,
+    E: DateTimeProperties,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_same_day_as";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.date_property() == self.expected.date_property()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = DateTimeComponents::of(&self.expected);
+        let actual = DateTimeComponents::of(actual);
+        let marked_actual =
+            actual.mark_differences(&expected, Compare::Date, mark_unexpected_string, format);
+        let marked_expected =
+            expected.mark_differences(&actual, Compare::Date, mark_missing_string, format);
+        format!(
+            "expected {expression} to be {not}on the same day as {}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}",
+            expected.render(&actual),
+        )
+    }
+}
+
+impl<E> Invertible for IsSameDayAs<E> {}
+
+impl<S, E> Expectation<S> for IsWithinDurationOf<E>
+where
+    S: DateTimeProperties,
+    E: DateTimeProperties,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_within_duration_of";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let difference = subject
+            .unix_timestamp_nanos_property()
+            .abs_diff(self.expected.unix_timestamp_nanos_property());
+        difference <= self.tolerance.as_nanos()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let tolerance = self.tolerance;
+        let difference = duration_from_nanos(
+            actual
+                .unix_timestamp_nanos_property()
+                .abs_diff(self.expected.unix_timestamp_nanos_property()),
+        );
+        let expected = DateTimeComponents::of(&self.expected);
+        let actual = DateTimeComponents::of(actual);
+        let marked_actual =
+            actual.mark_differences(&expected, Compare::All, mark_unexpected_string, format);
+        let marked_expected =
+            expected.mark_differences(&actual, Compare::All, mark_missing_string, format);
+        format!(
+            "expected {expression} to be {not}within {tolerance:?} of {}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}\ndifference: {difference:?}",
+            expected.render(&actual),
+        )
+    }
+}
+
+impl<E> Invertible for IsWithinDurationOf<E> {}
+
+impl<S> Expectation<S> for IsInYear
+where
+    S: DateTimeProperties,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_in_year";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.date_property().0 == self.expected_year
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected_year = self.expected_year;
+        let actual = DateTimeComponents::of(actual);
+        let mut expected = actual;
+        expected.year = expected_year;
+        let marked_actual =
+            actual.mark_differences(&expected, Compare::Year, mark_unexpected_string, format);
+        let marked_expected = mark_missing_string(&format_year(expected_year), format);
+        format!(
+            "expected {expression} to be {not}in year {expected_year}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl Invertible for IsInYear {}
+
+fn duration_from_nanos(nanos: u128) -> Duration {
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).unwrap_or(u64::MAX);
+    #[allow(clippy::cast_possible_truncation)]
+    let subsec_nanos = (nanos % NANOS_PER_SEC) as u32;
+    Duration::new(secs, subsec_nanos)
+}
+
+fn format_year(year: i32) -> String {
+    if year < 0 {
+        format!("-{:04}", year.unsigned_abs())
+    } else {
+        format!("{year:04}")
+    }
+}
+
+/// Which components of a date or date-time are compared when highlighting
+/// differences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compare {
+    Year,
+    Date,
+    All,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DateTimeComponents {
+    year: i32,
+    month: u8,
+    day: u8,
+    time_of_day: Option<(u8, u8, u8, u32)>,
+}
+
+impl DateTimeComponents {
+    fn of(value: &impl DateTimeProperties) -> Self {
+        let (year, month, day) = value.date_property();
+        Self {
+            year,
+            month,
+            day,
+            time_of_day: value.time_of_day_property(),
+        }
+    }
+
+    /// Renders the components without any highlighting.
+    ///
+    /// The fraction of a second is only rendered if this or the other value
+    /// has a fraction of a second.
+    fn render(&self, other: &Self) -> String {
+        self.mark_differences(
+            other,
+            Compare::All,
+            mark_unexpected_string,
+            &DIFF_FORMAT_NO_HIGHLIGHT,
+        )
+    }
+
+    /// Renders the components in the format `YYYY-MM-DD hh:mm:ss.fffffffff`
+    /// and marks each component that differs from the same component of the
+    /// other value using the given `mark` function.
+    ///
+    /// A value without a time of day is compared as midnight.
+    fn mark_differences(
+        &self,
+        other: &Self,
+        compare: Compare,
+        mark: fn(&str, &DiffFormat) -> String,
+        format: &DiffFormat,
+    ) -> String {
+        let component = |string: String, differs: bool| {
+            if differs {
+                mark(&string, format)
+            } else {
+                string
+            }
+        };
+        let compare_date = compare != Compare::Year;
+        let compare_time = compare == Compare::All;
+        let date = format!(
+            "{}-{}-{}",
+            component(format_year(self.year), self.year != other.year),
+            component(
+                format!("{:02}", self.month),
+                compare_date && self.month != other.month
+            ),
+            component(
+                format!("{:02}", self.day),
+                compare_date && self.day != other.day
+            ),
+        );
+        let Some((hour, minute, second, nanos)) = self.time_of_day else {
+            return date;
+        };
+        let (other_hour, other_minute, other_second, other_nanos) =
+            other.time_of_day.unwrap_or_default();
+        let fraction = if nanos != 0 || other_nanos != 0 {
+            format!(
+                ".{}",
+                component(format!("{nanos:09}"), compare_time && nanos != other_nanos)
+            )
+        } else {
+            String::new()
+        };
+        format!(
+            "{date} {}:{}:{}{fraction}",
+            component(format!("{hour:02}"), compare_time && hour != other_hour),
+            component(
+                format!("{minute:02}"),
+                compare_time && minute != other_minute
+            ),
+            component(
+                format!("{second:02}"),
+                compare_time && second != other_second
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
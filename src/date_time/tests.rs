@@ -0,0 +1,361 @@
+use crate::expectations::{is_before_date, is_in_year, is_same_day_as, is_within_duration_of, not};
+use crate::prelude::*;
+use crate::std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+struct Moment {
+    date: (i32, u8, u8),
+    time_of_day: Option<(u8, u8, u8, u32)>,
+}
+
+const fn date(year: i32, month: u8, day: u8) -> Moment {
+    Moment {
+        date: (year, month, day),
+        time_of_day: None,
+    }
+}
+
+const fn date_time(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Moment {
+    Moment {
+        date: (year, month, day),
+        time_of_day: Some((hour, minute, second, 0)),
+    }
+}
+
+const fn with_nanos(moment: Moment, nanos: u32) -> Moment {
+    let (hour, minute, second, _) = match moment.time_of_day {
+        Some(time_of_day) => time_of_day,
+        None => (0, 0, 0, 0),
+    };
+    Moment {
+        date: moment.date,
+        time_of_day: Some((hour, minute, second, nanos)),
+    }
+}
+
+impl DateTimeProperties for Moment {
+    fn date_property(&self) -> (i32, u8, u8) {
+        self.date
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        self.time_of_day
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        // days from civil, see http://howardhinnant.github.io/date_algorithms.html
+        let (year, month, day) = self.date;
+        let year = i64::from(year) - i64::from(month <= 2);
+        let era = if year >= 0 { year } else { year - 399 } / 400;
+        let year_of_era = year - era * 400;
+        let month = i64::from(month);
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146_097 + day_of_era - 719_468;
+        let (hour, minute, second, nanos) = self.time_of_day.unwrap_or_default();
+        let seconds =
+            days * 86_400 + i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
+        i128::from(seconds) * 1_000_000_000 + i128::from(nanos)
+    }
+}
+
+#[test]
+fn date_is_before_date() {
+    assert_that(date(2024, 3, 15)).is_before_date(date(2024, 3, 16));
+    assert_that(date(2023, 12, 31)).is_before_date(date(2024, 1, 1));
+    assert_that(date_time(2024, 3, 15, 23, 59, 59)).is_before_date(date(2024, 3, 16));
+}
+
+#[test]
+fn verify_date_is_before_date_fails() {
+    let failures = verify_that(date(2024, 3, 16))
+        .named("released_on")
+        .is_before_date(date(2024, 3, 15))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected released_on to be before 2024-03-15
+   but was: 2024-03-16
+  expected: < 2024-03-15
+"]
+    );
+}
+
+#[test]
+fn verify_date_time_is_not_before_date_fails() {
+    let failures = verify_that(date_time(2024, 3, 15, 10, 30, 0))
+        .named("published_at")
+        .expecting(not(is_before_date(date(2024, 3, 16))))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected published_at to be not before 2024-03-16
+   but was: 2024-03-15 10:30:00
+  expected: >= 2024-03-16
+"]
+    );
+}
+
+#[test]
+fn date_time_is_after_date() {
+    assert_that(date_time(2024, 3, 15, 0, 0, 1)).is_after_date(date(2024, 3, 15));
+    assert_that(date(2024, 3, 16)).is_after_date(date_time(2024, 3, 15, 23, 59, 59));
+}
+
+#[test]
+fn verify_date_time_is_after_date_fails() {
+    let failures = verify_that(date_time(2024, 3, 15, 10, 30, 0))
+        .named("published_at")
+        .is_after_date(date_time(2024, 3, 15, 10, 30, 0))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected published_at to be after 2024-03-15 10:30:00
+   but was: 2024-03-15 10:30:00
+  expected: > 2024-03-15 10:30:00
+"]
+    );
+}
+
+#[test]
+fn date_time_is_same_day_as_other_date_time() {
+    assert_that(date_time(2024, 3, 15, 0, 0, 0)).is_same_day_as(date_time(2024, 3, 15, 23, 59, 59));
+    assert_that(date_time(2024, 3, 15, 10, 30, 0)).is_same_day_as(date(2024, 3, 15));
+    assert_that(date(2024, 3, 15)).is_same_day_as(date(2024, 3, 15));
+}
+
+#[test]
+fn verify_date_time_is_same_day_as_fails() {
+    let failures = verify_that(date_time(2024, 3, 16, 10, 30, 0))
+        .named("published_at")
+        .is_same_day_as(date(2024, 3, 15))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected published_at to be on the same day as 2024-03-15
+   but was: 2024-03-16 10:30:00
+  expected: 2024-03-15
+"]
+    );
+}
+
+#[test]
+fn date_is_not_same_day_as_other_date() {
+    assert_that(date(2024, 3, 15)).expecting(not(is_same_day_as(date(2023, 3, 15))));
+}
+
+#[test]
+fn date_time_is_within_duration_of_other_date_time() {
+    let subject = date_time(2024, 3, 15, 10, 30, 0);
+
+    assert_that(subject)
+        .is_within_duration_of(date_time(2024, 3, 15, 10, 30, 5), Duration::from_secs(5));
+    assert_that(subject)
+        .is_within_duration_of(date_time(2024, 3, 15, 10, 29, 55), Duration::from_secs(5));
+    assert_that(subject).is_within_duration_of(subject, Duration::ZERO);
+    assert_that(date(2024, 3, 16))
+        .is_within_duration_of(date_time(2024, 3, 15, 23, 59, 59), Duration::from_secs(1));
+}
+
+#[test]
+fn verify_date_time_is_within_duration_of_other_date_time_fails() {
+    let failures = verify_that(date_time(2024, 3, 15, 10, 30, 0))
+        .named("published_at")
+        .is_within_duration_of(date_time(2024, 3, 15, 10, 31, 30), Duration::from_secs(60))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected published_at to be within 60s of 2024-03-15 10:31:30
+   but was: 2024-03-15 10:30:00
+  expected: 2024-03-15 10:31:30
+difference: 90s
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_date_time_is_not_within_duration_of_other_date_time_fails() {
+    let failures = verify_that(with_nanos(date_time(2024, 3, 15, 10, 30, 0), 500_000_000))
+        .named("published_at")
+        .expecting(not(is_within_duration_of(
+            date_time(2024, 3, 15, 10, 30, 0),
+            Duration::from_secs(1),
+        )))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected published_at to be not within 1s of 2024-03-15 10:30:00.000000000
+   but was: 2024-03-15 10:30:00.500000000
+  expected: not 2024-03-15 10:30:00.000000000
+difference: 500ms
+"
+        ]
+    );
+}
+
+#[test]
+fn date_is_in_year() {
+    assert_that(date(2024, 1, 1)).is_in_year(2024);
+    assert_that(date_time(2023, 12, 31, 23, 59, 59)).is_in_year(2023);
+    assert_that(date(-44, 3, 15)).is_in_year(-44);
+}
+
+#[test]
+fn verify_date_is_in_year_fails() {
+    let failures = verify_that(date(2023, 12, 31))
+        .named("released_on")
+        .is_in_year(2024)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected released_on to be in year 2024
+   but was: 2023-12-31
+  expected: 2024
+"]
+    );
+}
+
+#[test]
+fn verify_date_is_not_in_year_fails() {
+    let failures = verify_that(date(-44, 3, 15))
+        .named("ides_of_march")
+        .expecting(not(is_in_year(-44)))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected ides_of_march to be not in year -44
+   but was: -0044-03-15
+  expected: not -0044
+"]
+    );
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use super::{date, date_time, with_nanos};
+    use crate::prelude::*;
+    use crate::std::time::Duration;
+
+    #[test]
+    fn highlight_diffs_date_is_before_date() {
+        let failures = verify_that(date(2024, 3, 16))
+            .named("released_on")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_before_date(date(2024, 3, 15))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected released_on to be before 2024-03-15\n   \
+                  but was: 2024-03-\u{1b}[31m16\u{1b}[0m\n  \
+                 expected: < 2024-03-\u{1b}[34m15\u{1b}[0m\n\
+            "]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_date_time_is_after_date_time() {
+        let failures = verify_that(date_time(2023, 3, 15, 10, 30, 0))
+            .named("published_at")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_after_date(date_time(2024, 3, 15, 10, 45, 0))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected published_at to be after 2024-03-15 10:45:00\n   \
+                  but was: \u{1b}[31m2023\u{1b}[0m-03-15 10:\u{1b}[31m30\u{1b}[0m:00\n  \
+                 expected: > \u{1b}[34m2024\u{1b}[0m-03-15 10:\u{1b}[34m45\u{1b}[0m:00\n\
+            "]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_date_time_is_before_date_highlights_time_of_day_as_differing_from_midnight()
+    {
+        let failures = verify_that(date_time(2024, 3, 15, 10, 0, 0))
+            .named("published_at")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_before_date(date(2024, 3, 15))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected published_at to be before 2024-03-15\n   \
+                  but was: 2024-03-15 \u{1b}[31m10\u{1b}[0m:00:00\n  \
+                 expected: < 2024-03-15\n\
+            "]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_date_time_is_same_day_as_highlights_date_components_only() {
+        let failures = verify_that(date_time(2024, 4, 16, 10, 30, 0))
+            .named("published_at")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_same_day_as(date_time(2024, 3, 16, 8, 0, 0))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected published_at to be on the same day as 2024-03-16 08:00:00\n   \
+                  but was: 2024-\u{1b}[31m04\u{1b}[0m-16 10:30:00\n  \
+                 expected: 2024-\u{1b}[34m03\u{1b}[0m-16 08:00:00\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_date_time_is_within_duration_of_highlights_fraction_of_second() {
+        let failures = verify_that(with_nanos(date_time(2024, 3, 15, 10, 30, 0), 250_000_000))
+            .named("published_at")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_within_duration_of(
+                date_time(2024, 3, 15, 10, 30, 0),
+                Duration::from_millis(100),
+            )
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected published_at to be within 100ms of 2024-03-15 10:30:00.000000000\n   \
+                  but was: 2024-03-15 10:30:00.\u{1b}[31m250000000\u{1b}[0m\n  \
+                 expected: 2024-03-15 10:30:00.\u{1b}[34m000000000\u{1b}[0m\n\
+               difference: 250ms\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_date_is_in_year_highlights_the_year_only() {
+        let failures = verify_that(date(2023, 12, 31))
+            .named("released_on")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_in_year(2024)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected released_on to be in year 2024\n   \
+                  but was: \u{1b}[31m2023\u{1b}[0m-12-31\n  \
+                 expected: \u{1b}[34m2024\u{1b}[0m\n\
+            "]
+        );
+    }
+}
@@ -1,51 +1,95 @@
 //! Defines the [`DerivedSpec`], which keeps track of the original subject while doing assertions
 //! on a derived subject.
 
+use crate::assertion_id;
 use crate::assertions::{
-    AssertBoolean, AssertChar, AssertDebugString, AssertDecimalNumber, AssertDisplayString,
-    AssertElements, AssertEmptiness, AssertEquality, AssertErrorHasSource, AssertHasCharCount,
-    AssertHasDebugString, AssertHasDisplayString, AssertHasError, AssertHasErrorMessage,
-    AssertHasLength, AssertHasValue, AssertInRange, AssertInfinity, AssertIteratorContains,
-    AssertIteratorContainsInAnyOrder, AssertIteratorContainsInOrder, AssertMapContainsKey,
-    AssertMapContainsValue, AssertNotANumber, AssertNumericIdentity, AssertOption,
-    AssertOptionValue, AssertOrder, AssertOrderedElements, AssertOrderedElementsRef, AssertResult,
-    AssertResultValue, AssertSameAs, AssertSignum, AssertStringContainsAnyOf, AssertStringPattern,
+    AssertBits, AssertBoolean, AssertBytes, AssertCalendarDate, AssertChar, AssertDateTime,
+    AssertDebugString, AssertDecimalNumber, AssertDefault, AssertDisplayString, AssertDowncast,
+    AssertElements, AssertEmptiness, AssertEquality, AssertErrorHasSource, AssertErrorMessage,
+    AssertFuturePoll, AssertHasCapacity, AssertHasCharCount, AssertHasDebugString,
+    AssertHasDisplayString, AssertHasError, AssertHasErrorMatching, AssertHasErrorMessage,
+    AssertHasErrorOfType, AssertHasLength, AssertHasValue, AssertInRange, AssertInfinity,
+    AssertInnerValue, AssertInstanceOf, AssertIpAddr, AssertIsSortedBy, AssertIteratorContains,
+    AssertIteratorContainsInAnyOrder, AssertIteratorContainsInOrder, AssertIteratorCount,
+    AssertKeyValuePairs, AssertMapContainsKey, AssertMapContainsValue, AssertMapEquality,
+    AssertMapKeysSorted, AssertMapValue, AssertMatchesPattern, AssertMonotonicity,
+    AssertNestedOptionValue, AssertNestedResultValue, AssertNotANumber, AssertNumericIdentity,
+    AssertOption, AssertOptionHasDefaultValue, AssertOptionIsNoneOr, AssertOptionValue,
+    AssertOrder, AssertOrderedElements, AssertOrderedElementsRef, AssertOrderedMapEntries,
+    AssertPairKeys, AssertPoll, AssertPollValue, AssertRange, AssertResult, AssertResultElements,
+    AssertResultValue, AssertSameAs, AssertSignum, AssertSocketAddr, AssertStringContainsAnyOf,
+    AssertStringOccurrences, AssertStringPattern, AssertStringSimilarity, AssertStringWildcard,
+    AssertUtf8Content, AssertUtf8Text, AssertValuesForKey, AssertWithin,
 };
+use crate::colored::layout_failure_message;
 use crate::expectations::{
-    error_has_source, error_has_source_message, has_at_least_char_count, has_at_least_length,
-    has_at_least_number_of_elements, has_at_most_char_count, has_at_most_length, has_char_count,
-    has_char_count_greater_than, has_char_count_in_range, has_char_count_less_than,
-    has_debug_string, has_display_string, has_error, has_length, has_length_greater_than,
-    has_length_in_range, has_length_less_than, has_precision_of, has_scale_of, has_value,
-    is_a_number, is_after, is_alphabetic, is_alphanumeric, is_ascii, is_at_least, is_at_most,
-    is_before, is_between, is_control_char, is_digit, is_empty, is_equal_to, is_err, is_false,
-    is_finite, is_greater_than, is_in_range, is_infinite, is_integer, is_less_than, is_lower_case,
-    is_negative, is_none, is_ok, is_one, is_positive, is_same_as, is_some, is_true, is_upper_case,
-    is_whitespace, is_zero, iterator_contains, iterator_contains_all_in_order,
-    iterator_contains_all_of, iterator_contains_any_of, iterator_contains_exactly,
-    iterator_contains_exactly_in_any_order, iterator_contains_only, iterator_contains_only_once,
-    iterator_contains_sequence, iterator_ends_with, iterator_starts_with,
-    map_contains_exactly_keys, map_contains_key, map_contains_keys, map_contains_value,
-    map_contains_values, map_does_not_contain_keys, map_does_not_contain_values, not, satisfies,
-    string_contains, string_contains_any_of, string_ends_with, string_starts_with,
+    MatchesPattern, all_err, all_ok, any_err, contains_pair, contains_subsequence,
+    error_has_message, error_has_message_containing, error_has_source, error_has_source_message,
+    has_at_least_char_count, has_at_least_length, has_at_least_number_of_elements,
+    has_at_most_char_count, has_at_most_length, has_bit_set, has_bits_set, has_capacity,
+    has_capacity_at_least, has_char_count, has_char_count_greater_than, has_char_count_in_range,
+    has_char_count_less_than, has_count, has_count_at_least, has_count_in_range, has_debug_string,
+    has_display_string, has_error, has_error_matching, has_error_of_type, has_error_satisfying,
+    has_ip, has_length, has_length_greater_than, has_length_in_range, has_length_less_than,
+    has_no_bits_set, has_port, has_precision_of, has_scale_of, has_spare_capacity_of,
+    has_utf8_content, has_value, has_value_equal_to_default, is_a_number, is_after, is_after_date,
+    is_alphabetic, is_alphanumeric, is_ascii, is_at_least, is_at_most, is_before, is_before_date,
+    is_between, is_control_char, is_default, is_digit, is_empty, is_equal_to, is_equal_to_bytes,
+    is_err, is_false, is_false_because, is_finite, is_greater_than, is_in_range,
+    is_in_range_exclusive, is_in_year, is_infinite, is_instance_of, is_integer, is_ipv4, is_ipv6,
+    is_less_than, is_loopback, is_lower_case, is_negative, is_nested_some, is_non_decreasing,
+    is_non_increasing, is_none, is_none_or, is_ok, is_ok_some, is_one, is_pending, is_positive,
+    is_private, is_ready, is_ready_with, is_same_as, is_same_day_as, is_similar_to, is_some,
+    is_sorted_by, is_sorted_by_key, is_strictly_decreasing, is_strictly_increasing,
+    is_sub_range_of, is_true, is_true_because, is_upper_case, is_whitespace, is_within,
+    is_within_duration_of, is_within_percent, is_zero, iterator_contains,
+    iterator_contains_all_in_order, iterator_contains_all_of, iterator_contains_any_of,
+    iterator_contains_exactly, iterator_contains_exactly_in_any_order,
+    iterator_contains_exactly_shuffled, iterator_contains_only, iterator_contains_only_once,
+    iterator_contains_sequence, iterator_ends_with, iterator_ends_with_in_any_order,
+    iterator_starts_with, iterator_starts_with_in_any_order, keys_contain,
+    map_contains_entries_in_order, map_contains_exactly_keys, map_contains_key, map_contains_keys,
+    map_contains_value, map_contains_values, map_does_not_contain_keys,
+    map_does_not_contain_values, map_first_entry_is, map_is_equal_to, map_keys_are_sorted,
+    map_last_entry_is, not, range_contains_value, range_overlaps_with, satisfies,
+    starts_with_bytes, string_contains, string_contains_any_of, string_contains_at_most_n_times,
+    string_contains_n_times, string_ends_with, string_matches_wildcard, string_starts_with,
 };
+use crate::iterator::{count_elements_up_to, count_limit_for_range, shuffle};
+use crate::key_value_pairs::{find_values_for_key, take_values_for_key};
+use crate::map::find_value_of;
+use crate::poll::poll_once;
 use crate::properties::{
-    AdditiveIdentityProperty, CharCountProperty, DecimalProperties, DefinedOrderProperty,
-    InfinityProperty, IsEmptyProperty, IsNanProperty, LengthProperty, MapProperties,
-    MultiplicativeIdentityProperty, SignumProperty,
+    AdditiveIdentityProperty, AnyTypeProperty, BitsProperty, CapacityProperty, CharCountProperty,
+    DateTimeProperties, DecimalProperties, DefaultProperty, DefinedOrderProperty, DistanceProperty,
+    ErrorTypeProperty, InfinityProperty, InnerValueProperty, IpAddrProperty, IsEmptyProperty,
+    IsNanProperty, KeyValuePairsProperty, LengthProperty, MapProperties,
+    MultiplicativeIdentityProperty, PortProperty, SignumProperty,
 };
+#[cfg(feature = "std")]
+use crate::spec::format_debug_log;
 use crate::spec::{
-    And, AssertFailure, CollectFailures, DiffFormat, DoFail, Expectation, Expecting, Expression,
-    FailingStrategy, GetFailures, GetLocation, Location, PanicOnFail, Satisfies, SoftPanic, Spec,
+    And, AssertFailure, CollectFailures, ComparedBy, DiffFormat, DoFail, Expectation,
+    ExpectationWithContext, Expecting, Expression, FailingStrategy, GetFailures, GetLocation,
+    Location, PanicOnFail, Satisfies, SoftPanic, Spec, SpecMetadata, Unknown, WithoutContext,
+    format_eval_error,
 };
+use crate::std::any::{self, Any};
 use crate::std::borrow::{Cow, ToOwned};
+use crate::std::boxed::Box;
 use crate::std::error::Error;
 use crate::std::fmt::{Debug, Display};
 use crate::std::format;
+use crate::std::future::Future;
 use crate::std::ops::RangeBounds;
+use crate::std::rc::Rc;
 use crate::std::slice;
 use crate::std::string::{String, ToString};
+use crate::std::task::Poll;
+use crate::std::time::Duration;
 use crate::std::vec::Vec;
+#[cfg(feature = "std")]
+use crate::{listener, trace};
 use hashbrown::HashSet;
 
 /// A `DerivedSpec` does assertions on a derived subject while keeping track
@@ -61,9 +105,12 @@ use hashbrown::HashSet;
 /// [`Spec`]: Spec
 pub struct DerivedSpec<'a, O, S> {
     original: O,
-    subject: S,
+    /// The subject, or `None` if a preceding assertion failed to narrow the
+    /// subject, like `some()` on a `None` value.
+    subject: Option<S>,
     expression: Expression<'a>,
     diff_format: DiffFormat,
+    ignores_order: bool,
 }
 
 impl<O, S> DerivedSpec<'_, O, S> {
@@ -76,21 +123,38 @@ impl<O, S> DerivedSpec<'_, O, S> {
     pub const fn diff_format(&self) -> &DiffFormat {
         &self.diff_format
     }
+
+    /// Returns whether the order-sensitive assertions of this derived spec
+    /// ignore the order of the elements.
+    ///
+    /// See [`Spec::ignoring_order()`] for details.
+    pub const fn ignores_order(&self) -> bool {
+        self.ignores_order
+    }
+
+    /// Returns whether the assertions of this derived spec are skipped.
+    ///
+    /// See [`Spec::is_skipped()`] for details.
+    pub const fn is_skipped(&self) -> bool {
+        self.subject.is_none()
+    }
 }
 
 impl<'a, O, S> DerivedSpec<'a, O, S> {
     #[must_use = "a derived spec does nothing unless an assertion method is called"]
     pub(crate) fn new(
         original: O,
-        derived_subject: S,
+        derived_subject: Option<S>,
         expression: Expression<'a>,
         diff_format: DiffFormat,
+        ignores_order: bool,
     ) -> Self {
         Self {
             original,
             subject: derived_subject,
             expression,
             diff_format,
+            ignores_order,
         }
     }
 
@@ -112,6 +176,46 @@ impl<'a, O, S> DerivedSpec<'a, O, S> {
         self.diff_format = diff_format;
         self
     }
+
+    /// Switches on pretty debug formatting (`{:#?}`) of the actual and the
+    /// expected value in failure messages.
+    ///
+    /// See [`Spec::with_pretty_debug()`] for details.
+    #[must_use = "a derived spec does nothing unless an assertion method is called"]
+    pub const fn with_pretty_debug(mut self) -> Self {
+        self.diff_format.pretty_debug = true;
+        self
+    }
+
+    /// Switches on display formatting of the actual and the expected value in
+    /// failure messages.
+    ///
+    /// See [`Spec::with_display_formatting()`] for details.
+    #[must_use = "a derived spec does nothing unless an assertion method is called"]
+    pub const fn with_display_formatting(mut self) -> Self {
+        self.diff_format.display_formatting = true;
+        self
+    }
+
+    /// Switches on the vertical layout of the actual and the expected value in
+    /// failure messages.
+    ///
+    /// See [`Spec::with_vertical_layout()`] for details.
+    #[must_use = "a derived spec does nothing unless an assertion method is called"]
+    pub const fn with_vertical_layout(mut self) -> Self {
+        self.diff_format.vertical_layout = true;
+        self
+    }
+
+    /// Relaxes all following order-sensitive assertions on sequences to their
+    /// order-insensitive equivalents.
+    ///
+    /// See [`Spec::ignoring_order()`] for details.
+    #[must_use = "a derived spec does nothing unless an assertion method is called"]
+    pub const fn ignoring_order(mut self) -> Self {
+        self.ignores_order = true;
+        self
+    }
 }
 
 impl<'a, O, S> GetLocation<'a> for DerivedSpec<'a, O, S>
@@ -149,7 +253,12 @@ where
     }
 
     fn do_fail_with_message(&mut self, message: impl Into<String>) {
-        self.original.do_fail_with_message(message);
+        self.original
+            .do_fail_with_message(layout_failure_message(message.into(), &self.diff_format));
+    }
+
+    fn do_pass(&mut self) {
+        self.original.do_pass();
     }
 }
 
@@ -307,16 +416,21 @@ impl<'a, O, S> DerivedSpec<'a, O, S> {
         F: FnOnce(&S) -> &B,
         B: ToOwned<Owned = U> + ?Sized,
     {
-        let derived_subject = extract(&self.subject).to_owned();
+        let derived_subject = self
+            .subject
+            .as_ref()
+            .map(|subject| extract(subject).to_owned());
         let orig_subject_name = &self.expression;
         let property_name = property_name.into();
         let expression = Expression(format!("{orig_subject_name}.{property_name}").into());
         let diff_format = self.diff_format.clone();
+        let ignores_order = self.ignores_order;
         DerivedSpec {
             original: self,
             subject: derived_subject,
             expression,
             diff_format,
+            ignores_order,
         }
     }
 
@@ -412,7 +526,7 @@ impl<'a, O, S> DerivedSpec<'a, O, S> {
     where
         F: FnOnce(S) -> U,
     {
-        let derived_subject = extract(self.subject);
+        let derived_subject = self.subject.map(extract);
         let orig_subject_name = &self.expression;
         let property_name = property_name.into();
         let expression = Expression(format!("{orig_subject_name}.{property_name}").into());
@@ -422,6 +536,7 @@ impl<'a, O, S> DerivedSpec<'a, O, S> {
             subject: derived_subject,
             expression,
             diff_format,
+            ignores_order: self.ignores_order,
         }
     }
 
@@ -483,13 +598,70 @@ impl<'a, O, S> DerivedSpec<'a, O, S> {
     where
         F: FnOnce(S) -> U,
     {
-        let mapped = map(self.subject);
+        let mapped = self.subject.map(map);
         DerivedSpec {
             original: self.original,
             subject: mapped,
             expression: self.expression,
             diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
+        }
+    }
+
+    /// Narrows the subject with the given closure, skipping all following
+    /// assertions if the closure returns `None`.
+    ///
+    /// See `Spec::narrowing()` for details.
+    pub(crate) fn narrowing<F, U>(self, narrow: F) -> DerivedSpec<'a, O, U>
+    where
+        F: FnOnce(S) -> Option<U>,
+    {
+        DerivedSpec {
+            original: self.original,
+            subject: self.subject.and_then(narrow),
+            expression: self.expression,
+            diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
+        }
+    }
+
+    /// Calls the given closure with a reference to the current subject and
+    /// returns this `DerivedSpec` unchanged.
+    ///
+    /// See [`Spec::inspecting()`] for details.
+    #[must_use = "a derived spec does nothing unless an assertion method is called"]
+    pub fn inspecting<F>(self, inspect: F) -> Self
+    where
+        F: FnOnce(&S),
+    {
+        if let Some(subject) = &self.subject {
+            inspect(subject);
+        }
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'a, O, S> DerivedSpec<'a, O, S>
+where
+    O: GetLocation<'a>,
+    S: Debug,
+{
+    /// Prints the current subject pretty formatted to stderr and returns this
+    /// `DerivedSpec` unchanged.
+    ///
+    /// See [`Spec::debug_log()`] for details.
+    #[must_use = "a derived spec does nothing unless an assertion method is called"]
+    #[allow(clippy::print_stderr)]
+    pub fn debug_log(self) -> Self {
+        if let Some(subject) = &self.subject {
+            eprintln!(
+                "{}",
+                format_debug_log(self.location(), &self.expression, subject)
+            );
         }
+        self
     }
 }
 
@@ -497,6 +669,86 @@ impl<'a, O, I> DerivedSpec<'a, O, I>
 where
     I: IntoIterator,
 {
+    /// Maps each element of a collection or an iterator to some other value.
+    ///
+    /// It takes a closure that is called for each element of the current
+    /// subject and returns a new `DerivedSpec` with a `Vec` of the values
+    /// returned by the closure as the new subject. The order of the elements
+    /// is preserved.
+    ///
+    /// The expression for failure reports is built from the expression of the
+    /// current subject and notes that each element has been extracted. The
+    /// resulting expression is equal to
+    /// `format!("the extracted elements of {current_expression}")`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Clone)]
+    /// struct Item {
+    ///     sku: String,
+    ///     quantity: u32,
+    /// }
+    ///
+    /// struct Order {
+    ///     id: String,
+    ///     items: Vec<Item>,
+    /// }
+    ///
+    /// let order = Order {
+    ///     id: "O261234".into(),
+    ///     items: vec![
+    ///         Item { sku: "A-100".into(), quantity: 2 },
+    ///         Item { sku: "B-200".into(), quantity: 1 },
+    ///     ],
+    /// };
+    ///
+    /// assert_that!(order)
+    ///     .extracting_ref("id", |o| &o.id)
+    ///     .is_equal_to("O261234")
+    ///     .and()
+    ///     .extracting_ref("items", |o| &o.items)
+    ///     .extracting_each(|i| i.quantity)
+    ///     .contains_exactly([2, 1]);
+    /// ```
+    #[must_use = "a derived spec does nothing unless an assertion method is called"]
+    pub fn extracting_each<F, U>(self, extract: F) -> DerivedSpec<'a, O, Vec<U>>
+    where
+        F: FnMut(<I as IntoIterator>::Item) -> U,
+    {
+        let orig_subject_name = &self.expression;
+        let expression =
+            Expression(format!("the extracted elements of {orig_subject_name}").into());
+        DerivedSpec {
+            original: self.original,
+            subject: self
+                .subject
+                .map(|subject| subject.into_iter().map(extract).collect()),
+            expression,
+            diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
+        }
+    }
+
+    /// Compares the elements of a collection or an iterator with the given
+    /// closure instead of their `PartialEq` implementation in all following
+    /// containment assertions.
+    ///
+    /// See [`Spec::comparing_elements_by`] for details.
+    #[must_use = "a derived spec does nothing unless an assertion method is called"]
+    pub fn comparing_elements_by<F>(
+        self,
+        compare: F,
+    ) -> DerivedSpec<'a, O, Vec<ComparedBy<<I as IntoIterator>::Item, F>>>
+    where
+        F: Fn(&<I as IntoIterator>::Item, &<I as IntoIterator>::Item) -> bool,
+    {
+        let compare = Rc::new(compare);
+        self.mapping(|subject| ComparedBy::wrap_all(subject, &compare))
+    }
+
     pub(crate) fn extracting_ref_iter<F, U>(
         self,
         property_name: impl Into<Cow<'a, str>>,
@@ -507,13 +759,18 @@ where
     {
         let property_name = Expression(property_name.into());
         let diff_format = self.diff_format.clone();
+        let ignores_order = self.ignores_order;
         let orig_spec = self.mapping(Vec::from_iter);
-        let new_subject = extract(orig_spec.subject.iter());
+        let new_subject = orig_spec
+            .subject
+            .as_ref()
+            .map(|subject| extract(subject.iter()));
         DerivedSpec {
             original: orig_spec,
             subject: new_subject,
             expression: property_name,
             diff_format,
+            ignores_order,
         }
     }
 }
@@ -541,11 +798,54 @@ impl<O, S> Expecting<S> for DerivedSpec<'_, O, S>
 where
     O: DoFail,
 {
-    fn expecting(mut self, mut expectation: impl Expectation<S>) -> Self {
-        if !expectation.test(&self.subject) {
-            let message =
-                expectation.message(&self.expression, &self.subject, false, &self.diff_format);
-            self.do_fail_with_message(message);
+    fn expecting(self, expectation: impl Expectation<S>) -> Self {
+        self.expecting_with_context(WithoutContext(expectation))
+    }
+}
+
+impl<O, S> DerivedSpec<'_, O, S>
+where
+    O: DoFail,
+{
+    /// Asserts the given expectation, which can make use of the metadata of
+    /// this `DerivedSpec` to form its failure message.
+    ///
+    /// The description and the location are not known to a `DerivedSpec` and
+    /// therefore are `None` in the metadata.
+    ///
+    /// See [`ExpectationWithContext`] for an example.
+    #[allow(clippy::needless_pass_by_value, clippy::return_self_not_must_use)]
+    #[track_caller]
+    pub fn expecting_with_context(
+        mut self,
+        mut expectation: impl ExpectationWithContext<S>,
+    ) -> Self {
+        let Some(subject) = &self.subject else {
+            return self;
+        };
+        let evaluation = expectation.try_test(subject);
+        let passed = evaluation == Ok(true);
+        let metadata = SpecMetadata {
+            expression: &self.expression,
+            description: None,
+            location: None,
+            subject_type_name: any::type_name::<S>(),
+            diff_format: &self.diff_format,
+        };
+        #[cfg(feature = "std")]
+        trace::trace_expectation(&expectation, &metadata, subject, &evaluation);
+        #[cfg(feature = "std")]
+        listener::notify_listeners(&expectation, &metadata, subject, &evaluation);
+        let failure = (!passed).then(|| {
+            let message = match evaluation {
+                Ok(_) => expectation.message(&metadata, subject, false),
+                Err(error) => format_eval_error(&self.expression, &error),
+            };
+            assertion_id::tag_failure_message(message, &expectation.assertion_id(), &metadata)
+        });
+        match failure {
+            Some(message) => self.do_fail_with_message(message),
+            None => self.do_pass(),
         }
         self
     }
@@ -580,11 +880,66 @@ where
     }
 }
 
+impl<O, S> AssertBits for DerivedSpec<'_, O, S>
+where
+    S: BitsProperty,
+    O: DoFail,
+{
+    fn has_bit_set(self, index: u32) -> Self {
+        self.expecting(has_bit_set(index))
+    }
+
+    fn has_bits_set<M>(self, mask: M) -> Self
+    where
+        M: BitsProperty,
+    {
+        self.expecting(has_bits_set(mask))
+    }
+
+    fn has_no_bits_set<M>(self, mask: M) -> Self
+    where
+        M: BitsProperty,
+    {
+        self.expecting(has_no_bits_set(mask))
+    }
+}
+
+impl<O, S> AssertDefault for DerivedSpec<'_, O, S>
+where
+    S: DefaultProperty + Debug,
+    O: DoFail,
+{
+    fn is_default(self) -> Self {
+        self.expecting(is_default())
+    }
+
+    fn is_not_default(self) -> Self {
+        self.expecting(not(is_default()))
+    }
+}
+
+impl<O, S, F> AssertMatchesPattern<MatchesPattern<F>> for DerivedSpec<'_, O, S>
+where
+    S: Debug,
+    F: Fn(&S) -> bool,
+    O: DoFail,
+{
+    fn matches_pattern(self, pattern: MatchesPattern<F>) -> Self {
+        self.expecting(pattern)
+    }
+
+    fn does_not_match_pattern(self, pattern: MatchesPattern<F>) -> Self {
+        self.expecting(not(pattern))
+    }
+}
+
 #[cfg(feature = "float-cmp")]
 mod float_cmp {
     use super::DerivedSpec;
-    use crate::assertions::{AssertIsCloseToWithDefaultMargin, AssertIsCloseToWithinMargin};
-    use crate::expectations::{is_close_to, not};
+    use crate::assertions::{
+        AssertElementsCloseTo, AssertIsCloseToWithDefaultMargin, AssertIsCloseToWithinMargin,
+    };
+    use crate::expectations::{contains_exactly_close_to, is_close_to, is_close_to_each, not};
     use crate::spec::{DoFail, Expecting};
     use float_cmp::{F32Margin, F64Margin};
 
@@ -643,6 +998,51 @@ mod float_cmp {
             ))
         }
     }
+
+    macro_rules! impl_elements_close_to {
+        ($float:ty, $margin:ty) => {
+            impl<O, S> AssertElementsCloseTo<$float, $margin> for DerivedSpec<'_, O, S>
+            where
+                S: AsRef<[$float]>,
+                O: DoFail,
+            {
+                fn contains_exactly_close_to(
+                    self,
+                    expected: impl IntoIterator<Item = $float>,
+                ) -> Self {
+                    self.expecting(
+                        contains_exactly_close_to(expected)
+                            .within_margin((4. * <$float>::EPSILON, 4)),
+                    )
+                }
+
+                fn contains_exactly_close_to_with_margin(
+                    self,
+                    expected: impl IntoIterator<Item = $float>,
+                    margin: impl Into<$margin>,
+                ) -> Self {
+                    self.expecting(contains_exactly_close_to(expected).within_margin(margin))
+                }
+
+                fn is_close_to_each(self, expected: $float) -> Self {
+                    self.expecting(
+                        is_close_to_each(expected).within_margin((4. * <$float>::EPSILON, 4)),
+                    )
+                }
+
+                fn is_close_to_each_with_margin(
+                    self,
+                    expected: $float,
+                    margin: impl Into<$margin>,
+                ) -> Self {
+                    self.expecting(is_close_to_each(expected).within_margin(margin))
+                }
+            }
+        };
+    }
+
+    impl_elements_close_to!(f32, F32Margin);
+    impl_elements_close_to!(f64, F64Margin);
 }
 
 impl<O, S, E> AssertOrder<E> for DerivedSpec<'_, O, S>
@@ -701,6 +1101,49 @@ where
     }
 }
 
+impl<O, S, E> AssertRange<E> for DerivedSpec<'_, O, S>
+where
+    S: RangeBounds<E> + Debug,
+    E: PartialOrd + Debug,
+    O: DoFail,
+{
+    fn contains_value(self, expected: E) -> Self {
+        self.expecting(range_contains_value(expected))
+    }
+
+    fn overlaps_with<R>(self, other: R) -> Self
+    where
+        R: RangeBounds<E> + Debug,
+    {
+        self.expecting(range_overlaps_with(other))
+    }
+
+    fn is_sub_range_of<R>(self, other: R) -> Self
+    where
+        R: RangeBounds<E> + Debug,
+    {
+        self.expecting(is_sub_range_of(other))
+    }
+}
+
+impl<O, S> AssertWithin<S> for DerivedSpec<'_, O, S>
+where
+    S: DistanceProperty + PartialOrd + Debug,
+    O: DoFail,
+{
+    fn is_in_range_exclusive(self, start: S, end: S) -> Self {
+        self.expecting(is_in_range_exclusive(start, end))
+    }
+
+    fn is_within(self, offset: S, center: S) -> Self {
+        self.expecting(is_within(offset, center))
+    }
+
+    fn is_within_percent(self, percent: f64, expected: S) -> Self {
+        self.expecting(is_within_percent(percent, expected))
+    }
+}
+
 impl<O, S> AssertNumericIdentity for DerivedSpec<'_, O, S>
 where
     S: AdditiveIdentityProperty + MultiplicativeIdentityProperty + PartialEq + Debug,
@@ -783,6 +1226,39 @@ where
     }
 }
 
+impl<O, S, E> AssertDateTime<E> for DerivedSpec<'_, O, S>
+where
+    S: DateTimeProperties,
+    E: DateTimeProperties,
+    O: DoFail,
+{
+    fn is_before_date(self, expected: E) -> Self {
+        self.expecting(is_before_date(expected))
+    }
+
+    fn is_after_date(self, expected: E) -> Self {
+        self.expecting(is_after_date(expected))
+    }
+
+    fn is_same_day_as(self, expected: E) -> Self {
+        self.expecting(is_same_day_as(expected))
+    }
+
+    fn is_within_duration_of(self, expected: E, tolerance: Duration) -> Self {
+        self.expecting(is_within_duration_of(expected, tolerance))
+    }
+}
+
+impl<O, S> AssertCalendarDate for DerivedSpec<'_, O, S>
+where
+    S: DateTimeProperties,
+    O: DoFail,
+{
+    fn is_in_year(self, expected_year: i32) -> Self {
+        self.expecting(is_in_year(expected_year))
+    }
+}
+
 impl<O> AssertBoolean for DerivedSpec<'_, O, bool>
 where
     O: DoFail,
@@ -794,6 +1270,14 @@ where
     fn is_false(self) -> Self {
         self.expecting(is_false())
     }
+
+    fn is_true_because(self, reason: impl Into<String>) -> Self {
+        self.expecting(is_true_because(reason))
+    }
+
+    fn is_false_because(self, reason: impl Into<String>) -> Self {
+        self.expecting(is_false_because(reason))
+    }
 }
 
 impl<O> AssertChar for DerivedSpec<'_, O, char>
@@ -950,6 +1434,60 @@ where
     }
 }
 
+impl<O, S> AssertHasCapacity for DerivedSpec<'_, O, S>
+where
+    S: CapacityProperty + LengthProperty + Debug,
+    O: DoFail,
+{
+    fn has_capacity(self, expected_capacity: usize) -> Self {
+        self.expecting(has_capacity(expected_capacity))
+    }
+
+    fn has_capacity_at_least(self, expected_capacity: usize) -> Self {
+        self.expecting(has_capacity_at_least(expected_capacity))
+    }
+
+    fn has_spare_capacity_of(self, expected_spare_capacity: usize) -> Self {
+        self.expecting(has_spare_capacity_of(expected_spare_capacity))
+    }
+}
+
+impl<O, S> AssertIpAddr for DerivedSpec<'_, O, S>
+where
+    S: IpAddrProperty,
+    O: DoFail,
+{
+    fn is_ipv4(self) -> Self {
+        self.expecting(is_ipv4())
+    }
+
+    fn is_ipv6(self) -> Self {
+        self.expecting(is_ipv6())
+    }
+
+    fn is_loopback(self) -> Self {
+        self.expecting(is_loopback())
+    }
+
+    fn is_private(self) -> Self {
+        self.expecting(is_private())
+    }
+
+    fn has_ip(self, expected: impl AsRef<str>) -> Self {
+        self.expecting(has_ip(expected))
+    }
+}
+
+impl<O, S> AssertSocketAddr for DerivedSpec<'_, O, S>
+where
+    S: PortProperty,
+    O: DoFail,
+{
+    fn has_port(self, expected: u16) -> Self {
+        self.expecting(has_port(expected))
+    }
+}
+
 impl<O, S> AssertOption for DerivedSpec<'_, O, Option<S>>
 where
     S: Debug,
@@ -966,56 +1504,276 @@ where
 
 impl<'a, O, T> AssertOptionValue for DerivedSpec<'a, O, Option<T>>
 where
-    O: DoFail,
+    O: DoFail + GetFailures,
 {
     type Some = DerivedSpec<'a, O, T>;
 
-    fn some(self) -> Self::Some {
-        self.mapping(|subject| match subject {
-            None => {
-                panic!("expected the subject to be `Some(_)`, but was `None`")
-            },
-            Some(value) => value,
-        })
+    fn some(mut self) -> Self::Some {
+        if self.subject.as_ref().is_some_and(Option::is_none) {
+            let message =
+                is_some().message(&self.expression, &None::<Unknown>, false, &self.diff_format);
+            self.do_fail_with_message(message);
+        }
+        let expression = format!("{} unwrapped Some value", self.expression);
+        self.narrowing(|subject| subject).named(expression)
     }
 }
 
 impl<'a, O, T> AssertOptionValue for DerivedSpec<'a, O, &'a Option<T>>
 where
     T: 'a,
-    O: DoFail,
+    O: DoFail + GetFailures,
 {
     type Some = DerivedSpec<'a, O, &'a T>;
 
-    fn some(self) -> Self::Some {
-        self.mapping(|subject| match subject {
-            None => {
-                panic!("expected the subject to be `Some(_)`, but was `None`")
-            },
-            Some(value) => value,
-        })
+    fn some(mut self) -> Self::Some {
+        if self.subject.is_some_and(Option::is_none) {
+            let message =
+                is_some().message(&self.expression, &None::<Unknown>, false, &self.diff_format);
+            self.do_fail_with_message(message);
+        }
+        let expression = format!("{} unwrapped Some value", self.expression);
+        self.narrowing(Option::as_ref).named(expression)
     }
 }
 
-impl<O, T, E> AssertHasValue<E> for DerivedSpec<'_, O, Option<T>>
+impl<'a, O, T> AssertNestedOptionValue for DerivedSpec<'a, O, Option<Option<T>>>
 where
-    T: PartialEq<E> + Debug,
-    E: Debug,
-    O: DoFail,
+    O: DoFail + GetFailures,
 {
-    fn has_value(self, expected: E) -> Self {
-        self.expecting(has_value(expected))
+    type Flattened = DerivedSpec<'a, O, T>;
+
+    fn flattened(mut self) -> Self::Flattened {
+        if self
+            .subject
+            .as_ref()
+            .is_some_and(|subject| !matches!(subject, Some(Some(_))))
+        {
+            self = self.expecting(is_nested_some());
+        }
+        self.narrowing(Option::flatten)
     }
 }
 
-impl<O, T, E> AssertHasValue<E> for DerivedSpec<'_, O, &Option<T>>
+impl<'a, O, T> AssertNestedOptionValue for DerivedSpec<'a, O, &'a Option<Option<T>>>
 where
-    T: PartialEq<E> + Debug,
-    E: Debug,
-    O: DoFail,
+    T: 'a,
+    O: DoFail + GetFailures,
 {
-    fn has_value(self, expected: E) -> Self {
-        self.expecting(has_value(expected))
+    type Flattened = DerivedSpec<'a, O, &'a T>;
+
+    fn flattened(mut self) -> Self::Flattened {
+        if self
+            .subject
+            .as_ref()
+            .is_some_and(|subject| !matches!(subject, Some(Some(_))))
+        {
+            self = self.expecting(is_nested_some());
+        }
+        self.narrowing(|subject| subject.as_ref().and_then(Option::as_ref))
+    }
+}
+
+impl<O, S> AssertPoll for DerivedSpec<'_, O, Poll<S>>
+where
+    S: Debug,
+    O: DoFail,
+{
+    fn is_ready(self) -> Self {
+        self.expecting(is_ready())
+    }
+
+    fn is_pending(self) -> Self {
+        self.expecting(is_pending())
+    }
+}
+
+impl<O, S, E> AssertPollValue<E> for DerivedSpec<'_, O, Poll<S>>
+where
+    S: PartialEq<E> + Debug,
+    E: Debug,
+    O: DoFail,
+{
+    fn is_ready_with(self, expected: E) -> Self {
+        self.expecting(is_ready_with(expected))
+    }
+}
+
+impl<'a, O, F> AssertFuturePoll for DerivedSpec<'a, O, F>
+where
+    F: Future,
+{
+    type Polled = DerivedSpec<'a, O, Poll<F::Output>>;
+
+    fn poll_once(self) -> Self::Polled {
+        self.mapping(poll_once)
+    }
+}
+
+impl<O, S> AssertInstanceOf for DerivedSpec<'_, O, S>
+where
+    S: AnyTypeProperty,
+    O: DoFail,
+{
+    fn is_instance_of<T>(self) -> Self
+    where
+        T: Any,
+    {
+        self.expecting(is_instance_of::<T>())
+    }
+
+    fn is_not_instance_of<T>(self) -> Self
+    where
+        T: Any,
+    {
+        self.expecting(not(is_instance_of::<T>()))
+    }
+}
+
+macro_rules! impl_downcast_for_dyn_any {
+    ($any:ty) => {
+        impl<'a, O> AssertDowncast for DerivedSpec<'a, O, Box<$any>>
+        where
+            O: DoFail + GetFailures,
+        {
+            type Downcast<T>
+                = DerivedSpec<'a, O, T>
+            where
+                T: Any;
+
+            fn downcasting<T>(mut self) -> Self::Downcast<T>
+            where
+                T: Any,
+            {
+                if let Some(subject) = self
+                    .subject
+                    .as_ref()
+                    .filter(|subject| !subject.is_instance_of_property::<T>())
+                {
+                    let message = is_instance_of::<T>().message(
+                        &self.expression,
+                        subject,
+                        false,
+                        &self.diff_format,
+                    );
+                    self.do_fail_with_message(message);
+                }
+                self.narrowing(|subject| subject.downcast::<T>().ok().map(|value| *value))
+            }
+        }
+
+        impl<'a, 'b, O> AssertDowncast for DerivedSpec<'a, O, &'b $any>
+        where
+            O: DoFail + GetFailures,
+        {
+            type Downcast<T>
+                = DerivedSpec<'a, O, &'b T>
+            where
+                T: Any;
+
+            fn downcasting<T>(mut self) -> Self::Downcast<T>
+            where
+                T: Any,
+            {
+                if let Some(subject) = self
+                    .subject
+                    .as_ref()
+                    .filter(|subject| !subject.is_instance_of_property::<T>())
+                {
+                    let message = is_instance_of::<T>().message(
+                        &self.expression,
+                        subject,
+                        false,
+                        &self.diff_format,
+                    );
+                    self.do_fail_with_message(message);
+                }
+                self.narrowing(|subject| subject.downcast_ref::<T>())
+            }
+        }
+    };
+}
+
+impl_downcast_for_dyn_any!(dyn Any);
+impl_downcast_for_dyn_any!(dyn Any + Send);
+impl_downcast_for_dyn_any!(dyn Any + Send + Sync);
+
+impl<'a, O, S> AssertInnerValue for DerivedSpec<'a, O, S>
+where
+    S: InnerValueProperty,
+{
+    type Inner = DerivedSpec<'a, O, S::Inner>;
+
+    fn inner(self) -> Self::Inner {
+        self.mapping(InnerValueProperty::into_inner_property)
+    }
+}
+
+impl<O, T, E> AssertHasValue<E> for DerivedSpec<'_, O, Option<T>>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+    O: DoFail,
+{
+    fn has_value(self, expected: E) -> Self {
+        self.expecting(has_value(expected))
+    }
+}
+
+impl<O, T, E> AssertHasValue<E> for DerivedSpec<'_, O, &Option<T>>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+    O: DoFail,
+{
+    fn has_value(self, expected: E) -> Self {
+        self.expecting(has_value(expected))
+    }
+}
+
+impl<O, T> AssertOptionHasDefaultValue for DerivedSpec<'_, O, Option<T>>
+where
+    T: Default + PartialEq + Debug,
+    O: DoFail,
+{
+    fn has_value_equal_to_default(self) -> Self {
+        self.expecting(has_value_equal_to_default())
+    }
+}
+
+impl<O, T> AssertOptionHasDefaultValue for DerivedSpec<'_, O, &Option<T>>
+where
+    T: Default + PartialEq + Debug,
+    O: DoFail,
+{
+    fn has_value_equal_to_default(self) -> Self {
+        self.expecting(has_value_equal_to_default())
+    }
+}
+
+impl<O, T> AssertOptionIsNoneOr<T> for DerivedSpec<'_, O, Option<T>>
+where
+    T: Debug,
+    O: DoFail,
+{
+    fn is_none_or<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.expecting(is_none_or(predicate))
+    }
+}
+
+impl<O, T> AssertOptionIsNoneOr<T> for DerivedSpec<'_, O, &Option<T>>
+where
+    T: Debug,
+    O: DoFail,
+{
+    fn is_none_or<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.expecting(is_none_or(predicate))
     }
 }
 
@@ -1053,27 +1811,25 @@ impl<'a, O, T, E> AssertResultValue for DerivedSpec<'a, O, Result<T, E>>
 where
     T: Debug,
     E: Debug,
-    O: DoFail,
+    O: DoFail + GetFailures,
 {
     type Ok = DerivedSpec<'a, O, T>;
     type Err = DerivedSpec<'a, O, E>;
 
-    fn ok(self) -> Self::Ok {
-        self.mapping(|subject| match subject {
-            Ok(value) => value,
-            Err(error) => {
-                panic!("expected the subject to be `Ok(_)`, but was `Err({error:?})`")
-            },
-        })
+    fn ok(mut self) -> Self::Ok {
+        if self.subject.as_ref().is_some_and(Result::is_err) {
+            self = self.expecting(is_ok());
+        }
+        let expression = format!("{} unwrapped Ok value", self.expression);
+        self.narrowing(Result::ok).named(expression)
     }
 
-    fn err(self) -> Self::Err {
-        self.mapping(|subject| match subject {
-            Ok(value) => {
-                panic!("expected the subject to be `Err(_)`, but was `Ok({value:?})`")
-            },
-            Err(error) => error,
-        })
+    fn err(mut self) -> Self::Err {
+        if self.subject.as_ref().is_some_and(Result::is_ok) {
+            self = self.expecting(is_err());
+        }
+        let expression = format!("{} unwrapped Err value", self.expression);
+        self.narrowing(Result::err).named(expression)
     }
 }
 
@@ -1081,27 +1837,105 @@ impl<'a, O, T, E> AssertResultValue for DerivedSpec<'a, O, &'a Result<T, E>>
 where
     T: Debug,
     E: Debug,
-    O: DoFail,
+    O: DoFail + GetFailures,
 {
     type Ok = DerivedSpec<'a, O, &'a T>;
     type Err = DerivedSpec<'a, O, &'a E>;
 
-    fn ok(self) -> Self::Ok {
-        self.mapping(|subject| match subject {
-            Ok(value) => value,
-            Err(error) => {
-                panic!("expected the subject to be `Ok(_)`, but was `Err({error:?})`")
-            },
-        })
+    fn ok(mut self) -> Self::Ok {
+        if self
+            .subject
+            .as_ref()
+            .is_some_and(|subject| subject.is_err())
+        {
+            self = self.expecting(is_ok());
+        }
+        let expression = format!("{} unwrapped Ok value", self.expression);
+        self.narrowing(|subject| subject.as_ref().ok())
+            .named(expression)
     }
 
-    fn err(self) -> Self::Err {
-        self.mapping(|subject| match subject {
-            Ok(value) => {
-                panic!("expected the subject to be `Err(_)`, but was `Ok({value:?})`")
-            },
-            Err(error) => error,
-        })
+    fn err(mut self) -> Self::Err {
+        if self.subject.as_ref().is_some_and(|subject| subject.is_ok()) {
+            self = self.expecting(is_err());
+        }
+        let expression = format!("{} unwrapped Err value", self.expression);
+        self.narrowing(|subject| subject.as_ref().err())
+            .named(expression)
+    }
+}
+
+impl<'a, O, S, T, E> AssertResultElements for DerivedSpec<'a, O, S>
+where
+    S: IntoIterator<Item = Result<T, E>>,
+    T: Debug,
+    E: Debug,
+    O: DoFail,
+{
+    type Results = DerivedSpec<'a, O, Vec<Result<T, E>>>;
+    type OkValues = DerivedSpec<'a, O, Vec<T>>;
+    type ErrValues = DerivedSpec<'a, O, Vec<E>>;
+
+    fn all_ok(self) -> Self::Results {
+        self.mapping(Vec::from_iter).expecting(all_ok())
+    }
+
+    fn all_err(self) -> Self::Results {
+        self.mapping(Vec::from_iter).expecting(all_err())
+    }
+
+    fn any_err(self) -> Self::Results {
+        self.mapping(Vec::from_iter).expecting(any_err())
+    }
+
+    fn ok_values(self) -> Self::OkValues {
+        let new_expression = format!("the ok values of {}", self.expression());
+        self.mapping(|subject| subject.into_iter().filter_map(Result::ok).collect())
+            .named(new_expression)
+    }
+
+    fn err_values(self) -> Self::ErrValues {
+        let new_expression = format!("the err values of {}", self.expression());
+        self.mapping(|subject| subject.into_iter().filter_map(Result::err).collect())
+            .named(new_expression)
+    }
+}
+
+impl<'a, O, T, E> AssertNestedResultValue for DerivedSpec<'a, O, Result<Option<T>, E>>
+where
+    E: Debug,
+    O: DoFail + GetFailures,
+{
+    type SomeOk = DerivedSpec<'a, O, T>;
+
+    fn some_ok(mut self) -> Self::SomeOk {
+        if self
+            .subject
+            .as_ref()
+            .is_some_and(|subject| !matches!(subject, Ok(Some(_))))
+        {
+            self = self.expecting(is_ok_some());
+        }
+        self.narrowing(|subject| subject.ok().flatten())
+    }
+}
+
+impl<'a, O, T, E> AssertNestedResultValue for DerivedSpec<'a, O, &'a Result<Option<T>, E>>
+where
+    E: Debug,
+    O: DoFail + GetFailures,
+{
+    type SomeOk = DerivedSpec<'a, O, &'a T>;
+
+    fn some_ok(mut self) -> Self::SomeOk {
+        if self
+            .subject
+            .as_ref()
+            .is_some_and(|subject| !matches!(subject, Ok(Some(_))))
+        {
+            self = self.expecting(is_ok_some());
+        }
+        self.narrowing(|subject| subject.as_ref().ok().and_then(Option::as_ref))
     }
 }
 
@@ -1153,6 +1987,78 @@ where
     }
 }
 
+impl<O, T, E> AssertHasErrorMatching<E> for DerivedSpec<'_, O, Result<T, E>>
+where
+    T: Debug,
+    E: Debug,
+    O: DoFail,
+{
+    fn has_error_matching<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> bool,
+    {
+        self.expecting(has_error_matching(predicate))
+    }
+
+    fn has_error_satisfying<P, M>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> Result<(), M>,
+        M: Display,
+    {
+        self.expecting(has_error_satisfying(predicate))
+    }
+}
+
+impl<O, T, E> AssertHasErrorMatching<E> for DerivedSpec<'_, O, &Result<T, E>>
+where
+    T: Debug,
+    E: Debug,
+    O: DoFail,
+{
+    fn has_error_matching<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> bool,
+    {
+        self.expecting(has_error_matching(predicate))
+    }
+
+    fn has_error_satisfying<P, M>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> Result<(), M>,
+        M: Display,
+    {
+        self.expecting(has_error_satisfying(predicate))
+    }
+}
+
+impl<O, T, E> AssertHasErrorOfType for DerivedSpec<'_, O, Result<T, E>>
+where
+    T: Debug,
+    E: ErrorTypeProperty + Debug,
+    O: DoFail,
+{
+    fn has_error_of_type<X>(self) -> Self
+    where
+        X: Error + 'static,
+    {
+        self.expecting(has_error_of_type::<X>())
+    }
+}
+
+impl<O, T, E> AssertHasErrorOfType for DerivedSpec<'_, O, &Result<T, E>>
+where
+    T: Debug,
+    E: ErrorTypeProperty + Debug,
+    O: DoFail,
+{
+    fn has_error_of_type<X>(self) -> Self
+    where
+        X: Error + 'static,
+    {
+        self.expecting(has_error_of_type::<X>())
+    }
+}
+
 impl<'a, O, T, E, X> AssertHasErrorMessage<X> for DerivedSpec<'a, O, Result<T, E>>
 where
     T: Debug,
@@ -1211,6 +2117,20 @@ where
     }
 }
 
+impl<O, S> AssertErrorMessage for DerivedSpec<'_, O, S>
+where
+    S: Display,
+    O: DoFail,
+{
+    fn has_message(self, expected_message: impl Into<String>) -> Self {
+        self.expecting(error_has_message(expected_message))
+    }
+
+    fn has_message_containing(self, expected_substring: impl Into<String>) -> Self {
+        self.expecting(error_has_message_containing(expected_substring))
+    }
+}
+
 impl<O, S, E> AssertHasDebugString<E> for DerivedSpec<'_, O, S>
 where
     S: Debug,
@@ -1269,6 +2189,54 @@ where
     }
 }
 
+impl<O, S, E> AssertBytes<E> for DerivedSpec<'_, O, S>
+where
+    S: AsRef<[u8]>,
+    E: AsRef<[u8]>,
+    O: DoFail,
+{
+    fn is_equal_to_bytes(self, expected: E) -> Self {
+        self.expecting(is_equal_to_bytes(expected))
+    }
+
+    fn starts_with_bytes(self, expected: E) -> Self {
+        self.expecting(starts_with_bytes(expected))
+    }
+
+    fn contains_subsequence(self, expected: E) -> Self {
+        self.expecting(contains_subsequence(expected))
+    }
+}
+
+impl<O, S, E> AssertUtf8Content<E> for DerivedSpec<'_, O, S>
+where
+    S: AsRef<[u8]>,
+    E: AsRef<str>,
+    O: DoFail,
+{
+    fn has_utf8_content(self, expected: E) -> Self {
+        self.expecting(has_utf8_content(expected))
+    }
+
+    fn does_not_have_utf8_content(self, expected: E) -> Self {
+        self.expecting(not(has_utf8_content(expected)))
+    }
+}
+
+impl<'a, O, S> AssertUtf8Text for DerivedSpec<'a, O, S>
+where
+    S: AsRef<[u8]>,
+    O: DoFail,
+{
+    type Utf8Text = DerivedSpec<'a, O, String>;
+
+    fn as_utf8_text(self) -> Self::Utf8Text {
+        let expression_utf8_text = format!("{}'s UTF-8 text", self.expression);
+        self.mapping(|subject| String::from_utf8_lossy(subject.as_ref()).into_owned())
+            .named(expression_utf8_text)
+    }
+}
+
 impl<'a, O, S> AssertStringPattern<&'a str> for DerivedSpec<'a, O, S>
 where
     S: 'a + AsRef<str> + Debug,
@@ -1401,13 +2369,57 @@ where
     }
 }
 
+impl<O, S, E> AssertStringSimilarity<E> for DerivedSpec<'_, O, S>
+where
+    S: AsRef<str> + Debug,
+    E: AsRef<str> + Debug,
+    O: DoFail,
+{
+    fn is_similar_to(self, expected: E, max_distance: usize) -> Self {
+        self.expecting(is_similar_to(expected, max_distance))
+    }
+}
+
+impl<O, S> AssertStringWildcard for DerivedSpec<'_, O, S>
+where
+    S: AsRef<str> + Debug,
+    O: DoFail,
+{
+    fn matches_wildcard(self, pattern: &str) -> Self {
+        self.expecting(string_matches_wildcard(pattern))
+    }
+
+    fn does_not_match_wildcard(self, pattern: &str) -> Self {
+        self.expecting(not(string_matches_wildcard(pattern)))
+    }
+}
+
+impl<O, S, E> AssertStringOccurrences<E> for DerivedSpec<'_, O, S>
+where
+    S: AsRef<str> + Debug,
+    E: AsRef<str> + Debug,
+    O: DoFail,
+{
+    fn contains_exactly_once(self, needle: E) -> Self {
+        self.expecting(string_contains_n_times(needle, 1))
+    }
+
+    fn contains_n_times(self, needle: E, times: usize) -> Self {
+        self.expecting(string_contains_n_times(needle, times))
+    }
+
+    fn does_not_contain_more_than(self, needle: E, max_times: usize) -> Self {
+        self.expecting(string_contains_at_most_n_times(needle, max_times))
+    }
+}
+
 #[cfg(feature = "regex")]
 mod regex {
-    use crate::assertions::AssertStringMatches;
+    use crate::assertions::{AssertErrorMessageMatches, AssertStringMatches};
     use crate::derived_spec::DerivedSpec;
-    use crate::expectations::{not, string_matches};
+    use crate::expectations::{error_has_message_matching, not, string_matches};
     use crate::spec::{DoFail, Expecting};
-    use crate::std::fmt::Debug;
+    use crate::std::fmt::{Debug, Display};
 
     impl<O, S> AssertStringMatches for DerivedSpec<'_, O, S>
     where
@@ -1422,6 +2434,273 @@ mod regex {
             self.expecting(not(string_matches(regex_pattern)))
         }
     }
+
+    impl<O, S> AssertErrorMessageMatches for DerivedSpec<'_, O, S>
+    where
+        S: Display,
+        O: DoFail,
+    {
+        fn has_message_matching(self, regex_pattern: &str) -> Self {
+            self.expecting(error_has_message_matching(regex_pattern))
+        }
+    }
+}
+
+#[cfg(feature = "unicode")]
+mod unicode {
+    use crate::assertions::AssertGraphemes;
+    use crate::derived_spec::DerivedSpec;
+    use crate::expectations::{has_grapheme_count, not, string_contains_grapheme};
+    use crate::spec::{DoFail, Expecting};
+    use crate::std::fmt::Debug;
+
+    impl<'a, O, S> AssertGraphemes<&'a str> for DerivedSpec<'a, O, S>
+    where
+        S: 'a + AsRef<str> + Debug,
+        O: DoFail,
+    {
+        fn has_grapheme_count(self, expected_grapheme_count: usize) -> Self {
+            self.expecting(has_grapheme_count(expected_grapheme_count))
+        }
+
+        fn contains_grapheme(self, expected: &'a str) -> Self {
+            self.expecting(string_contains_grapheme(expected))
+        }
+
+        fn does_not_contain_grapheme(self, expected: &'a str) -> Self {
+            self.expecting(not(string_contains_grapheme(expected)))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod process {
+    use crate::assertions::{AssertExitStatus, AssertProcessOutput};
+    use crate::derived_spec::DerivedSpec;
+    use crate::expectations::{
+        has_exit_code, not, process_failed, process_succeeded, stderr, stdout, string_contains,
+    };
+    use crate::properties::ExitStatusProperty;
+    use crate::spec::{DoFail, Expecting};
+    use crate::std::borrow::Borrow;
+    use std::process::Output;
+
+    impl<O, S> AssertExitStatus for DerivedSpec<'_, O, S>
+    where
+        S: ExitStatusProperty,
+        O: DoFail,
+    {
+        fn has_exit_code(self, expected: i32) -> Self {
+            self.expecting(has_exit_code(expected))
+        }
+
+        fn does_not_have_exit_code(self, expected: i32) -> Self {
+            self.expecting(not(has_exit_code(expected)))
+        }
+
+        fn succeeded(self) -> Self {
+            self.expecting(process_succeeded())
+        }
+
+        fn failed(self) -> Self {
+            self.expecting(process_failed())
+        }
+    }
+
+    impl<'a, O, S> AssertProcessOutput<'a> for DerivedSpec<'_, O, S>
+    where
+        S: Borrow<Output>,
+        O: DoFail,
+    {
+        fn stdout_contains(self, expected: &'a str) -> Self {
+            self.expecting(stdout(string_contains(expected)))
+        }
+
+        fn stdout_does_not_contain(self, expected: &'a str) -> Self {
+            self.expecting(stdout(not(string_contains(expected))))
+        }
+
+        fn stderr_contains(self, expected: &'a str) -> Self {
+            self.expecting(stderr(string_contains(expected)))
+        }
+
+        fn stderr_does_not_contain(self, expected: &'a str) -> Self {
+            self.expecting(stderr(not(string_contains(expected))))
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    mod regex {
+        use crate::assertions::AssertProcessOutputMatches;
+        use crate::derived_spec::DerivedSpec;
+        use crate::expectations::{stderr, stdout, string_matches};
+        use crate::spec::{DoFail, Expecting};
+        use crate::std::borrow::Borrow;
+        use std::process::Output;
+
+        impl<O, S> AssertProcessOutputMatches for DerivedSpec<'_, O, S>
+        where
+            S: Borrow<Output>,
+            O: DoFail,
+        {
+            fn stdout_matches(self, regex_pattern: &str) -> Self {
+                self.expecting(stdout(string_matches(regex_pattern)))
+            }
+
+            fn stderr_matches(self, regex_pattern: &str) -> Self {
+                self.expecting(stderr(string_matches(regex_pattern)))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "url")]
+mod url {
+    use crate::assertions::AssertUrl;
+    use crate::derived_spec::DerivedSpec;
+    use crate::expectations::{
+        has_host, has_path, has_query_parameter, has_scheme, is_equal_to_url_ignoring_query_order,
+    };
+    use crate::spec::{DoFail, Expecting};
+    use crate::std::borrow::Borrow;
+    use url::Url;
+
+    impl<O, S> AssertUrl for DerivedSpec<'_, O, S>
+    where
+        S: Borrow<Url>,
+        O: DoFail,
+    {
+        fn has_scheme(self, expected: impl AsRef<str>) -> Self {
+            self.expecting(has_scheme(expected))
+        }
+
+        fn has_host(self, expected: impl AsRef<str>) -> Self {
+            self.expecting(has_host(expected))
+        }
+
+        fn has_path(self, expected: impl AsRef<str>) -> Self {
+            self.expecting(has_path(expected))
+        }
+
+        fn has_query_parameter(self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+            self.expecting(has_query_parameter(name, value))
+        }
+
+        fn is_equal_to_url_ignoring_query_order(self, expected: impl AsRef<str>) -> Self {
+            self.expecting(is_equal_to_url_ignoring_query_order(expected))
+        }
+    }
+}
+
+#[cfg(feature = "anyhow")]
+mod anyhow {
+    use crate::assertions::AssertAnyhowError;
+    use crate::derived_spec::DerivedSpec;
+    use crate::expectations::{has_context_containing, has_root_cause_message, is_downcastable_to};
+    use crate::spec::{DoFail, Expecting};
+    use crate::std::borrow::Borrow;
+    use crate::std::fmt::{Debug, Display};
+    use crate::std::string::String;
+    use anyhow::Error;
+
+    impl<O, S> AssertAnyhowError for DerivedSpec<'_, O, S>
+    where
+        S: Borrow<Error>,
+        O: DoFail,
+    {
+        fn has_root_cause_message(self, expected_message: impl Into<String>) -> Self {
+            self.expecting(has_root_cause_message(expected_message))
+        }
+
+        fn is_downcastable_to<E>(self) -> Self
+        where
+            E: Display + Debug + Send + Sync + 'static,
+        {
+            self.expecting(is_downcastable_to::<E>())
+        }
+
+        fn has_context_containing(self, expected_substring: impl Into<String>) -> Self {
+            self.expecting(has_context_containing(expected_substring))
+        }
+    }
+}
+
+#[cfg(feature = "bitflags")]
+mod bitflags {
+    use crate::assertions::AssertBitFlags;
+    use crate::derived_spec::DerivedSpec;
+    use crate::expectations::contains_flags;
+    use crate::properties::BitsProperty;
+    use crate::spec::{DoFail, Expecting};
+    use crate::std::fmt::Debug;
+    use bitflags::Flags;
+
+    impl<O, S> AssertBitFlags<S> for DerivedSpec<'_, O, S>
+    where
+        S: Flags + Debug,
+        S::Bits: BitsProperty,
+        O: DoFail,
+    {
+        fn contains_flags(self, flags: S) -> Self {
+            self.expecting(contains_flags(flags))
+        }
+    }
+}
+
+#[cfg(feature = "rust-decimal")]
+mod rust_decimal {
+    use crate::assertions::AssertRustDecimal;
+    use crate::derived_spec::DerivedSpec;
+    use crate::expectations::{is_close_to_decimal, is_equal_to_decimal};
+    use crate::spec::{DoFail, Expecting};
+    use crate::std::borrow::Borrow;
+    use rust_decimal::Decimal;
+
+    impl<O, S> AssertRustDecimal for DerivedSpec<'_, O, S>
+    where
+        S: Borrow<Decimal>,
+        O: DoFail,
+    {
+        fn is_equal_to_decimal(self, expected: impl AsRef<str>) -> Self {
+            self.expecting(is_equal_to_decimal(expected))
+        }
+
+        fn is_close_to_decimal(self, expected: impl AsRef<str>, margin: impl AsRef<str>) -> Self {
+            self.expecting(is_close_to_decimal(expected, margin))
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid {
+    use crate::assertions::AssertUuid;
+    use crate::derived_spec::DerivedSpec;
+    use crate::expectations::{has_version, is_equal_to_uuid_string, is_nil, not};
+    use crate::spec::{DoFail, Expecting};
+    use crate::std::borrow::Borrow;
+    use uuid::{Uuid, Version};
+
+    impl<O, S> AssertUuid for DerivedSpec<'_, O, S>
+    where
+        S: Borrow<Uuid>,
+        O: DoFail,
+    {
+        fn is_nil(self) -> Self {
+            self.expecting(is_nil())
+        }
+
+        fn is_not_nil(self) -> Self {
+            self.expecting(not(is_nil()))
+        }
+
+        fn has_version(self, expected: Version) -> Self {
+            self.expecting(has_version(expected))
+        }
+
+        fn is_equal_to_uuid_string(self, expected: impl AsRef<str>) -> Self {
+            self.expecting(is_equal_to_uuid_string(expected))
+        }
+    }
 }
 
 impl<'a, O, S, T, E> AssertIteratorContains<E> for DerivedSpec<'a, O, S>
@@ -1459,6 +2738,11 @@ where
             .expecting(iterator_contains_exactly_in_any_order(expected))
     }
 
+    fn contains_exactly_shuffled(self, expected: E, seed: u64) -> Self::Sequence {
+        self.mapping(|subject| shuffle(Vec::from_iter(subject), seed))
+            .expecting(iterator_contains_exactly_shuffled(expected, seed))
+    }
+
     fn contains_any_of(self, expected: E) -> Self::Sequence {
         self.mapping(Vec::from_iter)
             .expecting(iterator_contains_any_of(expected))
@@ -1485,6 +2769,36 @@ where
     }
 }
 
+impl<'a, O, S> AssertIteratorCount for DerivedSpec<'a, O, S>
+where
+    S: IntoIterator,
+    O: DoFail,
+{
+    type Counted = DerivedSpec<'a, O, ()>;
+
+    fn has_count(self, expected_count: usize) -> Self::Counted {
+        self.mapping(|subject| count_elements_up_to(subject, expected_count.saturating_add(1)))
+            .expecting(has_count(expected_count))
+            .mapping(|_| ())
+    }
+
+    fn has_count_at_least(self, expected_count: usize) -> Self::Counted {
+        self.mapping(|subject| count_elements_up_to(subject, expected_count))
+            .expecting(has_count_at_least(expected_count))
+            .mapping(|_| ())
+    }
+
+    fn has_count_in_range<U>(self, expected_range: U) -> Self::Counted
+    where
+        U: RangeBounds<usize> + Debug,
+    {
+        let limit = count_limit_for_range(&expected_range);
+        self.mapping(|subject| count_elements_up_to(subject, limit))
+            .expecting(has_count_in_range(expected_range))
+            .mapping(|_| ())
+    }
+}
+
 impl<'a, O, S, T, E> AssertIteratorContainsInOrder<E> for DerivedSpec<'a, O, S>
 where
     S: IntoIterator<Item = T>,
@@ -1498,8 +2812,13 @@ where
     type Sequence = DerivedSpec<'a, O, Vec<T>>;
 
     fn contains_exactly(self, expected: E) -> Self::Sequence {
-        self.mapping(Vec::from_iter)
-            .expecting(iterator_contains_exactly(expected))
+        if self.ignores_order() {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_contains_exactly_in_any_order(expected))
+        } else {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_contains_exactly(expected))
+        }
     }
 
     fn contains_sequence(self, expected: E) -> Self::Sequence {
@@ -1508,18 +2827,33 @@ where
     }
 
     fn contains_all_in_order(self, expected: E) -> Self::Sequence {
-        self.mapping(Vec::from_iter)
-            .expecting(iterator_contains_all_in_order(expected))
+        if self.ignores_order() {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_contains_all_of(expected))
+        } else {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_contains_all_in_order(expected))
+        }
     }
 
     fn starts_with(self, expected: E) -> Self::Sequence {
-        self.mapping(Vec::from_iter)
-            .expecting(iterator_starts_with(expected))
+        if self.ignores_order() {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_starts_with_in_any_order(expected))
+        } else {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_starts_with(expected))
+        }
     }
 
     fn ends_with(self, expected: E) -> Self::Sequence {
-        self.mapping(Vec::from_iter)
-            .expecting(iterator_ends_with(expected))
+        if self.ignores_order() {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_ends_with_in_any_order(expected))
+        } else {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_ends_with(expected))
+        }
     }
 }
 
@@ -1577,6 +2911,190 @@ where
     }
 }
 
+impl<O, S, E, F> AssertOrderedMapEntries<E, F> for DerivedSpec<'_, O, S>
+where
+    S: MapProperties + DefinedOrderProperty + Debug,
+    <S as MapProperties>::Key: PartialEq<E> + Debug,
+    <S as MapProperties>::Value: PartialEq<F> + Debug,
+    E: Debug,
+    F: Debug,
+    O: DoFail,
+{
+    fn first_entry_is(self, expected_entry: (E, F)) -> Self {
+        self.expecting(map_first_entry_is(expected_entry))
+    }
+
+    fn last_entry_is(self, expected_entry: (E, F)) -> Self {
+        self.expecting(map_last_entry_is(expected_entry))
+    }
+
+    fn contains_entries_in_order(self, expected_entries: impl IntoIterator<Item = (E, F)>) -> Self {
+        self.expecting(map_contains_entries_in_order(expected_entries))
+    }
+}
+
+impl<O, S> AssertMapKeysSorted for DerivedSpec<'_, O, S>
+where
+    S: MapProperties + DefinedOrderProperty + Debug,
+    <S as MapProperties>::Key: PartialOrd + Debug,
+    <S as MapProperties>::Value: Debug,
+    O: DoFail,
+{
+    fn keys_are_sorted(self) -> Self {
+        self.expecting(map_keys_are_sorted())
+    }
+}
+
+impl<O, S, E> AssertMapEquality<E> for DerivedSpec<'_, O, S>
+where
+    S: MapProperties + Debug,
+    <S as MapProperties>::Key: PartialEq<<E as MapProperties>::Key> + Debug,
+    <S as MapProperties>::Value: PartialEq<<E as MapProperties>::Value> + Debug,
+    E: MapProperties,
+    <E as MapProperties>::Key: Debug,
+    <E as MapProperties>::Value: Debug,
+    O: DoFail,
+{
+    fn is_equal_to_map(self, expected: E) -> Self {
+        self.expecting(map_is_equal_to(expected))
+    }
+}
+
+impl<O, S, K, V> AssertKeyValuePairs<K, V> for DerivedSpec<'_, O, S>
+where
+    S: KeyValuePairsProperty + Debug,
+    <S as KeyValuePairsProperty>::Key: PartialEq<K> + Debug,
+    <S as KeyValuePairsProperty>::Value: PartialEq<V> + Debug,
+    K: Debug,
+    V: Debug,
+    O: DoFail,
+{
+    fn contains_pair(self, key: K, value: V) -> Self {
+        self.expecting(contains_pair(key, value))
+    }
+
+    fn does_not_contain_pair(self, key: K, value: V) -> Self {
+        self.expecting(not(contains_pair(key, value)))
+    }
+}
+
+impl<O, S, K> AssertPairKeys<K> for DerivedSpec<'_, O, S>
+where
+    S: KeyValuePairsProperty + Debug,
+    <S as KeyValuePairsProperty>::Key: PartialEq<K> + Debug,
+    <S as KeyValuePairsProperty>::Value: Debug,
+    K: Debug,
+    O: DoFail,
+{
+    fn keys_contain(self, key: K) -> Self {
+        self.expecting(keys_contain(key))
+    }
+
+    fn keys_do_not_contain(self, key: K) -> Self {
+        self.expecting(not(keys_contain(key)))
+    }
+}
+
+impl<'a, O, P, E> AssertValuesForKey<E> for DerivedSpec<'a, O, &'a P>
+where
+    P: KeyValuePairsProperty + ?Sized,
+    <P as KeyValuePairsProperty>::Key: PartialEq<E>,
+{
+    type Values = DerivedSpec<'a, O, Vec<&'a <P as KeyValuePairsProperty>::Value>>;
+
+    fn values_for_key(self, key: E) -> Self::Values {
+        self.mapping(|subject| find_values_for_key(subject, &key))
+    }
+}
+
+impl<'a, O, K, V, E> AssertValuesForKey<E> for DerivedSpec<'a, O, Vec<(K, V)>>
+where
+    K: PartialEq<E>,
+{
+    type Values = DerivedSpec<'a, O, Vec<V>>;
+
+    fn values_for_key(self, key: E) -> Self::Values {
+        self.mapping(|subject| take_values_for_key(subject, &key))
+    }
+}
+
+impl<'a, O, M, E> AssertMapValue<E> for DerivedSpec<'a, O, &'a M>
+where
+    M: MapProperties + ?Sized,
+    <M as MapProperties>::Key: PartialEq<E> + Debug,
+    <M as MapProperties>::Value: Debug,
+    E: Debug,
+    O: DoFail + GetFailures,
+{
+    type Value = DerivedSpec<'a, O, &'a <M as MapProperties>::Value>;
+
+    fn value_of(mut self, key: E) -> Self::Value {
+        let subject: Option<&'a M> = self.subject;
+        let value = subject.and_then(|subject| find_value_of(subject, &key));
+        if let (Some(subject), None) = (subject, value) {
+            let message =
+                map_contains_key(key).message(&self.expression, &subject, false, &self.diff_format);
+            self.do_fail_with_message(message);
+        }
+        self.narrowing(|_| value)
+    }
+}
+
+impl<'a, O, S, T> AssertMonotonicity for DerivedSpec<'a, O, S>
+where
+    S: IntoIterator<Item = T>,
+    <S as IntoIterator>::IntoIter: DefinedOrderProperty,
+    T: PartialOrd + Debug,
+    O: DoFail,
+{
+    type Sequence = DerivedSpec<'a, O, Vec<T>>;
+
+    fn is_strictly_increasing(self) -> Self::Sequence {
+        self.mapping(Vec::from_iter)
+            .expecting(is_strictly_increasing())
+    }
+
+    fn is_strictly_decreasing(self) -> Self::Sequence {
+        self.mapping(Vec::from_iter)
+            .expecting(is_strictly_decreasing())
+    }
+
+    fn is_non_decreasing(self) -> Self::Sequence {
+        self.mapping(Vec::from_iter).expecting(is_non_decreasing())
+    }
+
+    fn is_non_increasing(self) -> Self::Sequence {
+        self.mapping(Vec::from_iter).expecting(is_non_increasing())
+    }
+}
+
+impl<'a, O, S, T> AssertIsSortedBy<T> for DerivedSpec<'a, O, S>
+where
+    S: IntoIterator<Item = T>,
+    <S as IntoIterator>::IntoIter: DefinedOrderProperty,
+    T: Debug,
+    O: DoFail,
+{
+    type Sequence = DerivedSpec<'a, O, Vec<T>>;
+
+    fn is_sorted_by<C>(self, compare: C) -> Self::Sequence
+    where
+        C: FnMut(&T, &T) -> bool,
+    {
+        self.mapping(Vec::from_iter)
+            .expecting(is_sorted_by(compare))
+    }
+
+    fn is_sorted_by_key<K, F>(self, key: F) -> Self::Sequence
+    where
+        F: FnMut(&T) -> K,
+        K: PartialOrd + Debug,
+    {
+        self.mapping(Vec::from_iter)
+            .expecting(is_sorted_by_key(key))
+    }
+}
+
 impl<'a, O, S, T> AssertOrderedElements for DerivedSpec<'a, O, S>
 where
     S: IntoIterator<Item = T>,
@@ -1667,7 +3185,7 @@ where
         let location = self.location();
         let mut collected_failures = Vec::new();
         let mut position = -1;
-        for item in self.subject {
+        for item in self.subject.into_iter().flatten() {
             position += 1;
             let mut element_spec = Spec::new(item, CollectFailures)
                 .named(format!("{root_expression}[{position}]"))
@@ -1683,9 +3201,10 @@ where
         }
         DerivedSpec {
             original: self.original,
-            subject: (),
+            subject: Some(()),
             expression: self.expression,
             diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
         }
     }
 
@@ -1698,9 +3217,9 @@ where
         let diff_format = self.diff_format().clone();
         let location = self.location();
         let mut collected_failures = Vec::new();
-        let mut any_success = false;
+        let mut any_success = self.subject.is_none();
         let mut position = -1;
-        for item in self.subject {
+        for item in self.subject.into_iter().flatten() {
             position += 1;
             let mut element_spec = Spec::new(item, CollectFailures)
                 .named(format!("{root_expression}[{position}]"))
@@ -1720,9 +3239,10 @@ where
         }
         DerivedSpec {
             original: self.original,
-            subject: (),
+            subject: Some(()),
             expression: self.expression,
             diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
         }
     }
 }
@@ -58,6 +58,41 @@ fn mapping_person_name_starts_with_alex() {
     assert_that(person).mapping(|p| p.name).starts_with("Alex");
 }
 
+#[test]
+fn inspecting_derived_subject_passes_the_current_subject() {
+    let person = Person {
+        name: "Marie".to_string(),
+        age: 44,
+        gender: Gender::Female,
+    };
+    let mut inspected = 0;
+
+    assert_that(person)
+        .extracting_ref("age", |p| &p.age)
+        .inspecting(|age| inspected = *age)
+        .is_equal_to(44)
+        .and()
+        .extracting_ref("name", Person::name)
+        .starts_with("Ma");
+
+    assert_eq!(inspected, 44);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn debug_log_derived_subject() {
+    let person = Person {
+        name: "Marie".to_string(),
+        age: 44,
+        gender: Gender::Female,
+    };
+
+    assert_that(person)
+        .extracting_ref("name", Person::name)
+        .debug_log()
+        .starts_with("Ma");
+}
+
 #[test]
 fn extracting_person_name_contains_i() {
     let person = Person {
@@ -142,6 +177,55 @@ fn verify_extracting_ref_to_assert_all_fields_fails_with_all_failures() {
     );
 }
 
+struct Team {
+    name: String,
+    members: Vec<(String, u8)>,
+}
+
+#[test]
+fn extracting_ref_and_extracting_each_element_of_derived_subject() {
+    let team = Team {
+        name: "Avengers".to_string(),
+        members: vec![("Silvia".to_string(), 47), ("Alexander".to_string(), 49)],
+    };
+
+    assert_that(team)
+        .extracting_ref("members", |t| &t.members)
+        .extracting_each(|(name, _)| name)
+        .contains_exactly(["Silvia", "Alexander"])
+        .and()
+        .extracting_ref("name", |t| &t.name)
+        .is_equal_to("Avengers");
+}
+
+#[test]
+fn verify_extracting_each_element_of_derived_subject_fails() {
+    let team = Team {
+        name: "Avengers".to_string(),
+        members: vec![("Silvia".to_string(), 47), ("Alexander".to_string(), 17)],
+    };
+
+    let failures = verify_that(team)
+        .named("team")
+        .extracting_ref("members", |t| &t.members)
+        .extracting_each(|(_, age)| age)
+        .contains_exactly([47, 49])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected the extracted elements of team.members to contain exactly in order [47, 49]
+       but was: [47, 17]
+      expected: [47, 49]
+       missing: [49]
+         extra: [17]
+  out-of-order: []
+"
+        ]
+    );
+}
+
 #[cfg(feature = "float-cmp")]
 #[test]
 fn extracting_ref_to_assert_all_order_item_fields() {
@@ -428,6 +512,19 @@ fn extracting_ref_f64_is_close_to_within_margin() {
         .is_close_to_with_margin(1.99, (0.001, 2));
 }
 
+#[cfg(feature = "float-cmp")]
+#[test]
+fn extracting_ref_vec_of_f64_contains_exactly_close_to() {
+    struct Samples(Vec<f64>);
+
+    let samples = Samples(vec![0.1 + 0.2, 0.15 * 2.]);
+
+    assert_that(samples)
+        .extracting_ref("0", |s| &s.0)
+        .contains_exactly_close_to([0.3, 0.3])
+        .is_close_to_each(0.3);
+}
+
 #[test]
 fn extracting_ref_f64_is_zero() {
     struct Float(f64);
@@ -1490,3 +1587,66 @@ mod iterator_extracted_elements_ref {
         );
     }
 }
+
+#[test]
+fn extracting_ref_option_matches_pattern() {
+    struct Lookup(Option<i32>);
+
+    let lookup = Lookup(Some(42));
+
+    assert_that(lookup)
+        .extracting_ref("0", |l| &l.0)
+        .matches_pattern(pattern!(Some(40..)))
+        .does_not_match_pattern(pattern!(None));
+}
+
+#[test]
+fn verify_extracting_ref_nested_option_flattened_with_inner_none_fails() {
+    let config: (&str, Option<Option<u32>>) = ("timeout", Some(None));
+
+    let failures = verify_that(config)
+        .named("config")
+        .extracting_ref("1", |c| &c.1)
+        .flattened()
+        .is_not_equal_to(0)
+        .is_at_most(30)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected config.1 to be Some(Some(_)), but the inner option is None
+   but was: Some(None)
+  expected: Some(Some(_))
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_extracting_ref_nested_result_some_ok_with_err_fails() {
+    struct Response {
+        body: Result<Option<String>, String>,
+    }
+
+    let response = Response {
+        body: Err("timed out".to_string()),
+    };
+
+    let failures = verify_that(response)
+        .named("response")
+        .extracting_ref("body", |r| &r.body)
+        .some_ok()
+        .is_not_empty()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected response.body to be Ok(Some(_)), but the result is an error
+   but was: Err("timed out")
+  expected: Ok(Some(_))
+"#
+        ]
+    );
+}
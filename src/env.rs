@@ -9,12 +9,20 @@ pub use std::env::VarError;
 #[cfg(not(test))]
 pub use std::env::var;
 
+/// Returns whether stderr is connected to a terminal.
+#[cfg(all(feature = "colored", not(test)))]
+pub fn is_stderr_terminal() -> bool {
+    use std::io::IsTerminal;
+
+    std::io::stderr().is_terminal()
+}
+
 #[cfg(test)]
 pub use fake_env::*;
 
 #[cfg(test)]
 mod fake_env {
-    use crate::std::cell::RefCell;
+    use crate::std::cell::{Cell, RefCell};
     use crate::std::env::VarError;
     use fakeenv::EnvStore;
 
@@ -23,8 +31,17 @@ mod fake_env {
             let env = EnvStore::fake();
             env.remove_var("ASSERTING_HIGHLIGHT_DIFFS");
             env.remove_var("NO_COLOR");
+            env.remove_var("ASSERTING_TRACE");
+            env.remove_var("ASSERTING_DIFF_GRANULARITY");
+            env.remove_var("CLICOLOR");
+            env.remove_var("CLICOLOR_FORCE");
+            env.remove_var("ASSERTING_TRUNCATION_MAX_LENGTH");
+            env.remove_var("ASSERTING_TRUNCATION_CONTEXT");
+            env.remove_var("ASSERTING_PRETTY_DEBUG");
+            env.remove_var("ASSERTING_OUTPUT_FORMAT");
             env
         });
+        static STDERR_IS_TERMINAL: Cell<bool> = const { Cell::new(true) };
     }
 
     pub fn var(key: &str) -> Result<String, VarError> {
@@ -38,4 +55,14 @@ mod fake_env {
     pub fn remove_var(key: &str) {
         ENV_STORE.with(|env| env.borrow_mut().remove_var(key));
     }
+
+    #[cfg(feature = "colored")]
+    pub fn is_stderr_terminal() -> bool {
+        STDERR_IS_TERMINAL.with(Cell::get)
+    }
+
+    #[cfg(feature = "colored")]
+    pub fn set_stderr_terminal(is_terminal: bool) {
+        STDERR_IS_TERMINAL.with(|stderr_is_terminal| stderr_is_terminal.set(is_terminal));
+    }
 }
@@ -0,0 +1,96 @@
+//! Implementation of assertions for environment variables.
+
+use crate::assertions::AssertEnvVar;
+use crate::colored::{mark_missing_string, mark_unexpected_string};
+use crate::env::VarError;
+use crate::expectations::{
+    EnvVarHasValueContaining, EnvVarIsSet, env_var_has_value_containing, env_var_is_set, not,
+};
+use crate::spec::{
+    DiffFormat, EnvVar, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::format;
+use crate::std::string::String;
+
+impl<R> AssertEnvVar for Spec<'_, EnvVar, R>
+where
+    R: FailingStrategy,
+{
+    fn is_set(self) -> Self {
+        self.expecting(env_var_is_set())
+    }
+
+    fn is_not_set(self) -> Self {
+        self.expecting(not(env_var_is_set()))
+    }
+
+    fn has_value_containing(self, expected: &str) -> Self {
+        self.expecting(env_var_has_value_containing(expected))
+    }
+}
+
+fn describe_value(env_var: &EnvVar) -> String {
+    match env_var.value() {
+        Ok(value) => format!("{value:?}"),
+        Err(VarError::NotPresent) => String::from("<not set>"),
+        Err(VarError::NotUnicode(value)) => format!("<not unicode: {value:?}>"),
+    }
+}
+
+impl Expectation<EnvVar> for EnvVarIsSet {
+    const ASSERTION_ID: &'static str = "asserting::is_set";
+
+    fn test(&mut self, subject: &EnvVar) -> bool {
+        !matches!(subject.value(), Err(VarError::NotPresent))
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &EnvVar,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let (not, expected) = if inverted {
+            ("not ", "<not set>")
+        } else {
+            ("", "<set>")
+        };
+        let marked_actual = mark_unexpected_string(&describe_value(actual), format);
+        let marked_expected = mark_missing_string(expected, format);
+        format!(
+            "expected {expression} to {not}be set\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl Invertible for EnvVarIsSet {}
+
+impl Expectation<EnvVar> for EnvVarHasValueContaining {
+    const ASSERTION_ID: &'static str = "asserting::has_value_containing";
+
+    fn test(&mut self, subject: &EnvVar) -> bool {
+        subject
+            .value()
+            .as_ref()
+            .is_ok_and(|value| value.contains(&self.expected_substring))
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &EnvVar,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected_substring = &self.expected_substring;
+        let marked_actual = mark_unexpected_string(&describe_value(actual), format);
+        let marked_expected = mark_missing_string(&format!("{expected_substring:?}"), format);
+        format!(
+            "expected {expression} to have a value containing {expected_substring:?}\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
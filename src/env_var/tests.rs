@@ -0,0 +1,135 @@
+use crate::env::{remove_var, set_var};
+use crate::prelude::*;
+
+#[test]
+fn env_var_that_is_set_is_set() {
+    set_var("ASSERTING_TEST_LOG_LEVEL", "debug");
+
+    assert_that_env!("ASSERTING_TEST_LOG_LEVEL").is_set();
+}
+
+#[test]
+fn env_var_with_empty_value_is_set() {
+    set_var("ASSERTING_TEST_LOG_LEVEL", "");
+
+    assert_that_env!("ASSERTING_TEST_LOG_LEVEL").is_set();
+}
+
+#[test]
+fn env_var_that_is_removed_is_not_set() {
+    set_var("ASSERTING_TEST_LOG_LEVEL", "debug");
+    remove_var("ASSERTING_TEST_LOG_LEVEL");
+
+    assert_that_env!("ASSERTING_TEST_LOG_LEVEL").is_not_set();
+}
+
+#[test]
+fn env_var_has_value_containing_substring() {
+    set_var("ASSERTING_TEST_PATH", "/usr/local/bin:/usr/bin");
+
+    assert_that_env!("ASSERTING_TEST_PATH")
+        .is_set()
+        .has_value_containing("/usr/local/bin");
+}
+
+#[test]
+fn verify_env_var_is_set_fails() {
+    remove_var("ASSERTING_TEST_LOG_LEVEL");
+
+    let failures = verify_that_env("ASSERTING_TEST_LOG_LEVEL")
+        .is_set()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected environment variable ASSERTING_TEST_LOG_LEVEL to be set
+   but was: <not set>
+  expected: <set>
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_env_var_is_not_set_fails() {
+    set_var("ASSERTING_TEST_LOG_LEVEL", "debug");
+
+    let failures = verify_that_env("ASSERTING_TEST_LOG_LEVEL")
+        .is_not_set()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected environment variable ASSERTING_TEST_LOG_LEVEL to not be set
+   but was: "debug"
+  expected: <not set>
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_env_var_has_value_containing_fails() {
+    set_var("ASSERTING_TEST_PATH", "/usr/bin");
+
+    let failures = verify_that_env("ASSERTING_TEST_PATH")
+        .has_value_containing("/usr/local/bin")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected environment variable ASSERTING_TEST_PATH to have a value containing "/usr/local/bin"
+   but was: "/usr/bin"
+  expected: "/usr/local/bin"
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_env_var_that_is_not_set_has_value_containing_fails() {
+    remove_var("ASSERTING_TEST_PATH");
+
+    let failures = verify_that_env("ASSERTING_TEST_PATH")
+        .has_value_containing("/usr/local/bin")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected environment variable ASSERTING_TEST_PATH to have a value containing "/usr/local/bin"
+   but was: <not set>
+  expected: "/usr/local/bin"
+"#
+        ]
+    );
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use crate::env::remove_var;
+    use crate::prelude::*;
+
+    #[test]
+    fn highlight_diffs_env_var_is_set() {
+        remove_var("ASSERTING_TEST_LOG_LEVEL");
+
+        let failures = verify_that_env("ASSERTING_TEST_LOG_LEVEL")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_set()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected environment variable ASSERTING_TEST_LOG_LEVEL to be set\n   \
+                but was: \u{1b}[31m<not set>\u{1b}[0m\n  \
+               expected: \u{1b}[34m<set>\u{1b}[0m\n\
+            "
+            ]
+        );
+    }
+}
@@ -0,0 +1,464 @@
+//! Implementation of the equality assertions.
+
+use crate::assertions::{
+    AssertDefault, AssertEquality, AssertHasDebugString, AssertHasDisplayString, AssertSameAs,
+    AssertSameInstance,
+};
+use crate::colored::{
+    mark_diff, mark_diff_display, mark_diff_str, mark_missing_string, mark_unexpected_string,
+};
+use crate::expectations::{
+    HasDebugString, HasDisplayString, IsDefault, IsEqualTo, IsSameAs, IsSameInstanceAs,
+    has_debug_string, has_display_string, is_default, is_equal_to, is_same_as, is_same_instance_as,
+    not,
+};
+use crate::properties::DefaultProperty;
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::any;
+use crate::std::fmt::{Debug, Display};
+use crate::std::format;
+use crate::std::ptr;
+use crate::std::rc::Rc;
+use crate::std::string::{String, ToString};
+use crate::std::sync::Arc;
+use crate::structured_diff::{
+    collection_differences, format_differences, format_map_differences, map_differences,
+};
+use crate::text_position::{
+    debug_string_difference_position, format_difference_position, text_difference_position,
+    unescape_debug_string,
+};
+
+impl<S, E, R> AssertEquality<E> for Spec<'_, S, R>
+where
+    S: PartialEq<E> + Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    fn is_equal_to(self, expected: E) -> Self {
+        self.expecting(is_equal_to(expected))
+    }
+
+    fn is_not_equal_to(self, expected: E) -> Self {
+        self.expecting(not(is_equal_to(expected)))
+    }
+}
+
+impl<S, E> Expectation<S> for IsEqualTo<E>
+where
+    S: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_equal_to";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject == &self.expected
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = &self.expected;
+        if let Some((actual_text, expected_text)) = display_strings(actual, expected, format) {
+            let (marked_actual, marked_expected) =
+                mark_diff_str(&actual_text, &expected_text, format);
+            let position = if inverted {
+                String::new()
+            } else {
+                format_difference_position(text_difference_position(&actual_text, &expected_text))
+            };
+            return format!(
+                "expected {expression} to be {not}equal to {expected:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}{position}",
+            );
+        }
+        let (marked_actual, marked_expected) = mark_diff(actual, expected, format);
+        let differences = if inverted {
+            String::new()
+        } else if let Some(map_differences) = map_differences(actual, expected) {
+            format_map_differences(&map_differences, format)
+        } else {
+            format_differences(&collection_differences(actual, expected), format)
+                + &format_difference_position(debug_string_difference_position(actual, expected))
+        };
+        format!(
+            "expected {expression} to be {not}equal to {expected:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}{differences}",
+        )
+    }
+}
+
+impl<E> Invertible for IsEqualTo<E> {}
+
+/// Returns the texts of the actual and the expected value if display
+/// formatting is switched on and both values are strings.
+fn display_strings<S, E>(actual: &S, expected: &E, format: &DiffFormat) -> Option<(String, String)>
+where
+    S: Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    if !format.is_display_formatting() || !is_string_type::<S>() || !is_string_type::<E>() {
+        return None;
+    }
+    let actual = unescape_debug_string(&format!("{actual:?}"))?;
+    let expected = unescape_debug_string(&format!("{expected:?}"))?;
+    Some((actual, expected))
+}
+
+/// Returns whether the given type is a string type, like `String` or `&str`.
+fn is_string_type<T: ?Sized>() -> bool {
+    let mut type_name = any::type_name::<T>();
+    while let Some(referenced) = type_name.strip_prefix('&') {
+        type_name = referenced.trim_start_matches("mut ");
+    }
+    matches!(
+        type_name,
+        "str" | "alloc::string::String" | "alloc::borrow::Cow<str>" | "alloc::boxed::Box<str>"
+    )
+}
+
+impl<S, R> AssertSameAs<S> for Spec<'_, S, R>
+where
+    S: PartialEq + Debug,
+    R: FailingStrategy,
+{
+    fn is_same_as(self, expected: S) -> Self {
+        self.expecting(is_same_as(expected))
+    }
+
+    fn is_not_same_as(self, expected: S) -> Self {
+        self.expecting(not(is_same_as(expected)))
+    }
+}
+
+impl<S> Expectation<S> for IsSameAs<S>
+where
+    S: PartialEq + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_same_as";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject == &self.expected
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = &self.expected;
+        let (marked_actual, marked_expected) = mark_diff(actual, expected, format);
+        format!(
+            "expected {expression} to be {not}the same as {expected:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}",
+        )
+    }
+}
+
+impl<E> Invertible for IsSameAs<E> {}
+
+impl<'b, T, R> AssertSameInstance<&'b T> for Spec<'_, &T, R>
+where
+    T: Debug + ?Sized,
+    R: FailingStrategy,
+{
+    fn is_same_instance_as(self, other: &'b T) -> Self {
+        self.expecting(is_same_instance_as(other))
+    }
+
+    fn is_not_same_instance_as(self, other: &'b T) -> Self {
+        self.expecting(not(is_same_instance_as(other)))
+    }
+}
+
+impl<T> Expectation<&T> for IsSameInstanceAs<&T>
+where
+    T: Debug + ?Sized,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_same_instance_as";
+
+    fn test(&mut self, subject: &&T) -> bool {
+        ptr::eq(*subject, self.other)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&T,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        same_instance_message(
+            expression,
+            actual,
+            ptr::from_ref(*actual).cast(),
+            &self.other,
+            ptr::from_ref(self.other).cast(),
+            inverted,
+            format,
+        )
+    }
+}
+
+impl<T, R> AssertSameInstance<&Rc<T>> for Spec<'_, Rc<T>, R>
+where
+    T: Debug + ?Sized,
+    R: FailingStrategy,
+{
+    fn is_same_instance_as(self, other: &Rc<T>) -> Self {
+        self.expecting(is_same_instance_as(other))
+    }
+
+    fn is_not_same_instance_as(self, other: &Rc<T>) -> Self {
+        self.expecting(not(is_same_instance_as(other)))
+    }
+}
+
+impl<T> Expectation<Rc<T>> for IsSameInstanceAs<&Rc<T>>
+where
+    T: Debug + ?Sized,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_same_instance_as";
+
+    fn test(&mut self, subject: &Rc<T>) -> bool {
+        Rc::ptr_eq(subject, self.other)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Rc<T>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        same_instance_message(
+            expression,
+            actual,
+            Rc::as_ptr(actual).cast(),
+            &self.other,
+            Rc::as_ptr(self.other).cast(),
+            inverted,
+            format,
+        )
+    }
+}
+
+impl<T, R> AssertSameInstance<&Arc<T>> for Spec<'_, Arc<T>, R>
+where
+    T: Debug + ?Sized,
+    R: FailingStrategy,
+{
+    fn is_same_instance_as(self, other: &Arc<T>) -> Self {
+        self.expecting(is_same_instance_as(other))
+    }
+
+    fn is_not_same_instance_as(self, other: &Arc<T>) -> Self {
+        self.expecting(not(is_same_instance_as(other)))
+    }
+}
+
+impl<T> Expectation<Arc<T>> for IsSameInstanceAs<&Arc<T>>
+where
+    T: Debug + ?Sized,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_same_instance_as";
+
+    fn test(&mut self, subject: &Arc<T>) -> bool {
+        Arc::ptr_eq(subject, self.other)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Arc<T>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        same_instance_message(
+            expression,
+            actual,
+            Arc::as_ptr(actual).cast(),
+            &self.other,
+            Arc::as_ptr(self.other).cast(),
+            inverted,
+            format,
+        )
+    }
+}
+
+impl<E> Invertible for IsSameInstanceAs<E> {}
+
+fn same_instance_message(
+    expression: &Expression<'_>,
+    actual: &dyn Debug,
+    actual_address: *const (),
+    other: &dyn Debug,
+    other_address: *const (),
+    inverted: bool,
+    format: &DiffFormat,
+) -> String {
+    if inverted {
+        let marked_address = mark_unexpected_string(&format!("{actual_address:p}"), format);
+        format!(
+            "expected {expression} to not be the same instance as {other:?}\n   but was: {actual:?} at address {marked_address}\n  expected: not {other:?} at address {other_address:p}"
+        )
+    } else {
+        let marked_actual_address = mark_unexpected_string(&format!("{actual_address:p}"), format);
+        let marked_other_address = mark_missing_string(&format!("{other_address:p}"), format);
+        format!(
+            "expected {expression} to be the same instance as {other:?}\n   but was: {actual:?} at address {marked_actual_address}\n  expected: {other:?} at address {marked_other_address}"
+        )
+    }
+}
+
+impl<S, R> AssertDefault for Spec<'_, S, R>
+where
+    S: DefaultProperty + Debug,
+    R: FailingStrategy,
+{
+    fn is_default(self) -> Self {
+        self.expecting(is_default())
+    }
+
+    fn is_not_default(self) -> Self {
+        self.expecting(not(is_default()))
+    }
+}
+
+impl<S> Expectation<S> for IsDefault
+where
+    S: DefaultProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_default";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.is_default_property()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let default_value = S::default_property();
+        let (marked_actual, marked_expected) = mark_diff(actual, &default_value, format);
+        format!(
+            "expected {expression} to be {not}the default value {default_value:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}",
+        )
+    }
+}
+
+impl Invertible for IsDefault {}
+
+impl<S, E, R> AssertHasDebugString<E> for Spec<'_, S, R>
+where
+    S: Debug,
+    E: AsRef<str>,
+    R: FailingStrategy,
+{
+    fn has_debug_string(self, expected: E) -> Self {
+        self.expecting(has_debug_string(expected))
+    }
+
+    fn does_not_have_debug_string(self, expected: E) -> Self {
+        self.expecting(not(has_debug_string(expected)))
+    }
+}
+
+impl<S, E> Expectation<S> for HasDebugString<E>
+where
+    S: Debug,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_debug_string";
+
+    fn test(&mut self, subject: &S) -> bool {
+        format!("{subject:?}") == self.expected.as_ref()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected.as_ref();
+        let actual = format!("{actual:?}");
+        let (marked_actual, marked_expected) = mark_diff_str(&actual, expected, format);
+        let position = if inverted {
+            String::new()
+        } else {
+            format_difference_position(text_difference_position(&actual, expected))
+        };
+        format!(
+            "expected {expression} to {not}have a debug string equal to {expected:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}{position}",
+        )
+    }
+}
+
+impl<E> Invertible for HasDebugString<E> {}
+
+impl<S, E, R> AssertHasDisplayString<E> for Spec<'_, S, R>
+where
+    S: Display,
+    E: AsRef<str>,
+    R: FailingStrategy,
+{
+    fn has_display_string(self, expected: E) -> Self {
+        self.expecting(has_display_string(expected))
+    }
+
+    fn does_not_have_display_string(self, expected: E) -> Self {
+        self.expecting(not(has_display_string(expected)))
+    }
+}
+
+impl<S, E> Expectation<S> for HasDisplayString<E>
+where
+    S: Display,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_display_string";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.to_string() == self.expected.as_ref()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected.as_ref();
+        let (marked_actual, marked_expected) = mark_diff_display(actual, expected, format);
+        let quote = format.string_quote();
+        let position = if inverted {
+            String::new()
+        } else {
+            format_difference_position(text_difference_position(&actual.to_string(), expected))
+        };
+        format!(
+            "expected {expression} to {not}have a display string equal to {expected:?}\n   but was: {quote}{marked_actual}{quote}\n  expected: {not}{quote}{marked_expected}{quote}{position}",
+        )
+    }
+}
+
+impl<E> Invertible for HasDisplayString<E> {}
+
+#[cfg(test)]
+mod tests;
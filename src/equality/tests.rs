@@ -0,0 +1,485 @@
+use crate::prelude::*;
+use crate::std::{
+    collections::BTreeMap,
+    fmt, format,
+    rc::Rc,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+
+#[derive(Debug, Default, PartialEq)]
+struct Settings {
+    verbose: bool,
+    retries: u8,
+    name: String,
+}
+
+#[test]
+fn i32_zero_is_default() {
+    assert_that(0_i32).is_default();
+}
+
+#[test]
+fn i32_other_than_zero_is_not_default() {
+    assert_that(-1_i32).is_not_default();
+}
+
+#[test]
+fn empty_string_is_default() {
+    assert_that(String::new()).is_default();
+}
+
+#[test]
+fn empty_vec_is_default() {
+    assert_that(Vec::<u8>::new()).is_default();
+}
+
+#[test]
+fn non_empty_vec_is_not_default() {
+    assert_that(vec![1, 2, 3]).is_not_default();
+}
+
+#[test]
+fn custom_struct_is_default() {
+    assert_that(Settings::default()).is_default();
+}
+
+#[test]
+fn custom_struct_with_changed_field_is_not_default() {
+    let subject = Settings {
+        retries: 3,
+        ..Default::default()
+    };
+
+    assert_that(subject).is_not_default();
+}
+
+#[test]
+fn verify_custom_struct_is_default_fails() {
+    let subject = Settings {
+        verbose: true,
+        retries: 0,
+        name: "dolor".to_string(),
+    };
+
+    let failures = verify_that(subject)
+        .named("settings")
+        .is_default()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected settings to be the default value Settings { verbose: false, retries: 0, name: "" }
+   but was: Settings { verbose: true, retries: 0, name: "dolor" }
+  expected: Settings { verbose: false, retries: 0, name: "" }
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_i32_is_not_default_fails() {
+    let failures = verify_that(0_i32)
+        .named("retries")
+        .is_not_default()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected retries to be not the default value 0
+   but was: 0
+  expected: not 0
+"]
+    );
+}
+
+#[test]
+fn property_of_custom_struct_is_default() {
+    let subject = Settings {
+        verbose: true,
+        retries: 0,
+        name: String::new(),
+    };
+
+    assert_that(subject)
+        .extracting_ref("retries", |s| &s.retries)
+        .is_default()
+        .and()
+        .extracting_ref("verbose", |s| &s.verbose)
+        .is_not_default();
+}
+
+#[test]
+#[should_panic = "expected subject to be the default value 0\n   but was: 5\n  expected: 0\n"]
+fn string_length_is_default_panics() {
+    assert_that("lorem")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .mapping(str::len)
+        .is_default();
+}
+
+#[test]
+fn verify_vec_of_structs_is_equal_to_fails_with_field_level_differences() {
+    let subject = vec![
+        Settings {
+            verbose: true,
+            retries: 3,
+            name: "alpha".to_string(),
+        },
+        Settings {
+            verbose: false,
+            retries: 1,
+            name: "beta".to_string(),
+        },
+    ];
+
+    let failures = verify_that(subject)
+        .named("profiles")
+        .is_equal_to(vec![
+            Settings {
+                verbose: true,
+                retries: 3,
+                name: "alpha".to_string(),
+            },
+            Settings {
+                verbose: true,
+                retries: 1,
+                name: "gamma".to_string(),
+            },
+        ])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected profiles to be equal to [Settings { verbose: true, retries: 3, name: "alpha" }, Settings { verbose: true, retries: 1, name: "gamma" }]
+   but was: [Settings { verbose: true, retries: 3, name: "alpha" }, Settings { verbose: false, retries: 1, name: "beta" }]
+  expected: [Settings { verbose: true, retries: 3, name: "alpha" }, Settings { verbose: true, retries: 1, name: "gamma" }]
+  differences:
+    [1].verbose: but was false, expected true
+    [1].name: but was "beta", expected "gamma"
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_vec_of_values_with_custom_debug_layout_is_equal_to_fails() {
+    struct Multiline(u8);
+
+    impl fmt::Debug for Multiline {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Multiline {{\nvalue: {}\n}}", self.0)
+        }
+    }
+
+    impl PartialEq for Multiline {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    let failures = verify_that(vec![Multiline(1)])
+        .named("values")
+        .is_equal_to(vec![Multiline(2)])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected values to be equal to [Multiline {
+value: 2
+}]
+   but was: [Multiline {
+value: 1
+}]
+  expected: [Multiline {
+value: 2
+}]
+  differences: not available, the debug string could not be parsed
+"]
+    );
+}
+
+#[test]
+fn verify_vec_of_structs_is_not_equal_to_fails_without_differences() {
+    let failures = verify_that(vec![Settings::default()])
+        .named("profiles")
+        .is_not_equal_to(vec![Settings::default()])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected profiles to be not equal to [Settings { verbose: false, retries: 0, name: "" }]
+   but was: [Settings { verbose: false, retries: 0, name: "" }]
+  expected: not [Settings { verbose: false, retries: 0, name: "" }]
+"#
+        ]
+    );
+}
+
+#[test]
+fn reference_is_same_instance_as_reference_to_same_value() {
+    let value = String::from("ipsum aliquip");
+    let borrowed = &value;
+
+    assert_that(borrowed).is_same_instance_as(&value);
+}
+
+#[test]
+fn reference_is_not_same_instance_as_reference_to_equal_value() {
+    let value = String::from("ipsum aliquip");
+    let other = value.clone();
+
+    assert_that(&value).is_not_same_instance_as(&other);
+}
+
+#[test]
+fn verify_reference_is_same_instance_as_fails() {
+    let value = vec![1, 2, 3];
+    let other = vec![1, 2, 3];
+
+    let failures = verify_that(&value)
+        .named("numbers")
+        .is_same_instance_as(&other)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[format!(
+            "expected numbers to be the same instance as [1, 2, 3]\n   but was: [1, 2, 3] at address {:p}\n  expected: [1, 2, 3] at address {:p}\n",
+            &raw const value, &raw const other
+        )]
+    );
+}
+
+#[test]
+fn verify_reference_is_not_same_instance_as_fails() {
+    let value = vec![1, 2, 3];
+
+    let failures = verify_that(&value)
+        .named("numbers")
+        .is_not_same_instance_as(&value)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[format!(
+            "expected numbers to not be the same instance as [1, 2, 3]\n   but was: [1, 2, 3] at address {0:p}\n  expected: not [1, 2, 3] at address {0:p}\n",
+            &raw const value
+        )]
+    );
+}
+
+#[test]
+fn rc_is_same_instance_as_its_clone() {
+    let shared = Rc::new(Settings::default());
+
+    assert_that(Rc::clone(&shared)).is_same_instance_as(&shared);
+}
+
+#[test]
+fn rc_is_not_same_instance_as_rc_with_equal_value() {
+    let shared = Rc::new(Settings::default());
+
+    assert_that(Rc::new(Settings::default())).is_not_same_instance_as(&shared);
+}
+
+#[test]
+fn verify_rc_is_same_instance_as_fails() {
+    let subject = Rc::new(42);
+    let other = Rc::new(42);
+
+    let failures = verify_that(Rc::clone(&subject))
+        .named("shared")
+        .is_same_instance_as(&other)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[format!(
+            "expected shared to be the same instance as 42\n   but was: 42 at address {:p}\n  expected: 42 at address {:p}\n",
+            Rc::as_ptr(&subject),
+            Rc::as_ptr(&other)
+        )]
+    );
+}
+
+#[test]
+fn arc_is_same_instance_as_its_clone() {
+    let shared = Arc::new(Settings::default());
+
+    assert_that(Arc::clone(&shared)).is_same_instance_as(&shared);
+}
+
+#[test]
+fn arc_is_not_same_instance_as_arc_with_equal_value() {
+    let shared = Arc::new(Settings::default());
+
+    assert_that(Arc::new(Settings::default())).is_not_same_instance_as(&shared);
+}
+
+#[test]
+fn verify_arc_is_not_same_instance_as_fails() {
+    let shared = Arc::new("dolor");
+
+    let failures = verify_that(Arc::clone(&shared))
+        .named("shared")
+        .is_not_same_instance_as(&shared)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[format!(
+            "expected shared to not be the same instance as \"dolor\"\n   but was: \"dolor\" at address {0:p}\n  expected: not \"dolor\" at address {0:p}\n",
+            Arc::as_ptr(&shared)
+        )]
+    );
+}
+
+#[test]
+fn verify_map_is_equal_to_fails_with_missing_extra_and_different_keys() {
+    let subject: BTreeMap<&str, u16> = [("http", 80), ("https", 443), ("ssh", 22)].into();
+
+    let failures = verify_that(subject)
+        .named("ports")
+        .is_equal_to(BTreeMap::from([
+            ("ftp", 21),
+            ("http", 8080),
+            ("https", 443),
+        ]))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected ports to be equal to {"ftp": 21, "http": 8080, "https": 443}
+   but was: {"http": 80, "https": 443, "ssh": 22}
+  expected: {"ftp": 21, "http": 8080, "https": 443}
+    only in actual: {"ssh": 22}
+  only in expected: {"ftp": 21}
+  different values:
+    ["http"]: but was 80, expected 8080
+"#
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn verify_map_of_structs_is_equal_to_fails_with_field_level_differences() {
+    use std::collections::HashMap;
+
+    let subject = HashMap::from([(
+        1,
+        Settings {
+            verbose: true,
+            retries: 3,
+            name: "alpha".to_string(),
+        },
+    )]);
+
+    let failures = verify_that(subject)
+        .named("profiles")
+        .is_equal_to(HashMap::from([(
+            1,
+            Settings {
+                verbose: true,
+                retries: 5,
+                name: "alpha".to_string(),
+            },
+        )]))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected profiles to be equal to {1: Settings { verbose: true, retries: 5, name: "alpha" }}
+   but was: {1: Settings { verbose: true, retries: 3, name: "alpha" }}
+  expected: {1: Settings { verbose: true, retries: 5, name: "alpha" }}
+  different values:
+    [1].retries: but was 3, expected 5
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_multi_line_string_is_equal_to_fails_with_position_of_first_difference() {
+    let failures = verify_that("lorem ipsum\ndolor sit amet\nconsectetur adipiscing elit")
+        .named("text")
+        .is_equal_to("lorem ipsum\ndolor sit amet\nconsectetur adipisicing elit")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected text to be equal to "lorem ipsum\ndolor sit amet\nconsectetur adipisicing elit"
+   but was: "lorem ipsum\ndolor sit amet\nconsectetur adipiscing elit"
+  expected: "lorem ipsum\ndolor sit amet\nconsectetur adipisicing elit"
+  first difference at line 3, column 19
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_multi_line_string_is_not_equal_to_fails_without_position() {
+    let failures = verify_that(String::from("lorem\nipsum"))
+        .named("text")
+        .is_not_equal_to("lorem\nipsum")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected text to be not equal to "lorem\nipsum"
+   but was: "lorem\nipsum"
+  expected: not "lorem\nipsum"
+"#]
+    );
+}
+
+#[test]
+fn verify_multi_line_display_string_fails_with_position_of_first_difference() {
+    let failures = verify_that("lorem ipsum\ndolor sit amet".to_string())
+        .named("text")
+        .has_display_string("lorem ipsum\ndolor sed amet")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected text to have a display string equal to "lorem ipsum\ndolor sed amet"
+   but was: "lorem ipsum
+dolor sit amet"
+  expected: "lorem ipsum
+dolor sed amet"
+  first difference at line 2, column 8
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_long_debug_string_fails_with_position_of_first_difference() {
+    let subject = vec!["lorem ipsum dolor sit amet"; 4];
+
+    let failures = verify_that(subject)
+        .named("texts")
+        .has_debug_string(r#"["lorem ipsum dolor sit amet", "lorem ipsum dolor sit amet", "lorem ipsum dolor sed amet", "lorem ipsum dolor sit amet"]"#)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected texts to have a debug string equal to "[\"lorem ipsum dolor sit amet\", \"lorem ipsum dolor sit amet\", \"lorem ipsum dolor sed amet\", \"lorem ipsum dolor sit amet\"]"
+   but was: ["lorem ipsum dolor sit amet", "lorem ipsum dolor sit amet", "lorem ipsum dolor sit amet", "lorem ipsum dolor sit amet"]
+  expected: ["lorem ipsum dolor sit amet", "lorem ipsum dolor sit amet", "lorem ipsum dolor sed amet", "lorem ipsum dolor sit amet"]
+  first difference at line 1, column 82
+"#
+        ]
+    );
+}
@@ -1,12 +1,20 @@
-use crate::assertions::AssertErrorHasSource;
-use crate::colored::{mark_missing, mark_missing_string, mark_unexpected, mark_unexpected_string};
+use crate::assertions::{AssertErrorHasSource, AssertErrorMessage};
+use crate::colored::{
+    mark_diff_str, mark_missing, mark_missing_string, mark_unexpected, mark_unexpected_string,
+    mark_unexpected_substring_in_string,
+};
 use crate::expectations::{
-    ErrorHasSource, ErrorHasSourceMessage, error_has_source, error_has_source_message, not,
+    ErrorHasMessage, ErrorHasMessageContaining, ErrorHasSource, ErrorHasSourceMessage,
+    error_has_message, error_has_message_containing, error_has_source, error_has_source_message,
+    not,
 };
+use crate::properties::ErrorTypeProperty;
 use crate::spec::{
     DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
 };
+use crate::std::boxed::Box;
 use crate::std::error::Error;
+use crate::std::fmt::Display;
 use crate::std::format;
 use crate::std::string::{String, ToString};
 
@@ -32,10 +40,65 @@ where
     }
 }
 
+impl<S, R> AssertErrorMessage for Spec<'_, S, R>
+where
+    S: Display,
+    R: FailingStrategy,
+{
+    fn has_message(self, expected_message: impl Into<String>) -> Self {
+        self.expecting(error_has_message(expected_message))
+    }
+
+    fn has_message_containing(self, expected_substring: impl Into<String>) -> Self {
+        self.expecting(error_has_message_containing(expected_substring))
+    }
+}
+
+impl<E> ErrorTypeProperty for Box<E>
+where
+    E: ErrorTypeProperty + ?Sized,
+{
+    fn is_error_of_type_property<X>(&self) -> bool
+    where
+        X: Error + 'static,
+    {
+        <E as ErrorTypeProperty>::is_error_of_type_property::<X>(self)
+    }
+}
+
+impl ErrorTypeProperty for dyn Error + 'static {
+    fn is_error_of_type_property<X>(&self) -> bool
+    where
+        X: Error + 'static,
+    {
+        self.is::<X>()
+    }
+}
+
+impl ErrorTypeProperty for dyn Error + Send + 'static {
+    fn is_error_of_type_property<X>(&self) -> bool
+    where
+        X: Error + 'static,
+    {
+        self.is::<X>()
+    }
+}
+
+impl ErrorTypeProperty for dyn Error + Send + Sync + 'static {
+    fn is_error_of_type_property<X>(&self) -> bool
+    where
+        X: Error + 'static,
+    {
+        self.is::<X>()
+    }
+}
+
 impl<S> Expectation<S> for ErrorHasSource
 where
     S: Error,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_source";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.source().is_some()
     }
@@ -66,6 +129,8 @@ impl<S> Expectation<S> for ErrorHasSourceMessage
 where
     S: Error,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_source_message";
+
     fn test(&mut self, subject: &S) -> bool {
         subject
             .source()
@@ -100,5 +165,141 @@ where
 
 impl Invertible for ErrorHasSourceMessage {}
 
+impl<S> Expectation<S> for ErrorHasMessage
+where
+    S: Display,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_message";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.to_string() == self.expected_message
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected = &self.expected_message;
+        let actual = actual.to_string();
+        let (not, marked_actual, marked_expected) = if inverted {
+            let marked_actual = mark_unexpected_string(&actual, format);
+            let marked_expected = mark_missing_string(expected, format);
+            ("not ", marked_actual, marked_expected)
+        } else {
+            let (marked_actual, marked_expected) = mark_diff_str(&actual, expected, format);
+            ("", marked_actual, marked_expected)
+        };
+        format!(
+            "expected {expression} to have a message {not}equal to \"{expected}\"\n   but was: \"{marked_actual}\"\n  expected: {not}\"{marked_expected}\""
+        )
+    }
+}
+
+impl Invertible for ErrorHasMessage {}
+
+impl<S> Expectation<S> for ErrorHasMessageContaining
+where
+    S: Display,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_message_containing";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.to_string().contains(&self.expected_substring)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected = &self.expected_substring;
+        let actual = actual.to_string();
+        let (not, marked_actual) = if inverted {
+            let marked_actual = mark_unexpected_substring_in_string(&actual, expected, format);
+            ("not ", marked_actual)
+        } else {
+            let marked_actual = mark_unexpected_string(&actual, format);
+            ("", marked_actual)
+        };
+        let marked_expected = mark_missing_string(expected, format);
+        format!(
+            "expected {expression} to have a message {not}containing \"{expected}\"\n   but was: \"{marked_actual}\"\n  expected: {not}\"{marked_expected}\""
+        )
+    }
+}
+
+impl Invertible for ErrorHasMessageContaining {}
+
+#[cfg(feature = "regex")]
+mod regex {
+    use crate::assertions::AssertErrorMessageMatches;
+    use crate::colored::{mark_missing_string, mark_unexpected_string};
+    use crate::expectations::{ErrorHasMessageMatching, error_has_message_matching};
+    use crate::spec::{
+        DiffFormat, EvalError, Expectation, Expecting, Expression, FailingStrategy, Invertible,
+        Spec,
+    };
+    use crate::std::fmt::Display;
+    use crate::std::format;
+    use crate::std::string::{String, ToString};
+
+    impl<S, R> AssertErrorMessageMatches for Spec<'_, S, R>
+    where
+        S: Display,
+        R: FailingStrategy,
+    {
+        fn has_message_matching(self, regex_pattern: &str) -> Self {
+            self.expecting(error_has_message_matching(regex_pattern))
+        }
+    }
+
+    impl<S> Expectation<S> for ErrorHasMessageMatching<'_>
+    where
+        S: Display,
+    {
+        const ASSERTION_ID: &'static str = "asserting::has_message_matching";
+
+        fn test(&mut self, subject: &S) -> bool {
+            self.try_test(subject).unwrap_or(false)
+        }
+
+        fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+            match &self.error {
+                Some(err) => Err(EvalError::new(format!(
+                    "failed to match error message with regex: {err}"
+                ))),
+                None => Ok(self.regex.is_match(&subject.to_string())),
+            }
+        }
+
+        fn message(
+            &self,
+            expression: &Expression<'_>,
+            actual: &S,
+            inverted: bool,
+            format: &DiffFormat,
+        ) -> String {
+            let (not, does_not_match) = if inverted {
+                ("not ", "    does match")
+            } else {
+                ("", "does not match")
+            };
+            let regex = self.pattern;
+            let marked_actual = mark_unexpected_string(&actual.to_string(), format);
+            let marked_expected = mark_missing_string(regex, format);
+            format!(
+                "expected {expression} to have a message {not}matching the regex {regex}\n               but was: {marked_actual}\n  {does_not_match} regex: {marked_expected}"
+            )
+        }
+    }
+
+    impl Invertible for ErrorHasMessageMatching<'_> {}
+}
+
 #[cfg(test)]
 mod tests;
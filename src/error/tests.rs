@@ -1,4 +1,6 @@
+use crate::expectations::{error_has_message, error_has_message_containing, not};
 use crate::prelude::*;
+use crate::std::boxed::Box;
 use crate::std::error::Error;
 use crate::std::fmt::{self, Display};
 use crate::std::vec::Vec;
@@ -274,9 +276,129 @@ fn result_is_error_which_has_source() {
         .has_source_message("bar error");
 }
 
+#[test]
+fn error_has_expected_message() {
+    let error = SuperError {
+        source: SourceError::Foo,
+    };
+
+    assert_that(error).has_message("super-error caused by foo error");
+}
+
+#[test]
+fn boxed_error_has_message() {
+    let error: Box<dyn Error> = Box::new(SourceError::Bar);
+
+    assert_that(error).has_message("bar error");
+}
+
+#[test]
+fn anyhow_error_has_message() {
+    let error = anyhow::anyhow!("connection refused by peer");
+
+    assert_that(error).has_message("connection refused by peer");
+}
+
+#[test]
+fn verify_error_has_message_fails() {
+    let error = SuperError {
+        source: SourceError::Bar,
+    };
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .has_message("super-error caused by foo error")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to have a message equal to "super-error caused by foo error"
+   but was: "super-error caused by bar error"
+  expected: "super-error caused by foo error"
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_error_has_message_not_equal_to_fails() {
+    let error = SourceError::Foo;
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .expecting(not(error_has_message("foo error")))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_error to have a message not equal to "foo error"
+   but was: "foo error"
+  expected: not "foo error"
+"#
+        ]
+    );
+}
+
+#[test]
+fn error_has_message_containing_substring() {
+    let error = SuperError {
+        source: SourceError::Bar,
+    };
+
+    assert_that(error).has_message_containing("caused by bar");
+}
+
+#[test]
+fn anyhow_error_in_result_has_message_containing() {
+    let result: Result<(), anyhow::Error> = Err(anyhow::anyhow!("file not found: config.toml"));
+
+    assert_that(result)
+        .err()
+        .has_message_containing("not found");
+}
+
+#[test]
+fn verify_error_has_message_containing_fails() {
+    let error: Box<dyn Error> = Box::new(SourceError::Foo);
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .has_message_containing("bar")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_error to have a message containing "bar"
+   but was: "foo error"
+  expected: "bar"
+"#]
+    );
+}
+
+#[test]
+fn verify_error_has_message_not_containing_fails() {
+    let error = SourceError::Foo;
+
+    let failures = verify_that(error)
+        .named("my_error")
+        .expecting(not(error_has_message_containing("foo")))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_error to have a message not containing "foo"
+   but was: "foo error"
+  expected: not "foo"
+"#]
+    );
+}
+
 #[cfg(feature = "colored")]
 mod colored {
     use crate::error::tests::{SourceError, SuperError};
+    use crate::expectations::{error_has_message_containing, not};
     use crate::prelude::*;
 
     #[test]
@@ -358,11 +480,52 @@ mod colored {
             ]
         );
     }
+
+    #[test]
+    fn highlight_diffs_error_has_message() {
+        let error = SourceError::Bar;
+
+        let failures = verify_that(error)
+            .with_diff_format(DIFF_FORMAT_RED_YELLOW)
+            .has_message("baz error")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to have a message equal to \"baz error\"\n   \
+                but was: \"ba\u{1b}[31mr\u{1b}[0m error\"\n  \
+               expected: \"ba\u{1b}[33mz\u{1b}[0m error\"\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_error_has_message_not_containing() {
+        let error = SourceError::Bar;
+
+        let failures = verify_that(error)
+            .with_diff_format(DIFF_FORMAT_RED_YELLOW)
+            .expecting(not(error_has_message_containing("error")))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to have a message not containing \"error\"\n   \
+                but was: \"bar \u{1b}[31merror\u{1b}[0m\"\n  \
+               expected: not \"\u{1b}[33merror\u{1b}[0m\"\n\
+            "
+            ]
+        );
+    }
 }
 
 #[cfg(feature = "regex")]
 mod with_regex {
     use super::*;
+    use crate::expectations::error_has_message_matching;
 
     #[test]
     fn error_has_debug_string_matching_regex() {
@@ -425,4 +588,71 @@ mod with_regex {
             ]
         );
     }
+
+    #[test]
+    fn error_has_message_matching_regex() {
+        let error = anyhow::anyhow!("request timed out after 30s");
+
+        assert_that(error).has_message_matching(r"timed out after \d+s$");
+    }
+
+    #[test]
+    fn verify_error_has_message_matching_regex_fails() {
+        let error: Box<dyn Error> = Box::new(SourceError::Foo);
+
+        let failures = verify_that(error)
+            .named("my_error")
+            .has_message_matching(r"^(bar|baz) error$")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected my_error to have a message matching the regex ^(bar|baz) error$
+               but was: foo error
+  does not match regex: ^(bar|baz) error$
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_error_has_message_not_matching_regex_fails() {
+        let error = SourceError::Bar;
+
+        let failures = verify_that(error)
+            .named("my_error")
+            .expecting(not(error_has_message_matching(r"^(bar|baz) error$")))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected my_error to have a message not matching the regex ^(bar|baz) error$
+               but was: bar error
+      does match regex: ^(bar|baz) error$
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_has_message_matching_given_an_invalid_regex_fails_with_evaluation_error() {
+        let failures = verify_that(SourceError::Foo)
+            .named("my_error")
+            .has_message_matching(r"foo(")
+            .display_failures();
+
+        assert_that(&failures[0]).starts_with(
+            "could not evaluate the assertion on my_error\n  error: failed to match error message with regex: regex parse error",
+        );
+    }
+
+    #[test]
+    #[should_panic = "could not evaluate the assertion on subject\n  error: failed to match error message with regex: regex parse error"]
+    fn has_message_matching_panics_on_invalid_regex() {
+        let error = SourceError::Foo;
+
+        assert_that(error).has_message_matching(r"foo(");
+    }
 }
@@ -1,17 +1,33 @@
-use crate::expectations::{All, Any, IntoRec, Not, Rec};
-use crate::spec::{DiffFormat, Expectation, Expression, Invertible};
+use crate::assertion_id::wrap_assertion_id;
+use crate::expectations::{All, Any, IntoRec, Negate, Not, Rec};
+use crate::spec::{
+    DiffFormat, EvalError, Expectation, ExpectationWithContext, Expression, Invertible,
+    SpecMetadata,
+};
+use crate::std::borrow::Cow;
+use crate::std::format;
 use crate::std::string::String;
 
 impl<S, E> Expectation<S> for Rec<E>
 where
     E: Expectation<S>,
 {
+    fn assertion_id(&self) -> Cow<'static, str> {
+        self.expectation.assertion_id()
+    }
+
     fn test(&mut self, subject: &S) -> bool {
         let result = self.expectation.test(subject);
         self.result = Some(result);
         result
     }
 
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        let result = self.expectation.try_test(subject)?;
+        self.result = Some(result);
+        Ok(result)
+    }
+
     fn message(
         &self,
         expression: &Expression<'_>,
@@ -29,6 +45,35 @@ where
     }
 }
 
+impl<S, E> ExpectationWithContext<S> for Rec<E>
+where
+    E: ExpectationWithContext<S>,
+{
+    fn assertion_id(&self) -> Cow<'static, str> {
+        self.expectation.assertion_id()
+    }
+
+    fn test(&mut self, subject: &S) -> bool {
+        let result = self.expectation.test(subject);
+        self.result = Some(result);
+        result
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        let result = self.expectation.try_test(subject)?;
+        self.result = Some(result);
+        Ok(result)
+    }
+
+    fn message(&self, metadata: &SpecMetadata<'_>, actual: &S, inverted: bool) -> String {
+        if self.is_failure() {
+            self.expectation.message(metadata, actual, inverted) + "\n"
+        } else {
+            String::new()
+        }
+    }
+}
+
 impl<E> From<E> for Rec<E> {
     fn from(expectation: E) -> Self {
         Self::new(expectation)
@@ -66,10 +111,18 @@ impl<S, E> Expectation<S> for Not<E>
 where
     E: Invertible + Expectation<S>,
 {
+    fn assertion_id(&self) -> Cow<'static, str> {
+        Cow::Owned(wrap_assertion_id("not", &self.0.assertion_id()))
+    }
+
     fn test(&mut self, subject: &S) -> bool {
         !self.0.test(subject)
     }
 
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        self.0.try_test(subject).map(|result| !result)
+    }
+
     fn message(
         &self,
         expression: &Expression<'_>,
@@ -81,10 +134,73 @@ where
     }
 }
 
+impl<S, E> ExpectationWithContext<S> for Not<E>
+where
+    E: Invertible + ExpectationWithContext<S>,
+{
+    fn assertion_id(&self) -> Cow<'static, str> {
+        Cow::Owned(wrap_assertion_id("not", &self.0.assertion_id()))
+    }
+
+    fn test(&mut self, subject: &S) -> bool {
+        !self.0.test(subject)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        self.0.try_test(subject).map(|result| !result)
+    }
+
+    fn message(&self, metadata: &SpecMetadata<'_>, actual: &S, inverted: bool) -> String {
+        self.0.message(metadata, actual, !inverted)
+    }
+}
+
+impl<S, E> Expectation<S> for Negate<E>
+where
+    E: Expectation<S>,
+{
+    fn assertion_id(&self) -> Cow<'static, str> {
+        Cow::Owned(wrap_assertion_id("not", &self.0.assertion_id()))
+    }
+
+    fn test(&mut self, subject: &S) -> bool {
+        !self.0.test(subject)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        self.0.try_test(subject).map(|result| !result)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let message = self.0.message(expression, actual, false, format);
+        if inverted {
+            return message;
+        }
+        let inverted_message = self.0.message(expression, actual, true, format);
+        if inverted_message != message {
+            return inverted_message;
+        }
+        message.strip_prefix("expected ").map_or_else(
+            || format!("expected NOT: {message}"),
+            |description| format!("expected NOT {description}"),
+        )
+    }
+}
+
+impl<E> Invertible for Negate<E> {}
+
 macro_rules! impl_expectation_for_all_combinator {
     ( $( $tp_name:ident )+ ) => {
         #[allow(non_snake_case)]
         impl<S, $($tp_name: Expectation<S>),+> Expectation<S> for All<($(Rec<$tp_name>,)+)> {
+            const ASSERTION_ID: &'static str = "asserting::all";
+
             fn test(&mut self, subject: &S) -> bool {
                 let ($($tp_name,)+) = &mut self.0;
                 $(
@@ -93,6 +209,14 @@ macro_rules! impl_expectation_for_all_combinator {
                 $( $tp_name )&&+
             }
 
+            fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+                let ($($tp_name,)+) = &mut self.0;
+                $(
+                    let $tp_name = $tp_name.try_test(subject)?;
+                )+
+                Ok($( $tp_name )&&+)
+            }
+
             fn message(
                 &self,
                 expression: &Expression<'_>,
@@ -124,10 +248,61 @@ impl_expectation_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 }
 impl_expectation_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 }
 impl_expectation_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 }
 
+macro_rules! impl_expectation_with_context_for_all_combinator {
+    ( $( $tp_name:ident )+ ) => {
+        #[allow(non_snake_case)]
+        impl<S, $($tp_name: ExpectationWithContext<S>),+> ExpectationWithContext<S>
+            for All<($(Rec<$tp_name>,)+)>
+        {
+            const ASSERTION_ID: &'static str = "asserting::all";
+
+            fn test(&mut self, subject: &S) -> bool {
+                let ($($tp_name,)+) = &mut self.0;
+                $(
+                    let $tp_name = $tp_name.test(subject);
+                )+
+                $( $tp_name )&&+
+            }
+
+            fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+                let ($($tp_name,)+) = &mut self.0;
+                $(
+                    let $tp_name = $tp_name.try_test(subject)?;
+                )+
+                Ok($( $tp_name )&&+)
+            }
+
+            fn message(&self, metadata: &SpecMetadata<'_>, actual: &S, inverted: bool) -> String {
+                let ($($tp_name,)+) = &self.0;
+                let mut message = String::new();
+                $(
+                    message.push_str(&$tp_name.message(metadata, actual, inverted));
+                )+
+                message
+            }
+        }
+    };
+}
+
+impl_expectation_with_context_for_all_combinator! { A1 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 A5 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 A5 A6 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 }
+impl_expectation_with_context_for_all_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 }
+
 macro_rules! impl_expectation_for_any_combinator {
     ( $( $tp_name:ident )+ ) => {
         #[allow(non_snake_case)]
         impl<S, $($tp_name: Expectation<S>),+> Expectation<S> for Any<($(Rec<$tp_name>,)+)> {
+            const ASSERTION_ID: &'static str = "asserting::any";
+
             fn test(&mut self, subject: &S) -> bool {
                 let ($($tp_name,)+) = &mut self.0;
                 $(
@@ -136,6 +311,14 @@ macro_rules! impl_expectation_for_any_combinator {
                 $( $tp_name )||+
             }
 
+            fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+                let ($($tp_name,)+) = &mut self.0;
+                $(
+                    let $tp_name = $tp_name.try_test(subject)?;
+                )+
+                Ok($( $tp_name )||+)
+            }
+
             fn message(
                 &self,
                 expression: &Expression<'_>,
@@ -167,5 +350,54 @@ impl_expectation_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 }
 impl_expectation_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 }
 impl_expectation_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 }
 
+macro_rules! impl_expectation_with_context_for_any_combinator {
+    ( $( $tp_name:ident )+ ) => {
+        #[allow(non_snake_case)]
+        impl<S, $($tp_name: ExpectationWithContext<S>),+> ExpectationWithContext<S>
+            for Any<($(Rec<$tp_name>,)+)>
+        {
+            const ASSERTION_ID: &'static str = "asserting::any";
+
+            fn test(&mut self, subject: &S) -> bool {
+                let ($($tp_name,)+) = &mut self.0;
+                $(
+                    let $tp_name = $tp_name.test(subject);
+                )+
+                $( $tp_name )||+
+            }
+
+            fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+                let ($($tp_name,)+) = &mut self.0;
+                $(
+                    let $tp_name = $tp_name.try_test(subject)?;
+                )+
+                Ok($( $tp_name )||+)
+            }
+
+            fn message(&self, metadata: &SpecMetadata<'_>, actual: &S, inverted: bool) -> String {
+                let ($($tp_name,)+) = &self.0;
+                let mut message = String::new();
+                $(
+                    message.push_str(&$tp_name.message(metadata, actual, inverted));
+                )+
+                message
+            }
+        }
+    };
+}
+
+impl_expectation_with_context_for_any_combinator! { A1 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 A5 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 A5 A6 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 }
+impl_expectation_with_context_for_any_combinator! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 }
+
 #[cfg(test)]
 mod tests;
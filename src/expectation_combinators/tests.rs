@@ -1,9 +1,14 @@
 use crate::expectations::{
-    IsBetween, IsEmpty, IsGreaterThan, IsLessThan, IsNegative, IsOne, IsPositive, IsZero,
-    StringContains, StringContainsAnyOf, all, any, not, rec,
+    IsBetween, IsEmpty, IsEqualTo, IsGreaterThan, IsLessThan, IsNegative, IsOne, IsPositive,
+    IsZero, StringContains, StringContainsAnyOf, all, any, negate, not, rec,
 };
 use crate::prelude::*;
-use crate::spec::{Expectation, Expression};
+use crate::spec::{
+    DiffFormat, Expectation, ExpectationWithContext, Expression, Invertible, SpecMetadata,
+    WithoutContext,
+};
+use crate::std::format;
+use crate::std::string::String;
 
 #[test]
 fn newly_created_rec_combinator_is_neither_success_nor_failure() {
@@ -524,3 +529,243 @@ fn any_combinator_asserts_12_expectations() {
         StringContains { expected: 'k' },
     )));
 }
+
+struct IsEven;
+
+impl Expectation<i32> for IsEven {
+    fn test(&mut self, subject: &i32) -> bool {
+        subject % 2 == 0
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &i32,
+        _inverted: bool,
+        _format: &DiffFormat,
+    ) -> String {
+        format!(
+            "expected {expression} to be even\n   but was: {actual}\n  expected: an even number"
+        )
+    }
+}
+
+struct IsDivisibleByThree;
+
+impl Expectation<i32> for IsDivisibleByThree {
+    fn test(&mut self, subject: &i32) -> bool {
+        subject % 3 == 0
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &i32,
+        _inverted: bool,
+        _format: &DiffFormat,
+    ) -> String {
+        format!("{expression} = {actual} is not divisible by 3")
+    }
+}
+
+#[test]
+fn negate_combinator_inverts_expectation_that_is_not_invertible() {
+    assert_that(41).expecting(negate(IsEven));
+}
+
+#[test]
+fn verify_negate_combinator_inverts_expectation_that_is_not_invertible_fails() {
+    let failures = verify_that(42)
+        .named("answer")
+        .expecting(negate(IsEven))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected NOT answer to be even
+   but was: 42
+  expected: an even number
+"]
+    );
+}
+
+#[test]
+fn verify_negate_combinator_with_message_not_starting_with_expected_fails() {
+    let failures = verify_that(42)
+        .named("answer")
+        .expecting(negate(IsDivisibleByThree))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected NOT: answer = 42 is not divisible by 3
+"]
+    );
+}
+
+#[test]
+fn verify_negate_combinator_uses_the_inverted_message_of_the_expectation_fails() {
+    let failures = verify_that(42)
+        .named("answer")
+        .expecting(negate(IsEqualTo { expected: 42 }))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected answer to be not equal to 42
+   but was: 42
+  expected: not 42
+"]
+    );
+}
+
+#[test]
+fn not_combinator_reverts_negate_combinator() {
+    assert_that(42).expecting(not(negate(IsEven)));
+}
+
+#[test]
+fn verify_not_combinator_reverts_negate_combinator_fails() {
+    let failures = verify_that(41)
+        .named("answer")
+        .expecting(not(negate(IsEven)))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected answer to be even
+   but was: 41
+  expected: an even number
+"]
+    );
+}
+
+#[test]
+fn not_expecting_asserts_that_expectation_is_not_met() {
+    assert_that(41)
+        .not_expecting(IsEven)
+        .not_expecting(IsDivisibleByThree)
+        .not_expecting(IsZero);
+}
+
+#[test]
+fn verify_not_expecting_asserts_that_expectation_is_not_met_fails() {
+    let failures = verify_that(42)
+        .named("answer")
+        .not_expecting(IsEven)
+        .not_expecting(IsPositive)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected NOT answer to be even
+   but was: 42
+  expected: an even number
+",
+            r"expected answer to be not positive
+   but was: 42
+  expected: <= 0
+"
+        ]
+    );
+}
+
+#[test]
+fn extracted_subject_not_expecting_expectation() {
+    struct Answer(i32);
+
+    assert_that(Answer(43))
+        .extracting_ref("0", |answer| &answer.0)
+        .not_expecting(IsEven);
+}
+
+struct IsPowerOfTwo;
+
+impl ExpectationWithContext<i32> for IsPowerOfTwo {
+    fn test(&mut self, subject: &i32) -> bool {
+        *subject > 0 && subject.count_ones() == 1
+    }
+
+    fn message(&self, metadata: &SpecMetadata<'_>, actual: &i32, inverted: bool) -> String {
+        let not = if inverted { "NOT " } else { "" };
+        format!(
+            "expected {} of type {} {not}to be a power of two\n   but was: {actual}",
+            metadata.expression, metadata.subject_type_name,
+        )
+    }
+}
+
+impl Invertible for IsPowerOfTwo {}
+
+#[test]
+fn not_combinator_with_context_expectation() {
+    assert_that(12_i32).expecting_with_context(not(IsPowerOfTwo));
+}
+
+#[test]
+fn verify_not_combinator_with_context_expectation_fails() {
+    let failures = verify_that(16_i32)
+        .named("size")
+        .expecting_with_context(not(IsPowerOfTwo))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected size of type i32 NOT to be a power of two
+   but was: 16
+"]
+    );
+}
+
+#[test]
+fn all_combinator_with_context_expectations() {
+    assert_that(16_i32).expecting_with_context(all((
+        IsPowerOfTwo,
+        WithoutContext(IsPositive),
+        not(WithoutContext(IsOne)),
+    )));
+}
+
+#[test]
+fn verify_all_combinator_with_context_expectations_fails() {
+    let failures = verify_that(0_i32)
+        .named("size")
+        .expecting_with_context(all((IsPowerOfTwo, WithoutContext(IsPositive))))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected size of type i32 to be a power of two
+   but was: 0
+expected size to be positive
+   but was: 0
+  expected: > 0
+
+"]
+    );
+}
+
+#[test]
+fn any_combinator_with_context_expectations() {
+    assert_that(12_i32).expecting_with_context(any((IsPowerOfTwo, WithoutContext(IsPositive))));
+}
+
+#[test]
+fn verify_any_combinator_with_context_expectations_fails() {
+    let failures = verify_that(0_i32)
+        .named("size")
+        .expecting_with_context(any((IsPowerOfTwo, WithoutContext(IsOne))))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected size of type i32 to be a power of two
+   but was: 0
+expected size to be one
+   but was: 0
+  expected: 1
+
+"]
+    );
+}
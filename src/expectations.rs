@@ -3,7 +3,9 @@
 #![allow(missing_docs)]
 #![warn(clippy::return_self_not_must_use)]
 
+use crate::spec::Expression;
 use crate::std::marker::PhantomData;
+use crate::std::time::Duration;
 use crate::std::{string::String, vec::Vec};
 use hashbrown::HashSet;
 #[cfg(feature = "regex")]
@@ -43,6 +45,55 @@ pub fn not<E>(expectation: E) -> Not<E> {
 #[must_use]
 pub struct Not<E>(pub E);
 
+/// Creates a [`Negate`] expectation combinator wrapping the given expectation.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::expectations::negate;
+/// use asserting::prelude::*;
+/// use asserting::spec::{DiffFormat, Expectation, Expression};
+///
+/// struct IsEven;
+///
+/// impl Expectation<i32> for IsEven {
+///     fn test(&mut self, subject: &i32) -> bool {
+///         subject % 2 == 0
+///     }
+///
+///     fn message(
+///         &self,
+///         expression: &Expression<'_>,
+///         actual: &i32,
+///         _inverted: bool,
+///         _format: &DiffFormat,
+///     ) -> String {
+///         format!("expected {expression} to be even\n   but was: {actual}\n  expected: an even number")
+///     }
+/// }
+///
+/// assert_that!(41).expecting(negate(IsEven));
+/// ```
+pub fn negate<E>(expectation: E) -> Negate<E> {
+    Negate(expectation)
+}
+
+/// A combinator expectation that negates the wrapped expectation.
+///
+/// In contrast to the [`Not`] combinator, this combinator can be used with
+/// any expectation, even if it does not implement the [`Invertible`] trait.
+/// The failure message is the message of the wrapped expectation formed with
+/// the `inverted` flag set. If the wrapped expectation does not take the flag
+/// into account, the message states that the positive message was expected to
+/// NOT be met.
+///
+/// Use the function [`negate()`] to construct a `Negate` combinator
+/// containing the given expectation.
+///
+/// [`Invertible`]: crate::spec::Invertible
+#[must_use]
+pub struct Negate<E>(pub E);
+
 /// Creates an [`All`] expectation combinator from a tuple of expectations.
 ///
 /// # Examples
@@ -224,6 +275,96 @@ impl<F> Predicate<F> {
     }
 }
 
+/// Creates a [`ResultPredicate`] expectation from a predicate function that
+/// returns a `Result`.
+///
+/// The predicate returns `Ok(())` if the subject meets the criteria and an
+/// error explaining why the subject does not meet the criteria otherwise. The
+/// explanation becomes part of the failure message.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::expectations::satisfies_returning_result;
+/// use asserting::prelude::*;
+///
+/// fn is_odd(number: &i32) -> Result<(), String> {
+///     if *number & 1 == 1 {
+///         Ok(())
+///     } else {
+///         Err(format!("{number} is divisible by 2"))
+///     }
+/// }
+///
+/// assert_that!(5).expecting(satisfies_returning_result(is_odd));
+/// ```
+pub fn satisfies_returning_result<F>(predicate: F) -> ResultPredicate<F> {
+    ResultPredicate {
+        predicate,
+        reason: None,
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct ResultPredicate<F> {
+    pub predicate: F,
+    pub reason: Option<String>,
+}
+
+/// Creates a [`FromFn`] expectation from a test function and a message
+/// function.
+///
+/// This is an adapter to turn any predicate, e.g., one provided by another
+/// crate, into an [`Expectation`]. The test function is called with the
+/// subject and returns whether the expectation is met. In case the test
+/// function returns false, the message function is called with the
+/// expression and the subject to form the failure message.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::expectations::from_fn;
+/// use asserting::prelude::*;
+///
+/// fn is_palindrome(text: &&str) -> bool {
+///     text.chars().eq(text.chars().rev())
+/// }
+///
+/// assert_that!("level").expecting(from_fn(is_palindrome, |expression, actual| {
+///     format!("expected {expression} to be a palindrome\n   but was: {actual:?}")
+/// }));
+///
+/// let failures = verify_that!("levels")
+///     .expecting(from_fn(is_palindrome, |expression, actual| {
+///         format!("expected {expression} to be a palindrome\n   but was: {actual:?}")
+///     }))
+///     .display_failures();
+///
+/// assert_that!(failures).contains_exactly([
+///     "expected \"levels\" to be a palindrome\n   but was: \"levels\"\n"
+/// ]);
+/// ```
+///
+/// [`Expectation`]: crate::spec::Expectation
+pub fn from_fn<S, T, M>(test: T, message: M) -> FromFn<T, M>
+where
+    S: ?Sized,
+    T: FnMut(&S) -> bool,
+    M: Fn(&Expression<'_>, &S) -> String,
+{
+    FromFn { test, message }
+}
+
+/// An expectation that is defined by a test function and a message function.
+///
+/// Use the function [`from_fn()`] to construct a `FromFn` expectation.
+#[must_use]
+pub struct FromFn<T, M> {
+    pub test: T,
+    pub message: M,
+}
+
 /// Creates an [`IsTrue`] expectation.
 pub fn is_true() -> IsTrue {
     IsTrue
@@ -240,6 +381,30 @@ pub fn is_false() -> IsFalse {
 #[must_use]
 pub struct IsFalse;
 
+/// Creates an [`IsTrueBecause`] expectation.
+pub fn is_true_because(reason: impl Into<String>) -> IsTrueBecause {
+    IsTrueBecause {
+        reason: reason.into(),
+    }
+}
+
+#[must_use]
+pub struct IsTrueBecause {
+    pub reason: String,
+}
+
+/// Creates an [`IsFalseBecause`] expectation.
+pub fn is_false_because(reason: impl Into<String>) -> IsFalseBecause {
+    IsFalseBecause {
+        reason: reason.into(),
+    }
+}
+
+#[must_use]
+pub struct IsFalseBecause {
+    pub reason: String,
+}
+
 /// Creates an [`IsEqualTo`] expectation.
 pub fn is_equal_to<E>(expected: E) -> IsEqualTo<E> {
     IsEqualTo { expected }
@@ -259,6 +424,60 @@ pub struct IsSameAs<E> {
     pub expected: E,
 }
 
+/// Creates an [`IsSameInstanceAs`] expectation.
+pub fn is_same_instance_as<E>(other: E) -> IsSameInstanceAs<E> {
+    IsSameInstanceAs { other }
+}
+
+#[must_use]
+pub struct IsSameInstanceAs<E> {
+    pub other: E,
+}
+
+/// Creates a [`PairAreEqual`] expectation.
+pub fn pair_are_equal() -> PairAreEqual {
+    PairAreEqual
+}
+
+#[must_use]
+pub struct PairAreEqual;
+
+/// Creates a [`PairIsOrderedAscending`] expectation.
+pub fn pair_is_ordered_ascending() -> PairIsOrderedAscending {
+    PairIsOrderedAscending
+}
+
+#[must_use]
+pub struct PairIsOrderedAscending;
+
+/// Creates a [`PairDiffersByAtMost`] expectation.
+pub fn pair_differs_by_at_most<T>(max_difference: T) -> PairDiffersByAtMost<T> {
+    PairDiffersByAtMost { max_difference }
+}
+
+#[must_use]
+pub struct PairDiffersByAtMost<T> {
+    pub max_difference: T,
+}
+
+/// Creates an [`IsDefault`] expectation.
+pub fn is_default() -> IsDefault {
+    IsDefault
+}
+
+#[must_use]
+pub struct IsDefault;
+
+/// Creates a [`SetIsEqualTo`] expectation.
+pub fn set_is_equal_to<E>(expected: E) -> SetIsEqualTo<E> {
+    SetIsEqualTo { expected }
+}
+
+#[must_use]
+pub struct SetIsEqualTo<E> {
+    pub expected: E,
+}
+
 /// Creates an [`IsCloseTo`] expectation.
 ///
 /// The margin is set to a default value. To define a custom margin, use the
@@ -318,6 +537,149 @@ impl<E, M> IsCloseTo<E, M> {
     }
 }
 
+/// Creates a [`ContainsExactlyCloseTo`] expectation.
+///
+/// The margin is set to a default value. To define a custom margin, use the
+/// method [`ContainsExactlyCloseTo::within_margin`] on the newly constructed
+/// expectation.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "float-cmp"))]
+/// # fn main() {}
+/// # #[cfg(feature = "float-cmp")]
+/// # fn main() {
+/// use asserting::expectations::contains_exactly_close_to;
+/// use asserting::prelude::*;
+///
+/// let values: [f64; 2] = [0.1 + 0.2, 0.7 * 3.0];
+///
+/// // using the default margin
+/// assert_that!(values).expecting(contains_exactly_close_to([0.3, 2.1]));
+///
+/// // with custom margin
+/// assert_that!(values)
+///     .expecting(contains_exactly_close_to([0.301, 2.099]).within_margin((0.001, 4)));
+/// # }
+/// ```
+#[cfg(feature = "float-cmp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float-cmp")))]
+pub fn contains_exactly_close_to<E, M>(
+    expected: impl IntoIterator<Item = E>,
+) -> ContainsExactlyCloseTo<E, M>
+where
+    M: Default,
+{
+    ContainsExactlyCloseTo {
+        expected: Vec::from_iter(expected),
+        margin: M::default(),
+        outside_tolerance: HashSet::new(),
+    }
+}
+
+#[cfg(feature = "float-cmp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float-cmp")))]
+#[must_use]
+#[non_exhaustive]
+pub struct ContainsExactlyCloseTo<E, M> {
+    pub expected: Vec<E>,
+    pub margin: M,
+    pub outside_tolerance: HashSet<usize>,
+}
+
+#[cfg(feature = "float-cmp")]
+impl<E, M> ContainsExactlyCloseTo<E, M> {
+    pub fn within_margin(mut self, margin: impl Into<M>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+}
+
+/// Creates an [`IsCloseToEach`] expectation.
+///
+/// The margin is set to a default value. To define a custom margin, use the
+/// method [`IsCloseToEach::within_margin`] on the newly constructed
+/// expectation.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "float-cmp"))]
+/// # fn main() {}
+/// # #[cfg(feature = "float-cmp")]
+/// # fn main() {
+/// use asserting::expectations::is_close_to_each;
+/// use asserting::prelude::*;
+///
+/// let values: Vec<f32> = vec![0.2995, 0.3, 0.3005];
+///
+/// // using the default margin
+/// assert_that!(&values[1..2]).expecting(is_close_to_each(0.3));
+///
+/// // with custom margin
+/// assert_that!(values).expecting(is_close_to_each(0.3).within_margin((0.001, 4)));
+/// # }
+/// ```
+#[cfg(feature = "float-cmp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float-cmp")))]
+pub fn is_close_to_each<E, M>(expected: E) -> IsCloseToEach<E, M>
+where
+    M: Default,
+{
+    IsCloseToEach {
+        expected,
+        margin: M::default(),
+        outside_tolerance: HashSet::new(),
+    }
+}
+
+#[cfg(feature = "float-cmp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float-cmp")))]
+#[must_use]
+#[non_exhaustive]
+pub struct IsCloseToEach<E, M> {
+    pub expected: E,
+    pub margin: M,
+    pub outside_tolerance: HashSet<usize>,
+}
+
+#[cfg(feature = "float-cmp")]
+impl<E, M> IsCloseToEach<E, M> {
+    pub fn within_margin(mut self, margin: impl Into<M>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+}
+
+/// Creates a [`MatchesPattern`] expectation.
+///
+/// This function is usually not called directly. Use the [`pattern!`] macro
+/// instead, which generates the predicate and the source of the pattern from
+/// a pattern like in a `match` expression.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::expectations::matches_pattern;
+/// use asserting::prelude::*;
+///
+/// assert_that!(Some(42)).expecting(matches_pattern("Some(40..)", |value: &Option<i32>| {
+///     matches!(value, Some(40..))
+/// }));
+/// ```
+///
+/// [`pattern!`]: crate::pattern
+pub fn matches_pattern<F>(pattern: &'static str, predicate: F) -> MatchesPattern<F> {
+    MatchesPattern { pattern, predicate }
+}
+
+#[must_use]
+pub struct MatchesPattern<F> {
+    pub pattern: &'static str,
+    pub predicate: F,
+}
+
 /// Creates an [`IsLessThan`] expectation.
 pub fn is_less_than<E>(expected: E) -> IsLessThan<E> {
     IsLessThan { expected }
@@ -413,6 +775,121 @@ impl<R, E> IsInRange<R, E> {
     }
 }
 
+/// Creates a [`RangeContainsValue`] expectation.
+pub fn range_contains_value<E>(expected: E) -> RangeContainsValue<E> {
+    RangeContainsValue { expected }
+}
+
+#[must_use]
+pub struct RangeContainsValue<E> {
+    pub expected: E,
+}
+
+/// Creates a [`RangeOverlapsWith`] expectation.
+pub fn range_overlaps_with<R, E>(other: R) -> RangeOverlapsWith<R, E> {
+    RangeOverlapsWith {
+        other,
+        _element_type: PhantomData,
+    }
+}
+
+#[must_use]
+pub struct RangeOverlapsWith<R, E> {
+    pub other: R,
+    _element_type: PhantomData<E>,
+}
+
+/// Creates an [`IsSubRangeOf`] expectation.
+pub fn is_sub_range_of<R, E>(other: R) -> IsSubRangeOf<R, E> {
+    IsSubRangeOf {
+        other,
+        _element_type: PhantomData,
+    }
+}
+
+#[must_use]
+pub struct IsSubRangeOf<R, E> {
+    pub other: R,
+    _element_type: PhantomData<E>,
+}
+
+/// Creates an [`IsInRangeExclusive`] expectation.
+pub fn is_in_range_exclusive<E>(start: E, end: E) -> IsInRangeExclusive<E> {
+    IsInRangeExclusive { start, end }
+}
+
+#[must_use]
+pub struct IsInRangeExclusive<E> {
+    pub start: E,
+    pub end: E,
+}
+
+/// Creates an [`IsWithin`] expectation.
+pub fn is_within<E>(offset: E, center: E) -> IsWithin<E> {
+    IsWithin { offset, center }
+}
+
+#[must_use]
+pub struct IsWithin<E> {
+    pub offset: E,
+    pub center: E,
+}
+
+/// Creates an [`IsWithinPercent`] expectation.
+pub fn is_within_percent<E>(percent: f64, expected: E) -> IsWithinPercent<E> {
+    IsWithinPercent { percent, expected }
+}
+
+#[must_use]
+pub struct IsWithinPercent<E> {
+    pub percent: f64,
+    pub expected: E,
+}
+
+/// Creates a [`HasBitSet`] expectation.
+pub fn has_bit_set(index: u32) -> HasBitSet {
+    HasBitSet { index }
+}
+
+#[must_use]
+pub struct HasBitSet {
+    pub index: u32,
+}
+
+/// Creates a [`HasBitsSet`] expectation.
+pub fn has_bits_set<E>(mask: E) -> HasBitsSet<E> {
+    HasBitsSet { mask }
+}
+
+#[must_use]
+pub struct HasBitsSet<E> {
+    pub mask: E,
+}
+
+/// Creates a [`HasNoBitsSet`] expectation.
+pub fn has_no_bits_set<E>(mask: E) -> HasNoBitsSet<E> {
+    HasNoBitsSet { mask }
+}
+
+#[must_use]
+pub struct HasNoBitsSet<E> {
+    pub mask: E,
+}
+
+/// Creates a [`ContainsFlags`] expectation.
+#[cfg(feature = "bitflags")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bitflags")))]
+pub fn contains_flags<E>(flags: E) -> ContainsFlags<E> {
+    ContainsFlags { flags }
+}
+
+#[cfg(feature = "bitflags")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bitflags")))]
+#[must_use]
+pub struct ContainsFlags<E> {
+    pub flags: E,
+}
+
 /// Creates an [`IsNegative`] expectation.
 pub fn is_negative() -> IsNegative {
     IsNegative
@@ -497,13 +974,49 @@ pub fn is_integer() -> IsInteger {
 #[must_use]
 pub struct IsInteger;
 
-/// Creates an [`IsLowerCase`] expectation.
-pub fn is_lower_case() -> IsLowerCase {
-    IsLowerCase
+/// Creates an [`Overflows`] expectation.
+///
+/// The given checked arithmetic operation is applied to the operands held by
+/// the subject.
+pub fn overflows<F>(operation: F) -> Overflows<F> {
+    Overflows {
+        operation: Some(operation),
+        result: None,
+    }
 }
 
 #[must_use]
-pub struct IsLowerCase;
+#[non_exhaustive]
+pub struct Overflows<F> {
+    pub operation: Option<F>,
+    pub result: Option<String>,
+}
+
+/// Creates a [`DoesNotOverflow`] expectation.
+///
+/// The given checked arithmetic operation is applied to the operands held by
+/// the subject.
+pub fn does_not_overflow<F>(operation: F) -> DoesNotOverflow<F> {
+    DoesNotOverflow {
+        operation: Some(operation),
+        result: None,
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct DoesNotOverflow<F> {
+    pub operation: Option<F>,
+    pub result: Option<String>,
+}
+
+/// Creates an [`IsLowerCase`] expectation.
+pub fn is_lower_case() -> IsLowerCase {
+    IsLowerCase
+}
+
+#[must_use]
+pub struct IsLowerCase;
 
 /// Creates an [`IsUpperCase`] expectation.
 pub fn is_upper_case() -> IsUpperCase {
@@ -571,6 +1084,14 @@ pub fn is_some() -> IsSome {
 #[must_use]
 pub struct IsSome;
 
+/// Creates an [`IsNestedSome`] expectation.
+pub fn is_nested_some() -> IsNestedSome {
+    IsNestedSome
+}
+
+#[must_use]
+pub struct IsNestedSome;
+
 /// Creates an [`IsNone`] expectation.
 pub fn is_none() -> IsNone {
     IsNone
@@ -589,6 +1110,50 @@ pub struct HasValue<E> {
     pub expected: E,
 }
 
+/// Creates a [`HasValueEqualToDefault`] expectation.
+pub fn has_value_equal_to_default() -> HasValueEqualToDefault {
+    HasValueEqualToDefault
+}
+
+#[must_use]
+pub struct HasValueEqualToDefault;
+
+/// Creates an [`IsNoneOr`] expectation.
+pub fn is_none_or<P>(predicate: P) -> IsNoneOr<P> {
+    IsNoneOr { predicate }
+}
+
+#[must_use]
+pub struct IsNoneOr<P> {
+    pub predicate: P,
+}
+
+/// Creates an [`IsReady`] expectation.
+pub fn is_ready() -> IsReady {
+    IsReady
+}
+
+#[must_use]
+pub struct IsReady;
+
+/// Creates an [`IsPending`] expectation.
+pub fn is_pending() -> IsPending {
+    IsPending
+}
+
+#[must_use]
+pub struct IsPending;
+
+/// Creates an [`IsReadyWith`] expectation.
+pub fn is_ready_with<E>(expected: E) -> IsReadyWith<E> {
+    IsReadyWith { expected }
+}
+
+#[must_use]
+pub struct IsReadyWith<E> {
+    pub expected: E,
+}
+
 /// Creates an [`IsOk`] expectation.
 pub fn is_ok() -> IsOk {
     IsOk
@@ -597,6 +1162,14 @@ pub fn is_ok() -> IsOk {
 #[must_use]
 pub struct IsOk;
 
+/// Creates an [`IsOkSome`] expectation.
+pub fn is_ok_some() -> IsOkSome {
+    IsOkSome
+}
+
+#[must_use]
+pub struct IsOkSome;
+
 /// Creates an [`IsErr`] expectation.
 pub fn is_err() -> IsErr {
     IsErr
@@ -605,6 +1178,40 @@ pub fn is_err() -> IsErr {
 #[must_use]
 pub struct IsErr;
 
+/// Creates an [`AllOk`] expectation.
+pub fn all_ok() -> AllOk {
+    AllOk {
+        failing: HashSet::new(),
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct AllOk {
+    pub failing: HashSet<usize>,
+}
+
+/// Creates an [`AllErr`] expectation.
+pub fn all_err() -> AllErr {
+    AllErr {
+        failing: HashSet::new(),
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct AllErr {
+    pub failing: HashSet<usize>,
+}
+
+/// Creates an [`AnyErr`] expectation.
+pub fn any_err() -> AnyErr {
+    AnyErr
+}
+
+#[must_use]
+pub struct AnyErr;
+
 /// Creates a [`HasError`] expectation.
 pub fn has_error<E>(expected: E) -> HasError<E> {
     HasError { expected }
@@ -615,6 +1222,55 @@ pub struct HasError<E> {
     pub expected: E,
 }
 
+/// Creates a [`HasErrorMatching`] expectation.
+pub fn has_error_matching<P>(predicate: P) -> HasErrorMatching<P> {
+    HasErrorMatching { predicate }
+}
+
+#[must_use]
+pub struct HasErrorMatching<P> {
+    pub predicate: P,
+}
+
+/// Creates a [`HasErrorSatisfying`] expectation.
+pub fn has_error_satisfying<P>(predicate: P) -> HasErrorSatisfying<P> {
+    HasErrorSatisfying {
+        predicate,
+        reason: None,
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct HasErrorSatisfying<P> {
+    pub predicate: P,
+    pub reason: Option<String>,
+}
+
+/// Creates a [`HasErrorOfType`] expectation.
+pub fn has_error_of_type<X>() -> HasErrorOfType<X> {
+    HasErrorOfType {
+        _error_type: PhantomData,
+    }
+}
+
+#[must_use]
+pub struct HasErrorOfType<X> {
+    _error_type: PhantomData<X>,
+}
+
+/// Creates an [`IsInstanceOf`] expectation.
+pub fn is_instance_of<T>() -> IsInstanceOf<T> {
+    IsInstanceOf {
+        _instance_type: PhantomData,
+    }
+}
+
+#[must_use]
+pub struct IsInstanceOf<T> {
+    _instance_type: PhantomData<T>,
+}
+
 /// Creates an [`ErrorHasSource`] expectation.
 pub fn error_has_source() -> ErrorHasSource {
     ErrorHasSource
@@ -637,6 +1293,104 @@ pub struct ErrorHasSourceMessage {
     pub expected_source_message: String,
 }
 
+/// Creates an [`ErrorHasMessage`] expectation.
+pub fn error_has_message(expected_message: impl Into<String>) -> ErrorHasMessage {
+    ErrorHasMessage {
+        expected_message: expected_message.into(),
+    }
+}
+
+#[must_use]
+pub struct ErrorHasMessage {
+    pub expected_message: String,
+}
+
+/// Creates an [`ErrorHasMessageContaining`] expectation.
+pub fn error_has_message_containing(
+    expected_substring: impl Into<String>,
+) -> ErrorHasMessageContaining {
+    ErrorHasMessageContaining {
+        expected_substring: expected_substring.into(),
+    }
+}
+
+#[must_use]
+pub struct ErrorHasMessageContaining {
+    pub expected_substring: String,
+}
+
+/// Creates an [`ErrorHasMessageMatching`] expectation.
+///
+/// If the regex pattern is invalid or exceeds the size limit, the expectation
+/// can not be evaluated and reports the error in its failure message.
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+pub fn error_has_message_matching(regex_pattern: &str) -> ErrorHasMessageMatching<'_> {
+    let (regex, error) = compile_regex(regex_pattern);
+    ErrorHasMessageMatching {
+        pattern: regex_pattern,
+        regex,
+        error,
+    }
+}
+
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+#[must_use]
+pub struct ErrorHasMessageMatching<'a> {
+    pub pattern: &'a str,
+    pub regex: Regex,
+    pub(crate) error: Option<regex::Error>,
+}
+
+/// Creates a [`HasRootCauseMessage`] expectation.
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+pub fn has_root_cause_message(expected_message: impl Into<String>) -> HasRootCauseMessage {
+    HasRootCauseMessage {
+        expected_message: expected_message.into(),
+    }
+}
+
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+#[must_use]
+pub struct HasRootCauseMessage {
+    pub expected_message: String,
+}
+
+/// Creates an [`IsDowncastableTo`] expectation.
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+pub fn is_downcastable_to<E>() -> IsDowncastableTo<E> {
+    IsDowncastableTo {
+        _error_type: PhantomData,
+    }
+}
+
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+#[must_use]
+pub struct IsDowncastableTo<E> {
+    _error_type: PhantomData<E>,
+}
+
+/// Creates a [`HasContextContaining`] expectation.
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+pub fn has_context_containing(expected_substring: impl Into<String>) -> HasContextContaining {
+    HasContextContaining {
+        expected_substring: expected_substring.into(),
+    }
+}
+
+#[cfg(feature = "anyhow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anyhow")))]
+#[must_use]
+pub struct HasContextContaining {
+    pub expected_substring: String,
+}
+
 /// Creates a [`HasDebugString`] expectation.
 pub fn has_debug_string<E>(expected: E) -> HasDebugString<E> {
     HasDebugString { expected }
@@ -655,6 +1409,402 @@ pub struct HasDisplayString<E> {
     pub expected: E,
 }
 
+/// Creates a [`HasUtf8Content`] expectation.
+pub fn has_utf8_content<E>(expected: E) -> HasUtf8Content<E> {
+    HasUtf8Content { expected }
+}
+
+#[must_use]
+pub struct HasUtf8Content<E> {
+    pub expected: E,
+}
+
+/// Creates an [`IsEqualToBytes`] expectation.
+pub fn is_equal_to_bytes<E>(expected: E) -> IsEqualToBytes<E> {
+    IsEqualToBytes { expected }
+}
+
+#[must_use]
+pub struct IsEqualToBytes<E> {
+    pub expected: E,
+}
+
+/// Creates a [`StartsWithBytes`] expectation.
+pub fn starts_with_bytes<E>(expected: E) -> StartsWithBytes<E> {
+    StartsWithBytes { expected }
+}
+
+#[must_use]
+pub struct StartsWithBytes<E> {
+    pub expected: E,
+}
+
+/// Creates a [`ContainsSubsequence`] expectation.
+pub fn contains_subsequence<E>(expected: E) -> ContainsSubsequence<E> {
+    ContainsSubsequence { expected }
+}
+
+#[must_use]
+pub struct ContainsSubsequence<E> {
+    pub expected: E,
+}
+
+/// Creates an [`IsBeforeDate`] expectation.
+pub fn is_before_date<E>(expected: E) -> IsBeforeDate<E> {
+    IsBeforeDate { expected }
+}
+
+#[must_use]
+pub struct IsBeforeDate<E> {
+    pub expected: E,
+}
+
+/// Creates an [`IsAfterDate`] expectation.
+pub fn is_after_date<E>(expected: E) -> IsAfterDate<E> {
+    IsAfterDate { expected }
+}
+
+#[must_use]
+pub struct IsAfterDate<E> {
+    pub expected: E,
+}
+
+/// Creates an [`IsSameDayAs`] expectation.
+pub fn is_same_day_as<E>(expected: E) -> IsSameDayAs<E> {
+    IsSameDayAs { expected }
+}
+
+#[must_use]
+pub struct IsSameDayAs<E> {
+    pub expected: E,
+}
+
+/// Creates an [`IsWithinDurationOf`] expectation.
+pub fn is_within_duration_of<E>(expected: E, tolerance: Duration) -> IsWithinDurationOf<E> {
+    IsWithinDurationOf {
+        expected,
+        tolerance,
+    }
+}
+
+#[must_use]
+pub struct IsWithinDurationOf<E> {
+    pub expected: E,
+    pub tolerance: Duration,
+}
+
+/// Creates an [`IsInYear`] expectation.
+pub fn is_in_year(expected_year: i32) -> IsInYear {
+    IsInYear { expected_year }
+}
+
+#[must_use]
+pub struct IsInYear {
+    pub expected_year: i32,
+}
+
+/// Creates a [`HasExitCode`] expectation.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn has_exit_code(expected: i32) -> HasExitCode {
+    HasExitCode { expected }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use]
+pub struct HasExitCode {
+    pub expected: i32,
+}
+
+/// Creates a [`ProcessSucceeded`] expectation.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn process_succeeded() -> ProcessSucceeded {
+    ProcessSucceeded
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use]
+pub struct ProcessSucceeded;
+
+/// Creates a [`ProcessFailed`] expectation.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn process_failed() -> ProcessFailed {
+    ProcessFailed
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use]
+pub struct ProcessFailed;
+
+/// Creates a [`Stdout`] expectation combinator, which verifies the given
+/// expectation on the stdout of a process output decoded as UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::expectations::{stdout, string_contains};
+/// use asserting::prelude::*;
+/// use std::process::{ExitStatus, Output};
+///
+/// let output = Output {
+///     status: ExitStatus::default(),
+///     stdout: b"build finished".to_vec(),
+///     stderr: Vec::new(),
+/// };
+///
+/// assert_that!(output).expecting(stdout(string_contains("finished")));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn stdout<E>(expectation: E) -> Stdout<E> {
+    Stdout(expectation)
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use]
+pub struct Stdout<E>(pub E);
+
+/// Creates a [`Stderr`] expectation combinator, which verifies the given
+/// expectation on the stderr of a process output decoded as UTF-8.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn stderr<E>(expectation: E) -> Stderr<E> {
+    Stderr(expectation)
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use]
+pub struct Stderr<E>(pub E);
+
+/// Creates an [`EnvVarIsSet`] expectation.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn env_var_is_set() -> EnvVarIsSet {
+    EnvVarIsSet
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use]
+pub struct EnvVarIsSet;
+
+/// Creates an [`EnvVarHasValueContaining`] expectation.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn env_var_has_value_containing(
+    expected_substring: impl Into<String>,
+) -> EnvVarHasValueContaining {
+    EnvVarHasValueContaining {
+        expected_substring: expected_substring.into(),
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use]
+pub struct EnvVarHasValueContaining {
+    pub expected_substring: String,
+}
+
+/// Creates an [`IsEqualToDecimal`] expectation.
+#[cfg(feature = "rust-decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust-decimal")))]
+pub fn is_equal_to_decimal<E>(expected: E) -> IsEqualToDecimal<E> {
+    IsEqualToDecimal { expected }
+}
+
+#[cfg(feature = "rust-decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust-decimal")))]
+#[must_use]
+pub struct IsEqualToDecimal<E> {
+    pub expected: E,
+}
+
+/// Creates an [`IsCloseToDecimal`] expectation.
+#[cfg(feature = "rust-decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust-decimal")))]
+pub fn is_close_to_decimal<E, M>(expected: E, margin: M) -> IsCloseToDecimal<E, M> {
+    IsCloseToDecimal { expected, margin }
+}
+
+#[cfg(feature = "rust-decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust-decimal")))]
+#[must_use]
+pub struct IsCloseToDecimal<E, M> {
+    pub expected: E,
+    pub margin: M,
+}
+
+/// Creates an [`IsNil`] expectation.
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+pub fn is_nil() -> IsNil {
+    IsNil
+}
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+#[must_use]
+pub struct IsNil;
+
+/// Creates a [`HasVersion`] expectation.
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+pub fn has_version(expected: uuid::Version) -> HasVersion {
+    HasVersion { expected }
+}
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+#[must_use]
+pub struct HasVersion {
+    pub expected: uuid::Version,
+}
+
+/// Creates an [`IsEqualToUuidString`] expectation.
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+pub fn is_equal_to_uuid_string<E>(expected: E) -> IsEqualToUuidString<E> {
+    IsEqualToUuidString { expected }
+}
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+#[must_use]
+pub struct IsEqualToUuidString<E> {
+    pub expected: E,
+}
+
+/// Creates a [`HasScheme`] expectation.
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub fn has_scheme<E>(expected: E) -> HasScheme<E> {
+    HasScheme { expected }
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+#[must_use]
+pub struct HasScheme<E> {
+    pub expected: E,
+}
+
+/// Creates a [`HasHost`] expectation.
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub fn has_host<E>(expected: E) -> HasHost<E> {
+    HasHost { expected }
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+#[must_use]
+pub struct HasHost<E> {
+    pub expected: E,
+}
+
+/// Creates a [`HasPath`] expectation.
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub fn has_path<E>(expected: E) -> HasPath<E> {
+    HasPath { expected }
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+#[must_use]
+pub struct HasPath<E> {
+    pub expected: E,
+}
+
+/// Creates a [`HasQueryParameter`] expectation.
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub fn has_query_parameter<N, V>(name: N, value: V) -> HasQueryParameter<N, V> {
+    HasQueryParameter { name, value }
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+#[must_use]
+pub struct HasQueryParameter<N, V> {
+    pub name: N,
+    pub value: V,
+}
+
+/// Creates an [`IsEqualToUrlIgnoringQueryOrder`] expectation.
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub fn is_equal_to_url_ignoring_query_order<E>(expected: E) -> IsEqualToUrlIgnoringQueryOrder<E> {
+    IsEqualToUrlIgnoringQueryOrder { expected }
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+#[must_use]
+pub struct IsEqualToUrlIgnoringQueryOrder<E> {
+    pub expected: E,
+}
+
+/// Creates an [`IsIpv4`] expectation.
+pub fn is_ipv4() -> IsIpv4 {
+    IsIpv4
+}
+
+#[must_use]
+pub struct IsIpv4;
+
+/// Creates an [`IsIpv6`] expectation.
+pub fn is_ipv6() -> IsIpv6 {
+    IsIpv6
+}
+
+#[must_use]
+pub struct IsIpv6;
+
+/// Creates an [`IsLoopback`] expectation.
+pub fn is_loopback() -> IsLoopback {
+    IsLoopback
+}
+
+#[must_use]
+pub struct IsLoopback;
+
+/// Creates an [`IsPrivate`] expectation.
+pub fn is_private() -> IsPrivate {
+    IsPrivate
+}
+
+#[must_use]
+pub struct IsPrivate;
+
+/// Creates a [`HasIp`] expectation.
+pub fn has_ip<E>(expected: E) -> HasIp<E> {
+    HasIp { expected }
+}
+
+#[must_use]
+pub struct HasIp<E> {
+    pub expected: E,
+}
+
+/// Creates a [`HasPort`] expectation.
+pub fn has_port(expected: u16) -> HasPort {
+    HasPort { expected }
+}
+
+#[must_use]
+pub struct HasPort {
+    pub expected: u16,
+}
+
 /// Creates an [`IsEmpty`] expectation.
 pub fn is_empty() -> IsEmpty {
     IsEmpty
@@ -817,8 +1967,40 @@ pub fn has_at_least_char_count<E>(expected_char_count: E) -> HasAtLeastCharCount
 }
 
 #[must_use]
-pub struct HasAtLeastCharCount<E> {
-    pub expected_char_count: E,
+pub struct HasAtLeastCharCount<E> {
+    pub expected_char_count: E,
+}
+
+/// Creates a [`HasCapacity`] expectation.
+pub fn has_capacity(expected_capacity: usize) -> HasCapacity {
+    HasCapacity { expected_capacity }
+}
+
+#[must_use]
+pub struct HasCapacity {
+    pub expected_capacity: usize,
+}
+
+/// Creates a [`HasCapacityAtLeast`] expectation.
+pub fn has_capacity_at_least(expected_capacity: usize) -> HasCapacityAtLeast {
+    HasCapacityAtLeast { expected_capacity }
+}
+
+#[must_use]
+pub struct HasCapacityAtLeast {
+    pub expected_capacity: usize,
+}
+
+/// Creates a [`HasSpareCapacity`] expectation.
+pub fn has_spare_capacity_of(expected_spare_capacity: usize) -> HasSpareCapacity {
+    HasSpareCapacity {
+        expected_spare_capacity,
+    }
+}
+
+#[must_use]
+pub struct HasSpareCapacity {
+    pub expected_spare_capacity: usize,
 }
 
 /// Creates a [`StringContains`] expectation.
@@ -863,17 +2045,16 @@ pub struct StringEndsWith<E> {
 
 /// Creates a [`StringMatches`] expectation.
 ///
-/// # Panics
-///
-/// Panics, if the regex pattern is invalid or exceeds the size limit.
+/// If the regex pattern is invalid or exceeds the size limit, the expectation
+/// can not be evaluated and reports the error in its failure message.
 #[cfg(feature = "regex")]
 #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
 pub fn string_matches(regex_pattern: &str) -> StringMatches<'_> {
-    let regex = Regex::new(regex_pattern)
-        .unwrap_or_else(|err| panic!("failed to match string with regex: {err}"));
+    let (regex, error) = compile_regex(regex_pattern);
     StringMatches {
         pattern: regex_pattern,
         regex,
+        error,
     }
 }
 
@@ -883,27 +2064,114 @@ pub fn string_matches(regex_pattern: &str) -> StringMatches<'_> {
 pub struct StringMatches<'a> {
     pub pattern: &'a str,
     pub regex: Regex,
+    pub(crate) error: Option<regex::Error>,
+}
+
+/// Compiles the given regex pattern.
+///
+/// If the pattern is invalid, a regex that never matches is returned together
+/// with the error, which is reported when the expectation is evaluated.
+#[cfg(feature = "regex")]
+fn compile_regex(regex_pattern: &str) -> (Regex, Option<regex::Error>) {
+    match Regex::new(regex_pattern) {
+        Ok(regex) => (regex, None),
+        Err(error) => {
+            let never_matching = Regex::new(r"[^\s\S]")
+                .unwrap_or_else(|_| unreachable!("the never matching regex should be valid"));
+            (never_matching, Some(error))
+        },
+    }
 }
 
 #[cfg(feature = "regex")]
 #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
 impl<'a> StringMatches<'a> {
     /// Creates a new `StringMatches`-expectation.
-    ///
-    /// # Panics
-    ///
-    /// Panics, if the regex pattern is invalid or exceeds the size limit.
     #[deprecated = "use the function [`string_matches`] instead"]
     pub fn new(regex_pattern: &'a str) -> Self {
-        let regex = Regex::new(regex_pattern)
-            .unwrap_or_else(|err| panic!("failed to match string with regex: {err}"));
-        Self {
-            pattern: regex_pattern,
-            regex,
-        }
+        string_matches(regex_pattern)
+    }
+}
+
+/// Creates a [`StringMatchesWildcard`] expectation.
+pub fn string_matches_wildcard(pattern: &str) -> StringMatchesWildcard<'_> {
+    StringMatchesWildcard { pattern }
+}
+
+#[must_use]
+pub struct StringMatchesWildcard<'a> {
+    pub pattern: &'a str,
+}
+
+/// Creates an [`IsSimilarTo`] expectation.
+pub fn is_similar_to<E>(expected: E, max_distance: usize) -> IsSimilarTo<E> {
+    IsSimilarTo {
+        expected,
+        max_distance,
+    }
+}
+
+#[must_use]
+pub struct IsSimilarTo<E> {
+    pub expected: E,
+    pub max_distance: usize,
+}
+
+/// Creates a [`StringContainsNTimes`] expectation.
+pub fn string_contains_n_times<E>(needle: E, times: usize) -> StringContainsNTimes<E> {
+    StringContainsNTimes { needle, times }
+}
+
+#[must_use]
+pub struct StringContainsNTimes<E> {
+    pub needle: E,
+    pub times: usize,
+}
+
+/// Creates a [`StringContainsAtMostNTimes`] expectation.
+pub fn string_contains_at_most_n_times<E>(
+    needle: E,
+    max_times: usize,
+) -> StringContainsAtMostNTimes<E> {
+    StringContainsAtMostNTimes { needle, max_times }
+}
+
+#[must_use]
+pub struct StringContainsAtMostNTimes<E> {
+    pub needle: E,
+    pub max_times: usize,
+}
+
+/// Creates a [`HasGraphemeCount`] expectation.
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+pub fn has_grapheme_count(expected_grapheme_count: usize) -> HasGraphemeCount {
+    HasGraphemeCount {
+        expected_grapheme_count,
     }
 }
 
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+#[must_use]
+pub struct HasGraphemeCount {
+    pub expected_grapheme_count: usize,
+}
+
+/// Creates a [`StringContainsGrapheme`] expectation.
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+pub fn string_contains_grapheme<E>(expected: E) -> StringContainsGrapheme<E> {
+    StringContainsGrapheme { expected }
+}
+
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+#[must_use]
+pub struct StringContainsGrapheme<E> {
+    pub expected: E,
+}
+
 /// Creates an [`IteratorContains`] expectation.
 pub fn iterator_contains<E>(expected: E) -> IteratorContains<E> {
     IteratorContains { expected }
@@ -926,6 +2194,7 @@ pub fn iterator_contains_exactly_in_any_order<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorContainsExactlyInAnyOrder<E> {
     pub expected: Vec<E>,
     pub missing: HashSet<usize>,
@@ -943,6 +2212,32 @@ impl<E> IteratorContainsExactlyInAnyOrder<E> {
     }
 }
 
+/// Creates an [`IteratorContainsExactlyShuffled`] expectation.
+///
+/// The `seed` is the seed of the random number generator that shuffles the
+/// subject. It is printed in the failure message, so that a failed randomized
+/// test can be reproduced.
+pub fn iterator_contains_exactly_shuffled<E>(
+    expected: impl IntoIterator<Item = E>,
+    seed: u64,
+) -> IteratorContainsExactlyShuffled<E> {
+    IteratorContainsExactlyShuffled {
+        expected: Vec::from_iter(expected),
+        seed,
+        missing: HashSet::new(),
+        extra: HashSet::new(),
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IteratorContainsExactlyShuffled<E> {
+    pub expected: Vec<E>,
+    pub seed: u64,
+    pub missing: HashSet<usize>,
+    pub extra: HashSet<usize>,
+}
+
 /// Creates an [`IteratorContainsAnyOf`] expectation.
 pub fn iterator_contains_any_of<E>(
     expected: impl IntoIterator<Item = E>,
@@ -968,6 +2263,7 @@ pub fn iterator_contains_all_of<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorContainsAllOf<E> {
     pub expected: Vec<E>,
     pub missing: HashSet<usize>,
@@ -992,6 +2288,7 @@ pub fn iterator_contains_only<E>(expected: impl IntoIterator<Item = E>) -> Itera
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorContainsOnly<E> {
     pub expected: Vec<E>,
     pub extra: HashSet<usize>,
@@ -1019,6 +2316,7 @@ pub fn iterator_contains_only_once<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorContainsOnlyOnce<E> {
     pub expected: Vec<E>,
     pub extra: HashSet<usize>,
@@ -1049,6 +2347,7 @@ pub fn iterator_contains_exactly<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorContainsExactly<E> {
     pub expected: Vec<E>,
     pub missing: HashSet<usize>,
@@ -1080,6 +2379,7 @@ pub fn iterator_contains_sequence<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorContainsSequence<E> {
     pub expected: Vec<E>,
     pub missing: HashSet<usize>,
@@ -1108,6 +2408,7 @@ pub fn iterator_contains_all_in_order<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorContainsAllInOrder<E> {
     pub expected: Vec<E>,
     pub missing: HashSet<usize>,
@@ -1133,6 +2434,7 @@ pub fn iterator_starts_with<E>(expected: impl IntoIterator<Item = E>) -> Iterato
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorStartsWith<E> {
     pub expected: Vec<E>,
     pub missing: HashSet<usize>,
@@ -1160,6 +2462,7 @@ pub fn iterator_ends_with<E>(expected: impl IntoIterator<Item = E>) -> IteratorE
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct IteratorEndsWith<E> {
     pub expected: Vec<E>,
     pub missing: HashSet<usize>,
@@ -1177,6 +2480,44 @@ impl<E> IteratorEndsWith<E> {
     }
 }
 
+/// Creates an [`IteratorStartsWithInAnyOrder`] expectation.
+pub fn iterator_starts_with_in_any_order<E>(
+    expected: impl IntoIterator<Item = E>,
+) -> IteratorStartsWithInAnyOrder<E> {
+    IteratorStartsWithInAnyOrder {
+        expected: Vec::from_iter(expected),
+        missing: HashSet::new(),
+        extra: HashSet::new(),
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IteratorStartsWithInAnyOrder<E> {
+    pub expected: Vec<E>,
+    pub missing: HashSet<usize>,
+    pub extra: HashSet<usize>,
+}
+
+/// Creates an [`IteratorEndsWithInAnyOrder`] expectation.
+pub fn iterator_ends_with_in_any_order<E>(
+    expected: impl IntoIterator<Item = E>,
+) -> IteratorEndsWithInAnyOrder<E> {
+    IteratorEndsWithInAnyOrder {
+        expected: Vec::from_iter(expected),
+        missing: HashSet::new(),
+        extra: HashSet::new(),
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IteratorEndsWithInAnyOrder<E> {
+    pub expected: Vec<E>,
+    pub missing: HashSet<usize>,
+    pub extra: HashSet<usize>,
+}
+
 pub fn has_single_element() -> HasSingleElement {
     HasSingleElement
 }
@@ -1197,6 +2538,36 @@ pub struct HasAtLeastNumberOfElements {
     pub expected_number_of_elements: usize,
 }
 
+/// Creates a [`HasCount`] expectation.
+pub fn has_count(expected_count: usize) -> HasCount {
+    HasCount { expected_count }
+}
+
+#[must_use]
+pub struct HasCount {
+    pub expected_count: usize,
+}
+
+/// Creates a [`HasCountAtLeast`] expectation.
+pub fn has_count_at_least(expected_count: usize) -> HasCountAtLeast {
+    HasCountAtLeast { expected_count }
+}
+
+#[must_use]
+pub struct HasCountAtLeast {
+    pub expected_count: usize,
+}
+
+/// Creates a [`HasCountInRange`] expectation.
+pub fn has_count_in_range<U>(expected_range: U) -> HasCountInRange<U> {
+    HasCountInRange { expected_range }
+}
+
+#[must_use]
+pub struct HasCountInRange<U> {
+    pub expected_range: U,
+}
+
 pub fn any_satisfies<P>(predicate: P) -> AnySatisfies<P> {
     AnySatisfies { predicate }
 }
@@ -1214,6 +2585,7 @@ pub fn all_satisfy<P>(predicate: P) -> AllSatisfy<P> {
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct AllSatisfy<P> {
     pub predicate: P,
     pub failing: HashSet<usize>,
@@ -1227,11 +2599,110 @@ pub fn none_satisfies<P>(predicate: P) -> NoneSatisfies<P> {
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct NoneSatisfies<P> {
     pub predicate: P,
     pub failing: HashSet<usize>,
 }
 
+/// Creates an [`IsStrictlyIncreasing`] expectation.
+pub fn is_strictly_increasing() -> IsStrictlyIncreasing {
+    IsStrictlyIncreasing { violation: None }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IsStrictlyIncreasing {
+    pub violation: Option<usize>,
+}
+
+/// Creates an [`IsStrictlyDecreasing`] expectation.
+pub fn is_strictly_decreasing() -> IsStrictlyDecreasing {
+    IsStrictlyDecreasing { violation: None }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IsStrictlyDecreasing {
+    pub violation: Option<usize>,
+}
+
+/// Creates an [`IsNonDecreasing`] expectation.
+pub fn is_non_decreasing() -> IsNonDecreasing {
+    IsNonDecreasing { violation: None }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IsNonDecreasing {
+    pub violation: Option<usize>,
+}
+
+/// Creates an [`IsNonIncreasing`] expectation.
+pub fn is_non_increasing() -> IsNonIncreasing {
+    IsNonIncreasing { violation: None }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IsNonIncreasing {
+    pub violation: Option<usize>,
+}
+
+/// Creates an [`IsSortedBy`] expectation.
+pub fn is_sorted_by<C>(compare: C) -> IsSortedBy<C> {
+    IsSortedBy {
+        compare,
+        violation: None,
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IsSortedBy<C> {
+    pub compare: C,
+    pub violation: Option<usize>,
+}
+
+/// Creates an [`IsSortedByKey`] expectation.
+pub fn is_sorted_by_key<F, K>(key: F) -> IsSortedByKey<F, K> {
+    IsSortedByKey {
+        key,
+        violation: None,
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct IsSortedByKey<F, K> {
+    pub key: F,
+    pub violation: Option<(usize, K, K)>,
+}
+
+/// Creates a [`ContainsPair`] expectation.
+pub fn contains_pair<K, V>(expected_key: K, expected_value: V) -> ContainsPair<K, V> {
+    ContainsPair {
+        expected_key,
+        expected_value,
+    }
+}
+
+#[must_use]
+pub struct ContainsPair<K, V> {
+    pub expected_key: K,
+    pub expected_value: V,
+}
+
+/// Creates a [`KeysContain`] expectation.
+pub fn keys_contain<K>(expected_key: K) -> KeysContain<K> {
+    KeysContain { expected_key }
+}
+
+#[must_use]
+pub struct KeysContain<K> {
+    pub expected_key: K,
+}
+
 /// Creates a [`MapContainsKey`] expectation.
 pub fn map_contains_key<E>(expected_key: E) -> MapContainsKey<E> {
     MapContainsKey { expected_key }
@@ -1261,6 +2732,7 @@ pub fn map_contains_keys<E>(expected_keys: impl IntoIterator<Item = E>) -> MapCo
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct MapContainsKeys<E> {
     pub expected_keys: Vec<E>,
     pub missing: HashSet<usize>,
@@ -1276,6 +2748,56 @@ impl<E> MapContainsKeys<E> {
     }
 }
 
+/// Creates a [`MapFirstEntryIs`] expectation.
+pub fn map_first_entry_is<E, F>(expected_entry: (E, F)) -> MapFirstEntryIs<E, F> {
+    MapFirstEntryIs { expected_entry }
+}
+
+#[must_use]
+pub struct MapFirstEntryIs<E, F> {
+    pub expected_entry: (E, F),
+}
+
+/// Creates a [`MapLastEntryIs`] expectation.
+pub fn map_last_entry_is<E, F>(expected_entry: (E, F)) -> MapLastEntryIs<E, F> {
+    MapLastEntryIs { expected_entry }
+}
+
+#[must_use]
+pub struct MapLastEntryIs<E, F> {
+    pub expected_entry: (E, F),
+}
+
+/// Creates a [`MapContainsEntriesInOrder`] expectation.
+pub fn map_contains_entries_in_order<E, F>(
+    expected_entries: impl IntoIterator<Item = (E, F)>,
+) -> MapContainsEntriesInOrder<E, F> {
+    MapContainsEntriesInOrder {
+        expected_entries: Vec::from_iter(expected_entries),
+        missing: HashSet::new(),
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct MapContainsEntriesInOrder<E, F> {
+    pub expected_entries: Vec<(E, F)>,
+    pub missing: HashSet<usize>,
+}
+
+/// Creates a [`MapKeysAreSorted`] expectation.
+pub fn map_keys_are_sorted() -> MapKeysAreSorted {
+    MapKeysAreSorted {
+        out_of_order: HashSet::new(),
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct MapKeysAreSorted {
+    pub out_of_order: HashSet<usize>,
+}
+
 /// Creates a [`MapDoesNotContainKeys`] expectation.
 pub fn map_does_not_contain_keys<E>(
     expected_keys: impl IntoIterator<Item = E>,
@@ -1287,6 +2809,7 @@ pub fn map_does_not_contain_keys<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct MapDoesNotContainKeys<E> {
     pub expected_keys: Vec<E>,
     pub extra: HashSet<usize>,
@@ -1313,6 +2836,7 @@ pub fn map_contains_values<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct MapContainsValues<E> {
     pub expected_values: Vec<E>,
     pub missing: HashSet<usize>,
@@ -1339,6 +2863,7 @@ pub fn map_does_not_contain_values<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct MapDoesNotContainValues<E> {
     pub expected_values: Vec<E>,
     pub extra: HashSet<usize>,
@@ -1366,6 +2891,7 @@ pub fn map_contains_exactly_keys<E>(
 }
 
 #[must_use]
+#[non_exhaustive]
 pub struct MapContainsExactlyKeys<E> {
     pub expected_keys: Vec<E>,
     pub missing: HashSet<usize>,
@@ -1383,6 +2909,27 @@ impl<E> MapContainsExactlyKeys<E> {
     }
 }
 
+/// Creates a [`MapIsEqualTo`] expectation.
+pub fn map_is_equal_to<E>(expected: E) -> MapIsEqualTo<E> {
+    MapIsEqualTo {
+        expected,
+        missing: HashSet::new(),
+        extra: HashSet::new(),
+        different_actual: HashSet::new(),
+        different_expected: HashSet::new(),
+    }
+}
+
+#[must_use]
+#[non_exhaustive]
+pub struct MapIsEqualTo<E> {
+    pub expected: E,
+    pub missing: HashSet<usize>,
+    pub extra: HashSet<usize>,
+    pub different_actual: HashSet<usize>,
+    pub different_expected: HashSet<usize>,
+}
+
 /// Creates a [`DoesPanic`] expectation.
 ///
 /// The panic message is not being asserted. To expect to panic with a specific
@@ -1415,6 +2962,7 @@ pub fn does_panic() -> DoesPanic {
 #[cfg(feature = "panic")]
 #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
 #[must_use]
+#[non_exhaustive]
 pub struct DoesPanic {
     pub expected_message: Option<String>,
     pub actual_message: Option<String>,
@@ -1440,6 +2988,150 @@ pub fn does_not_panic() -> DoesNotPanic {
 #[cfg(feature = "panic")]
 #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
 #[must_use]
+#[non_exhaustive]
 pub struct DoesNotPanic {
     pub actual_message: Option<Box<dyn std::any::Any + Send>>,
 }
+
+/// Creates a [`CompletesWithin`] expectation.
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+pub fn completes_within(budget: Duration) -> CompletesWithin {
+    CompletesWithin {
+        budget,
+        elapsed: None,
+        actual_panic: None,
+    }
+}
+
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+#[must_use]
+#[non_exhaustive]
+pub struct CompletesWithin {
+    pub budget: Duration,
+    pub elapsed: Option<Duration>,
+    pub actual_panic: Option<Box<dyn std::any::Any + Send>>,
+}
+
+/// Creates an [`AllocatesAtMost`] expectation.
+#[cfg(feature = "alloc-track")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc-track")))]
+pub fn allocates_at_most(max: usize) -> AllocatesAtMost {
+    AllocatesAtMost {
+        max,
+        allocations: None,
+        actual_panic: None,
+    }
+}
+
+#[cfg(feature = "alloc-track")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc-track")))]
+#[must_use]
+#[non_exhaustive]
+pub struct AllocatesAtMost {
+    pub max: usize,
+    pub allocations: Option<usize>,
+    pub actual_panic: Option<Box<dyn std::any::Any + Send>>,
+}
+
+/// Creates a [`CodeOverflows`] expectation.
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+pub fn code_overflows() -> CodeOverflows {
+    CodeOverflows { result: None }
+}
+
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+#[must_use]
+#[non_exhaustive]
+pub struct CodeOverflows {
+    pub result: Option<String>,
+}
+
+/// Creates a [`CodeDoesNotOverflow`] expectation.
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+pub fn code_does_not_overflow() -> CodeDoesNotOverflow {
+    CodeDoesNotOverflow { result: None }
+}
+
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+#[must_use]
+#[non_exhaustive]
+pub struct CodeDoesNotOverflow {
+    pub result: Option<String>,
+}
+
+/// Creates a [`DoesPanicWithPayloadOfType`] expectation.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::expectations::does_panic_with_payload_of_type;
+/// use asserting::prelude::*;
+/// use std::panic::panic_any;
+///
+/// #[derive(Debug)]
+/// struct ErrorCode(u16);
+///
+/// assert_that_code!(|| { panic_any(ErrorCode(503)); })
+///     .expecting(does_panic_with_payload_of_type::<ErrorCode>());
+/// ```
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+pub fn does_panic_with_payload_of_type<P>() -> DoesPanicWithPayloadOfType<P> {
+    DoesPanicWithPayloadOfType {
+        actual_payload: None,
+        _payload_type: PhantomData,
+    }
+}
+
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+#[must_use]
+#[non_exhaustive]
+pub struct DoesPanicWithPayloadOfType<P> {
+    pub actual_payload: Option<Box<dyn std::any::Any + Send>>,
+    _payload_type: PhantomData<P>,
+}
+
+/// Creates a [`DoesPanicSatisfying`] expectation.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::expectations::does_panic_satisfying;
+/// use asserting::prelude::*;
+/// use std::panic::panic_any;
+///
+/// #[derive(Debug)]
+/// struct ErrorCode(u16);
+///
+/// assert_that_code!(|| { panic_any(ErrorCode(503)); })
+///     .expecting(does_panic_satisfying(|payload| {
+///         payload.downcast_ref::<ErrorCode>().is_some_and(|code| code.0 >= 500)
+///     }));
+/// ```
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+pub fn does_panic_satisfying<F>(predicate: F) -> DoesPanicSatisfying<F>
+where
+    F: Fn(&dyn std::any::Any) -> bool,
+{
+    DoesPanicSatisfying {
+        predicate,
+        actual_payload: None,
+    }
+}
+
+#[cfg(feature = "panic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
+#[must_use]
+#[non_exhaustive]
+pub struct DoesPanicSatisfying<F> {
+    pub predicate: F,
+    pub actual_payload: Option<Box<dyn std::any::Any + Send>>,
+}
@@ -1,6 +1,6 @@
 use crate::properties::{
-    AdditiveIdentityProperty, InfinityProperty, IsNanProperty, MultiplicativeIdentityProperty,
-    SignumProperty,
+    AdditiveIdentityProperty, CheckedDifferenceProperty, DistanceProperty, InfinityProperty,
+    IsNanProperty, MultiplicativeIdentityProperty, SignumProperty,
 };
 
 impl SignumProperty for f32 {
@@ -23,6 +23,64 @@ impl SignumProperty for f64 {
     }
 }
 
+impl DistanceProperty for f32 {
+    type Distance = Self;
+
+    fn distance_property(&self, other: &Self) -> Self::Distance {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn bounds_within_property(&self, offset: &Self) -> (Self, Self) {
+        let offset = if *offset < 0. { -offset } else { *offset };
+        (self - offset, self + offset)
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::suboptimal_flops)]
+    fn percentage_property(&self, percent: f64) -> Self {
+        let amount = f64::from(*self) * percent / 100.;
+        (if amount < 0. { -amount } else { amount }) as Self
+    }
+}
+
+impl CheckedDifferenceProperty for f32 {
+    fn checked_difference_property(&self, other: &Self) -> Option<Self> {
+        Some(self.distance_property(other))
+    }
+}
+
+impl DistanceProperty for f64 {
+    type Distance = Self;
+
+    fn distance_property(&self, other: &Self) -> Self::Distance {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn bounds_within_property(&self, offset: &Self) -> (Self, Self) {
+        let offset = if *offset < 0. { -offset } else { *offset };
+        (self - offset, self + offset)
+    }
+
+    #[allow(clippy::suboptimal_flops)]
+    fn percentage_property(&self, percent: f64) -> Self {
+        let amount = self * percent / 100.;
+        if amount < 0. { -amount } else { amount }
+    }
+}
+
+impl CheckedDifferenceProperty for f64 {
+    fn checked_difference_property(&self, other: &Self) -> Option<Self> {
+        Some(self.distance_property(other))
+    }
+}
+
 macro_rules! impl_additive_identity_property {
     ($type:ty) => {
         impl AdditiveIdentityProperty for $type {
@@ -95,9 +153,16 @@ impl IsNanProperty for f64 {
 
 #[cfg(feature = "float-cmp")]
 mod cmp {
-    use crate::assertions::{AssertIsCloseToWithDefaultMargin, AssertIsCloseToWithinMargin};
-    use crate::colored::mark_diff;
-    use crate::expectations::{IsCloseTo, is_close_to, not};
+    use crate::assertions::{
+        AssertElementsCloseTo, AssertIsCloseToWithDefaultMargin, AssertIsCloseToWithinMargin,
+    };
+    use crate::colored::{
+        mark_diff, mark_missing, mark_selected_items_in_collection, mark_unexpected,
+    };
+    use crate::expectations::{
+        ContainsExactlyCloseTo, IsCloseTo, IsCloseToEach, contains_exactly_close_to, is_close_to,
+        is_close_to_each, not,
+    };
     use crate::spec::{
         DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
     };
@@ -161,6 +226,8 @@ mod cmp {
     }
 
     impl Expectation<f32> for IsCloseTo<f32, F32Margin> {
+        const ASSERTION_ID: &'static str = "asserting::is_close_to";
+
         fn test(&mut self, subject: &f32) -> bool {
             subject.approx_eq(self.expected, self.margin)
         }
@@ -184,6 +251,8 @@ mod cmp {
     impl Invertible for IsCloseTo<f32, F32Margin> {}
 
     impl Expectation<f64> for IsCloseTo<f64, F64Margin> {
+        const ASSERTION_ID: &'static str = "asserting::is_close_to";
+
         fn test(&mut self, subject: &f64) -> bool {
             subject.approx_eq(self.expected, self.margin)
         }
@@ -205,6 +274,133 @@ mod cmp {
     }
 
     impl Invertible for IsCloseTo<f64, F64Margin> {}
+
+    macro_rules! impl_elements_close_to {
+        ($float:ty, $margin:ty) => {
+            impl<S, R> AssertElementsCloseTo<$float, $margin> for Spec<'_, S, R>
+            where
+                S: AsRef<[$float]>,
+                R: FailingStrategy,
+            {
+                fn contains_exactly_close_to(
+                    self,
+                    expected: impl IntoIterator<Item = $float>,
+                ) -> Self {
+                    self.expecting(
+                        contains_exactly_close_to(expected)
+                            .within_margin((4. * <$float>::EPSILON, 4)),
+                    )
+                }
+
+                fn contains_exactly_close_to_with_margin(
+                    self,
+                    expected: impl IntoIterator<Item = $float>,
+                    margin: impl Into<$margin>,
+                ) -> Self {
+                    self.expecting(contains_exactly_close_to(expected).within_margin(margin))
+                }
+
+                fn is_close_to_each(self, expected: $float) -> Self {
+                    self.expecting(
+                        is_close_to_each(expected).within_margin((4. * <$float>::EPSILON, 4)),
+                    )
+                }
+
+                fn is_close_to_each_with_margin(
+                    self,
+                    expected: $float,
+                    margin: impl Into<$margin>,
+                ) -> Self {
+                    self.expecting(is_close_to_each(expected).within_margin(margin))
+                }
+            }
+
+            impl<S> Expectation<S> for ContainsExactlyCloseTo<$float, $margin>
+            where
+                S: AsRef<[$float]>,
+            {
+                const ASSERTION_ID: &'static str = "asserting::contains_exactly_close_to";
+
+                fn test(&mut self, subject: &S) -> bool {
+                    let actual = subject.as_ref();
+                    let length = actual.len().max(self.expected.len());
+                    for index in 0..length {
+                        match (actual.get(index), self.expected.get(index)) {
+                            (Some(actual), Some(expected))
+                                if actual.approx_eq(*expected, self.margin) => {},
+                            _ => {
+                                self.outside_tolerance.insert(index);
+                            },
+                        }
+                    }
+                    self.outside_tolerance.is_empty()
+                }
+
+                fn message(
+                    &self,
+                    expression: &Expression<'_>,
+                    actual: &S,
+                    _inverted: bool,
+                    format: &DiffFormat,
+                ) -> String {
+                    let marked_actual = mark_selected_items_in_collection(
+                        actual.as_ref(),
+                        &self.outside_tolerance,
+                        format,
+                        mark_unexpected,
+                    );
+                    let marked_expected = mark_selected_items_in_collection(
+                        &self.expected,
+                        &self.outside_tolerance,
+                        format,
+                        mark_missing,
+                    );
+                    format!(
+                        "expected {expression} to contain exactly values close to {:?}\n  within a margin of epsilon={:e} and ulps={}\n   but was: {marked_actual}\n  expected: {marked_expected}",
+                        self.expected, self.margin.epsilon, self.margin.ulps
+                    )
+                }
+            }
+
+            impl<S> Expectation<S> for IsCloseToEach<$float, $margin>
+            where
+                S: AsRef<[$float]>,
+            {
+                const ASSERTION_ID: &'static str = "asserting::is_close_to_each";
+
+                fn test(&mut self, subject: &S) -> bool {
+                    for (index, actual) in subject.as_ref().iter().enumerate() {
+                        if !actual.approx_eq(self.expected, self.margin) {
+                            self.outside_tolerance.insert(index);
+                        }
+                    }
+                    self.outside_tolerance.is_empty()
+                }
+
+                fn message(
+                    &self,
+                    expression: &Expression<'_>,
+                    actual: &S,
+                    _inverted: bool,
+                    format: &DiffFormat,
+                ) -> String {
+                    let marked_actual = mark_selected_items_in_collection(
+                        actual.as_ref(),
+                        &self.outside_tolerance,
+                        format,
+                        mark_unexpected,
+                    );
+                    format!(
+                        "expected {expression} to contain only values close to {:?}\n  within a margin of epsilon={:e} and ulps={}\n   but was: {marked_actual}\n  expected: each close to {:?}",
+                        self.expected, self.margin.epsilon, self.margin.ulps, self.expected
+                    )
+                }
+            }
+        };
+    }
+
+    impl_elements_close_to!(f32, F32Margin);
+    impl_elements_close_to!(f64, F64Margin);
 }
 
 #[cfg(test)]
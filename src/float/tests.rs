@@ -437,6 +437,7 @@ fn verify_f64_is_not_a_number_fails() {
 #[cfg(feature = "float-cmp")]
 mod cmp {
     use crate::prelude::*;
+    use crate::std::{vec, vec::Vec};
 
     #[test]
     fn f32_is_close_to_another_f32_within_default_margin() {
@@ -614,9 +615,100 @@ mod cmp {
         );
     }
 
+    #[test]
+    fn vec_of_f32_contains_exactly_close_to_expected_values_within_default_margin() {
+        let values: Vec<f32> = vec![1. / 3., 2. / 3., 3. / 3.];
+
+        assert_that(values).contains_exactly_close_to([0.333_333_34, 0.666_666_7, 1.]);
+    }
+
+    #[test]
+    fn slice_of_f64_contains_exactly_close_to_expected_values_within_given_margin() {
+        let values: &[f64] = &[10. / 3., 20. / 3.];
+
+        assert_that(values).contains_exactly_close_to_with_margin([3.333, 6.667], (0.001, 4));
+    }
+
+    #[test]
+    fn verify_vec_of_f32_contains_exactly_close_to_expected_values_fails() {
+        let failures = verify_that(vec![1.0_f32, 2.0, 3.0])
+            .named("values")
+            .contains_exactly_close_to([1.0, 2.5, 3.0])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected values to contain exactly values close to [1.0, 2.5, 3.0]
+  within a margin of epsilon=4.7683716e-7 and ulps=4
+   but was: [1.0, 2.0, 3.0]
+  expected: [1.0, 2.5, 3.0]
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_array_of_f64_contains_exactly_close_to_more_expected_values_fails() {
+        let failures = verify_that([0.5_f64, 1.5])
+            .named("values")
+            .contains_exactly_close_to_with_margin([0.5, 1.5, 2.5], (0.01, 2))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected values to contain exactly values close to [0.5, 1.5, 2.5]
+  within a margin of epsilon=1e-2 and ulps=2
+   but was: [0.5, 1.5]
+  expected: [0.5, 1.5, 2.5]
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn array_of_f64_is_close_to_each_expected_value_within_default_margin() {
+        let values: [f64; 3] = [0.1 + 0.2, 0.3, 0.15 * 2.];
+
+        assert_that(values).is_close_to_each(0.3);
+    }
+
+    #[test]
+    fn vec_of_f32_is_close_to_each_expected_value_within_given_margin() {
+        let values: Vec<f32> = vec![0.2995, 0.3, 0.3005];
+
+        assert_that(&values).is_close_to_each_with_margin(0.3, (0.001, 4));
+    }
+
+    #[test]
+    fn empty_slice_of_f32_is_close_to_each_expected_value() {
+        let values: &[f32] = &[];
+
+        assert_that(values).is_close_to_each(1.0);
+    }
+
+    #[test]
+    fn verify_vec_of_f64_is_close_to_each_expected_value_fails() {
+        let failures = verify_that(vec![0.3_f64, 0.31, 0.3])
+            .named("values")
+            .is_close_to_each_with_margin(0.3, (0.001, 4))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected values to contain only values close to 0.3
+  within a margin of epsilon=1e-3 and ulps=4
+   but was: [0.3, 0.31, 0.3]
+  expected: each close to 0.3
+"]
+        );
+    }
+
     #[cfg(feature = "colored")]
     mod colored {
         use crate::prelude::*;
+        use crate::std::vec;
 
         #[test]
         fn highlight_diffs_f32_is_close_to() {
@@ -651,5 +743,41 @@ mod cmp {
             "]
             );
         }
+
+        #[test]
+        fn highlight_diffs_vec_of_f32_contains_exactly_close_to() {
+            let failures = verify_that(vec![1.0_f32, 2.0, 3.0, 4.0])
+                .with_diff_format(DIFF_FORMAT_RED_BLUE)
+                .contains_exactly_close_to([1.0, 2.5, 3.0])
+                .display_failures();
+
+            assert_eq!(
+                failures,
+                &[
+                    "expected subject to contain exactly values close to [1.0, 2.5, 3.0]\n  \
+                within a margin of epsilon=4.7683716e-7 and ulps=4\n   \
+                 but was: [1.0, \u{1b}[31m2.0\u{1b}[0m, 3.0, \u{1b}[31m4.0\u{1b}[0m]\n  \
+                expected: [1.0, \u{1b}[34m2.5\u{1b}[0m, 3.0]\n\
+            "
+                ]
+            );
+        }
+
+        #[test]
+        fn highlight_diffs_vec_of_f64_is_close_to_each() {
+            let failures = verify_that(vec![0.3_f64, 0.31, 0.3, 0.29])
+                .with_diff_format(DIFF_FORMAT_RED_BLUE)
+                .is_close_to_each_with_margin(0.3, (0.001, 4))
+                .display_failures();
+
+            assert_eq!(
+                failures,
+                &["expected subject to contain only values close to 0.3\n  \
+                within a margin of epsilon=1e-3 and ulps=4\n   \
+                 but was: [0.3, \u{1b}[31m0.31\u{1b}[0m, 0.3, \u{1b}[31m0.29\u{1b}[0m]\n  \
+                expected: each close to 0.3\n\
+            "]
+            );
+        }
     }
 }
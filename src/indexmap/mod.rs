@@ -0,0 +1,125 @@
+//! Implementation of the collection and map properties for the `IndexMap` and
+//! `IndexSet` types of the `indexmap` crate.
+//!
+//! Both types iterate their elements in insertion order. Therefore, they
+//! support the order-aware assertions in addition to the assertions for maps
+//! and sets.
+
+use crate::assertions::{AssertMapValue, AssertSetEquality};
+use crate::expectations::set_is_equal_to;
+use crate::map::take_value_of;
+use crate::properties::{
+    CapacityProperty, DefinedOrderProperty, IsEmptyProperty, LengthProperty, MapProperties,
+    SetProperty,
+};
+use crate::spec::{Expecting, FailingStrategy, Spec};
+use crate::std::fmt::Debug;
+use crate::std::hash::{BuildHasher, Hash};
+use indexmap::{IndexMap, IndexSet, map, set};
+
+impl<K, V, S> DefinedOrderProperty for IndexMap<K, V, S> {}
+impl<K, V> DefinedOrderProperty for map::IntoIter<K, V> {}
+impl<K, V> DefinedOrderProperty for map::Iter<'_, K, V> {}
+impl<K, V> DefinedOrderProperty for map::IterMut<'_, K, V> {}
+impl<K, V> DefinedOrderProperty for map::Keys<'_, K, V> {}
+impl<K, V> DefinedOrderProperty for map::Values<'_, K, V> {}
+impl<T, S> DefinedOrderProperty for IndexSet<T, S> {}
+impl<T> DefinedOrderProperty for set::IntoIter<T> {}
+impl<T> DefinedOrderProperty for set::Iter<'_, T> {}
+
+impl<K, V, S> MapProperties for IndexMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn keys_property(&self) -> impl Iterator<Item = &<Self as MapProperties>::Key> {
+        self.keys()
+    }
+
+    fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
+        self.values()
+    }
+
+    fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S, E, R> AssertMapValue<E> for Spec<'a, IndexMap<K, V, S>, R>
+where
+    K: PartialEq<E> + Debug,
+    V: Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    type Value = Spec<'a, V, R>;
+
+    fn value_of(self, key: E) -> Self::Value {
+        take_value_of(self, key)
+    }
+}
+
+impl<T, S> SetProperty for IndexSet<T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    type Element = T;
+
+    fn elements_property(&self) -> impl Iterator<Item = &Self::Element> {
+        self.iter()
+    }
+
+    fn contains_property(&self, element: &Self::Element) -> bool {
+        self.contains(element)
+    }
+}
+
+impl<K, V, S> IsEmptyProperty for IndexMap<K, V, S> {
+    fn is_empty_property(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T, S> IsEmptyProperty for IndexSet<T, S> {
+    fn is_empty_property(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<K, V, S> LengthProperty for IndexMap<K, V, S> {
+    fn length_property(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> LengthProperty for IndexSet<T, S> {
+    fn length_property(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> CapacityProperty for IndexMap<K, V, S> {
+    fn capacity_property(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T, S> CapacityProperty for IndexSet<T, S> {
+    fn capacity_property(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T, H, R> AssertSetEquality<IndexSet<T, H>> for Spec<'_, IndexSet<T, H>, R>
+where
+    T: Hash + Ord + Debug,
+    H: BuildHasher,
+    R: FailingStrategy,
+{
+    fn is_equal_to_set(self, expected: IndexSet<T, H>) -> Self {
+        self.expecting(set_is_equal_to(expected))
+    }
+}
+
+#[cfg(test)]
+mod tests;
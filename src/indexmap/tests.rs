@@ -0,0 +1,153 @@
+use crate::prelude::*;
+use crate::std::collections::BTreeMap;
+use indexmap::{IndexMap, IndexSet};
+
+#[test]
+fn index_map_is_empty() {
+    let subject: IndexMap<usize, &str> = IndexMap::new();
+
+    assert_that(subject).is_empty();
+}
+
+#[test]
+fn index_map_has_length() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+    assert_that(subject).has_length(3);
+}
+
+#[test]
+fn index_map_has_capacity_at_least() {
+    let subject: IndexMap<usize, &str> = IndexMap::with_capacity(10);
+
+    assert_that(subject).has_capacity_at_least(10);
+}
+
+#[test]
+fn index_map_contains_key_and_value() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+    assert_that(&subject).contains_key(1).contains_value("four");
+    assert_that(subject).contains_exactly_keys([4, 5, 1]);
+}
+
+#[test]
+fn index_map_value_of_key() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+    assert_that(&subject).value_of(4).is_equal_to(&"four");
+}
+
+#[test]
+fn owned_index_map_value_of_key() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+    assert_that(subject).value_of(4).is_equal_to("four");
+}
+
+#[test]
+fn index_map_is_equal_to_btree_map() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+    assert_that(subject).is_equal_to_map(BTreeMap::from([(1, "one"), (4, "four"), (5, "five")]));
+}
+
+#[test]
+fn index_map_first_and_last_entry_are_in_insertion_order() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+    assert_that(&subject).first_entry_is((5, "five"));
+    assert_that(&subject).last_entry_is((4, "four"));
+}
+
+#[test]
+fn index_map_contains_entries_in_order() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four"), (6, "six")].into();
+
+    assert_that(subject).contains_entries_in_order([(5, "five"), (4, "four"), (6, "six")]);
+}
+
+#[test]
+fn verify_index_map_contains_entries_in_order_fails() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four"), (6, "six")].into();
+
+    let failures = verify_that(subject)
+        .named("foo_map")
+        .contains_entries_in_order([(1, "one"), (5, "five"), (6, "six")])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected foo_map to contain the entries [(1, "one"), (5, "five"), (6, "six")] in order
+   but was: {5: "five", 1: "one", 4: "four", 6: "six"}
+  expected: [(1, "one"), (5, "five"), (6, "six")]
+   missing: [(5, "five")]
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_index_map_keys_are_sorted_fails() {
+    let subject: IndexMap<_, _> = [(1, "one"), (5, "five"), (4, "four")].into();
+
+    let failures = verify_that(subject)
+        .named("foo_map")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .keys_are_sorted()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected foo_map to have its keys sorted in ascending order
+   but was: {1: "one", 5: "five", 4: "four"}
+  expected: keys in ascending order
+"#
+        ]
+    );
+}
+
+#[test]
+fn iterator_over_index_map_starts_with_first_inserted_entries() {
+    let subject: IndexMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+    assert_that(subject.iter()).starts_with([(&5, &"five"), (&1, &"one")]);
+    assert_that(subject).ends_with([(1, "one"), (4, "four")]);
+}
+
+#[test]
+fn index_set_has_length_and_contains_elements_in_order() {
+    let subject: IndexSet<_> = ["lorem", "ipsum", "dolor"].into();
+
+    assert_that(&subject).has_length(3).is_not_empty();
+    assert_that(subject).contains_exactly(["lorem", "ipsum", "dolor"]);
+}
+
+#[test]
+fn index_set_is_equal_to_set_in_any_order() {
+    let subject: IndexSet<_> = ["lorem", "ipsum", "dolor"].into();
+
+    assert_that(subject).is_equal_to_set(["dolor", "lorem", "ipsum"].into());
+}
+
+#[test]
+fn verify_index_set_is_equal_to_set_fails() {
+    let subject: IndexSet<_> = ["lorem", "ipsum", "dolor"].into();
+
+    let failures = verify_that(subject)
+        .named("words")
+        .is_equal_to_set(["ipsum", "dolor", "amet"].into())
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected words to be equal to {"ipsum", "dolor", "amet"}
+           but was: {"lorem", "ipsum", "dolor"}
+          expected: {"ipsum", "dolor", "amet"}
+    only in actual: ["lorem"]
+  only in expected: ["amet"]
+"#]
+    );
+}
@@ -1,6 +1,9 @@
 //! Implementation of assertions for integer values.
 
-use crate::properties::{AdditiveIdentityProperty, MultiplicativeIdentityProperty, SignumProperty};
+use crate::properties::{
+    AdditiveIdentityProperty, BitsProperty, CheckedDifferenceProperty, DistanceProperty,
+    MultiplicativeIdentityProperty, SignumProperty,
+};
 
 macro_rules! impl_signum_property {
     ($type:ty) => {
@@ -23,6 +26,83 @@ impl_signum_property!(i64);
 impl_signum_property!(i128);
 impl_signum_property!(isize);
 
+macro_rules! impl_distance_property {
+    ($type:ty, $distance:ty) => {
+        impl DistanceProperty for $type {
+            type Distance = $distance;
+
+            fn distance_property(&self, other: &Self) -> Self::Distance {
+                self.abs_diff(*other)
+            }
+
+            fn bounds_within_property(&self, offset: &Self) -> (Self, Self) {
+                let lower = self.saturating_sub(*offset);
+                let upper = self.saturating_add(*offset);
+                // a negative offset turns the bounds around
+                if lower <= upper {
+                    (lower, upper)
+                } else {
+                    (upper, lower)
+                }
+            }
+
+            #[allow(
+                clippy::cast_lossless,
+                clippy::cast_precision_loss,
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::suboptimal_flops
+            )]
+            fn percentage_property(&self, percent: f64) -> Self {
+                let amount = *self as f64 * percent / 100.;
+                (if amount < 0. { -amount } else { amount }) as $type
+            }
+        }
+    };
+}
+
+impl_distance_property!(i8, u8);
+impl_distance_property!(i16, u16);
+impl_distance_property!(i32, u32);
+impl_distance_property!(i64, u64);
+impl_distance_property!(i128, u128);
+impl_distance_property!(isize, usize);
+
+impl_distance_property!(u8, u8);
+impl_distance_property!(u16, u16);
+impl_distance_property!(u32, u32);
+impl_distance_property!(u64, u64);
+impl_distance_property!(u128, u128);
+impl_distance_property!(usize, usize);
+
+macro_rules! impl_checked_difference_property {
+    ($type:ty) => {
+        impl CheckedDifferenceProperty for $type {
+            fn checked_difference_property(&self, other: &Self) -> Option<Self> {
+                if self > other {
+                    self.checked_sub(*other)
+                } else {
+                    other.checked_sub(*self)
+                }
+            }
+        }
+    };
+}
+
+impl_checked_difference_property!(i8);
+impl_checked_difference_property!(i16);
+impl_checked_difference_property!(i32);
+impl_checked_difference_property!(i64);
+impl_checked_difference_property!(i128);
+impl_checked_difference_property!(isize);
+
+impl_checked_difference_property!(u8);
+impl_checked_difference_property!(u16);
+impl_checked_difference_property!(u32);
+impl_checked_difference_property!(u64);
+impl_checked_difference_property!(u128);
+impl_checked_difference_property!(usize);
+
 macro_rules! impl_additive_identity_property {
     ($type:ty) => {
         impl AdditiveIdentityProperty for $type {
@@ -83,5 +163,69 @@ impl_multiplicative_identity_property!(u64);
 impl_multiplicative_identity_property!(u128);
 impl_multiplicative_identity_property!(usize);
 
+macro_rules! impl_bits_property {
+    ($type:ty, $unsigned:ty) => {
+        impl BitsProperty for $type {
+            fn bit_width_property(&self) -> u32 {
+                <$type>::BITS
+            }
+
+            fn bits_property(&self) -> u128 {
+                u128::from(<$unsigned>::from_ne_bytes(self.to_ne_bytes()))
+            }
+        }
+    };
+}
+
+impl_bits_property!(i8, u8);
+impl_bits_property!(i16, u16);
+impl_bits_property!(i32, u32);
+impl_bits_property!(i64, u64);
+
+impl_bits_property!(u8, u8);
+impl_bits_property!(u16, u16);
+impl_bits_property!(u32, u32);
+impl_bits_property!(u64, u64);
+
+impl BitsProperty for i128 {
+    fn bit_width_property(&self) -> u32 {
+        Self::BITS
+    }
+
+    fn bits_property(&self) -> u128 {
+        u128::from_ne_bytes(self.to_ne_bytes())
+    }
+}
+
+impl BitsProperty for u128 {
+    fn bit_width_property(&self) -> u32 {
+        Self::BITS
+    }
+
+    fn bits_property(&self) -> u128 {
+        *self
+    }
+}
+
+impl BitsProperty for isize {
+    fn bit_width_property(&self) -> u32 {
+        Self::BITS
+    }
+
+    fn bits_property(&self) -> u128 {
+        usize::from_ne_bytes(self.to_ne_bytes()) as u128
+    }
+}
+
+impl BitsProperty for usize {
+    fn bit_width_property(&self) -> u32 {
+        Self::BITS
+    }
+
+    fn bits_property(&self) -> u128 {
+        *self as u128
+    }
+}
+
 #[cfg(test)]
 mod tests;
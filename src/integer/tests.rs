@@ -478,6 +478,76 @@ fn verify_u64_is_one_fails() {
     );
 }
 
+#[test]
+fn checked_addition_of_operands_overflows() {
+    let a: u8 = 200;
+    let b: u8 = 100;
+
+    assert_that((a, b)).overflows(|(a, b)| a.checked_add(*b));
+}
+
+#[test]
+fn checked_subtraction_of_operands_does_not_overflow() {
+    let a: u8 = 200;
+    let b: u8 = 100;
+
+    assert_that((a, b)).does_not_overflow(|(a, b)| a.checked_sub(*b));
+}
+
+#[test]
+fn checked_operation_returning_result_overflows() {
+    let a = i64::MIN;
+
+    assert_that(a).overflows(|a| a.checked_neg().ok_or("overflow"));
+    assert_that(a).does_not_overflow(|a| a.checked_add(1).ok_or("overflow"));
+}
+
+#[test]
+fn verify_checked_operation_overflows_fails() {
+    let a: u8 = 200;
+    let b: u8 = 50;
+
+    let failures = verify_that!((a, b))
+        .overflows(|(a, b)| a.checked_add(*b))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected the checked operation on (a, b) to overflow
+   but was: Some(250)
+  expected: None
+  operands: (200, 50)
+"]
+    );
+}
+
+#[test]
+fn verify_checked_operation_does_not_overflow_fails() {
+    let a: u8 = 200;
+    let b: u8 = 100;
+
+    let failures = verify_that!((a, b))
+        .does_not_overflow(|(a, b)| a.checked_mul(*b).ok_or("overflow"))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected the checked operation on (a, b) to not overflow
+   but was: Err("overflow")
+  expected: Ok(_)
+  operands: (200, 100)
+"#]
+    );
+}
+
+#[test]
+#[should_panic = "expected the checked operation on subject to not overflow\n   but was: None\n  expected: Some(_)\n  operands: 2147483647"]
+fn checked_operation_does_not_overflow_panics_if_operation_overflows() {
+    assert_that(i32::MAX)
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .does_not_overflow(|a| a.checked_add(1));
+}
+
 #[cfg(feature = "colored")]
 mod colored {
     use crate::prelude::*;
@@ -1,8 +1,9 @@
 //! Implementations of assertions for `Iterator` values.
 
 use crate::assertions::{
-    AssertFilteredElements, AssertIteratorContains, AssertIteratorContainsInAnyOrder,
-    AssertIteratorContainsInOrder, AssertOrderedElements, AssertOrderedElementsRef,
+    AssertFilteredElements, AssertIsSortedBy, AssertIteratorContains,
+    AssertIteratorContainsInAnyOrder, AssertIteratorContainsInOrder, AssertIteratorCount,
+    AssertMonotonicity, AssertOrderedElements, AssertOrderedElementsRef, AssertSequence,
 };
 use crate::colored::{
     mark_all_items_in_collection, mark_missing, mark_missing_string,
@@ -10,17 +11,23 @@ use crate::colored::{
 };
 use crate::derived_spec::DerivedSpec;
 use crate::expectations::{
-    AllSatisfy, AnySatisfies, HasAtLeastNumberOfElements, HasSingleElement, IteratorContains,
-    IteratorContainsAllInOrder, IteratorContainsAllOf, IteratorContainsAnyOf,
-    IteratorContainsExactly, IteratorContainsExactlyInAnyOrder, IteratorContainsOnly,
-    IteratorContainsOnlyOnce, IteratorContainsSequence, IteratorEndsWith, IteratorStartsWith,
-    NoneSatisfies, all_satisfy, any_satisfies, has_at_least_number_of_elements, has_single_element,
-    iterator_contains, iterator_contains_all_in_order, iterator_contains_all_of,
-    iterator_contains_any_of, iterator_contains_exactly, iterator_contains_exactly_in_any_order,
-    iterator_contains_only, iterator_contains_only_once, iterator_contains_sequence,
-    iterator_ends_with, iterator_starts_with, none_satisfies, not,
+    AllSatisfy, AnySatisfies, HasAtLeastNumberOfElements, HasCount, HasCountAtLeast,
+    HasCountInRange, HasSingleElement, IsNonDecreasing, IsNonIncreasing, IsSortedBy, IsSortedByKey,
+    IsStrictlyDecreasing, IsStrictlyIncreasing, IteratorContains, IteratorContainsAllInOrder,
+    IteratorContainsAllOf, IteratorContainsAnyOf, IteratorContainsExactly,
+    IteratorContainsExactlyInAnyOrder, IteratorContainsExactlyShuffled, IteratorContainsOnly,
+    IteratorContainsOnlyOnce, IteratorContainsSequence, IteratorEndsWith,
+    IteratorEndsWithInAnyOrder, IteratorStartsWith, IteratorStartsWithInAnyOrder, NoneSatisfies,
+    all_satisfy, any_satisfies, has_at_least_number_of_elements, has_count, has_count_at_least,
+    has_count_in_range, has_single_element, is_non_decreasing, is_non_increasing, is_sorted_by,
+    is_sorted_by_key, is_strictly_decreasing, is_strictly_increasing, iterator_contains,
+    iterator_contains_all_in_order, iterator_contains_all_of, iterator_contains_any_of,
+    iterator_contains_exactly, iterator_contains_exactly_in_any_order,
+    iterator_contains_exactly_shuffled, iterator_contains_only, iterator_contains_only_once,
+    iterator_contains_sequence, iterator_ends_with, iterator_ends_with_in_any_order,
+    iterator_starts_with, iterator_starts_with_in_any_order, none_satisfies, not,
 };
-use crate::properties::DefinedOrderProperty;
+use crate::properties::{DefinedOrderProperty, SequenceProperty};
 use crate::spec::{
     DiffFormat, Expectation, Expecting, Expression, FailingStrategy, GetFailures, Invertible,
     PanicOnFail, Spec,
@@ -29,7 +36,13 @@ use crate::std::borrow::ToOwned;
 use crate::std::cmp::Ordering;
 use crate::std::fmt::Debug;
 use crate::std::mem;
-use crate::std::{format, string::String, vec, vec::Vec};
+use crate::std::ops::{Bound, RangeBounds};
+use crate::std::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::structured_diff::{element_differences, format_differences};
 use hashbrown::HashSet;
 
 impl<'a, S, T, E, R> AssertIteratorContains<E> for Spec<'a, S, R>
@@ -57,6 +70,8 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         subject.iter().any(|e| e == &self.expected)
     }
@@ -116,6 +131,11 @@ where
             .expecting(iterator_contains_exactly_in_any_order(expected))
     }
 
+    fn contains_exactly_shuffled(self, expected: E, seed: u64) -> Self::Sequence {
+        self.mapping(|subject| shuffle(Vec::from_iter(subject), seed))
+            .expecting(iterator_contains_exactly_shuffled(expected, seed))
+    }
+
     fn contains_any_of(self, expected: E) -> Spec<'a, Vec<T>, R> {
         self.mapping(Vec::from_iter)
             .expecting(iterator_contains_any_of(expected))
@@ -147,25 +167,10 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
-    fn test(&mut self, subject: &Vec<T>) -> bool {
-        let missing = &mut self.missing;
-        let extra = &mut self.extra;
-        *extra = (0..subject.len()).collect();
-
-        let mut subject_values = subject.iter().enumerate().collect::<Vec<_>>();
-        for (expected_index, expected) in self.expected.iter().enumerate() {
-            if let Some(index) = subject_values
-                .iter()
-                .position(|(_, value)| *value == expected)
-            {
-                let (subject_index, _) = subject_values.remove(index);
-                extra.remove(&subject_index);
-            } else {
-                missing.insert(expected_index);
-            }
-        }
+    const ASSERTION_ID: &'static str = "asserting::contains_exactly_in_any_order";
 
-        extra.is_empty() && missing.is_empty()
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        find_missing_and_extra_values(subject, &self.expected, &mut self.missing, &mut self.extra)
     }
 
     fn message(
@@ -193,11 +198,104 @@ where
     }
 }
 
+impl<T, E> Expectation<Vec<T>> for IteratorContainsExactlyShuffled<E>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::contains_exactly_shuffled";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        find_missing_and_extra_values(subject, &self.expected, &mut self.missing, &mut self.extra)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let missing = collect_selected_values(&self.missing, &self.expected);
+        let extra = collect_selected_values(&self.extra, actual);
+        let marked_actual =
+            mark_selected_items_in_collection(actual, &self.extra, format, mark_unexpected);
+        let marked_expected =
+            mark_selected_items_in_collection(&self.expected, &self.missing, format, mark_missing);
+
+        format!(
+            r"expected {expression} to contain exactly in any order {:?}
+   but was: {marked_actual}
+  expected: {marked_expected}
+   missing: {missing:?}
+     extra: {extra:?}
+      seed: {}",
+            self.expected, self.seed
+        )
+    }
+}
+
+/// Shuffles the given values with the Fisher-Yates algorithm.
+///
+/// The random numbers are generated by a `SplitMix64` generator initialized
+/// with the given seed, so the same seed always results in the same order on
+/// every platform.
+pub fn shuffle<T>(mut values: Vec<T>, seed: u64) -> Vec<T> {
+    let mut state = seed;
+    let mut next_random = || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+    for index in (1..values.len()).rev() {
+        #[allow(clippy::cast_possible_truncation)]
+        let other = (next_random() % (index as u64 + 1)) as usize;
+        values.swap(index, other);
+    }
+    values
+}
+
+/// Finds the indices of the expected values that are missing in the subject
+/// and the indices of the subject's values that are not expected, regardless
+/// of the order of the values.
+///
+/// Returns true if no values are missing and no extra values are found.
+fn find_missing_and_extra_values<T, E>(
+    subject: &[T],
+    expected: &[E],
+    missing: &mut HashSet<usize>,
+    extra: &mut HashSet<usize>,
+) -> bool
+where
+    T: PartialEq<E>,
+{
+    *extra = (0..subject.len()).collect();
+
+    let mut subject_values = subject.iter().enumerate().collect::<Vec<_>>();
+    for (expected_index, expected) in expected.iter().enumerate() {
+        if let Some(index) = subject_values
+            .iter()
+            .position(|(_, value)| *value == expected)
+        {
+            let (subject_index, _) = subject_values.remove(index);
+            extra.remove(&subject_index);
+        } else {
+            missing.insert(expected_index);
+        }
+    }
+
+    extra.is_empty() && missing.is_empty()
+}
+
 impl<T, E> Expectation<Vec<T>> for IteratorContainsAnyOf<E>
 where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_any_of";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         for expected in &self.expected {
             if subject.iter().any(|value| value == expected) {
@@ -269,6 +367,8 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_all_of";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         let missing = &mut self.missing;
 
@@ -315,6 +415,8 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_only";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         let extra = &mut self.extra;
 
@@ -361,6 +463,8 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_only_once";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         let extra = &mut self.extra;
         let duplicates = &mut self.duplicates;
@@ -433,8 +537,13 @@ where
     type Sequence = Spec<'a, Vec<T>, R>;
 
     fn contains_exactly(self, expected: E) -> Self::Sequence {
-        self.mapping(Vec::from_iter)
-            .expecting(iterator_contains_exactly(expected))
+        if self.ignores_order() {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_contains_exactly_in_any_order(expected))
+        } else {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_contains_exactly(expected))
+        }
     }
 
     fn contains_sequence(self, expected: E) -> Self::Sequence {
@@ -443,18 +552,55 @@ where
     }
 
     fn contains_all_in_order(self, expected: E) -> Self::Sequence {
-        self.mapping(Vec::from_iter)
-            .expecting(iterator_contains_all_in_order(expected))
+        if self.ignores_order() {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_contains_all_of(expected))
+        } else {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_contains_all_in_order(expected))
+        }
     }
 
     fn starts_with(self, expected: E) -> Self::Sequence {
-        self.mapping(Vec::from_iter)
-            .expecting(iterator_starts_with(expected))
+        if self.ignores_order() {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_starts_with_in_any_order(expected))
+        } else {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_starts_with(expected))
+        }
     }
 
     fn ends_with(self, expected: E) -> Self::Sequence {
-        self.mapping(Vec::from_iter)
-            .expecting(iterator_ends_with(expected))
+        if self.ignores_order() {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_ends_with_in_any_order(expected))
+        } else {
+            self.mapping(Vec::from_iter)
+                .expecting(iterator_ends_with(expected))
+        }
+    }
+}
+
+impl<S, T, E, R> AssertSequence<E> for Spec<'_, S, R>
+where
+    S: SequenceProperty<Item = T>,
+    E: IntoIterator,
+    <E as IntoIterator>::IntoIter: DefinedOrderProperty,
+    <E as IntoIterator>::Item: Debug,
+    T: PartialEq<<E as IntoIterator>::Item> + Debug,
+    R: FailingStrategy,
+{
+    fn contains_sequence(self, expected: E) -> Self {
+        self.expecting(iterator_contains_sequence(expected))
+    }
+
+    fn starts_with(self, expected: E) -> Self {
+        self.expecting(iterator_starts_with(expected))
+    }
+
+    fn ends_with(self, expected: E) -> Self {
+        self.expecting(iterator_ends_with(expected))
     }
 }
 
@@ -463,6 +609,8 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_exactly";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         let mut maybe_extras = Vec::new();
         let mut maybe_missing = Vec::new();
@@ -532,13 +680,22 @@ where
         let missing = collect_selected_values(&self.missing, &self.expected);
         let extra = collect_selected_values(&self.extra, actual);
 
+        let mut replaced = self.extra.intersection(&self.missing).collect::<Vec<_>>();
+        replaced.sort();
+        let differences = replaced
+            .into_iter()
+            .map(|&index| element_differences(index, &actual[index], &self.expected[index]))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|differences| differences.into_iter().flatten().collect());
+        let differences = format_differences(&differences, format);
+
         format!(
             r"expected {expression} to contain exactly in order {:?}
        but was: {marked_actual}
       expected: {marked_expected}
        missing: {missing:?}
          extra: {extra:?}
-  out-of-order: {out_of_order:?}",
+  out-of-order: {out_of_order:?}{differences}",
             self.expected,
         )
     }
@@ -549,65 +706,15 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_sequence";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
-        let subject_length = subject.len();
-        let sequence_length = self.expected.len();
-        let possible_sequence_starts = if sequence_length >= subject_length {
-            vec![0]
-        } else {
-            (0..=subject_length - sequence_length).collect()
-        };
-        let best_missing = &mut self.missing;
-        let best_extra = &mut self.extra;
-        let mut best_match_count = 0;
-        let mut missing = HashSet::new();
-        let mut extra = HashSet::new();
-        let mut match_count = 0;
-        for start_index in possible_sequence_starts {
-            let mut expected_iter = self.expected.iter().enumerate();
-            let mut subject_iter = subject.iter().enumerate().skip(start_index);
-            loop {
-                match (expected_iter.next(), subject_iter.next()) {
-                    (
-                        Some((expected_index, expected_value)),
-                        Some((subject_index, actual_value)),
-                    ) => {
-                        if actual_value == expected_value {
-                            match_count += 1;
-                            continue;
-                        }
-                        missing.insert(expected_index);
-                        extra.insert(subject_index);
-                    },
-                    (Some((expected_index, _)), None) => {
-                        missing.insert(expected_index);
-                    },
-                    (None, _) => break,
-                }
-            }
-            if missing.is_empty() && extra.is_empty() {
-                *best_missing = HashSet::new();
-                *best_extra = HashSet::new();
-                return true;
-            }
-            match match_count.cmp(&best_match_count) {
-                Ordering::Less => {
-                    missing.clear();
-                    extra.clear();
-                },
-                Ordering::Equal => {
-                    best_missing.extend(mem::replace(&mut missing, HashSet::new()));
-                    best_extra.extend(mem::replace(&mut extra, HashSet::new()));
-                },
-                Ordering::Greater => {
-                    best_match_count = match_count;
-                    *best_missing = mem::replace(&mut missing, HashSet::new());
-                    *best_extra = mem::replace(&mut extra, HashSet::new());
-                },
-            }
-            match_count = 0;
-        }
-        false
+        test_contains_sequence(
+            || subject.iter(),
+            &self.expected,
+            &mut self.missing,
+            &mut self.extra,
+        )
     }
 
     fn message(
@@ -617,22 +724,145 @@ where
         _inverted: bool,
         format: &DiffFormat,
     ) -> String {
-        let marked_actual =
-            mark_selected_items_in_collection(actual, &self.extra, format, mark_unexpected);
-        let marked_expected =
-            mark_selected_items_in_collection(&self.expected, &self.missing, format, mark_missing);
-        let missing = collect_selected_values(&self.missing, &self.expected);
-        let extra = collect_selected_values(&self.extra, actual);
+        sequence_message(
+            expression,
+            "contain the sequence",
+            actual,
+            &self.expected,
+            &self.missing,
+            &self.extra,
+            format,
+        )
+    }
+}
 
-        format!(
-            r"expected {expression} to contain the sequence {:?}
+impl<S, T, E> Expectation<S> for IteratorContainsSequence<E>
+where
+    S: SequenceProperty<Item = T>,
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::contains_sequence";
+
+    fn test(&mut self, subject: &S) -> bool {
+        test_contains_sequence(
+            || subject.sequence_property(),
+            &self.expected,
+            &mut self.missing,
+            &mut self.extra,
+        )
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        sequence_message(
+            expression,
+            "contain the sequence",
+            &actual.sequence_property().collect::<Vec<_>>(),
+            &self.expected,
+            &self.missing,
+            &self.extra,
+            format,
+        )
+    }
+}
+
+fn test_contains_sequence<'a, T, E, I>(
+    subject: impl Fn() -> I,
+    expected: &[E],
+    best_missing: &mut HashSet<usize>,
+    best_extra: &mut HashSet<usize>,
+) -> bool
+where
+    T: PartialEq<E> + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    let subject_length = subject().count();
+    let sequence_length = expected.len();
+    let possible_sequence_starts = if sequence_length >= subject_length {
+        0..=0
+    } else {
+        0..=subject_length - sequence_length
+    };
+    let mut best_match_count = 0;
+    let mut missing = HashSet::new();
+    let mut extra = HashSet::new();
+    let mut match_count = 0;
+    for start_index in possible_sequence_starts {
+        let mut expected_iter = expected.iter().enumerate();
+        let mut subject_iter = subject().enumerate().skip(start_index);
+        loop {
+            match (expected_iter.next(), subject_iter.next()) {
+                (Some((expected_index, expected_value)), Some((subject_index, actual_value))) => {
+                    if actual_value == expected_value {
+                        match_count += 1;
+                        continue;
+                    }
+                    missing.insert(expected_index);
+                    extra.insert(subject_index);
+                },
+                (Some((expected_index, _)), None) => {
+                    missing.insert(expected_index);
+                },
+                (None, _) => break,
+            }
+        }
+        if missing.is_empty() && extra.is_empty() {
+            *best_missing = HashSet::new();
+            *best_extra = HashSet::new();
+            return true;
+        }
+        match match_count.cmp(&best_match_count) {
+            Ordering::Less => {
+                missing.clear();
+                extra.clear();
+            },
+            Ordering::Equal => {
+                best_missing.extend(mem::replace(&mut missing, HashSet::new()));
+                best_extra.extend(mem::replace(&mut extra, HashSet::new()));
+            },
+            Ordering::Greater => {
+                best_match_count = match_count;
+                *best_missing = mem::replace(&mut missing, HashSet::new());
+                *best_extra = mem::replace(&mut extra, HashSet::new());
+            },
+        }
+        match_count = 0;
+    }
+    false
+}
+
+fn sequence_message<A, E>(
+    expression: &Expression<'_>,
+    assertion: &str,
+    actual: &[A],
+    expected: &[E],
+    missing: &HashSet<usize>,
+    extra: &HashSet<usize>,
+    format: &DiffFormat,
+) -> String
+where
+    A: Debug,
+    E: Debug,
+{
+    let marked_actual = mark_selected_items_in_collection(actual, extra, format, mark_unexpected);
+    let marked_expected =
+        mark_selected_items_in_collection(expected, missing, format, mark_missing);
+    let missing = collect_selected_values(missing, expected);
+    let extra = collect_selected_values(extra, actual);
+
+    format!(
+        r"expected {expression} to {assertion} {expected:?}
    but was: {marked_actual}
   expected: {marked_expected}
    missing: {missing:?}
      extra: {extra:?}",
-            self.expected,
-        )
-    }
+    )
 }
 
 impl<T, E> Expectation<Vec<T>> for IteratorContainsAllInOrder<E>
@@ -640,6 +870,8 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_all_in_order";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         let missing = &mut self.missing;
         let mut last_match_index = 0;
@@ -684,27 +916,15 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::starts_with";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
-        let missing = &mut self.missing;
-        let extra = &mut self.extra;
-        let mut expected_iter = self.expected.iter().enumerate();
-        let mut subject_iter = subject.iter().enumerate();
-        loop {
-            match (expected_iter.next(), subject_iter.next()) {
-                (Some((expected_index, expected)), Some((subject_index, actual))) => {
-                    if actual == expected {
-                        continue;
-                    }
-                    missing.insert(expected_index);
-                    extra.insert(subject_index);
-                },
-                (Some((expected_index, _)), None) => {
-                    missing.insert(expected_index);
-                },
-                (None, _) => break,
-            }
-        }
-        extra.is_empty() && missing.is_empty()
+        test_starts_with(
+            subject.iter(),
+            &self.expected,
+            &mut self.missing,
+            &mut self.extra,
+        )
     }
 
     fn message(
@@ -714,87 +934,276 @@ where
         _inverted: bool,
         format: &DiffFormat,
     ) -> String {
-        let marked_actual =
-            mark_selected_items_in_collection(actual, &self.extra, format, mark_unexpected);
-        let marked_expected =
-            mark_selected_items_in_collection(&self.expected, &self.missing, format, mark_missing);
-        let missing = collect_selected_values(&self.missing, &self.expected);
-        let extra = collect_selected_values(&self.extra, actual);
-
-        format!(
-            r"expected {expression} to start with {:?}
-   but was: {marked_actual}
-  expected: {marked_expected}
-   missing: {missing:?}
-     extra: {extra:?}",
-            self.expected,
+        sequence_message(
+            expression,
+            "start with",
+            actual,
+            &self.expected,
+            &self.missing,
+            &self.extra,
+            format,
         )
     }
 }
 
-impl<T, E> Expectation<Vec<T>> for IteratorEndsWith<E>
+impl<S, T, E> Expectation<S> for IteratorStartsWith<E>
 where
+    S: SequenceProperty<Item = T>,
     T: PartialEq<E> + Debug,
     E: Debug,
 {
-    fn test(&mut self, subject: &Vec<T>) -> bool {
-        let missing = &mut self.missing;
-        let extra = &mut self.extra;
-        let mut expected_iter = self.expected.iter().enumerate().rev();
-        let mut subject_iter = subject.iter().enumerate().rev();
-        loop {
-            match (expected_iter.next(), subject_iter.next()) {
-                (Some((expected_index, expected)), Some((subject_index, actual))) => {
-                    if actual == expected {
-                        continue;
-                    }
-                    missing.insert(expected_index);
-                    extra.insert(subject_index);
-                },
-                (Some((expected_index, _)), None) => {
-                    missing.insert(expected_index);
-                },
-                (None, _) => break,
-            }
-        }
-        extra.is_empty() && missing.is_empty()
+    const ASSERTION_ID: &'static str = "asserting::starts_with";
+
+    fn test(&mut self, subject: &S) -> bool {
+        test_starts_with(
+            subject.sequence_property(),
+            &self.expected,
+            &mut self.missing,
+            &mut self.extra,
+        )
     }
 
     fn message(
         &self,
         expression: &Expression<'_>,
-        actual: &Vec<T>,
+        actual: &S,
         _inverted: bool,
         format: &DiffFormat,
     ) -> String {
-        let marked_actual =
-            mark_selected_items_in_collection(actual, &self.extra, format, mark_unexpected);
-        let marked_expected =
-            mark_selected_items_in_collection(&self.expected, &self.missing, format, mark_missing);
-        let missing = collect_selected_values(&self.missing, &self.expected);
-        let extra = collect_selected_values(&self.extra, actual);
-
-        format!(
-            r"expected {expression} to end with {:?}
-   but was: {marked_actual}
-  expected: {marked_expected}
-   missing: {missing:?}
-     extra: {extra:?}",
-            self.expected,
+        sequence_message(
+            expression,
+            "start with",
+            &actual.sequence_property().collect::<Vec<_>>(),
+            &self.expected,
+            &self.missing,
+            &self.extra,
+            format,
         )
     }
 }
 
-impl<'a, S, T, R> AssertFilteredElements<T> for Spec<'a, S, R>
+fn test_starts_with<'a, T, E>(
+    subject: impl Iterator<Item = &'a T>,
+    expected: &[E],
+    missing: &mut HashSet<usize>,
+    extra: &mut HashSet<usize>,
+) -> bool
 where
-    S: IntoIterator<Item = T>,
-    T: Debug,
-    R: FailingStrategy,
+    T: PartialEq<E> + 'a,
 {
-    type SingleElement = Spec<'a, T, R>;
-    type MultipleElements = Spec<'a, Vec<T>, R>;
+    let mut expected_iter = expected.iter().enumerate();
+    let mut subject_iter = subject.enumerate();
+    loop {
+        match (expected_iter.next(), subject_iter.next()) {
+            (Some((expected_index, expected)), Some((subject_index, actual))) => {
+                if actual == expected {
+                    continue;
+                }
+                missing.insert(expected_index);
+                extra.insert(subject_index);
+            },
+            (Some((expected_index, _)), None) => {
+                missing.insert(expected_index);
+            },
+            (None, _) => break,
+        }
+    }
+    extra.is_empty() && missing.is_empty()
+}
 
-    fn single_element(self) -> Self::SingleElement {
+impl<T, E> Expectation<Vec<T>> for IteratorEndsWith<E>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::ends_with";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        test_ends_with(
+            || subject.iter(),
+            &self.expected,
+            &mut self.missing,
+            &mut self.extra,
+        )
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        sequence_message(
+            expression,
+            "end with",
+            actual,
+            &self.expected,
+            &self.missing,
+            &self.extra,
+            format,
+        )
+    }
+}
+
+impl<S, T, E> Expectation<S> for IteratorEndsWith<E>
+where
+    S: SequenceProperty<Item = T>,
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::ends_with";
+
+    fn test(&mut self, subject: &S) -> bool {
+        test_ends_with(
+            || subject.sequence_property(),
+            &self.expected,
+            &mut self.missing,
+            &mut self.extra,
+        )
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        sequence_message(
+            expression,
+            "end with",
+            &actual.sequence_property().collect::<Vec<_>>(),
+            &self.expected,
+            &self.missing,
+            &self.extra,
+            format,
+        )
+    }
+}
+
+fn test_ends_with<'a, T, E, I>(
+    subject: impl Fn() -> I,
+    expected: &[E],
+    missing: &mut HashSet<usize>,
+    extra: &mut HashSet<usize>,
+) -> bool
+where
+    T: PartialEq<E> + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    let subject_length = subject().count();
+    let start_index = subject_length.saturating_sub(expected.len());
+    let expected_start_index = expected.len().saturating_sub(subject_length);
+    missing.extend(0..expected_start_index);
+    let expected_iter = expected.iter().enumerate().skip(expected_start_index);
+    let subject_iter = subject().enumerate().skip(start_index);
+    for ((expected_index, expected), (subject_index, actual)) in expected_iter.zip(subject_iter) {
+        if actual != expected {
+            missing.insert(expected_index);
+            extra.insert(subject_index);
+        }
+    }
+    extra.is_empty() && missing.is_empty()
+}
+
+impl<T, E> Expectation<Vec<T>> for IteratorStartsWithInAnyOrder<E>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::starts_with";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        let length = self.expected.len().min(subject.len());
+        find_missing_and_extra_values(
+            &subject[..length],
+            &self.expected,
+            &mut self.missing,
+            &mut self.extra,
+        )
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let marked_actual =
+            mark_selected_items_in_collection(actual, &self.extra, format, mark_unexpected);
+        let marked_expected =
+            mark_selected_items_in_collection(&self.expected, &self.missing, format, mark_missing);
+        let missing = collect_selected_values(&self.missing, &self.expected);
+        let extra = collect_selected_values(&self.extra, actual);
+
+        format!(
+            r"expected {expression} to start with {:?} in any order
+   but was: {marked_actual}
+  expected: {marked_expected}
+   missing: {missing:?}
+     extra: {extra:?}",
+            self.expected,
+        )
+    }
+}
+
+impl<T, E> Expectation<Vec<T>> for IteratorEndsWithInAnyOrder<E>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::ends_with";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        let offset = subject.len().saturating_sub(self.expected.len());
+        let mut extra = HashSet::new();
+        let ends_with = find_missing_and_extra_values(
+            &subject[offset..],
+            &self.expected,
+            &mut self.missing,
+            &mut extra,
+        );
+        self.extra = extra.into_iter().map(|index| index + offset).collect();
+        ends_with
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let marked_actual =
+            mark_selected_items_in_collection(actual, &self.extra, format, mark_unexpected);
+        let marked_expected =
+            mark_selected_items_in_collection(&self.expected, &self.missing, format, mark_missing);
+        let missing = collect_selected_values(&self.missing, &self.expected);
+        let extra = collect_selected_values(&self.extra, actual);
+
+        format!(
+            r"expected {expression} to end with {:?} in any order
+   but was: {marked_actual}
+  expected: {marked_expected}
+   missing: {missing:?}
+     extra: {extra:?}",
+            self.expected,
+        )
+    }
+}
+
+impl<'a, S, T, R> AssertFilteredElements<T> for Spec<'a, S, R>
+where
+    S: IntoIterator<Item = T>,
+    T: Debug,
+    R: FailingStrategy,
+{
+    type SingleElement = Spec<'a, T, R>;
+    type MultipleElements = Spec<'a, Vec<T>, R>;
+
+    fn single_element(self) -> Self::SingleElement {
         let spec = self.mapping(Vec::from_iter).expecting(has_single_element());
         if spec.has_failures() {
             PanicOnFail.do_fail_with(&spec.failures());
@@ -817,6 +1226,16 @@ where
         self.mapping(|subject| subject.into_iter().filter(condition).collect())
     }
 
+    fn filtering<P>(self, predicate: P) -> Self::MultipleElements
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let original_expression = self.expression();
+        let new_expression = format!("{original_expression} filtered on the given predicate");
+        self.mapping(|subject| subject.into_iter().filter(predicate).collect())
+            .named(new_expression)
+    }
+
     fn any_satisfies<P>(self, predicate: P) -> Self::MultipleElements
     where
         P: FnMut(&T) -> bool,
@@ -846,6 +1265,8 @@ impl<T> Expectation<Vec<T>> for HasSingleElement
 where
     T: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::single_element";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         subject.len() == 1
     }
@@ -876,6 +1297,8 @@ where
     T: Debug,
     P: FnMut(&T) -> bool,
 {
+    const ASSERTION_ID: &'static str = "asserting::any_satisfies";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         subject.iter().any(|e| (self.predicate)(e))
     }
@@ -899,6 +1322,8 @@ where
     T: Debug,
     P: FnMut(&T) -> bool,
 {
+    const ASSERTION_ID: &'static str = "asserting::all_satisfy";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         for (i, e) in subject.iter().enumerate() {
             if !(self.predicate)(e) {
@@ -932,6 +1357,8 @@ where
     T: Debug,
     P: FnMut(&T) -> bool,
 {
+    const ASSERTION_ID: &'static str = "asserting::none_satisfies";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         for (i, e) in subject.iter().enumerate() {
             if (self.predicate)(e) {
@@ -960,6 +1387,274 @@ where
     }
 }
 
+impl<'a, S, T, R> AssertMonotonicity for Spec<'a, S, R>
+where
+    S: IntoIterator<Item = T>,
+    <S as IntoIterator>::IntoIter: DefinedOrderProperty,
+    T: PartialOrd + Debug,
+    R: FailingStrategy,
+{
+    type Sequence = Spec<'a, Vec<T>, R>;
+
+    fn is_strictly_increasing(self) -> Self::Sequence {
+        self.mapping(Vec::from_iter)
+            .expecting(is_strictly_increasing())
+    }
+
+    fn is_strictly_decreasing(self) -> Self::Sequence {
+        self.mapping(Vec::from_iter)
+            .expecting(is_strictly_decreasing())
+    }
+
+    fn is_non_decreasing(self) -> Self::Sequence {
+        self.mapping(Vec::from_iter).expecting(is_non_decreasing())
+    }
+
+    fn is_non_increasing(self) -> Self::Sequence {
+        self.mapping(Vec::from_iter).expecting(is_non_increasing())
+    }
+}
+
+impl<T> Expectation<Vec<T>> for IsStrictlyIncreasing
+where
+    T: PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_strictly_increasing";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        self.violation = find_first_violating_pair(subject, |left, right| left < right);
+        self.violation.is_none()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        unordered_pair_message(
+            expression,
+            "strictly increasing",
+            actual,
+            self.violation,
+            format,
+        )
+    }
+}
+
+impl<T> Expectation<Vec<T>> for IsStrictlyDecreasing
+where
+    T: PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_strictly_decreasing";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        self.violation = find_first_violating_pair(subject, |left, right| left > right);
+        self.violation.is_none()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        unordered_pair_message(
+            expression,
+            "strictly decreasing",
+            actual,
+            self.violation,
+            format,
+        )
+    }
+}
+
+impl<T> Expectation<Vec<T>> for IsNonDecreasing
+where
+    T: PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_non_decreasing";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        self.violation = find_first_violating_pair(subject, |left, right| left <= right);
+        self.violation.is_none()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        unordered_pair_message(expression, "non-decreasing", actual, self.violation, format)
+    }
+}
+
+impl<T> Expectation<Vec<T>> for IsNonIncreasing
+where
+    T: PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_non_increasing";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        self.violation = find_first_violating_pair(subject, |left, right| left >= right);
+        self.violation.is_none()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        unordered_pair_message(expression, "non-increasing", actual, self.violation, format)
+    }
+}
+
+/// Returns the index of the first element of the first pair of adjacent
+/// elements that are not in the order checked by the given function.
+fn find_first_violating_pair<T>(
+    collection: &[T],
+    is_in_order: impl Fn(&T, &T) -> bool,
+) -> Option<usize> {
+    collection
+        .windows(2)
+        .position(|pair| !is_in_order(&pair[0], &pair[1]))
+}
+
+fn unordered_pair_message<T>(
+    expression: &Expression<'_>,
+    expected_order: &str,
+    actual: &[T],
+    violation: Option<usize>,
+    format: &DiffFormat,
+) -> String
+where
+    T: Debug,
+{
+    let Some(index) = violation else {
+        return format!("expected {expression} to be {expected_order}, but it was");
+    };
+    let next_index = index + 1;
+    let violating_pair = HashSet::from([index, next_index]);
+    let failing = collect_selected_values(&violating_pair, actual);
+    let marked_actual =
+        mark_selected_items_in_collection(actual, &violating_pair, format, mark_unexpected);
+    format!(
+        r"expected {expression} to be {expected_order}, but the elements at index {index} and {next_index} are not
+   actual: {marked_actual}
+  failing: {failing:?}"
+    )
+}
+
+impl<'a, S, T, R> AssertIsSortedBy<T> for Spec<'a, S, R>
+where
+    S: IntoIterator<Item = T>,
+    <S as IntoIterator>::IntoIter: DefinedOrderProperty,
+    T: Debug,
+    R: FailingStrategy,
+{
+    type Sequence = Spec<'a, Vec<T>, R>;
+
+    fn is_sorted_by<C>(self, compare: C) -> Self::Sequence
+    where
+        C: FnMut(&T, &T) -> bool,
+    {
+        self.mapping(Vec::from_iter)
+            .expecting(is_sorted_by(compare))
+    }
+
+    fn is_sorted_by_key<K, F>(self, key: F) -> Self::Sequence
+    where
+        F: FnMut(&T) -> K,
+        K: PartialOrd + Debug,
+    {
+        self.mapping(Vec::from_iter)
+            .expecting(is_sorted_by_key(key))
+    }
+}
+
+impl<T, C> Expectation<Vec<T>> for IsSortedBy<C>
+where
+    T: Debug,
+    C: FnMut(&T, &T) -> bool,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_sorted_by";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        self.violation = subject
+            .windows(2)
+            .position(|pair| !(self.compare)(&pair[0], &pair[1]));
+        self.violation.is_none()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        unordered_pair_message(
+            expression,
+            "sorted by the given comparison",
+            actual,
+            self.violation,
+            format,
+        )
+    }
+}
+
+impl<T, F, K> Expectation<Vec<T>> for IsSortedByKey<F, K>
+where
+    T: Debug,
+    F: FnMut(&T) -> K,
+    K: PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_sorted_by_key";
+
+    fn test(&mut self, subject: &Vec<T>) -> bool {
+        self.violation = None;
+        let mut keys = subject.iter().map(&mut self.key).enumerate();
+        let Some((_, mut previous_key)) = keys.next() else {
+            return true;
+        };
+        for (index, key) in keys {
+            let is_in_order = previous_key <= key;
+            if !is_in_order {
+                self.violation = Some((index - 1, previous_key, key));
+                return false;
+            }
+            previous_key = key;
+        }
+        true
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let message = unordered_pair_message(
+            expression,
+            "sorted by key",
+            actual,
+            self.violation.as_ref().map(|(index, _, _)| *index),
+            format,
+        );
+        match &self.violation {
+            Some((_, key, next_key)) => format!("{message}\n     keys: [{key:?}, {next_key:?}]"),
+            None => message,
+        }
+    }
+}
+
 impl<'a, S, T, R> AssertOrderedElements for Spec<'a, S, R>
 where
     S: IntoIterator<Item = T>,
@@ -1124,6 +1819,8 @@ impl<T> Expectation<Vec<T>> for HasAtLeastNumberOfElements
 where
     T: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_at_least_number_of_elements";
+
     fn test(&mut self, subject: &Vec<T>) -> bool {
         subject.len() >= self.expected_number_of_elements
     }
@@ -1156,6 +1853,147 @@ where
     }
 }
 
+impl<'a, S, R> AssertIteratorCount for Spec<'a, S, R>
+where
+    S: IntoIterator,
+    R: FailingStrategy,
+{
+    type Counted = Spec<'a, (), R>;
+
+    fn has_count(self, expected_count: usize) -> Self::Counted {
+        self.mapping(|subject| count_elements_up_to(subject, expected_count.saturating_add(1)))
+            .expecting(has_count(expected_count))
+            .mapping(|_| ())
+    }
+
+    fn has_count_at_least(self, expected_count: usize) -> Self::Counted {
+        self.mapping(|subject| count_elements_up_to(subject, expected_count))
+            .expecting(has_count_at_least(expected_count))
+            .mapping(|_| ())
+    }
+
+    fn has_count_in_range<U>(self, expected_range: U) -> Self::Counted
+    where
+        U: RangeBounds<usize> + Debug,
+    {
+        let limit = count_limit_for_range(&expected_range);
+        self.mapping(|subject| count_elements_up_to(subject, limit))
+            .expecting(has_count_in_range(expected_range))
+            .mapping(|_| ())
+    }
+}
+
+/// Counts the elements yielded by the given iterator, but stops counting
+/// when the given limit is reached.
+pub fn count_elements_up_to(iterator: impl IntoIterator, limit: usize) -> usize {
+    iterator.into_iter().take(limit).count()
+}
+
+/// Returns the number of elements that have to be counted to decide whether
+/// the number of elements is within the given range.
+pub fn count_limit_for_range(range: &impl RangeBounds<usize>) -> usize {
+    match range.end_bound() {
+        Bound::Included(end) => end.saturating_add(1),
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        },
+    }
+}
+
+fn describe_number_of_elements(count: usize) -> String {
+    match count {
+        0 => "no elements".to_string(),
+        1 => "one element".to_string(),
+        _ => format!("{count} elements"),
+    }
+}
+
+impl Expectation<usize> for HasCount {
+    const ASSERTION_ID: &'static str = "asserting::has_count";
+
+    fn test(&mut self, subject: &usize) -> bool {
+        *subject == self.expected_count
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &usize,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual_elements = if *actual > self.expected_count {
+            format!("at least {}", describe_number_of_elements(*actual))
+        } else {
+            describe_number_of_elements(*actual)
+        };
+        let marked_actual = mark_unexpected_string(&actual_elements, format);
+        let marked_expected =
+            mark_missing_string(&describe_number_of_elements(self.expected_count), format);
+        format!("expected {expression} to have {marked_expected}, but has {marked_actual}")
+    }
+}
+
+impl Expectation<usize> for HasCountAtLeast {
+    const ASSERTION_ID: &'static str = "asserting::has_count_at_least";
+
+    fn test(&mut self, subject: &usize) -> bool {
+        *subject >= self.expected_count
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &usize,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let marked_actual = mark_unexpected_string(&describe_number_of_elements(*actual), format);
+        let marked_expected = mark_missing_string(
+            &format!(
+                "at least {}",
+                describe_number_of_elements(self.expected_count)
+            ),
+            format,
+        );
+        format!("expected {expression} to have {marked_expected}, but has {marked_actual}")
+    }
+}
+
+impl<U> Expectation<usize> for HasCountInRange<U>
+where
+    U: RangeBounds<usize> + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_count_in_range";
+
+    fn test(&mut self, subject: &usize) -> bool {
+        self.expected_range.contains(subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &usize,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual_elements =
+            if *actual > 0 && *actual == count_limit_for_range(&self.expected_range) {
+                format!("at least {}", describe_number_of_elements(*actual))
+            } else {
+                describe_number_of_elements(*actual)
+            };
+        let marked_actual = mark_unexpected_string(&actual_elements, format);
+        let marked_expected = mark_missing(&self.expected_range, format);
+        format!(
+            "expected {expression} to have a number of elements within range {marked_expected}, but has {marked_actual}"
+        )
+    }
+}
+
 pub fn collect_selected_values<'a, T>(indices: &HashSet<usize>, collection: &'a [T]) -> Vec<&'a T> {
     collection
         .iter()
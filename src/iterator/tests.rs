@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::std::boxed::Box;
 use crate::std::string::String;
 use crate::std::{vec, vec::Vec};
 
@@ -232,6 +233,354 @@ fn verify_custom_iterator_does_not_contain_fails() {
     );
 }
 
+fn boxed_primes() -> Box<dyn Iterator<Item = i32>> {
+    Box::new(vec![2, 3, 5, 7, 11, 13].into_iter())
+}
+
+#[test]
+fn boxed_dyn_iterator_contains() {
+    assert_that(boxed_primes()).contains(7).contains(13);
+}
+
+#[test]
+fn boxed_dyn_iterator_contains_exactly() {
+    assert_that(boxed_primes()).contains_exactly([2, 3, 5, 7, 11, 13]);
+}
+
+#[test]
+fn boxed_dyn_iterator_starts_with_and_ends_with() {
+    assert_that(boxed_primes())
+        .starts_with([2, 3, 5])
+        .ends_with([7, 11, 13]);
+}
+
+#[test]
+fn boxed_dyn_iterator_first_and_last_element() {
+    assert_that(boxed_primes()).first_element().is_equal_to(2);
+    assert_that(boxed_primes()).last_element().is_equal_to(13);
+}
+
+#[test]
+fn boxed_send_dyn_iterator_contains_exactly() {
+    let subject: Box<dyn Iterator<Item = char> + Send> = Box::new("abc".chars());
+
+    assert_that(subject).contains_exactly(['a', 'b', 'c']);
+}
+
+#[test]
+fn verify_boxed_dyn_iterator_contains_exactly_fails() {
+    let failures = verify_that(boxed_primes())
+        .named("my_iterator")
+        .contains_exactly([2, 3, 5, 7, 11, 17])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_iterator to contain exactly in order [2, 3, 5, 7, 11, 17]
+       but was: [2, 3, 5, 7, 11, 13]
+      expected: [2, 3, 5, 7, 11, 17]
+       missing: [17]
+         extra: [13]
+  out-of-order: []
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_vec_of_structs_contains_exactly_fails_with_field_level_differences() {
+    #[derive(Debug, PartialEq)]
+    struct Item {
+        sku: &'static str,
+        quantity: u32,
+    }
+
+    let subject = vec![
+        Item {
+            sku: "A-1",
+            quantity: 2,
+        },
+        Item {
+            sku: "B-2",
+            quantity: 5,
+        },
+    ];
+
+    let failures = verify_that(subject)
+        .named("order_items")
+        .contains_exactly([
+            Item {
+                sku: "A-1",
+                quantity: 2,
+            },
+            Item {
+                sku: "B-2",
+                quantity: 3,
+            },
+        ])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected order_items to contain exactly in order [Item { sku: "A-1", quantity: 2 }, Item { sku: "B-2", quantity: 3 }]
+       but was: [Item { sku: "A-1", quantity: 2 }, Item { sku: "B-2", quantity: 5 }]
+      expected: [Item { sku: "A-1", quantity: 2 }, Item { sku: "B-2", quantity: 3 }]
+       missing: [Item { sku: "B-2", quantity: 3 }]
+         extra: [Item { sku: "B-2", quantity: 5 }]
+  out-of-order: []
+  differences:
+    [1].quantity: but was 5, expected 3
+"#
+        ]
+    );
+}
+
+#[test]
+fn mutable_reference_to_iterator_contains_exactly_the_remaining_elements() {
+    let mut iterator = vec![1, 2, 3, 4].into_iter();
+    _ = iterator.next();
+
+    assert_that(&mut iterator).contains_exactly([2, 3, 4]);
+}
+
+#[test]
+fn mutable_reference_to_boxed_dyn_iterator_starts_with() {
+    let mut iterator = boxed_primes();
+
+    assert_that(&mut iterator).starts_with([2, 3]);
+}
+
+mod count {
+    use super::*;
+
+    #[test]
+    fn filtered_iterator_has_count() {
+        let subject = (1..=20).filter(|n| n % 3 == 0);
+
+        assert_that(subject).has_count(6);
+    }
+
+    #[test]
+    fn infinite_iterator_has_count_at_least() {
+        assert_that(1..).has_count_at_least(1_000);
+    }
+
+    #[test]
+    fn infinite_iterator_fails_has_count_in_range_without_consuming_all_elements() {
+        let failures = verify_that(1..)
+            .named("numbers")
+            .has_count_in_range(..=5)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected numbers to have a number of elements within range ..=5, but has at least 6 elements\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn iterator_has_count_in_range() {
+        let subject = "lorem ipsum dolor sit amet";
+
+        assert_that(subject.split(' ')).has_count_in_range(4..8);
+        assert_that(subject.split(' ')).has_count_in_range(5..=5);
+        assert_that(subject.split(' ')).has_count_in_range(5..);
+        assert_that(subject.split(' ')).has_count_in_range(..6);
+        assert_that(subject.split(' ')).has_count_in_range(..);
+    }
+
+    #[test]
+    fn empty_iterator_has_count_zero() {
+        assert_that(Vec::<i32>::new()).has_count(0);
+    }
+
+    #[test]
+    fn verify_iterator_has_count_fails_with_fewer_elements() {
+        let failures = verify_that([1, 2].into_iter())
+            .named("my_iter")
+            .has_count(3)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected my_iter to have 3 elements, but has 2 elements\n"]
+        );
+    }
+
+    #[test]
+    fn verify_iterator_has_count_fails_with_more_elements() {
+        let failures = verify_that(0..)
+            .named("my_iter")
+            .has_count(1)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected my_iter to have one element, but has at least 2 elements\n"]
+        );
+    }
+
+    #[test]
+    fn verify_iterator_has_count_at_least_fails() {
+        let failures = verify_that([7].into_iter())
+            .named("my_iter")
+            .has_count_at_least(2)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected my_iter to have at least 2 elements, but has one element\n"]
+        );
+    }
+
+    #[test]
+    fn verify_iterator_has_count_in_range_fails_with_too_few_elements() {
+        let failures = verify_that(Vec::<i32>::new())
+            .named("my_iter")
+            .has_count_in_range(1..)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected my_iter to have a number of elements within range 1.., but has no elements\n"
+            ]
+        );
+    }
+}
+
+mod sequence_property {
+    use super::*;
+    use crate::expectations::{
+        iterator_contains_sequence, iterator_ends_with, iterator_starts_with,
+    };
+
+    #[derive(Debug)]
+    struct Playlist {
+        titles: Vec<&'static str>,
+    }
+
+    impl SequenceProperty for Playlist {
+        type Item = &'static str;
+
+        fn sequence_property(&self) -> impl Iterator<Item = &Self::Item> {
+            self.titles.iter()
+        }
+    }
+
+    fn playlist() -> Playlist {
+        Playlist {
+            titles: vec!["intro", "verse", "chorus", "verse", "outro"],
+        }
+    }
+
+    #[test]
+    fn custom_container_starts_with_ends_with_and_contains_sequence() {
+        assert_that(playlist())
+            .starts_with(["intro", "verse"])
+            .ends_with(["verse", "outro"])
+            .contains_sequence(["chorus", "verse"]);
+    }
+
+    #[test]
+    fn borrowed_custom_container_starts_with_ends_with_and_contains_sequence() {
+        let playlist = playlist();
+
+        assert_that(&playlist)
+            .starts_with(["intro"])
+            .ends_with(["outro"])
+            .contains_sequence(["verse", "chorus", "verse"]);
+    }
+
+    #[test]
+    fn custom_container_is_asserted_with_sequence_expectations() {
+        assert_that(playlist())
+            .expecting(iterator_starts_with(["intro", "verse"]))
+            .expecting(iterator_ends_with(["verse", "outro"]))
+            .expecting(iterator_contains_sequence(["chorus", "verse"]));
+    }
+
+    #[test]
+    fn verify_custom_container_starts_with_fails() {
+        let failures = verify_that(playlist())
+            .named("playlist")
+            .starts_with(["intro", "chorus"])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected playlist to start with ["intro", "chorus"]
+   but was: ["intro", "verse", "chorus", "verse", "outro"]
+  expected: ["intro", "chorus"]
+   missing: ["chorus"]
+     extra: ["verse"]
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_custom_container_ends_with_fails() {
+        let failures = verify_that(playlist())
+            .named("playlist")
+            .ends_with(["chorus", "outro"])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected playlist to end with ["chorus", "outro"]
+   but was: ["intro", "verse", "chorus", "verse", "outro"]
+  expected: ["chorus", "outro"]
+   missing: ["chorus"]
+     extra: ["verse"]
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_custom_container_ends_with_more_items_than_it_contains_fails() {
+        let failures = verify_that(Playlist {
+            titles: vec!["intro", "outro"],
+        })
+        .named("playlist")
+        .ends_with(["intro", "verse", "outro"])
+        .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected playlist to end with ["intro", "verse", "outro"]
+   but was: ["intro", "outro"]
+  expected: ["intro", "verse", "outro"]
+   missing: ["intro", "verse"]
+     extra: ["intro"]
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_custom_container_contains_sequence_fails() {
+        let failures = verify_that(playlist())
+            .named("playlist")
+            .contains_sequence(["verse", "outro", "intro"])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r#"expected playlist to contain the sequence ["verse", "outro", "intro"]
+   but was: ["intro", "verse", "chorus", "verse", "outro"]
+  expected: ["verse", "outro", "intro"]
+   missing: ["outro", "intro"]
+     extra: ["chorus", "verse"]
+"#
+            ]
+        );
+    }
+}
+
 mod all_elements {
     use super::*;
 
@@ -334,6 +683,30 @@ mod all_elements {
         );
     }
 
+    #[test]
+    fn verify_nested_each_element_fails_with_the_full_index_path() {
+        let matrix = [[1, 2, 3], [4, -5, 6], [7, 8, -9]];
+
+        let failures = verify_that(matrix)
+            .named("matrix")
+            .each_element(|row| row.each_element(|cell| cell.is_positive()))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected matrix [1] [1] to be positive
+   but was: -5
+  expected: > 0
+",
+                r"expected matrix [2] [2] to be positive
+   but was: -9
+  expected: > 0
+",
+            ]
+        );
+    }
+
     #[test]
     fn assert_any_element_of_an_iterator_of_str() {
         let subject = ["one", "two", "three", "four", "five"];
@@ -425,6 +798,122 @@ mod all_elements {
     }
 }
 
+mod windows_and_chunks {
+    use super::*;
+
+    #[test]
+    fn assert_each_window_of_an_array() {
+        let subject = [3, 5, 8, 13, 21];
+
+        assert_that(&subject).each_window(2, |window| window.satisfies(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn assert_each_window_of_a_vec() {
+        let subject = vec![3, 5, 8, 13, 21];
+
+        assert_that(&subject).each_window(3, |window| window.satisfies(|w| w[0] + w[1] == w[2]));
+    }
+
+    #[test]
+    fn assert_each_window_of_a_slice_shorter_than_the_window_size() {
+        let subject: &[i32] = &[1, 2];
+
+        assert_that(subject).each_window(3, |window| window.has_length(3));
+    }
+
+    #[test]
+    fn assert_each_chunk_of_a_slice() {
+        let subject: &[u8] = &[0xca, 0xfe, 0xba, 0xbe, 0x00];
+
+        assert_that(subject).each_chunk(2, |chunk| chunk.has_length_in_range(1..=2));
+    }
+
+    #[test]
+    #[should_panic = "expected numbers window [2] to satisfy the given predicate, but returned false\n   context:\n     window: [8, 7]\n"]
+    fn assert_each_window_panics_if_one_assertion_fails() {
+        let subject = [3, 5, 8, 7, 21];
+
+        assert_that(&subject)
+            .named("numbers")
+            .each_window(2, |window| window.satisfies(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    #[should_panic = "window size must be non-zero"]
+    fn assert_each_window_with_size_zero_panics() {
+        let subject = [3, 5, 8];
+
+        assert_that(&subject).each_window(0, |window| window.is_not_empty());
+    }
+
+    #[test]
+    fn verify_each_window_fails() {
+        let subject = vec![1, 2, 3, 3, 2];
+
+        let failures = verify_that(&subject)
+            .named("numbers")
+            .each_window(2, |window| window.satisfies(|w| w[0] < w[1]))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected numbers window [2] to satisfy the given predicate, but returned false
+   context:
+     window: [3, 3]
+",
+                r"expected numbers window [3] to satisfy the given predicate, but returned false
+   context:
+     window: [3, 2]
+",
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_each_chunk_fails() {
+        let subject = [1, 2, 3, 4, 5];
+
+        let failures = verify_that(&subject)
+            .named("pairs")
+            .each_chunk(2, |chunk| chunk.has_length(2))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected pairs chunk [2] to have a length of 2
+   but was: 1
+  expected: 2
+   context:
+     chunk: [5]
+"]
+        );
+    }
+
+    #[test]
+    fn verify_each_chunk_keeps_the_context_of_the_subject() {
+        let subject = [1, 2, 3, 4, 5];
+
+        let failures = verify_that(&subject)
+            .named("pairs")
+            .with_context("size", 2)
+            .each_chunk(2, |chunk| chunk.has_length(2))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected pairs chunk [2] to have a length of 2
+   but was: 1
+  expected: 2
+   context:
+     size: 2
+     chunk: [5]
+"]
+        );
+    }
+}
+
 mod filtered_elements {
     use super::*;
 
@@ -493,7 +982,42 @@ mod filtered_elements {
     }
 
     #[test]
-    fn elements_at_positions_of_iterator() {
+    fn filtering_elements_of_iterator_even_elements() {
+        let subject = CustomCollection {
+            inner: vec![1, 2, 3, 4, 5],
+        };
+
+        assert_that(subject)
+            .filtering(|e| e & 1 == 0)
+            .has_length(2)
+            .each_element(|e| e.is_at_most(4));
+    }
+
+    #[test]
+    fn verify_filtering_elements_of_iterator_has_length_fails() {
+        let subject = CustomCollection {
+            inner: vec![1, 2, 3, 4, 5],
+        };
+
+        let failures = verify_that(subject)
+            .named("my_thing")
+            .filtering(|e| e & 1 == 0)
+            .has_length(3)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected my_thing filtered on the given predicate to have a length of 3
+   but was: 2
+  expected: 3
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn elements_at_positions_of_iterator() {
         let subject = CustomOrderedCollection {
             inner: vec!["one", "two", "three", "four", "five"],
         };
@@ -1115,3 +1639,513 @@ mod extracted_elements_ref {
         );
     }
 }
+
+mod comparing_elements_by {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct User {
+        id: u32,
+        name: &'static str,
+    }
+
+    fn user(id: u32, name: &'static str) -> User {
+        User { id, name }
+    }
+
+    #[test]
+    fn collection_of_non_partial_eq_elements_contains_exactly_expected_elements() {
+        let subject = vec![user(1, "alice"), user(2, "bob")];
+
+        assert_that(subject)
+            .comparing_elements_by(|a, b| a.id == b.id)
+            .contains_exactly([user(1, "Alice"), user(2, "Bob")]);
+    }
+
+    #[test]
+    fn collection_of_non_partial_eq_elements_contains_expected_element() {
+        let subject = vec![user(1, "alice"), user(2, "bob"), user(3, "charlie")];
+
+        assert_that(subject)
+            .comparing_elements_by(|a, b| a.name == b.name)
+            .contains(user(0, "bob"))
+            .does_not_contain(user(2, "dave"))
+            .contains_exactly_in_any_order([user(0, "charlie"), user(0, "alice"), user(0, "bob")])
+            .starts_with([user(0, "alice")])
+            .ends_with([user(0, "charlie")]);
+    }
+
+    #[test]
+    fn verify_collection_of_non_partial_eq_elements_contains_exactly_fails() {
+        let subject = vec![user(1, "alice"), user(2, "bob")];
+
+        let failures = verify_that(subject)
+            .named("users")
+            .comparing_elements_by(|a, b| a.id == b.id)
+            .contains_exactly([user(1, "alice"), user(3, "bob")])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r#"expected users to contain exactly in order [User { id: 1, name: "alice" }, User { id: 3, name: "bob" }]
+       but was: [User { id: 1, name: "alice" }, User { id: 2, name: "bob" }]
+      expected: [User { id: 1, name: "alice" }, User { id: 3, name: "bob" }]
+       missing: [User { id: 3, name: "bob" }]
+         extra: [User { id: 2, name: "bob" }]
+  out-of-order: []
+  differences:
+    [1].id: but was 2, expected 3
+"#
+            ]
+        );
+    }
+
+    #[test]
+    fn comparing_elements_of_extracted_collection_by_custom_equality() {
+        struct Team {
+            members: Vec<User>,
+        }
+
+        let subject = Team {
+            members: vec![user(1, "alice"), user(2, "bob")],
+        };
+
+        assert_that(subject)
+            .extracting_ref("members", |t| &t.members)
+            .comparing_elements_by(|a, b| a.id == b.id)
+            .contains_all_of([user(2, "Bob")]);
+    }
+}
+
+mod monotonic_elements {
+    use super::*;
+
+    #[test]
+    fn custom_ordered_collection_is_strictly_increasing() {
+        let subject = CustomOrderedCollection {
+            inner: vec![-7, 0, 3, 14, 15],
+        };
+
+        assert_that(subject).is_strictly_increasing();
+    }
+
+    #[test]
+    fn vec_of_floats_is_strictly_decreasing() {
+        let subject = vec![3.5, 2.25, 0.0, -1.5];
+
+        assert_that(subject).is_strictly_decreasing();
+    }
+
+    #[test]
+    fn slice_with_duplicates_is_non_decreasing() {
+        let subject = &[1, 1, 2, 3, 3, 3, 8][..];
+
+        assert_that(subject).is_non_decreasing();
+    }
+
+    #[test]
+    fn array_with_duplicates_is_non_increasing() {
+        let subject = [8, 3, 3, 3, 2, 1, 1];
+
+        assert_that(subject).is_non_increasing();
+    }
+
+    #[test]
+    fn empty_and_single_element_collections_are_monotonic() {
+        assert_that(Vec::<i32>::new())
+            .is_strictly_increasing()
+            .is_strictly_decreasing();
+        assert_that(vec![42])
+            .is_non_decreasing()
+            .is_non_increasing();
+    }
+
+    #[test]
+    fn verify_vec_is_strictly_increasing_fails() {
+        let subject = vec![1, 3, 5, 5, 7];
+
+        let failures = verify_that(subject)
+            .named("my_numbers")
+            .is_strictly_increasing()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected my_numbers to be strictly increasing, but the elements at index 2 and 3 are not
+   actual: [1, 3, 5, 5, 7]
+  failing: [5, 5]
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_vec_is_strictly_decreasing_fails() {
+        let subject = vec![9, 7, 8, 2, 4];
+
+        let failures = verify_that(subject)
+            .named("my_numbers")
+            .is_strictly_decreasing()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected my_numbers to be strictly decreasing, but the elements at index 1 and 2 are not
+   actual: [9, 7, 8, 2, 4]
+  failing: [7, 8]
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_vec_is_non_decreasing_fails() {
+        let subject = vec![1, 1, 2, 3, 0, 5];
+
+        let failures = verify_that(subject)
+            .named("my_numbers")
+            .is_non_decreasing()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected my_numbers to be non-decreasing, but the elements at index 3 and 4 are not
+   actual: [1, 1, 2, 3, 0, 5]
+  failing: [3, 0]
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_vec_is_non_increasing_fails() {
+        let subject = vec![5, 5, 3, 4, 1];
+
+        let failures = verify_that(subject)
+            .named("my_numbers")
+            .is_non_increasing()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected my_numbers to be non-increasing, but the elements at index 2 and 3 are not
+   actual: [5, 5, 3, 4, 1]
+  failing: [3, 4]
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_vec_of_floats_with_nan_is_non_decreasing_fails() {
+        let subject = vec![0.5, f64::NAN, 1.5];
+
+        let failures = verify_that(subject)
+            .named("my_numbers")
+            .is_non_decreasing()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected my_numbers to be non-decreasing, but the elements at index 0 and 1 are not
+   actual: [0.5, NaN, 1.5]
+  failing: [0.5, NaN]
+"
+            ]
+        );
+    }
+}
+
+mod sorted_by {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Event {
+        name: &'static str,
+        timestamp: u64,
+    }
+
+    #[test]
+    fn slice_of_structs_is_sorted_by_key() {
+        let subject = [
+            Event {
+                name: "started",
+                timestamp: 100,
+            },
+            Event {
+                name: "progress",
+                timestamp: 100,
+            },
+            Event {
+                name: "finished",
+                timestamp: 250,
+            },
+        ];
+
+        assert_that(&subject[..]).is_sorted_by_key(|event| event.timestamp);
+    }
+
+    #[test]
+    fn vec_of_structs_is_sorted_by_comparison() {
+        let subject = vec![
+            Event {
+                name: "finished",
+                timestamp: 250,
+            },
+            Event {
+                name: "progress",
+                timestamp: 120,
+            },
+            Event {
+                name: "started",
+                timestamp: 100,
+            },
+        ];
+
+        assert_that(subject).is_sorted_by(|a, b| a.timestamp >= b.timestamp);
+    }
+
+    #[test]
+    fn custom_ordered_collection_is_sorted_by_key() {
+        let subject = CustomOrderedCollection {
+            inner: vec![-1, 2, -3, 4, -5],
+        };
+
+        assert_that(subject).is_sorted_by_key(|value: &i32| value.abs());
+    }
+
+    #[test]
+    fn empty_and_single_element_collections_are_sorted_by_any_order() {
+        assert_that(Vec::<Event>::new())
+            .is_sorted_by(|_, _| false)
+            .is_sorted_by_key(|event| event.timestamp);
+        assert_that(vec![Event {
+            name: "started",
+            timestamp: 100,
+        }])
+        .is_sorted_by(|_, _| false)
+        .is_sorted_by_key(|event| event.name);
+    }
+
+    #[test]
+    fn verify_vec_of_structs_is_sorted_by_key_fails() {
+        let subject = vec![
+            Event {
+                name: "started",
+                timestamp: 100,
+            },
+            Event {
+                name: "progress",
+                timestamp: 250,
+            },
+            Event {
+                name: "finished",
+                timestamp: 120,
+            },
+        ];
+
+        let failures = verify_that(subject)
+            .named("events")
+            .is_sorted_by_key(|event| event.timestamp)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r#"expected events to be sorted by key, but the elements at index 1 and 2 are not
+   actual: [Event { name: "started", timestamp: 100 }, Event { name: "progress", timestamp: 250 }, Event { name: "finished", timestamp: 120 }]
+  failing: [Event { name: "progress", timestamp: 250 }, Event { name: "finished", timestamp: 120 }]
+     keys: [250, 120]
+"#
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_vec_of_strings_is_sorted_by_comparison_fails() {
+        let subject = vec!["a", "bb", "c", "ddd"];
+
+        let failures = verify_that(subject)
+            .named("words")
+            .is_sorted_by(|a, b| a.len() <= b.len())
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r#"expected words to be sorted by the given comparison, but the elements at index 1 and 2 are not
+   actual: ["a", "bb", "c", "ddd"]
+  failing: ["bb", "c"]
+"#
+            ]
+        );
+    }
+
+    #[cfg(feature = "colored")]
+    #[test]
+    fn highlight_diffs_vec_is_sorted_by_key() {
+        let subject = vec![3, 1, 2];
+
+        let failures = verify_that(subject)
+            .named("numbers")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_sorted_by_key(|value| *value)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected numbers to be sorted by key, but the elements at index 0 and 1 are not\n   \
+                    actual: [\u{1b}[31m3\u{1b}[0m, \u{1b}[31m1\u{1b}[0m, 2]\n  \
+                   failing: [3, 1]\n     \
+                      keys: [3, 1]\n\
+                "
+            ]
+        );
+    }
+}
+
+#[test]
+fn vec_ignoring_order_contains_exactly_elements_in_any_order() {
+    let subject = vec![13, 3, 7, 5];
+
+    assert_that(subject)
+        .ignoring_order()
+        .contains_exactly([3, 5, 7, 13])
+        .contains_all_in_order([7, 13])
+        .starts_with([3, 13])
+        .ends_with([5, 7]);
+}
+
+#[test]
+fn iterator_ignoring_order_starts_with_elements_in_any_order() {
+    let subject = vec![13, 3, 7, 5].into_iter();
+
+    assert_that(subject)
+        .ignoring_order()
+        .starts_with([3, 7, 13]);
+}
+
+#[test]
+fn mapped_subject_keeps_ignoring_order() {
+    let subject = vec!["lorem", "ip", "dolor sit"];
+
+    assert_that(subject)
+        .ignoring_order()
+        .mapping(|words| words.into_iter().map(str::len).collect::<Vec<_>>())
+        .contains_exactly([9, 5, 2]);
+}
+
+#[test]
+fn property_extracted_by_reference_keeps_ignoring_order() {
+    let subject = CustomCollection {
+        inner: vec![13, 3, 7, 5],
+    };
+
+    assert_that(subject)
+        .ignoring_order()
+        .extracting_ref("inner", |collection| &collection.inner)
+        .contains_exactly([3, 5, 7, 13]);
+}
+
+#[test]
+fn verify_vec_ignoring_order_contains_exactly_fails() {
+    let subject = vec![13, 3, 7, 5];
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .ignoring_order()
+        .contains_exactly([3, 5, 11, 13])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to contain exactly in any order [3, 5, 11, 13]
+   but was: [13, 3, 7, 5]
+  expected: [3, 5, 11, 13]
+   missing: [11]
+     extra: [7]
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_vec_ignoring_order_starts_with_fails() {
+    let subject = vec![13, 3, 7, 5, 1];
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .ignoring_order()
+        .starts_with([3, 5, 13])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to start with [3, 5, 13] in any order
+   but was: [13, 3, 7, 5, 1]
+  expected: [3, 5, 13]
+   missing: [5]
+     extra: [7]
+"]
+    );
+}
+
+#[test]
+fn verify_vec_ignoring_order_ends_with_fails() {
+    let subject = vec![13, 3, 7, 5, 1];
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .ignoring_order()
+        .ends_with([1, 5, 3])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to end with [1, 5, 3] in any order
+   but was: [13, 3, 7, 5, 1]
+  expected: [1, 5, 3]
+   missing: [3]
+     extra: [7]
+"]
+    );
+}
+
+#[test]
+fn verify_vec_ignoring_order_ends_with_more_elements_than_vec_fails() {
+    let subject = vec![3, 5];
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .ignoring_order()
+        .ends_with([5, 3, 1])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to end with [5, 3, 1] in any order
+   but was: [3, 5]
+  expected: [5, 3, 1]
+   missing: [1]
+     extra: []
+"]
+    );
+}
+
+#[test]
+#[should_panic = "expected my_thing to start with [5, 3] in any order\n   but was: [13, 3, 7, 5]\n"]
+fn vec_ignoring_order_starts_with_panics_if_first_elements_differ() {
+    assert_that(vec![13, 3, 7, 5])
+        .named("my_thing")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .ignoring_order()
+        .starts_with([5, 3]);
+}
@@ -0,0 +1,205 @@
+//! Implementation of assertions for sequences of key/value-pairs, like
+//! `Vec<(K, V)>`.
+
+use crate::assertions::{AssertKeyValuePairs, AssertPairKeys, AssertValuesForKey};
+use crate::colored::{
+    mark_all_items_in_collection, mark_missing, mark_selected_items_in_collection, mark_unexpected,
+};
+use crate::expectations::{ContainsPair, KeysContain, contains_pair, keys_contain, not};
+use crate::properties::KeyValuePairsProperty;
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::fmt::Debug;
+use crate::std::format;
+use crate::std::string::String;
+use crate::std::vec::Vec;
+use hashbrown::HashSet;
+
+impl<S, K, V, R> AssertKeyValuePairs<K, V> for Spec<'_, S, R>
+where
+    S: KeyValuePairsProperty + Debug,
+    <S as KeyValuePairsProperty>::Key: PartialEq<K> + Debug,
+    <S as KeyValuePairsProperty>::Value: PartialEq<V> + Debug,
+    K: Debug,
+    V: Debug,
+    R: FailingStrategy,
+{
+    fn contains_pair(self, key: K, value: V) -> Self {
+        self.expecting(contains_pair(key, value))
+    }
+
+    fn does_not_contain_pair(self, key: K, value: V) -> Self {
+        self.expecting(not(contains_pair(key, value)))
+    }
+}
+
+impl<S, K, R> AssertPairKeys<K> for Spec<'_, S, R>
+where
+    S: KeyValuePairsProperty + Debug,
+    <S as KeyValuePairsProperty>::Key: PartialEq<K> + Debug,
+    <S as KeyValuePairsProperty>::Value: Debug,
+    K: Debug,
+    R: FailingStrategy,
+{
+    fn keys_contain(self, key: K) -> Self {
+        self.expecting(keys_contain(key))
+    }
+
+    fn keys_do_not_contain(self, key: K) -> Self {
+        self.expecting(not(keys_contain(key)))
+    }
+}
+
+impl<'a, P, E, R> AssertValuesForKey<E> for Spec<'a, &'a P, R>
+where
+    P: KeyValuePairsProperty + ?Sized,
+    <P as KeyValuePairsProperty>::Key: PartialEq<E>,
+    R: FailingStrategy,
+{
+    type Values = Spec<'a, Vec<&'a <P as KeyValuePairsProperty>::Value>, R>;
+
+    fn values_for_key(self, key: E) -> Self::Values {
+        self.mapping(|subject| find_values_for_key(subject, &key))
+    }
+}
+
+impl<'a, K, V, E, R> AssertValuesForKey<E> for Spec<'a, Vec<(K, V)>, R>
+where
+    K: PartialEq<E>,
+    R: FailingStrategy,
+{
+    type Values = Spec<'a, Vec<V>, R>;
+
+    fn values_for_key(self, key: E) -> Self::Values {
+        self.mapping(|subject| take_values_for_key(subject, &key))
+    }
+}
+
+/// Returns the values of all pairs with the given key in the order of the
+/// pairs.
+pub fn find_values_for_key<'a, P, E>(pairs: &'a P, key: &E) -> Vec<&'a P::Value>
+where
+    P: KeyValuePairsProperty + ?Sized,
+    <P as KeyValuePairsProperty>::Key: PartialEq<E>,
+{
+    pairs
+        .pairs_property()
+        .filter_map(|(k, v)| if k == key { Some(v) } else { None })
+        .collect()
+}
+
+/// Consumes the pairs and returns the values of all pairs with the given key
+/// in the order of the pairs.
+pub fn take_values_for_key<K, V, E>(pairs: Vec<(K, V)>, key: &E) -> Vec<V>
+where
+    K: PartialEq<E>,
+{
+    pairs
+        .into_iter()
+        .filter_map(|(k, v)| if k == *key { Some(v) } else { None })
+        .collect()
+}
+
+/// Formats the pairs of the actual subject for a failure message.
+///
+/// If the expectation is inverted, only the pairs matching the given predicate
+/// are highlighted as unexpected. Otherwise, all pairs are highlighted.
+fn mark_actual_pairs<P>(
+    actual: &P,
+    inverted: bool,
+    format: &DiffFormat,
+    is_match: impl Fn(&P::Key, &P::Value) -> bool,
+) -> String
+where
+    P: KeyValuePairsProperty,
+    <P as KeyValuePairsProperty>::Key: Debug,
+    <P as KeyValuePairsProperty>::Value: Debug,
+{
+    let actual_pairs: Vec<_> = actual.pairs_property().collect();
+    if inverted {
+        let found: HashSet<usize> = actual_pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (k, v))| if is_match(k, v) { Some(index) } else { None })
+            .collect();
+        mark_selected_items_in_collection(&actual_pairs, &found, format, mark_unexpected)
+    } else {
+        mark_all_items_in_collection(&actual_pairs, format, mark_unexpected)
+    }
+}
+
+impl<S, K, V> Expectation<S> for ContainsPair<K, V>
+where
+    S: KeyValuePairsProperty,
+    <S as KeyValuePairsProperty>::Key: PartialEq<K> + Debug,
+    <S as KeyValuePairsProperty>::Value: PartialEq<V> + Debug,
+    K: Debug,
+    V: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::contains_pair";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject
+            .pairs_property()
+            .any(|(k, v)| k == &self.expected_key && v == &self.expected_value)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected_key = &self.expected_key;
+        let expected_value = &self.expected_value;
+        let marked_actual = mark_actual_pairs(actual, inverted, format, |k, v| {
+            k == expected_key && v == expected_value
+        });
+        let marked_expected = mark_missing(&(expected_key, expected_value), format);
+        format!(
+            "expected {expression} to {not}contain the pair ({expected_key:?}, {expected_value:?})\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl<K, V> Invertible for ContainsPair<K, V> {}
+
+impl<S, K> Expectation<S> for KeysContain<K>
+where
+    S: KeyValuePairsProperty,
+    <S as KeyValuePairsProperty>::Key: PartialEq<K> + Debug,
+    <S as KeyValuePairsProperty>::Value: Debug,
+    K: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::keys_contain";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject
+            .pairs_property()
+            .any(|(k, _)| k == &self.expected_key)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected_key = &self.expected_key;
+        let marked_actual = mark_actual_pairs(actual, inverted, format, |k, _| k == expected_key);
+        let marked_expected = mark_missing(expected_key, format);
+        format!(
+            "expected {expression} to {not}contain a pair with the key {expected_key:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl<K> Invertible for KeysContain<K> {}
+
+#[cfg(test)]
+mod tests;
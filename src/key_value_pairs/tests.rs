@@ -0,0 +1,260 @@
+use crate::prelude::*;
+use crate::std::string::ToString;
+use crate::std::vec;
+use crate::std::vec::Vec;
+
+#[test]
+fn vec_of_pairs_contains_pair() {
+    let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    assert_that(&subject).contains_pair(2, "two");
+    assert_that(subject).contains_pair(3, "three");
+}
+
+#[test]
+fn slice_of_pairs_contains_pair() {
+    let subject: &[(char, u8)] = &[('a', 1), ('b', 2)];
+
+    assert_that(subject).contains_pair('a', 1);
+}
+
+#[test]
+fn array_of_pairs_contains_pair() {
+    let subject = [("x", 1.5), ("y", -0.5)];
+
+    assert_that(subject).contains_pair("y", -0.5);
+}
+
+#[test]
+fn vec_of_string_pairs_contains_pair_of_str() {
+    let subject = vec![
+        ("alpha".to_string(), "first".to_string()),
+        ("beta".to_string(), "second".to_string()),
+    ];
+
+    assert_that(&subject).contains_pair("beta", "second");
+}
+
+#[test]
+fn verify_vec_of_pairs_contains_pair_fails() {
+    let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    let failures = verify_that(subject)
+        .named("my_pairs")
+        .contains_pair(2, "three")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_pairs to contain the pair (2, "three")
+   but was: [(1, "one"), (2, "two"), (3, "three")]
+  expected: (2, "three")
+"#]
+    );
+}
+
+#[test]
+fn vec_of_pairs_does_not_contain_pair() {
+    let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    assert_that(subject).does_not_contain_pair(2, "three");
+}
+
+#[test]
+fn verify_vec_of_pairs_does_not_contain_pair_fails() {
+    let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    let failures = verify_that(subject)
+        .named("my_pairs")
+        .does_not_contain_pair(2, "two")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_pairs to not contain the pair (2, "two")
+   but was: [(1, "one"), (2, "two"), (3, "three")]
+  expected: not (2, "two")
+"#]
+    );
+}
+
+#[test]
+fn vec_of_pairs_keys_contain() {
+    let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    assert_that(&subject).keys_contain(1);
+    assert_that(subject).keys_contain(3);
+}
+
+#[test]
+fn verify_vec_of_pairs_keys_contain_fails() {
+    let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    let failures = verify_that(subject)
+        .named("my_pairs")
+        .keys_contain(4)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_pairs to contain a pair with the key 4
+   but was: [(1, "one"), (2, "two"), (3, "three")]
+  expected: 4
+"#]
+    );
+}
+
+#[test]
+fn vec_of_pairs_keys_do_not_contain() {
+    let subject = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    assert_that(subject).keys_do_not_contain(4);
+}
+
+#[test]
+fn verify_vec_of_pairs_keys_do_not_contain_fails() {
+    let subject = vec![(1, "one"), (2, "two"), (1, "uno")];
+
+    let failures = verify_that(subject)
+        .named("my_pairs")
+        .keys_do_not_contain(1)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_pairs to not contain a pair with the key 1
+   but was: [(1, "one"), (2, "two"), (1, "uno")]
+  expected: not 1
+"#]
+    );
+}
+
+#[test]
+fn values_for_key_returns_the_values_of_all_pairs_with_the_key() {
+    let subject = vec![("a", 1), ("b", 2), ("a", 3)];
+
+    assert_that(&subject)
+        .values_for_key("a")
+        .contains_exactly([&1, &3]);
+}
+
+#[test]
+fn values_for_key_of_owned_vec_of_pairs() {
+    let subject = vec![("a", 1), ("b", 2), ("a", 3)];
+
+    assert_that(subject)
+        .values_for_key("a")
+        .contains_exactly([1, 3]);
+}
+
+#[test]
+fn values_for_key_of_owned_vec_of_non_copy_values() {
+    let subject = vec![(1, "one".to_string()), (2, "two".to_string())];
+
+    assert_that(subject)
+        .values_for_key(2)
+        .contains_exactly(["two".to_string()]);
+}
+
+#[test]
+fn values_for_key_of_a_missing_key_is_empty() {
+    let subject = vec![("a", 1), ("b", 2)];
+
+    assert_that(&subject).values_for_key("c").is_empty();
+}
+
+#[test]
+fn values_for_key_of_slice_of_pairs() {
+    let subject: &[(u8, char)] = &[(1, 'x'), (2, 'y'), (1, 'z')];
+
+    assert_that(subject)
+        .values_for_key(1)
+        .contains_exactly([&'x', &'z']);
+}
+
+#[test]
+fn verify_values_for_key_keeps_the_expression() {
+    let subject = vec![("a", 1), ("b", 2)];
+
+    let failures = verify_that(&subject)
+        .named("my_pairs")
+        .values_for_key("b")
+        .is_empty()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_pairs to be empty
+   but was: [2]
+  expected: <empty>
+"]
+    );
+}
+
+#[test]
+fn derived_spec_contains_pair_and_values_for_key() {
+    let subject = Some(vec![("a", 1), ("b", 2)]);
+
+    assert_that(subject)
+        .some()
+        .contains_pair("a", 1)
+        .keys_contain("b")
+        .keys_do_not_contain("c")
+        .values_for_key("b")
+        .contains_exactly([2]);
+}
+
+#[test]
+fn pairs_of_an_empty_vec() {
+    let subject: Vec<(i32, i32)> = Vec::new();
+
+    assert_that(&subject)
+        .keys_do_not_contain(1)
+        .does_not_contain_pair(1, 1);
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use crate::prelude::*;
+    use crate::std::vec;
+
+    #[test]
+    fn highlight_diffs_vec_of_pairs_contains_pair() {
+        let subject = vec![(1, 'a'), (2, 'b')];
+
+        let failures = verify_that(subject)
+            .named("my_pairs")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .contains_pair(2, 'c')
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected my_pairs to contain the pair (2, 'c')\n   \
+                but was: [\u{1b}[31m(1, 'a')\u{1b}[0m, \u{1b}[31m(2, 'b')\u{1b}[0m]\n  \
+               expected: \u{1b}[34m(2, 'c')\u{1b}[0m\n\
+            "]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_vec_of_pairs_keys_do_not_contain() {
+        let subject = vec![(1, 'a'), (2, 'b')];
+
+        let failures = verify_that(subject)
+            .named("my_pairs")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .keys_do_not_contain(2)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected my_pairs to not contain a pair with the key 2\n   \
+                but was: [(1, 'a'), \u{1b}[31m(2, 'b')\u{1b}[0m]\n  \
+               expected: not \u{1b}[34m2\u{1b}[0m\n\
+            "
+            ]
+        );
+    }
+}
@@ -33,6 +33,8 @@ impl<S> Expectation<S> for IsEmpty
 where
     S: IsEmptyProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_empty";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.is_empty_property()
     }
@@ -95,6 +97,8 @@ impl<S> Expectation<S> for HasLength<usize>
 where
     S: LengthProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_length";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.length_property() == self.expected_length
     }
@@ -123,6 +127,8 @@ where
     S: LengthProperty + Debug,
     R: RangeBounds<usize> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_length_in_range";
+
     fn test(&mut self, subject: &S) -> bool {
         self.expected_range.contains(&subject.length_property())
     }
@@ -150,6 +156,8 @@ impl<S> Expectation<S> for HasLengthLessThan<usize>
 where
     S: LengthProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_length_less_than";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.length_property() < self.expected_length
     }
@@ -177,6 +185,8 @@ impl<S> Expectation<S> for HasLengthGreaterThan<usize>
 where
     S: LengthProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_length_greater_than";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.length_property() > self.expected_length
     }
@@ -204,6 +214,8 @@ impl<S> Expectation<S> for HasAtMostLength<usize>
 where
     S: LengthProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_at_most_length";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.length_property() <= self.expected_length
     }
@@ -231,6 +243,8 @@ impl<S> Expectation<S> for HasAtLeastLength<usize>
 where
     S: LengthProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_at_least_length";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.length_property() >= self.expected_length
     }
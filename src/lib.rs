@@ -264,6 +264,102 @@
 //!
 //! For more details see [`SoftPanic::soft_panic()`].
 //!
+//! Instead of panicking, the failures of soft assertions can also be returned
+//! as an error, for example, from a function that returns a `Result`:
+//!
+//! ```
+//! use asserting::prelude::*;
+//! use std::error::Error;
+//!
+//! fn check_answer(answer: i32) -> Result<(), Box<dyn Error>> {
+//!     verify_that!(answer)
+//!         .is_positive()
+//!         .is_equal_to(42)
+//!         .into_result()?;
+//!     Ok(())
+//! }
+//!
+//! assert!(check_answer(6 * 7).is_ok());
+//! assert!(check_answer(-42).is_err());
+//! ```
+//!
+//! For more details see [`Spec::into_result()`].
+//!
+//! Outside of tests, for example, to validate input, the [`check_that!`] macro
+//! evaluates a chain of assertions without panicking and returns whether all
+//! assertions have passed:
+//!
+//! ```
+//! use asserting::prelude::*;
+//!
+//! let answer = 42;
+//!
+//! assert!(check_that!(answer, .is_positive().is_equal_to(42)));
+//! assert!(!check_that!(answer, .is_negative()));
+//! ```
+//!
+//! The failures of soft assertions on different subjects can be combined into
+//! one report, which groups the failures by subject:
+//!
+//! ```should_panic
+//! use asserting::prelude::*;
+//! use asserting::spec::AssertFailureList;
+//!
+//! let answer = 41;
+//! let name = "Alice";
+//!
+//! let failures: AssertFailureList = [
+//!     verify_that!(answer).is_positive().is_equal_to(42).into_failure_list(),
+//!     verify_that!(name).starts_with("B").ends_with("e").into_failure_list(),
+//! ]
+//! .into_iter()
+//! .collect();
+//!
+//! failures.soft_panic();
+//! ```
+//!
+//! prints a header for each subject followed by its failures and a summary:
+//!
+//! ```console
+//! answer at src/lib.rs:10:5:
+//!   expected answer to be equal to 42
+//!      but was: 41
+//!     expected: 42
+//!
+//! name at src/lib.rs:11:5:
+//!   expected name to start with "B"
+//!      but was: "Alice"
+//!     expected: "B"
+//!
+//! 2 of 4 assertions failed
+//! ```
+//!
+//! ## Asserting borrowed subjects
+//!
+//! Large subjects do not need to be moved or cloned into an assertion. All
+//! assertions for collections, maps, and strings are also implemented for
+//! references to them, so the subject can be borrowed:
+//!
+//! ```
+//! use asserting::prelude::*;
+//! use std::collections::HashMap;
+//!
+//! let big_vec = (1..=1000).collect::<Vec<i32>>();
+//! let headers = HashMap::from([("Content-Type", "text/plain")]);
+//! let body = String::from("lorem ipsum dolor sit amet");
+//!
+//! assert_that!(&big_vec).has_length(1000).contains(&500).starts_with(&[1, 2]);
+//! assert_that!(&headers).contains_key("Content-Type");
+//! assert_that!(&body).starts_with("lorem").has_char_count(26);
+//!
+//! // the subjects are still usable after the assertions
+//! assert_eq!(big_vec.len(), 1000);
+//! ```
+//!
+//! The elements of a borrowed collection are references as well. Therefore,
+//! the expected elements are given as references, like `&500` in the example
+//! above.
+//!
 //! ## Asserting custom types
 //!
 //! We can extract a property of a custom type and assert its value:
@@ -312,6 +408,27 @@
 //! Note: The [`extracting_ref`] method can only be used if the type of the
 //! extracted value implements `ToOwned`.
 //!
+//! Alternatively, the [`which`] method executes a block of assertions on an
+//! extracted property and continues with the original subject afterward:
+//!
+//! ```
+//! use asserting::prelude::*;
+//!
+//! struct Person {
+//!     name: String,
+//!     age: u8,
+//! }
+//!
+//! let person = Person {
+//!     name: "Silvia".into(),
+//!     age: 25
+//! };
+//!
+//! assert_that!(person)
+//!     .which(|p| &p.name, |name| name.starts_with('S').has_length(6))
+//!     .which(|p| &p.age, |age| age.is_at_least(18));
+//! ```
+//!
 //! In some cases we might want to map a custom type that does not implement a
 //! required trait to some other type that implements the required traits. For
 //! example, we map a value of custom type `Point` to a tuple of two integers
@@ -343,6 +460,23 @@
 //! assert_that!(37).satisfies_with_message("expected my number to be odd", is_odd);
 //! ```
 //!
+//! If the predicate function returns a `Result`, the error explains why the
+//! subject does not satisfy the predicate and becomes part of the failure
+//! message:
+//!
+//! ```
+//! # use asserting::prelude::*;
+//! fn is_odd(value: &i32) -> Result<(), String> {
+//!     if value & 1 == 1 {
+//!         Ok(())
+//!     } else {
+//!         Err(format!("{value} is divisible by 2"))
+//!     }
+//! }
+//!
+//! assert_that!(37).satisfies_with(is_odd);
+//! ```
+//!
 //! ## Assert that some code panics or does not panic
 //!
 //! Requires crate feature `panic`.
@@ -568,6 +702,10 @@
 //! [Writing custom assertions without writing an expectation](#writing-custom-assertions-without-writing-an-expectation)
 //! chapter below for an example.
 //!
+//! Crates that build their own assertions on top of the expectations provided
+//! by `asserting` should import them from the [`matchers`] module. It is the
+//! stable extension API, which follows semantic versioning.
+//!
 //! ## Property-based assertions
 //!
 //! Some assertions provided by `asserting` are so-called property-based
@@ -705,6 +843,50 @@
 //! assert_that!(subject).expecting(IsLeft);
 //! ```
 //!
+//! To assert that a custom expectation is not met, we can use the
+//! [`Spec::not_expecting()`] method. It works with any expectation, even if
+//! the expectation does not implement the [`Invertible`](spec::Invertible)
+//! trait. The failure message is derived from the message of the expectation.
+//!
+//! ```
+//! # use asserting::spec::{DiffFormat, Expectation, Expression, Unknown};
+//! # use std::fmt::Debug;
+//! #
+//! # #[derive(Debug)]
+//! # enum Either<L, R> {
+//! #     Left(L),
+//! #     Right(R),
+//! # }
+//! #
+//! # struct IsLeft;
+//! #
+//! # impl<L, R> Expectation<Either<L, R>> for IsLeft
+//! # where
+//! #     L: Debug,
+//! #     R: Debug,
+//! # {
+//! #     fn test(&mut self, subject: &Either<L, R>) -> bool {
+//! #         match subject {
+//! #             Either::Left(_) => true,
+//! #             _ => false,
+//! #         }
+//! #     }
+//! #
+//! #     fn message(&self, expression: &Expression<'_>, actual: &Either<L, R>, _inverted: bool, _format: &DiffFormat) -> String {
+//! #         format!(
+//! #             "expected {expression} is {:?}\n   but was: {actual:?}\n  expected: {:?}",
+//! #             Either::Left::<_, Unknown>(Unknown),
+//! #             Either::Left::<_, Unknown>(Unknown),
+//! #         )
+//! #      }
+//! # }
+//! use asserting::prelude::*;
+//!
+//! let subject: Either<String, i64> = Either::Right(-1);
+//!
+//! assert_that!(subject).not_expecting(IsLeft);
+//! ```
+//!
 //! ## Providing a custom assertion method
 //!
 //! In the previous chapter, we implemented a custom expectation which can be
@@ -822,6 +1004,12 @@
 //! assert_that!(subject).is_left();
 //! ```
 //!
+//! An expectation that can fail to be evaluated, e.g., because it is given an
+//! invalid regex pattern, should additionally implement the method
+//! [`Expectation::try_test()`] and return an [`EvalError`](spec::EvalError)
+//! in that case. The failure message then states that the assertion could not
+//! be evaluated instead of reporting a regular failure.
+//!
 //! ## Writing custom assertions without writing an expectation
 //!
 //! In real world projects custom assertions are often very specific, and custom
@@ -873,9 +1061,12 @@
 //! [`AssertFilteredElements`]: assertions::AssertFilteredElements
 //! [`AssertFailure`]: spec::AssertFailure
 //! [`Expectation`]: spec::Expectation
+//! [`Expectation::try_test()`]: spec::Expectation::try_test
 //! [`LengthProperty`]: properties::LengthProperty
 //! [`Spec`]: spec::Spec
+//! [`Spec::into_result()`]: spec::Spec::into_result
 //! [`Spec::expecting()`]: spec::Expecting::expecting
+//! [`Spec::not_expecting()`]: spec::Expecting::not_expecting
 //! [`Spec::satisfies()`]: spec::Satisfies::satisfies
 //! [`SoftPanic::soft_panic()`]: spec::SoftPanic::soft_panic
 //! [`assert_that`]: spec::assert_that
@@ -888,10 +1079,13 @@
 //! [`located_at()`]: spec::Spec::located_at
 //! [`extracting_ref`]: spec::Spec::extracting_ref
 //! [`and`]: spec::And::and
+//! [`which`]: spec::Spec::which
 //! [`serde::Serialize`]: serde_core::Serialize
 
 #![doc(html_root_url = "https://docs.rs/asserting/0.15.0")]
 #![cfg_attr(not(feature = "std"), no_std)]
+// The global allocator of the feature `alloc-track` is the only unsafe code.
+#![cfg_attr(not(feature = "alloc-track"), forbid(unsafe_code))]
 // Render feature requirements in docs.rs
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
@@ -937,6 +1131,12 @@ mod std {
         pub use alloc::ffi::*;
         pub use core::ffi::*;
     }
+
+    pub mod task {
+        extern crate alloc;
+        pub use alloc::task::*;
+        pub use core::task::*;
+    }
 }
 
 #[cfg(feature = "std")]
@@ -950,37 +1150,73 @@ pub mod __private {
     extern crate alloc;
     #[doc(hidden)]
     pub use alloc::vec;
+    #[cfg(feature = "proptest")]
+    #[doc(hidden)]
+    pub use proptest::test_runner::TestCaseError;
 }
 
+#[cfg(feature = "alloc-track")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc-track")))]
+pub mod alloc_track;
 pub mod assertions;
 pub mod colored;
 pub mod derived_spec;
 pub mod expectations;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod listener;
+pub mod matchers;
 pub mod prelude;
 pub mod properties;
 #[cfg(feature = "recursive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "recursive")))]
 pub mod recursive_comparison;
+pub mod report;
 pub mod spec;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use panic_hook::install_panic_hook;
+
+mod any;
+#[cfg(feature = "anyhow")]
+mod anyhow;
+mod assertion_id;
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
+#[cfg(feature = "bitflags")]
+mod bitflags;
+mod bits;
 mod boolean;
+mod bytes;
 mod c_string;
+mod capacity;
 mod char;
 mod char_count;
+#[cfg(feature = "chrono")]
+mod chrono;
 mod collection;
-#[cfg(all(feature = "std", feature = "colored"))]
+#[cfg(feature = "std")]
+mod config;
+mod const_assert;
+mod date_time;
+#[cfg(feature = "std")]
 mod env;
+#[cfg(feature = "std")]
+mod env_var;
 mod equality;
 mod error;
 mod expectation_combinators;
 mod float;
+#[cfg(feature = "indexmap")]
+mod indexmap;
 mod integer;
 mod iterator;
+mod key_value_pairs;
 mod length;
 mod map;
 mod mapping;
+mod net;
 #[cfg(feature = "num-bigint")]
 mod num_bigint;
 mod number;
@@ -990,13 +1226,36 @@ mod order;
 mod os_sting;
 #[cfg(feature = "panic")]
 mod panic;
+#[cfg(feature = "std")]
+mod panic_hook;
+#[cfg(feature = "std")]
+mod path;
+mod pattern;
+mod poll;
 mod predicate;
+#[cfg(feature = "std")]
+mod process;
+#[cfg(feature = "proptest")]
+mod proptest;
 mod range;
 mod result;
 #[cfg(feature = "rust-decimal")]
 mod rust_decimal;
+mod set;
 mod slice;
+mod smart_pointer;
 mod string;
+mod structured_diff;
+mod text_position;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "std")]
+mod trace;
+mod tuple;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
 mod vec;
 
 // test code snippets in the README.md
@@ -1013,6 +1272,7 @@ mod dummy_extern_uses {
     use proptest as _;
     use serde as _;
     use serde_bytes as _;
+    use serde_test as _;
     use time as _;
     use version_sync as _;
 }
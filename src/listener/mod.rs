@@ -0,0 +1,138 @@
+//! Hook for listening to executed assertions.
+//!
+//! An [`AssertionListener`] is notified about every executed assertion,
+//! whether it passed or failed. Listeners are registered globally with the
+//! [`register_listener`] function. This enables test frameworks and tools to
+//! count assertions, log them via a logging or tracing framework, or forward
+//! failures to custom reporters without replacing the panic hook.
+//!
+//! Listeners are notified before a failing assertion panics in the
+//! [`PanicOnFail`](crate::spec::PanicOnFail) mode.
+//!
+//! # Examples
+//!
+//! ```
+//! use asserting::listener::{register_listener, AssertionEvent, AssertionListener};
+//! use asserting::prelude::*;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! static PASSED: AtomicUsize = AtomicUsize::new(0);
+//!
+//! struct CountPassed;
+//!
+//! impl AssertionListener for CountPassed {
+//!     fn on_assertion_passed(&self, event: &AssertionEvent<'_>) {
+//!         if event.expression() == "answer" {
+//!             PASSED.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//! }
+//!
+//! register_listener(CountPassed);
+//!
+//! let answer = 42;
+//! assert_that!(answer).is_positive().is_equal_to(42);
+//!
+//! assert_eq!(PASSED.load(Ordering::Relaxed), 2);
+//! ```
+
+use crate::spec::{EvalError, ExpectationWithContext, SpecMetadata, plain_message};
+use crate::std::string::ToString;
+use crate::std::sync::{Arc, PoisonError, RwLock};
+use crate::std::vec::Vec;
+
+/// The globally registered listeners.
+static LISTENERS: RwLock<Vec<Arc<dyn AssertionListener>>> = RwLock::new(Vec::new());
+
+/// A listener that is notified about executed assertions.
+///
+/// Both methods do nothing by default, so an implementation only needs to
+/// implement the method for the events it is interested in.
+///
+/// Listeners are called from the thread that executes the assertion. As tests
+/// run in parallel, a listener must be [`Send`] and [`Sync`].
+pub trait AssertionListener: Send + Sync {
+    /// Called after an assertion passed.
+    #[allow(unused_variables)]
+    fn on_assertion_passed(&self, event: &AssertionEvent<'_>) {}
+
+    /// Called after an assertion failed.
+    #[allow(unused_variables)]
+    fn on_assertion_failed(&self, event: &AssertionEvent<'_>) {}
+}
+
+/// Information about an executed assertion that is passed to an
+/// [`AssertionListener`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssertionEvent<'a> {
+    expression: &'a str,
+    message: &'a str,
+}
+
+impl<'a> AssertionEvent<'a> {
+    /// Returns the expression or name of the subject that was asserted.
+    pub fn expression(&self) -> &'a str {
+        self.expression
+    }
+
+    /// Returns the message of the assertion without highlighted differences.
+    ///
+    /// For a failed assertion, this is the failure message. For a passed
+    /// assertion, this is the message that describes what was expected.
+    pub fn message(&self) -> &'a str {
+        self.message
+    }
+}
+
+/// Registers the given listener to be notified about all assertions executed
+/// from now on.
+pub fn register_listener(listener: impl AssertionListener + 'static) {
+    LISTENERS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(Arc::new(listener));
+}
+
+/// Removes all registered listeners.
+pub fn clear_listeners() {
+    LISTENERS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+}
+
+/// Notifies all registered listeners about an executed expectation.
+pub(crate) fn notify_listeners<S>(
+    expectation: &impl ExpectationWithContext<S>,
+    metadata: &SpecMetadata<'_>,
+    subject: &S,
+    evaluation: &Result<bool, EvalError>,
+) where
+    S: ?Sized,
+{
+    // Clone the listeners to release the lock before calling them, so that
+    // listeners can execute assertions themselves.
+    let listeners = LISTENERS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    if listeners.is_empty() {
+        return;
+    }
+    let message = plain_message(expectation, metadata, subject, evaluation);
+    let expression = metadata.expression.to_string();
+    let event = AssertionEvent {
+        expression: &expression,
+        message: &message,
+    };
+    for listener in listeners {
+        if *evaluation == Ok(true) {
+            listener.on_assertion_passed(&event);
+        } else {
+            listener.on_assertion_failed(&event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,159 @@
+use super::*;
+use crate::prelude::*;
+use crate::std::format;
+use crate::std::string::{String, ToString};
+use crate::std::sync::Mutex;
+
+/// Records the events of assertions on subjects with the given name only, as
+/// listeners are global and tests are run in parallel.
+struct RecordingListener {
+    expression: &'static str,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl RecordingListener {
+    fn register(expression: &'static str) -> Arc<Mutex<Vec<String>>> {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        register_listener(Self {
+            expression,
+            events: Arc::clone(&events),
+        });
+        events
+    }
+
+    fn record(&self, outcome: &str, event: &AssertionEvent<'_>) {
+        if event.expression() == self.expression {
+            self.events
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(format!("{outcome}: {}", event.message()));
+        }
+    }
+}
+
+impl AssertionListener for RecordingListener {
+    fn on_assertion_passed(&self, event: &AssertionEvent<'_>) {
+        self.record("passed", event);
+    }
+
+    fn on_assertion_failed(&self, event: &AssertionEvent<'_>) {
+        self.record("failed", event);
+    }
+}
+
+fn recorded(events: &Arc<Mutex<Vec<String>>>) -> Vec<String> {
+    events
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+#[test]
+fn listener_is_notified_about_passed_assertions() {
+    let events = RecordingListener::register("listened_answer_1");
+
+    assert_that(42)
+        .named("listened_answer_1")
+        .is_positive()
+        .is_equal_to(42);
+
+    assert_eq!(
+        recorded(&events),
+        &[
+            "passed: expected listened_answer_1 to be positive\n   but was: 42\n  expected: > 0",
+            "passed: expected listened_answer_1 to be equal to 42\n   but was: 42\n  expected: 42",
+        ]
+    );
+}
+
+#[test]
+fn listener_is_notified_about_failed_assertions() {
+    let events = RecordingListener::register("listened_answer_2");
+
+    let failures = verify_that(-42)
+        .named("listened_answer_2")
+        .is_positive()
+        .is_equal_to(-42)
+        .display_failures();
+
+    assert_eq!(failures.len(), 1);
+    assert_eq!(
+        recorded(&events),
+        &[
+            "failed: expected listened_answer_2 to be positive\n   but was: -42\n  expected: > 0",
+            "passed: expected listened_answer_2 to be equal to -42\n   but was: -42\n  expected: -42",
+        ]
+    );
+}
+
+#[test]
+fn listener_is_notified_about_assertions_on_derived_subjects() {
+    let events = RecordingListener::register("listened_value unwrapped Some value");
+
+    assert_that(Some(7))
+        .named("listened_value")
+        .some()
+        .is_equal_to(7);
+
+    assert_eq!(
+        recorded(&events),
+        &[
+            "passed: expected listened_value unwrapped Some value to be equal to 7\n   but was: 7\n  expected: 7"
+        ]
+    );
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn listener_is_notified_before_a_failing_assertion_panics() {
+    let events = RecordingListener::register("listened_answer_3");
+
+    assert_that_code(|| {
+        assert_that(41).named("listened_answer_3").is_equal_to(42);
+    })
+    .panics();
+
+    assert_eq!(
+        recorded(&events),
+        &["failed: expected listened_answer_3 to be equal to 42\n   but was: 41\n  expected: 42"]
+    );
+}
+
+#[test]
+fn listener_may_execute_assertions_itself() {
+    struct AssertingListener;
+
+    impl AssertionListener for AssertingListener {
+        fn on_assertion_passed(&self, event: &AssertionEvent<'_>) {
+            if event.expression() == "listened_answer_4" {
+                assert_that(event.message().to_string()).starts_with("expected");
+            }
+        }
+    }
+
+    register_listener(AssertingListener);
+
+    assert_that(42).named("listened_answer_4").is_equal_to(42);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn listener_is_notified_about_assertions_that_could_not_be_evaluated() {
+    let events = RecordingListener::register("listened_name");
+
+    let failures = verify_that("Silvia")
+        .named("listened_name")
+        .matches(r"^(S\w+")
+        .display_failures();
+
+    assert_eq!(failures.len(), 1);
+    let recorded = recorded(&events);
+    assert_eq!(recorded.len(), 1);
+    assert!(
+        recorded[0].starts_with(
+            "failed: could not evaluate the assertion on listened_name\n  error: failed to match string with regex: "
+        ),
+        "unexpected event: {}",
+        recorded[0]
+    );
+}
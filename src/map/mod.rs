@@ -1,23 +1,30 @@
-use crate::assertions::{AssertMapContainsKey, AssertMapContainsValue};
+use crate::assertions::{
+    AssertMapContainsKey, AssertMapContainsValue, AssertMapEquality, AssertMapKeysSorted,
+    AssertMapValue, AssertOrderedMapEntries,
+};
 use crate::colored::{
-    mark_all_entries_in_map, mark_missing, mark_selected_entries_in_map,
+    mark_all_entries_in_map, mark_missing, mark_missing_string, mark_selected_entries_in_map,
     mark_selected_items_in_collection, mark_unexpected_string,
 };
 use crate::expectations::{
-    MapContainsExactlyKeys, MapContainsKey, MapContainsKeys, MapContainsValue, MapContainsValues,
-    MapDoesNotContainKeys, MapDoesNotContainValues, map_contains_exactly_keys, map_contains_key,
-    map_contains_keys, map_contains_value, map_contains_values, map_does_not_contain_keys,
-    map_does_not_contain_values, not,
+    MapContainsEntriesInOrder, MapContainsExactlyKeys, MapContainsKey, MapContainsKeys,
+    MapContainsValue, MapContainsValues, MapDoesNotContainKeys, MapDoesNotContainValues,
+    MapFirstEntryIs, MapIsEqualTo, MapKeysAreSorted, MapLastEntryIs, map_contains_entries_in_order,
+    map_contains_exactly_keys, map_contains_key, map_contains_keys, map_contains_value,
+    map_contains_values, map_does_not_contain_keys, map_does_not_contain_values,
+    map_first_entry_is, map_is_equal_to, map_keys_are_sorted, map_last_entry_is, not,
 };
 use crate::iterator::collect_selected_values;
-use crate::properties::MapProperties;
+use crate::properties::{DefinedOrderProperty, MapProperties};
 use crate::spec::{
-    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+    DiffFormat, DoFail, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
 };
+use crate::std::cmp::Ordering;
 use crate::std::fmt::Debug;
 use crate::std::format;
 use crate::std::string::String;
 use crate::std::vec::Vec;
+use crate::structured_diff::{MapDifferences, entry_differences, format_map_differences};
 use hashbrown::HashSet;
 
 impl<S, E, R> AssertMapContainsKey<E> for Spec<'_, S, R>
@@ -49,6 +56,71 @@ where
     }
 }
 
+impl<'a, M, E, R> AssertMapValue<E> for Spec<'a, &'a M, R>
+where
+    M: MapProperties + ?Sized,
+    <M as MapProperties>::Key: PartialEq<E> + Debug,
+    <M as MapProperties>::Value: Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    type Value = Spec<'a, &'a <M as MapProperties>::Value, R>;
+
+    fn value_of(mut self, key: E) -> Self::Value {
+        let subject = (!self.is_skipped()).then(|| *self.subject());
+        let value = subject.and_then(|subject| find_value_of(subject, &key));
+        if let (Some(subject), None) = (subject, value) {
+            let message = map_contains_key(key).message(
+                self.expression(),
+                &subject,
+                false,
+                self.diff_format(),
+            );
+            self.do_fail_with_message(message);
+        }
+        self.narrowing(|_| value)
+    }
+}
+
+/// Returns the value that is mapped to the given key in the given map.
+pub fn find_value_of<'a, M, E>(map: &'a M, key: &E) -> Option<&'a M::Value>
+where
+    M: MapProperties + ?Sized,
+    <M as MapProperties>::Key: PartialEq<E>,
+{
+    map.entries_property()
+        .find_map(|(k, v)| if k == key { Some(v) } else { None })
+}
+
+/// Maps the subject of the given spec, which is an owned map, to the value
+/// that is mapped to the given key.
+///
+/// Implements [`AssertMapValue::value_of()`] for owned maps.
+pub fn take_value_of<M, E, R>(mut spec: Spec<'_, M, R>, key: E) -> Spec<'_, M::Value, R>
+where
+    M: MapProperties + IntoIterator<Item = (M::Key, M::Value)> + Debug,
+    <M as MapProperties>::Key: PartialEq<E> + Debug,
+    <M as MapProperties>::Value: Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    if !spec.is_skipped() && find_value_of(spec.subject(), &key).is_none() {
+        let message = map_contains_key(key).message(
+            spec.expression(),
+            spec.subject(),
+            false,
+            spec.diff_format(),
+        );
+        spec.do_fail_with_message(message);
+        return spec.narrowing(|_| None);
+    }
+    spec.narrowing(|subject| {
+        subject
+            .into_iter()
+            .find_map(|(k, v)| if k == key { Some(v) } else { None })
+    })
+}
+
 impl<M, E> Expectation<M> for MapContainsKey<E>
 where
     M: MapProperties,
@@ -56,6 +128,8 @@ where
     <M as MapProperties>::Value: Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_key";
+
     fn test(&mut self, subject: &M) -> bool {
         subject.keys_property().any(|k| k == &self.expected_key)
     }
@@ -109,6 +183,8 @@ where
     <M as MapProperties>::Value: Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_keys";
+
     fn test(&mut self, subject: &M) -> bool {
         let keys = subject.keys_property().collect::<Vec<_>>();
         let missing = &mut self.missing;
@@ -165,6 +241,8 @@ where
     <M as MapProperties>::Value: Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::does_not_contain_keys";
+
     fn test(&mut self, subject: &M) -> bool {
         let keys = subject.keys_property().collect::<Vec<_>>();
         let extra = &mut self.extra;
@@ -215,6 +293,8 @@ where
     <M as MapProperties>::Value: Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_exactly_keys";
+
     fn test(&mut self, subject: &M) -> bool {
         let actual_keys = subject.keys_property().collect::<Vec<_>>();
         let expected_keys = &self.expected_keys;
@@ -293,6 +373,8 @@ where
     <M as MapProperties>::Value: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_value";
+
     fn test(&mut self, subject: &M) -> bool {
         subject.values_property().any(|v| v == &self.expected_value)
     }
@@ -347,6 +429,8 @@ where
     <M as MapProperties>::Value: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_values";
+
     fn test(&mut self, subject: &M) -> bool {
         let values = subject.values_property().collect::<Vec<_>>();
         let missing = &mut self.missing;
@@ -403,6 +487,8 @@ where
     <M as MapProperties>::Value: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::does_not_contain_values";
+
     fn test(&mut self, subject: &M) -> bool {
         let values = subject.values_property().collect::<Vec<_>>();
         let extra = &mut self.extra;
@@ -449,127 +535,378 @@ where
     }
 }
 
-mod hashbrown_impls {
-    use crate::properties::MapProperties;
-    use crate::std::iter::Iterator;
-    use hashbrown::HashMap;
-
-    impl<K, V, H> MapProperties for HashMap<K, V, H> {
-        type Key = K;
-        type Value = V;
+impl<S, E, F, R> AssertOrderedMapEntries<E, F> for Spec<'_, S, R>
+where
+    S: MapProperties + DefinedOrderProperty + Debug,
+    <S as MapProperties>::Key: PartialEq<E> + Debug,
+    <S as MapProperties>::Value: PartialEq<F> + Debug,
+    E: Debug,
+    F: Debug,
+    R: FailingStrategy,
+{
+    fn first_entry_is(self, expected_entry: (E, F)) -> Self {
+        self.expecting(map_first_entry_is(expected_entry))
+    }
 
-        fn keys_property(&self) -> impl Iterator<Item = &<Self as MapProperties>::Key> {
-            self.keys()
-        }
+    fn last_entry_is(self, expected_entry: (E, F)) -> Self {
+        self.expecting(map_last_entry_is(expected_entry))
+    }
 
-        fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
-            self.values()
-        }
+    fn contains_entries_in_order(self, expected_entries: impl IntoIterator<Item = (E, F)>) -> Self {
+        self.expecting(map_contains_entries_in_order(expected_entries))
+    }
+}
 
-        fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
-            self.iter()
-        }
+impl<S, R> AssertMapKeysSorted for Spec<'_, S, R>
+where
+    S: MapProperties + DefinedOrderProperty + Debug,
+    <S as MapProperties>::Key: PartialOrd + Debug,
+    <S as MapProperties>::Value: Debug,
+    R: FailingStrategy,
+{
+    fn keys_are_sorted(self) -> Self {
+        self.expecting(map_keys_are_sorted())
     }
+}
 
-    impl<K, V, H> MapProperties for &HashMap<K, V, H> {
-        type Key = K;
-        type Value = V;
+fn entry_is_equal_to<K, V, E, F>(entry: (&K, &V), expected_entry: &(E, F)) -> bool
+where
+    K: PartialEq<E>,
+    V: PartialEq<F>,
+{
+    *entry.0 == expected_entry.0 && *entry.1 == expected_entry.1
+}
 
-        fn keys_property(&self) -> impl Iterator<Item = &<Self as MapProperties>::Key> {
-            self.keys()
-        }
+fn expected_entry_message<M, E, F>(
+    expression: &Expression<'_>,
+    position: &str,
+    actual: &M,
+    actual_index: Option<usize>,
+    expected_entry: &(E, F),
+    format: &DiffFormat,
+) -> String
+where
+    M: MapProperties,
+    <M as MapProperties>::Key: Debug,
+    <M as MapProperties>::Value: Debug,
+    E: Debug,
+    F: Debug,
+{
+    let actual_entries: Vec<_> = actual.entries_property().collect();
+    let selected = actual_index.into_iter().collect::<HashSet<_>>();
+    let marked_actual =
+        mark_selected_entries_in_map(&actual_entries, &selected, format, mark_unexpected_string);
+    let marked_expected = mark_missing(expected_entry, format);
+    format!(
+        "expected {expression} to have the {position} entry {expected_entry:?}\n   but was: {marked_actual}\n  expected: {marked_expected}"
+    )
+}
 
-        fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
-            self.values()
-        }
+impl<M, E, F> Expectation<M> for MapFirstEntryIs<E, F>
+where
+    M: MapProperties,
+    <M as MapProperties>::Key: PartialEq<E> + Debug,
+    <M as MapProperties>::Value: PartialEq<F> + Debug,
+    E: Debug,
+    F: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::first_entry_is";
 
-        fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
-            self.iter()
-        }
+    fn test(&mut self, subject: &M) -> bool {
+        subject
+            .entries_property()
+            .next()
+            .is_some_and(|entry| entry_is_equal_to(entry, &self.expected_entry))
     }
 
-    impl<K, V, H> MapProperties for &mut HashMap<K, V, H> {
-        type Key = K;
-        type Value = V;
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &M,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let first_index = actual.entries_property().next().map(|_| 0);
+        expected_entry_message(
+            expression,
+            "first",
+            actual,
+            first_index,
+            &self.expected_entry,
+            format,
+        )
+    }
+}
 
-        fn keys_property(&self) -> impl Iterator<Item = &<Self as MapProperties>::Key> {
-            self.keys()
-        }
+impl<M, E, F> Expectation<M> for MapLastEntryIs<E, F>
+where
+    M: MapProperties,
+    <M as MapProperties>::Key: PartialEq<E> + Debug,
+    <M as MapProperties>::Value: PartialEq<F> + Debug,
+    E: Debug,
+    F: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::last_entry_is";
 
-        fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
-            self.values()
-        }
+    fn test(&mut self, subject: &M) -> bool {
+        subject
+            .entries_property()
+            .last()
+            .is_some_and(|entry| entry_is_equal_to(entry, &self.expected_entry))
+    }
 
-        fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
-            self.iter()
-        }
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &M,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let last_index = actual.entries_property().count().checked_sub(1);
+        expected_entry_message(
+            expression,
+            "last",
+            actual,
+            last_index,
+            &self.expected_entry,
+            format,
+        )
     }
 }
 
-#[cfg(feature = "std")]
-mod std_hashmap_impls {
-    use crate::properties::MapProperties;
-    use crate::std::collections::HashMap;
-    use crate::std::iter::Iterator;
-
-    impl<K, V, H> MapProperties for HashMap<K, V, H> {
-        type Key = K;
-        type Value = V;
+impl<M, E, F> Expectation<M> for MapContainsEntriesInOrder<E, F>
+where
+    M: MapProperties + Debug,
+    <M as MapProperties>::Key: PartialEq<E> + Debug,
+    <M as MapProperties>::Value: PartialEq<F> + Debug,
+    E: Debug,
+    F: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::contains_entries_in_order";
 
-        fn keys_property(&self) -> impl Iterator<Item = &<Self as MapProperties>::Key> {
-            self.keys()
+    fn test(&mut self, subject: &M) -> bool {
+        let actual_entries: Vec<_> = subject.entries_property().collect();
+        let missing = &mut self.missing;
+        let mut last_match_index = 0;
+        for (expected_index, expected_entry) in self.expected_entries.iter().enumerate() {
+            if let Some((actual_index, _)) = actual_entries
+                .iter()
+                .enumerate()
+                .skip(last_match_index)
+                .find(|(_, entry)| entry_is_equal_to(**entry, expected_entry))
+            {
+                last_match_index = actual_index + 1;
+            } else {
+                missing.insert(expected_index);
+            }
         }
+        missing.is_empty()
+    }
 
-        fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
-            self.values()
-        }
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &M,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected_entries = &self.expected_entries;
+        let marked_expected = mark_selected_items_in_collection(
+            expected_entries,
+            &self.missing,
+            format,
+            mark_missing,
+        );
+        let missing = collect_selected_values(&self.missing, expected_entries);
 
-        fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
-            self.iter()
-        }
+        format!(
+            r"expected {expression} to contain the entries {expected_entries:?} in order
+   but was: {actual:?}
+  expected: {marked_expected}
+   missing: {missing:?}"
+        )
     }
+}
 
-    impl<K, V, H> MapProperties for &HashMap<K, V, H> {
-        type Key = K;
-        type Value = V;
+impl<M> Expectation<M> for MapKeysAreSorted
+where
+    M: MapProperties,
+    <M as MapProperties>::Key: PartialOrd + Debug,
+    <M as MapProperties>::Value: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::keys_are_sorted";
 
-        fn keys_property(&self) -> impl Iterator<Item = &<Self as MapProperties>::Key> {
-            self.keys()
+    fn test(&mut self, subject: &M) -> bool {
+        let keys: Vec<_> = subject.keys_property().collect();
+        for (index, pair) in keys.windows(2).enumerate() {
+            if !matches!(
+                pair[0].partial_cmp(pair[1]),
+                Some(Ordering::Less | Ordering::Equal)
+            ) {
+                self.out_of_order.insert(index + 1);
+            }
         }
+        self.out_of_order.is_empty()
+    }
 
-        fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
-            self.values()
-        }
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &M,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual_entries: Vec<_> = actual.entries_property().collect();
+        let marked_actual = mark_selected_entries_in_map(
+            &actual_entries,
+            &self.out_of_order,
+            format,
+            mark_unexpected_string,
+        );
+        format!(
+            "expected {expression} to have its keys sorted in ascending order\n   but was: {marked_actual}\n  expected: keys in ascending order"
+        )
+    }
+}
 
-        fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
-            self.iter()
-        }
+impl<S, E, R> AssertMapEquality<E> for Spec<'_, S, R>
+where
+    S: MapProperties + Debug,
+    <S as MapProperties>::Key: PartialEq<<E as MapProperties>::Key> + Debug,
+    <S as MapProperties>::Value: PartialEq<<E as MapProperties>::Value> + Debug,
+    E: MapProperties,
+    <E as MapProperties>::Key: Debug,
+    <E as MapProperties>::Value: Debug,
+    R: FailingStrategy,
+{
+    fn is_equal_to_map(self, expected: E) -> Self {
+        self.expecting(map_is_equal_to(expected))
     }
+}
 
-    impl<K, V, H> MapProperties for &mut HashMap<K, V, H> {
-        type Key = K;
-        type Value = V;
+impl<M, E> Expectation<M> for MapIsEqualTo<E>
+where
+    M: MapProperties,
+    <M as MapProperties>::Key: PartialEq<<E as MapProperties>::Key> + Debug,
+    <M as MapProperties>::Value: PartialEq<<E as MapProperties>::Value> + Debug,
+    E: MapProperties,
+    <E as MapProperties>::Key: Debug,
+    <E as MapProperties>::Value: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_equal_to_map";
 
-        fn keys_property(&self) -> impl Iterator<Item = &<Self as MapProperties>::Key> {
-            self.keys()
+    fn test(&mut self, subject: &M) -> bool {
+        let expected_entries: Vec<_> = self.expected.entries_property().collect();
+        let mut matched = HashSet::new();
+        for (actual_index, (actual_key, actual_value)) in subject.entries_property().enumerate() {
+            let expected_index = expected_entries
+                .iter()
+                .enumerate()
+                .position(|(index, (key, _))| !matched.contains(&index) && actual_key == *key);
+            if let Some(expected_index) = expected_index {
+                matched.insert(expected_index);
+                if actual_value != expected_entries[expected_index].1 {
+                    self.different_actual.insert(actual_index);
+                    self.different_expected.insert(expected_index);
+                }
+            } else {
+                self.extra.insert(actual_index);
+            }
         }
+        self.missing = (0..expected_entries.len())
+            .filter(|index| !matched.contains(index))
+            .collect();
+        self.missing.is_empty() && self.extra.is_empty() && self.different_actual.is_empty()
+    }
 
-        fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
-            self.values()
-        }
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &M,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual_entries: Vec<_> = actual.entries_property().collect();
+        let expected_entries: Vec<_> = self.expected.entries_property().collect();
+        let unexpected = self.extra.union(&self.different_actual).copied().collect();
+        let missing = self
+            .missing
+            .union(&self.different_expected)
+            .copied()
+            .collect();
+        let marked_actual = mark_selected_entries_in_map(
+            &actual_entries,
+            &unexpected,
+            format,
+            mark_unexpected_string,
+        );
+        let marked_expected =
+            mark_selected_entries_in_map(&expected_entries, &missing, format, mark_missing_string);
+        // No entry is selected, so the expected map is formatted without highlights.
+        let expected_map = mark_selected_entries_in_map(
+            &expected_entries,
+            &HashSet::new(),
+            format,
+            mark_missing_string,
+        );
 
-        fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
-            self.iter()
+        let mut differences = MapDifferences {
+            only_in_actual: entries_at(&actual_entries, &self.extra),
+            only_in_expected: entries_at(&expected_entries, &self.missing),
+            different_values: Vec::new(),
+        };
+        for (actual_index, (key, actual_value)) in actual_entries.iter().enumerate() {
+            if !self.different_actual.contains(&actual_index) {
+                continue;
+            }
+            if let Some((_, expected_value)) = expected_entries
+                .iter()
+                .enumerate()
+                .find(|(index, (expected_key, _))| {
+                    self.different_expected.contains(index) && *key == *expected_key
+                })
+                .map(|(_, entry)| entry)
+            {
+                differences.different_values.extend(entry_differences(
+                    key,
+                    actual_value,
+                    expected_value,
+                ));
+            }
         }
+        let differences = format_map_differences(&differences, format);
+
+        format!(
+            "expected {expression} to be equal to map {expected_map}\n   but was: {marked_actual}\n  expected: {marked_expected}{differences}"
+        )
     }
 }
 
-mod btree_map_impls {
+/// Returns the debug strings of the key and the value of the entries at the
+/// given indices in the order of the entries.
+fn entries_at<K, V>(entries: &[(&K, &V)], indices: &HashSet<usize>) -> Vec<(String, String)>
+where
+    K: Debug + ?Sized,
+    V: Debug + ?Sized,
+{
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| indices.contains(index))
+        .map(|(_, (key, value))| (format!("{key:?}"), format!("{value:?}")))
+        .collect()
+}
+
+mod hashbrown_impls {
+    use super::take_value_of;
+    use crate::assertions::AssertMapValue;
     use crate::properties::MapProperties;
-    use crate::std::collections::BTreeMap;
+    use crate::spec::{FailingStrategy, Spec};
+    use crate::std::fmt::Debug;
     use crate::std::iter::Iterator;
+    use hashbrown::HashMap;
 
-    impl<K, V> MapProperties for BTreeMap<K, V> {
+    impl<K, V, H> MapProperties for HashMap<K, V, H> {
         type Key = K;
         type Value = V;
 
@@ -586,7 +923,32 @@ mod btree_map_impls {
         }
     }
 
-    impl<K, V> MapProperties for &BTreeMap<K, V> {
+    impl<'a, K, V, H, E, R> AssertMapValue<E> for Spec<'a, HashMap<K, V, H>, R>
+    where
+        K: PartialEq<E> + Debug,
+        V: Debug,
+        E: Debug,
+        R: FailingStrategy,
+    {
+        type Value = Spec<'a, V, R>;
+
+        fn value_of(self, key: E) -> Self::Value {
+            take_value_of(self, key)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_hashmap_impls {
+    use super::take_value_of;
+    use crate::assertions::AssertMapValue;
+    use crate::properties::MapProperties;
+    use crate::spec::{FailingStrategy, Spec};
+    use crate::std::collections::HashMap;
+    use crate::std::fmt::Debug;
+    use crate::std::iter::Iterator;
+
+    impl<K, V, H> MapProperties for HashMap<K, V, H> {
         type Key = K;
         type Value = V;
 
@@ -603,7 +965,33 @@ mod btree_map_impls {
         }
     }
 
-    impl<K, V> MapProperties for &mut BTreeMap<K, V> {
+    impl<'a, K, V, H, E, R> AssertMapValue<E> for Spec<'a, HashMap<K, V, H>, R>
+    where
+        K: PartialEq<E> + Debug,
+        V: Debug,
+        E: Debug,
+        R: FailingStrategy,
+    {
+        type Value = Spec<'a, V, R>;
+
+        fn value_of(self, key: E) -> Self::Value {
+            take_value_of(self, key)
+        }
+    }
+}
+
+mod btree_map_impls {
+    use super::take_value_of;
+    use crate::assertions::AssertMapValue;
+    use crate::properties::{DefinedOrderProperty, MapProperties};
+    use crate::spec::{FailingStrategy, Spec};
+    use crate::std::collections::BTreeMap;
+    use crate::std::fmt::Debug;
+    use crate::std::iter::Iterator;
+
+    impl<K, V> DefinedOrderProperty for BTreeMap<K, V> {}
+
+    impl<K, V> MapProperties for BTreeMap<K, V> {
         type Key = K;
         type Value = V;
 
@@ -619,6 +1007,20 @@ mod btree_map_impls {
             self.iter()
         }
     }
+
+    impl<'a, K, V, E, R> AssertMapValue<E> for Spec<'a, BTreeMap<K, V>, R>
+    where
+        K: PartialEq<E> + Debug,
+        V: Debug,
+        E: Debug,
+        R: FailingStrategy,
+    {
+        type Value = Spec<'a, V, R>;
+
+        fn value_of(self, key: E) -> Self::Value {
+            take_value_of(self, key)
+        }
+    }
 }
 
 #[cfg(test)]
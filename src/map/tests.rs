@@ -33,6 +33,43 @@ mod hashbrown {
         assert_that(&subject).has_length_in_range(3..=4);
     }
 
+    #[test]
+    fn hashmap_has_capacity_at_least() {
+        let mut subject: HashMap<_, _> = HashMap::with_capacity(10);
+        subject.insert(5, "five");
+
+        assert_that(&subject).has_capacity_at_least(10);
+    }
+
+    #[test]
+    fn hashmap_has_capacity_and_spare_capacity() {
+        let mut subject: HashMap<_, _> = HashMap::with_capacity(10);
+        subject.extend([(5, "five"), (1, "one"), (4, "four")]);
+        let capacity = subject.capacity();
+
+        assert_that(&subject)
+            .has_capacity(capacity)
+            .has_spare_capacity_of(capacity - 3);
+    }
+
+    #[test]
+    fn verify_hashmap_has_capacity_at_least_fails() {
+        let subject: HashMap<i32, &str> = HashMap::new();
+
+        let failures = verify_that(subject)
+            .named("my_map")
+            .has_capacity_at_least(10)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected my_map to have a capacity of at least 10
+   but was: 0
+  expected: >= 10
+"]
+        );
+    }
+
     #[test]
     fn hashmap_contains_key_value_pair() {
         let subject: HashMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
@@ -397,6 +434,24 @@ mod hashbrown {
             )]
         );
     }
+
+    #[test]
+    fn hashmap_value_of_key() {
+        let subject: HashMap<_, _> = [("content-type", "application/json")].into();
+
+        assert_that(&subject)
+            .value_of("content-type")
+            .is_equal_to(&"application/json");
+    }
+
+    #[test]
+    fn owned_hashmap_value_of_key() {
+        let subject: HashMap<_, _> = [("content-type", "application/json")].into();
+
+        assert_that(subject)
+            .value_of("content-type")
+            .is_equal_to("application/json");
+    }
 }
 
 #[cfg(feature = "std")]
@@ -405,6 +460,17 @@ mod std_hash_map {
     use crate::std::collections::HashMap;
     use crate::std::format;
 
+    #[test]
+    fn hashmap_is_equal_to_btree_map() {
+        let subject: HashMap<_, _> = [("alpha", 1), ("beta", 2), ("gamma", 3)].into();
+
+        assert_that(subject).is_equal_to_map(crate::std::collections::BTreeMap::from([
+            ("gamma", 3),
+            ("alpha", 1),
+            ("beta", 2),
+        ]));
+    }
+
     #[test]
     fn hashmap_is_empty() {
         let subject: HashMap<usize, &str> = HashMap::new();
@@ -434,6 +500,43 @@ mod std_hash_map {
         assert_that(&subject).has_length_in_range(3..=4);
     }
 
+    #[test]
+    fn hashmap_has_capacity_at_least() {
+        let mut subject: HashMap<_, _> = HashMap::with_capacity(10);
+        subject.insert(5, "five");
+
+        assert_that(&subject).has_capacity_at_least(10);
+    }
+
+    #[test]
+    fn hashmap_has_capacity_and_spare_capacity() {
+        let mut subject: HashMap<_, _> = HashMap::with_capacity(10);
+        subject.extend([(5, "five"), (1, "one"), (4, "four")]);
+        let capacity = subject.capacity();
+
+        assert_that(&subject)
+            .has_capacity(capacity)
+            .has_spare_capacity_of(capacity - 3);
+    }
+
+    #[test]
+    fn verify_hashmap_has_capacity_at_least_fails() {
+        let subject: HashMap<i32, &str> = HashMap::new();
+
+        let failures = verify_that(subject)
+            .named("my_map")
+            .has_capacity_at_least(10)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected my_map to have a capacity of at least 10
+   but was: 0
+  expected: >= 10
+"]
+        );
+    }
+
     #[test]
     fn hashmap_contains_key_value_pair() {
         let subject: HashMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
@@ -476,6 +579,17 @@ mod std_hash_map {
         assert_that(&mut subject).contains_key(5);
     }
 
+    #[test]
+    fn double_borrowed_hashmap_contains_key_and_value() {
+        let subject: HashMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+        let borrowed = &subject;
+
+        assert_that(&borrowed)
+            .contains_key(5)
+            .contains_value("four")
+            .has_length(3);
+    }
+
     #[test]
     fn verify_hashmap_contains_key_fails() {
         let subject: HashMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
@@ -726,12 +840,33 @@ mod std_hash_map {
             )]
         );
     }
+
+    #[test]
+    fn hashmap_value_of_key() {
+        let subject: HashMap<String, String> =
+            [("content-type".to_string(), "application/json".to_string())].into();
+
+        assert_that(&subject)
+            .value_of("content-type")
+            .is_equal_to("application/json");
+    }
+
+    #[test]
+    fn owned_hashmap_value_of_key() {
+        let headers: HashMap<String, String> =
+            [("content-type".to_string(), "application/json".to_string())].into();
+
+        assert_that!(headers)
+            .value_of("content-type")
+            .is_equal_to("application/json");
+    }
 }
 
 mod btree_map {
     use crate::prelude::*;
     use crate::std::collections::BTreeMap;
     use crate::std::format;
+    use crate::std::vec;
     use crate::std::vec::Vec;
 
     #[test]
@@ -805,6 +940,17 @@ mod btree_map {
         assert_that(&mut subject).contains_key(5);
     }
 
+    #[test]
+    fn double_borrowed_btree_map_contains_key_and_value() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+        let borrowed = &subject;
+
+        assert_that(&borrowed)
+            .contains_key(5)
+            .contains_value("four")
+            .has_length(3);
+    }
+
     #[test]
     fn verify_btree_map_contains_key_fails() {
         let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
@@ -1050,11 +1196,297 @@ mod btree_map {
             )]
         );
     }
+
+    #[test]
+    fn btree_map_value_of_key() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        assert_that(&subject).value_of(4).is_equal_to(&"four");
+    }
+
+    #[test]
+    fn btree_map_value_of_key_continues_with_assertions_on_value() {
+        let subject: BTreeMap<&str, Vec<u8>> = [("alpha", vec![1, 2, 3]), ("beta", vec![])].into();
+
+        assert_that(&subject)
+            .value_of("alpha")
+            .is_equal_to(&vec![1, 2, 3]);
+        assert_that(&subject).value_of("beta").is_empty();
+    }
+
+    #[test]
+    fn owned_btree_map_value_of_key_continues_with_assertions_on_value() {
+        let subject: BTreeMap<&str, Vec<u8>> = [("alpha", vec![1, 2, 3]), ("beta", vec![])].into();
+
+        assert_that(subject)
+            .value_of("alpha")
+            .contains_exactly([1, 2, 3]);
+    }
+
+    #[test]
+    fn verify_owned_btree_map_value_of_key_fails_if_key_is_missing() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .value_of(7)
+            .is_equal_to("seven")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected foo_map to contain the key 7
+   but was: {1: "one", 4: "four", 5: "five"}
+  expected: 7
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_btree_map_value_of_key_fails_if_key_is_missing() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        let failures = verify_that(&subject)
+            .named("foo_map")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .value_of(7)
+            .is_equal_to(&"seven")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected foo_map to contain the key 7
+   but was: {1: "one", 4: "four", 5: "five"}
+  expected: 7
+"#]
+        );
+    }
+
+    #[test]
+    #[should_panic = "expected foo_map to contain the key 7\n   but was: {1: \"one\", 4: \"four\", 5: \"five\"}\n  expected: 7\n"]
+    fn btree_map_value_of_key_panics_if_key_is_missing() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        assert_that(&subject)
+            .named("foo_map")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .value_of(7)
+            .is_equal_to(&"seven");
+    }
+
+    #[test]
+    fn btree_map_first_entry_is() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        assert_that(subject).first_entry_is((1, "one"));
+    }
+
+    #[test]
+    fn verify_btree_map_first_entry_is_fails() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .first_entry_is((4, "four"))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected foo_map to have the first entry (4, "four")
+   but was: {1: "one", 4: "four", 5: "five"}
+  expected: (4, "four")
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_empty_btree_map_first_entry_is_fails() {
+        let subject: BTreeMap<i32, &str> = BTreeMap::new();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .first_entry_is((1, "one"))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected foo_map to have the first entry (1, "one")
+   but was: {}
+  expected: (1, "one")
+"#]
+        );
+    }
+
+    #[test]
+    fn borrowed_btree_map_last_entry_is() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        assert_that(&subject).last_entry_is((5, "five"));
+    }
+
+    #[test]
+    fn verify_btree_map_last_entry_is_fails() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .last_entry_is((5, "FIVE"))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected foo_map to have the last entry (5, "FIVE")
+   but was: {1: "one", 4: "four", 5: "five"}
+  expected: (5, "FIVE")
+"#]
+        );
+    }
+
+    #[test]
+    fn btree_map_keys_are_sorted() {
+        let subject: BTreeMap<_, _> = [("gamma", 3), ("alpha", 1), ("beta", 2)].into();
+
+        assert_that(subject).keys_are_sorted();
+    }
+
+    #[test]
+    fn btree_map_contains_entries_in_order() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four"), (6, "six")].into();
+
+        assert_that(subject).contains_entries_in_order([(1, "one"), (5, "five"), (6, "six")]);
+    }
+
+    #[test]
+    fn verify_btree_map_contains_entries_in_order_fails() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four"), (6, "six")].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .contains_entries_in_order([(4, "four"), (1, "one"), (6, "six"), (7, "seven")])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r#"expected foo_map to contain the entries [(4, "four"), (1, "one"), (6, "six"), (7, "seven")] in order
+   but was: {1: "one", 4: "four", 5: "five", 6: "six"}
+  expected: [(4, "four"), (1, "one"), (6, "six"), (7, "seven")]
+   missing: [(1, "one"), (7, "seven")]
+"#
+            ]
+        );
+    }
+
+    #[test]
+    fn mutable_borrowed_btree_map_contains_entries_in_order() {
+        let mut subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        assert_that(&mut subject).contains_entries_in_order([(1, "one"), (4, "four")]);
+    }
+
+    #[test]
+    fn btree_map_value_of_key_continues_with_first_entry_is() {
+        let subject: BTreeMap<&str, BTreeMap<u8, char>> =
+            [("alpha", [(2, 'b'), (1, 'a')].into())].into();
+
+        assert_that(&subject)
+            .value_of("alpha")
+            .first_entry_is((1, 'a'))
+            .last_entry_is((2, 'b'))
+            .keys_are_sorted();
+    }
+
+    #[test]
+    fn btree_map_is_equal_to_map_with_same_entries() {
+        let subject: BTreeMap<_, _> = [(1, "one"), (2, "two"), (3, "three")].into();
+
+        assert_that(&subject).is_equal_to_map(BTreeMap::from([
+            (3, "three"),
+            (1, "one"),
+            (2, "two"),
+        ]));
+        assert_that(subject).is_equal_to_map(BTreeMap::from([
+            (2, "two"),
+            (3, "three"),
+            (1, "one"),
+        ]));
+    }
+
+    #[test]
+    fn empty_btree_map_is_equal_to_empty_map() {
+        let subject: BTreeMap<u8, char> = BTreeMap::new();
+
+        assert_that(subject).is_equal_to_map(BTreeMap::<u8, char>::new());
+    }
+
+    #[test]
+    fn verify_btree_map_is_equal_to_map_fails() {
+        let subject: BTreeMap<_, _> = [("alpha", 1), ("beta", 2), ("gamma", 3)].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .is_equal_to_map(BTreeMap::from([("gamma", 3), ("delta", 4), ("beta", 20)]))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r#"expected foo_map to be equal to map {"beta": 20, "delta": 4, "gamma": 3}
+   but was: {"alpha": 1, "beta": 2, "gamma": 3}
+  expected: {"beta": 20, "delta": 4, "gamma": 3}
+    only in actual: {"alpha": 1}
+  only in expected: {"delta": 4}
+  different values:
+    ["beta"]: but was 2, expected 20
+"#
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_btree_map_is_equal_to_map_with_structured_values_fails() {
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        let subject: BTreeMap<_, _> = [('a', Point { x: 1, y: 2 })].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .is_equal_to_map(BTreeMap::from([('a', Point { x: 1, y: -2 })]))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected foo_map to be equal to map {'a': Point { x: 1, y: -2 }}
+   but was: {'a': Point { x: 1, y: 2 }}
+  expected: {'a': Point { x: 1, y: -2 }}
+  different values:
+    ['a'].y: but was 2, expected -2
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn btree_map_value_of_key_is_equal_to_map() {
+        let subject: BTreeMap<&str, BTreeMap<u8, char>> =
+            [("alpha", [(2, 'b'), (1, 'a')].into())].into();
+
+        assert_that(&subject)
+            .value_of("alpha")
+            .is_equal_to_map(BTreeMap::from([(1, 'a'), (2, 'b')]));
+    }
 }
 
 #[cfg(feature = "colored")]
 mod colored {
     use crate::prelude::*;
+    use crate::std::collections::BTreeMap;
     use crate::std::format;
     use crate::std::vec::Vec;
     use hashbrown::HashMap;
@@ -1303,4 +1735,72 @@ mod colored {
             )]
         );
     }
+
+    #[test]
+    fn highlight_diffs_btree_map_first_entry_is() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .with_diff_format(DIFF_FORMAT_RED_GREEN)
+            .first_entry_is((4, "four"))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected foo_map to have the first entry (4, \"four\")\n   \
+                    but was: {\u{1b}[31m1: \"one\"\u{1b}[0m, 4: \"four\", 5: \"five\"}\n  \
+                   expected: \u{1b}[32m(4, \"four\")\u{1b}[0m\n\
+                "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_btree_map_is_equal_to_map() {
+        let subject: BTreeMap<_, _> = [(1, "one"), (2, "two")].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .with_diff_format(DIFF_FORMAT_RED_GREEN)
+            .is_equal_to_map(BTreeMap::from([(2, "deux"), (3, "three")]))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected foo_map to be equal to map {2: \"deux\", 3: \"three\"}\n   \
+                    but was: {\u{1b}[31m1: \"one\"\u{1b}[0m, \u{1b}[31m2: \"two\"\u{1b}[0m}\n  \
+                   expected: {\u{1b}[32m2: \"deux\"\u{1b}[0m, \u{1b}[32m3: \"three\"\u{1b}[0m}\n    \
+                   only in actual: \u{1b}[31m{1: \"one\"}\u{1b}[0m\n  \
+                   only in expected: \u{1b}[32m{3: \"three\"}\u{1b}[0m\n  \
+                   different values:\n    \
+                   [2]: but was \"\u{1b}[31mtwo\u{1b}[0m\", expected \"\u{1b}[32mdeux\u{1b}[0m\"\n\
+                "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_btree_map_contains_entries_in_order() {
+        let subject: BTreeMap<_, _> = [(5, "five"), (1, "one"), (4, "four")].into();
+
+        let failures = verify_that(subject)
+            .named("foo_map")
+            .with_diff_format(DIFF_FORMAT_RED_GREEN)
+            .contains_entries_in_order([(4, "four"), (1, "one")])
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected foo_map to contain the entries [(4, \"four\"), (1, \"one\")] in order\n   \
+                    but was: {1: \"one\", 4: \"four\", 5: \"five\"}\n  \
+                   expected: [(4, \"four\"), \u{1b}[32m(1, \"one\")\u{1b}[0m]\n   \
+                    missing: [(1, \"one\")]\n\
+                "
+            ]
+        );
+    }
 }
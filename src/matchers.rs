@@ -0,0 +1,80 @@
+//! The stable extension API for building custom assertions on top of the
+//! expectations provided by this crate.
+//!
+//! This module re-exports all expectations together with the functions to
+//! construct them, and the traits and types needed to implement custom
+//! expectations. Crates that provide their own assertions should import the
+//! items from this module.
+//!
+//! # Stability
+//!
+//! The items re-exported by this module follow semantic versioning:
+//!
+//! * Expectations are constructed by their constructor functions, like
+//!   [`iterator_contains_exactly()`] or [`string_contains()`]. The signatures
+//!   of these functions only change with a new major version (or minor version
+//!   before 1.0).
+//! * Expectations that hold state computed while testing the subject, like
+//!   the sets of missing or extra elements, are `#[non_exhaustive]`. Outside
+//!   this crate they can only be constructed by their constructor functions,
+//!   so new state fields may be added in a minor release. Their fields can
+//!   still be read, e.g. to inspect the result of a test.
+//! * All other expectations can also be constructed with a struct literal.
+//!   Their fields only change with a new major version (or minor version
+//!   before 1.0).
+//!
+//! # Wrapping foreign predicates
+//!
+//! Any predicate function can be turned into an [`Expectation`] by either
+//! using [`satisfies()`], which forms a generic failure message, or by using
+//! [`from_fn()`], which takes a function to form a custom failure message.
+//!
+//! ```
+//! use asserting::matchers::{from_fn, satisfies};
+//! use asserting::prelude::*;
+//!
+//! fn is_leap_year(year: &u16) -> bool {
+//!     year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+//! }
+//!
+//! assert_that!(2024).expecting(satisfies(is_leap_year));
+//!
+//! assert_that!(2000).expecting(from_fn(is_leap_year, |expression, actual| {
+//!     format!("expected {expression} to be a leap year\n   but was: {actual}")
+//! }));
+//! ```
+//!
+//! # Reusing provided expectations
+//!
+//! The expectations provided by this crate can be reused in custom assertion
+//! methods.
+//!
+//! ```
+//! use asserting::matchers::{iterator_contains_exactly, not, string_contains};
+//! use asserting::prelude::*;
+//! use asserting::spec::{FailingStrategy, Spec};
+//!
+//! trait AssertGreeting {
+//!     fn is_polite_greeting(self) -> Self;
+//! }
+//!
+//! impl<R> AssertGreeting for Spec<'_, &str, R>
+//! where
+//!     R: FailingStrategy,
+//! {
+//!     fn is_polite_greeting(self) -> Self {
+//!         self.expecting(string_contains("please"))
+//!             .expecting(not(string_contains("!")))
+//!     }
+//! }
+//!
+//! assert_that!("hello, please come in").is_polite_greeting();
+//!
+//! assert_that!(vec![1, 2, 3]).expecting(iterator_contains_exactly([1, 2, 3]));
+//! ```
+
+pub use crate::expectations::*;
+pub use crate::spec::{
+    DiffFormat, DiffFormatBuilder, DiffGranularity, Expectation, Expression, Invertible,
+    Truncation, Unknown,
+};
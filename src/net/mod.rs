@@ -0,0 +1,321 @@
+//! Implementation of assertions for IP addresses and socket addresses.
+
+use crate::assertions::{AssertIpAddr, AssertSocketAddr};
+use crate::colored::{mark_diff_str, mark_missing_string, mark_unexpected_string};
+use crate::expectations::{
+    HasIp, HasPort, IsIpv4, IsIpv6, IsLoopback, IsPrivate, has_ip, has_port, is_ipv4, is_ipv6,
+    is_loopback, is_private,
+};
+use crate::properties::{IpAddrProperty, PortProperty};
+use crate::spec::{
+    DiffFormat, EvalError, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::format;
+use crate::std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use crate::std::string::{String, ToString};
+
+impl IpAddrProperty for IpAddr {
+    fn ip_addr_property(&self) -> IpAddr {
+        *self
+    }
+}
+
+impl IpAddrProperty for Ipv4Addr {
+    fn ip_addr_property(&self) -> IpAddr {
+        IpAddr::V4(*self)
+    }
+}
+
+impl IpAddrProperty for Ipv6Addr {
+    fn ip_addr_property(&self) -> IpAddr {
+        IpAddr::V6(*self)
+    }
+}
+
+impl IpAddrProperty for SocketAddr {
+    fn ip_addr_property(&self) -> IpAddr {
+        self.ip()
+    }
+}
+
+impl IpAddrProperty for SocketAddrV4 {
+    fn ip_addr_property(&self) -> IpAddr {
+        IpAddr::V4(*self.ip())
+    }
+}
+
+impl IpAddrProperty for SocketAddrV6 {
+    fn ip_addr_property(&self) -> IpAddr {
+        IpAddr::V6(*self.ip())
+    }
+}
+
+impl PortProperty for SocketAddr {
+    fn port_property(&self) -> u16 {
+        self.port()
+    }
+}
+
+impl PortProperty for SocketAddrV4 {
+    fn port_property(&self) -> u16 {
+        self.port()
+    }
+}
+
+impl PortProperty for SocketAddrV6 {
+    fn port_property(&self) -> u16 {
+        self.port()
+    }
+}
+
+impl<S, R> AssertIpAddr for Spec<'_, S, R>
+where
+    S: IpAddrProperty,
+    R: FailingStrategy,
+{
+    fn is_ipv4(self) -> Self {
+        self.expecting(is_ipv4())
+    }
+
+    fn is_ipv6(self) -> Self {
+        self.expecting(is_ipv6())
+    }
+
+    fn is_loopback(self) -> Self {
+        self.expecting(is_loopback())
+    }
+
+    fn is_private(self) -> Self {
+        self.expecting(is_private())
+    }
+
+    fn has_ip(self, expected: impl AsRef<str>) -> Self {
+        self.expecting(has_ip(expected))
+    }
+}
+
+impl<S, R> AssertSocketAddr for Spec<'_, S, R>
+where
+    S: PortProperty,
+    R: FailingStrategy,
+{
+    fn has_port(self, expected: u16) -> Self {
+        self.expecting(has_port(expected))
+    }
+}
+
+fn address_kind_message(
+    expression: &Expression<'_>,
+    actual: IpAddr,
+    kind: &str,
+    inverted: bool,
+    format: &DiffFormat,
+) -> String {
+    let not = if inverted { "not " } else { "" };
+    let marked_actual = mark_unexpected_string(&actual.to_string(), format);
+    let marked_expected = mark_missing_string(&format!("<{kind}>"), format);
+    format!(
+        "expected {expression} to {not}be {kind}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+    )
+}
+
+impl<S> Expectation<S> for IsIpv4
+where
+    S: IpAddrProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_ipv4";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.ip_addr_property().is_ipv4()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        address_kind_message(
+            expression,
+            actual.ip_addr_property(),
+            "an IPv4 address",
+            inverted,
+            format,
+        )
+    }
+}
+
+impl Invertible for IsIpv4 {}
+
+impl<S> Expectation<S> for IsIpv6
+where
+    S: IpAddrProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_ipv6";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.ip_addr_property().is_ipv6()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        address_kind_message(
+            expression,
+            actual.ip_addr_property(),
+            "an IPv6 address",
+            inverted,
+            format,
+        )
+    }
+}
+
+impl Invertible for IsIpv6 {}
+
+impl<S> Expectation<S> for IsLoopback
+where
+    S: IpAddrProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_loopback";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.ip_addr_property().is_loopback()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        address_kind_message(
+            expression,
+            actual.ip_addr_property(),
+            "a loopback address",
+            inverted,
+            format,
+        )
+    }
+}
+
+impl Invertible for IsLoopback {}
+
+impl<S> Expectation<S> for IsPrivate
+where
+    S: IpAddrProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_private";
+
+    fn test(&mut self, subject: &S) -> bool {
+        match subject.ip_addr_property() {
+            IpAddr::V4(address) => address.is_private(),
+            IpAddr::V6(address) => address.is_unique_local(),
+        }
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        address_kind_message(
+            expression,
+            actual.ip_addr_property(),
+            "a private address",
+            inverted,
+            format,
+        )
+    }
+}
+
+impl Invertible for IsPrivate {}
+
+impl<S, E> Expectation<S> for HasIp<E>
+where
+    S: IpAddrProperty,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_ip";
+
+    fn test(&mut self, subject: &S) -> bool {
+        self.try_test(subject).unwrap_or(false)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        let expected_string = self.expected.as_ref();
+        expected_string
+            .parse::<IpAddr>()
+            .map(|expected| subject.ip_addr_property() == expected)
+            .map_err(|err| {
+                EvalError::new(format!(
+                    "the expected string {expected_string:?} is not a valid IP address: {err}"
+                ))
+            })
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected_string = self.expected.as_ref();
+        match expected_string.parse::<IpAddr>() {
+            Ok(expected) => {
+                let actual = actual.ip_addr_property().to_string();
+                let (marked_actual, marked_expected) =
+                    mark_diff_str(&actual, &expected.to_string(), format);
+                format!(
+                    "expected {expression} to {not}have IP {expected_string:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+                )
+            },
+            Err(err) => format!(
+                "expected {expression} to {not}have IP {expected_string:?}\n  but the expected string is not a valid IP address: {err}"
+            ),
+        }
+    }
+}
+
+impl<E> Invertible for HasIp<E> {}
+
+impl<S> Expectation<S> for HasPort
+where
+    S: PortProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_port";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.port_property() == self.expected
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected;
+        let marked_actual = mark_unexpected_string(&actual.port_property().to_string(), format);
+        let marked_expected = mark_missing_string(&expected.to_string(), format);
+        format!(
+            "expected {expression} to {not}have port {expected}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl Invertible for HasPort {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,234 @@
+use crate::expectations::{has_ip, is_private, not};
+use crate::prelude::*;
+use crate::std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+#[test]
+fn ip_addr_is_ipv4() {
+    let subject = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 12));
+
+    assert_that(subject).is_ipv4();
+}
+
+#[test]
+fn ip_addr_is_ipv6() {
+    let subject = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 7));
+
+    assert_that(subject).is_ipv6();
+}
+
+#[test]
+fn ipv4_and_ipv6_addr_is_loopback() {
+    assert_that(Ipv4Addr::LOCALHOST).is_loopback();
+    assert_that(Ipv4Addr::new(127, 0, 1, 1)).is_loopback();
+    assert_that(Ipv6Addr::LOCALHOST).is_loopback();
+}
+
+#[test]
+fn ipv4_addr_is_private() {
+    assert_that(Ipv4Addr::new(10, 0, 0, 1)).is_private();
+    assert_that(Ipv4Addr::new(172, 31, 255, 1)).is_private();
+    assert_that(Ipv4Addr::new(192, 168, 100, 1)).is_private();
+}
+
+#[test]
+fn ipv6_unique_local_addr_is_private() {
+    assert_that(Ipv6Addr::new(0xfd12, 0x3456, 0, 0, 0, 0, 0, 1)).is_private();
+}
+
+#[test]
+fn ip_addr_is_not_private() {
+    let subject = Ipv4Addr::new(8, 8, 8, 8);
+
+    assert_that(subject).expecting(not(is_private()));
+}
+
+#[test]
+fn ip_addr_has_ip() {
+    let subject = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+
+    assert_that(subject).has_ip("10.0.0.1");
+}
+
+#[test]
+fn ipv6_addr_has_ip_in_other_notation() {
+    let subject = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+
+    assert_that(subject).has_ip("2001:0db8:0000:0000:0000:0000:0000:0001");
+}
+
+#[test]
+fn socket_addr_has_ip_and_port() {
+    let subject = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 8080);
+
+    assert_that(subject)
+        .is_ipv4()
+        .is_private()
+        .has_ip("10.0.0.1")
+        .has_port(8080);
+}
+
+#[test]
+fn socket_addr_v4_and_v6_have_port() {
+    assert_that(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 5432))
+        .is_loopback()
+        .has_port(5432);
+    assert_that(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0))
+        .is_ipv6()
+        .has_port(443);
+}
+
+#[test]
+fn borrowed_socket_addr_has_port() {
+    let subject = SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 9000);
+
+    assert_that(&subject).has_port(9000);
+}
+
+#[test]
+fn verify_ip_addr_is_ipv6_fails() {
+    let subject = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 12));
+
+    let failures = verify_that(subject)
+        .named("my_address")
+        .is_ipv6()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_address to be an IPv6 address
+   but was: 192.168.0.12
+  expected: <an IPv6 address>
+"]
+    );
+}
+
+#[test]
+fn verify_ip_addr_is_loopback_fails() {
+    let subject = Ipv4Addr::new(10, 0, 0, 1);
+
+    let failures = verify_that(subject)
+        .named("my_address")
+        .is_loopback()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_address to be a loopback address
+   but was: 10.0.0.1
+  expected: <a loopback address>
+"]
+    );
+}
+
+#[test]
+fn verify_ip_addr_is_not_private_fails() {
+    let subject = Ipv4Addr::new(10, 0, 0, 1);
+
+    let failures = verify_that(subject)
+        .named("my_address")
+        .expecting(not(is_private()))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_address to not be a private address
+   but was: 10.0.0.1
+  expected: not <a private address>
+"]
+    );
+}
+
+#[test]
+fn verify_socket_addr_has_ip_fails() {
+    let subject = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 8080);
+
+    let failures = verify_that(subject)
+        .named("my_socket")
+        .has_ip("10.0.0.2")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_socket to have IP "10.0.0.2"
+   but was: 10.0.0.1
+  expected: 10.0.0.2
+"#]
+    );
+}
+
+#[test]
+fn verify_ip_addr_has_ip_with_invalid_expected_ip_fails() {
+    let subject = Ipv4Addr::LOCALHOST;
+
+    let failures = verify_that(subject)
+        .named("my_address")
+        .has_ip("localhost")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"could not evaluate the assertion on my_address
+  error: the expected string "localhost" is not a valid IP address: invalid IP address syntax
+"#]
+    );
+}
+
+#[test]
+fn verify_ip_addr_does_not_have_invalid_expected_ip_fails() {
+    let subject = Ipv4Addr::LOCALHOST;
+
+    let failures = verify_that(subject)
+        .named("my_address")
+        .expecting(not(has_ip("localhost")))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"could not evaluate the assertion on my_address
+  error: the expected string "localhost" is not a valid IP address: invalid IP address syntax
+"#]
+    );
+}
+
+#[test]
+fn verify_socket_addr_has_port_fails() {
+    let subject = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 8080);
+
+    let failures = verify_that(subject)
+        .named("my_socket")
+        .has_port(8443)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_socket to have port 8443
+   but was: 8080
+  expected: 8443
+"]
+    );
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use crate::prelude::*;
+    use crate::std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    #[test]
+    fn highlight_diffs_socket_addr_has_ip() {
+        let subject = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 8080);
+
+        let failures = verify_that(subject)
+            .named("my_socket")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .has_ip("10.0.0.2")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected my_socket to have IP \"10.0.0.2\"\n   \
+                but was: 10.0.0.\u{1b}[31m1\u{1b}[0m\n  \
+               expected: 10.0.0.\u{1b}[34m2\u{1b}[0m\n\
+            "]
+        );
+    }
+}
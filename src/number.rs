@@ -1,17 +1,19 @@
 //! Implementations of assertions specific for numbers.
 
 use crate::assertions::{
-    AssertDecimalNumber, AssertInfinity, AssertNotANumber, AssertNumericIdentity, AssertSignum,
+    AssertDecimalNumber, AssertInfinity, AssertNotANumber, AssertNumericIdentity, AssertOverflow,
+    AssertSignum,
 };
-use crate::colored::{mark_missing, mark_missing_string, mark_unexpected};
+use crate::colored::{mark_missing, mark_missing_string, mark_unexpected, mark_unexpected_string};
 use crate::expectations::{
-    HasPrecisionOf, HasScaleOf, IsANumber, IsFinite, IsInfinite, IsInteger, IsNegative, IsOne,
-    IsPositive, IsZero, has_precision_of, has_scale_of, is_a_number, is_finite, is_infinite,
-    is_integer, is_negative, is_one, is_positive, is_zero, not,
+    DoesNotOverflow, HasPrecisionOf, HasScaleOf, IsANumber, IsFinite, IsInfinite, IsInteger,
+    IsNegative, IsOne, IsPositive, IsZero, Overflows, does_not_overflow, has_precision_of,
+    has_scale_of, is_a_number, is_finite, is_infinite, is_integer, is_negative, is_one,
+    is_positive, is_zero, not, overflows,
 };
 use crate::properties::{
-    AdditiveIdentityProperty, DecimalProperties, InfinityProperty, IsNanProperty,
-    MultiplicativeIdentityProperty, SignumProperty,
+    AdditiveIdentityProperty, CheckedArithmeticProperty, DecimalProperties, InfinityProperty,
+    IsNanProperty, MultiplicativeIdentityProperty, SignumProperty,
 };
 use crate::spec::{
     DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
@@ -46,6 +48,8 @@ impl<S> Expectation<S> for IsNegative
 where
     S: SignumProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_negative";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.is_negative_property()
     }
@@ -76,6 +80,8 @@ impl<S> Expectation<S> for IsPositive
 where
     S: SignumProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_positive";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.is_positive_property()
     }
@@ -120,6 +126,8 @@ impl<S> Expectation<S> for IsZero
 where
     S: AdditiveIdentityProperty + PartialEq + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_zero";
+
     fn test(&mut self, subject: &S) -> bool {
         *subject == <S as AdditiveIdentityProperty>::additive_identity()
     }
@@ -146,6 +154,8 @@ impl<S> Expectation<S> for IsOne
 where
     S: MultiplicativeIdentityProperty + PartialEq + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_one";
+
     fn test(&mut self, subject: &S) -> bool {
         *subject == <S as MultiplicativeIdentityProperty>::multiplicative_identity()
     }
@@ -186,6 +196,8 @@ impl<S> Expectation<S> for IsFinite
 where
     S: InfinityProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_finite";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.is_finite_property()
     }
@@ -216,6 +228,8 @@ impl<S> Expectation<S> for IsInfinite
 where
     S: InfinityProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_infinite";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.is_infinite_property()
     }
@@ -260,6 +274,8 @@ impl<S> Expectation<S> for IsANumber
 where
     S: IsNanProperty + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_a_number";
+
     fn test(&mut self, subject: &S) -> bool {
         !subject.is_nan_property()
     }
@@ -308,6 +324,8 @@ impl<S> Expectation<S> for HasScaleOf
 where
     S: DecimalProperties + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_scale_of";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.scale_property() == self.expected_scale
     }
@@ -335,6 +353,8 @@ impl<S> Expectation<S> for HasPrecisionOf
 where
     S: DecimalProperties + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_precision_of";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.precision_property() == self.expected_precision
     }
@@ -360,6 +380,8 @@ impl<S> Expectation<S> for IsInteger
 where
     S: DecimalProperties + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_integer";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.is_integer_property()
     }
@@ -383,3 +405,141 @@ where
         )
     }
 }
+
+impl<S, R> AssertOverflow<S> for Spec<'_, S, R>
+where
+    S: Debug,
+    R: FailingStrategy,
+{
+    fn overflows<F, O>(self, operation: F) -> Self
+    where
+        F: FnOnce(&S) -> O,
+        O: CheckedArithmeticProperty + Debug,
+    {
+        self.expecting(overflows(operation))
+    }
+
+    fn does_not_overflow<F, O>(self, operation: F) -> Self
+    where
+        F: FnOnce(&S) -> O,
+        O: CheckedArithmeticProperty + Debug,
+    {
+        self.expecting(does_not_overflow(operation))
+    }
+}
+
+impl<T> CheckedArithmeticProperty for Option<T> {
+    fn is_overflow_property(&self) -> bool {
+        self.is_none()
+    }
+
+    fn overflow_pattern_property() -> &'static str {
+        "None"
+    }
+
+    fn no_overflow_pattern_property() -> &'static str {
+        "Some(_)"
+    }
+}
+
+impl<T, E> CheckedArithmeticProperty for Result<T, E> {
+    fn is_overflow_property(&self) -> bool {
+        self.is_err()
+    }
+
+    fn overflow_pattern_property() -> &'static str {
+        "Err(_)"
+    }
+
+    fn no_overflow_pattern_property() -> &'static str {
+        "Ok(_)"
+    }
+}
+
+impl<S, F, O> Expectation<S> for Overflows<F>
+where
+    S: Debug,
+    F: FnOnce(&S) -> O,
+    O: CheckedArithmeticProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::overflows";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let Some(operation) = self.operation.take() else {
+            return false;
+        };
+        let result = operation(subject);
+        self.result = Some(format!("{result:?}"));
+        result.is_overflow_property()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        overflow_message(
+            expression,
+            "overflow",
+            actual,
+            self.result.as_deref(),
+            O::overflow_pattern_property(),
+            format,
+        )
+    }
+}
+
+impl<S, F, O> Expectation<S> for DoesNotOverflow<F>
+where
+    S: Debug,
+    F: FnOnce(&S) -> O,
+    O: CheckedArithmeticProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::does_not_overflow";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let Some(operation) = self.operation.take() else {
+            return false;
+        };
+        let result = operation(subject);
+        self.result = Some(format!("{result:?}"));
+        !result.is_overflow_property()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        overflow_message(
+            expression,
+            "not overflow",
+            actual,
+            self.result.as_deref(),
+            O::no_overflow_pattern_property(),
+            format,
+        )
+    }
+}
+
+fn overflow_message<S>(
+    expression: &Expression<'_>,
+    expectation: &str,
+    operands: &S,
+    result: Option<&str>,
+    expected_pattern: &str,
+    format: &DiffFormat,
+) -> String
+where
+    S: Debug + ?Sized,
+{
+    let marked_result = mark_unexpected_string(result.unwrap_or("<not evaluated>"), format);
+    let marked_expected = mark_missing_string(expected_pattern, format);
+    format!(
+        "expected the checked operation on {expression} to {expectation}\n   but was: {marked_result}\n  expected: {marked_expected}\n  operands: {operands:?}"
+    )
+}
@@ -1,10 +1,17 @@
 //! Implementation of assertions for `Option` values.
 
-use crate::assertions::{AssertHasValue, AssertOption, AssertOptionValue};
-use crate::colored::{mark_missing, mark_unexpected};
-use crate::expectations::{HasValue, IsNone, IsSome, has_value, is_none, is_some};
+use crate::assertions::{
+    AssertHasValue, AssertNestedOptionValue, AssertOption, AssertOptionHasDefaultValue,
+    AssertOptionIsNoneOr, AssertOptionValue,
+};
+use crate::colored::{mark_diff, mark_missing, mark_unexpected};
+use crate::expectations::{
+    HasValue, HasValueEqualToDefault, IsNestedSome, IsNone, IsNoneOr, IsSome, has_value,
+    has_value_equal_to_default, is_nested_some, is_none, is_none_or, is_some,
+};
 use crate::spec::{
-    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec, Unknown,
+    DiffFormat, DoFail, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+    Unknown,
 };
 use crate::std::fmt::Debug;
 use crate::std::{format, string::String};
@@ -43,13 +50,18 @@ where
 {
     type Some = Spec<'a, T, R>;
 
-    fn some(self) -> Self::Some {
-        self.mapping(|subject| match subject {
-            None => {
-                panic!("expected the subject to be `Some(_)`, but was `None`")
-            },
-            Some(value) => value,
-        })
+    fn some(mut self) -> Self::Some {
+        if !self.is_skipped() && self.subject().is_none() {
+            let message = is_some().message(
+                self.expression(),
+                &None::<Unknown>,
+                false,
+                self.diff_format(),
+            );
+            self.do_fail_with_message(message);
+        }
+        let expression = format!("{} unwrapped Some value", self.expression());
+        self.narrowing(|subject| subject).named(expression)
     }
 }
 
@@ -59,13 +71,46 @@ where
 {
     type Some = Spec<'a, &'a T, R>;
 
-    fn some(self) -> Self::Some {
-        self.mapping(|subject| match subject {
-            None => {
-                panic!("expected the subject to be `Some(_)`, but was `None`")
-            },
-            Some(value) => value,
-        })
+    fn some(mut self) -> Self::Some {
+        if !self.is_skipped() && self.subject().is_none() {
+            let message = is_some().message(
+                self.expression(),
+                &None::<Unknown>,
+                false,
+                self.diff_format(),
+            );
+            self.do_fail_with_message(message);
+        }
+        let expression = format!("{} unwrapped Some value", self.expression());
+        self.narrowing(Option::as_ref).named(expression)
+    }
+}
+
+impl<'a, T, R> AssertNestedOptionValue for Spec<'a, Option<Option<T>>, R>
+where
+    R: FailingStrategy,
+{
+    type Flattened = Spec<'a, T, R>;
+
+    fn flattened(mut self) -> Self::Flattened {
+        if !self.is_skipped() && !matches!(self.subject(), Some(Some(_))) {
+            self = self.expecting(is_nested_some());
+        }
+        self.narrowing(Option::flatten)
+    }
+}
+
+impl<'a, T, R> AssertNestedOptionValue for Spec<'a, &'a Option<Option<T>>, R>
+where
+    R: FailingStrategy,
+{
+    type Flattened = Spec<'a, &'a T, R>;
+
+    fn flattened(mut self) -> Self::Flattened {
+        if !self.is_skipped() && !matches!(self.subject(), Some(Some(_))) {
+            self = self.expecting(is_nested_some());
+        }
+        self.narrowing(|subject| subject.as_ref().and_then(Option::as_ref))
     }
 }
 
@@ -91,10 +136,58 @@ where
     }
 }
 
+impl<S, R> AssertOptionHasDefaultValue for Spec<'_, Option<S>, R>
+where
+    S: Default + PartialEq + Debug,
+    R: FailingStrategy,
+{
+    fn has_value_equal_to_default(self) -> Self {
+        self.expecting(has_value_equal_to_default())
+    }
+}
+
+impl<S, R> AssertOptionHasDefaultValue for Spec<'_, &Option<S>, R>
+where
+    S: Default + PartialEq + Debug,
+    R: FailingStrategy,
+{
+    fn has_value_equal_to_default(self) -> Self {
+        self.expecting(has_value_equal_to_default())
+    }
+}
+
+impl<S, R> AssertOptionIsNoneOr<S> for Spec<'_, Option<S>, R>
+where
+    S: Debug,
+    R: FailingStrategy,
+{
+    fn is_none_or<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&S) -> bool,
+    {
+        self.expecting(is_none_or(predicate))
+    }
+}
+
+impl<S, R> AssertOptionIsNoneOr<S> for Spec<'_, &Option<S>, R>
+where
+    S: Debug,
+    R: FailingStrategy,
+{
+    fn is_none_or<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&S) -> bool,
+    {
+        self.expecting(is_none_or(predicate))
+    }
+}
+
 impl<T> Expectation<Option<T>> for IsSome
 where
     T: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_some";
+
     fn test(&mut self, subject: &Option<T>) -> bool {
         subject.is_some()
     }
@@ -119,6 +212,8 @@ impl<T> Expectation<&Option<T>> for IsSome
 where
     T: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_some";
+
     fn test(&mut self, subject: &&Option<T>) -> bool {
         <Self as Expectation<Option<T>>>::test(self, subject)
     }
@@ -134,10 +229,60 @@ where
     }
 }
 
+impl<T> Expectation<Option<Option<T>>> for IsNestedSome {
+    const ASSERTION_ID: &'static str = "asserting::flattened";
+
+    fn test(&mut self, subject: &Option<Option<T>>) -> bool {
+        matches!(subject, Some(Some(_)))
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Option<Option<T>>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected = Some(Some(Unknown));
+        let (actual, wrong_layer) = match actual {
+            None => (None, ", but the outer option is None"),
+            Some(None) => (Some(None), ", but the inner option is None"),
+            Some(Some(_)) => (Some(Some(Unknown)), ""),
+        };
+        let marked_actual = mark_unexpected(&actual, format);
+        let marked_expected = mark_missing(&expected, format);
+        format!(
+            "expected {expression} to be {expected:?}{wrong_layer}\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<T> Expectation<&Option<Option<T>>> for IsNestedSome {
+    const ASSERTION_ID: &'static str = "asserting::flattened";
+
+    fn test(&mut self, subject: &&Option<Option<T>>) -> bool {
+        <Self as Expectation<Option<Option<T>>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Option<Option<T>>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Option<Option<T>>>>::message(
+            self, expression, actual, inverted, format,
+        )
+    }
+}
+
 impl<T> Expectation<Option<T>> for IsNone
 where
     T: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_none";
+
     fn test(&mut self, subject: &Option<T>) -> bool {
         subject.is_none()
     }
@@ -162,6 +307,8 @@ impl<T> Expectation<&Option<T>> for IsNone
 where
     T: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_none";
+
     fn test(&mut self, subject: &&Option<T>) -> bool {
         <Self as Expectation<Option<T>>>::test(self, subject)
     }
@@ -182,6 +329,8 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_value";
+
     fn test(&mut self, subject: &Option<T>) -> bool {
         subject
             .as_ref()
@@ -212,6 +361,112 @@ where
     T: PartialEq<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_value";
+
+    fn test(&mut self, subject: &&Option<T>) -> bool {
+        <Self as Expectation<Option<T>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Option<T>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Option<T>>>::message(self, expression, actual, inverted, format)
+    }
+}
+
+impl<T> Expectation<Option<T>> for HasValueEqualToDefault
+where
+    T: Default + PartialEq + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_value_equal_to_default";
+
+    fn test(&mut self, subject: &Option<T>) -> bool {
+        subject.as_ref().is_some_and(|value| value == &T::default())
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Option<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let default_value = T::default();
+        let expected = Some(&default_value);
+        let (reason, marked_actual, marked_expected) = match actual {
+            None => (
+                "is none",
+                mark_unexpected(actual, format),
+                mark_missing(&expected, format),
+            ),
+            Some(value) => {
+                let (marked_actual, marked_expected) = mark_diff(&Some(value), &expected, format);
+                ("holds a different value", marked_actual, marked_expected)
+            },
+        };
+        format!(
+            "expected {expression} to be some containing the default value {default_value:?}, but it {reason}\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<T> Expectation<&Option<T>> for HasValueEqualToDefault
+where
+    T: Default + PartialEq + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_value_equal_to_default";
+
+    fn test(&mut self, subject: &&Option<T>) -> bool {
+        <Self as Expectation<Option<T>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Option<T>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Option<T>>>::message(self, expression, actual, inverted, format)
+    }
+}
+
+impl<T, P> Expectation<Option<T>> for IsNoneOr<P>
+where
+    T: Debug,
+    P: Fn(&T) -> bool,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_none_or";
+
+    fn test(&mut self, subject: &Option<T>) -> bool {
+        subject.as_ref().is_none_or(&self.predicate)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Option<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let marked_actual = mark_unexpected(actual, format);
+        format!(
+            "expected {expression} to be none or some value satisfying the given predicate, but it holds a value that does not satisfy the predicate\n   but was: {marked_actual}\n  expected: None or Some(<value satisfying the predicate>)"
+        )
+    }
+}
+
+impl<T, P> Expectation<&Option<T>> for IsNoneOr<P>
+where
+    T: Debug,
+    P: Fn(&T) -> bool,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_none_or";
+
     fn test(&mut self, subject: &&Option<T>) -> bool {
         <Self as Expectation<Option<T>>>::test(self, subject)
     }
@@ -2,6 +2,7 @@ use crate::prelude::*;
 use crate::std::{
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 
 #[test]
@@ -296,15 +297,112 @@ fn map_option_with_some_value_to_its_value() {
     assert_that(subject).some().is_not_empty();
 }
 
+#[test]
+fn option_of_u64_has_value_equal_to_default() {
+    let subject: Option<u64> = Some(0);
+
+    assert_that(subject).has_value_equal_to_default();
+}
+
+#[test]
+fn borrowed_option_of_string_has_value_equal_to_default() {
+    let subject: Option<String> = Some(String::new());
+
+    assert_that(&subject).has_value_equal_to_default();
+}
+
+#[test]
+fn verify_option_of_u64_has_value_equal_to_default_fails_if_none() {
+    let subject: Option<u64> = None;
+
+    let failures = verify_that(subject)
+        .named("timeout")
+        .has_value_equal_to_default()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected timeout to be some containing the default value 0, but it is none
+   but was: None
+  expected: Some(0)
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_option_of_u64_has_value_equal_to_default_fails_if_some_other_value() {
+    let subject: Option<u64> = Some(30);
+
+    let failures = verify_that(subject)
+        .named("timeout")
+        .has_value_equal_to_default()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected timeout to be some containing the default value 0, but it holds a different value
+   but was: Some(30)
+  expected: Some(0)
+"
+        ]
+    );
+}
+
+#[test]
+fn option_of_u16_is_none_or_satisfies_predicate_if_none() {
+    let subject: Option<u16> = None;
+
+    assert_that(subject).is_none_or(|port| *port >= 1024);
+}
+
+#[test]
+fn borrowed_option_of_u16_is_none_or_satisfies_predicate_if_some() {
+    let subject: Option<u16> = Some(8080);
+
+    assert_that(&subject).is_none_or(|port| *port >= 1024);
+}
+
+#[test]
+fn verify_option_of_u16_is_none_or_satisfies_predicate_fails() {
+    let subject: Option<u16> = Some(80);
+
+    let failures = verify_that(subject)
+        .named("port")
+        .is_none_or(|port| *port >= 1024)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected port to be none or some value satisfying the given predicate, but it holds a value that does not satisfy the predicate
+   but was: Some(80)
+  expected: None or Some(<value satisfying the predicate>)
+"
+        ]
+    );
+}
+
 #[cfg(feature = "panic")]
 #[test]
 fn map_option_with_none_to_its_value() {
     let subject: Option<Vec<usize>> = None;
 
     assert_that_code(|| {
-        assert_that(subject).some().is_empty();
+        assert_that(subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .some()
+            .is_empty();
     })
-    .panics_with_message("expected the subject to be `Some(_)`, but was `None`");
+    .panics_with_message(
+        r"expected my_thing to be Some(_)
+   but was: None
+  expected: Some(_)
+",
+    );
 }
 
 #[test]
@@ -320,9 +418,167 @@ fn map_borrowed_option_with_none_to_its_value() {
     let subject: Option<Vec<usize>> = None;
 
     assert_that_code(|| {
-        assert_that(&subject).some().is_empty();
+        assert_that(&subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .some()
+            .is_empty();
+    })
+    .panics_with_message(
+        r"expected my_thing to be Some(_)
+   but was: None
+  expected: Some(_)
+",
+    );
+}
+
+#[test]
+fn verify_assertion_on_the_value_of_option_refers_to_the_unwrapped_value() {
+    let subject = Some(vec![1, 2, 3]);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .some()
+        .is_empty()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing unwrapped Some value to be empty
+   but was: [1, 2, 3]
+  expected: <empty>
+"]
+    );
+}
+
+#[test]
+fn verify_map_option_with_none_to_its_value_skips_following_assertions() {
+    let subject: Option<Vec<usize>> = None;
+
+    let spec = verify_that(subject)
+        .named("my_thing")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .is_some()
+        .some();
+
+    assert!(spec.is_skipped());
+
+    let failures = spec.is_empty().has_length(2).display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be Some(_)
+   but was: None
+  expected: Some(_)
+",
+            r"expected my_thing to be Some(_)
+   but was: None
+  expected: Some(_)
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_map_borrowed_option_with_none_in_derived_spec_skips_following_assertions() {
+    let subject: (&str, Option<Vec<usize>>) = ("foo", None);
+
+    let failures = verify_that(&subject)
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .extracting_ref("values", |(_, values)| values)
+        .some()
+        .is_empty()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected subject.values to be Some(_)
+   but was: None
+  expected: Some(_)
+"]
+    );
+}
+
+#[test]
+fn map_nested_option_with_some_value_to_its_value() {
+    let subject = Some(Some(vec![1, 2, 3]));
+
+    assert_that(subject).flattened().contains_exactly([1, 2, 3]);
+}
+
+#[test]
+fn map_borrowed_nested_option_with_some_value_to_its_value() {
+    let subject = Some(Some("tempor"));
+
+    assert_that(&subject).flattened().is_equal_to(&"tempor");
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn map_nested_option_with_outer_none_to_its_value() {
+    let subject: Option<Option<Vec<usize>>> = None;
+
+    assert_that_code(|| {
+        assert_that(subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .flattened()
+            .is_empty();
+    })
+    .panics_with_message(
+        r"expected my_thing to be Some(Some(_)), but the outer option is None
+   but was: None
+  expected: Some(Some(_))
+",
+    );
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn map_borrowed_nested_option_with_inner_none_to_its_value() {
+    let subject: Option<Option<Vec<usize>>> = Some(None);
+
+    assert_that_code(|| {
+        assert_that(&subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .flattened()
+            .is_empty();
     })
-    .panics_with_message("expected the subject to be `Some(_)`, but was `None`");
+    .panics_with_message(
+        r"expected my_thing to be Some(Some(_)), but the inner option is None
+   but was: Some(None)
+  expected: Some(Some(_))
+",
+    );
+}
+
+#[test]
+fn verify_map_nested_option_with_inner_none_skips_following_assertions() {
+    let subject: Option<Option<usize>> = Some(None);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .is_none()
+        .flattened()
+        .is_zero()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be None
+   but was: Some(None)
+  expected: None
+",
+            r"expected my_thing to be Some(Some(_)), but the inner option is None
+   but was: Some(None)
+  expected: Some(Some(_))
+"
+        ]
+    );
 }
 
 #[cfg(feature = "colored")]
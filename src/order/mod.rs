@@ -52,6 +52,8 @@ where
     S: PartialOrd<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_less_than";
+
     fn test(&mut self, subject: &S) -> bool {
         subject < &self.expected
     }
@@ -80,6 +82,8 @@ where
     S: PartialOrd<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_at_most";
+
     fn test(&mut self, subject: &S) -> bool {
         subject <= &self.expected
     }
@@ -108,6 +112,8 @@ where
     S: PartialOrd<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_greater_than";
+
     fn test(&mut self, subject: &S) -> bool {
         subject > &self.expected
     }
@@ -136,6 +142,8 @@ where
     S: PartialOrd<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_at_least";
+
     fn test(&mut self, subject: &S) -> bool {
         subject >= &self.expected
     }
@@ -164,6 +172,8 @@ where
     S: PartialOrd<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_before";
+
     fn test(&mut self, subject: &S) -> bool {
         subject < &self.expected
     }
@@ -192,6 +202,8 @@ where
     S: PartialOrd<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_after";
+
     fn test(&mut self, subject: &S) -> bool {
         subject > &self.expected
     }
@@ -220,6 +232,8 @@ where
     S: PartialOrd<E> + Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_between";
+
     fn test(&mut self, subject: &S) -> bool {
         subject >= &self.min && subject <= &self.max
     }
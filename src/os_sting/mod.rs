@@ -3,8 +3,13 @@
 //! `OsString` and `OsStr` are only available in std environments. Thus,
 //! assertions for those types are only available with crate feature `std`
 //! enabled.
+//!
+//! The length of an `OsStr` is the number of bytes in its platform-specific
+//! encoding. The character count is determined on the lossy UTF-8 conversion
+//! of the string, where each invalid sequence counts as one replacement
+//! character.
 
-use crate::properties::{IsEmptyProperty, LengthProperty};
+use crate::properties::{CharCountProperty, IsEmptyProperty, LengthProperty};
 use crate::std::ffi::{OsStr, OsString};
 
 impl IsEmptyProperty for OsString {
@@ -31,5 +36,17 @@ impl LengthProperty for &OsStr {
     }
 }
 
+impl CharCountProperty for OsString {
+    fn char_count_property(&self) -> usize {
+        self.to_string_lossy().chars().count()
+    }
+}
+
+impl CharCountProperty for &OsStr {
+    fn char_count_property(&self) -> usize {
+        self.to_string_lossy().chars().count()
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -42,3 +42,17 @@ fn os_string_has_length() {
 
     assert_that(subject).has_length(3);
 }
+
+#[test]
+fn os_str_has_char_count() {
+    let subject: &OsStr = OsStr::new("Grüße");
+
+    assert_that(subject).has_length(7).has_char_count(5);
+}
+
+#[test]
+fn os_string_has_char_count() {
+    let subject: OsString = OsString::from("Grüße");
+
+    assert_that(subject).has_char_count_less_than(6);
+}
@@ -1,18 +1,35 @@
 //! Implementation of assertions for code that should or should not panic.
 
-use crate::assertions::AssertCodePanics;
+#[cfg(feature = "alloc-track")]
+use crate::alloc_track::allocation_count;
+#[cfg(feature = "alloc-track")]
+use crate::assertions::AssertCodeAllocations;
+use crate::assertions::{AssertCodeCompletesWithin, AssertCodeOverflows, AssertCodePanics};
 use crate::colored::{mark_missing_string, mark_unexpected_string};
-use crate::expectations::{DoesNotPanic, DoesPanic, does_not_panic, does_panic};
+#[cfg(feature = "alloc-track")]
+use crate::expectations::{AllocatesAtMost, allocates_at_most};
+use crate::expectations::{
+    CodeDoesNotOverflow, CodeOverflows, CompletesWithin, DoesNotPanic, DoesPanic,
+    DoesPanicSatisfying, DoesPanicWithPayloadOfType, code_does_not_overflow, code_overflows,
+    completes_within, does_not_panic, does_panic, does_panic_satisfying,
+    does_panic_with_payload_of_type,
+};
+use crate::properties::CheckedArithmeticProperty;
 use crate::spec::{Code, DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Spec};
-use crate::std::any::Any;
+use crate::std::any::{Any, type_name};
+use crate::std::borrow::Cow;
+use crate::std::fmt::Debug;
+#[cfg(panic = "unwind")]
 use crate::std::panic;
+use crate::std::time::{Duration, Instant};
 
 const ONLY_ONE_EXPECTATION: &str = "only one expectation allowed when asserting closures!";
 const UNKNOWN_PANIC_MESSAGE: &str = "<unknown panic message>";
+const UNWINDING_NOT_SUPPORTED: &str = "asserting whether code panics is not supported on targets that abort on panic, like `wasm32-unknown-unknown`!";
 
-impl<'a, S, R> AssertCodePanics for Spec<'a, Code<S>, R>
+impl<'a, S, T, R> AssertCodePanics for Spec<'a, Code<S>, R>
 where
-    S: FnOnce(),
+    S: FnOnce() -> T,
     R: FailingStrategy,
 {
     type Mapped = Spec<'a, (), R>;
@@ -29,15 +46,62 @@ where
         self.expecting(does_panic().with_message(message))
             .mapping(|_| ())
     }
+
+    fn panics_with_payload_of_type<P>(self) -> Self::Mapped
+    where
+        P: Any,
+    {
+        self.expecting(does_panic_with_payload_of_type::<P>())
+            .mapping(|_| ())
+    }
+
+    fn panics_satisfying<F>(self, predicate: F) -> Self::Mapped
+    where
+        F: Fn(&dyn Any) -> bool,
+    {
+        self.expecting(does_panic_satisfying(predicate))
+            .mapping(|_| ())
+    }
+}
+
+impl<'a, S, T, R> AssertCodeCompletesWithin for Spec<'a, Code<S>, R>
+where
+    S: FnOnce() -> T,
+    R: FailingStrategy,
+{
+    type Mapped = Spec<'a, (), R>;
+
+    fn completes_within(self, budget: Duration) -> Self::Mapped {
+        self.expecting(completes_within(budget)).mapping(|_| ())
+    }
+}
+
+#[cfg(feature = "alloc-track")]
+impl<'a, S, T, R> AssertCodeAllocations for Spec<'a, Code<S>, R>
+where
+    S: FnOnce() -> T,
+    R: FailingStrategy,
+{
+    type Mapped = Spec<'a, (), R>;
+
+    fn allocates_at_most(self, max: usize) -> Self::Mapped {
+        self.expecting(allocates_at_most(max)).mapping(|_| ())
+    }
+
+    fn does_not_allocate(self) -> Self::Mapped {
+        self.expecting(allocates_at_most(0)).mapping(|_| ())
+    }
 }
 
-impl<S> Expectation<Code<S>> for DoesNotPanic
+impl<S, T> Expectation<Code<S>> for DoesNotPanic
 where
-    S: FnOnce(),
+    S: FnOnce() -> T,
 {
+    const ASSERTION_ID: &'static str = "asserting::does_not_panic";
+
     fn test(&mut self, subject: &Code<S>) -> bool {
         if let Some(function) = subject.take() {
-            let result = panic::catch_unwind(panic::AssertUnwindSafe(function));
+            let result = catch_unwind(function);
             match result {
                 Ok(()) => true,
                 Err(panic_message) => {
@@ -61,8 +125,8 @@ where
         let panic_message = read_panic_message(self.actual_message.as_ref())
             .unwrap_or_else(|| UNKNOWN_PANIC_MESSAGE.to_string());
 
-        if panic_message == ONLY_ONE_EXPECTATION {
-            format!("error in test assertion: {ONLY_ONE_EXPECTATION}")
+        if panic_message == ONLY_ONE_EXPECTATION || panic_message == UNWINDING_NOT_SUPPORTED {
+            format!("error in test assertion: {panic_message}")
         } else {
             let marked_did_panic = mark_unexpected_string("did panic", format);
             let marked_panic_message = mark_unexpected_string(&panic_message, format);
@@ -73,19 +137,31 @@ where
     }
 }
 
-impl<S> Expectation<Code<S>> for DoesPanic
+impl<S, T> Expectation<Code<S>> for DoesPanic
 where
-    S: FnOnce(),
+    S: FnOnce() -> T,
 {
+    const ASSERTION_ID: &'static str = "asserting::panics";
+
+    fn assertion_id(&self) -> Cow<'static, str> {
+        if self.expected_message.is_some() {
+            Cow::Borrowed("asserting::panics_with_message")
+        } else {
+            Cow::Borrowed(<Self as Expectation<Code<S>>>::ASSERTION_ID)
+        }
+    }
+
     fn test(&mut self, subject: &Code<S>) -> bool {
         if let Some(function) = subject.take() {
-            let result = panic::catch_unwind(panic::AssertUnwindSafe(function));
+            let result = catch_unwind(function);
             match result {
                 Ok(()) => false,
                 Err(panic_message) => {
                     let panic_message = read_panic_message(Some(panic_message).as_ref())
                         .unwrap_or_else(|| UNKNOWN_PANIC_MESSAGE.to_string());
-                    let test_result = if let Some(expected_message) = &self.expected_message {
+                    let test_result = if panic_message == UNWINDING_NOT_SUPPORTED {
+                        false
+                    } else if let Some(expected_message) = &self.expected_message {
                         &panic_message == expected_message
                     } else {
                         // did panic - panic message should not be asserted
@@ -109,8 +185,8 @@ where
         format: &DiffFormat,
     ) -> String {
         if let Some(actual_message) = self.actual_message.as_ref() {
-            if actual_message == ONLY_ONE_EXPECTATION {
-                format!("error in test assertion: {ONLY_ONE_EXPECTATION}")
+            if actual_message == ONLY_ONE_EXPECTATION || actual_message == UNWINDING_NOT_SUPPORTED {
+                format!("error in test assertion: {actual_message}")
             } else if let Some(expected_message) = &self.expected_message {
                 let marked_expected_message = mark_missing_string(expected_message, format);
                 let marked_actual_message = mark_unexpected_string(actual_message, format);
@@ -133,6 +209,323 @@ where
     }
 }
 
+impl<S, T, P> Expectation<Code<S>> for DoesPanicWithPayloadOfType<P>
+where
+    S: FnOnce() -> T,
+    P: Any,
+{
+    const ASSERTION_ID: &'static str = "asserting::panics_with_payload_of_type";
+
+    fn test(&mut self, subject: &Code<S>) -> bool {
+        let Some(function) = subject.take() else {
+            self.actual_payload = Some(Box::new(ONLY_ONE_EXPECTATION));
+            return false;
+        };
+        let Err(payload) = catch_unwind(function) else {
+            return false;
+        };
+        let test_result = payload.is::<P>() && !is_assertion_error(&payload);
+        self.actual_payload = Some(payload);
+        test_result
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        _actual: &Code<S>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected_type = type_name::<P>();
+        let Some(payload) = self.actual_payload.as_ref() else {
+            let marked_did_not_panic = mark_unexpected_string("did not panic", format);
+            return format!(
+                "expected {expression} to panic with a payload of type {expected_type},\n  but {marked_did_not_panic}"
+            );
+        };
+        if let Some(error) = read_assertion_error(payload) {
+            return format!("error in test assertion: {error}");
+        }
+        let marked_actual = mark_unexpected_string(&describe_payload(payload), format);
+        let marked_expected = mark_missing_string(expected_type, format);
+        format!(
+            "expected {expression} to panic with a payload of type {expected_type}\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<S, T, F> Expectation<Code<S>> for DoesPanicSatisfying<F>
+where
+    S: FnOnce() -> T,
+    F: Fn(&dyn Any) -> bool,
+{
+    const ASSERTION_ID: &'static str = "asserting::panics_satisfying";
+
+    fn test(&mut self, subject: &Code<S>) -> bool {
+        let Some(function) = subject.take() else {
+            self.actual_payload = Some(Box::new(ONLY_ONE_EXPECTATION));
+            return false;
+        };
+        let Err(payload) = catch_unwind(function) else {
+            return false;
+        };
+        let test_result = !is_assertion_error(&payload) && (self.predicate)(&*payload);
+        self.actual_payload = Some(payload);
+        test_result
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        _actual: &Code<S>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let Some(payload) = self.actual_payload.as_ref() else {
+            let marked_did_not_panic = mark_unexpected_string("did not panic", format);
+            return format!(
+                "expected {expression} to panic with a payload that satisfies the given predicate,\n  but {marked_did_not_panic}"
+            );
+        };
+        if let Some(error) = read_assertion_error(payload) {
+            return format!("error in test assertion: {error}");
+        }
+        let marked_actual = mark_unexpected_string(&describe_payload(payload), format);
+        format!(
+            "expected {expression} to panic with a payload that satisfies the given predicate\n   but was: {marked_actual}"
+        )
+    }
+}
+
+impl<S, T> Expectation<Code<S>> for CompletesWithin
+where
+    S: FnOnce() -> T,
+{
+    const ASSERTION_ID: &'static str = "asserting::completes_within";
+
+    fn test(&mut self, subject: &Code<S>) -> bool {
+        let Some(function) = subject.take() else {
+            self.actual_panic = Some(Box::new(ONLY_ONE_EXPECTATION));
+            return false;
+        };
+        let start = Instant::now();
+        let result = catch_unwind(function);
+        let elapsed = start.elapsed();
+        self.elapsed = Some(elapsed);
+        match result {
+            Ok(()) => elapsed <= self.budget,
+            Err(payload) => {
+                self.actual_panic = Some(payload);
+                false
+            },
+        }
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        _actual: &Code<S>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let budget = self.budget;
+        if let Some(payload) = self.actual_panic.as_ref() {
+            if let Some(error) = read_assertion_error(payload) {
+                return format!("error in test assertion: {error}");
+            }
+            let panic_message = read_panic_message(Some(payload))
+                .unwrap_or_else(|| UNKNOWN_PANIC_MESSAGE.to_string());
+            let marked_did_panic = mark_unexpected_string("did panic", format);
+            let marked_panic_message = mark_unexpected_string(&panic_message, format);
+            return format!(
+                "expected {expression} to complete within {budget:?}, but {marked_did_panic}\n  with message: \"{marked_panic_message}\""
+            );
+        }
+        let elapsed = self.elapsed.unwrap_or_default();
+        let marked_actual = mark_unexpected_string(&format!("{elapsed:?}"), format);
+        let marked_expected = mark_missing_string(&format!("{budget:?}"), format);
+        format!(
+            "expected {expression} to complete within {budget:?}\n   but was: {marked_actual}\n  expected: <= {marked_expected}"
+        )
+    }
+}
+
+#[cfg(feature = "alloc-track")]
+impl<S, T> Expectation<Code<S>> for AllocatesAtMost
+where
+    S: FnOnce() -> T,
+{
+    const ASSERTION_ID: &'static str = "asserting::allocates_at_most";
+
+    fn assertion_id(&self) -> Cow<'static, str> {
+        if self.max == 0 {
+            Cow::Borrowed("asserting::does_not_allocate")
+        } else {
+            Cow::Borrowed(<Self as Expectation<Code<S>>>::ASSERTION_ID)
+        }
+    }
+
+    fn test(&mut self, subject: &Code<S>) -> bool {
+        let Some(function) = subject.take() else {
+            self.actual_panic = Some(Box::new(ONLY_ONE_EXPECTATION));
+            return false;
+        };
+        let Some(before) = allocation_count() else {
+            return false;
+        };
+        let result = catch_unwind(function);
+        let after = allocation_count().unwrap_or(before);
+        let allocations = after.wrapping_sub(before);
+        self.allocations = Some(allocations);
+        match result {
+            Ok(()) => allocations <= self.max,
+            Err(payload) => {
+                self.actual_panic = Some(payload);
+                false
+            },
+        }
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        _actual: &Code<S>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let max = self.max;
+        let expectation = if max == 0 {
+            "not allocate".to_string()
+        } else {
+            format!("allocate at most {max} times")
+        };
+        if let Some(payload) = self.actual_panic.as_ref() {
+            if let Some(error) = read_assertion_error(payload) {
+                return format!("error in test assertion: {error}");
+            }
+            let panic_message = read_panic_message(Some(payload))
+                .unwrap_or_else(|| UNKNOWN_PANIC_MESSAGE.to_string());
+            let marked_did_panic = mark_unexpected_string("did panic", format);
+            let marked_panic_message = mark_unexpected_string(&panic_message, format);
+            return format!(
+                "expected {expression} to {expectation}, but {marked_did_panic}\n  with message: \"{marked_panic_message}\""
+            );
+        }
+        let Some(allocations) = self.allocations else {
+            return format!(
+                "expected {expression} to {expectation}, but allocations can not be counted\n  hint: install the `TrackingAllocator` as global allocator"
+            );
+        };
+        let marked_actual = mark_unexpected_string(&allocations.to_string(), format);
+        let marked_expected = mark_missing_string(&max.to_string(), format);
+        format!(
+            "expected {expression} to {expectation}\n   but was: {marked_actual} allocations\n  expected: <= {marked_expected} allocations"
+        )
+    }
+}
+
+impl<'a, S, O, R> AssertCodeOverflows for Spec<'a, Code<S>, R>
+where
+    S: FnOnce() -> O,
+    O: CheckedArithmeticProperty + Debug,
+    R: FailingStrategy,
+{
+    type Mapped = Spec<'a, (), R>;
+
+    fn overflows(self) -> Self::Mapped {
+        self.expecting(code_overflows()).mapping(|_| ())
+    }
+
+    fn does_not_overflow(self) -> Self::Mapped {
+        self.expecting(code_does_not_overflow()).mapping(|_| ())
+    }
+}
+
+impl<S, O> Expectation<Code<S>> for CodeOverflows
+where
+    S: FnOnce() -> O,
+    O: CheckedArithmeticProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::overflows";
+
+    fn test(&mut self, subject: &Code<S>) -> bool {
+        let Some(function) = subject.take() else {
+            return false;
+        };
+        let result = function();
+        self.result = Some(format!("{result:?}"));
+        result.is_overflow_property()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        _actual: &Code<S>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let Some(result) = &self.result else {
+            return format!("error in test assertion: {ONLY_ONE_EXPECTATION}");
+        };
+        let marked_actual = mark_unexpected_string(result, format);
+        let marked_expected = mark_missing_string(O::overflow_pattern_property(), format);
+        format!(
+            "expected {expression} to overflow\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<S, O> Expectation<Code<S>> for CodeDoesNotOverflow
+where
+    S: FnOnce() -> O,
+    O: CheckedArithmeticProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::does_not_overflow";
+
+    fn test(&mut self, subject: &Code<S>) -> bool {
+        let Some(function) = subject.take() else {
+            return false;
+        };
+        let result = function();
+        self.result = Some(format!("{result:?}"));
+        !result.is_overflow_property()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        _actual: &Code<S>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let Some(result) = &self.result else {
+            return format!("error in test assertion: {ONLY_ONE_EXPECTATION}");
+        };
+        let marked_actual = mark_unexpected_string(result, format);
+        let marked_expected = mark_missing_string(O::no_overflow_pattern_property(), format);
+        format!(
+            "expected {expression} to not overflow\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+/// Calls the given function and catches a panic if the function panics.
+#[cfg(panic = "unwind")]
+fn catch_unwind<T>(function: impl FnOnce() -> T) -> Result<(), Box<dyn Any + Send>> {
+    panic::catch_unwind(panic::AssertUnwindSafe(function)).map(|_| ())
+}
+
+/// Stub for targets that abort on panic, like `wasm32-unknown-unknown`.
+///
+/// A panic can not be caught on these targets. Calling the function would
+/// abort the whole test run, so it is not called at all. Instead, the
+/// assertion fails with a message explaining that asserting panics is not
+/// supported.
+#[cfg(not(panic = "unwind"))]
+fn catch_unwind<T>(_function: impl FnOnce() -> T) -> Result<(), Box<dyn Any + Send>> {
+    Err(Box::new(UNWINDING_NOT_SUPPORTED))
+}
+
 fn read_panic_message(error: Option<&Box<dyn Any + Send>>) -> Option<String> {
     error.and_then(|message| {
         let message = &**message;
@@ -143,5 +536,29 @@ fn read_panic_message(error: Option<&Box<dyn Any + Send>>) -> Option<String> {
     })
 }
 
+/// Returns the error message if the payload signals an error in the test
+/// assertion itself, rather than a panic of the code under test.
+fn read_assertion_error(payload: &Box<dyn Any + Send>) -> Option<&'static str> {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .filter(|message| *message == ONLY_ONE_EXPECTATION || *message == UNWINDING_NOT_SUPPORTED)
+}
+
+fn is_assertion_error(payload: &Box<dyn Any + Send>) -> bool {
+    read_assertion_error(payload).is_some()
+}
+
+/// Describes a panic payload for failure messages.
+///
+/// The type of payload can not be determined at runtime. Only string messages
+/// are shown, all other payloads are described as of unknown type.
+fn describe_payload(payload: &Box<dyn Any + Send>) -> String {
+    read_panic_message(Some(payload)).map_or_else(
+        || "<payload of unknown type>".to_string(),
+        |message| format!("{message:?}"),
+    )
+}
+
 #[cfg(test)]
 mod tests;
@@ -12,6 +12,16 @@ fn code_does_not_panic() {
     .does_not_panic();
 }
 
+#[test]
+fn code_returning_a_value_panics() {
+    fn divide(a: i32, b: i32) -> i32 {
+        a / b
+    }
+
+    assert_that_code!(|| divide(7, 0)).panics_with_message("attempt to divide by zero");
+    assert_that_code!(|| divide(7, 3)).does_not_panic();
+}
+
 #[test]
 fn verify_code_does_not_panic_fails() {
     let failures = verify_that_code(|| panic!("excepteur stet sadipscing eu"))
@@ -118,6 +128,120 @@ fn verify_code_does_panic_with_message_fails_because_unexpected_panic_message()
     );
 }
 
+#[derive(Debug)]
+struct ErrorCode(u16);
+
+#[test]
+fn code_does_panic_with_payload_of_type() {
+    assert_that_code(|| std::panic::panic_any(ErrorCode(503)))
+        .panics_with_payload_of_type::<ErrorCode>();
+}
+
+#[test]
+fn code_does_panic_with_message_as_payload_of_type_str() {
+    assert_that_code(|| panic!("tempor consequat")).panics_with_payload_of_type::<&str>();
+}
+
+#[test]
+fn verify_code_does_panic_with_payload_of_type_fails_because_code_does_not_panic() {
+    let failures = verify_that_code(|| {})
+        .named("my_closure")
+        .panics_with_payload_of_type::<ErrorCode>()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_closure to panic with a payload of type asserting::panic::tests::ErrorCode,
+  but did not panic
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_code_does_panic_with_payload_of_type_fails_because_of_other_payload_type() {
+    let failures = verify_that_code(|| panic!("nonummy {} elit", 42))
+        .named("my_closure")
+        .panics_with_payload_of_type::<ErrorCode>()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_closure to panic with a payload of type asserting::panic::tests::ErrorCode
+   but was: "nonummy 42 elit"
+  expected: asserting::panic::tests::ErrorCode
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_code_does_panic_with_payload_of_type_fails_because_of_unknown_payload_type() {
+    let failures = verify_that_code(|| std::panic::panic_any(7_u8))
+        .named("my_closure")
+        .panics_with_payload_of_type::<ErrorCode>()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_closure to panic with a payload of type asserting::panic::tests::ErrorCode
+   but was: <payload of unknown type>
+  expected: asserting::panic::tests::ErrorCode
+"
+        ]
+    );
+}
+
+#[test]
+fn code_does_panic_satisfying() {
+    assert_that_code(|| std::panic::panic_any(ErrorCode(404))).panics_satisfying(|payload| {
+        payload
+            .downcast_ref::<ErrorCode>()
+            .is_some_and(|code| code.0 == 404)
+    });
+}
+
+#[test]
+fn verify_code_does_panic_satisfying_fails_because_code_does_not_panic() {
+    let failures = verify_that_code(|| {})
+        .named("my_closure")
+        .panics_satisfying(|_| true)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_closure to panic with a payload that satisfies the given predicate,
+  but did not panic
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_code_does_panic_satisfying_fails_because_predicate_returns_false() {
+    let failures = verify_that_code(|| std::panic::panic_any(ErrorCode(500)))
+        .named("my_closure")
+        .panics_satisfying(|payload| {
+            payload
+                .downcast_ref::<ErrorCode>()
+                .is_some_and(|code| code.0 < 500)
+        })
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_closure to panic with a payload that satisfies the given predicate
+   but was: <payload of unknown type>
+"
+        ]
+    );
+}
+
 #[cfg(feature = "colored")]
 mod colored {
     use crate::prelude::*;
@@ -177,6 +301,23 @@ mod colored {
         );
     }
 
+    #[test]
+    fn highlight_diffs_code_does_panic_with_payload_of_type() {
+        let failures = verify_that_code(|| foo(Some("eirmod at labore")))
+            .named("foo")
+            .with_diff_format(DIFF_FORMAT_RED_GREEN)
+            .panics_with_payload_of_type::<u32>()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected foo to panic with a payload of type u32\n   \
+                  but was: \u{1b}[31m\"eirmod at labore\"\u{1b}[0m\n  \
+                 expected: \u{1b}[32mu32\u{1b}[0m\n\
+                "]
+        );
+    }
+
     #[test]
     fn highlight_diffs_code_does_panic_with_message() {
         let failures = verify_that_code(|| foo(Some("foo does not work with message")))
@@ -196,3 +337,139 @@ mod colored {
         );
     }
 }
+
+mod completes_within {
+    use crate::prelude::*;
+    use crate::std::thread;
+    use crate::std::time::Duration;
+
+    #[test]
+    fn code_completes_within_budget() {
+        assert_that_code(|| {
+            let _sum: u64 = (1..=1_000_u64).sum();
+        })
+        .completes_within(Duration::from_secs(10));
+    }
+
+    #[test]
+    fn verify_code_completes_within_fails_if_budget_is_exceeded() {
+        let failures = verify_that_code(|| thread::sleep(Duration::from_millis(20)))
+            .named("my_closure")
+            .completes_within(Duration::from_millis(1))
+            .display_failures();
+
+        assert_eq!(failures.len(), 1);
+        let failure = &failures[0];
+        assert_that(failure)
+            .starts_with("expected my_closure to complete within 1ms\n   but was: ")
+            .ends_with("ms\n  expected: <= 1ms\n");
+    }
+
+    #[test]
+    fn verify_code_completes_within_fails_if_code_panics() {
+        let failures = verify_that_code(|| panic!("dolor takimata elit"))
+            .named("my_closure")
+            .completes_within(Duration::from_secs(10))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected my_closure to complete within 10s, but did panic
+  with message: "dolor takimata elit"
+"#]
+        );
+    }
+
+    #[test]
+    #[should_panic = "expected my_closure to complete within 1ms\n   but was: "]
+    fn code_completes_within_panics_if_budget_is_exceeded() {
+        assert_that_code(|| thread::sleep(Duration::from_millis(20)))
+            .named("my_closure")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .completes_within(Duration::from_millis(1));
+    }
+}
+mod overflows {
+    use crate::prelude::*;
+
+    #[test]
+    fn checked_addition_overflows() {
+        let a: u8 = 200;
+        let b: u8 = 100;
+
+        assert_that_code!(|| a.checked_add(b)).overflows();
+    }
+
+    #[test]
+    fn checked_subtraction_does_not_overflow() {
+        let a: u8 = 200;
+        let b: u8 = 100;
+
+        assert_that_code!(|| a.checked_sub(b)).does_not_overflow();
+    }
+
+    #[test]
+    fn checked_operation_returning_result_overflows() {
+        let a = i64::MIN;
+
+        assert_that_code!(|| a.checked_neg().ok_or("overflow")).overflows();
+        assert_that_code!(|| a.checked_add(1).ok_or("overflow")).does_not_overflow();
+    }
+
+    #[test]
+    fn verify_code_overflows_fails() {
+        let a: u8 = 200;
+        let b: u8 = 50;
+
+        let failures = verify_that_code!(|| a.checked_add(b))
+            .overflows()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected || a.checked_add(b) to overflow
+   but was: Some(250)
+  expected: None
+"]
+        );
+    }
+
+    #[test]
+    fn verify_code_does_not_overflow_fails() {
+        let a: u8 = 200;
+        let b: u8 = 100;
+
+        let failures = verify_that_code!(|| a.checked_mul(b).ok_or("overflow"))
+            .does_not_overflow()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r#"expected || a.checked_mul(b).ok_or("overflow") to not overflow
+   but was: Err("overflow")
+  expected: Ok(_)
+"#
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic = "expected the closure to not overflow\n   but was: None\n  expected: Some(_)"]
+    fn code_does_not_overflow_panics_if_operation_overflows() {
+        let a = i32::MAX;
+
+        assert_that_code(|| a.checked_add(1))
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .does_not_overflow();
+    }
+
+    #[test]
+    fn operand_form_and_code_form_can_be_used_side_by_side() {
+        let a: u8 = 200;
+        let b: u8 = 100;
+
+        assert_that!((a, b)).overflows(|(a, b)| a.checked_add(*b));
+        assert_that_code!(|| a.checked_add(b)).overflows();
+    }
+}
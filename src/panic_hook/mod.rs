@@ -0,0 +1,218 @@
+//! Panic hook that reports failed assertions with a shortened backtrace.
+//!
+//! The hook is installed with [`install_panic_hook`]. It only handles panics
+//! raised by failed assertions. All other panics are passed on to the panic
+//! hook that was installed before.
+
+use crate::spec::OwnedLocation;
+use crate::std::backtrace::{Backtrace, BacktraceStatus};
+use crate::std::cell::Cell;
+use crate::std::fmt::Write as _;
+use crate::std::format;
+use crate::std::io::{self, Write};
+use crate::std::panic::{self, PanicHookInfo};
+use crate::std::string::String;
+use crate::std::sync::Once;
+use crate::std::sync::atomic::{AtomicBool, Ordering};
+use crate::std::thread;
+use crate::std::vec::Vec;
+
+/// Whether the panic hook of this crate has been installed.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// The location of the failed assertion the current thread is about to
+    /// panic for, if any.
+    static ASSERTION_PANIC: Cell<Option<AssertionPanic>> = const { Cell::new(None) };
+}
+
+/// A panic caused by a failed assertion.
+struct AssertionPanic {
+    location: Option<OwnedLocation>,
+}
+
+/// Symbol prefixes of the frames of this crate, the panic machinery and the
+/// panic hook itself, which precede the frame of the failed assertion.
+const INTERNAL_FRAME_PREFIXES: [&str; 10] = [
+    "asserting::",
+    "<asserting::",
+    "std::panicking::",
+    "std::panic::",
+    "std::backtrace::",
+    "std::sys::backtrace::__rust_end_short_backtrace",
+    "core::panicking::",
+    "core::panic::",
+    "__rustc::",
+    "<alloc::boxed::Box<dyn for<'a, 'b> core::ops::function::Fn<(&'a std::panic::PanicHookInfo<'b>,)",
+];
+
+/// Installs a panic hook that reports failed assertions with the location of
+/// the assertion first, followed by the failure message.
+///
+/// If backtraces are enabled, e.g. via the environment variable
+/// `RUST_BACKTRACE`, the leading frames of this crate and of the panic
+/// machinery are removed from the printed backtrace, so that it starts at the
+/// code that executed the assertion.
+///
+/// Panics that are not caused by a failed assertion are passed on to the
+/// panic hook that was installed before. Calling this function more than
+/// once has no further effect.
+///
+/// # Examples
+///
+/// ```should_panic
+/// use asserting::prelude::*;
+///
+/// asserting::install_panic_hook();
+///
+/// assert_that!(6 * 7).is_equal_to(41);
+/// ```
+///
+/// prints:
+///
+/// ```console
+/// assertion failed at src/main.rs:5:1 in thread 'main':
+/// expected 6 * 7 to be equal to 41
+///    but was: 42
+///   expected: 41
+/// ```
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if let Some(assertion_panic) = ASSERTION_PANIC.with(Cell::take) {
+                report_assertion_panic(info, assertion_panic);
+            } else {
+                previous_hook(info);
+            }
+        }));
+        INSTALLED.store(true, Ordering::Release);
+    });
+}
+
+/// Marks the following panic on the current thread as caused by the failed
+/// assertion at the given location.
+pub fn mark_assertion_panic(location: Option<&OwnedLocation>) {
+    if INSTALLED.load(Ordering::Acquire) {
+        let location = location.cloned();
+        ASSERTION_PANIC
+            .with(|assertion_panic| assertion_panic.set(Some(AssertionPanic { location })));
+    }
+}
+
+/// Prints the failure message of an assertion panic to stderr.
+fn report_assertion_panic(info: &PanicHookInfo<'_>, assertion_panic: AssertionPanic) {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or_default();
+    let thread = thread::current();
+    let thread_name = thread.name().unwrap_or("<unnamed>");
+    let backtrace = Backtrace::capture();
+    let backtrace = if backtrace.status() == BacktraceStatus::Captured {
+        Some(backtrace.to_string())
+    } else {
+        None
+    };
+    // The location of the assertion is preferred as the panic location may
+    // point to the internals of this crate.
+    let location = assertion_panic.location.map_or_else(
+        || info.location().map(ToString::to_string),
+        |location| Some(location.to_string()),
+    );
+    let report = format_report(
+        location.as_deref(),
+        thread_name,
+        message,
+        backtrace.as_deref(),
+    );
+    // Errors writing to stderr cannot be reported anywhere else.
+    _ = io::stderr().lock().write_all(report.as_bytes());
+}
+
+/// Formats the report of an assertion panic.
+fn format_report(
+    location: Option<&str>,
+    thread_name: &str,
+    message: &str,
+    backtrace: Option<&str>,
+) -> String {
+    let location = location.unwrap_or("unknown location");
+    let mut report =
+        format!("assertion failed at {location} in thread '{thread_name}':\n{message}");
+    if !report.ends_with('\n') {
+        report.push('\n');
+    }
+    match backtrace {
+        Some(backtrace) => {
+            report.push_str("stack backtrace:\n");
+            report.push_str(&strip_internal_frames(backtrace));
+        },
+        None => report.push_str(
+            "note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n",
+        ),
+    }
+    report
+}
+
+/// Removes the leading frames of this crate and of the panic machinery from
+/// the given formatted backtrace and renumbers the remaining frames.
+fn strip_internal_frames(backtrace: &str) -> String {
+    let mut frames: Vec<Vec<&str>> = Vec::new();
+    let mut trailer = Vec::new();
+    for line in backtrace.lines() {
+        if frame_symbol(line).is_some() {
+            frames.push(vec![line]);
+        } else if let Some(frame) = frames.last_mut().filter(|_| line.starts_with(' ')) {
+            frame.push(line);
+        } else {
+            trailer.push(line);
+        }
+    }
+    let first_kept = frames
+        .iter()
+        .position(|frame| !is_internal_frame(frame[0]))
+        .unwrap_or(frames.len());
+    let mut stripped = String::with_capacity(backtrace.len());
+    for (number, frame) in frames[first_kept..].iter().enumerate() {
+        let symbol = frame_symbol(frame[0]).unwrap_or_default();
+        _ = writeln!(stripped, "{number:>4}: {symbol}");
+        for line in &frame[1..] {
+            stripped.push_str(line);
+            stripped.push('\n');
+        }
+    }
+    for line in trailer {
+        stripped.push_str(line);
+        stripped.push('\n');
+    }
+    stripped
+}
+
+/// Returns whether the frame starting with the given line belongs to this
+/// crate or the panic machinery.
+fn is_internal_frame(line: &str) -> bool {
+    frame_symbol(line).is_some_and(|symbol| {
+        INTERNAL_FRAME_PREFIXES
+            .iter()
+            .any(|prefix| symbol.starts_with(prefix))
+    })
+}
+
+/// Returns the symbol of the given line if it starts a frame, like
+/// `  12: my_crate::my_function`.
+fn frame_symbol(line: &str) -> Option<&str> {
+    let (number, symbol) = line.trim_start().split_once(": ")?;
+    if !number.is_empty() && number.bytes().all(|byte| byte.is_ascii_digit()) {
+        Some(symbol)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests;
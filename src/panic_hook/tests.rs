@@ -0,0 +1,99 @@
+use super::*;
+use crate::prelude::*;
+
+const BACKTRACE: &str = "   0: __rustc::rust_begin_unwind
+             at /rustc/library/std/src/panicking.rs:689:5
+   1: core::panicking::panic_fmt
+             at /rustc/library/core/src/panicking.rs:80:14
+   2: <asserting::spec::PanicOnFail as asserting::spec::FailingStrategy>::do_fail_with
+             at ./src/spec/mod.rs:3568:9
+   3: asserting::spec::Spec<S,R>::expecting
+             at ./src/spec/mod.rs:1200:13
+   4: my_crate::tests::answer_is_42
+             at ./src/tests.rs:12:5
+   5: my_crate::tests::answer_is_42::{{closure}}
+             at ./src/tests.rs:10:21
+   6: core::ops::function::FnOnce::call_once
+             at /rustc/library/core/src/ops/function.rs:250:5
+note: Some details are omitted, run with `RUST_BACKTRACE=full` for a verbose backtrace.
+";
+
+#[test]
+fn strip_internal_frames_removes_frames_of_asserting_and_the_panic_machinery() {
+    let stripped = strip_internal_frames(BACKTRACE);
+
+    assert_that(stripped).is_equal_to(
+        "   0: my_crate::tests::answer_is_42
+             at ./src/tests.rs:12:5
+   1: my_crate::tests::answer_is_42::{{closure}}
+             at ./src/tests.rs:10:21
+   2: core::ops::function::FnOnce::call_once
+             at /rustc/library/core/src/ops/function.rs:250:5
+note: Some details are omitted, run with `RUST_BACKTRACE=full` for a verbose backtrace.
+",
+    );
+}
+
+#[test]
+fn strip_internal_frames_keeps_frames_of_crates_with_a_similar_name() {
+    let stripped =
+        strip_internal_frames("   0: asserting::spec::check\n   1: asserting_extras::check\n");
+
+    assert_that(stripped).is_equal_to("   0: asserting_extras::check\n");
+}
+
+#[test]
+fn strip_internal_frames_keeps_internal_frames_following_the_first_kept_frame() {
+    let stripped = strip_internal_frames(
+        "   0: asserting::spec::check\n   1: my_crate::check\n   2: asserting::spec::satisfies\n",
+    );
+
+    assert_that(stripped).is_equal_to("   0: my_crate::check\n   1: asserting::spec::satisfies\n");
+}
+
+#[test]
+fn frame_symbol_of_a_line_starting_a_frame() {
+    assert_that(frame_symbol("  12: my_crate::my_function")).has_value("my_crate::my_function");
+}
+
+#[test]
+fn frame_symbol_of_a_line_with_the_source_location() {
+    assert_that(frame_symbol("             at ./src/tests.rs:12:5")).is_none();
+}
+
+#[test]
+fn format_report_without_backtrace() {
+    let report = format_report(
+        Some("src/tests.rs:12:5"),
+        "main",
+        "expected answer to be equal to 42\n   but was: 41\n  expected: 42\n",
+        None,
+    );
+
+    assert_that(report).is_equal_to(
+        "assertion failed at src/tests.rs:12:5 in thread 'main':
+expected answer to be equal to 42
+   but was: 41
+  expected: 42
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+",
+    );
+}
+
+#[test]
+fn format_report_with_backtrace() {
+    let report = format_report(
+        None,
+        "answer_is_42",
+        "expected answer to be positive",
+        Some("   0: asserting::spec::check\n   1: my_crate::check\n"),
+    );
+
+    assert_that(report).is_equal_to(
+        "assertion failed at unknown location in thread 'answer_is_42':
+expected answer to be positive
+stack backtrace:
+   0: my_crate::check
+",
+    );
+}
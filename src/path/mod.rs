@@ -0,0 +1,51 @@
+//! Implementation of assertions for `PathBuf` and `Path` values.
+//!
+//! `PathBuf` and `Path` are only available in std environments. Thus,
+//! assertions for those types are only available with crate feature `std`
+//! enabled.
+//!
+//! The length of a path is the number of bytes of its underlying `OsStr`. The
+//! character count is determined on the lossy UTF-8 conversion of the path,
+//! where each invalid sequence counts as one replacement character.
+
+use crate::properties::{CharCountProperty, IsEmptyProperty, LengthProperty};
+use std::path::{Path, PathBuf};
+
+impl IsEmptyProperty for PathBuf {
+    fn is_empty_property(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl LengthProperty for PathBuf {
+    fn length_property(&self) -> usize {
+        self.as_os_str().len()
+    }
+}
+
+impl CharCountProperty for PathBuf {
+    fn char_count_property(&self) -> usize {
+        self.to_string_lossy().chars().count()
+    }
+}
+
+impl IsEmptyProperty for &Path {
+    fn is_empty_property(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl LengthProperty for &Path {
+    fn length_property(&self) -> usize {
+        self.as_os_str().len()
+    }
+}
+
+impl CharCountProperty for &Path {
+    fn char_count_property(&self) -> usize {
+        self.to_string_lossy().chars().count()
+    }
+}
+
+#[cfg(test)]
+mod tests;
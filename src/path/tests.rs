@@ -0,0 +1,94 @@
+use crate::prelude::*;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn path_is_empty() {
+    let subject: &Path = Path::new("");
+
+    assert_that(subject).is_empty();
+}
+
+#[test]
+fn path_is_not_empty() {
+    let subject: &Path = Path::new("/tmp/lorem/ipsum.txt");
+
+    assert_that(subject).is_not_empty();
+}
+
+#[test]
+fn path_buf_is_empty() {
+    let subject: PathBuf = PathBuf::new();
+
+    assert_that(subject).is_empty();
+}
+
+#[test]
+fn path_buf_is_not_empty() {
+    let subject: PathBuf = PathBuf::from("dolor/sit.rs");
+
+    assert_that(subject).is_not_empty();
+}
+
+#[test]
+fn path_has_length() {
+    let subject: &Path = Path::new("src/lib.rs");
+
+    assert_that(subject).has_length(10);
+}
+
+#[test]
+fn path_buf_has_length() {
+    let subject: PathBuf = PathBuf::from("Cargo.toml");
+
+    assert_that(subject).has_length(10);
+}
+
+#[test]
+fn path_has_char_count() {
+    let subject: &Path = Path::new("daten/über.txt");
+
+    assert_that(subject).has_length(15).has_char_count(14);
+}
+
+#[test]
+fn path_buf_has_char_count() {
+    let subject: PathBuf = PathBuf::from("daten/über.txt");
+
+    assert_that(subject).has_char_count_in_range(10..=14);
+}
+
+#[test]
+fn verify_path_is_empty_fails() {
+    let subject: &Path = Path::new("foo/bar");
+
+    let failures = verify_that(subject)
+        .named("my_path")
+        .is_empty()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_path to be empty
+   but was: "foo/bar"
+  expected: <empty>
+"#]
+    );
+}
+
+#[test]
+fn verify_path_buf_has_length_fails() {
+    let subject: PathBuf = PathBuf::from("foo/bar");
+
+    let failures = verify_that(subject)
+        .named("my_path")
+        .has_length(8)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_path to have a length of 8
+   but was: 7
+  expected: 8
+"]
+    );
+}
@@ -0,0 +1,100 @@
+//! Implementation of assertions that match a value against a pattern.
+
+use crate::assertions::AssertMatchesPattern;
+use crate::colored::{mark_missing_string, mark_unexpected};
+use crate::expectations::{MatchesPattern, not};
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::fmt::Debug;
+use crate::std::format;
+use crate::std::string::String;
+
+/// Creates an expectation that a value matches the given pattern.
+///
+/// The pattern is written like a pattern in a `match` expression or the
+/// [`matches!`] macro of the standard library. It can contain alternatives
+/// separated by `|` and an optional `if` guard.
+///
+/// The created expectation can be used with the
+/// [`matches_pattern`](crate::assertions::AssertMatchesPattern::matches_pattern)
+/// assertion or with the [`expecting`](crate::spec::Expecting::expecting)
+/// method. The failure message shows the source of the pattern and the debug
+/// representation of the actual value.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// #[derive(Debug)]
+/// enum Message {
+///     Ping { seq: u32, payload: Vec<u8> },
+///     Quit,
+/// }
+///
+/// let message = Message::Ping { seq: 7, payload: vec![] };
+///
+/// assert_that!(message).matches_pattern(pattern!(Message::Ping { seq: 1.., .. }));
+///
+/// let message = Message::Quit;
+///
+/// assert_that!(message).expecting(pattern!(Message::Quit | Message::Ping { seq: 0, .. }));
+/// ```
+#[macro_export]
+macro_rules! pattern {
+    ($pattern:pat $(if $guard:expr)? $(,)?) => {
+        $crate::expectations::matches_pattern(
+            ::core::stringify!($pattern $(if $guard)?),
+            |subject: &_| ::core::matches!(subject, $pattern $(if $guard)?),
+        )
+    };
+}
+
+impl<S, F, R> AssertMatchesPattern<MatchesPattern<F>> for Spec<'_, S, R>
+where
+    S: Debug,
+    F: Fn(&S) -> bool,
+    R: FailingStrategy,
+{
+    fn matches_pattern(self, pattern: MatchesPattern<F>) -> Self {
+        self.expecting(pattern)
+    }
+
+    fn does_not_match_pattern(self, pattern: MatchesPattern<F>) -> Self {
+        self.expecting(not(pattern))
+    }
+}
+
+impl<S, F> Expectation<S> for MatchesPattern<F>
+where
+    S: Debug,
+    F: Fn(&S) -> bool,
+{
+    const ASSERTION_ID: &'static str = "asserting::matches_pattern";
+
+    fn test(&mut self, subject: &S) -> bool {
+        (self.predicate)(subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let pattern = self.pattern;
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing_string(pattern, format);
+        format!(
+            "expected {expression} to {not}match the pattern {pattern}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl<F> Invertible for MatchesPattern<F> {}
+
+#[cfg(test)]
+mod tests;
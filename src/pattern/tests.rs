@@ -0,0 +1,149 @@
+use crate::prelude::*;
+use crate::std::string::String;
+use crate::std::vec;
+use crate::std::vec::Vec;
+
+#[derive(Debug)]
+enum Message {
+    Ping { seq: u32, payload: Vec<u8> },
+    Text(String),
+    Quit,
+}
+
+#[test]
+fn enum_variant_matches_pattern() {
+    let message = Message::Ping {
+        seq: 3,
+        payload: vec![1, 2],
+    };
+
+    assert_that(message).matches_pattern(pattern!(Message::Ping { seq: 1.., .. }));
+}
+
+#[test]
+fn enum_variant_matches_pattern_with_guard() {
+    let message = Message::Text("hello world".into());
+
+    assert_that(message)
+        .matches_pattern(pattern!(Message::Text(text) if text.starts_with("hello")));
+}
+
+#[test]
+fn enum_variant_matches_pattern_with_binding_in_guard() {
+    let message = Message::Ping {
+        seq: 5,
+        payload: vec![],
+    };
+
+    assert_that(message).matches_pattern(
+        pattern!(Message::Ping { seq, payload } if *seq > 1 && payload.is_empty()),
+    );
+}
+
+#[test]
+fn enum_variant_matches_pattern_with_alternatives() {
+    let message = Message::Quit;
+
+    assert_that(message).matches_pattern(pattern!(Message::Quit | Message::Text(_)));
+}
+
+#[test]
+fn enum_variant_does_not_match_pattern() {
+    let message = Message::Text("hello world".into());
+
+    assert_that(message).does_not_match_pattern(pattern!(Message::Ping { .. }));
+}
+
+#[test]
+fn pattern_can_be_used_with_expecting() {
+    let subject: Option<(i32, char)> = Some((42, 'x'));
+
+    assert_that(subject).expecting(pattern!(Some((40..=50, 'a'..='z'))));
+}
+
+#[test]
+fn verify_enum_variant_matches_pattern_fails() {
+    let message = Message::Ping {
+        seq: 0,
+        payload: vec![],
+    };
+
+    let failures = verify_that(message)
+        .named("message")
+        .matches_pattern(pattern!(Message::Ping { seq: 1.., .. }))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected message to match the pattern Message::Ping { seq: 1.., .. }
+   but was: Ping { seq: 0, payload: [] }
+  expected: Message::Ping { seq: 1.., .. }
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_enum_variant_matches_pattern_with_guard_fails() {
+    let message = Message::Text("goodbye".into());
+
+    let failures = verify_that(message)
+        .named("message")
+        .matches_pattern(pattern!(Message::Text(text) if text.starts_with("hello")))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected message to match the pattern Message::Text(text) if text.starts_with("hello")
+   but was: Text("goodbye")
+  expected: Message::Text(text) if text.starts_with("hello")
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_enum_variant_does_not_match_pattern_fails() {
+    let message = Message::Quit;
+
+    let failures = verify_that(message)
+        .named("message")
+        .does_not_match_pattern(pattern!(Message::Quit))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected message to not match the pattern Message::Quit
+   but was: Quit
+  expected: not Message::Quit
+"]
+    );
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use super::*;
+
+    #[test]
+    fn highlight_diffs_enum_variant_matches_pattern() {
+        let message = Message::Quit;
+
+        let failures = verify_that(message)
+            .named("message")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .matches_pattern(pattern!(Message::Text(_)))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected message to match the pattern Message::Text(_)\n   \
+                but was: \u{1b}[31mQuit\u{1b}[0m\n  \
+               expected: \u{1b}[34mMessage::Text(_)\u{1b}[0m\n\
+            "
+            ]
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! Implementation of assertions for `Poll` values and for polling futures.
+
+use crate::assertions::{AssertFuturePoll, AssertPoll, AssertPollValue};
+use crate::colored::{mark_diff, mark_missing, mark_unexpected};
+use crate::expectations::{IsPending, IsReady, IsReadyWith, is_pending, is_ready, is_ready_with};
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec, Unknown,
+};
+use crate::std::fmt::Debug;
+use crate::std::future::Future;
+use crate::std::pin::pin;
+use crate::std::task::{Context, Poll, Waker};
+use crate::std::{format, string::String};
+
+impl<S, R> AssertPoll for Spec<'_, Poll<S>, R>
+where
+    S: Debug,
+    R: FailingStrategy,
+{
+    fn is_ready(self) -> Self {
+        self.expecting(is_ready())
+    }
+
+    fn is_pending(self) -> Self {
+        self.expecting(is_pending())
+    }
+}
+
+impl<S, R> AssertPoll for Spec<'_, &Poll<S>, R>
+where
+    S: Debug,
+    R: FailingStrategy,
+{
+    fn is_ready(self) -> Self {
+        self.expecting(is_ready())
+    }
+
+    fn is_pending(self) -> Self {
+        self.expecting(is_pending())
+    }
+}
+
+impl<S, E, R> AssertPollValue<E> for Spec<'_, Poll<S>, R>
+where
+    S: PartialEq<E> + Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    fn is_ready_with(self, expected: E) -> Self {
+        self.expecting(is_ready_with(expected))
+    }
+}
+
+impl<S, E, R> AssertPollValue<E> for Spec<'_, &Poll<S>, R>
+where
+    S: PartialEq<E> + Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    fn is_ready_with(self, expected: E) -> Self {
+        self.expecting(is_ready_with(expected))
+    }
+}
+
+impl<'a, F, R> AssertFuturePoll for Spec<'a, F, R>
+where
+    F: Future,
+    R: FailingStrategy,
+{
+    type Polled = Spec<'a, Poll<F::Output>, R>;
+
+    fn poll_once(self) -> Self::Polled {
+        self.mapping(poll_once)
+    }
+}
+
+/// Polls the given future once using a waker that does nothing.
+pub fn poll_once<F>(future: F) -> Poll<F::Output>
+where
+    F: Future,
+{
+    let mut context = Context::from_waker(Waker::noop());
+    pin!(future).poll(&mut context)
+}
+
+impl<T> Expectation<Poll<T>> for IsReady
+where
+    T: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_ready";
+
+    fn test(&mut self, subject: &Poll<T>) -> bool {
+        subject.is_ready()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Poll<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected = Poll::Ready(Unknown);
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing(&expected, format);
+        format!(
+            "expected {expression} to be {expected:?}\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<T> Expectation<&Poll<T>> for IsReady
+where
+    T: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_ready";
+
+    fn test(&mut self, subject: &&Poll<T>) -> bool {
+        <Self as Expectation<Poll<T>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Poll<T>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Poll<T>>>::message(self, expression, actual, inverted, format)
+    }
+}
+
+impl<T> Expectation<Poll<T>> for IsPending
+where
+    T: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_pending";
+
+    fn test(&mut self, subject: &Poll<T>) -> bool {
+        subject.is_pending()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Poll<T>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected = Poll::<Unknown>::Pending;
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing(&expected, format);
+        format!(
+            "expected {expression} to be {expected:?}\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<T> Expectation<&Poll<T>> for IsPending
+where
+    T: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_pending";
+
+    fn test(&mut self, subject: &&Poll<T>) -> bool {
+        <Self as Expectation<Poll<T>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Poll<T>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Poll<T>>>::message(self, expression, actual, inverted, format)
+    }
+}
+
+impl<T, E> Expectation<Poll<T>> for IsReadyWith<E>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_ready_with";
+
+    fn test(&mut self, subject: &Poll<T>) -> bool {
+        match subject {
+            Poll::Ready(value) => value == &self.expected,
+            Poll::Pending => false,
+        }
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Poll<T>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = &self.expected;
+        let (marked_actual, marked_expected) = match actual {
+            Poll::Ready(value) => mark_diff(&Poll::Ready(value), &Poll::Ready(expected), format),
+            Poll::Pending => (
+                mark_unexpected(actual, format),
+                mark_missing(&Poll::Ready(expected), format),
+            ),
+        };
+        format!(
+            "expected {expression} to be ready {not}with {expected:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl<E> Invertible for IsReadyWith<E> {}
+
+impl<T, E> Expectation<&Poll<T>> for IsReadyWith<E>
+where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_ready_with";
+
+    fn test(&mut self, subject: &&Poll<T>) -> bool {
+        <Self as Expectation<Poll<T>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Poll<T>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Poll<T>>>::message(self, expression, actual, inverted, format)
+    }
+}
+
+#[cfg(test)]
+mod tests;
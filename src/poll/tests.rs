@@ -0,0 +1,199 @@
+use crate::prelude::*;
+use crate::std::future::{pending, ready};
+use crate::std::task::Poll;
+use crate::std::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+#[test]
+fn poll_of_i32_is_ready() {
+    let subject: Poll<i32> = Poll::Ready(42);
+
+    assert_that(subject).is_ready();
+}
+
+#[test]
+fn poll_of_i32_is_pending() {
+    let subject: Poll<i32> = Poll::Pending;
+
+    assert_that(subject).is_pending();
+}
+
+#[test]
+fn borrowed_poll_of_string_is_ready() {
+    let subject: Poll<String> = Poll::Ready("vero".to_string());
+
+    assert_that(&subject).is_ready();
+}
+
+#[test]
+fn verify_poll_of_i32_is_ready_fails() {
+    let subject: Poll<i32> = Poll::Pending;
+
+    let failures = verify_that(subject)
+        .named("next_value")
+        .is_ready()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected next_value to be Ready(_)
+   but was: Pending
+  expected: Ready(_)
+"]
+    );
+}
+
+#[test]
+fn verify_poll_of_i32_is_pending_fails() {
+    let subject: Poll<i32> = Poll::Ready(-3);
+
+    let failures = verify_that(subject)
+        .named("next_value")
+        .is_pending()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected next_value to be Pending
+   but was: Ready(-3)
+  expected: Pending
+"]
+    );
+}
+
+#[test]
+fn poll_of_vec_is_ready_with_value() {
+    let subject: Poll<Vec<i32>> = Poll::Ready(vec![1, 2, 3]);
+
+    assert_that(subject).is_ready_with(vec![1, 2, 3]);
+}
+
+#[test]
+fn borrowed_poll_of_string_is_ready_with_str() {
+    let subject: Poll<String> = Poll::Ready("vero".to_string());
+
+    assert_that(&subject).is_ready_with("vero");
+}
+
+#[test]
+fn verify_poll_of_i32_is_ready_with_fails_if_pending() {
+    let subject: Poll<i32> = Poll::Pending;
+
+    let failures = verify_that(subject)
+        .named("next_value")
+        .is_ready_with(7)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected next_value to be ready with 7
+   but was: Pending
+  expected: Ready(7)
+"]
+    );
+}
+
+#[test]
+fn verify_poll_of_i32_is_ready_with_fails_if_ready_with_other_value() {
+    let subject: Poll<i32> = Poll::Ready(5);
+
+    let failures = verify_that(subject)
+        .named("next_value")
+        .is_ready_with(7)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected next_value to be ready with 7
+   but was: Ready(5)
+  expected: Ready(7)
+"]
+    );
+}
+
+#[test]
+fn ready_future_polled_once_is_ready_with_value() {
+    assert_that(ready(42)).poll_once().is_ready_with(42);
+}
+
+#[test]
+fn pending_future_polled_once_is_pending() {
+    assert_that(pending::<()>()).poll_once().is_pending();
+}
+
+#[test]
+fn async_block_polled_once_is_ready_with_value() {
+    let future = async { "ipsum".len() };
+
+    assert_that(future).poll_once().is_ready_with(5);
+}
+
+#[test]
+fn borrowed_future_can_be_polled_more_than_once() {
+    let mut future = pending::<i32>();
+
+    assert_that(&mut future).poll_once().is_pending();
+    assert_that(&mut future).poll_once().is_pending();
+}
+
+#[test]
+fn verify_pending_future_polled_once_is_ready_fails() {
+    let failures = verify_that(pending::<u8>())
+        .named("future")
+        .poll_once()
+        .is_ready()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected future to be Ready(_)
+   but was: Pending
+  expected: Ready(_)
+"]
+    );
+}
+
+#[test]
+fn extracted_poll_is_ready_with_value() {
+    struct Task {
+        state: Poll<u8>,
+    }
+
+    let task = Task {
+        state: Poll::Ready(3),
+    };
+
+    assert_that(task)
+        .extracting_ref("state", |t| &t.state)
+        .is_ready()
+        .and()
+        .extracting_ref("state", |t| &t.state)
+        .is_ready_with(3);
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use crate::prelude::*;
+    use crate::std::task::Poll;
+
+    #[test]
+    fn highlight_diffs_poll_of_i32_is_ready() {
+        let subject: Poll<i32> = Poll::Pending;
+
+        let failures = verify_that(subject)
+            .with_diff_format(DIFF_FORMAT_RED_YELLOW)
+            .is_ready()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected subject to be Ready(_)\n   \
+                but was: \u{1b}[31mPending\u{1b}[0m\n  \
+               expected: \u{1b}[33mReady(_)\u{1b}[0m\n\
+            "]
+        );
+    }
+}
@@ -1,13 +1,16 @@
 //! Implementation of the predicate assertion.
 
-use crate::expectations::Predicate;
+use crate::expectations::{FromFn, Predicate, ResultPredicate};
 use crate::spec::{DiffFormat, Expectation, Expression, Invertible};
-use crate::std::{format, string::String};
+use crate::std::fmt::Display;
+use crate::std::{format, string::String, string::ToString};
 
 impl<S, P> Expectation<S> for Predicate<P>
 where
     P: Fn(&S) -> bool,
 {
+    const ASSERTION_ID: &'static str = "asserting::satisfies";
+
     fn test(&mut self, subject: &S) -> bool {
         (self.predicate)(subject)
     }
@@ -27,5 +30,55 @@ where
 
 impl<P> Invertible for Predicate<P> {}
 
+impl<S, P, E> Expectation<S> for ResultPredicate<P>
+where
+    P: Fn(&S) -> Result<(), E>,
+    E: Display,
+{
+    const ASSERTION_ID: &'static str = "asserting::satisfies_with";
+
+    fn test(&mut self, subject: &S) -> bool {
+        match (self.predicate)(subject) {
+            Ok(()) => true,
+            Err(reason) => {
+                self.reason = Some(reason.to_string());
+                false
+            },
+        }
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        _actual: &S,
+        _inverted: bool,
+        _format: &DiffFormat,
+    ) -> String {
+        let reason = self.reason.as_deref().unwrap_or_default();
+        format!("expected {expression} to satisfy the given predicate, but failed: {reason}")
+    }
+}
+
+impl<S, T, M> Expectation<S> for FromFn<T, M>
+where
+    S: ?Sized,
+    T: FnMut(&S) -> bool,
+    M: Fn(&Expression<'_>, &S) -> String,
+{
+    fn test(&mut self, subject: &S) -> bool {
+        (self.test)(subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        _format: &DiffFormat,
+    ) -> String {
+        (self.message)(expression, actual)
+    }
+}
+
 #[cfg(test)]
 mod tests;
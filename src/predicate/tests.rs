@@ -1,4 +1,7 @@
+use crate::matchers::{from_fn, satisfies};
 use crate::prelude::*;
+use crate::std::format;
+use crate::std::string::String;
 
 #[test]
 fn assert_that_subject_satisfies_predicate() {
@@ -37,3 +40,106 @@ fn verify_that_subject_satisfies_predicate_fails_with_custom_message() {
 
     assert_eq!(failures, &["the answer to all important questions is 42\n"]);
 }
+
+#[test]
+fn assert_that_subject_satisfies_predicate_returning_result() {
+    let subject = 42;
+
+    assert_that(subject)
+        .named("my_thing")
+        .satisfies_with(|actual| if *actual == 42 { Ok(()) } else { Err("not 42") });
+}
+
+#[test]
+fn verify_that_subject_satisfies_predicate_returning_result_fails() {
+    let subject = 51;
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .satisfies_with(|actual| {
+            if *actual == 42 {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{actual} is not the answer to all important questions"
+                ))
+            }
+        })
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            "expected my_thing to satisfy the given predicate, but failed: 51 is not the answer to all important questions\n"
+        ]
+    );
+}
+
+#[test]
+fn satisfies_with_is_provided_for_custom_implementations_of_satisfies() {
+    use crate::spec::{Expectation, Expecting, Satisfies};
+
+    struct Checked {
+        subject: i32,
+        passed: bool,
+    }
+
+    impl Expecting<i32> for Checked {
+        fn expecting(mut self, mut expectation: impl Expectation<i32>) -> Self {
+            self.passed = self.passed && expectation.test(&self.subject);
+            self
+        }
+    }
+
+    impl Satisfies<i32> for Checked {
+        fn satisfies<P>(self, predicate: P) -> Self
+        where
+            P: Fn(&i32) -> bool,
+        {
+            self.expecting(satisfies(predicate))
+        }
+
+        fn satisfies_with_message<P>(self, _message: impl Into<String>, predicate: P) -> Self
+        where
+            P: Fn(&i32) -> bool,
+        {
+            self.satisfies(predicate)
+        }
+    }
+
+    let checked = Checked {
+        subject: 51,
+        passed: true,
+    }
+    .satisfies_with(|actual| if *actual == 42 { Ok(()) } else { Err("not 42") });
+
+    assert_that!(checked.passed).is_false();
+}
+
+#[test]
+fn assert_that_subject_satisfies_expectation_from_fn() {
+    let subject = 42;
+
+    assert_that(subject).named("my_thing").expecting(from_fn(
+        |actual: &i32| *actual % 2 == 0,
+        |expression, actual| format!("expected {expression} to be even\n   but was: {actual}"),
+    ));
+}
+
+#[test]
+fn verify_that_subject_satisfies_expectation_from_fn_fails() {
+    let subject = 51;
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .expecting(from_fn(
+            |actual: &i32| *actual % 2 == 0,
+            |expression, actual| format!("expected {expression} to be even\n   but was: {actual}"),
+        ))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &["expected my_thing to be even\n   but was: 51\n"]
+    );
+}
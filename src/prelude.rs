@@ -17,11 +17,13 @@
 pub use super::{
     assert_that,
     assertions::*,
+    check_that,
     colored::{DEFAULT_DIFF_FORMAT, DIFF_FORMAT_NO_HIGHLIGHT},
+    const_assert_that, pattern,
     properties::*,
     spec::{
-        And, CollectFailures, DoFail, Expecting, GetFailures, Location, PanicOnFail, Satisfies,
-        SoftPanic, assert_that, verify_that,
+        And, CollectFailures, DoFail, EnsureChecked, Expecting, FailAfter, GetFailures, Location,
+        PanicOnFail, Satisfies, SoftPanic, assert_that, verify_that,
     },
     verify_that,
 };
@@ -29,7 +31,8 @@ pub use super::{
 #[cfg(feature = "colored")]
 #[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
 pub use super::colored::{
-    DIFF_FORMAT_BOLD, DIFF_FORMAT_RED_BLUE, DIFF_FORMAT_RED_GREEN, DIFF_FORMAT_RED_YELLOW,
+    DIFF_FORMAT_BOLD, DIFF_FORMAT_PLAIN, DIFF_FORMAT_RED_BLUE, DIFF_FORMAT_RED_GREEN,
+    DIFF_FORMAT_RED_YELLOW,
 };
 
 #[cfg(feature = "panic")]
@@ -40,6 +43,18 @@ pub use super::{
     verify_that_code,
 };
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use super::{
+    assert_that_env,
+    spec::{assert_that_env, verify_that_env},
+    verify_that_env,
+};
+
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub use super::prop_verify_that;
+
 #[cfg(feature = "recursive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "recursive")))]
 pub use super::value;
@@ -0,0 +1,258 @@
+//! Implementation of assertions for the exit status and the output of
+//! finished processes.
+
+use crate::assertion_id::wrap_assertion_id;
+use crate::assertions::{AssertExitStatus, AssertProcessOutput};
+use crate::colored::{mark_missing_string, mark_unexpected_string};
+use crate::expectations::{
+    HasExitCode, ProcessFailed, ProcessSucceeded, Stderr, Stdout, has_exit_code, not,
+    process_failed, process_succeeded, stderr, stdout, string_contains,
+};
+use crate::properties::ExitStatusProperty;
+use crate::spec::{
+    DiffFormat, EvalError, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::borrow::{Borrow, Cow};
+use crate::std::format;
+use crate::std::string::{String, ToString};
+use std::process::{ExitStatus, Output};
+
+impl ExitStatusProperty for ExitStatus {
+    fn exit_status_property(&self) -> ExitStatus {
+        *self
+    }
+}
+
+impl ExitStatusProperty for Output {
+    fn exit_status_property(&self) -> ExitStatus {
+        self.status
+    }
+}
+
+impl<S, R> AssertExitStatus for Spec<'_, S, R>
+where
+    S: ExitStatusProperty,
+    R: FailingStrategy,
+{
+    fn has_exit_code(self, expected: i32) -> Self {
+        self.expecting(has_exit_code(expected))
+    }
+
+    fn does_not_have_exit_code(self, expected: i32) -> Self {
+        self.expecting(not(has_exit_code(expected)))
+    }
+
+    fn succeeded(self) -> Self {
+        self.expecting(process_succeeded())
+    }
+
+    fn failed(self) -> Self {
+        self.expecting(process_failed())
+    }
+}
+
+/// Formats the exit code of the given exit status or the exit status itself if
+/// the process has been terminated by a signal.
+fn exit_code_or_status(status: ExitStatus) -> String {
+    status
+        .code()
+        .map_or_else(|| status.to_string(), |code| code.to_string())
+}
+
+impl<S> Expectation<S> for HasExitCode
+where
+    S: ExitStatusProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_exit_code";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.exit_status_property().code() == Some(self.expected)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected;
+        let actual = exit_code_or_status(actual.exit_status_property());
+        let marked_actual = mark_unexpected_string(&actual, format);
+        let marked_expected = mark_missing_string(&expected.to_string(), format);
+        format!(
+            "expected {expression} to {not}have exit code {expected}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl Invertible for HasExitCode {}
+
+impl<S> Expectation<S> for ProcessSucceeded
+where
+    S: ExitStatusProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::succeeded";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.exit_status_property().success()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual = actual.exit_status_property().to_string();
+        let marked_actual = mark_unexpected_string(&actual, format);
+        let marked_expected = mark_missing_string("success", format);
+        format!(
+            "expected {expression} to succeed\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<S> Expectation<S> for ProcessFailed
+where
+    S: ExitStatusProperty,
+{
+    const ASSERTION_ID: &'static str = "asserting::failed";
+
+    fn test(&mut self, subject: &S) -> bool {
+        !subject.exit_status_property().success()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let actual = actual.exit_status_property().to_string();
+        let marked_actual = mark_unexpected_string(&actual, format);
+        let marked_expected = mark_missing_string("failure", format);
+        format!(
+            "expected {expression} to fail\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<'a, S, R> AssertProcessOutput<'a> for Spec<'_, S, R>
+where
+    S: Borrow<Output>,
+    R: FailingStrategy,
+{
+    fn stdout_contains(self, expected: &'a str) -> Self {
+        self.expecting(stdout(string_contains(expected)))
+    }
+
+    fn stdout_does_not_contain(self, expected: &'a str) -> Self {
+        self.expecting(stdout(not(string_contains(expected))))
+    }
+
+    fn stderr_contains(self, expected: &'a str) -> Self {
+        self.expecting(stderr(string_contains(expected)))
+    }
+
+    fn stderr_does_not_contain(self, expected: &'a str) -> Self {
+        self.expecting(stderr(not(string_contains(expected))))
+    }
+}
+
+impl<S, E> Expectation<S> for Stdout<E>
+where
+    S: Borrow<Output>,
+    E: Expectation<String>,
+{
+    fn assertion_id(&self) -> Cow<'static, str> {
+        Cow::Owned(wrap_assertion_id("stdout", &self.0.assertion_id()))
+    }
+
+    fn test(&mut self, subject: &S) -> bool {
+        let stdout = String::from_utf8_lossy(&subject.borrow().stdout).into_owned();
+        self.0.test(&stdout)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        let stdout = String::from_utf8_lossy(&subject.borrow().stdout).into_owned();
+        self.0.try_test(&stdout)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let stdout = String::from_utf8_lossy(&actual.borrow().stdout).into_owned();
+        let expression = Expression::from(format!("{expression}'s stdout"));
+        self.0.message(&expression, &stdout, inverted, format)
+    }
+}
+
+impl<E> Invertible for Stdout<E> where E: Invertible {}
+
+impl<S, E> Expectation<S> for Stderr<E>
+where
+    S: Borrow<Output>,
+    E: Expectation<String>,
+{
+    fn assertion_id(&self) -> Cow<'static, str> {
+        Cow::Owned(wrap_assertion_id("stderr", &self.0.assertion_id()))
+    }
+
+    fn test(&mut self, subject: &S) -> bool {
+        let stderr = String::from_utf8_lossy(&subject.borrow().stderr).into_owned();
+        self.0.test(&stderr)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        let stderr = String::from_utf8_lossy(&subject.borrow().stderr).into_owned();
+        self.0.try_test(&stderr)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let stderr = String::from_utf8_lossy(&actual.borrow().stderr).into_owned();
+        let expression = Expression::from(format!("{expression}'s stderr"));
+        self.0.message(&expression, &stderr, inverted, format)
+    }
+}
+
+impl<E> Invertible for Stderr<E> where E: Invertible {}
+
+#[cfg(feature = "regex")]
+mod regex {
+    use crate::assertions::AssertProcessOutputMatches;
+    use crate::expectations::{stderr, stdout, string_matches};
+    use crate::spec::{Expecting, FailingStrategy, Spec};
+    use crate::std::borrow::Borrow;
+    use std::process::Output;
+
+    impl<S, R> AssertProcessOutputMatches for Spec<'_, S, R>
+    where
+        S: Borrow<Output>,
+        R: FailingStrategy,
+    {
+        fn stdout_matches(self, regex_pattern: &str) -> Self {
+            self.expecting(stdout(string_matches(regex_pattern)))
+        }
+
+        fn stderr_matches(self, regex_pattern: &str) -> Self {
+            self.expecting(stderr(string_matches(regex_pattern)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
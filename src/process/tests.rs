@@ -0,0 +1,233 @@
+use crate::prelude::*;
+use crate::std::vec::Vec;
+use std::process::{ExitStatus, Output};
+
+#[cfg(unix)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+
+    ExitStatus::from_raw(code << 8)
+}
+
+#[cfg(windows)]
+fn exit_status(code: i32) -> ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+
+    #[allow(clippy::cast_sign_loss)]
+    ExitStatus::from_raw(code as u32)
+}
+
+fn output(code: i32, stdout: &str, stderr: &str) -> Output {
+    Output {
+        status: exit_status(code),
+        stdout: stdout.as_bytes().to_vec(),
+        stderr: stderr.as_bytes().to_vec(),
+    }
+}
+
+#[test]
+fn exit_status_of_successful_process_has_exit_code_zero() {
+    let subject = ExitStatus::default();
+
+    assert_that(subject)
+        .has_exit_code(0)
+        .does_not_have_exit_code(1)
+        .succeeded();
+}
+
+#[test]
+fn output_of_failed_process_has_exit_code() {
+    let subject = output(2, "", "error: no input file");
+
+    assert_that(&subject)
+        .has_exit_code(2)
+        .does_not_have_exit_code(0)
+        .failed();
+}
+
+#[test]
+fn output_stdout_and_stderr_contain_text() {
+    let subject = output(0, "compiled 3 files\n", "warning: unused variable\n");
+
+    assert_that(subject)
+        .succeeded()
+        .stdout_contains("3 files")
+        .stdout_does_not_contain("warning")
+        .stderr_contains("unused variable")
+        .stderr_does_not_contain("error");
+}
+
+#[test]
+fn output_stdout_with_invalid_utf8_is_decoded_lossy() {
+    let subject = Output {
+        status: ExitStatus::default(),
+        stdout: b"Hello, \xF0\x90\x80World!".to_vec(),
+        stderr: Vec::new(),
+    };
+
+    assert_that(subject).stdout_contains("\u{FFFD}World");
+}
+
+#[test]
+fn verify_output_has_exit_code_fails() {
+    let subject = output(101, "", "");
+
+    let failures = verify_that(subject)
+        .named("output")
+        .has_exit_code(0)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected output to have exit code 0
+   but was: 101
+  expected: 0
+"]
+    );
+}
+
+#[test]
+fn verify_exit_status_does_not_have_exit_code_fails() {
+    let subject = exit_status(3);
+
+    let failures = verify_that(subject)
+        .named("status")
+        .does_not_have_exit_code(3)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected status to not have exit code 3
+   but was: 3
+  expected: not 3
+"]
+    );
+}
+
+#[test]
+fn verify_output_succeeded_fails() {
+    let subject = output(1, "", "");
+
+    let failures = verify_that(subject)
+        .named("output")
+        .succeeded()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected output to succeed
+   but was: exit status: 1
+  expected: success
+"]
+    );
+}
+
+#[test]
+fn verify_exit_status_failed_fails() {
+    let subject = ExitStatus::default();
+
+    let failures = verify_that(subject)
+        .named("status")
+        .failed()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected status to fail
+   but was: exit status: 0
+  expected: failure
+"]
+    );
+}
+
+#[test]
+fn verify_output_stdout_contains_fails() {
+    let subject = output(0, "compiled 3 files", "");
+
+    let failures = verify_that(&subject)
+        .named("output")
+        .stdout_contains("4 files")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected output's stdout to contain "4 files"
+   but was: "compiled 3 files"
+  expected: "4 files"
+"#]
+    );
+}
+
+#[test]
+fn verify_output_stderr_does_not_contain_fails() {
+    let subject = output(1, "", "error: no input file");
+
+    let failures = verify_that(subject)
+        .named("output")
+        .stderr_does_not_contain("error")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected output's stderr to not contain "error"
+   but was: "error: no input file"
+  expected: not "error"
+"#]
+    );
+}
+
+#[cfg(feature = "regex")]
+mod regex {
+    use super::*;
+
+    #[test]
+    fn output_stdout_and_stderr_match_regex() {
+        let subject = output(0, "rustc 1.85.1 (4eb161250 2025-03-15)", "");
+
+        assert_that(subject)
+            .stdout_matches(r"^rustc \d+\.\d+\.\d+")
+            .stderr_matches(r"^$");
+    }
+
+    #[test]
+    fn verify_output_stderr_matches_fails() {
+        let subject = output(1, "", "error: no input file");
+
+        let failures = verify_that(subject)
+            .named("output")
+            .stderr_matches(r"^warning")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected output's stderr to match the regex ^warning
+               but was: error: no input file
+  does not match regex: ^warning
+"]
+        );
+    }
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use super::*;
+
+    #[test]
+    fn highlight_diffs_output_has_exit_code() {
+        let subject = output(101, "", "");
+
+        let failures = verify_that(subject)
+            .named("output")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .has_exit_code(0)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected output to have exit code 0\n   \
+                but was: \u{1b}[31m101\u{1b}[0m\n  \
+               expected: \u{1b}[34m0\u{1b}[0m\n\
+            "]
+        );
+    }
+}
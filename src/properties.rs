@@ -23,7 +23,13 @@
 //! specifies that a collection's iterator yields the items in a well-defined
 //! order.
 
+use crate::std::any::Any;
+use crate::std::error::Error;
+use crate::std::fmt::Debug;
 use crate::std::iter::Iterator;
+use crate::std::net::IpAddr;
+#[cfg(feature = "std")]
+use std::process::ExitStatus;
 
 /// The "empty" property of a collection-like type.
 ///
@@ -52,6 +58,34 @@ where
     }
 }
 
+/// The property of a value to be equal to the default value of its type.
+///
+/// This property is implemented for all types that implement [`Default`] and
+/// [`PartialEq`].
+///
+/// This property is used by the implementation of the
+/// [`AssertDefault`](crate::assertions::AssertDefault) assertions.
+pub trait DefaultProperty: Sized {
+    /// Returns the default value of the type.
+    fn default_property() -> Self;
+
+    /// Returns whether the value is equal to the default value of its type.
+    fn is_default_property(&self) -> bool;
+}
+
+impl<T> DefaultProperty for T
+where
+    T: Default + PartialEq,
+{
+    fn default_property() -> Self {
+        T::default()
+    }
+
+    fn is_default_property(&self) -> bool {
+        self == &T::default()
+    }
+}
+
 /// The length property of a collection-like type.
 ///
 /// Collection-like types are, for example, `Vec`, slice, array, `HashSet`,
@@ -89,6 +123,272 @@ pub trait DefinedOrderProperty {}
 impl<C> DefinedOrderProperty for &C where C: DefinedOrderProperty + ?Sized {}
 impl<C> DefinedOrderProperty for &mut C where C: DefinedOrderProperty + ?Sized {}
 
+/// The property of a collection-like type to hold its items in a defined
+/// order, which can be iterated without consuming the collection.
+///
+/// This property is used by the assertions of
+/// [`AssertSequence`](crate::assertions::AssertSequence) and the
+/// expectations
+/// [`IteratorContainsSequence`](crate::expectations::IteratorContainsSequence),
+/// [`IteratorStartsWith`](crate::expectations::IteratorStartsWith) and
+/// [`IteratorEndsWith`](crate::expectations::IteratorEndsWith). By
+/// implementing this property, a custom container can be asserted with these
+/// assertions without converting it into a `Vec` first.
+///
+/// Collections of the standard library, which implement `IntoIterator`, are
+/// asserted with the assertions of
+/// [`AssertIteratorContainsInOrder`](crate::assertions::AssertIteratorContainsInOrder)
+/// instead. A custom container should implement either `IntoIterator` or this
+/// property, but not both, as the assertion methods of the two traits have the
+/// same names.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use asserting::properties::SequenceProperty;
+///
+/// #[derive(Debug)]
+/// struct Playlist {
+///     titles: Vec<&'static str>,
+/// }
+///
+/// impl SequenceProperty for Playlist {
+///     type Item = &'static str;
+///
+///     fn sequence_property(&self) -> impl Iterator<Item = &Self::Item> {
+///         self.titles.iter()
+///     }
+/// }
+///
+/// let playlist = Playlist {
+///     titles: vec!["intro", "verse", "chorus", "outro"],
+/// };
+///
+/// assert_that!(playlist)
+///     .starts_with(["intro", "verse"])
+///     .ends_with(["outro"]);
+/// ```
+pub trait SequenceProperty {
+    /// The type of the items in the sequence.
+    type Item;
+
+    /// Returns an iterator over the items in their defined order.
+    fn sequence_property(&self) -> impl Iterator<Item = &Self::Item>;
+}
+
+impl<C> SequenceProperty for &C
+where
+    C: SequenceProperty + ?Sized,
+{
+    type Item = C::Item;
+
+    fn sequence_property(&self) -> impl Iterator<Item = &Self::Item> {
+        <C as SequenceProperty>::sequence_property(self)
+    }
+}
+
+impl<C> SequenceProperty for &mut C
+where
+    C: SequenceProperty + ?Sized,
+{
+    type Item = C::Item;
+
+    fn sequence_property(&self) -> impl Iterator<Item = &Self::Item> {
+        <C as SequenceProperty>::sequence_property(self)
+    }
+}
+
+/// The property of an error value to be of a specific concrete error type.
+///
+/// This property is implemented for error trait objects, like
+/// `Box<dyn Error>`, which hide the concrete type of the error. The type of the
+/// error is determined at runtime by downcasting the trait object.
+///
+/// This property is used by the implementation of the
+/// [`AssertHasErrorOfType`](crate::assertions::AssertHasErrorOfType)
+/// assertion.
+pub trait ErrorTypeProperty {
+    /// Returns whether the error is of the concrete type `X`.
+    fn is_error_of_type_property<X>(&self) -> bool
+    where
+        X: Error + 'static;
+}
+
+impl<T> ErrorTypeProperty for &T
+where
+    T: ErrorTypeProperty + ?Sized,
+{
+    fn is_error_of_type_property<X>(&self) -> bool
+    where
+        X: Error + 'static,
+    {
+        <T as ErrorTypeProperty>::is_error_of_type_property::<X>(self)
+    }
+}
+
+impl<T> ErrorTypeProperty for &mut T
+where
+    T: ErrorTypeProperty + ?Sized,
+{
+    fn is_error_of_type_property<X>(&self) -> bool
+    where
+        X: Error + 'static,
+    {
+        <T as ErrorTypeProperty>::is_error_of_type_property::<X>(self)
+    }
+}
+
+/// The property of a type-erased value, like `Box<dyn Any>`, to be an
+/// instance of a concrete type.
+///
+/// This property is used by the implementation of the
+/// [`AssertInstanceOf`](crate::assertions::AssertInstanceOf) assertions.
+pub trait AnyTypeProperty {
+    /// Returns whether the value is an instance of the concrete type `T`.
+    fn is_instance_of_property<T>(&self) -> bool
+    where
+        T: Any;
+}
+
+impl<A> AnyTypeProperty for &A
+where
+    A: AnyTypeProperty + ?Sized,
+{
+    fn is_instance_of_property<T>(&self) -> bool
+    where
+        T: Any,
+    {
+        <A as AnyTypeProperty>::is_instance_of_property::<T>(self)
+    }
+}
+
+impl<A> AnyTypeProperty for &mut A
+where
+    A: AnyTypeProperty + ?Sized,
+{
+    fn is_instance_of_property<T>(&self) -> bool
+    where
+        T: Any,
+    {
+        <A as AnyTypeProperty>::is_instance_of_property::<T>(self)
+    }
+}
+
+/// The exit status of a finished process.
+///
+/// This property is used by the implementation of the
+/// [`AssertExitStatus`](crate::assertions::AssertExitStatus) assertions.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait ExitStatusProperty {
+    /// Returns the exit status of the finished process.
+    fn exit_status_property(&self) -> ExitStatus;
+}
+
+#[cfg(feature = "std")]
+impl<T> ExitStatusProperty for &T
+where
+    T: ExitStatusProperty + ?Sized,
+{
+    fn exit_status_property(&self) -> ExitStatus {
+        <T as ExitStatusProperty>::exit_status_property(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> ExitStatusProperty for &mut T
+where
+    T: ExitStatusProperty + ?Sized,
+{
+    fn exit_status_property(&self) -> ExitStatus {
+        <T as ExitStatusProperty>::exit_status_property(self)
+    }
+}
+
+/// The IP address of a network address, like `IpAddr` or `SocketAddr`.
+///
+/// This property is used by the implementation of the
+/// [`AssertIpAddr`](crate::assertions::AssertIpAddr) assertions.
+pub trait IpAddrProperty {
+    /// Returns the IP address of this network address.
+    fn ip_addr_property(&self) -> IpAddr;
+}
+
+impl<T> IpAddrProperty for &T
+where
+    T: IpAddrProperty + ?Sized,
+{
+    fn ip_addr_property(&self) -> IpAddr {
+        <T as IpAddrProperty>::ip_addr_property(self)
+    }
+}
+
+impl<T> IpAddrProperty for &mut T
+where
+    T: IpAddrProperty + ?Sized,
+{
+    fn ip_addr_property(&self) -> IpAddr {
+        <T as IpAddrProperty>::ip_addr_property(self)
+    }
+}
+
+/// The port number of a socket address, like `SocketAddr`.
+///
+/// This property is used by the implementation of the
+/// [`AssertSocketAddr`](crate::assertions::AssertSocketAddr) assertions.
+pub trait PortProperty {
+    /// Returns the port number of this socket address.
+    fn port_property(&self) -> u16;
+}
+
+impl<T> PortProperty for &T
+where
+    T: PortProperty + ?Sized,
+{
+    fn port_property(&self) -> u16 {
+        <T as PortProperty>::port_property(self)
+    }
+}
+
+impl<T> PortProperty for &mut T
+where
+    T: PortProperty + ?Sized,
+{
+    fn port_property(&self) -> u16 {
+        <T as PortProperty>::port_property(self)
+    }
+}
+
+/// The capacity property of a collection-like type that preallocates memory.
+///
+/// Types with a capacity are, for example, `Vec`, `String` and `HashMap`.
+///
+/// This property is used by the implementation of the
+/// [`AssertHasCapacity`](crate::assertions::AssertHasCapacity) assertion.
+pub trait CapacityProperty {
+    /// Returns the number of elements the value can hold without reallocating.
+    fn capacity_property(&self) -> usize;
+}
+
+impl<T> CapacityProperty for &T
+where
+    T: CapacityProperty + ?Sized,
+{
+    fn capacity_property(&self) -> usize {
+        <T as CapacityProperty>::capacity_property(self)
+    }
+}
+
+impl<T> CapacityProperty for &mut T
+where
+    T: CapacityProperty + ?Sized,
+{
+    fn capacity_property(&self) -> usize {
+        <T as CapacityProperty>::capacity_property(self)
+    }
+}
+
 /// Property for types that contain characters.
 pub trait CharCountProperty {
     /// Returns the number of characters contained in this type.
@@ -125,6 +425,46 @@ pub trait MultiplicativeIdentityProperty {
     fn multiplicative_identity() -> Self;
 }
 
+/// The distance between numeric values and the tolerance around a numeric
+/// value.
+///
+/// This property is used by the implementation of the
+/// [`AssertWithin`](crate::assertions::AssertWithin) assertions.
+pub trait DistanceProperty: Sized {
+    /// The type of the absolute difference between two values, which can
+    /// represent the difference between any two values, like `u8` for `i8`.
+    type Distance: Debug;
+
+    /// Returns the absolute difference between this value and the other
+    /// value.
+    #[must_use]
+    fn distance_property(&self, other: &Self) -> Self::Distance;
+
+    /// Returns the lower and upper bound of the range of values that lie
+    /// within the given offset around this value.
+    ///
+    /// The sign of the offset is ignored. The bounds saturate at the minimum
+    /// and maximum value of the type.
+    fn bounds_within_property(&self, offset: &Self) -> (Self, Self);
+
+    /// Returns the absolute amount of the given percentage of this value.
+    #[must_use]
+    fn percentage_property(&self, percent: f64) -> Self;
+}
+
+/// The absolute difference between two numeric values, which may not be
+/// representable by the numeric type itself, like the difference between
+/// `i8::MIN` and `i8::MAX`.
+///
+/// This property is used by the implementation of the
+/// [`AssertPair::differ_by_at_most`](crate::assertions::AssertPair::differ_by_at_most)
+/// assertion.
+pub trait CheckedDifferenceProperty: Sized {
+    /// Returns the absolute difference between this value and the other value
+    /// or `None` if the difference overflows the type.
+    fn checked_difference_property(&self, other: &Self) -> Option<Self>;
+}
+
 /// A property of numeric types that can have negative and positive values.
 pub trait SignumProperty {
     /// Returns whether this value is negative.
@@ -160,6 +500,60 @@ where
     }
 }
 
+/// The bit pattern of an integer type.
+///
+/// This property is used by the implementation of the
+/// [`AssertBits`](crate::assertions::AssertBits) assertions.
+pub trait BitsProperty {
+    /// Returns the number of bits of the type.
+    fn bit_width_property(&self) -> u32;
+
+    /// Returns the bit pattern of this value extended with zeros to 128 bits.
+    fn bits_property(&self) -> u128;
+}
+
+impl<T> BitsProperty for &T
+where
+    T: BitsProperty + ?Sized,
+{
+    fn bit_width_property(&self) -> u32 {
+        <T as BitsProperty>::bit_width_property(self)
+    }
+
+    fn bits_property(&self) -> u128 {
+        <T as BitsProperty>::bits_property(self)
+    }
+}
+
+impl<T> BitsProperty for &mut T
+where
+    T: BitsProperty + ?Sized,
+{
+    fn bit_width_property(&self) -> u32 {
+        <T as BitsProperty>::bit_width_property(self)
+    }
+
+    fn bits_property(&self) -> u128 {
+        <T as BitsProperty>::bits_property(self)
+    }
+}
+
+/// The result of a checked arithmetic operation, like the value returned by
+/// [`i32::checked_add`], which indicates whether the operation overflowed.
+///
+/// This property is used by the implementation of the
+/// [`AssertOverflow`](crate::assertions::AssertOverflow) assertions.
+pub trait CheckedArithmeticProperty {
+    /// Returns whether the operation overflowed.
+    fn is_overflow_property(&self) -> bool;
+
+    /// Returns the pattern of a result that indicates an overflow.
+    fn overflow_pattern_property() -> &'static str;
+
+    /// Returns the pattern of a result that holds the computed value.
+    fn no_overflow_pattern_property() -> &'static str;
+}
+
 /// A property of floating point numbers that may have infinite or finite
 /// values.
 pub trait InfinityProperty {
@@ -270,7 +664,60 @@ where
     }
 }
 
+/// Properties of a date or a date and time.
+pub trait DateTimeProperties {
+    /// Returns the calendar date as year, month (1-12) and day of the month.
+    fn date_property(&self) -> (i32, u8, u8);
+
+    /// Returns the time of day as hour, minute, second and nanosecond or
+    /// `None` if this value does not have a time of day.
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)>;
+
+    /// Returns the point in time as the number of nanoseconds since the Unix
+    /// epoch.
+    ///
+    /// A date without a time of day is treated as midnight UTC of that date.
+    fn unix_timestamp_nanos_property(&self) -> i128;
+}
+
+impl<T> DateTimeProperties for &T
+where
+    T: DateTimeProperties + ?Sized,
+{
+    fn date_property(&self) -> (i32, u8, u8) {
+        <T as DateTimeProperties>::date_property(self)
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        <T as DateTimeProperties>::time_of_day_property(self)
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        <T as DateTimeProperties>::unix_timestamp_nanos_property(self)
+    }
+}
+
+impl<T> DateTimeProperties for &mut T
+where
+    T: DateTimeProperties + ?Sized,
+{
+    fn date_property(&self) -> (i32, u8, u8) {
+        <T as DateTimeProperties>::date_property(self)
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        <T as DateTimeProperties>::time_of_day_property(self)
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        <T as DateTimeProperties>::unix_timestamp_nanos_property(self)
+    }
+}
+
 /// The properties of a map-like type.
+///
+/// Besides maps, this trait is implemented for `Vec`s, slices and arrays of
+/// key/value-pairs, which are treated like maps with their pairs as entries.
 pub trait MapProperties {
     /// The type of the keys in this map.
     type Key;
@@ -287,3 +734,128 @@ pub trait MapProperties {
     /// Returns an iterator over the key/value-pairs in this map.
     fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)>;
 }
+
+impl<M> MapProperties for &M
+where
+    M: MapProperties + ?Sized,
+{
+    type Key = M::Key;
+    type Value = M::Value;
+
+    fn keys_property(&self) -> impl Iterator<Item = &Self::Key> {
+        <M as MapProperties>::keys_property(self)
+    }
+
+    fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
+        <M as MapProperties>::values_property(self)
+    }
+
+    fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        <M as MapProperties>::entries_property(self)
+    }
+}
+
+impl<M> MapProperties for &mut M
+where
+    M: MapProperties + ?Sized,
+{
+    type Key = M::Key;
+    type Value = M::Value;
+
+    fn keys_property(&self) -> impl Iterator<Item = &Self::Key> {
+        <M as MapProperties>::keys_property(self)
+    }
+
+    fn values_property(&self) -> impl Iterator<Item = &Self::Value> {
+        <M as MapProperties>::values_property(self)
+    }
+
+    fn entries_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        <M as MapProperties>::entries_property(self)
+    }
+}
+
+/// The property of a set-like type to give access to its elements and to look
+/// up whether it contains a given element.
+///
+/// This property is used by the implementation of the
+/// [`AssertSetEquality`](crate::assertions::AssertSetEquality) assertion.
+pub trait SetProperty {
+    /// The type of the elements in this set.
+    type Element;
+
+    /// Returns an iterator over the elements in this set.
+    fn elements_property(&self) -> impl Iterator<Item = &Self::Element>;
+
+    /// Returns whether this set contains the given element.
+    fn contains_property(&self, element: &Self::Element) -> bool;
+}
+
+/// The property of a sequence of key/value-pairs, like a `Vec<(K, V)>`, to
+/// give access to its pairs.
+///
+/// In contrast to a map, a sequence of pairs may contain several pairs with
+/// the same key. This property is used by the implementation of the
+/// [`AssertKeyValuePairs`](crate::assertions::AssertKeyValuePairs),
+/// [`AssertPairKeys`](crate::assertions::AssertPairKeys) and
+/// [`AssertValuesForKey`](crate::assertions::AssertValuesForKey) assertions.
+pub trait KeyValuePairsProperty {
+    /// The type of the keys of the pairs.
+    type Key;
+
+    /// The type of the values of the pairs.
+    type Value;
+
+    /// Returns an iterator over the key/value-pairs in their order.
+    fn pairs_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)>;
+}
+
+impl<P> KeyValuePairsProperty for &P
+where
+    P: KeyValuePairsProperty + ?Sized,
+{
+    type Key = P::Key;
+    type Value = P::Value;
+
+    fn pairs_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        <P as KeyValuePairsProperty>::pairs_property(self)
+    }
+}
+
+impl<P> KeyValuePairsProperty for &mut P
+where
+    P: KeyValuePairsProperty + ?Sized,
+{
+    type Key = P::Key;
+    type Value = P::Value;
+
+    fn pairs_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        <P as KeyValuePairsProperty>::pairs_property(self)
+    }
+}
+
+/// The property of a wrapper type, like a smart pointer or a cell, to give
+/// access to the value it wraps.
+///
+/// This property is used by the implementation of the
+/// [`AssertInnerValue`](crate::assertions::AssertInnerValue) assertion.
+pub trait InnerValueProperty {
+    /// The type of the wrapped value.
+    type Inner;
+
+    /// Consumes the wrapper and returns the wrapped value.
+    fn into_inner_property(self) -> Self::Inner;
+}
+
+/// The property of a tuple to give access to its element at index `N`.
+///
+/// This property is implemented for tuples with up to 12 elements and for
+/// references to such tuples. It is used by the implementation of the
+/// [`AssertTupleElements`](crate::assertions::AssertTupleElements) assertion.
+pub trait TupleElementProperty<const N: usize> {
+    /// The type of the element at index `N`.
+    type Element;
+
+    /// Consumes the tuple and returns the element at index `N`.
+    fn into_element_property(self) -> Self::Element;
+}
@@ -0,0 +1,75 @@
+//! Integration of `asserting` with the `proptest` crate.
+//!
+//! Failing assertions are reported to the test runner as a failed test case
+//! instead of panicking, which lets `proptest` shrink the input as with its own
+//! `prop_assert!` macros.
+//!
+//! [`AssertFailureList`] and [`AssertFailure`] implement [`std::error::Error`],
+//! so they are converted into a `proptest::test_runner::TestCaseError` by the
+//! `From` implementation `proptest` provides for all error types. The failure
+//! messages become the reason of the failed test case.
+//!
+//! [`AssertFailure`]: crate::spec::AssertFailure
+//! [`AssertFailureList`]: crate::spec::AssertFailureList
+
+/// Verifies assertions on a subject inside a property test and returns early
+/// with a `TestCaseError` if any of the assertions fails.
+///
+/// The first argument is the subject or expression to be asserted. It is
+/// followed by a comma and the chain of assertion methods to be called on the
+/// subject, starting with a dot. All assertions of the chain are executed in
+/// the [`CollectFailures`] mode. If any assertion fails, the macro returns
+/// from the enclosing function with an `Err` containing all failure messages.
+///
+/// Like `prop_assert!` and its siblings, this macro can be used inside the
+/// `proptest!` macro or in any function returning a `Result` with an error
+/// type that can be converted from a `TestCaseError`.
+///
+/// Requires the crate feature `proptest`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "proptest"))]
+/// # fn main() {}
+/// # #[cfg(feature = "proptest")]
+/// # fn main() {
+/// use asserting::prelude::*;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     # #![proptest_config(ProptestConfig::with_cases(16))]
+///     fn reversing_twice_restores_the_input(input in prop::collection::vec(any::<u8>(), 0..32)) {
+///         let mut reversed = input.clone();
+///         reversed.reverse();
+///
+///         prop_verify_that!(reversed.len(), .is_equal_to(input.len()));
+///
+///         reversed.reverse();
+///         prop_verify_that!(reversed, .is_equal_to(input));
+///     }
+/// }
+/// # reversing_twice_restores_the_input();
+/// # }
+/// ```
+///
+/// [`CollectFailures`]: crate::spec::CollectFailures
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+macro_rules! prop_verify_that {
+    ($subject:expr, $($assertions:tt)+) => {
+        $crate::prelude::verify_that($subject)
+            .named(stringify!($subject))
+            .located_at($crate::prelude::Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            })
+            $($assertions)+
+            .into_result()
+            .map_err($crate::__private::TestCaseError::from)?
+    };
+}
+
+#[cfg(test)]
+mod tests;
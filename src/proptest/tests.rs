@@ -0,0 +1,106 @@
+use crate::prelude::*;
+use crate::std::string::{String, ToString};
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+
+fn failure_reason(error: &TestCaseError) -> Option<String> {
+    match error {
+        TestCaseError::Fail(reason) => Some(reason.message().to_string()),
+        TestCaseError::Reject(_) => None,
+    }
+}
+
+fn verify_is_even(value: u32) -> Result<(), TestCaseError> {
+    prop_verify_that!(value % 2, .is_equal_to(0));
+    Ok(())
+}
+
+#[test]
+fn assert_failure_list_is_converted_into_failed_test_case() {
+    let failures = verify_that(41)
+        .named("answer")
+        .is_equal_to(42)
+        .into_failure_list();
+
+    let error = TestCaseError::from(failures);
+
+    assert_that(failure_reason(&error))
+        .has_value("expected answer to be equal to 42\n   but was: 41\n  expected: 42\n");
+}
+
+#[test]
+fn assert_failure_is_converted_into_failed_test_case() {
+    let failure = verify_that(41)
+        .named("answer")
+        .is_equal_to(42)
+        .failures()
+        .remove(0);
+
+    let error = TestCaseError::from(failure);
+
+    assert_that(failure_reason(&error))
+        .has_value("expected answer to be equal to 42\n   but was: 41\n  expected: 42\n");
+}
+
+#[test]
+fn prop_verify_that_returns_ok_if_all_assertions_pass() {
+    let result = verify_is_even(42);
+
+    assert_that(result).is_ok();
+}
+
+#[test]
+fn prop_verify_that_returns_early_with_all_failures() {
+    fn verify_answer(answer: i32) -> Result<(), TestCaseError> {
+        prop_verify_that!(answer, .is_positive().is_equal_to(42));
+        Err(TestCaseError::reject("unreachable"))
+    }
+
+    let result = verify_answer(-41);
+
+    assert_that(result.as_ref().err().and_then(failure_reason)).has_value(
+        "expected answer to be positive\n   but was: -41\n  expected: > 0\n\
+         \n\
+         expected answer to be equal to 42\n   but was: -41\n  expected: 42\n",
+    );
+}
+
+#[test]
+fn failing_property_is_shrunk_to_minimal_input() {
+    let mut runner = TestRunner::new(Config {
+        failure_persistence: None,
+        ..Config::default()
+    });
+
+    let result = runner.run(&(0..1000_u32), |value| {
+        prop_verify_that!(value, .is_less_than(500));
+        Ok(())
+    });
+
+    let Err(TestError::Fail(reason, minimal_input)) = result else {
+        panic!("expected the property to fail, but was {result:?}");
+    };
+    assert_that(minimal_input).is_equal_to(500);
+    assert_that(reason.message().to_string())
+        .starts_with("expected value to be less than 500\n   but was: 500\n");
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn reversing_a_vec_twice_restores_it(input in prop::collection::vec(any::<u8>(), 0..16)) {
+        let mut reversed = input.clone();
+        reversed.reverse();
+        prop_verify_that!(reversed.len(), .is_equal_to(input.len()));
+
+        reversed.reverse();
+        prop_verify_that!(reversed, .is_equal_to(input));
+    }
+
+    #[test]
+    fn concatenated_strings_contain_both_parts(left in "[a-z]{0,8}", right in "[a-z]{0,8}") {
+        let concatenated: String = [left.as_str(), right.as_str()].concat();
+        prop_verify_that!(&concatenated, .starts_with(left.as_str()).ends_with(right.as_str()));
+    }
+}
@@ -1,16 +1,20 @@
 //! Implementation of assertions for `Range` and `RangeInclusive` values.
 
-use crate::assertions::AssertInRange;
+use crate::assertions::{AssertInRange, AssertRange, AssertWithin};
 use crate::colored::{mark_missing, mark_missing_string, mark_unexpected};
-use crate::expectations::{IsInRange, is_in_range, not};
-use crate::properties::IsEmptyProperty;
+use crate::expectations::{
+    IsInRange, IsInRangeExclusive, IsSubRangeOf, IsWithin, IsWithinPercent, RangeContainsValue,
+    RangeOverlapsWith, is_in_range, is_in_range_exclusive, is_sub_range_of, is_within,
+    is_within_percent, not, range_contains_value, range_overlaps_with,
+};
+use crate::properties::{DistanceProperty, IsEmptyProperty, LengthProperty};
 use crate::spec::{
     DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
 };
 use crate::std::fmt::Debug;
 use crate::std::format;
 use crate::std::ops::{Bound, Range, RangeBounds, RangeInclusive};
-use crate::std::string::String;
+use crate::std::string::{String, ToString};
 
 impl<T> IsEmptyProperty for Range<T>
 where
@@ -30,6 +34,45 @@ where
     }
 }
 
+macro_rules! impl_range_length_property {
+    ($type:ty) => {
+        impl LengthProperty for Range<$type> {
+            fn length_property(&self) -> usize {
+                if self.start < self.end {
+                    usize::try_from(self.end.abs_diff(self.start)).unwrap_or(usize::MAX)
+                } else {
+                    0
+                }
+            }
+        }
+
+        impl LengthProperty for RangeInclusive<$type> {
+            fn length_property(&self) -> usize {
+                if self.start() <= self.end() {
+                    usize::try_from(self.end().abs_diff(*self.start()))
+                        .map_or(usize::MAX, |length| length.saturating_add(1))
+                } else {
+                    0
+                }
+            }
+        }
+    };
+}
+
+impl_range_length_property!(i8);
+impl_range_length_property!(i16);
+impl_range_length_property!(i32);
+impl_range_length_property!(i64);
+impl_range_length_property!(i128);
+impl_range_length_property!(isize);
+
+impl_range_length_property!(u8);
+impl_range_length_property!(u16);
+impl_range_length_property!(u32);
+impl_range_length_property!(u64);
+impl_range_length_property!(u128);
+impl_range_length_property!(usize);
+
 impl<S, E, R> AssertInRange<E> for Spec<'_, S, R>
 where
     S: PartialOrd<E> + Debug,
@@ -57,6 +100,8 @@ where
     E: PartialOrd<S> + Debug,
     R: RangeBounds<E> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_in_range";
+
     fn test(&mut self, subject: &S) -> bool {
         self.expected_range.contains(subject)
     }
@@ -136,5 +181,339 @@ where
 
 impl<R, E> Invertible for IsInRange<R, E> {}
 
+impl<S, R> AssertWithin<S> for Spec<'_, S, R>
+where
+    S: DistanceProperty + PartialOrd + Debug,
+    R: FailingStrategy,
+{
+    fn is_in_range_exclusive(self, start: S, end: S) -> Self {
+        self.expecting(is_in_range_exclusive(start, end))
+    }
+
+    fn is_within(self, offset: S, center: S) -> Self {
+        self.expecting(is_within(offset, center))
+    }
+
+    fn is_within_percent(self, percent: f64, expected: S) -> Self {
+        self.expecting(is_within_percent(percent, expected))
+    }
+}
+
+impl<S> Expectation<S> for IsInRangeExclusive<S>
+where
+    S: DistanceProperty + PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_in_range_exclusive";
+
+    fn test(&mut self, subject: &S) -> bool {
+        &self.start < subject && subject < &self.end
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        bounds_failure_message(
+            &format!(
+                "expected {expression} to be within range of {:?} and {:?} (both exclusive)",
+                self.start, self.end
+            ),
+            actual,
+            &self.start,
+            &self.end,
+            true,
+            format,
+        )
+    }
+}
+
+impl<S> Expectation<S> for IsWithin<S>
+where
+    S: DistanceProperty + PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_within";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let (lower, upper) = self.center.bounds_within_property(&self.offset);
+        &lower <= subject && subject <= &upper
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let (lower, upper) = self.center.bounds_within_property(&self.offset);
+        bounds_failure_message(
+            &format!(
+                "expected {expression} to be within {:?} of {:?}",
+                self.offset, self.center
+            ),
+            actual,
+            &lower,
+            &upper,
+            false,
+            format,
+        )
+    }
+}
+
+impl<S> Expectation<S> for IsWithinPercent<S>
+where
+    S: DistanceProperty + PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_within_percent";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let offset = self.expected.percentage_property(self.percent);
+        let (lower, upper) = self.expected.bounds_within_property(&offset);
+        &lower <= subject && subject <= &upper
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let offset = self.expected.percentage_property(self.percent);
+        let (lower, upper) = self.expected.bounds_within_property(&offset);
+        bounds_failure_message(
+            &format!(
+                "expected {expression} to be within {}% of {:?}",
+                self.percent, self.expected
+            ),
+            actual,
+            &lower,
+            &upper,
+            false,
+            format,
+        )
+    }
+}
+
+/// Forms the failure message for a value that is not within the given bounds,
+/// including the delta by which the actual value misses the bounds.
+fn bounds_failure_message<S>(
+    headline: &str,
+    actual: &S,
+    lower: &S,
+    upper: &S,
+    exclusive: bool,
+    format: &DiffFormat,
+) -> String
+where
+    S: DistanceProperty + PartialOrd + Debug,
+{
+    let operator = if exclusive { "<" } else { "<=" };
+    let below = actual < lower || (exclusive && actual == lower);
+    let above = actual > upper || (exclusive && actual == upper);
+    let marked_lower = if below {
+        mark_missing(lower, format)
+    } else {
+        format!("{lower:?}")
+    };
+    let marked_upper = if above {
+        mark_missing(upper, format)
+    } else {
+        format!("{upper:?}")
+    };
+    let delta = if actual == lower && below {
+        "\n     delta: 0, equal to the excluded lower bound".to_string()
+    } else if below {
+        format!(
+            "\n     delta: {:?} below the lower bound",
+            actual.distance_property(lower)
+        )
+    } else if actual == upper && above {
+        "\n     delta: 0, equal to the excluded upper bound".to_string()
+    } else if above {
+        format!(
+            "\n     delta: {:?} above the upper bound",
+            actual.distance_property(upper)
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "{headline}\n   but was: {}\n  expected: {marked_lower} {operator} x {operator} {marked_upper}{delta}",
+        mark_unexpected(actual, format),
+    )
+}
+
+impl<S, E, R> AssertRange<E> for Spec<'_, S, R>
+where
+    S: RangeBounds<E> + Debug,
+    E: PartialOrd + Debug,
+    R: FailingStrategy,
+{
+    fn contains_value(self, expected: E) -> Self {
+        self.expecting(range_contains_value(expected))
+    }
+
+    fn overlaps_with<U>(self, other: U) -> Self
+    where
+        U: RangeBounds<E> + Debug,
+    {
+        self.expecting(range_overlaps_with(other))
+    }
+
+    fn is_sub_range_of<U>(self, other: U) -> Self
+    where
+        U: RangeBounds<E> + Debug,
+    {
+        self.expecting(is_sub_range_of(other))
+    }
+}
+
+/// Returns whether there is at least one value between the given lower and
+/// upper bound.
+///
+/// Values are considered continuous, so a range with two different excluded
+/// bounds is never empty.
+fn is_lower_below_upper<E>(lower: Bound<&E>, upper: Bound<&E>) -> bool
+where
+    E: PartialOrd,
+{
+    match (lower, upper) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => true,
+        (Bound::Included(lower), Bound::Included(upper)) => lower <= upper,
+        (
+            Bound::Included(lower) | Bound::Excluded(lower),
+            Bound::Included(upper) | Bound::Excluded(upper),
+        ) => lower < upper,
+    }
+}
+
+/// Returns whether the `outer` lower bound is at or below the `inner` lower
+/// bound.
+fn is_lower_bound_covering<E>(outer: Bound<&E>, inner: Bound<&E>) -> bool
+where
+    E: PartialOrd,
+{
+    match (outer, inner) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Excluded(outer), Bound::Included(inner)) => outer < inner,
+        (
+            Bound::Included(outer) | Bound::Excluded(outer),
+            Bound::Included(inner) | Bound::Excluded(inner),
+        ) => outer <= inner,
+    }
+}
+
+/// Returns whether the `outer` upper bound is at or above the `inner` upper
+/// bound.
+fn is_upper_bound_covering<E>(outer: Bound<&E>, inner: Bound<&E>) -> bool
+where
+    E: PartialOrd,
+{
+    match (outer, inner) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Excluded(outer), Bound::Included(inner)) => outer > inner,
+        (
+            Bound::Included(outer) | Bound::Excluded(outer),
+            Bound::Included(inner) | Bound::Excluded(inner),
+        ) => outer >= inner,
+    }
+}
+
+impl<S, E> Expectation<S> for RangeContainsValue<E>
+where
+    S: RangeBounds<E> + Debug,
+    E: PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::contains_value";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.contains(&self.expected)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected = &self.expected;
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing(expected, format);
+        format!(
+            "expected {expression} to contain {expected:?}\n   but was: {marked_actual}\n  expected: a range containing {marked_expected}"
+        )
+    }
+}
+
+impl<S, U, E> Expectation<S> for RangeOverlapsWith<U, E>
+where
+    S: RangeBounds<E> + Debug,
+    U: RangeBounds<E> + Debug,
+    E: PartialOrd,
+{
+    const ASSERTION_ID: &'static str = "asserting::overlaps_with";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let other = &self.other;
+        is_lower_below_upper(subject.start_bound(), subject.end_bound())
+            && is_lower_below_upper(other.start_bound(), other.end_bound())
+            && is_lower_below_upper(subject.start_bound(), other.end_bound())
+            && is_lower_below_upper(other.start_bound(), subject.end_bound())
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let other = &self.other;
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing(other, format);
+        format!(
+            "expected {expression} to overlap with {other:?}\n   but was: {marked_actual}\n  expected: a range overlapping {marked_expected}"
+        )
+    }
+}
+
+impl<S, U, E> Expectation<S> for IsSubRangeOf<U, E>
+where
+    S: RangeBounds<E> + Debug,
+    U: RangeBounds<E> + Debug,
+    E: PartialOrd,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_sub_range_of";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let other = &self.other;
+        !is_lower_below_upper(subject.start_bound(), subject.end_bound())
+            || (is_lower_bound_covering(other.start_bound(), subject.start_bound())
+                && is_upper_bound_covering(other.end_bound(), subject.end_bound()))
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let other = &self.other;
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing(other, format);
+        format!(
+            "expected {expression} to be a sub-range of {other:?}\n   but was: {marked_actual}\n  expected: a range within {marked_expected}"
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -391,6 +391,320 @@ fn inclusive_range_is_not_empty() {
     assert_that(range).is_not_empty();
 }
 
+//
+// Is in exclusive range and is within a tolerance
+//
+
+#[test]
+fn i32_is_in_range_exclusive() {
+    assert_that(42).is_in_range_exclusive(41, 43);
+}
+
+#[test]
+fn verify_i32_is_in_range_exclusive_fails_on_excluded_end() {
+    let failures = verify_that(43)
+        .named("my_thing")
+        .is_in_range_exclusive(41, 43)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be within range of 41 and 43 (both exclusive)
+   but was: 43
+  expected: 41 < x < 43
+     delta: 0, equal to the excluded upper bound
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_i32_is_in_range_exclusive_fails_below_start() {
+    let failures = verify_that(37)
+        .named("my_thing")
+        .is_in_range_exclusive(41, 43)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be within range of 41 and 43 (both exclusive)
+   but was: 37
+  expected: 41 < x < 43
+     delta: 4 below the lower bound
+"
+        ]
+    );
+}
+
+#[test]
+fn f64_is_in_range_exclusive() {
+    assert_that(0.5).is_in_range_exclusive(0.0, 1.0);
+}
+
+#[test]
+fn i32_is_within_offset_of_center() {
+    assert_that(95).is_within(5, 100);
+    assert_that(100).is_within(5, 100);
+    assert_that(105).is_within(5, 100);
+}
+
+#[test]
+fn verify_i32_is_within_offset_of_center_fails() {
+    let failures = verify_that(107)
+        .named("my_thing")
+        .is_within(5, 100)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to be within 5 of 100
+   but was: 107
+  expected: 95 <= x <= 105
+     delta: 2 above the upper bound
+"]
+    );
+}
+
+#[test]
+fn u8_is_within_offset_of_center_saturates_bounds() {
+    assert_that(0_u8).is_within(5, 3);
+    assert_that(255_u8).is_within(5, 253);
+}
+
+#[test]
+fn verify_i8_is_within_offset_of_center_fails_with_delta_beyond_the_type() {
+    let failures = verify_that(i8::MIN)
+        .named("my_thing")
+        .is_within(10, 100)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to be within 10 of 100
+   but was: -128
+  expected: 90 <= x <= 110
+     delta: 218 below the lower bound
+"]
+    );
+}
+
+#[test]
+fn is_within_ignores_the_sign_of_the_offset() {
+    assert_that(95).is_within(-5, 100);
+    assert_that(105).is_within(-5, 100);
+    assert_that(0.95).is_within(-0.1, 1.0);
+}
+
+#[test]
+fn verify_i32_is_within_negative_offset_of_center_fails() {
+    let failures = verify_that(107)
+        .named("my_thing")
+        .is_within(-5, 100)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to be within -5 of 100
+   but was: 107
+  expected: 95 <= x <= 105
+     delta: 2 above the upper bound
+"]
+    );
+}
+
+#[test]
+fn verify_f64_is_within_offset_of_center_fails() {
+    let failures = verify_that(8.5)
+        .named("my_thing")
+        .is_within(0.5, 10.0)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to be within 0.5 of 10.0
+   but was: 8.5
+  expected: 9.5 <= x <= 10.5
+     delta: 1.0 below the lower bound
+"]
+    );
+}
+
+#[test]
+fn i32_is_within_percent_of_expected() {
+    assert_that(190).is_within_percent(5.0, 200);
+    assert_that(210).is_within_percent(5.0, 200);
+    assert_that(-210).is_within_percent(5.0, -200);
+}
+
+#[test]
+fn verify_i32_is_within_percent_of_expected_fails() {
+    let failures = verify_that(185)
+        .named("my_thing")
+        .is_within_percent(5.0, 200)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to be within 5% of 200
+   but was: 185
+  expected: 190 <= x <= 210
+     delta: 5 below the lower bound
+"]
+    );
+}
+
+#[test]
+fn f64_is_within_percent_of_expected() {
+    assert_that(1.98).is_within_percent(1.0, 2.0);
+}
+
+//
+// Assertions on ranges as the subject
+//
+
+#[test]
+fn range_contains_value() {
+    assert_that(1..5).contains_value(1);
+    assert_that(1..5).contains_value(4);
+    assert_that(1..=5).contains_value(5);
+    assert_that(0.5..).contains_value(99.9);
+    assert_that(..=10).contains_value(-3);
+}
+
+#[test]
+fn verify_range_contains_value_fails() {
+    let failures = verify_that(1..5)
+        .named("my_range")
+        .contains_value(5)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_range to contain 5
+   but was: 1..5
+  expected: a range containing 5
+"]
+    );
+}
+
+#[test]
+fn range_has_length() {
+    assert_that(20..30).has_length(10);
+    assert_that(0_u64..=u64::MAX).has_length(usize::MAX);
+    assert_that(-5_i8..=5).has_length(11);
+    assert_that(5..5).has_length(0);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn reversed_ranges_have_length_zero() {
+    assert_that(5..3).has_length(0);
+    assert_that(5..=3).has_length(0);
+}
+
+#[test]
+fn verify_range_has_length_fails() {
+    let failures = verify_that(20..30)
+        .named("my_range")
+        .has_length(11)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_range to have a length of 11
+   but was: 10
+  expected: 11
+"]
+    );
+}
+
+#[test]
+fn range_overlaps_with_other_range() {
+    assert_that(1..5).overlaps_with(4..8);
+    assert_that(4..8).overlaps_with(1..5);
+    assert_that(1..=5).overlaps_with(5..=8);
+    assert_that(1..10).overlaps_with(3..4);
+    assert_that('a'..='k').overlaps_with('f'..);
+    assert_that(..0).overlaps_with(..);
+}
+
+#[test]
+fn range_does_not_overlap_with_adjacent_range() {
+    assert_that(verify_that(1..5).overlaps_with(5..8).display_failures()).has_length(1);
+    assert_that(verify_that(5..8).overlaps_with(1..5).display_failures()).has_length(1);
+    assert_that(verify_that(1..=5).overlaps_with(6..=8).display_failures()).has_length(1);
+}
+
+#[test]
+fn empty_range_does_not_overlap_with_any_range() {
+    assert_that(verify_that(3..3).overlaps_with(1..5).display_failures()).has_length(1);
+    assert_that(verify_that(1..5).overlaps_with(3..3).display_failures()).has_length(1);
+}
+
+#[test]
+fn verify_range_overlaps_with_other_range_fails() {
+    let failures = verify_that(1..5)
+        .named("my_range")
+        .overlaps_with(5..8)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_range to overlap with 5..8
+   but was: 1..5
+  expected: a range overlapping 5..8
+"]
+    );
+}
+
+#[test]
+fn range_is_sub_range_of_other_range() {
+    assert_that(2..5).is_sub_range_of(0..10);
+    assert_that(2..5).is_sub_range_of(2..5);
+    assert_that(2..=4).is_sub_range_of(2..5);
+    assert_that(2..=5).is_sub_range_of(..);
+    assert_that(2..).is_sub_range_of(1..);
+}
+
+#[test]
+fn empty_range_is_sub_range_of_any_range() {
+    assert_that(20..20).is_sub_range_of(0..10);
+}
+
+#[test]
+fn verify_range_is_sub_range_of_other_range_fails() {
+    let failures = verify_that(2..=5)
+        .named("my_range")
+        .is_sub_range_of(2..5)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_range to be a sub-range of 2..5
+   but was: 2..=5
+  expected: a range within 2..5
+"]
+    );
+}
+
+#[test]
+fn verify_unbounded_range_is_sub_range_of_bounded_range_fails() {
+    let failures = verify_that(2..)
+        .named("my_range")
+        .is_sub_range_of(0..10)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_range to be a sub-range of 0..10
+   but was: 2..
+  expected: a range within 0..10
+"]
+    );
+}
+
 #[cfg(feature = "colored")]
 mod colored {
     use crate::prelude::*;
@@ -502,4 +816,21 @@ mod colored {
                 "]
         );
     }
+
+    #[test]
+    fn highlight_diffs_i32_is_within_offset_of_center() {
+        let failures = verify_that(107)
+            .with_diff_format(DIFF_FORMAT_RED_GREEN)
+            .is_within(5, 100)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected subject to be within 5 of 100\n   \
+                     but was: \u{1b}[31m107\u{1b}[0m\n  \
+                    expected: 95 <= x <= \u{1b}[32m105\u{1b}[0m\n     \
+                       delta: 2 above the upper bound\n\
+                "]
+        );
+    }
 }
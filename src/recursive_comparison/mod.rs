@@ -345,7 +345,10 @@ pub struct RecursiveComparison<'a, S, R> {
     ignore_not_expected_fields: bool,
 }
 
-impl<S, R> GetFailures for RecursiveComparison<'_, S, R> {
+impl<S, R> GetFailures for RecursiveComparison<'_, S, R>
+where
+    R: FailingStrategy,
+{
     fn has_failures(&self) -> bool {
         self.spec.has_failures()
     }
@@ -370,6 +373,10 @@ where
     fn do_fail_with_message(&mut self, message: impl Into<String>) {
         self.spec.do_fail_with_message(message);
     }
+
+    fn do_pass(&mut self) {
+        self.spec.do_pass();
+    }
 }
 
 impl<S> SoftPanic for RecursiveComparison<'_, S, CollectFailures> {
@@ -630,6 +637,9 @@ where
     R: FailingStrategy,
 {
     fn is_equal_to(mut self, expected: E) -> Self {
+        if self.spec.is_skipped() {
+            return self;
+        }
         let expression = self.spec.expression();
         let actual = to_recursive_value(self.spec.subject())
             .unwrap_or_else(|err| panic!("failed to serialize the subject, reason: {err}"));
@@ -647,11 +657,16 @@ where
   expected: {expected:?}
 {compare_details}"
             ));
+        } else {
+            self.do_pass();
         }
         self
     }
 
     fn is_not_equal_to(mut self, expected: E) -> Self {
+        if self.spec.is_skipped() {
+            return self;
+        }
         let expression = self.spec.expression();
         let actual = to_recursive_value(self.spec.subject())
             .unwrap_or_else(|err| panic!("failed to serialize the subject, reason: {err}"));
@@ -669,6 +684,8 @@ where
   expected: {expected:?}
 {compare_details}"
             ));
+        } else {
+            self.do_pass();
         }
         self
     }
@@ -680,6 +697,9 @@ where
     R: FailingStrategy,
 {
     fn is_equivalent_to(mut self, expected: Value) -> Self {
+        if self.spec.is_skipped() {
+            return self;
+        }
         let expression = self.spec.expression();
         let actual = to_recursive_value(self.spec.subject())
             .unwrap_or_else(|err| panic!("failed to serialize the subject, reason: {err}"));
@@ -695,11 +715,16 @@ where
   expected: {expected:?}
 {compare_details}"
             ));
+        } else {
+            self.do_pass();
         }
         self
     }
 
     fn is_not_equivalent_to(mut self, expected: Value) -> Self {
+        if self.spec.is_skipped() {
+            return self;
+        }
         let expression = self.spec.expression();
         let actual = to_recursive_value(self.spec.subject())
             .unwrap_or_else(|err| panic!("failed to serialize the subject, reason: {err}"));
@@ -716,6 +741,8 @@ where
   expected: {expected:?}
 {compare_details}"
             ));
+        } else {
+            self.do_pass();
         }
         self
     }
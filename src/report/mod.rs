@@ -0,0 +1,181 @@
+//! Export of assertion failures in machine-readable formats.
+//!
+//! The failures collected by a [`Spec`](crate::spec::Spec) with the
+//! [`CollectFailures`](crate::spec::CollectFailures) strategy can be exported
+//! as `JUnit` XML or as JSON, so that CI systems can ingest them together with
+//! the location of the failed assertions.
+//!
+//! Highlighted differences are removed from the messages in the exported
+//! reports.
+//!
+//! # Examples
+//!
+//! ```
+//! use asserting::prelude::*;
+//! use asserting::report::{to_json, to_junit_xml};
+//!
+//! let failures = verify_that!(5 * 9)
+//!     .named("answer")
+//!     .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+//!     .is_equal_to(42)
+//!     .failures();
+//!
+//! let xml = to_junit_xml("answers", &failures);
+//! assert_that!(xml).contains(r#"<testsuite name="answers" tests="1" failures="1">"#);
+//!
+//! let json = to_json(&failures);
+//! assert_that!(json).starts_with(r#"[{"description":null,"message":"expected answer to be equal to 42"#);
+//! ```
+
+use crate::spec::AssertFailure;
+use crate::std::fmt::Write;
+use crate::std::string::String;
+
+/// Serializes the given failures to a `JUnit` XML report.
+///
+/// All failures are reported as failed test cases of one test suite with the
+/// given name. A test case is named by the description of the assertion, if
+/// one is set, or by the first line of the failure message otherwise. The
+/// location of the assertion is reported as `file` and `line` attributes of
+/// the test case.
+#[must_use]
+pub fn to_junit_xml(test_suite: &str, failures: &[AssertFailure]) -> String {
+    let mut xml = String::new();
+    let count = failures.len();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    _ = writeln!(
+        xml,
+        r#"<testsuite name="{}" tests="{count}" failures="{count}">"#,
+        escape_xml(test_suite)
+    );
+    for failure in failures {
+        let message = strip_highlights(failure.message());
+        let first_line = message.lines().next().unwrap_or_default();
+        let name = failure
+            .description()
+            .map_or(first_line, |description| description.as_str());
+        _ = write!(xml, r#"  <testcase name="{}""#, escape_xml(name));
+        if let Some(location) = failure.location() {
+            _ = write!(
+                xml,
+                r#" file="{}" line="{}""#,
+                escape_xml(&location.file),
+                location.line
+            );
+        }
+        xml.push_str(">\n");
+        _ = writeln!(
+            xml,
+            r#"    <failure message="{}">{}</failure>"#,
+            escape_xml(first_line),
+            escape_xml(&message)
+        );
+        xml.push_str("  </testcase>\n");
+    }
+    xml.push_str("</testsuite>\n");
+    xml
+}
+
+/// Serializes the given failures to a JSON array.
+///
+/// Each failure is serialized as an object with the fields `description`,
+/// `message` and `location`. The `location` is an object with the fields
+/// `file`, `line` and `column`. Fields without a value are serialized as
+/// `null`.
+#[must_use]
+pub fn to_json(failures: &[AssertFailure]) -> String {
+    let mut json = String::from("[");
+    for (index, failure) in failures.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        json.push_str(r#"{"description":"#);
+        match failure.description() {
+            Some(description) => push_json_string(&mut json, description),
+            None => json.push_str("null"),
+        }
+        json.push_str(r#","message":"#);
+        push_json_string(&mut json, &strip_highlights(failure.message()));
+        json.push_str(r#","location":"#);
+        match failure.location() {
+            Some(location) => {
+                json.push_str(r#"{"file":"#);
+                push_json_string(&mut json, &location.file);
+                _ = write!(
+                    json,
+                    r#","line":{},"column":{}}}"#,
+                    location.line, location.column
+                );
+            },
+            None => json.push_str("null"),
+        }
+        json.push('}');
+    }
+    json.push(']');
+    json
+}
+
+/// Removes the ANSI escape sequences that highlight differences from the
+/// given message.
+fn strip_highlights(message: &str) -> String {
+    let mut stripped = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(char) = chars.next() {
+        if char == '\u{1b}' {
+            for code in chars.by_ref() {
+                if code.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(char);
+        }
+    }
+    stripped
+}
+
+/// Escapes the characters that have a special meaning in XML.
+///
+/// Characters that are not allowed in XML 1.0, like control characters other
+/// than tab, line feed and carriage return, are replaced with the replacement
+/// character `U+FFFD`.
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(char),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => {
+                escaped.push(char::REPLACEMENT_CHARACTER);
+            },
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+/// Appends the given value as a quoted and escaped JSON string.
+fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            control if control.is_control() => {
+                _ = write!(json, "\\u{:04x}", u32::from(control));
+            },
+            _ => json.push(char),
+        }
+    }
+    json.push('"');
+}
+
+#[cfg(test)]
+mod tests;
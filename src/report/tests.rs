@@ -0,0 +1,140 @@
+use super::*;
+use crate::spec::OwnedLocation;
+use crate::std::string::ToString;
+
+fn failure(
+    description: Option<&str>,
+    message: &str,
+    location: Option<OwnedLocation>,
+) -> AssertFailure {
+    AssertFailure {
+        description: description.map(ToString::to_string),
+        message: message.to_string(),
+        expression: None,
+        location,
+        highlights: None,
+    }
+}
+
+#[test]
+fn junit_xml_report_of_no_failures() {
+    let xml = to_junit_xml("my_suite", &[]);
+
+    assert_eq!(
+        xml,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuite name="my_suite" tests="0" failures="0">
+</testsuite>
+"#
+    );
+}
+
+#[test]
+fn junit_xml_report_of_failures_with_and_without_description_and_location() {
+    let failures = [
+        failure(
+            None,
+            "expected answer to be equal to 42\n   but was: 45\n  expected: 42",
+            Some(OwnedLocation::new("src/answer_test.rs", 12, 5)),
+        ),
+        failure(Some("names & <tags>"), "expected \"foo\" to be empty", None),
+    ];
+
+    let xml = to_junit_xml("my_suite", &failures);
+
+    assert_eq!(
+        xml,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuite name="my_suite" tests="2" failures="2">
+  <testcase name="expected answer to be equal to 42" file="src/answer_test.rs" line="12">
+    <failure message="expected answer to be equal to 42">expected answer to be equal to 42
+   but was: 45
+  expected: 42</failure>
+  </testcase>
+  <testcase name="names &amp; &lt;tags&gt;">
+    <failure message="expected &quot;foo&quot; to be empty">expected &quot;foo&quot; to be empty</failure>
+  </testcase>
+</testsuite>
+"#
+    );
+}
+
+#[test]
+fn json_report_of_no_failures() {
+    let json = to_json(&[]);
+
+    assert_eq!(json, "[]");
+}
+
+#[test]
+fn json_report_of_failures_with_and_without_description_and_location() {
+    let failures = [
+        failure(
+            None,
+            "expected answer to be equal to 42\n   but was: 45\n  expected: 42",
+            Some(OwnedLocation::new(r"src\answer_test.rs", 12, 5)),
+        ),
+        failure(
+            Some("names\tand \"quotes\""),
+            "expected \"foo\" to be empty",
+            None,
+        ),
+    ];
+
+    let json = to_json(&failures);
+
+    assert_eq!(
+        json,
+        r#"[{"description":null,"message":"expected answer to be equal to 42\n   but was: 45\n  expected: 42","location":{"file":"src\\answer_test.rs","line":12,"column":5}},{"description":"names\tand \"quotes\"","message":"expected \"foo\" to be empty","location":null}]"#
+    );
+}
+
+#[test]
+fn highlighted_differences_are_removed_from_messages() {
+    let failures = [failure(
+        None,
+        "expected answer to be equal to 42\n   but was: \u{1b}[31m45\u{1b}[0m\n  expected: \u{1b}[32m42\u{1b}[0m",
+        None,
+    )];
+
+    let json = to_json(&failures);
+
+    assert_eq!(
+        json,
+        r#"[{"description":null,"message":"expected answer to be equal to 42\n   but was: 45\n  expected: 42","location":null}]"#
+    );
+}
+
+#[test]
+fn characters_not_allowed_in_xml_are_replaced_in_junit_xml_report() {
+    let failures = [failure(
+        Some("bell \u{7} rings"),
+        "tab\tline\r\nnull \u{0} and \u{ffff}",
+        None,
+    )];
+
+    let xml = to_junit_xml("my_suite", &failures);
+
+    assert_eq!(
+        xml,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<testsuite name=\"my_suite\" tests=\"1\" failures=\"1\">
+  <testcase name=\"bell \u{fffd} rings\">
+    <failure message=\"tab\tline\">tab\tline\r\nnull \u{fffd} and \u{fffd}</failure>
+  </testcase>
+</testsuite>
+"
+    );
+}
+
+#[test]
+fn other_control_characters_are_escaped_in_json() {
+    let failures = [failure(None, "bell \u{7} rings", None)];
+
+    let json = to_json(&failures);
+
+    assert_eq!(
+        json,
+        r#"[{"description":null,"message":"bell \u0007 rings","location":null}]"#
+    );
+}
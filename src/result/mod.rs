@@ -1,19 +1,28 @@
 //! Implementation of assertions for `Result` values.
 
 use crate::assertions::{
-    AssertHasError, AssertHasErrorMessage, AssertHasValue, AssertResult, AssertResultValue,
+    AssertHasError, AssertHasErrorMatching, AssertHasErrorMessage, AssertHasErrorOfType,
+    AssertHasValue, AssertNestedResultValue, AssertResult, AssertResultElements, AssertResultValue,
+};
+use crate::colored::{
+    mark_missing, mark_missing_string, mark_selected_items_in_collection, mark_unexpected,
 };
-use crate::colored::{mark_missing, mark_unexpected};
 use crate::expectations::{
-    HasError, HasValue, IsErr, IsOk, has_error, has_value, is_equal_to, is_err, is_ok,
+    AllErr, AllOk, AnyErr, HasError, HasErrorMatching, HasErrorOfType, HasErrorSatisfying,
+    HasValue, IsErr, IsOk, IsOkSome, all_err, all_ok, any_err, has_error, has_error_matching,
+    has_error_of_type, has_error_satisfying, has_value, is_equal_to, is_err, is_ok, is_ok_some,
 };
+use crate::properties::ErrorTypeProperty;
 use crate::spec::{
     DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec, Unknown,
 };
+use crate::std::any;
+use crate::std::error::Error;
 use crate::std::fmt::{Debug, Display};
 use crate::std::{
     format,
     string::{String, ToString},
+    vec::Vec,
 };
 
 impl<T, E, R> AssertResult for Spec<'_, Result<T, E>, R>
@@ -50,26 +59,25 @@ impl<'a, T, E, R> AssertResultValue for Spec<'a, Result<T, E>, R>
 where
     T: Debug,
     E: Debug,
+    R: FailingStrategy,
 {
     type Ok = Spec<'a, T, R>;
     type Err = Spec<'a, E, R>;
 
-    fn ok(self) -> Self::Ok {
-        self.mapping(|subject| match subject {
-            Ok(value) => value,
-            Err(error) => {
-                panic!("expected the subject to be `Ok(_)`, but was `Err({error:?})`")
-            },
-        })
+    fn ok(mut self) -> Self::Ok {
+        if !self.is_skipped() && self.subject().is_err() {
+            self = self.expecting(is_ok());
+        }
+        let expression = format!("{} unwrapped Ok value", self.expression());
+        self.narrowing(Result::ok).named(expression)
     }
 
-    fn err(self) -> Self::Err {
-        self.mapping(|subject| match subject {
-            Ok(value) => {
-                panic!("expected the subject to be `Err(_)`, but was `Ok({value:?})`")
-            },
-            Err(error) => error,
-        })
+    fn err(mut self) -> Self::Err {
+        if !self.is_skipped() && self.subject().is_ok() {
+            self = self.expecting(is_err());
+        }
+        let expression = format!("{} unwrapped Err value", self.expression());
+        self.narrowing(Result::err).named(expression)
     }
 }
 
@@ -77,26 +85,201 @@ impl<'a, T, E, R> AssertResultValue for Spec<'a, &'a Result<T, E>, R>
 where
     T: Debug,
     E: Debug,
+    R: FailingStrategy,
 {
     type Ok = Spec<'a, &'a T, R>;
     type Err = Spec<'a, &'a E, R>;
 
-    fn ok(self) -> Self::Ok {
-        self.mapping(|subject| match subject {
-            Ok(value) => value,
-            Err(error) => {
-                panic!("expected the subject to be `Ok(_)`, but was `Err({error:?})`")
-            },
-        })
+    fn ok(mut self) -> Self::Ok {
+        if !self.is_skipped() && self.subject().is_err() {
+            self = self.expecting(is_ok());
+        }
+        let expression = format!("{} unwrapped Ok value", self.expression());
+        self.narrowing(|subject| subject.as_ref().ok())
+            .named(expression)
     }
 
-    fn err(self) -> Self::Err {
-        self.mapping(|subject| match subject {
-            Ok(value) => {
-                panic!("expected the subject to be `Err(_)`, but was `Ok({value:?})`")
-            },
-            Err(error) => error,
-        })
+    fn err(mut self) -> Self::Err {
+        if !self.is_skipped() && self.subject().is_ok() {
+            self = self.expecting(is_err());
+        }
+        let expression = format!("{} unwrapped Err value", self.expression());
+        self.narrowing(|subject| subject.as_ref().err())
+            .named(expression)
+    }
+}
+
+impl<'a, T, E, R> AssertNestedResultValue for Spec<'a, Result<Option<T>, E>, R>
+where
+    E: Debug,
+    R: FailingStrategy,
+{
+    type SomeOk = Spec<'a, T, R>;
+
+    fn some_ok(mut self) -> Self::SomeOk {
+        if !self.is_skipped() && !matches!(self.subject(), Ok(Some(_))) {
+            self = self.expecting(is_ok_some());
+        }
+        self.narrowing(|subject| subject.ok().flatten())
+    }
+}
+
+impl<'a, T, E, R> AssertNestedResultValue for Spec<'a, &'a Result<Option<T>, E>, R>
+where
+    E: Debug,
+    R: FailingStrategy,
+{
+    type SomeOk = Spec<'a, &'a T, R>;
+
+    fn some_ok(mut self) -> Self::SomeOk {
+        if !self.is_skipped() && !matches!(self.subject(), Ok(Some(_))) {
+            self = self.expecting(is_ok_some());
+        }
+        self.narrowing(|subject| subject.as_ref().ok().and_then(Option::as_ref))
+    }
+}
+
+impl<'a, S, T, E, R> AssertResultElements for Spec<'a, S, R>
+where
+    S: IntoIterator<Item = Result<T, E>>,
+    T: Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    type Results = Spec<'a, Vec<Result<T, E>>, R>;
+    type OkValues = Spec<'a, Vec<T>, R>;
+    type ErrValues = Spec<'a, Vec<E>, R>;
+
+    fn all_ok(self) -> Self::Results {
+        self.mapping(Vec::from_iter).expecting(all_ok())
+    }
+
+    fn all_err(self) -> Self::Results {
+        self.mapping(Vec::from_iter).expecting(all_err())
+    }
+
+    fn any_err(self) -> Self::Results {
+        self.mapping(Vec::from_iter).expecting(any_err())
+    }
+
+    fn ok_values(self) -> Self::OkValues {
+        let new_expression = format!("the ok values of {}", self.expression());
+        self.mapping(|subject| subject.into_iter().filter_map(Result::ok).collect())
+            .named(new_expression)
+    }
+
+    fn err_values(self) -> Self::ErrValues {
+        let new_expression = format!("the err values of {}", self.expression());
+        self.mapping(|subject| subject.into_iter().filter_map(Result::err).collect())
+            .named(new_expression)
+    }
+}
+
+impl<T, E> Expectation<Vec<Result<T, E>>> for AllOk
+where
+    T: Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::all_ok";
+
+    fn test(&mut self, subject: &Vec<Result<T, E>>) -> bool {
+        for (index, result) in subject.iter().enumerate() {
+            if result.is_err() {
+                self.failing.insert(index);
+            }
+        }
+        self.failing.is_empty()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<Result<T, E>>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let number_of_failing = self.failing.len();
+        let were_errors = if number_of_failing == 1 {
+            "was an error"
+        } else {
+            "were errors"
+        };
+        let mut indices = self.failing.iter().collect::<Vec<_>>();
+        indices.sort();
+        let marked_actual =
+            mark_selected_items_in_collection(actual, &self.failing, format, mark_unexpected);
+        format!(
+            r"expected all elements of {expression} to be ok, but {number_of_failing} {were_errors}
+   actual: {marked_actual}
+  indices: {indices:?}"
+        )
+    }
+}
+
+impl<T, E> Expectation<Vec<Result<T, E>>> for AllErr
+where
+    T: Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::all_err";
+
+    fn test(&mut self, subject: &Vec<Result<T, E>>) -> bool {
+        for (index, result) in subject.iter().enumerate() {
+            if result.is_ok() {
+                self.failing.insert(index);
+            }
+        }
+        self.failing.is_empty()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<Result<T, E>>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let number_of_failing = self.failing.len();
+        let were_ok = if number_of_failing == 1 {
+            "was ok"
+        } else {
+            "were ok"
+        };
+        let mut indices = self.failing.iter().collect::<Vec<_>>();
+        indices.sort();
+        let marked_actual =
+            mark_selected_items_in_collection(actual, &self.failing, format, mark_unexpected);
+        format!(
+            r"expected all elements of {expression} to be errors, but {number_of_failing} {were_ok}
+   actual: {marked_actual}
+  indices: {indices:?}"
+        )
+    }
+}
+
+impl<T, E> Expectation<Vec<Result<T, E>>> for AnyErr
+where
+    T: Debug,
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::any_err";
+
+    fn test(&mut self, subject: &Vec<Result<T, E>>) -> bool {
+        subject.iter().any(Result::is_err)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Vec<Result<T, E>>,
+        _inverted: bool,
+        _format: &DiffFormat,
+    ) -> String {
+        format!(
+            r"expected any element of {expression} to be an error
+   but was: {actual:?}
+  expected: at least one error"
+        )
     }
 }
 
@@ -148,6 +331,78 @@ where
     }
 }
 
+impl<T, E, R> AssertHasErrorMatching<E> for Spec<'_, Result<T, E>, R>
+where
+    T: Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    fn has_error_matching<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> bool,
+    {
+        self.expecting(has_error_matching(predicate))
+    }
+
+    fn has_error_satisfying<P, M>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> Result<(), M>,
+        M: Display,
+    {
+        self.expecting(has_error_satisfying(predicate))
+    }
+}
+
+impl<T, E, R> AssertHasErrorMatching<E> for Spec<'_, &Result<T, E>, R>
+where
+    T: Debug,
+    E: Debug,
+    R: FailingStrategy,
+{
+    fn has_error_matching<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> bool,
+    {
+        self.expecting(has_error_matching(predicate))
+    }
+
+    fn has_error_satisfying<P, M>(self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> Result<(), M>,
+        M: Display,
+    {
+        self.expecting(has_error_satisfying(predicate))
+    }
+}
+
+impl<T, E, R> AssertHasErrorOfType for Spec<'_, Result<T, E>, R>
+where
+    T: Debug,
+    E: ErrorTypeProperty + Debug,
+    R: FailingStrategy,
+{
+    fn has_error_of_type<X>(self) -> Self
+    where
+        X: Error + 'static,
+    {
+        self.expecting(has_error_of_type::<X>())
+    }
+}
+
+impl<T, E, R> AssertHasErrorOfType for Spec<'_, &Result<T, E>, R>
+where
+    T: Debug,
+    E: ErrorTypeProperty + Debug,
+    R: FailingStrategy,
+{
+    fn has_error_of_type<X>(self) -> Self
+    where
+        X: Error + 'static,
+    {
+        self.expecting(has_error_of_type::<X>())
+    }
+}
+
 impl<'a, T, E, X, R> AssertHasErrorMessage<X> for Spec<'a, Result<T, E>, R>
 where
     T: Debug,
@@ -197,6 +452,8 @@ where
     T: Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_ok";
+
     fn test(&mut self, subject: &Result<T, E>) -> bool {
         subject.is_ok()
     }
@@ -217,11 +474,67 @@ where
     }
 }
 
+impl<T, E> Expectation<Result<Option<T>, E>> for IsOkSome
+where
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::some_ok";
+
+    fn test(&mut self, subject: &Result<Option<T>, E>) -> bool {
+        matches!(subject, Ok(Some(_)))
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Result<Option<T>, E>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected = Ok::<_, Unknown>(Some(Unknown));
+        let (actual, wrong_variant) = match actual {
+            Err(error) => (Err(error), ", but the result is an error"),
+            Ok(None) => (Ok(None), ", but the ok value is None"),
+            Ok(Some(_)) => (Ok(Some(Unknown)), ""),
+        };
+        let marked_actual = mark_unexpected(&actual, format);
+        let marked_expected = mark_missing(&expected, format);
+        format!(
+            "expected {expression} to be {expected:?}{wrong_variant}\n   but was: {marked_actual}\n  expected: {marked_expected}"
+        )
+    }
+}
+
+impl<T, E> Expectation<&Result<Option<T>, E>> for IsOkSome
+where
+    E: Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::some_ok";
+
+    fn test(&mut self, subject: &&Result<Option<T>, E>) -> bool {
+        <Self as Expectation<Result<Option<T>, E>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Result<Option<T>, E>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Result<Option<T>, E>>>::message(
+            self, expression, actual, inverted, format,
+        )
+    }
+}
+
 impl<T, E> Expectation<Result<T, E>> for IsErr
 where
     T: Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_err";
+
     fn test(&mut self, subject: &Result<T, E>) -> bool {
         subject.is_err()
     }
@@ -247,6 +560,8 @@ where
     T: Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_ok";
+
     fn test(&mut self, subject: &&Result<T, E>) -> bool {
         <Self as Expectation<Result<T, E>>>::test(self, subject)
     }
@@ -267,6 +582,8 @@ where
     T: Debug,
     E: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::is_err";
+
     fn test(&mut self, subject: &&Result<T, E>) -> bool {
         <Self as Expectation<Result<T, E>>>::test(self, subject)
     }
@@ -288,6 +605,8 @@ where
     E: Debug,
     X: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_value";
+
     fn test(&mut self, subject: &Result<T, E>) -> bool {
         subject.as_ref().is_ok_and(|value| value == &self.expected)
     }
@@ -315,6 +634,8 @@ where
     E: Debug,
     X: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_value";
+
     fn test(&mut self, subject: &&Result<T, E>) -> bool {
         <Self as Expectation<Result<T, E>>>::test(self, subject)
     }
@@ -336,6 +657,8 @@ where
     E: PartialEq<X> + Debug,
     X: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_error";
+
     fn test(&mut self, subject: &Result<T, E>) -> bool {
         subject.as_ref().is_err_and(|err| err == &self.expected)
     }
@@ -365,6 +688,181 @@ where
     E: PartialEq<X> + Debug,
     X: Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::has_error";
+
+    fn test(&mut self, subject: &&Result<T, E>) -> bool {
+        <Self as Expectation<Result<T, E>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Result<T, E>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Result<T, E>>>::message(self, expression, actual, inverted, format)
+    }
+}
+
+impl<T, E, P> Expectation<Result<T, E>> for HasErrorMatching<P>
+where
+    T: Debug,
+    E: Debug,
+    P: Fn(&E) -> bool,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_error_matching";
+
+    fn test(&mut self, subject: &Result<T, E>) -> bool {
+        subject.as_ref().is_err_and(|err| (self.predicate)(err))
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Result<T, E>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let marked_actual = mark_unexpected(actual, format);
+        format!(
+            "expected {expression} to be an error {not}matching the given predicate\n   but was: {marked_actual}"
+        )
+    }
+}
+
+impl<P> Invertible for HasErrorMatching<P> {}
+
+impl<T, E, P> Expectation<&Result<T, E>> for HasErrorMatching<P>
+where
+    T: Debug,
+    E: Debug,
+    P: Fn(&E) -> bool,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_error_matching";
+
+    fn test(&mut self, subject: &&Result<T, E>) -> bool {
+        <Self as Expectation<Result<T, E>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Result<T, E>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Result<T, E>>>::message(self, expression, actual, inverted, format)
+    }
+}
+
+impl<T, E, P, M> Expectation<Result<T, E>> for HasErrorSatisfying<P>
+where
+    T: Debug,
+    E: Debug,
+    P: Fn(&E) -> Result<(), M>,
+    M: Display,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_error_satisfying";
+
+    fn test(&mut self, subject: &Result<T, E>) -> bool {
+        match subject {
+            Ok(_) => false,
+            Err(err) => match (self.predicate)(err) {
+                Ok(()) => true,
+                Err(reason) => {
+                    self.reason = Some(reason.to_string());
+                    false
+                },
+            },
+        }
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Result<T, E>,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let marked_actual = mark_unexpected(actual, format);
+        match &self.reason {
+            None => format!(
+                "expected {expression} to be an error satisfying the given predicate\n   but was: {marked_actual}"
+            ),
+            Some(reason) => format!(
+                "expected {expression} to be an error satisfying the given predicate\n   but was: {marked_actual}\n    reason: {reason}"
+            ),
+        }
+    }
+}
+
+impl<T, E, P, M> Expectation<&Result<T, E>> for HasErrorSatisfying<P>
+where
+    T: Debug,
+    E: Debug,
+    P: Fn(&E) -> Result<(), M>,
+    M: Display,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_error_satisfying";
+
+    fn test(&mut self, subject: &&Result<T, E>) -> bool {
+        <Self as Expectation<Result<T, E>>>::test(self, subject)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &&Result<T, E>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        <Self as Expectation<Result<T, E>>>::message(self, expression, actual, inverted, format)
+    }
+}
+
+impl<T, E, X> Expectation<Result<T, E>> for HasErrorOfType<X>
+where
+    T: Debug,
+    E: ErrorTypeProperty + Debug,
+    X: Error + 'static,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_error_of_type";
+
+    fn test(&mut self, subject: &Result<T, E>) -> bool {
+        subject
+            .as_ref()
+            .is_err_and(ErrorTypeProperty::is_error_of_type_property::<X>)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &Result<T, E>,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected_type = any::type_name::<X>();
+        let marked_actual = mark_unexpected(actual, format);
+        let marked_expected = mark_missing_string(&format!("Err(<{expected_type}>)"), format);
+        format!(
+            "expected {expression} to be an error {not}of type {expected_type}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl<X> Invertible for HasErrorOfType<X> {}
+
+impl<T, E, X> Expectation<&Result<T, E>> for HasErrorOfType<X>
+where
+    T: Debug,
+    E: ErrorTypeProperty + Debug,
+    X: Error + 'static,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_error_of_type";
+
     fn test(&mut self, subject: &&Result<T, E>) -> bool {
         <Self as Expectation<Result<T, E>>>::test(self, subject)
     }
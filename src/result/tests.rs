@@ -1,6 +1,9 @@
 use crate::prelude::*;
+use crate::std::boxed::Box;
+use crate::std::error::Error;
 use crate::std::fmt::{self, Display};
 use crate::std::{
+    format,
     string::{String, ToString},
     vec,
     vec::Vec,
@@ -335,10 +338,57 @@ fn map_result_with_err_value_to_its_ok_value() {
     let subject: Result<Vec<usize>, String> = Err("nam nihil iure liber".to_string());
 
     assert_that_code(|| {
-        assert_that(subject).ok().is_not_empty();
+        assert_that(subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .ok()
+            .is_not_empty();
     })
     .panics_with_message(
-        "expected the subject to be `Ok(_)`, but was `Err(\"nam nihil iure liber\")`",
+        r#"expected my_thing to be Ok(_)
+   but was: Err("nam nihil iure liber")
+  expected: Ok(_)
+"#,
+    );
+}
+
+#[test]
+fn verify_assertion_on_the_ok_value_of_result_refers_to_the_unwrapped_value() {
+    let subject: Result<i32, String> = Ok(41);
+
+    let failures = verify_that(&subject)
+        .named("some_result")
+        .ok()
+        .is_equal_to(&42)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected some_result unwrapped Ok value to be equal to 42
+   but was: 41
+  expected: 42
+"]
+    );
+}
+
+#[test]
+fn verify_assertion_on_the_err_value_of_result_refers_to_the_unwrapped_value() {
+    let subject: Result<i32, String> = Err("timeout".into());
+
+    let failures = verify_that(subject)
+        .named("some_result")
+        .err()
+        .is_equal_to("connection refused")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected some_result unwrapped Err value to be equal to "connection refused"
+   but was: "timeout"
+  expected: "connection refused"
+"#
+        ]
     );
 }
 
@@ -355,9 +405,18 @@ fn map_result_with_ok_value_to_its_err_value() {
     let subject: Result<Vec<usize>, String> = Ok(vec![1, 2, 3]);
 
     assert_that_code(|| {
-        assert_that(subject).err().is_not_empty();
+        assert_that(subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .err()
+            .is_not_empty();
     })
-    .panics_with_message("expected the subject to be `Err(_)`, but was `Ok([1, 2, 3])`");
+    .panics_with_message(
+        r"expected my_thing to be Err(_)
+   but was: Ok([1, 2, 3])
+  expected: Err(_)
+",
+    );
 }
 
 #[test]
@@ -373,10 +432,17 @@ fn map_borrowed_result_with_err_value_to_its_ok_value() {
     let subject: Result<Vec<usize>, String> = Err("nam nihil iure liber".to_string());
 
     assert_that_code(|| {
-        assert_that(&subject).ok().is_not_empty();
+        assert_that(&subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .ok()
+            .is_not_empty();
     })
     .panics_with_message(
-        "expected the subject to be `Ok(_)`, but was `Err(\"nam nihil iure liber\")`",
+        r#"expected my_thing to be Ok(_)
+   but was: Err("nam nihil iure liber")
+  expected: Ok(_)
+"#,
     );
 }
 
@@ -393,9 +459,18 @@ fn map_borrowed_result_with_ok_value_to_its_err_value() {
     let subject: Result<Vec<usize>, String> = Ok(vec![1, 2, 3]);
 
     assert_that_code(|| {
-        assert_that(&subject).err().is_not_empty();
+        assert_that(&subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .err()
+            .is_not_empty();
     })
-    .panics_with_message("expected the subject to be `Err(_)`, but was `Ok([1, 2, 3])`");
+    .panics_with_message(
+        r"expected my_thing to be Err(_)
+   but was: Ok([1, 2, 3])
+  expected: Err(_)
+",
+    );
 }
 
 #[test]
@@ -470,6 +545,409 @@ fn verify_borrowed_result_error_has_message_for_ok_value() {
     );
 }
 
+#[test]
+fn verify_map_result_with_err_value_to_its_ok_value_skips_following_assertions() {
+    let subject: Result<Vec<usize>, String> = Err("nam nihil iure liber".to_string());
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .has_error("sed eos tempor")
+        .ok()
+        .is_not_empty()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_thing to be an error containing "sed eos tempor"
+   but was: Err("nam nihil iure liber")
+  expected: Err("sed eos tempor")
+"#,
+            r#"expected my_thing to be Ok(_)
+   but was: Err("nam nihil iure liber")
+  expected: Ok(_)
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_map_nested_result_with_ok_none_skips_following_assertions() {
+    let subject: Result<Option<i32>, String> = Ok(None);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .some_ok()
+        .is_positive()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be Ok(Some(_)), but the ok value is None
+   but was: Ok(None)
+  expected: Ok(Some(_))
+"
+        ]
+    );
+}
+
+#[derive(Debug)]
+enum FileError {
+    NotFound(String),
+    PermissionDenied,
+}
+
+impl Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(file) => write!(f, "file {file} not found"),
+            Self::PermissionDenied => write!(f, "permission denied"),
+        }
+    }
+}
+
+impl Error for FileError {}
+
+#[derive(Debug)]
+struct ParseError;
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parse error")
+    }
+}
+
+impl Error for ParseError {}
+
+#[test]
+fn result_has_error_matching_predicate() {
+    let subject: Result<(), FileError> = Err(FileError::PermissionDenied);
+
+    assert_that(subject).has_error_matching(|err| matches!(err, FileError::PermissionDenied));
+}
+
+#[test]
+fn borrowed_result_has_error_matching_predicate() {
+    let subject: Result<(), FileError> = Err(FileError::NotFound("config.toml".to_string()));
+
+    assert_that(&subject).has_error_matching(|err| matches!(err, FileError::NotFound(_)));
+}
+
+#[test]
+fn verify_result_has_error_matching_predicate_fails() {
+    let subject: Result<(), FileError> = Err(FileError::PermissionDenied);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_error_matching(|err| matches!(err, FileError::NotFound(_)))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be an error matching the given predicate
+   but was: Err(PermissionDenied)
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_result_has_error_matching_predicate_fails_for_ok_value() {
+    let subject: Result<i32, FileError> = Ok(42);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_error_matching(|err| matches!(err, FileError::PermissionDenied))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be an error matching the given predicate
+   but was: Ok(42)
+"
+        ]
+    );
+}
+
+#[test]
+fn result_has_error_satisfying_predicate() {
+    let subject: Result<(), FileError> = Err(FileError::NotFound("config.toml".to_string()));
+
+    assert_that(subject).has_error_satisfying(|err| match err {
+        FileError::NotFound(file) if file == "config.toml" => Ok(()),
+        other => Err(format!("unexpected error: {other}")),
+    });
+}
+
+#[test]
+fn verify_borrowed_result_has_error_satisfying_predicate_fails() {
+    let subject: Result<(), FileError> = Err(FileError::NotFound("data.csv".to_string()));
+
+    let failures = verify_that(&subject)
+        .named("my_thing")
+        .has_error_satisfying(|err| match err {
+            FileError::NotFound(file) if file == "config.toml" => Ok(()),
+            other => Err(format!("unexpected error: {other}")),
+        })
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_thing to be an error satisfying the given predicate
+   but was: Err(NotFound("data.csv"))
+    reason: unexpected error: file data.csv not found
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_result_has_error_satisfying_predicate_fails_for_ok_value() {
+    let subject: Result<i32, FileError> = Ok(42);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_error_satisfying(|_| Ok::<_, String>(()))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be an error satisfying the given predicate
+   but was: Ok(42)
+"
+        ]
+    );
+}
+
+#[test]
+fn result_with_boxed_dyn_error_has_error_of_type() {
+    let subject: Result<(), Box<dyn Error>> = Err(Box::new(ParseError));
+
+    assert_that(subject).has_error_of_type::<ParseError>();
+}
+
+#[test]
+fn borrowed_result_with_boxed_dyn_error_has_error_of_type() {
+    let subject: Result<(), Box<dyn Error + Send + Sync>> =
+        Err(Box::new(FileError::PermissionDenied));
+
+    assert_that(&subject).has_error_of_type::<FileError>();
+}
+
+#[test]
+fn verify_result_with_boxed_dyn_error_has_error_of_type_fails() {
+    let subject: Result<(), Box<dyn Error + Send>> = Err(Box::new(FileError::PermissionDenied));
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_error_of_type::<ParseError>()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to be an error of type asserting::result::tests::ParseError
+   but was: Err(PermissionDenied)
+  expected: Err(<asserting::result::tests::ParseError>)
+"
+        ]
+    );
+}
+
+#[test]
+fn vec_of_results_all_ok() {
+    let subject: Vec<Result<i32, String>> = vec![Ok(1), Ok(2), Ok(3)];
+
+    assert_that(subject).all_ok();
+}
+
+#[test]
+fn verify_vec_of_results_all_ok_fails() {
+    let subject: Vec<Result<i32, &str>> = vec![Ok(1), Err("no"), Ok(3), Err("never")];
+
+    let failures = verify_that(subject)
+        .named("my_results")
+        .all_ok()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected all elements of my_results to be ok, but 2 were errors
+   actual: [Ok(1), Err("no"), Ok(3), Err("never")]
+  indices: [1, 3]
+"#
+        ]
+    );
+}
+
+#[test]
+fn vec_of_results_all_err() {
+    let subject: Vec<Result<i32, &str>> = vec![Err("no"), Err("never")];
+
+    assert_that(subject).all_err();
+}
+
+#[test]
+fn verify_vec_of_results_all_err_fails() {
+    let subject: Vec<Result<i32, &str>> = vec![Ok(1), Err("no"), Ok(3)];
+
+    let failures = verify_that(subject)
+        .named("my_results")
+        .all_err()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected all elements of my_results to be errors, but 2 were ok
+   actual: [Ok(1), Err("no"), Ok(3)]
+  indices: [0, 2]
+"#
+        ]
+    );
+}
+
+#[test]
+fn iterator_of_results_any_err() {
+    let subject = ["1", "x", "3"].into_iter().map(str::parse::<u8>);
+
+    assert_that(subject).any_err();
+}
+
+#[test]
+fn verify_vec_of_results_any_err_fails() {
+    let subject: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2)];
+
+    let failures = verify_that(subject)
+        .named("my_results")
+        .any_err()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected any element of my_results to be an error
+   but was: [Ok(1), Ok(2)]
+  expected: at least one error
+"
+        ]
+    );
+}
+
+#[test]
+fn vec_of_results_ok_values() {
+    let subject: Vec<Result<i32, &str>> = vec![Ok(1), Err("no"), Ok(3)];
+
+    assert_that(subject).ok_values().contains_exactly([1, 3]);
+}
+
+#[test]
+fn vec_of_results_err_values() {
+    let subject: Vec<Result<i32, &str>> = vec![Ok(1), Err("no"), Ok(3), Err("never")];
+
+    assert_that(subject)
+        .err_values()
+        .contains_exactly(["no", "never"]);
+}
+
+#[test]
+fn verify_vec_of_results_ok_values_fails_with_renamed_subject() {
+    let subject: Vec<Result<i32, &str>> = vec![Ok(1), Err("no"), Ok(3)];
+
+    let failures = verify_that(subject)
+        .named("my_results")
+        .ok_values()
+        .has_length(3)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected the ok values of my_results to have a length of 3
+   but was: 2
+  expected: 3
+"]
+    );
+}
+
+#[test]
+fn extracted_vec_of_results_all_ok_and_ok_values() {
+    struct Batch {
+        results: Vec<Result<u8, String>>,
+    }
+
+    let subject = Batch {
+        results: vec![Ok(2), Ok(4)],
+    };
+
+    assert_that(subject)
+        .extracting("results", |b| b.results)
+        .all_ok()
+        .ok_values()
+        .contains_exactly([2, 4]);
+}
+
+#[test]
+fn map_result_of_some_value_to_its_value() {
+    let subject: Result<Option<u64>, String> = Ok(Some(42));
+
+    assert_that(subject).some_ok().is_equal_to(42);
+}
+
+#[test]
+fn map_borrowed_result_of_some_value_to_its_value() {
+    let subject: Result<Option<Vec<i32>>, String> = Ok(Some(vec![1, 2]));
+
+    assert_that(&subject).some_ok().has_length(2);
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn map_result_of_option_with_error_to_its_value() {
+    let subject: Result<Option<u64>, String> = Err("te anim adipisici mollit".to_string());
+
+    assert_that_code(|| {
+        assert_that(subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .some_ok()
+            .is_equal_to(42);
+    })
+    .panics_with_message(
+        r#"expected my_thing to be Ok(Some(_)), but the result is an error
+   but was: Err("te anim adipisici mollit")
+  expected: Ok(Some(_))
+"#,
+    );
+}
+
+#[cfg(feature = "panic")]
+#[test]
+fn map_borrowed_result_of_none_to_its_value() {
+    let subject: Result<Option<u64>, String> = Ok(None);
+
+    assert_that_code(|| {
+        assert_that(&subject)
+            .named("my_thing")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .some_ok()
+            .is_equal_to(&42);
+    })
+    .panics_with_message(
+        r"expected my_thing to be Ok(Some(_)), but the ok value is None
+   but was: Ok(None)
+  expected: Ok(Some(_))
+",
+    );
+}
+
 #[cfg(feature = "colored")]
 mod colored {
     use crate::prelude::*;
@@ -552,4 +1030,24 @@ mod colored {
             ]
         );
     }
+
+    #[test]
+    fn highlight_diffs_vec_of_results_all_ok() {
+        let subject: Vec<Result<i32, &str>> = vec![Ok(1), Err("no"), Ok(3)];
+
+        let failures = verify_that(subject)
+            .with_diff_format(DIFF_FORMAT_RED_YELLOW)
+            .all_ok()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected all elements of subject to be ok, but 1 was an error\n   \
+                actual: [Ok(1), \u{1b}[31mErr(\"no\")\u{1b}[0m, Ok(3)]\n  \
+               indices: [1]\n\
+            "
+            ]
+        );
+    }
 }
@@ -1,6 +1,17 @@
+//! Implementation of assertions for `rust_decimal::Decimal`.
+
+use crate::assertions::AssertRustDecimal;
+use crate::colored::{mark_missing_string, mark_unexpected_string};
+use crate::expectations::{
+    IsCloseToDecimal, IsEqualToDecimal, is_close_to_decimal, is_equal_to_decimal,
+};
 use crate::properties::{
     AdditiveIdentityProperty, DecimalProperties, MultiplicativeIdentityProperty, SignumProperty,
 };
+use crate::spec::{DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Spec};
+use crate::std::borrow::Borrow;
+use crate::std::format;
+use crate::std::string::{String, ToString};
 use rust_decimal::Decimal;
 
 impl SignumProperty for Decimal {
@@ -51,5 +62,118 @@ impl DecimalProperties for Decimal {
     }
 }
 
+impl<S, R> AssertRustDecimal for Spec<'_, S, R>
+where
+    S: Borrow<Decimal>,
+    R: FailingStrategy,
+{
+    fn is_equal_to_decimal(self, expected: impl AsRef<str>) -> Self {
+        self.expecting(is_equal_to_decimal(expected))
+    }
+
+    fn is_close_to_decimal(self, expected: impl AsRef<str>, margin: impl AsRef<str>) -> Self {
+        self.expecting(is_close_to_decimal(expected, margin))
+    }
+}
+
+impl<S, E> Expectation<S> for IsEqualToDecimal<E>
+where
+    S: Borrow<Decimal>,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_equal_to_decimal";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let actual = subject.borrow();
+        self.expected
+            .as_ref()
+            .parse::<Decimal>()
+            .is_ok_and(|expected| *actual == expected && actual.scale() == expected.scale())
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected_string = self.expected.as_ref();
+        let actual = actual.borrow();
+        match expected_string.parse::<Decimal>() {
+            Ok(expected) => {
+                let marked_actual = mark_unexpected_string(&actual.to_string(), format);
+                let marked_expected = mark_missing_string(&expected.to_string(), format);
+                format!(
+                    "expected {expression} to be equal to decimal {expected_string:?}\n   but was: {marked_actual} (scale {})\n  expected: {marked_expected} (scale {})",
+                    actual.scale(),
+                    expected.scale(),
+                )
+            },
+            Err(err) => format!(
+                "expected {expression} to be equal to decimal {expected_string:?}\n  but the expected string is not a valid decimal: {err}"
+            ),
+        }
+    }
+}
+
+impl<S, E, M> Expectation<S> for IsCloseToDecimal<E, M>
+where
+    S: Borrow<Decimal>,
+    E: AsRef<str>,
+    M: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_close_to_decimal";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let actual = subject.borrow();
+        match (
+            self.expected.as_ref().parse::<Decimal>(),
+            self.margin.as_ref().parse::<Decimal>(),
+        ) {
+            (Ok(expected), Ok(margin)) => actual
+                .checked_sub(expected)
+                .is_some_and(|delta| delta.abs() <= margin.abs()),
+            _ => false,
+        }
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let expected_string = self.expected.as_ref();
+        let margin_string = self.margin.as_ref();
+        let actual = actual.borrow();
+        let headline = format!(
+            "expected {expression} to be close to decimal {expected_string:?} within a margin of {margin_string}"
+        );
+        match (
+            expected_string.parse::<Decimal>(),
+            margin_string.parse::<Decimal>(),
+        ) {
+            (Ok(expected), Ok(_)) => {
+                let marked_actual = mark_unexpected_string(&actual.to_string(), format);
+                let marked_expected = mark_missing_string(&expected.to_string(), format);
+                format!(
+                    "{headline}\n   but was: {marked_actual}\n  expected: {marked_expected}\n     delta: {}",
+                    actual
+                        .checked_sub(expected)
+                        .map_or_else(|| "<overflow>".to_string(), |delta| delta.abs().to_string()),
+                )
+            },
+            (Err(err), _) => {
+                format!("{headline}\n  but the expected string is not a valid decimal: {err}")
+            },
+            (_, Err(err)) => {
+                format!("{headline}\n  but the margin is not a valid decimal: {err}")
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -243,3 +243,105 @@ fn verify_decimal_is_integer_fails() {
 "]
     );
 }
+
+#[test]
+fn decimal_is_equal_to_decimal_string() {
+    let subject = Decimal::new(12_300, 4);
+
+    assert_that(subject).is_equal_to_decimal("1.2300");
+    assert_that(&Decimal::new(-42, 0)).is_equal_to_decimal("-42");
+}
+
+#[test]
+fn verify_decimal_is_equal_to_decimal_string_fails_on_different_scale() {
+    let subject = Decimal::new(123, 2);
+
+    let failures = verify_that(subject)
+        .named("price")
+        .is_equal_to_decimal("1.2300")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected price to be equal to decimal "1.2300"
+   but was: 1.23 (scale 2)
+  expected: 1.2300 (scale 4)
+"#]
+    );
+}
+
+#[test]
+fn verify_decimal_is_equal_to_decimal_string_fails_on_different_value() {
+    let subject = Decimal::new(12_310, 4);
+
+    let failures = verify_that(subject)
+        .named("price")
+        .is_equal_to_decimal("1.2300")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected price to be equal to decimal "1.2300"
+   but was: 1.2310 (scale 4)
+  expected: 1.2300 (scale 4)
+"#]
+    );
+}
+
+#[test]
+fn verify_decimal_is_equal_to_invalid_decimal_string_fails() {
+    let subject = Decimal::new(123, 2);
+
+    let failures = verify_that(subject)
+        .named("price")
+        .is_equal_to_decimal("1.2x")
+        .display_failures();
+
+    assert_that!(&failures).has_length(1);
+    assert_that!(failures[0].as_str()).starts_with(
+        "expected price to be equal to decimal \"1.2x\"\n  but the expected string is not a valid decimal: ",
+    );
+}
+
+#[test]
+fn decimal_is_close_to_decimal_string() {
+    assert_that(Decimal::new(10_001, 4)).is_close_to_decimal("1.00", "0.0001");
+    assert_that(Decimal::new(9_999, 4)).is_close_to_decimal("1.00", "0.0001");
+    assert_that(Decimal::new(1, 0)).is_close_to_decimal("1", "0");
+}
+
+#[test]
+fn verify_decimal_is_close_to_decimal_string_fails() {
+    let subject = Decimal::new(10_002, 4);
+
+    let failures = verify_that(subject)
+        .named("price")
+        .is_close_to_decimal("1.00", "0.0001")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected price to be close to decimal "1.00" within a margin of 0.0001
+   but was: 1.0002
+  expected: 1.00
+     delta: 0.0002
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_decimal_is_close_to_decimal_string_with_invalid_margin_fails() {
+    let subject = Decimal::new(10_002, 4);
+
+    let failures = verify_that(subject)
+        .named("price")
+        .is_close_to_decimal("1.00", "abc")
+        .display_failures();
+
+    assert_that!(&failures).has_length(1);
+    assert_that!(failures[0].as_str()).starts_with(
+        "expected price to be close to decimal \"1.00\" within a margin of abc\n  but the margin is not a valid decimal: ",
+    );
+}
@@ -0,0 +1,160 @@
+//! Implementation of assertions for sets.
+
+use crate::assertions::AssertSetEquality;
+use crate::expectations::{SetIsEqualTo, set_is_equal_to};
+use crate::properties::SetProperty;
+use crate::spec::{DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Spec};
+use crate::std::collections::BTreeSet;
+use crate::std::fmt::Debug;
+use crate::std::{format, string::String, vec::Vec};
+
+impl<T> SetProperty for BTreeSet<T>
+where
+    T: Ord,
+{
+    type Element = T;
+
+    fn elements_property(&self) -> impl Iterator<Item = &Self::Element> {
+        self.iter()
+    }
+
+    fn contains_property(&self, element: &Self::Element) -> bool {
+        self.contains(element)
+    }
+}
+
+impl<T, R> AssertSetEquality<BTreeSet<T>> for Spec<'_, BTreeSet<T>, R>
+where
+    T: Ord + Debug,
+    R: FailingStrategy,
+{
+    fn is_equal_to_set(self, expected: BTreeSet<T>) -> Self {
+        self.expecting(set_is_equal_to(expected))
+    }
+}
+
+impl<S, E> Expectation<S> for SetIsEqualTo<E>
+where
+    S: SetProperty + PartialEq<E> + Debug,
+    E: SetProperty<Element = <S as SetProperty>::Element> + Debug,
+    <S as SetProperty>::Element: Ord + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_equal_to_set";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject == &self.expected
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        _format: &DiffFormat,
+    ) -> String {
+        let expected = &self.expected;
+        let only_in_actual = elements_not_contained_in(actual, expected);
+        let only_in_expected = elements_not_contained_in(expected, actual);
+        format!(
+            r"expected {expression} to be equal to {expected:?}
+           but was: {actual:?}
+          expected: {expected:?}
+    only in actual: {only_in_actual:?}
+  only in expected: {only_in_expected:?}"
+        )
+    }
+}
+
+/// Collects the elements of the `source` set that are not contained in the
+/// `other` set, sorted by their natural order.
+fn elements_not_contained_in<'a, A, B>(source: &'a A, other: &B) -> Vec<&'a A::Element>
+where
+    A: SetProperty,
+    B: SetProperty<Element = A::Element>,
+    A::Element: Ord,
+{
+    let mut elements = source
+        .elements_property()
+        .filter(|element| !other.contains_property(element))
+        .collect::<Vec<_>>();
+    elements.sort();
+    elements
+}
+
+#[cfg(feature = "std")]
+mod std {
+    use crate::assertions::AssertSetEquality;
+    use crate::expectations::set_is_equal_to;
+    use crate::properties::SetProperty;
+    use crate::spec::{Expecting, FailingStrategy, Spec};
+    use crate::std::collections::HashSet;
+    use crate::std::fmt::Debug;
+    use crate::std::hash::{BuildHasher, Hash};
+
+    impl<T, H> SetProperty for HashSet<T, H>
+    where
+        T: Hash + Eq,
+        H: BuildHasher,
+    {
+        type Element = T;
+
+        fn elements_property(&self) -> impl Iterator<Item = &Self::Element> {
+            self.iter()
+        }
+
+        fn contains_property(&self, element: &Self::Element) -> bool {
+            self.contains(element)
+        }
+    }
+
+    impl<T, H, R> AssertSetEquality<HashSet<T, H>> for Spec<'_, HashSet<T, H>, R>
+    where
+        T: Hash + Ord + Debug,
+        H: BuildHasher,
+        R: FailingStrategy,
+    {
+        fn is_equal_to_set(self, expected: HashSet<T, H>) -> Self {
+            self.expecting(set_is_equal_to(expected))
+        }
+    }
+}
+
+mod hashbrown {
+    use crate::assertions::AssertSetEquality;
+    use crate::expectations::set_is_equal_to;
+    use crate::properties::SetProperty;
+    use crate::spec::{Expecting, FailingStrategy, Spec};
+    use crate::std::fmt::Debug;
+    use crate::std::hash::{BuildHasher, Hash};
+    use hashbrown::HashSet;
+
+    impl<T, H> SetProperty for HashSet<T, H>
+    where
+        T: Hash + Eq,
+        H: BuildHasher,
+    {
+        type Element = T;
+
+        fn elements_property(&self) -> impl Iterator<Item = &Self::Element> {
+            self.iter()
+        }
+
+        fn contains_property(&self, element: &Self::Element) -> bool {
+            self.contains(element)
+        }
+    }
+
+    impl<T, H, R> AssertSetEquality<HashSet<T, H>> for Spec<'_, HashSet<T, H>, R>
+    where
+        T: Hash + Ord + Debug,
+        H: BuildHasher,
+        R: FailingStrategy,
+    {
+        fn is_equal_to_set(self, expected: HashSet<T, H>) -> Self {
+            self.expecting(set_is_equal_to(expected))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,96 @@
+use crate::prelude::*;
+use crate::std::collections::BTreeSet;
+
+#[test]
+fn btree_set_is_equal_to_set() {
+    let subject = BTreeSet::from([1, 3, 5, 7]);
+
+    assert_that(subject).is_equal_to_set(BTreeSet::from([7, 5, 3, 1]));
+}
+
+#[test]
+fn verify_btree_set_is_equal_to_set_fails() {
+    let subject = BTreeSet::from([1, 3, 4, 6]);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .is_equal_to_set(BTreeSet::from([1, 2, 3, 5]))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to be equal to {1, 2, 3, 5}
+           but was: {1, 3, 4, 6}
+          expected: {1, 2, 3, 5}
+    only in actual: [4, 6]
+  only in expected: [2, 5]
+"]
+    );
+}
+
+#[test]
+fn verify_btree_set_is_equal_to_set_fails_for_subset() {
+    let subject = BTreeSet::from(["alpha", "beta"]);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .is_equal_to_set(BTreeSet::from(["alpha", "beta", "gamma"]))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_thing to be equal to {"alpha", "beta", "gamma"}
+           but was: {"alpha", "beta"}
+          expected: {"alpha", "beta", "gamma"}
+    only in actual: []
+  only in expected: ["gamma"]
+"#
+        ]
+    );
+}
+
+#[cfg(feature = "std")]
+mod std_hash_set {
+    use crate::prelude::*;
+    use crate::std::collections::HashSet;
+
+    #[test]
+    fn hash_set_is_equal_to_set() {
+        let subject = HashSet::from(["one", "two", "three"]);
+
+        assert_that(subject).is_equal_to_set(HashSet::from(["three", "two", "one"]));
+    }
+
+    #[test]
+    fn verify_hash_set_is_equal_to_set_fails() {
+        let subject = HashSet::from([4]);
+
+        let failures = verify_that(subject)
+            .named("my_thing")
+            .is_equal_to_set(HashSet::from([2]))
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected my_thing to be equal to {2}
+           but was: {4}
+          expected: {2}
+    only in actual: [4]
+  only in expected: [2]
+"]
+        );
+    }
+}
+
+mod hashbrown_hash_set {
+    use crate::prelude::*;
+    use hashbrown::HashSet;
+
+    #[test]
+    fn hashbrown_hash_set_is_equal_to_set() {
+        let subject = HashSet::<_>::from_iter([1, 2, 3]);
+
+        assert_that(subject).is_equal_to_set(HashSet::from_iter([3, 1, 2]));
+    }
+}
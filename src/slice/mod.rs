@@ -1,6 +1,6 @@
 //! Implementation of assertions for `slice` values.
 
-use crate::properties::{IsEmptyProperty, LengthProperty};
+use crate::properties::{IsEmptyProperty, KeyValuePairsProperty, LengthProperty};
 
 impl<T> IsEmptyProperty for &[T] {
     fn is_empty_property(&self) -> bool {
@@ -14,5 +14,23 @@ impl<T> LengthProperty for &[T] {
     }
 }
 
+impl<K, V> KeyValuePairsProperty for [(K, V)] {
+    type Key = K;
+    type Value = V;
+
+    fn pairs_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.iter().map(|(key, value)| (key, value))
+    }
+}
+
+impl<K, V, const N: usize> KeyValuePairsProperty for [(K, V); N] {
+    type Key = K;
+    type Value = V;
+
+    fn pairs_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.iter().map(|(key, value)| (key, value))
+    }
+}
+
 #[cfg(test)]
 mod tests;
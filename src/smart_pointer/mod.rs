@@ -0,0 +1,240 @@
+//! Implementation of assertions for smart pointers and cells.
+//!
+//! The property traits are forwarded to the value wrapped by a `Box`, `Rc`,
+//! `Arc` or `RefCell`, so that assertions like `is_empty` or `has_length` can
+//! be used on those types directly.
+//!
+//! Assertions that compare the subject with an expected value, like
+//! `is_equal_to` or `is_greater_than`, are not forwarded. They are implemented
+//! for all subjects that implement `PartialEq<E>` or `PartialOrd<E>`, and the
+//! standard library implements these traits for smart pointers only with
+//! another smart pointer as the expected value. An implementation forwarding
+//! to the wrapped value would overlap with the generic one. These assertions
+//! are available after mapping the subject to the wrapped value with the
+//! [`inner()`](crate::assertions::AssertInnerValue::inner) method.
+
+use crate::assertions::AssertInnerValue;
+use crate::properties::{
+    CharCountProperty, DefinedOrderProperty, InfinityProperty, InnerValueProperty, IsEmptyProperty,
+    IsNanProperty, LengthProperty, SignumProperty,
+};
+use crate::spec::{FailingStrategy, Spec};
+use crate::std::boxed::Box;
+use crate::std::cell::{Cell, RefCell};
+use crate::std::rc::Rc;
+use crate::std::sync::Arc;
+
+impl<'a, S, R> AssertInnerValue for Spec<'a, S, R>
+where
+    S: InnerValueProperty,
+    R: FailingStrategy,
+{
+    type Inner = Spec<'a, S::Inner, R>;
+
+    fn inner(self) -> Self::Inner {
+        self.mapping(InnerValueProperty::into_inner_property)
+    }
+}
+
+impl<T> InnerValueProperty for Box<T> {
+    type Inner = T;
+
+    fn into_inner_property(self) -> Self::Inner {
+        *self
+    }
+}
+
+impl<T> InnerValueProperty for Rc<T>
+where
+    T: Clone,
+{
+    type Inner = T;
+
+    fn into_inner_property(self) -> Self::Inner {
+        Self::unwrap_or_clone(self)
+    }
+}
+
+impl<T> InnerValueProperty for Arc<T>
+where
+    T: Clone,
+{
+    type Inner = T;
+
+    fn into_inner_property(self) -> Self::Inner {
+        Self::unwrap_or_clone(self)
+    }
+}
+
+impl<T> InnerValueProperty for Cell<T> {
+    type Inner = T;
+
+    fn into_inner_property(self) -> Self::Inner {
+        self.into_inner()
+    }
+}
+
+impl<T> InnerValueProperty for RefCell<T> {
+    type Inner = T;
+
+    fn into_inner_property(self) -> Self::Inner {
+        self.into_inner()
+    }
+}
+
+macro_rules! impl_smart_pointer_properties {
+    ($pointer:ident) => {
+        impl<'a, T> InnerValueProperty for &'a $pointer<T>
+        where
+            T: ?Sized,
+        {
+            type Inner = &'a T;
+
+            fn into_inner_property(self) -> Self::Inner {
+                self
+            }
+        }
+
+        impl<T> IsEmptyProperty for $pointer<T>
+        where
+            T: IsEmptyProperty + ?Sized,
+        {
+            fn is_empty_property(&self) -> bool {
+                <T as IsEmptyProperty>::is_empty_property(self)
+            }
+        }
+
+        impl<T> IsEmptyProperty for $pointer<[T]> {
+            fn is_empty_property(&self) -> bool {
+                self.is_empty()
+            }
+        }
+
+        impl IsEmptyProperty for $pointer<str> {
+            fn is_empty_property(&self) -> bool {
+                self.is_empty()
+            }
+        }
+
+        impl<T> LengthProperty for $pointer<T>
+        where
+            T: LengthProperty + ?Sized,
+        {
+            fn length_property(&self) -> usize {
+                <T as LengthProperty>::length_property(self)
+            }
+        }
+
+        impl<T> LengthProperty for $pointer<[T]> {
+            fn length_property(&self) -> usize {
+                self.len()
+            }
+        }
+
+        impl LengthProperty for $pointer<str> {
+            fn length_property(&self) -> usize {
+                self.len()
+            }
+        }
+
+        impl<T> CharCountProperty for $pointer<T>
+        where
+            T: CharCountProperty + ?Sized,
+        {
+            fn char_count_property(&self) -> usize {
+                <T as CharCountProperty>::char_count_property(self)
+            }
+        }
+
+        impl CharCountProperty for $pointer<str> {
+            fn char_count_property(&self) -> usize {
+                self.chars().count()
+            }
+        }
+
+        impl<T> SignumProperty for $pointer<T>
+        where
+            T: SignumProperty + ?Sized,
+        {
+            fn is_negative_property(&self) -> bool {
+                <T as SignumProperty>::is_negative_property(self)
+            }
+
+            fn is_positive_property(&self) -> bool {
+                <T as SignumProperty>::is_positive_property(self)
+            }
+        }
+
+        impl<T> InfinityProperty for $pointer<T>
+        where
+            T: InfinityProperty + ?Sized,
+        {
+            fn is_infinite_property(&self) -> bool {
+                <T as InfinityProperty>::is_infinite_property(self)
+            }
+
+            fn is_finite_property(&self) -> bool {
+                <T as InfinityProperty>::is_finite_property(self)
+            }
+        }
+
+        impl<T> IsNanProperty for $pointer<T>
+        where
+            T: IsNanProperty + ?Sized,
+        {
+            fn is_nan_property(&self) -> bool {
+                <T as IsNanProperty>::is_nan_property(self)
+            }
+        }
+    };
+}
+
+impl_smart_pointer_properties!(Box);
+impl_smart_pointer_properties!(Rc);
+impl_smart_pointer_properties!(Arc);
+
+impl<T> DefinedOrderProperty for Rc<T> where T: DefinedOrderProperty + ?Sized {}
+impl<T> DefinedOrderProperty for Arc<T> where T: DefinedOrderProperty + ?Sized {}
+
+impl<T> IsEmptyProperty for RefCell<T>
+where
+    T: IsEmptyProperty + ?Sized,
+{
+    fn is_empty_property(&self) -> bool {
+        self.borrow().is_empty_property()
+    }
+}
+
+impl<T> LengthProperty for RefCell<T>
+where
+    T: LengthProperty + ?Sized,
+{
+    fn length_property(&self) -> usize {
+        self.borrow().length_property()
+    }
+}
+
+impl<T> CharCountProperty for RefCell<T>
+where
+    T: CharCountProperty + ?Sized,
+{
+    fn char_count_property(&self) -> usize {
+        self.borrow().char_count_property()
+    }
+}
+
+impl<T> SignumProperty for RefCell<T>
+where
+    T: SignumProperty + ?Sized,
+{
+    fn is_negative_property(&self) -> bool {
+        self.borrow().is_negative_property()
+    }
+
+    fn is_positive_property(&self) -> bool {
+        self.borrow().is_positive_property()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,177 @@
+use crate::prelude::*;
+use crate::std::boxed::Box;
+use crate::std::cell::{Cell, RefCell};
+use crate::std::rc::Rc;
+use crate::std::string::{String, ToString};
+use crate::std::sync::Arc;
+use crate::std::vec;
+use crate::std::vec::Vec;
+
+#[test]
+fn box_of_i32_inner_is_equal_to() {
+    let subject = Box::new(42);
+
+    assert_that(subject).inner().is_equal_to(42);
+}
+
+#[test]
+fn arc_of_i32_inner_is_greater_than() {
+    let subject = Arc::new(5);
+
+    assert_that(subject).inner().is_greater_than(3);
+}
+
+#[test]
+fn arc_of_i32_is_greater_than_other_arc() {
+    let subject = Arc::new(5);
+
+    assert_that(subject).is_greater_than(Arc::new(3));
+}
+
+#[test]
+fn rc_of_string_inner_starts_with() {
+    let subject = Rc::new(String::from("sadipscing molestie"));
+
+    assert_that(subject).inner().starts_with("sadipscing");
+}
+
+#[test]
+fn shared_arc_of_vec_inner_contains_exactly() {
+    let subject = Arc::new(vec![1, 2, 3]);
+    let other = Arc::clone(&subject);
+
+    assert_that(subject).inner().contains_exactly([1, 2, 3]);
+    assert_that(other).has_length(3);
+}
+
+#[test]
+fn borrowed_rc_of_slice_inner_contains() {
+    let subject: Rc<[i32]> = Rc::from([2, 4, 6]);
+
+    assert_that(&subject).inner().contains(&4);
+}
+
+#[test]
+fn cell_of_f64_inner_is_in_range() {
+    let subject = Cell::new(0.25);
+
+    assert_that(subject).inner().is_in_range(0.0..=1.0);
+}
+
+#[test]
+fn ref_cell_of_vec_inner_contains_exactly() {
+    let subject = RefCell::new(vec!['a', 'b']);
+
+    assert_that(subject).inner().contains_exactly(['a', 'b']);
+}
+
+#[test]
+fn verify_arc_of_i32_inner_is_greater_than_fails() {
+    let subject = Arc::new(2);
+
+    let failures = verify_that(subject)
+        .named("counter")
+        .inner()
+        .is_greater_than(3)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected counter to be greater than 3
+   but was: 2
+  expected: > 3
+"]
+    );
+}
+
+#[test]
+fn box_of_slice_has_length() {
+    let subject: Box<[u8]> = Box::new([1, 2, 3, 4]);
+
+    assert_that(subject).has_length(4).is_not_empty();
+}
+
+#[test]
+fn arc_of_vec_has_length() {
+    let subject = Arc::new(vec![1, 2, 3]);
+
+    assert_that(subject).has_length(3).is_not_empty();
+}
+
+#[test]
+fn rc_of_empty_vec_is_empty() {
+    let subject: Rc<Vec<i32>> = Rc::new(Vec::new());
+
+    assert_that(subject).is_empty();
+}
+
+#[test]
+fn arc_of_str_has_char_count() {
+    let subject: Arc<str> = Arc::from("tempor ÿ");
+
+    assert_that(subject).has_char_count(8).has_length(9);
+}
+
+#[test]
+fn box_of_string_has_char_count() {
+    let subject = Box::new("Ünïcödé".to_string());
+
+    assert_that(subject).has_char_count(7);
+}
+
+#[test]
+fn ref_cell_of_vec_has_length() {
+    let subject = RefCell::new(vec![1]);
+
+    assert_that(subject).has_length(1).is_not_empty();
+}
+
+#[test]
+fn box_of_i32_is_negative() {
+    let subject = Box::new(-7);
+
+    assert_that(subject).is_negative();
+}
+
+#[test]
+fn rc_of_f64_is_finite_and_is_a_number() {
+    let subject = Rc::new(1.5_f64);
+
+    assert_that(subject).is_finite().is_a_number();
+}
+
+#[test]
+fn verify_box_of_slice_has_length_fails() {
+    let subject: Box<[u8]> = Box::new([1, 2, 3]);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_length(2)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to have a length of 2
+   but was: 3
+  expected: 2
+"]
+    );
+}
+
+#[test]
+fn verify_ref_cell_of_vec_is_empty_fails() {
+    let subject = RefCell::new(vec![1]);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .is_empty()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to be empty
+   but was: RefCell { value: [1] }
+  expected: <empty>
+"]
+    );
+}
@@ -1,24 +1,31 @@
 //! This is the core of the `asserting` crate.
 
-use crate::assertions::AssertElements;
+use crate::assertion_id;
+use crate::assertions::{AssertElements, AssertWindows};
 use crate::colored;
 use crate::derived_spec::DerivedSpec;
-use crate::expectations::satisfies;
+#[cfg(feature = "std")]
+use crate::env::{self, VarError};
+use crate::expectations::{negate, satisfies, satisfies_returning_result};
 #[cfg(feature = "recursive")]
 use crate::recursive_comparison::RecursiveComparison;
 use crate::std::any;
 use crate::std::borrow::{Borrow, Cow, ToOwned};
+use crate::std::cell::{Cell, RefCell};
 use crate::std::cmp::Ordering;
 use crate::std::error::Error as StdError;
-use crate::std::fmt::{self, Debug, Display};
+use crate::std::fmt::{self, Debug, Display, Write};
 use crate::std::format;
-use crate::std::ops::Deref;
+use crate::std::ops::{Deref, Range};
+use crate::std::rc::Rc;
 use crate::std::slice;
 use crate::std::string::{String, ToString};
+#[cfg(feature = "std")]
+use crate::std::sync::{Arc, Mutex, PoisonError, mpsc};
 use crate::std::vec;
 use crate::std::vec::Vec;
-#[cfg(feature = "panic")]
-use crate::std::{cell::RefCell, rc::Rc};
+#[cfg(feature = "std")]
+use crate::{listener, trace};
 
 /// Starts an assertion for the given subject or expression in the
 /// [`PanicOnFail`] mode.
@@ -47,11 +54,32 @@ use crate::std::{cell::RefCell, rc::Rc};
 ///     .located_at(Location { file: file!(), line: line!(), column: column!() })
 ///     .is_equal_to(42);
 /// ```
+///
+/// Given two expressions, the macro starts an assertion on the pair of both
+/// values, which is named after both expressions. On such a pair, the
+/// assertions of [`AssertPair`](crate::assertions::AssertPair) can be called:
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let (width, height) = (16, 9);
+///
+/// assert_that!(width * 9, height * 16).are_equal();
+/// ```
 #[macro_export]
 macro_rules! assert_that {
     ($subject:expr) => {
         $crate::prelude::assert_that($subject)
-            .named(&stringify!($subject).replace("\n", " "))
+            .named(stringify!($subject))
+            .located_at($crate::prelude::Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            })
+    };
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::prelude::assert_that(($first, $second))
+            .named(concat!(stringify!($first), " and ", stringify!($second)))
             .located_at($crate::prelude::Location {
                 file: file!(),
                 line: line!(),
@@ -101,7 +129,16 @@ macro_rules! assert_that {
 macro_rules! verify_that {
     ($subject:expr) => {
         $crate::prelude::verify_that($subject)
-            .named(&stringify!($subject).replace("\n", " "))
+            .named(stringify!($subject))
+            .located_at($crate::prelude::Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            })
+    };
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::prelude::verify_that(($first, $second))
+            .named(concat!(stringify!($first), " and ", stringify!($second)))
             .located_at($crate::prelude::Location {
                 file: file!(),
                 line: line!(),
@@ -110,6 +147,64 @@ macro_rules! verify_that {
     };
 }
 
+/// Evaluates a chain of assertions on the given subject and returns whether
+/// all of them have passed.
+///
+/// The macro takes the subject, followed by a comma and the chain of assertion
+/// methods to be called on the subject, starting with a dot. All assertions of
+/// the chain are executed in the [`CollectFailures`] mode. It returns `true` if
+/// no assertion has failed and `false` otherwise.
+///
+/// This is useful outside of tests, for example, for validating input or in
+/// guards like `debug_assert!`. To get the failures of the assertions, use
+/// [`verify_that!`] and call [`into_result()`](Spec::into_result) or
+/// [`failures()`](GetFailures::failures) on the [`Spec`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// fn is_valid_age(age: i32) -> bool {
+///     check_that!(age, .is_at_least(0).is_at_most(130))
+/// }
+///
+/// assert!(is_valid_age(42));
+/// assert!(!is_valid_age(-1));
+///
+/// let name = "Alice";
+/// debug_assert!(check_that!(name, .is_not_empty().starts_with('A')));
+/// ```
+///
+/// This call of the macro expands to:
+///
+/// ```
+/// # use asserting::prelude::*;
+/// # let age = 42;
+/// let passed = !verify_that(age)
+///     .named("age")
+///     .located_at(Location { file: file!(), line: line!(), column: column!() })
+///     .is_at_least(0)
+///     .is_at_most(130)
+///     .has_failures();
+/// # assert!(passed);
+/// ```
+#[macro_export]
+macro_rules! check_that {
+    ($subject:expr, $($assertions:tt)+) => {
+        !$crate::prelude::GetFailures::has_failures(
+            &$crate::prelude::verify_that($subject)
+                .named(stringify!($subject))
+                .located_at($crate::prelude::Location {
+                    file: file!(),
+                    line: line!(),
+                    column: column!(),
+                })
+                $($assertions)+
+        )
+    };
+}
+
 /// Starts an assertion for some piece of code in the [`PanicOnFail`] mode.
 ///
 /// It takes a closure and wraps it into a [`Spec`]. On the [`Spec`] any
@@ -140,7 +235,7 @@ macro_rules! verify_that {
 macro_rules! assert_that_code {
     ($subject:expr) => {
         $crate::prelude::assert_that_code($subject)
-            .named(&stringify!($subject).replace("\n", " "))
+            .named(stringify!($subject))
             .located_at($crate::prelude::Location {
                 file: file!(),
                 line: line!(),
@@ -201,7 +296,7 @@ macro_rules! assert_that_code {
 macro_rules! verify_that_code {
     ($subject:expr) => {
         $crate::prelude::verify_that_code($subject)
-            .named(&stringify!($subject).replace("\n", " "))
+            .named(stringify!($subject))
             .located_at($crate::prelude::Location {
                 file: file!(),
                 line: line!(),
@@ -210,6 +305,79 @@ macro_rules! verify_that_code {
     };
 }
 
+/// Starts an assertion for an environment variable in the [`PanicOnFail`]
+/// mode.
+///
+/// It reads the environment variable with the given name, wraps it into a
+/// [`Spec`] and sets the code location of the assertion. On the [`Spec`] any
+/// assertion method implemented for [`EnvVar`] can be called.
+///
+/// Assertions started with `assert_that_env!` will panic on the first failing
+/// assertion.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// assert_that_env!("PATH").is_set();
+///
+/// assert_that_env!("ASSERTING_SURELY_UNDEFINED_VARIABLE").is_not_set();
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "std", macro_export)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+macro_rules! assert_that_env {
+    ($name:expr) => {
+        $crate::prelude::assert_that_env($name).located_at($crate::prelude::Location {
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        })
+    };
+}
+
+/// Starts an assertion for an environment variable in the [`CollectFailures`]
+/// mode.
+///
+/// It reads the environment variable with the given name, wraps it into a
+/// [`Spec`] and sets the code location of the assertion. On the [`Spec`] any
+/// assertion method implemented for [`EnvVar`] can be called.
+///
+/// Assertions started with `verify_that_env!` will collect [`AssertFailure`]s
+/// for all failing assertions. The collected failures can be queried by calling
+/// one of the methods [`failures`](GetFailures::failures) or
+/// [`display_failures`](GetFailures::display_failures) on the [`Spec`].
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let failures = verify_that_env!("ASSERTING_SURELY_UNDEFINED_VARIABLE")
+///     .is_set()
+///     .display_failures();
+///
+/// assert_that!(failures).contains_exactly([
+///     r"expected environment variable ASSERTING_SURELY_UNDEFINED_VARIABLE to be set
+///    but was: <not set>
+///   expected: <set>
+/// "
+/// ]);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "std", macro_export)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+macro_rules! verify_that_env {
+    ($name:expr) => {
+        $crate::prelude::verify_that_env($name).located_at($crate::prelude::Location {
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        })
+    };
+}
+
 /// Starts an assertion for the given subject or expression in the
 /// [`PanicOnFail`] mode.
 ///
@@ -347,10 +515,7 @@ pub fn verify_that<'a, S>(subject: S) -> Spec<'a, S, CollectFailures> {
 /// ```
 #[cfg(feature = "panic")]
 #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-pub fn assert_that_code<'a, S>(code: S) -> Spec<'a, Code<S>, PanicOnFail>
-where
-    S: FnOnce(),
-{
+pub fn assert_that_code<'a, S>(code: S) -> Spec<'a, Code<S>, PanicOnFail> {
     #[cfg(not(feature = "colored"))]
     {
         Spec::new(Code::from(code), PanicOnFail).named("the closure")
@@ -417,13 +582,68 @@ where
 /// ```
 #[cfg(feature = "panic")]
 #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
-pub fn verify_that_code<'a, S>(code: S) -> Spec<'a, Code<S>, CollectFailures>
-where
-    S: FnOnce(),
-{
+pub fn verify_that_code<'a, S>(code: S) -> Spec<'a, Code<S>, CollectFailures> {
     Spec::new(Code::from(code), CollectFailures).named("the closure")
 }
 
+/// Starts an assertion for an environment variable in the [`PanicOnFail`]
+/// mode.
+///
+/// It reads the environment variable with the given name and wraps it into a
+/// [`Spec`]. The expression is named "environment variable" followed by the
+/// name of the variable.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// assert_that_env("PATH").is_set();
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[track_caller]
+pub fn assert_that_env<'a>(name: &str) -> Spec<'a, EnvVar, PanicOnFail> {
+    #[cfg(not(feature = "colored"))]
+    {
+        Spec::new(EnvVar::read(name), PanicOnFail).named(format!("environment variable {name}"))
+    }
+    #[cfg(feature = "colored")]
+    {
+        Spec::new(EnvVar::read(name), PanicOnFail)
+            .named(format!("environment variable {name}"))
+            .with_configured_diff_format()
+    }
+}
+
+/// Starts an assertion for an environment variable in the [`CollectFailures`]
+/// mode.
+///
+/// It reads the environment variable with the given name and wraps it into a
+/// [`Spec`]. The expression is named "environment variable" followed by the
+/// name of the variable.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let failures = verify_that_env("ASSERTING_SURELY_UNDEFINED_VARIABLE")
+///     .is_not_set()
+///     .failures();
+///
+/// assert_that!(failures).is_empty();
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn verify_that_env<'a>(name: &str) -> Spec<'a, EnvVar, CollectFailures> {
+    Spec::new(EnvVar::read(name), CollectFailures).named(format!("environment variable {name}"))
+}
+
+/// The identifier of assertions that do not define an identifier of their
+/// own.
+const CUSTOM_ASSERTION_ID: &str = "custom";
+
 /// An expectation defines a test for a property of the asserted subject.
 ///
 /// It requires two methods: a `test()` method and a `message()` method.
@@ -431,10 +651,87 @@ where
 /// expected property. In case the test of the expectation fails, the
 /// `message()` method is called to form an expectation-specific failure
 /// message.
+///
+/// An expectation that can not be evaluated in the first place, e.g., because
+/// a given regex pattern is invalid, reports an [`EvalError`] from the
+/// `try_test()` method instead. Such an error is surfaced in the failure
+/// message distinctly from a failed test.
 pub trait Expectation<S: ?Sized> {
+    /// The stable identifier of the assertion that this expectation verifies.
+    ///
+    /// It starts tagged failure messages (see [`OutputFormat::Tagged`]) and
+    /// should consist of the name of the crate and the name of the assertion
+    /// method, like `"my_crate::is_even"`. It defaults to `"custom"`.
+    const ASSERTION_ID: &'static str = CUSTOM_ASSERTION_ID;
+
+    /// Returns the stable identifier of the assertion that this expectation
+    /// verifies.
+    ///
+    /// The default implementation returns [`ASSERTION_ID`](Self::ASSERTION_ID).
+    /// Expectations that wrap other expectations, like
+    /// [`Not`](crate::expectations::Not), override it to compose the
+    /// identifier of the wrapped expectation.
+    fn assertion_id(&self) -> Cow<'static, str> {
+        Cow::Borrowed(Self::ASSERTION_ID)
+    }
+
     /// Verifies whether the actual subject fulfills the expected property.
     fn test(&mut self, subject: &S) -> bool;
 
+    /// Verifies whether the actual subject fulfills the expected property or
+    /// returns an error if the expectation can not be evaluated.
+    ///
+    /// The default implementation delegates to [`test()`](Self::test) and
+    /// never returns an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EvalError`] if the expectation can not be evaluated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use asserting::spec::{DiffFormat, EvalError, Expectation, Expression};
+    ///
+    /// struct IsMultipleOf(u32);
+    ///
+    /// impl Expectation<u32> for IsMultipleOf {
+    ///     fn test(&mut self, subject: &u32) -> bool {
+    ///         self.try_test(subject).unwrap_or(false)
+    ///     }
+    ///
+    ///     fn try_test(&mut self, subject: &u32) -> Result<bool, EvalError> {
+    ///         subject
+    ///             .checked_rem(self.0)
+    ///             .map(|remainder| remainder == 0)
+    ///             .ok_or_else(|| EvalError::new("the divisor must not be zero"))
+    ///     }
+    ///
+    ///     fn message(
+    ///         &self,
+    ///         expression: &Expression<'_>,
+    ///         actual: &u32,
+    ///         _inverted: bool,
+    ///         _format: &DiffFormat,
+    ///     ) -> String {
+    ///         format!("expected {expression} to be a multiple of {}\n   but was: {actual}", self.0)
+    ///     }
+    /// }
+    ///
+    /// let failures = verify_that(42_u32)
+    ///     .named("answer")
+    ///     .expecting(IsMultipleOf(0))
+    ///     .display_failures();
+    ///
+    /// assert_that!(failures).contains_exactly([
+    ///     "could not evaluate the assertion on answer\n  error: the divisor must not be zero\n",
+    /// ]);
+    /// ```
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        Ok(self.test(subject))
+    }
+
     /// Forms a failure message for this expectation.
     fn message(
         &self,
@@ -445,6 +742,229 @@ pub trait Expectation<S: ?Sized> {
     ) -> String;
 }
 
+/// An expectation whose failure message can make use of the metadata of the
+/// [`Spec`] in which it is asserted.
+///
+/// In addition to the expression and the diff format, which are also passed
+/// to an [`Expectation`], the [`SpecMetadata`] provides the description, the
+/// code location of the assertion and the type name of the subject. This
+/// enables custom assertions to form richer failure messages.
+///
+/// An `ExpectationWithContext` is asserted with the method
+/// `expecting_with_context` of [`Spec`] and [`DerivedSpec`]. Assertions using
+/// an [`Expectation`] are bridged to an `ExpectationWithContext` internally, so
+/// both kinds of expectations take part in tracing and assertion listeners
+/// alike.
+///
+/// The combinators [`not`](crate::expectations::not),
+/// [`all`](crate::expectations::all) and [`any`](crate::expectations::any)
+/// also accept expectations with context. To mix them with an
+/// [`Expectation`], wrap the latter in [`WithoutContext`].
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use asserting::spec::{ExpectationWithContext, SpecMetadata};
+///
+/// struct IsPowerOfTwo;
+///
+/// impl ExpectationWithContext<u32> for IsPowerOfTwo {
+///     fn test(&mut self, subject: &u32) -> bool {
+///         subject.is_power_of_two()
+///     }
+///
+///     fn message(&self, metadata: &SpecMetadata<'_>, actual: &u32, _inverted: bool) -> String {
+///         format!(
+///             "expected {} of type {} to be a power of two\n   but was: {actual}",
+///             metadata.expression, metadata.subject_type_name,
+///         )
+///     }
+/// }
+///
+/// let failures = verify_that!(6_u32)
+///     .expecting_with_context(IsPowerOfTwo)
+///     .display_failures();
+///
+/// assert_that!(failures).contains_exactly([
+///     "expected 6_u32 of type u32 to be a power of two\n   but was: 6\n"
+/// ]);
+/// ```
+pub trait ExpectationWithContext<S: ?Sized> {
+    /// The stable identifier of the assertion that this expectation verifies.
+    ///
+    /// See [`Expectation::ASSERTION_ID`].
+    const ASSERTION_ID: &'static str = CUSTOM_ASSERTION_ID;
+
+    /// Returns the stable identifier of the assertion that this expectation
+    /// verifies.
+    ///
+    /// The default implementation returns [`ASSERTION_ID`](Self::ASSERTION_ID).
+    fn assertion_id(&self) -> Cow<'static, str> {
+        Cow::Borrowed(Self::ASSERTION_ID)
+    }
+
+    /// Verifies whether the actual subject fulfills the expected property.
+    fn test(&mut self, subject: &S) -> bool;
+
+    /// Verifies whether the actual subject fulfills the expected property or
+    /// returns an error if the expectation can not be evaluated.
+    ///
+    /// The default implementation delegates to [`test()`](Self::test) and
+    /// never returns an error. See [`Expectation::try_test`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EvalError`] if the expectation can not be evaluated.
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        Ok(self.test(subject))
+    }
+
+    /// Forms a failure message for this expectation using the metadata of the
+    /// [`Spec`] in which the expectation is asserted.
+    fn message(&self, metadata: &SpecMetadata<'_>, actual: &S, inverted: bool) -> String;
+}
+
+/// Adapts an [`Expectation`] to an [`ExpectationWithContext`] by passing the
+/// expression and the diff format of the metadata to the expectation.
+///
+/// This adapter is needed to combine an [`Expectation`] with expectations
+/// that implement [`ExpectationWithContext`], e.g., using the combinators
+/// [`all`](crate::expectations::all) or [`any`](crate::expectations::any).
+///
+/// # Examples
+///
+/// ```
+/// use asserting::expectations::{all, is_positive};
+/// use asserting::prelude::*;
+/// use asserting::spec::{ExpectationWithContext, SpecMetadata, WithoutContext};
+///
+/// struct IsPowerOfTwo;
+///
+/// impl ExpectationWithContext<i32> for IsPowerOfTwo {
+///     fn test(&mut self, subject: &i32) -> bool {
+///         subject.count_ones() == 1
+///     }
+///
+///     fn message(&self, metadata: &SpecMetadata<'_>, actual: &i32, _inverted: bool) -> String {
+///         format!(
+///             "expected {} to be a power of two\n   but was: {actual}",
+///             metadata.expression
+///         )
+///     }
+/// }
+///
+/// assert_that!(64).expecting_with_context(all((WithoutContext(is_positive()), IsPowerOfTwo)));
+/// ```
+pub struct WithoutContext<E>(pub E);
+
+impl<S, E> ExpectationWithContext<S> for WithoutContext<E>
+where
+    S: ?Sized,
+    E: Expectation<S>,
+{
+    fn assertion_id(&self) -> Cow<'static, str> {
+        self.0.assertion_id()
+    }
+
+    fn test(&mut self, subject: &S) -> bool {
+        self.0.test(subject)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        self.0.try_test(subject)
+    }
+
+    fn message(&self, metadata: &SpecMetadata<'_>, actual: &S, inverted: bool) -> String {
+        self.0
+            .message(metadata.expression, actual, inverted, metadata.diff_format)
+    }
+}
+
+impl<E> Invertible for WithoutContext<E> where E: Invertible {}
+
+/// An error that prevents an expectation from being evaluated.
+///
+/// It is returned by [`Expectation::try_test`] if the expectation itself is
+/// not valid, like an invalid regex pattern, or if the subject can not be
+/// examined. The failure message states that the assertion could not be
+/// evaluated, followed by the message of this error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EvalError {
+    message: String,
+}
+
+impl EvalError {
+    /// Constructs a new `EvalError` with the given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Returns the message of this error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl StdError for EvalError {}
+
+/// Forms the failure message for an expectation that could not be evaluated.
+pub(crate) fn format_eval_error(expression: &Expression<'_>, error: &EvalError) -> String {
+    format!("could not evaluate the assertion on {expression}\n  error: {error}")
+}
+
+/// Forms the message without highlighting for an evaluated expectation, as it
+/// is passed to the trace and the assertion listeners.
+///
+/// If the expectation could not be evaluated, this is the message of the
+/// evaluation error.
+#[cfg(feature = "std")]
+pub(crate) fn plain_message<S>(
+    expectation: &impl ExpectationWithContext<S>,
+    metadata: &SpecMetadata<'_>,
+    subject: &S,
+    evaluation: &Result<bool, EvalError>,
+) -> String
+where
+    S: ?Sized,
+{
+    match evaluation {
+        Ok(_) => {
+            let metadata = SpecMetadata {
+                diff_format: &colored::DIFF_FORMAT_NO_HIGHLIGHT,
+                ..*metadata
+            };
+            expectation.message(&metadata, subject, false)
+        },
+        Err(error) => format_eval_error(metadata.expression, error),
+    }
+}
+
+/// Metadata of the [`Spec`] in which an expectation is asserted.
+///
+/// It is passed to the `message()` method of an [`ExpectationWithContext`].
+#[derive(Debug, Clone, Copy)]
+pub struct SpecMetadata<'a> {
+    /// The expression (or subject name) of the assertion.
+    pub expression: &'a Expression<'a>,
+    /// The description of the assertion, if one has been set.
+    pub description: Option<&'a str>,
+    /// The code location of the assertion, if it is known.
+    pub location: Option<Location<'a>>,
+    /// The type name of the subject as returned by [`core::any::type_name`].
+    pub subject_type_name: &'static str,
+    /// The diff format to be used for highlighting differences.
+    pub diff_format: &'a DiffFormat,
+}
+
 /// Marks an expectation that it can be inverted by using the [`Not`]
 /// combinator.
 ///
@@ -460,6 +980,10 @@ pub trait Invertible {}
 
 /// A textual representation of the expression or subject that is being
 /// asserted.
+///
+/// The text is stored as given. Line breaks, e.g. from a stringified
+/// expression that spans multiple lines, are replaced by spaces when the
+/// expression is displayed.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Expression<'a>(pub Cow<'a, str>);
 
@@ -471,7 +995,15 @@ impl Default for Expression<'_> {
 
 impl Display for Expression<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        let mut lines = self.0.split('\n');
+        if let Some(first) = lines.next() {
+            f.write_str(first)?;
+        }
+        for line in lines {
+            f.write_str(" ")?;
+            f.write_str(line)?;
+        }
+        Ok(())
     }
 }
 
@@ -557,7 +1089,11 @@ impl Location<'_> {
 ///
 /// It is basically the same as [`Location`] but uses owned types instead of
 /// borrowed types for its fields.
+///
+/// With the crate feature `serde` enabled, it implements `serde::Serialize`
+/// and `serde::Deserialize`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OwnedLocation {
     /// The file path of the source file where the assertion is located.
     pub file: String,
@@ -665,36 +1201,83 @@ impl PartialOrd<OwnedLocation> for Location<'_> {
 /// In case of the [`CollectFailures`] failing strategy, the [`AssertFailure`]s
 /// are collected in this struct.
 pub struct Spec<'a, S, R> {
-    subject: S,
+    /// The subject, or `None` if a preceding assertion failed to narrow the
+    /// subject, like `some()` on a `None` value.
+    subject: Option<S>,
     expression: Expression<'a>,
     description: Option<Cow<'a, str>>,
+    describes_all: bool,
+    context: Vec<(Cow<'a, str>, String)>,
     location: Option<Location<'a>>,
     failures: Vec<AssertFailure>,
+    passed: usize,
     diff_format: DiffFormat,
+    ignores_order: bool,
     failing_strategy: R,
 }
 
 impl<S, R> Spec<'_, S, R> {
     /// Returns the subject.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Spec` has no subject, because a preceding assertion
+    /// failed to narrow the subject (see [`is_skipped`](Spec::is_skipped)).
+    #[track_caller]
     pub fn subject(&self) -> &S {
-        &self.subject
-    }
-
-    /// Returns the expression (or subject name) if one has been set.
-    pub fn expression(&self) -> &Expression<'_> {
-        &self.expression
-    }
-
-    /// Returns the description or the assertion if it has been set.
-    pub fn description(&self) -> Option<&str> {
-        self.description.as_deref()
+        self.subject.as_ref().unwrap_or_else(|| {
+            panic!("the subject is not available, because a preceding assertion has failed")
+        })
     }
 
-    /// Returns the diff format used with this assertion.
-    pub const fn diff_format(&self) -> &DiffFormat {
+    /// Returns whether the assertions of this `Spec` are skipped.
+    ///
+    /// Assertions like `some()` or `ok()` narrow the subject to a value that
+    /// only exists if the assertion passes. If such an assertion fails, its
+    /// failure is reported according to the failing strategy, and all
+    /// assertions chained after it are skipped, as there is no subject to
+    /// assert on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let spec = verify_that(None::<i32>).some();
+    /// assert!(spec.is_skipped());
+    ///
+    /// let failures = spec.is_equal_to(42).display_failures();
+    /// assert_that!(failures).contains_exactly([
+    ///     "expected subject to be Some(_)\n   but was: None\n  expected: Some(_)\n",
+    /// ]);
+    /// ```
+    pub const fn is_skipped(&self) -> bool {
+        self.subject.is_none()
+    }
+
+    /// Returns the expression (or subject name) if one has been set.
+    pub fn expression(&self) -> &Expression<'_> {
+        &self.expression
+    }
+
+    /// Returns the description of the next assertion if it has been set.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the diff format used with this assertion.
+    pub const fn diff_format(&self) -> &DiffFormat {
         &self.diff_format
     }
 
+    /// Returns whether the order-sensitive assertions of this `Spec` ignore the
+    /// order of the elements.
+    ///
+    /// See [`ignoring_order`](Spec::ignoring_order) for details.
+    pub const fn ignores_order(&self) -> bool {
+        self.ignores_order
+    }
+
     /// Returns the failing strategy used in case an assertion fails.
     pub fn failing_strategy(&self) -> &R {
         &self.failing_strategy
@@ -710,12 +1293,16 @@ impl<'a, S, R> Spec<'a, S, R> {
     #[must_use = "a spec does nothing unless an assertion method is called"]
     pub fn new(subject: S, failing_strategy: R) -> Self {
         Self {
-            subject,
+            subject: Some(subject),
             expression: Expression::default(),
             description: None,
+            describes_all: false,
+            context: Vec::new(),
             location: None,
             failures: vec![],
+            passed: 0,
             diff_format: colored::DIFF_FORMAT_NO_HIGHLIGHT,
+            ignores_order: false,
             failing_strategy,
         }
     }
@@ -727,13 +1314,117 @@ impl<'a, S, R> Spec<'a, S, R> {
         self
     }
 
-    /// Sets a custom description about what is being asserted.
+    /// Sets a custom description about what is being asserted by the next
+    /// assertion.
+    ///
+    /// The description is only attached to a failure of the assertion that
+    /// immediately follows. Assertions chained after that one are reported
+    /// without this description. Use [`describe_all`](Self::describe_all) to
+    /// set a description for all assertions of the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let failures = verify_that!(51)
+    ///     .described_as("the answer must be 42")
+    ///     .is_equal_to(42)
+    ///     .and()
+    ///     .described_as("the answer must be less than 50")
+    ///     .is_less_than(50)
+    ///     .failures();
+    ///
+    /// assert_eq!(failures[0].description().unwrap(), "the answer must be 42");
+    /// assert_eq!(failures[1].description().unwrap(), "the answer must be less than 50");
+    /// ```
     #[must_use = "a spec does nothing unless an assertion method is called"]
     pub fn described_as(mut self, description: impl Into<Cow<'a, str>>) -> Self {
         self.description = Some(description.into());
+        self.describes_all = false;
+        self
+    }
+
+    /// Sets a custom description about what is being asserted by all
+    /// following assertions of the chain.
+    ///
+    /// In contrast to [`described_as`](Self::described_as), the description
+    /// is attached to the failures of all assertions that follow, until it is
+    /// replaced by another call to `describe_all` or `described_as`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let failures = verify_that!(51)
+    ///     .describe_all("the answer to all important questions")
+    ///     .is_equal_to(42)
+    ///     .and()
+    ///     .is_less_than(50)
+    ///     .failures();
+    ///
+    /// assert_eq!(failures.len(), 2);
+    /// assert!(failures.iter().all(|failure| {
+    ///     failure.description().unwrap() == "the answer to all important questions"
+    /// }));
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub fn describe_all(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
+        self.describes_all = true;
+        self
+    }
+
+    /// Adds a key-value pair to the context of this `Spec`.
+    ///
+    /// The context is appended as a "context:" block to the message of every
+    /// failure reported by the following assertions. It is meant to record
+    /// the inputs of a parameterized test, so that a failure tells which case
+    /// produced it. This method can be called multiple times to add several
+    /// key-value pairs. The value is formatted with its `Debug` implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// fn multiply(a: i32, b: i32) -> i32 {
+    ///     a + b
+    /// }
+    ///
+    /// let (a, b) = (2, 3);
+    ///
+    /// let failures = verify_that!(multiply(a, b))
+    ///     .with_context("a", a)
+    ///     .with_context("b", b)
+    ///     .is_equal_to(6)
+    ///     .display_failures();
+    ///
+    /// assert_eq!(failures, &[
+    ///     r"expected multiply(a, b) to be equal to 6
+    ///    but was: 5
+    ///   expected: 6
+    ///    context:
+    ///      a: 2
+    ///      b: 3
+    /// "
+    /// ]);
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub fn with_context(mut self, key: impl Into<Cow<'a, str>>, value: impl Debug) -> Self {
+        self.context.push((key.into(), format!("{value:?}")));
         self
     }
 
+    /// Removes the description after an assertion has been executed, unless
+    /// it has been set for all assertions of the chain.
+    fn end_of_assertion(&mut self) {
+        if !self.describes_all {
+            self.description = None;
+        }
+    }
+
     /// Sets the location of the assertion in the source code respectively test
     /// code.
     #[must_use = "a spec does nothing unless an assertion method is called"]
@@ -742,6 +1433,44 @@ impl<'a, S, R> Spec<'a, S, R> {
         self
     }
 
+    /// Replaces the failing strategy of this `Spec` with the given one.
+    ///
+    /// All other data like subject, expression, description, location, and
+    /// diff format are taken over into the returned `Spec`. Failures that have
+    /// been collected so far are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use asserting::spec::ForwardFailures;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let collector = Arc::new(Mutex::new(Vec::new()));
+    ///
+    /// verify_that!(6 * 7)
+    ///     .with_failing_strategy(ForwardFailures::new(Arc::clone(&collector)))
+    ///     .is_equal_to(41);
+    ///
+    /// assert_eq!(collector.lock().unwrap().len(), 1);
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub fn with_failing_strategy<T>(self, failing_strategy: T) -> Spec<'a, S, T> {
+        Spec {
+            subject: self.subject,
+            expression: self.expression,
+            description: self.description,
+            describes_all: self.describes_all,
+            context: self.context,
+            location: self.location,
+            failures: self.failures,
+            passed: self.passed,
+            diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
+            failing_strategy,
+        }
+    }
+
     /// Sets the diff format used to highlight differences between the actual
     /// value and the expected value.
     ///
@@ -754,11 +1483,95 @@ impl<'a, S, R> Spec<'a, S, R> {
         self
     }
 
+    /// Switches on pretty debug formatting (`{:#?}`) of the actual and the
+    /// expected value in failure messages.
+    ///
+    /// This is useful for large nested structs, which are hard to read when
+    /// formatted on a single line. See
+    /// [`DiffFormat::with_pretty_debug()`] for details.
+    ///
+    /// Note: This method must be called before an assertion method is called to
+    /// affect the failure message of the assertion as failure messages are
+    /// formatted immediately when an assertion is executed.
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub const fn with_pretty_debug(mut self) -> Self {
+        self.diff_format.pretty_debug = true;
+        self
+    }
+
+    /// Switches on display formatting of the actual and the expected value in
+    /// failure messages.
+    ///
+    /// Assertions on types that implement `Display` render values using their
+    /// `Display` format instead of their `Debug` format. See
+    /// [`DiffFormat::with_display_formatting()`] for details.
+    ///
+    /// Note: This method must be called before an assertion method is called to
+    /// affect the failure message of the assertion as failure messages are
+    /// formatted immediately when an assertion is executed.
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub const fn with_display_formatting(mut self) -> Self {
+        self.diff_format.display_formatting = true;
+        self
+    }
+
+    /// Switches on the vertical layout of the actual and the expected value in
+    /// failure messages.
+    ///
+    /// The values are printed on their own lines marked with `<` for the
+    /// actual and `>` for the expected value. See
+    /// [`DiffFormat::with_vertical_layout()`] for details.
+    ///
+    /// Note: This method must be called before an assertion method is called to
+    /// affect the failure message of the assertion as failure messages are
+    /// formatted immediately when an assertion is executed.
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub const fn with_vertical_layout(mut self) -> Self {
+        self.diff_format.vertical_layout = true;
+        self
+    }
+
+    /// Relaxes all following order-sensitive assertions on sequences to their
+    /// order-insensitive equivalents.
+    ///
+    /// This is convenient for asserting results whose order is not
+    /// deterministic, like the rows returned by a database query, while the
+    /// assertions read the same as for ordered results. The assertions are
+    /// relaxed as follows:
+    ///
+    /// | assertion               | is asserted like                                             |
+    /// |-------------------------|--------------------------------------------------------------|
+    /// | `contains_exactly`      | `contains_exactly_in_any_order`                              |
+    /// | `contains_all_in_order` | `contains_all_of`                                            |
+    /// | `starts_with`           | the first elements contain the expected ones in any order    |
+    /// | `ends_with`             | the last elements contain the expected ones in any order     |
+    ///
+    /// Other assertions, like `contains_sequence`, are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let user_ids = vec![13, 3, 7, 5];
+    ///
+    /// assert_that!(user_ids)
+    ///     .ignoring_order()
+    ///     .contains_exactly([3, 5, 7, 13])
+    ///     .starts_with([3, 13])
+    ///     .ends_with([5, 7]);
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub const fn ignoring_order(mut self) -> Self {
+        self.ignores_order = true;
+        self
+    }
+
     /// Sets the diff format used to highlight differences between the actual
     /// value and the expected value according to the configured mode.
     ///
-    /// The mode is configured via environment variables as described in the
-    /// module [colored].
+    /// The mode is configured via environment variables or programmatically as
+    /// described in the module [colored].
     #[cfg(feature = "colored")]
     #[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
     #[must_use = "a spec does nothing unless an assertion method is called"]
@@ -770,10 +1583,12 @@ impl<'a, S, R> Spec<'a, S, R> {
         }
         #[cfg(feature = "std")]
         {
+            use crate::colored::programmatic_diff_format;
             use crate::std::sync::OnceLock;
             static DIFF_FORMAT: OnceLock<DiffFormat> = OnceLock::new();
-            let diff_format = DIFF_FORMAT.get_or_init(configured_diff_format);
-            self.with_diff_format(diff_format.clone())
+            let diff_format = programmatic_diff_format()
+                .unwrap_or_else(|| DIFF_FORMAT.get_or_init(configured_diff_format).clone());
+            self.with_diff_format(diff_format)
         }
     }
 
@@ -875,12 +1690,22 @@ impl<'a, S, R> Spec<'a, S, R> {
         F: FnOnce(&S) -> &B,
         B: ToOwned<Owned = U> + ?Sized,
     {
-        let derived_subject = extract(&self.subject).to_owned();
+        let derived_subject = self
+            .subject
+            .as_ref()
+            .map(|subject| extract(subject).to_owned());
         let orig_subject_name = &self.expression;
         let property_name = property_name.into();
         let expression = Expression(format!("{orig_subject_name}.{property_name}").into());
         let diff_format = self.diff_format.clone();
-        DerivedSpec::new(self, derived_subject, expression, diff_format)
+        let ignores_order = self.ignores_order;
+        DerivedSpec::new(
+            self,
+            derived_subject,
+            expression,
+            diff_format,
+            ignores_order,
+        )
     }
 
     /// Maps the current subject to some other value.
@@ -973,7 +1798,7 @@ impl<'a, S, R> Spec<'a, S, R> {
     where
         F: FnOnce(S) -> U,
     {
-        let derived_subject = extract(self.subject);
+        let derived_subject = self.subject.map(extract);
         let orig_subject_name = &self.expression;
         let property_name = property_name.into();
         let expression = Expression(format!("{orig_subject_name}.{property_name}").into());
@@ -981,9 +1806,13 @@ impl<'a, S, R> Spec<'a, S, R> {
             subject: derived_subject,
             expression,
             description: self.description,
+            describes_all: self.describes_all,
+            context: self.context,
             location: self.location,
             failures: self.failures,
+            passed: self.passed,
             diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
             failing_strategy: self.failing_strategy,
         }
     }
@@ -1030,20 +1859,133 @@ impl<'a, S, R> Spec<'a, S, R> {
         F: FnOnce(S) -> U,
     {
         Spec {
-            subject: map(self.subject),
+            subject: self.subject.map(map),
+            expression: self.expression,
+            description: self.description,
+            describes_all: self.describes_all,
+            context: self.context,
+            location: self.location,
+            failures: self.failures,
+            passed: self.passed,
+            diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
+            failing_strategy: self.failing_strategy,
+        }
+    }
+
+    /// Narrows the subject with the given closure, skipping all following
+    /// assertions if the closure returns `None`.
+    ///
+    /// Accessor assertions like `some()` or `ok()` record their failure
+    /// before narrowing, so the spec continues according to the failing
+    /// strategy instead of panicking unconditionally.
+    pub(crate) fn narrowing<F, U>(self, narrow: F) -> Spec<'a, U, R>
+    where
+        F: FnOnce(S) -> Option<U>,
+    {
+        Spec {
+            subject: self.subject.and_then(narrow),
             expression: self.expression,
             description: self.description,
+            describes_all: self.describes_all,
+            context: self.context,
             location: self.location,
             failures: self.failures,
+            passed: self.passed,
             diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
             failing_strategy: self.failing_strategy,
         }
     }
+
+    /// Calls the given closure with a reference to the current subject and
+    /// returns this `Spec` unchanged.
+    ///
+    /// This method is useful for troubleshooting a failing assertion chain,
+    /// for example, to look at a subject that has been extracted or mapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let mut seen = Vec::new();
+    ///
+    /// assert_that!(vec![3, 1, 2])
+    ///     .mapping(|mut v| { v.sort(); v })
+    ///     .inspecting(|subject| seen.extend_from_slice(subject))
+    ///     .contains_exactly([1, 2, 3]);
+    ///
+    /// assert_eq!(seen, [1, 2, 3]);
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub fn inspecting<F>(self, inspect: F) -> Self
+    where
+        F: FnOnce(&S),
+    {
+        if let Some(subject) = &self.subject {
+            inspect(subject);
+        }
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<S, R> Spec<'_, S, R>
+where
+    S: Debug,
+{
+    /// Prints the current subject pretty formatted to stderr and returns this
+    /// `Spec` unchanged.
+    ///
+    /// The output is similar to the one of the [`dbg!`] macro. It starts with
+    /// the location of the assertion, if known, followed by the expression
+    /// (or subject name) and the subject formatted with `{:#?}`.
+    ///
+    /// This method is useful for troubleshooting a failing assertion chain,
+    /// for example, to look at a subject that has been extracted or mapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!("cupiditat voluptua")
+    ///     .mapping(|s| s.split(' ').collect::<Vec<_>>())
+    ///     .debug_log()
+    ///     .has_length(2);
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    #[allow(clippy::print_stderr)]
+    pub fn debug_log(self) -> Self {
+        if let Some(subject) = &self.subject {
+            eprintln!(
+                "{}",
+                format_debug_log(self.location, &self.expression, subject)
+            );
+        }
+        self
+    }
+}
+
+/// Formats the output of the `debug_log` method.
+#[cfg(feature = "std")]
+pub(crate) fn format_debug_log(
+    location: Option<Location<'_>>,
+    expression: &Expression<'_>,
+    subject: &impl Debug,
+) -> String {
+    location.map_or_else(
+        || format!("{expression} = {subject:#?}"),
+        |location| format!("[{location}] {expression} = {subject:#?}"),
+    )
 }
 
 impl<'a, I, R> AssertElements<'a, I> for Spec<'a, I, R>
 where
     I: IntoIterator,
+    R: FailingStrategy,
 {
     type Output = Spec<'a, (), R>;
 
@@ -1054,19 +1996,31 @@ where
     {
         let root_expression = &self.expression;
         let mut position = -1;
-        for item in self.subject {
+        for item in self.subject.into_iter().flatten() {
             position += 1;
             let element_spec = Spec {
-                subject: item,
+                subject: Some(item),
                 expression: format!("{root_expression} [{position}]").into(),
                 description: None,
+                describes_all: false,
+                context: self.context.clone(),
                 location: self.location,
                 failures: vec![],
+                passed: 0,
                 diff_format: self.diff_format.clone(),
+                ignores_order: self.ignores_order,
                 failing_strategy: CollectFailures,
             };
             let failures = assert(element_spec).failures();
+            if failures.is_empty() {
+                continue;
+            }
             self.failures.extend(failures);
+            // all elements are asserted before panicking, other strategies
+            // like `FailAfter` get the chance to stop early
+            if any::type_name_of_val(&self.failing_strategy) != any::type_name::<PanicOnFail>() {
+                self.failing_strategy.do_fail_with(&self.failures);
+            }
         }
         if !self.failures.is_empty()
             && any::type_name_of_val(&self.failing_strategy) == any::type_name::<PanicOnFail>()
@@ -1074,12 +2028,16 @@ where
             PanicOnFail.do_fail_with(&self.failures);
         }
         Spec {
-            subject: (),
+            subject: Some(()),
             expression: self.expression,
-            description: self.description,
+            description: self.description.filter(|_| self.describes_all),
+            describes_all: self.describes_all,
+            context: self.context,
             location: self.location,
             failures: self.failures,
+            passed: self.passed,
             diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
             failing_strategy: self.failing_strategy,
         }
     }
@@ -1092,15 +2050,19 @@ where
         let root_expression = &self.expression;
         let mut any_success = false;
         let mut position = -1;
-        for item in self.subject {
+        for item in self.subject.into_iter().flatten() {
             position += 1;
             let element_spec = Spec {
-                subject: item,
+                subject: Some(item),
                 expression: format!("{root_expression} [{position}]").into(),
                 description: None,
+                describes_all: false,
+                context: self.context.clone(),
                 location: self.location,
                 failures: vec![],
+                passed: 0,
                 diff_format: self.diff_format.clone(),
+                ignores_order: self.ignores_order,
                 failing_strategy: CollectFailures,
             };
             let failures = assert(element_spec).failures();
@@ -1116,70 +2078,375 @@ where
             PanicOnFail.do_fail_with(&self.failures);
         }
         Spec {
-            subject: (),
+            subject: Some(()),
             expression: self.expression,
-            description: self.description,
+            description: self.description.filter(|_| self.describes_all),
+            describes_all: self.describes_all,
+            context: self.context,
             location: self.location,
             failures: self.failures,
+            passed: self.passed,
             diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
             failing_strategy: self.failing_strategy,
         }
     }
 }
 
-impl<'a, I, R> Spec<'a, I, R>
+impl<'a, 'b, C, T, R> AssertWindows<'a, &'b [T]> for Spec<'a, &'b C, R>
 where
-    I: IntoIterator,
+    C: AsRef<[T]> + ?Sized,
+    T: Debug,
+    R: FailingStrategy,
 {
-    pub(crate) fn extracting_ref_iter<F, U>(
-        self,
-        property_name: impl Into<Cow<'a, str>>,
-        extract: F,
-    ) -> DerivedSpec<'a, Spec<'a, Vec<<I as IntoIterator>::Item>, R>, Vec<U>>
+    type Output = Spec<'a, (), R>;
+
+    fn each_window<A, B>(self, size: usize, assert: A) -> Self::Output
     where
-        for<'b> F: Fn(slice::Iter<'b, <I as IntoIterator>::Item>) -> Vec<U>,
+        A: Fn(Spec<'a, &'b [T], CollectFailures>) -> B,
+        B: GetFailures,
     {
-        let property_name = Expression(property_name.into());
-        let diff_format = self.diff_format.clone();
-        let orig_spec = self.mapping(Vec::from_iter);
-        let new_subject = extract(orig_spec.subject.iter());
-        DerivedSpec::new(orig_spec, new_subject, property_name, diff_format)
+        let windows = self.subject.map_or(&[][..], AsRef::as_ref).windows(size);
+        self.each_part("window", windows, assert)
     }
-}
 
-/// Trigger failing of an assertion according to the failing strategy of its
-/// implementing spec-like struct.
-pub trait DoFail {
-    /// Fails the assertion with the given [`AssertFailure`]s according to the
-    /// current failing strategy of the `Spec` or other implementing
-    /// spec-like struct.
-    fn do_fail_with(&mut self, failures: impl IntoIterator<Item = AssertFailure>);
-
-    /// Fails the assertion with the given failure message according to the
-    /// current failing strategy of the `Spec` or other implementing
-    /// spec-like struct.
-    fn do_fail_with_message(&mut self, message: impl Into<String>);
+    fn each_chunk<A, B>(self, size: usize, assert: A) -> Self::Output
+    where
+        A: Fn(Spec<'a, &'b [T], CollectFailures>) -> B,
+        B: GetFailures,
+    {
+        let chunks = self.subject.map_or(&[][..], AsRef::as_ref).chunks(size);
+        self.each_part("chunk", chunks, assert)
+    }
 }
 
-impl<S, R> DoFail for Spec<'_, S, R>
+impl<'a, 'b, C, R> Spec<'a, &'b C, R>
 where
+    C: ?Sized,
     R: FailingStrategy,
 {
-    fn do_fail_with(&mut self, failures: impl IntoIterator<Item = AssertFailure>) {
-        self.failures.extend(failures);
-        self.failing_strategy.do_fail_with(&self.failures);
+    /// Executes the given assertions for each part of the subject, like
+    /// windows or chunks, and collects the failures of all parts.
+    #[track_caller]
+    fn each_part<T, A, B>(
+        mut self,
+        kind: &'static str,
+        parts: impl Iterator<Item = &'b [T]>,
+        assert: A,
+    ) -> Spec<'a, (), R>
+    where
+        T: Debug + 'b,
+        A: Fn(Spec<'a, &'b [T], CollectFailures>) -> B,
+        B: GetFailures,
+    {
+        let root_expression = &self.expression;
+        for (position, part) in parts.enumerate() {
+            let mut context = self.context.clone();
+            context.push((kind.into(), format!("{part:?}")));
+            let part_spec = Spec {
+                subject: Some(part),
+                expression: format!("{root_expression} {kind} [{position}]").into(),
+                description: None,
+                describes_all: false,
+                context,
+                location: self.location,
+                failures: vec![],
+                passed: 0,
+                diff_format: self.diff_format.clone(),
+                ignores_order: self.ignores_order,
+                failing_strategy: CollectFailures,
+            };
+            let failures = assert(part_spec).failures();
+            if failures.is_empty() {
+                continue;
+            }
+            self.failures.extend(failures);
+            // all parts are asserted before panicking, other strategies
+            // like `FailAfter` get the chance to stop early
+            if any::type_name_of_val(&self.failing_strategy) != any::type_name::<PanicOnFail>() {
+                self.failing_strategy.do_fail_with(&self.failures);
+            }
+        }
+        if !self.failures.is_empty()
+            && any::type_name_of_val(&self.failing_strategy) == any::type_name::<PanicOnFail>()
+        {
+            PanicOnFail.do_fail_with(&self.failures);
+        }
+        Spec {
+            subject: Some(()),
+            expression: self.expression,
+            description: self.description.filter(|_| self.describes_all),
+            describes_all: self.describes_all,
+            context: self.context,
+            location: self.location,
+            failures: self.failures,
+            passed: self.passed,
+            diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
+            failing_strategy: self.failing_strategy,
+        }
+    }
+}
+
+/// An element of a collection that is compared to expected elements with a
+/// custom equality function instead of its `PartialEq` implementation.
+///
+/// It is the element type of the subject returned by
+/// [`Spec::comparing_elements_by`]. Its `Debug` implementation formats the
+/// wrapped element only.
+pub struct ComparedBy<T, F> {
+    element: T,
+    compare: Rc<F>,
+}
+
+impl<T, F> ComparedBy<T, F> {
+    pub(crate) fn wrap_all(elements: impl IntoIterator<Item = T>, compare: &Rc<F>) -> Vec<Self> {
+        elements
+            .into_iter()
+            .map(|element| Self {
+                element,
+                compare: Rc::clone(compare),
+            })
+            .collect()
+    }
+
+    /// Returns a reference to the wrapped element.
+    pub const fn element(&self) -> &T {
+        &self.element
+    }
+
+    /// Returns the wrapped element.
+    pub fn into_element(self) -> T {
+        self.element
+    }
+}
+
+impl<T, F> Debug for ComparedBy<T, F>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.element.fmt(f)
+    }
+}
+
+impl<T, F> PartialEq<T> for ComparedBy<T, F>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    fn eq(&self, other: &T) -> bool {
+        (self.compare)(&self.element, other)
+    }
+}
+
+impl<'a, I, R> Spec<'a, I, R>
+where
+    I: IntoIterator,
+{
+    /// Maps each element of a collection or an iterator to some other value.
+    ///
+    /// It takes a closure that is called for each element of the current
+    /// subject and returns a new `Spec` with a `Vec` of the values returned by
+    /// the closure as the new subject. The order of the elements is preserved.
+    /// All other data like description, location, and diff format are taken
+    /// over from this `Spec` into the returned `Spec`.
+    ///
+    /// The expression for failure reports is built from the expression of the
+    /// original subject and notes that each element has been extracted. The
+    /// resulting expression is equal to
+    /// `format!("the extracted elements of {original_expression}")`.
+    ///
+    /// If you do not like the default built expression, it can be overwritten
+    /// by calling the `named` method after the call to the `extracting_each`
+    /// method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// struct User {
+    ///     name: String,
+    ///     age: u8,
+    /// }
+    ///
+    /// let users = vec![
+    ///     User { name: "alice".into(), age: 27 },
+    ///     User { name: "bob".into(), age: 31 },
+    /// ];
+    ///
+    /// assert_that!(users)
+    ///     .extracting_each(|u| u.name)
+    ///     .contains_exactly(["alice", "bob"]);
+    /// ```
+    ///
+    /// In this example the resulting expression used in failure reports to
+    /// reference the asserted subject is `"the extracted elements of users"`.
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub fn extracting_each<F, U>(self, extract: F) -> Spec<'a, Vec<U>, R>
+    where
+        F: FnMut(<I as IntoIterator>::Item) -> U,
+    {
+        let orig_subject_name = &self.expression;
+        let expression =
+            Expression(format!("the extracted elements of {orig_subject_name}").into());
+        Spec {
+            subject: self
+                .subject
+                .map(|subject| subject.into_iter().map(extract).collect()),
+            expression,
+            description: self.description,
+            describes_all: self.describes_all,
+            context: self.context,
+            location: self.location,
+            failures: self.failures,
+            passed: self.passed,
+            diff_format: self.diff_format,
+            ignores_order: self.ignores_order,
+            failing_strategy: self.failing_strategy,
+        }
+    }
+
+    /// Compares the elements of a collection or an iterator with the given
+    /// closure instead of their `PartialEq` implementation in all following
+    /// containment assertions.
+    ///
+    /// The closure gets a reference to an actual element and a reference to
+    /// an expected element and returns whether the two shall be considered
+    /// equal. This way collections of types that do not implement `PartialEq`
+    /// or types for which only some fields matter can be verified with
+    /// assertions like `contains`, `contains_exactly`,
+    /// `contains_exactly_in_any_order` or `starts_with`.
+    ///
+    /// The returned `Spec` has a `Vec` of the elements wrapped into
+    /// [`ComparedBy`] as the new subject. The order of the elements is
+    /// preserved and failure reports show the elements as formatted by their
+    /// `Debug` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug)]
+    /// struct User {
+    ///     id: u32,
+    ///     name: String,
+    /// }
+    ///
+    /// let users = vec![
+    ///     User { id: 1, name: "alice".into() },
+    ///     User { id: 2, name: "bob".into() },
+    /// ];
+    ///
+    /// let expected_users = [
+    ///     User { id: 1, name: "Alice".into() },
+    ///     User { id: 2, name: "Bob".into() },
+    /// ];
+    ///
+    /// assert_that!(users)
+    ///     .comparing_elements_by(|a, b| a.id == b.id)
+    ///     .contains_exactly(expected_users);
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub fn comparing_elements_by<F>(
+        self,
+        compare: F,
+    ) -> Spec<'a, Vec<ComparedBy<<I as IntoIterator>::Item, F>>, R>
+    where
+        F: Fn(&<I as IntoIterator>::Item, &<I as IntoIterator>::Item) -> bool,
+    {
+        let compare = Rc::new(compare);
+        self.mapping(|subject| ComparedBy::wrap_all(subject, &compare))
+    }
+
+    pub(crate) fn extracting_ref_iter<F, U>(
+        self,
+        property_name: impl Into<Cow<'a, str>>,
+        extract: F,
+    ) -> DerivedSpec<'a, Spec<'a, Vec<<I as IntoIterator>::Item>, R>, Vec<U>>
+    where
+        for<'b> F: Fn(slice::Iter<'b, <I as IntoIterator>::Item>) -> Vec<U>,
+    {
+        let property_name = Expression(property_name.into());
+        let diff_format = self.diff_format.clone();
+        let ignores_order = self.ignores_order;
+        let orig_spec = self.mapping(Vec::from_iter);
+        let new_subject = orig_spec
+            .subject
+            .as_ref()
+            .map(|subject| extract(subject.iter()));
+        DerivedSpec::new(
+            orig_spec,
+            new_subject,
+            property_name,
+            diff_format,
+            ignores_order,
+        )
+    }
+}
+
+/// Trigger failing of an assertion according to the failing strategy of its
+/// implementing spec-like struct.
+pub trait DoFail {
+    /// Fails the assertion with the given [`AssertFailure`]s according to the
+    /// current failing strategy of the `Spec` or other implementing
+    /// spec-like struct.
+    fn do_fail_with(&mut self, failures: impl IntoIterator<Item = AssertFailure>);
+
+    /// Fails the assertion with the given failure message according to the
+    /// current failing strategy of the `Spec` or other implementing
+    /// spec-like struct.
+    fn do_fail_with_message(&mut self, message: impl Into<String>);
+
+    /// Records an assertion that has passed.
+    ///
+    /// The number of passed assertions is used for the summary line of
+    /// grouped failure reports (see [`AssertFailureList`]). The default
+    /// implementation does nothing.
+    fn do_pass(&mut self) {}
+}
+
+impl<S, R> DoFail for Spec<'_, S, R>
+where
+    R: FailingStrategy,
+{
+    fn do_fail_with(&mut self, failures: impl IntoIterator<Item = AssertFailure>) {
+        self.failures.extend(failures);
+        self.end_of_assertion();
+        self.failing_strategy.do_fail_with(&self.failures);
     }
 
     fn do_fail_with_message(&mut self, message: impl Into<String>) {
-        let message = message.into();
+        let mut message = colored::layout_failure_message(message.into(), &self.diff_format);
+        if !self.context.is_empty() {
+            message.push_str("\n   context:");
+            for (key, value) in &self.context {
+                _ = write!(message, "\n     {key}: {value}");
+            }
+        }
+        let (message, highlights) = if self.diff_format.is_html() {
+            let (message, highlights) = colored::extract_highlight_spans(&message);
+            (message, Some(highlights))
+        } else {
+            (message, None)
+        };
         let failure = AssertFailure {
             description: self.description.clone().map(String::from),
             message,
+            expression: Some(self.expression.to_string()),
             location: self.location.map(OwnedLocation::from),
+            highlights,
         };
         self.failures.push(failure);
+        self.end_of_assertion();
         self.failing_strategy.do_fail_with(&self.failures);
     }
+
+    fn do_pass(&mut self) {
+        self.passed += 1;
+        self.end_of_assertion();
+    }
 }
 
 /// Turns assertions into "soft assertions".
@@ -1256,122 +2523,469 @@ impl<S> SoftPanic for Spec<'_, S, CollectFailures> {
     }
 }
 
-/// Chaining another assertion.
-///
-/// Both the previous assertion and the next assertion must be met to pass the
-/// overall assertion.
-pub trait And {
-    /// The return type of the `and()` method.
-    type Output;
+impl<S> SoftPanic for Spec<'_, S, EnsureChecked> {
+    fn soft_panic(&self) {
+        self.failing_strategy.failures_checked();
+        if !self.failures.is_empty() {
+            PanicOnFail.do_fail_with(&self.failures);
+        }
+    }
+}
 
-    /// Express explicitly that another assertion must be met to pass the
-    /// overall assertion.
+impl<'a, S, R> Spec<'a, S, R>
+where
+    R: FailingStrategy,
+{
+    /// Executes a group of assertions on the current subject under a shared
+    /// description.
     ///
-    /// Note: assertions can be changed anyway without calling this `and`
-    /// method. So in most cases, this method does nothing and just offers a
-    /// different style of writing assertions.
+    /// The closure gets a `Spec` for the current subject, which collects the
+    /// failures of all assertions executed inside the closure. The failures
+    /// are prefixed with the description of the group and indented. After the
+    /// closure has returned, the failures of the group are reported according
+    /// to the failing strategy of this `Spec`. Thus, all assertions of a group
+    /// are executed even if this `Spec` panics on failures.
     ///
-    /// In combination with the [`Spec::extracting_ref`] the `and` method can be
-    /// used to chain multiple assertions on the original subject, instead of
-    /// the extracted one.
+    /// Groups can be nested to produce structured failure reports for
+    /// multistep assertions on one subject.
     ///
     /// # Examples
     ///
-    /// Calling the `and` method on the original subject is optional and just
-    /// a question of style how one wants to write assertions.
-    ///
     /// ```
     /// use asserting::prelude::*;
     ///
-    /// let subject = "the answer to all important questions in the universe is 42";
+    /// let header = "Content-Type: text/plain";
     ///
-    /// assert_that(subject).is_not_empty()
-    ///     .and().contains("answer to all important questions")
-    ///     .and().ends_with("42");
+    /// let failures = verify_that!(header)
+    ///     .group("parsing the header", |spec| {
+    ///         spec.starts_with("Content-Length").ends_with("text/html")
+    ///     })
+    ///     .display_failures();
     ///
-    /// // the same assertions can be written without calling `and()`:
+    /// assert_eq!(failures, &[
+    ///     r#"parsing the header:
+    ///   expected header to start with "Content-Length"
+    ///      but was: "Content-Type: text/plain"
+    ///     expected: "Content-Length"
     ///
-    /// assert_that(subject).is_not_empty()
-    ///     .contains("answer to all important questions")
-    ///     .ends_with("42");
+    ///   expected header to end with "text/html"
+    ///      but was: "Content-Type: text/plain"
+    ///     expected: "text/html"
+    /// "#
+    /// ]);
     /// ```
+    #[allow(clippy::return_self_not_must_use)]
+    #[track_caller]
+    pub fn group<F>(self, description: impl Into<Cow<'a, str>>, assertions: F) -> Self
+    where
+        F: FnOnce(Spec<'a, S, CollectFailures>) -> Spec<'a, S, CollectFailures>,
+    {
+        let Self {
+            subject,
+            expression,
+            description: spec_description,
+            describes_all,
+            context,
+            location,
+            failures,
+            passed,
+            diff_format,
+            ignores_order,
+            failing_strategy,
+        } = self;
+        let group_spec = assertions(Spec {
+            subject,
+            expression: expression.clone(),
+            description: None,
+            describes_all: false,
+            context: Vec::new(),
+            location,
+            failures: Vec::new(),
+            passed: 0,
+            diff_format: diff_format.clone(),
+            ignores_order,
+            failing_strategy: CollectFailures,
+        });
+        let mut spec = Self {
+            subject: group_spec.subject,
+            expression,
+            description: spec_description,
+            describes_all,
+            context,
+            location,
+            failures,
+            passed: passed + group_spec.passed,
+            diff_format,
+            ignores_order,
+            failing_strategy,
+        };
+        if !group_spec.failures.is_empty() {
+            let group_description = description.into();
+            let group_message = group_spec.failures.iter().fold(
+                format!("{group_description}:"),
+                |mut message, failure| {
+                    message.push('\n');
+                    for line in failure.to_string().lines() {
+                        if !line.is_empty() {
+                            message.push_str("  ");
+                            message.push_str(line);
+                        }
+                        message.push('\n');
+                    }
+                    message
+                },
+            );
+            spec.do_fail_with_message(group_message.trim_end());
+        } else {
+            spec.end_of_assertion();
+        }
+        spec
+    }
+
+    /// Executes nested assertions on a property of the current subject and
+    /// continues with the current subject afterward.
     ///
-    /// In combination with the [`Spec::extracting_ref`] method, the `and` method
-    /// switches back to the original subject to chain multiple assertions on
-    /// different extracted fields.
+    /// The extracting closure gets a reference to the current subject as an
+    /// argument and should return a reference to the property to be asserted.
+    /// The assertions closure gets a `Spec` for the extracted property, which
+    /// collects the failures of all assertions executed inside the closure.
+    /// After the closure has returned, the failures are reported according to
+    /// the failing strategy of this `Spec`, and the returned `Spec` still
+    /// holds the original subject for further chained assertions.
     ///
-    /// ```
-    /// use asserting::prelude::*;
+    /// The expression for failure reports of the nested assertions is equal to
+    /// `format!("the extracted property of {original_expression}")`. It can be
+    /// overwritten by calling the `named` method on the `Spec` inside the
+    /// assertions closure.
     ///
-    /// #[derive(Debug, Clone, Copy, PartialEq)]
-    /// enum Gender {
-    ///     Male,
-    ///     Female,
-    ///     NonBinary,
-    ///     PreferNotToSay,
-    /// }
+    /// In contrast to [`extracting_ref`], there is no need to call `and` to
+    /// switch back to the original subject.
     ///
-    /// struct Person {
-    ///     name: String,
-    ///     age: u8,
-    ///     gender: Gender,
-    /// }
+    /// # Arguments
     ///
-    /// impl Person {
-    ///     fn name(&self) -> &str {
-    ///         &self.name
-    ///     }
+    /// * `extract` - A closure that returns a reference to the property to be
+    ///   asserted.
+    /// * `assertions` - A closure that executes assertions on the extracted
+    ///   property.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug)]
+    /// struct Order {
+    ///     customer: String,
+    ///     items: Vec<&'static str>,
     /// }
     ///
-    /// let my_friend = Person {
-    ///     name: "Silvia".into(),
-    ///     age: 27,
-    ///     gender: Gender::Female,
+    /// let order = Order {
+    ///     customer: "Bob".into(),
+    ///     items: vec!["apple", "pear"],
     /// };
     ///
-    /// assert_that!(my_friend)
-    ///     .extracting_ref("name", Person::name)
-    ///     .is_equal_to("Silvia")
-    ///     .and()
-    ///     .extracting_ref("age", |p| &p.age)
-    ///     .is_at_least(18)
-    ///     .and()
-    ///     .extracting_ref("gender", |p| &p.gender)
-    ///     .is_equal_to(Gender::Female);
+    /// assert_that!(order)
+    ///     .which(|o| &o.customer, |c| c.named("customer").has_length(3))
+    ///     .which(|o| &o.items, |i| i.contains("pear"))
+    ///     .satisfies(|o| o.items.len() == 2);
     /// ```
     ///
-    /// The specified property name helps in case of a failing assertion as the
-    /// failure report references the more detailed name, such as
-    /// `my_friend.name` or `my_friend.age`, instead of just `subject`.
-    #[must_use = "calling the `and` method without calling another assertion method is useless"]
-    fn and(self) -> Self::Output;
-}
-
-impl<S, R> And for Spec<'_, S, R> {
-    type Output = Self;
-
-    fn and(self) -> Self::Output {
+    /// [`extracting_ref`]: Self::extracting_ref
+    #[allow(clippy::return_self_not_must_use)]
+    #[track_caller]
+    pub fn which<F, P, U, A, B>(mut self, extract: F, assertions: A) -> Self
+    where
+        F: FnOnce(&S) -> &P,
+        P: ToOwned<Owned = U> + ?Sized,
+        A: FnOnce(Spec<'a, U, CollectFailures>) -> B,
+        B: GetFailures,
+    {
+        let Some(subject) = &self.subject else {
+            return self;
+        };
+        let property = extract(subject).to_owned();
+        let orig_subject_name = &self.expression;
+        let property_spec = Spec {
+            subject: Some(property),
+            expression: Expression(format!("the extracted property of {orig_subject_name}").into()),
+            description: self.description.clone(),
+            describes_all: true,
+            context: self.context.clone(),
+            location: self.location,
+            failures: Vec::new(),
+            passed: 0,
+            diff_format: self.diff_format.clone(),
+            ignores_order: self.ignores_order,
+            failing_strategy: CollectFailures,
+        };
+        let failures = assertions(property_spec).failures();
+        if failures.is_empty() {
+            self.end_of_assertion();
+        } else {
+            self.do_fail_with(failures);
+        }
         self
     }
 }
 
-/// Verify whether a subject satisfies a given predicate.
-///
-/// A predicate is a function that takes the subject and returns true if the
-/// subject meets certain criteria.
-pub trait Satisfies<S> {
-    /// Asserts whether the given predicate is meet.
-    ///
-    /// A predicate is a function that takes the subject and returns true if the
-    /// subject meets certain criteria.
-    ///
-    /// This method takes a predicate function and calls it as an expectation.
-    /// In case the predicate function returns false, it does fail with a
-    /// generic failure message and according to the current failing strategy of
-    /// this `Spec`.
+impl<'a, S> Spec<'a, S, CollectFailures> {
+    /// Replaces the [`CollectFailures`]-[`FailingStrategy`] of this `Spec`
+    /// with the [`FailAfter`]-[`FailingStrategy`].
     ///
-    /// This method can be used to do simple custom assertions without
-    /// implementing an [`Expectation`] and an assertion trait.
+    /// Failures are collected until their number exceeds the given maximum.
+    /// Then it panics with all failures collected so far.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// let samples = [3, -1, 4, -1, 5, -9, 2, -6];
+    ///
+    /// // panics on the third negative sample, reporting the first three
+    /// verify_that!(samples)
+    ///     .failing_after(2)
+    ///     .each_element(|sample| sample.is_positive());
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub fn failing_after(self, max_failures: usize) -> Spec<'a, S, FailAfter> {
+        self.with_failing_strategy(FailAfter(max_failures))
+    }
+
+    /// Replaces the [`CollectFailures`]-[`FailingStrategy`] of this `Spec`
+    /// with the [`EnsureChecked`]-[`FailingStrategy`].
+    ///
+    /// Failures are collected as usual, but if the `Spec` is dropped while
+    /// holding failures that have never been read via
+    /// [`failures()`](GetFailures::failures),
+    /// [`display_failures()`](GetFailures::display_failures) or
+    /// [`soft_panic()`](SoftPanic::soft_panic), it panics with these failures.
+    /// This prevents failures from being silently lost when the final check
+    /// is forgotten.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    ///
+    /// // panics when the spec is dropped at the end of the statement
+    /// verify_that!(-7)
+    ///     .ensure_checked()
+    ///     .is_positive();
+    /// ```
+    ///
+    /// Reading the failures marks them as checked:
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let failures = verify_that!(-7)
+    ///     .ensure_checked()
+    ///     .is_positive()
+    ///     .failures();
+    ///
+    /// assert_eq!(failures.len(), 1);
+    /// ```
+    #[must_use = "a spec does nothing unless an assertion method is called"]
+    pub fn ensure_checked(self) -> Spec<'a, S, EnsureChecked> {
+        self.with_failing_strategy(EnsureChecked::default())
+    }
+
+    /// Converts this `Spec` into a `Result` that is `Ok(())` if all assertions
+    /// have passed, or an `Err` containing all collected failures otherwise.
+    ///
+    /// This method is only available on `Spec`s with the
+    /// [`CollectFailures`]-[`FailingStrategy`]. It allows using assertions in
+    /// tests that return a `Result` and in validations that should not panic.
+    /// The error type [`AssertFailureList`] implements [`std::error::Error`],
+    /// so it can be propagated with the `?` operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AssertFailureList`] with the failures of all assertions
+    /// that have failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use std::error::Error;
+    ///
+    /// fn validate_answer(answer: i32) -> Result<(), Box<dyn Error>> {
+    ///     verify_that!(answer)
+    ///         .is_positive()
+    ///         .is_equal_to(42)
+    ///         .into_result()?;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(validate_answer(42).is_ok());
+    ///
+    /// let error = validate_answer(-42).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "expected answer to be positive\n   but was: -42\n  expected: > 0\n\
+    ///     \n\
+    ///     expected answer to be equal to 42\n   but was: -42\n  expected: 42\n"
+    /// );
+    /// ```
+    pub fn into_result(self) -> Result<(), AssertFailureList> {
+        if self.failures.is_empty() {
+            Ok(())
+        } else {
+            Err(self.into_failure_list())
+        }
+    }
+
+    /// Converts this `Spec` into an [`AssertFailureList`] containing all
+    /// collected failures and the number of assertions that have passed.
+    ///
+    /// The lists of multiple `Spec`s, e.g. for different subjects, can be
+    /// combined into one list, which reports the failures grouped by subject
+    /// when calling [`soft_panic()`](SoftPanic::soft_panic) on it.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use asserting::prelude::*;
+    /// use asserting::spec::AssertFailureList;
+    ///
+    /// let answer = 41;
+    /// let name = "Alice";
+    ///
+    /// let failures: AssertFailureList = [
+    ///     verify_that!(answer).is_positive().is_equal_to(42).into_failure_list(),
+    ///     verify_that!(name).starts_with("B").ends_with("e").into_failure_list(),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// failures.soft_panic();
+    /// ```
+    pub fn into_failure_list(self) -> AssertFailureList {
+        AssertFailureList {
+            failures: self.failures,
+            passed: self.passed,
+        }
+    }
+}
+
+/// Chaining another assertion.
+///
+/// Both the previous assertion and the next assertion must be met to pass the
+/// overall assertion.
+pub trait And {
+    /// The return type of the `and()` method.
+    type Output;
+
+    /// Express explicitly that another assertion must be met to pass the
+    /// overall assertion.
+    ///
+    /// Note: assertions can be changed anyway without calling this `and`
+    /// method. So in most cases, this method does nothing and just offers a
+    /// different style of writing assertions.
+    ///
+    /// In combination with the [`Spec::extracting_ref`] the `and` method can be
+    /// used to chain multiple assertions on the original subject, instead of
+    /// the extracted one.
+    ///
+    /// # Examples
+    ///
+    /// Calling the `and` method on the original subject is optional and just
+    /// a question of style how one wants to write assertions.
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let subject = "the answer to all important questions in the universe is 42";
+    ///
+    /// assert_that(subject).is_not_empty()
+    ///     .and().contains("answer to all important questions")
+    ///     .and().ends_with("42");
+    ///
+    /// // the same assertions can be written without calling `and()`:
+    ///
+    /// assert_that(subject).is_not_empty()
+    ///     .contains("answer to all important questions")
+    ///     .ends_with("42");
+    /// ```
+    ///
+    /// In combination with the [`Spec::extracting_ref`] method, the `and` method
+    /// switches back to the original subject to chain multiple assertions on
+    /// different extracted fields.
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq)]
+    /// enum Gender {
+    ///     Male,
+    ///     Female,
+    ///     NonBinary,
+    ///     PreferNotToSay,
+    /// }
+    ///
+    /// struct Person {
+    ///     name: String,
+    ///     age: u8,
+    ///     gender: Gender,
+    /// }
+    ///
+    /// impl Person {
+    ///     fn name(&self) -> &str {
+    ///         &self.name
+    ///     }
+    /// }
+    ///
+    /// let my_friend = Person {
+    ///     name: "Silvia".into(),
+    ///     age: 27,
+    ///     gender: Gender::Female,
+    /// };
+    ///
+    /// assert_that!(my_friend)
+    ///     .extracting_ref("name", Person::name)
+    ///     .is_equal_to("Silvia")
+    ///     .and()
+    ///     .extracting_ref("age", |p| &p.age)
+    ///     .is_at_least(18)
+    ///     .and()
+    ///     .extracting_ref("gender", |p| &p.gender)
+    ///     .is_equal_to(Gender::Female);
+    /// ```
+    ///
+    /// The specified property name helps in case of a failing assertion as the
+    /// failure report references the more detailed name, such as
+    /// `my_friend.name` or `my_friend.age`, instead of just `subject`.
+    #[must_use = "calling the `and` method without calling another assertion method is useless"]
+    fn and(self) -> Self::Output;
+}
+
+impl<S, R> And for Spec<'_, S, R> {
+    type Output = Self;
+
+    fn and(self) -> Self::Output {
+        self
+    }
+}
+
+/// Verify whether a subject satisfies a given predicate.
+///
+/// A predicate is a function that takes the subject and returns true if the
+/// subject meets certain criteria.
+pub trait Satisfies<S> {
+    /// Asserts whether the given predicate is meet.
+    ///
+    /// A predicate is a function that takes the subject and returns true if the
+    /// subject meets certain criteria.
+    ///
+    /// This method takes a predicate function and calls it as an expectation.
+    /// In case the predicate function returns false, it does fail with a
+    /// generic failure message and according to the current failing strategy of
+    /// this `Spec`.
+    ///
+    /// This method can be used to do simple custom assertions without
+    /// implementing an [`Expectation`] and an assertion trait.
     ///
     /// # Examples
     ///
@@ -1440,6 +3054,56 @@ pub trait Satisfies<S> {
     fn satisfies_with_message<P>(self, message: impl Into<String>, predicate: P) -> Self
     where
         P: Fn(&S) -> bool;
+
+    /// Asserts whether the given predicate is meet, where the predicate
+    /// explains why it is not meet.
+    ///
+    /// The predicate is a function that takes the subject and returns
+    /// `Ok(())` if the subject meets certain criteria. Otherwise, it returns an
+    /// error that describes why the subject does not meet the criteria.
+    ///
+    /// In case the predicate function returns an error, it does fail with a
+    /// failure message containing the error and according to the current
+    /// failing strategy of this `Spec`.
+    ///
+    /// This method can be used to write reusable checks that provide detailed
+    /// diagnostics without implementing an [`Expectation`] and an assertion
+    /// trait.
+    ///
+    /// It is provided for all implementations of this trait that also
+    /// implement [`Expecting`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// fn is_odd(value: &i32) -> Result<(), String> {
+    ///     if value & 1 == 1 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(format!("{value} is divisible by 2"))
+    ///     }
+    /// }
+    ///
+    /// assert_that!(37).satisfies_with(is_odd);
+    ///
+    /// let failures = verify_that!(22).satisfies_with(is_odd).display_failures();
+    ///
+    /// assert_that!(failures).contains_exactly([
+    ///     "expected 22 to satisfy the given predicate, but failed: 22 is divisible by 2\n"
+    /// ]);
+    /// ```
+    #[allow(clippy::return_self_not_must_use)]
+    #[track_caller]
+    fn satisfies_with<P, E>(self, predicate: P) -> Self
+    where
+        Self: Expecting<S> + Sized,
+        P: Fn(&S) -> Result<(), E>,
+        E: Display,
+    {
+        self.expecting(satisfies_returning_result(predicate))
+    }
 }
 
 impl<S, R> Satisfies<S> for Spec<'_, S, R>
@@ -1466,7 +3130,7 @@ where
 pub trait Expecting<S> {
     /// Asserts the given expectation.
     ///
-    /// In case the expectation is not meet, the assertion fails, according to
+    /// In case the expectation is not met, the assertion fails according to
     /// the current failing strategy of this `Spec`.
     ///
     /// This method is called from the implementations of the assertion traits
@@ -1476,141 +3140,981 @@ pub trait Expecting<S> {
     /// # Examples
     ///
     /// ```
-    /// use asserting::expectations::{IsEmpty, IsEqualTo};
+    /// use asserting::expectations::{IsEmpty, IsEqualTo};
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(7 * 6).expecting(IsEqualTo {expected: 42 });
+    ///
+    /// assert_that!("").expecting(IsEmpty);
+    /// ```
+    #[allow(clippy::needless_pass_by_value, clippy::return_self_not_must_use)]
+    #[track_caller]
+    fn expecting(self, expectation: impl Expectation<S>) -> Self;
+
+    /// Asserts that the given expectation is NOT met.
+    ///
+    /// In contrast to wrapping an expectation into the [`Not`] combinator, this
+    /// method works with any expectation, even if it does not implement the
+    /// [`Invertible`] trait. The failure message is generated from the message
+    /// of the given expectation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::expectations::{IsEmpty, IsEqualTo};
+    /// use asserting::prelude::*;
+    ///
+    /// assert_that!(7 * 6).not_expecting(IsEqualTo { expected: 41 });
+    ///
+    /// assert_that!("lorem").not_expecting(IsEmpty);
+    /// ```
+    ///
+    /// [`Not`]: crate::expectations::Not
+    #[allow(clippy::return_self_not_must_use)]
+    #[track_caller]
+    fn not_expecting(self, expectation: impl Expectation<S>) -> Self
+    where
+        Self: Sized,
+    {
+        self.expecting(negate(expectation))
+    }
+}
+
+impl<S, R> Expecting<S> for Spec<'_, S, R>
+where
+    R: FailingStrategy,
+{
+    fn expecting(self, expectation: impl Expectation<S>) -> Self {
+        self.expecting_with_context(WithoutContext(expectation))
+    }
+}
+
+impl<S, R> Spec<'_, S, R>
+where
+    R: FailingStrategy,
+{
+    /// Asserts the given expectation, which can make use of the metadata of
+    /// this `Spec` to form its failure message.
+    ///
+    /// In case the expectation is not met, the assertion fails according to
+    /// the current failing strategy of this `Spec`.
+    ///
+    /// See [`ExpectationWithContext`] for an example.
+    #[allow(clippy::needless_pass_by_value, clippy::return_self_not_must_use)]
+    #[track_caller]
+    pub fn expecting_with_context(
+        mut self,
+        mut expectation: impl ExpectationWithContext<S>,
+    ) -> Self {
+        let Some(subject) = &self.subject else {
+            return self;
+        };
+        let evaluation = expectation.try_test(subject);
+        let passed = evaluation == Ok(true);
+        let metadata = SpecMetadata {
+            expression: &self.expression,
+            description: self.description.as_deref(),
+            location: self.location,
+            subject_type_name: any::type_name::<S>(),
+            diff_format: &self.diff_format,
+        };
+        #[cfg(feature = "std")]
+        trace::trace_expectation(&expectation, &metadata, subject, &evaluation);
+        #[cfg(feature = "std")]
+        listener::notify_listeners(&expectation, &metadata, subject, &evaluation);
+        let failure = (!passed).then(|| {
+            let message = match evaluation {
+                Ok(_) => expectation.message(&metadata, subject, false),
+                Err(error) => format_eval_error(&self.expression, &error),
+            };
+            assertion_id::tag_failure_message(message, &expectation.assertion_id(), &metadata)
+        });
+        match failure {
+            Some(message) => self.do_fail_with_message(message),
+            None => self.do_pass(),
+        }
+        self
+    }
+}
+
+/// Access the location of an assertion in the source code or test code.
+pub trait GetLocation<'a> {
+    /// Returns the location in source code or test code if it has been set.
+    fn location(&self) -> Option<Location<'a>>;
+}
+
+impl<'a, S, R> GetLocation<'a> for Spec<'a, S, R> {
+    fn location(&self) -> Option<Location<'a>> {
+        self.location
+    }
+}
+
+/// Access the assertion-failures collected by a `Spec` or spec-like struct.
+pub trait GetFailures {
+    /// Returns whether there are assertion failures collected so far.
+    fn has_failures(&self) -> bool;
+
+    /// Returns the assertion failures that have been collected so far.
+    fn failures(&self) -> Vec<AssertFailure>;
+
+    /// Returns the assertion failures collected so far as formatted text.
+    fn display_failures(&self) -> Vec<String>;
+}
+
+impl<S, R> GetFailures for Spec<'_, S, R>
+where
+    R: FailingStrategy,
+{
+    fn has_failures(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    fn failures(&self) -> Vec<AssertFailure> {
+        self.failing_strategy.failures_checked();
+        self.failures.clone()
+    }
+
+    fn display_failures(&self) -> Vec<String> {
+        self.failing_strategy.failures_checked();
+        self.failures.iter().map(ToString::to_string).collect()
+    }
+}
+
+/// An error describing a failed assertion.
+///
+/// This struct implements the [`std::error::Error`] trait. With the crate
+/// feature `serde` enabled, it also implements `serde::Serialize` and
+/// `serde::Deserialize`, so that collected failures can be persisted and
+/// post-processed by other tools.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssertFailure {
+    pub(crate) description: Option<String>,
+    pub(crate) message: String,
+    pub(crate) expression: Option<String>,
+    pub(crate) location: Option<OwnedLocation>,
+    /// The highlighted spans of the message, if the failure has been rendered
+    /// in HTML mode.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub(crate) highlights: Option<Vec<HighlightSpan>>,
+}
+
+/// A span of a failure message that is highlighted as a difference between
+/// the actual and the expected value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct HighlightSpan {
+    pub(crate) range: Range<usize>,
+    pub(crate) kind: HighlightKind,
+}
+
+/// The kind of difference a [`HighlightSpan`] marks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum HighlightKind {
+    /// An unexpected part of the actual value.
+    Unexpected,
+    /// A missing part of the expected value.
+    Missing,
+}
+
+impl Display for AssertFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.highlights.is_some() {
+            return writeln!(f, "{}", self.to_html());
+        }
+        match &self.description {
+            None => {
+                writeln!(f, "{}", self.message)?;
+            },
+            Some(description) => {
+                writeln!(f, "{description}\n{}", self.message)?;
+            },
+        }
+        Ok(())
+    }
+}
+
+impl StdError for AssertFailure {}
+
+#[allow(clippy::must_use_candidate)]
+impl AssertFailure {
+    /// Returns the description of the assertion that failed.
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+
+    /// Returns the failure message of the assertion that failed.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the expression or name of the subject of the assertion that
+    /// failed.
+    pub fn expression(&self) -> Option<&str> {
+        self.expression.as_deref()
+    }
+
+    /// Returns the location of the assertion in the source code / test code if
+    /// it has been set in the [`Spec`].
+    pub fn location(&self) -> Option<&OwnedLocation> {
+        self.location.as_ref()
+    }
+
+    /// Renders this failure as an HTML fragment for tools that display rich
+    /// output.
+    ///
+    /// The description and the message are HTML-escaped. If the failure has
+    /// been rendered with the diff format [`DIFF_FORMAT_HTML`] (highlight mode
+    /// `html`), differences are rendered as `<del>` elements for unexpected
+    /// parts and as `<ins>` elements for missing parts. Failures rendered in
+    /// any other diff format are rendered without highlighted differences,
+    /// and ANSI escape sequences are removed from them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "colored"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "colored")]
+    /// # fn main() {
+    /// use asserting::colored::DIFF_FORMAT_HTML;
+    /// use asserting::prelude::*;
+    ///
+    /// let failures = verify_that!(vec![1, 2, 3])
+    ///     .with_diff_format(DIFF_FORMAT_HTML)
+    ///     .is_equal_to(vec![1, 2, 4])
+    ///     .failures();
+    ///
+    /// assert_eq!(
+    ///     failures[0].to_html(),
+    ///     r#"<div class="asserting-failure">
+    /// <pre>expected vec![1, 2, 3] to be equal to [1, 2, 4]
+    ///    but was: [1, 2, <del>3</del>]
+    ///   expected: [1, 2, <ins>4</ins>]</pre>
+    /// </div>"#
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [`DIFF_FORMAT_HTML`]: crate::colored::DIFF_FORMAT_HTML
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<div class=\"asserting-failure\">\n");
+        if let Some(description) = &self.description {
+            html.push_str("<p class=\"asserting-description\">");
+            push_html_escaped(&mut html, &strip_ansi_escape_sequences(description));
+            html.push_str("</p>\n");
+        }
+        html.push_str("<pre>");
+        match &self.highlights {
+            Some(highlights) => {
+                let mut position = 0;
+                for span in highlights {
+                    let tag = match span.kind {
+                        HighlightKind::Unexpected => "del",
+                        HighlightKind::Missing => "ins",
+                    };
+                    push_html_escaped(&mut html, &self.message[position..span.range.start]);
+                    _ = write!(html, "<{tag}>");
+                    push_html_escaped(&mut html, &self.message[span.range.clone()]);
+                    _ = write!(html, "</{tag}>");
+                    position = span.range.end;
+                }
+                push_html_escaped(&mut html, &self.message[position..]);
+            },
+            None => push_html_escaped(&mut html, &strip_ansi_escape_sequences(&self.message)),
+        }
+        html.push_str("</pre>\n</div>");
+        html
+    }
+}
+
+/// Appends the given text HTML-escaped to the given HTML string.
+fn push_html_escaped(html: &mut String, text: &str) {
+    for character in text.chars() {
+        match character {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            _ => html.push(character),
+        }
+    }
+}
+
+/// Removes ANSI escape sequences, like the ones used for colors, from the
+/// given text.
+fn strip_ansi_escape_sequences(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut characters = text.chars().peekable();
+    while let Some(character) = characters.next() {
+        if character != '\u{1b}' {
+            stripped.push(character);
+            continue;
+        }
+        if characters.next_if_eq(&'[').is_some() {
+            // a control sequence ends with a character in the range `@` to `~`
+            for character in characters.by_ref() {
+                if ('@'..='~').contains(&character) {
+                    break;
+                }
+            }
+        } else {
+            characters.next();
+        }
+    }
+    stripped
+}
+
+/// An error containing all failures of assertions that have failed.
+///
+/// It is returned by [`Spec::into_result()`] if at least one assertion has
+/// failed. The lists of multiple `Spec`s can be combined into one list via
+/// [`Spec::into_failure_list()`] and collecting or extending.
+///
+/// If all failures stem from the same subject, its [`Display`] implementation
+/// formats all failures in the same way as the panic message of
+/// [`SoftPanic::soft_panic()`] on a `Spec`. If the failures stem from
+/// different subjects, the failures are grouped by the expression and the
+/// location of the subject. Each group has a header, and the failure messages
+/// are indented beneath it. The report ends with a summary line, like
+/// "3 of 7 assertions failed".
+///
+/// This struct implements the [`std::error::Error`] trait. With the crate
+/// feature `serde` enabled, it also implements `serde::Serialize` and
+/// `serde::Deserialize`.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssertFailureList {
+    failures: Vec<AssertFailure>,
+    passed: usize,
+}
+
+impl Display for AssertFailureList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = self.groups();
+        if groups.len() <= 1 {
+            let mut failures = self.failures.iter();
+            if let Some(first) = failures.next() {
+                write!(f, "{first}")?;
+            }
+            for failure in failures {
+                write!(f, "\n{failure}")?;
+            }
+            return Ok(());
+        }
+        for ((expression, location), failures) in groups {
+            match (expression, location) {
+                (Some(expression), Some(location)) => writeln!(f, "{expression} at {location}:")?,
+                (Some(expression), None) => writeln!(f, "{expression}:")?,
+                (None, Some(location)) => writeln!(f, "at {location}:")?,
+                (None, None) => writeln!(f, "unnamed subject:")?,
+            }
+            for failure in failures {
+                for line in failure.to_string().lines() {
+                    if !line.is_empty() {
+                        write!(f, "  {line}")?;
+                    }
+                    writeln!(f)?;
+                }
+                writeln!(f)?;
+            }
+        }
+        writeln!(
+            f,
+            "{} of {} assertions failed",
+            self.failures.len(),
+            self.assertions_count()
+        )
+    }
+}
+
+type FailureGroupKey<'a> = (Option<&'a str>, Option<&'a OwnedLocation>);
+
+impl AssertFailureList {
+    /// Groups the failures by the expression and location of the subject,
+    /// preserving the order in which the groups first occur.
+    fn groups(&self) -> Vec<(FailureGroupKey<'_>, Vec<&AssertFailure>)> {
+        let mut groups: Vec<(FailureGroupKey<'_>, Vec<&AssertFailure>)> = Vec::new();
+        for failure in &self.failures {
+            let key = (failure.expression(), failure.location());
+            if let Some((_, group)) = groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+                group.push(failure);
+            } else {
+                groups.push((key, vec![failure]));
+            }
+        }
+        groups
+    }
+}
+
+impl StdError for AssertFailureList {}
+
+#[allow(clippy::must_use_candidate)]
+impl AssertFailureList {
+    /// Returns the failures of the assertions that have failed.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn failures(&self) -> &[AssertFailure] {
+        &self.failures
+    }
+
+    /// Converts this list into a `Vec` of the failures.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_failures(self) -> Vec<AssertFailure> {
+        self.failures
+    }
+
+    /// Returns the number of assertions that have been executed, including
+    /// the ones that have passed.
+    pub fn assertions_count(&self) -> usize {
+        self.passed + self.failures.len()
+    }
+}
+
+impl Extend<Self> for AssertFailureList {
+    fn extend<T: IntoIterator<Item = Self>>(&mut self, iter: T) {
+        for list in iter {
+            self.failures.extend(list.failures);
+            self.passed += list.passed;
+        }
+    }
+}
+
+impl FromIterator<Self> for AssertFailureList {
+    fn from_iter<T: IntoIterator<Item = Self>>(iter: T) -> Self {
+        let mut list = Self::default();
+        list.extend(iter);
+        list
+    }
+}
+
+impl SoftPanic for AssertFailureList {
+    #[track_caller]
+    fn soft_panic(&self) {
+        assert!(self.failures.is_empty(), "{self}");
+    }
+}
+
+impl IntoIterator for AssertFailureList {
+    type Item = AssertFailure;
+    type IntoIter = vec::IntoIter<AssertFailure>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.failures.into_iter()
+    }
+}
+
+/// Start and end tag that marks a highlighted part of a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Highlight {
+    pub(crate) start: &'static str,
+    pub(crate) end: &'static str,
+}
+
+/// Definition of format properties for highlighting differences between two
+/// values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffFormat {
+    pub(crate) unexpected: Highlight,
+    pub(crate) missing: Highlight,
+    pub(crate) granularity: DiffGranularity,
+    pub(crate) truncation: Option<Truncation>,
+    pub(crate) pretty_debug: bool,
+    pub(crate) display_formatting: bool,
+    pub(crate) vertical_layout: bool,
+    pub(crate) output_format: OutputFormat,
+}
+
+impl DiffFormat {
+    /// Returns a builder to define a diff format with custom highlight
+    /// markers.
+    ///
+    /// The markers can be any strings, like ANSI escape sequences or plain
+    /// characters for logs and terminals that do not support ANSI escape
+    /// sequences. Markers that are not set explicitly are empty.
+    ///
+    /// Differences are only highlighted if the crate feature `colored` is
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "colored"))]
+    /// # fn main() {}
+    /// # #[cfg(feature = "colored")]
+    /// # fn main() {
+    /// use asserting::prelude::*;
+    /// use asserting::spec::DiffFormat;
+    ///
+    /// const DIFF_FORMAT_BRACKETS: DiffFormat = DiffFormat::builder()
+    ///     .unexpected("⟦", "⟧")
+    ///     .missing("⟨", "⟩")
+    ///     .build();
+    ///
+    /// let failures = verify_that("retries: 3")
+    ///     .with_diff_format(DIFF_FORMAT_BRACKETS)
+    ///     .is_equal_to("retries: 5")
+    ///     .display_failures();
+    ///
+    /// assert_that!(failures).contains_exactly([
+    ///     r#"expected subject to be equal to "retries: 5"
+    ///    but was: "retries: ⟦3⟧"
+    ///   expected: "retries: ⟨5⟩"
+    /// "#,
+    /// ]);
+    /// # }
+    /// ```
+    pub const fn builder() -> DiffFormatBuilder {
+        DiffFormatBuilder {
+            unexpected: Highlight { start: "", end: "" },
+            missing: Highlight { start: "", end: "" },
+            granularity: DiffGranularity::Char,
+            truncation: None,
+            pretty_debug: false,
+            display_formatting: false,
+            vertical_layout: false,
+            output_format: OutputFormat::Default,
+        }
+    }
+
+    /// Returns this diff format with the given granularity in which
+    /// differences are highlighted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use asserting::spec::DiffGranularity;
+    ///
+    /// let diff_format = DIFF_FORMAT_NO_HIGHLIGHT.with_granularity(DiffGranularity::Word);
+    ///
+    /// assert_eq!(diff_format.granularity(), DiffGranularity::Word);
+    /// ```
+    #[must_use]
+    pub const fn with_granularity(mut self, granularity: DiffGranularity) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    /// Returns the granularity in which differences are highlighted.
+    pub const fn granularity(&self) -> DiffGranularity {
+        self.granularity
+    }
+
+    /// Returns this diff format with the given truncation, which elides parts
+    /// of large values that are equal in the actual and the expected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    /// use asserting::spec::Truncation;
+    ///
+    /// let subject: Vec<i32> = (1..=1000).collect();
+    /// let mut expected = subject.clone();
+    /// expected[500] = 0;
+    ///
+    /// let failures = verify_that(subject)
+    ///     .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_truncation(Truncation::new(100, 3)))
+    ///     .contains_exactly(expected)
+    ///     .display_failures();
+    ///
+    /// assert_that!(&failures[0]).contains("… 494 more items …");
+    /// ```
+    #[must_use]
+    pub const fn with_truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
+
+    /// Returns the truncation of large values, if any.
+    pub const fn truncation(&self) -> Option<Truncation> {
+        self.truncation
+    }
+
+    /// Returns this diff format with pretty debug formatting switched on.
+    ///
+    /// Values are formatted using the alternate debug format (`{:#?}`), which
+    /// renders nested structs and collections on multiple lines with indented
+    /// fields. Continuation lines are indented to align with the first line of
+    /// the value in the failure message, and differences are highlighted per
+    /// line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Pos {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let failures = verify_that(Pos { x: 1, y: 2 })
+    ///     .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_pretty_debug())
+    ///     .is_equal_to(Pos { x: 1, y: 3 })
+    ///     .display_failures();
+    ///
+    /// assert_eq!(failures, &[r"expected subject to be equal to Pos { x: 1, y: 3 }
+    ///    but was: Pos {
+    ///                 x: 1,
+    ///                 y: 2,
+    ///             }
+    ///   expected: Pos {
+    ///                 x: 1,
+    ///                 y: 3,
+    ///             }
+    /// "]);
+    /// ```
+    #[must_use]
+    pub const fn with_pretty_debug(mut self) -> Self {
+        self.pretty_debug = true;
+        self
+    }
+
+    /// Returns whether values are formatted using the alternate debug format
+    /// (`{:#?}`).
+    pub const fn is_pretty_debug(&self) -> bool {
+        self.pretty_debug
+    }
+
+    /// Returns this diff format with display formatting switched on.
+    ///
+    /// Assertions that are implemented for types that implement `Display`
+    /// render the actual and the expected value using their `Display` format
+    /// instead of their `Debug` format. This avoids quotes and escaped
+    /// characters in failure messages for string-like values. This applies to
+    /// `is_equal_to` on strings, like `String` and `&str`, and to the
+    /// assertions on the content of strings, like `contains` or
+    /// `starts_with`. Assertions on types that do not implement `Display` are
+    /// not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let failures = verify_that("say \"hello\"")
+    ///     .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_display_formatting())
+    ///     .is_similar_to("say \"hallo\"", 0)
+    ///     .display_failures();
+    ///
+    /// assert_eq!(failures, &[r#"expected subject to be similar to "say \"hallo\""
+    ///   within an edit distance of 0
+    ///    but was: say "hello"
+    ///   expected: say "hallo"
+    ///   distance: 1
+    /// "#]);
+    /// ```
+    #[must_use]
+    pub const fn with_display_formatting(mut self) -> Self {
+        self.display_formatting = true;
+        self
+    }
+
+    /// Returns whether values are formatted using their `Display` format where
+    /// the asserted types support it.
+    pub const fn is_display_formatting(&self) -> bool {
+        self.display_formatting
+    }
+
+    /// Returns the quote that encloses strings in failure messages, which is
+    /// none if display formatting is switched on.
+    pub(crate) const fn string_quote(&self) -> &'static str {
+        if self.display_formatting { "" } else { "\"" }
+    }
+
+    /// Returns the quote that encloses characters in failure messages, which
+    /// is none if display formatting is switched on.
+    pub(crate) const fn char_quote(&self) -> &'static str {
+        if self.display_formatting { "" } else { "'" }
+    }
+
+    /// Returns whether failures are rendered as HTML fragments, which is the
+    /// case for the diff format `DIFF_FORMAT_HTML`.
+    pub(crate) fn is_html(&self) -> bool {
+        self.unexpected.start == colored::SPAN_UNEXPECTED_START
+    }
+
+    /// Returns this diff format with the vertical layout switched on.
+    ///
+    /// In the vertical layout, the actual and the expected value in failure
+    /// messages are printed on their own lines below the labels `but was:` and
+    /// `expected:`. Each line of the actual value is marked with a `<` in the
+    /// left gutter and each line of the expected value with a `>`. Together
+    /// with pretty debug formatting, this makes long values easier to compare
+    /// line by line. The values of other labels, like `missing:` or `extra:`,
+    /// are printed on their own lines as well, without a gutter marker.
+    ///
+    /// The layout is applied to every failure message of a spec, including
+    /// the messages of custom assertions, as long as their labels are
+    /// right-aligned on the colon like the labels of this crate. The lines of
+    /// a multi-line value must be indented like a pretty formatted value to be
+    /// recognized as part of the value. The context added with
+    /// [`Spec::with_context()`] is never laid out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use asserting::prelude::*;
+    ///
+    /// let failures = verify_that(vec![1, 2, 3])
+    ///     .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_vertical_layout())
+    ///     .is_equal_to(vec![1, 2, 4])
+    ///     .display_failures();
+    ///
+    /// assert_eq!(failures, &[r"expected subject to be equal to [1, 2, 4]
+    ///    but was:
+    /// < [1, 2, 3]
+    ///   expected:
+    /// > [1, 2, 4]
+    /// "]);
+    /// ```
+    #[must_use]
+    pub const fn with_vertical_layout(mut self) -> Self {
+        self.vertical_layout = true;
+        self
+    }
+
+    /// Returns whether the actual and the expected value are printed in the
+    /// vertical layout.
+    pub const fn is_vertical_layout(&self) -> bool {
+        self.vertical_layout
+    }
+
+    /// Returns this diff format with the given output format of failure
+    /// messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
     /// use asserting::prelude::*;
+    /// use asserting::spec::OutputFormat;
     ///
-    /// assert_that!(7 * 6).expecting(IsEqualTo {expected: 42 });
+    /// let failures = verify_that(41)
+    ///     .named("answer")
+    ///     .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT.with_output_format(OutputFormat::Tagged))
+    ///     .is_equal_to(42)
+    ///     .display_failures();
     ///
-    /// assert_that!("").expecting(IsEmpty);
+    /// assert_eq!(failures, &[r#"[asserting::is_equal_to] expression="answer" subject_type="i32"
+    /// expected answer to be equal to 42
+    ///    but was: 41
+    ///   expected: 42
+    /// "#]);
     /// ```
-    #[allow(clippy::needless_pass_by_value, clippy::return_self_not_must_use)]
-    #[track_caller]
-    fn expecting(self, expectation: impl Expectation<S>) -> Self;
-}
-
-impl<S, R> Expecting<S> for Spec<'_, S, R>
-where
-    R: FailingStrategy,
-{
-    fn expecting(mut self, mut expectation: impl Expectation<S>) -> Self {
-        if !expectation.test(&self.subject) {
-            let message =
-                expectation.message(&self.expression, &self.subject, false, &self.diff_format);
-            self.do_fail_with_message(message);
-        }
+    #[must_use]
+    pub const fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = output_format;
         self
     }
+
+    /// Returns the output format of failure messages.
+    pub const fn output_format(&self) -> OutputFormat {
+        self.output_format
+    }
 }
 
-/// Access the location of an assertion in the source code or test code.
-pub trait GetLocation<'a> {
-    /// Returns the location in source code or test code if it has been set.
-    fn location(&self) -> Option<Location<'a>>;
+/// Builder for a [`DiffFormat`] with custom highlight markers.
+///
+/// A builder is obtained by calling [`DiffFormat::builder()`].
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffFormatBuilder {
+    unexpected: Highlight,
+    missing: Highlight,
+    granularity: DiffGranularity,
+    truncation: Option<Truncation>,
+    pretty_debug: bool,
+    display_formatting: bool,
+    vertical_layout: bool,
+    output_format: OutputFormat,
 }
 
-impl<'a, S, R> GetLocation<'a> for Spec<'a, S, R> {
-    fn location(&self) -> Option<Location<'a>> {
-        self.location
+impl DiffFormatBuilder {
+    /// Sets the markers that are put before and after parts of the actual
+    /// value that are not expected.
+    pub const fn unexpected(mut self, start: &'static str, end: &'static str) -> Self {
+        self.unexpected = Highlight { start, end };
+        self
     }
-}
 
-/// Access the assertion-failures collected by a `Spec` or spec-like struct.
-pub trait GetFailures {
-    /// Returns whether there are assertion failures collected so far.
-    fn has_failures(&self) -> bool;
+    /// Sets the markers that are put before and after parts of the expected
+    /// value that are missing in the actual value.
+    pub const fn missing(mut self, start: &'static str, end: &'static str) -> Self {
+        self.missing = Highlight { start, end };
+        self
+    }
 
-    /// Returns the assertion failures that have been collected so far.
-    fn failures(&self) -> Vec<AssertFailure>;
+    /// Sets the granularity in which differences are highlighted.
+    pub const fn granularity(mut self, granularity: DiffGranularity) -> Self {
+        self.granularity = granularity;
+        self
+    }
 
-    /// Returns the assertion failures collected so far as formatted text.
-    fn display_failures(&self) -> Vec<String>;
-}
+    /// Sets the truncation of large values.
+    pub const fn truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
 
-impl<S, R> GetFailures for Spec<'_, S, R> {
-    fn has_failures(&self) -> bool {
-        !self.failures.is_empty()
+    /// Switches on the alternate debug format (`{:#?}`) for values.
+    pub const fn pretty_debug(mut self) -> Self {
+        self.pretty_debug = true;
+        self
     }
 
-    fn failures(&self) -> Vec<AssertFailure> {
-        self.failures.clone()
+    /// Switches on the `Display` format for values where supported.
+    pub const fn display_formatting(mut self) -> Self {
+        self.display_formatting = true;
+        self
     }
 
-    fn display_failures(&self) -> Vec<String> {
-        self.failures.iter().map(ToString::to_string).collect()
+    /// Switches on the vertical layout for the actual and the expected value.
+    pub const fn vertical_layout(mut self) -> Self {
+        self.vertical_layout = true;
+        self
     }
-}
 
-/// An error describing a failed assertion.
-///
-/// This struct implements the [`std::error::Error`] trait.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AssertFailure {
-    description: Option<String>,
-    message: String,
-    location: Option<OwnedLocation>,
-}
+    /// Sets the output format of failure messages.
+    pub const fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = output_format;
+        self
+    }
 
-impl Display for AssertFailure {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.description {
-            None => {
-                writeln!(f, "{}", self.message)?;
-            },
-            Some(description) => {
-                writeln!(f, "{description}\n{}", self.message)?;
-            },
+    /// Builds the [`DiffFormat`].
+    #[must_use]
+    pub const fn build(self) -> DiffFormat {
+        DiffFormat {
+            unexpected: self.unexpected,
+            missing: self.missing,
+            granularity: self.granularity,
+            truncation: self.truncation,
+            pretty_debug: self.pretty_debug,
+            display_formatting: self.display_formatting,
+            vertical_layout: self.vertical_layout,
+            output_format: self.output_format,
         }
-        Ok(())
     }
 }
 
-impl StdError for AssertFailure {}
+/// Configuration for eliding parts of large values in failure messages.
+///
+/// A value is truncated if it consists of more than `max_length` items,
+/// entries or characters. Only parts that are equal in the actual and the
+/// expected value are elided. Differing parts are always visible, surrounded
+/// by `context` items (respectively characters) on each side. The first and
+/// the last `context` items of a value are kept as well. Each elided part is
+/// replaced by a marker like `… 4321 more items …`.
+///
+/// If all items of a value are highlighted, the items in the middle are
+/// elided, keeping the first and the last `context` items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Truncation {
+    max_length: usize,
+    context: usize,
+}
 
-#[allow(clippy::must_use_candidate)]
-impl AssertFailure {
-    /// Returns the description of the assertion that failed.
-    pub fn description(&self) -> Option<&String> {
-        self.description.as_ref()
+impl Truncation {
+    /// Constructs a new `Truncation` for values longer than `max_length`,
+    /// keeping `context` items around differences.
+    #[must_use]
+    pub const fn new(max_length: usize, context: usize) -> Self {
+        Self {
+            max_length,
+            context,
+        }
     }
 
-    /// Returns the failure message of the assertion that failed.
-    #[allow(clippy::missing_const_for_fn)]
-    pub fn message(&self) -> &str {
-        &self.message
+    /// Returns the length of a value above which the value is truncated.
+    pub const fn max_length(&self) -> usize {
+        self.max_length
     }
 
-    /// Returns the location of the assertion in the source code / test code if
-    /// it has been set in the [`Spec`].
-    pub fn location(&self) -> Option<&OwnedLocation> {
-        self.location.as_ref()
+    /// Returns the number of items kept around differences.
+    pub const fn context(&self) -> usize {
+        self.context
     }
 }
 
-/// Start and end tag that marks a highlighted part of a string.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct Highlight {
-    pub(crate) start: &'static str,
-    pub(crate) end: &'static str,
+/// The granularity in which differences between the actual and the expected
+/// value are highlighted.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffGranularity {
+    /// Compares the values character by character.
+    ///
+    /// With crate feature `unicode` enabled, the values are compared by
+    /// extended grapheme clusters, so that an emoji or a letter with combining
+    /// marks is never split in the middle.
+    #[default]
+    Char,
+    /// Compares the values word by word.
+    ///
+    /// A word is a sequence of alphanumeric characters or underscores. A
+    /// sequence of whitespace is compared as one token and every other
+    /// character, like punctuation, as a token of its own.
+    Word,
+    /// Compares the values line by line.
+    Line,
 }
 
-/// Definition of format properties for highlighting differences between two
-/// values.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct DiffFormat {
-    pub(crate) unexpected: Highlight,
-    pub(crate) missing: Highlight,
+/// The output format of failure messages.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputFormat {
+    /// Failure messages are meant to be read by humans.
+    #[default]
+    Default,
+    /// Every failure message starts with a line that can be parsed by tools,
+    /// like log scrapers or analyzers of flaky tests.
+    ///
+    /// The line starts with a stable identifier of the failed assertion in
+    /// square brackets, like `[asserting::is_equal_to]`, followed by
+    /// structured fields in the form `name="value"`:
+    ///
+    /// | field          | content                                                   |
+    /// |----------------|-----------------------------------------------------------|
+    /// | `expression`   | the expression or name of the subject                     |
+    /// | `subject_type` | the type name of the subject                              |
+    /// | `location`     | the location of the assertion in the code, if it is known |
+    ///
+    /// The identifier is provided by the failed expectation (see
+    /// [`Expectation::ASSERTION_ID`]). It consists of the name of the crate
+    /// that defines the assertion and the name of the assertion method. An
+    /// inverted expectation is identified like `[asserting::not(is_equal_to)]`.
+    /// Custom expectations that do not define an identifier are identified as
+    /// `[custom]`.
+    Tagged,
 }
 
 /// Defines the behavior when an assertion fails.
 ///
-/// This crate provides two implementations:
+/// This crate provides six implementations:
 ///
 /// * [`PanicOnFail`] - panics when an assertion fails
 /// * [`CollectFailures`] - collects [`AssertFailure`]s of assertions that have failed.
+/// * [`FailAfter`] - collects [`AssertFailure`]s until their number exceeds a
+///   threshold and then panics.
+/// * [`EnsureChecked`] - collects [`AssertFailure`]s and panics if they are
+///   dropped without ever being checked.
+/// * [`ForwardFailures`] - forwards [`AssertFailure`]s of assertions that have
+///   failed to a [`FailureSink`].
+/// * [`LogFailures`] - appends [`AssertFailure`]s of assertions that have
+///   failed to a log file or writer (requires crate feature `std`).
 pub trait FailingStrategy {
     /// Reacts to an assertion that has failed with the [`AssertFailure`]s given
     /// as argument.
     fn do_fail_with(&self, failures: &[AssertFailure]);
+
+    /// Notifies the strategy that the failures collected so far have been
+    /// read, e.g. via [`GetFailures::failures`]. The default implementation
+    /// does nothing.
+    fn failures_checked(&self) {}
 }
 
 /// [`FailingStrategy`] that panics when an assertion fails.
@@ -1625,6 +4129,8 @@ impl FailingStrategy for PanicOnFail {
             .map(ToString::to_string)
             .collect::<Vec<_>>()
             .join("\n");
+        #[cfg(feature = "std")]
+        crate::panic_hook::mark_assertion_panic(failures.last().and_then(AssertFailure::location));
         panic!("{}", message);
     }
 }
@@ -1639,6 +4145,360 @@ impl FailingStrategy for CollectFailures {
     }
 }
 
+/// [`FailingStrategy`] that collects the failures from failing assertions
+/// until the number of failures exceeds the contained threshold. Then it
+/// panics with all failures collected so far.
+///
+/// This is useful for checking many values, like in property-style loops,
+/// where a few failures give enough context and the test should be stopped
+/// early.
+///
+/// A `Spec` with this strategy is usually constructed by calling
+/// [`failing_after`](Spec::failing_after) on a `Spec` started with
+/// [`verify_that!`](crate::verify_that).
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+///
+/// let failures = verify_that!(-7)
+///     .with_failing_strategy(FailAfter(2))
+///     .is_positive()
+///     .is_greater_than(-5)
+///     .failures();
+///
+/// assert_eq!(failures.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailAfter(pub usize);
+
+impl FailingStrategy for FailAfter {
+    #[track_caller]
+    fn do_fail_with(&self, failures: &[AssertFailure]) {
+        if failures.len() > self.0 {
+            PanicOnFail.do_fail_with(failures);
+        }
+    }
+}
+
+/// [`FailingStrategy`] that collects the failures from failing assertions
+/// like [`CollectFailures`], but panics when it is dropped while holding
+/// failures that have never been checked.
+///
+/// Failures count as checked once they are read via
+/// [`failures()`](GetFailures::failures),
+/// [`display_failures()`](GetFailures::display_failures) or
+/// [`soft_panic()`](SoftPanic::soft_panic). Failures of assertions executed
+/// after that must be checked again.
+///
+/// A `Spec` with this strategy is usually constructed by calling
+/// [`ensure_checked`](Spec::ensure_checked) on a `Spec` started with
+/// [`verify_that!`](crate::verify_that).
+#[derive(Default, Debug)]
+pub struct EnsureChecked {
+    unchecked: RefCell<Vec<AssertFailure>>,
+}
+
+impl FailingStrategy for EnsureChecked {
+    fn do_fail_with(&self, failures: &[AssertFailure]) {
+        // the given failures contain all failures collected by the spec so far
+        self.unchecked.replace(failures.to_vec());
+    }
+
+    fn failures_checked(&self) {
+        self.unchecked.borrow_mut().clear();
+    }
+}
+
+impl Drop for EnsureChecked {
+    fn drop(&mut self) {
+        let unchecked = self.unchecked.get_mut();
+        if unchecked.is_empty() {
+            return;
+        }
+        #[cfg(feature = "std")]
+        if std::thread::panicking() {
+            return;
+        }
+        let message = unchecked
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        panic!("dropped a spec with failures that have never been checked:\n\n{message}");
+    }
+}
+
+/// A receiver of [`AssertFailure`]s that are forwarded by the
+/// [`ForwardFailures`]-[`FailingStrategy`].
+///
+/// With the `std` feature enabled, this trait is implemented for
+/// `Arc<Mutex<Vec<AssertFailure>>>` and for the sending half of a channel
+/// ([`Sender`](std::sync::mpsc::Sender) and
+/// [`SyncSender`](std::sync::mpsc::SyncSender)). Custom harnesses can
+/// implement it for their own collectors.
+pub trait FailureSink {
+    /// Receives one failure of an assertion that has failed.
+    fn receive(&self, failure: AssertFailure);
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl FailureSink for Arc<Mutex<Vec<AssertFailure>>> {
+    fn receive(&self, failure: AssertFailure) {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(failure);
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl FailureSink for mpsc::Sender<AssertFailure> {
+    fn receive(&self, failure: AssertFailure) {
+        // a disconnected receiver is not interested in failures anymore
+        _ = self.send(failure);
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl FailureSink for mpsc::SyncSender<AssertFailure> {
+    fn receive(&self, failure: AssertFailure) {
+        // a disconnected receiver is not interested in failures anymore
+        _ = self.send(failure);
+    }
+}
+
+/// [`FailingStrategy`] that forwards the failures from failing assertions to
+/// a [`FailureSink`].
+///
+/// The sink can be shared between threads and test stages, like an
+/// `Arc<Mutex<Vec<AssertFailure>>>` or the sending half of a channel. This
+/// way custom harnesses can gather the failures of many assertions and
+/// decide at the end how to report them. Each failure is forwarded exactly
+/// once.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use asserting::spec::ForwardFailures;
+/// use std::sync::mpsc;
+/// use std::thread;
+///
+/// let (sender, receiver) = mpsc::channel();
+///
+/// let handles = (1..=3)
+///     .map(|n| {
+///         let sender = sender.clone();
+///         thread::spawn(move || {
+///             verify_that!(n)
+///                 .with_failing_strategy(ForwardFailures::new(sender))
+///                 .is_at_most(2);
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// drop(sender);
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// let failures = receiver.iter().map(|failure| failure.message().to_string()).collect::<Vec<_>>();
+/// assert_eq!(failures, ["expected n to be at most 2\n   but was: 3\n  expected: <= 2"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ForwardFailures<K> {
+    sink: K,
+    forwarded: Cell<usize>,
+}
+
+impl<K> ForwardFailures<K> {
+    /// Constructs a new `ForwardFailures` strategy, which forwards failures to
+    /// the given sink.
+    #[must_use]
+    pub const fn new(sink: K) -> Self {
+        Self {
+            sink,
+            forwarded: Cell::new(0),
+        }
+    }
+
+    /// Returns the sink the failures are forwarded to.
+    pub const fn sink(&self) -> &K {
+        &self.sink
+    }
+}
+
+impl<K> FailingStrategy for ForwardFailures<K>
+where
+    K: FailureSink,
+{
+    fn do_fail_with(&self, failures: &[AssertFailure]) {
+        // the given failures contain all failures collected by the spec so far
+        let forwarded = self.forwarded.get().min(failures.len());
+        for failure in &failures[forwarded..] {
+            self.sink.receive(failure.clone());
+        }
+        self.forwarded.set(failures.len());
+    }
+}
+
+/// [`FailingStrategy`] that appends each failure of a failing assertion to a
+/// log file or any other writer as soon as it occurs.
+///
+/// Each entry starts with a header line containing the time of the failure in
+/// UTC and the location of the assertion, if it is known, followed by the
+/// formatted failure and an empty line. Errors while writing to the log are
+/// ignored, so that long-running soak or stress tests keep running.
+///
+/// # Examples
+///
+/// ```
+/// use asserting::prelude::*;
+/// use asserting::spec::LogFailures;
+///
+/// let mut log = Vec::new();
+///
+/// for n in 1..=3 {
+///     verify_that!(n)
+///         .with_failing_strategy(LogFailures::new(&mut log))
+///         .is_at_most(2);
+/// }
+///
+/// let log = String::from_utf8(log).unwrap();
+/// assert!(log.contains("expected n to be at most 2\n   but was: 3\n  expected: <= 2\n"));
+/// ```
+///
+/// Appending the failures of many assertions to a file, which is opened only
+/// once. A shared reference to a `File` is a writer as well:
+///
+/// ```no_run
+/// use asserting::prelude::*;
+/// use asserting::spec::LogFailures;
+/// use std::fs::OpenOptions;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let log_file = OpenOptions::new()
+///     .create(true)
+///     .append(true)
+///     .open("target/soak-test-failures.log")?;
+///
+/// for n in 1..=1_000_000 {
+///     verify_that!(n % 7)
+///         .with_failing_strategy(LogFailures::new(&log_file))
+///         .is_not_equal_to(0);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct LogFailures<W> {
+    writer: RefCell<W>,
+    logged: Cell<usize>,
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<W> LogFailures<W> {
+    /// Constructs a new `LogFailures` strategy, which appends failures to the
+    /// given writer.
+    #[must_use]
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer: RefCell::new(writer),
+            logged: Cell::new(0),
+        }
+    }
+
+    /// Returns the writer the failures are appended to.
+    pub fn into_writer(self) -> W {
+        self.writer.into_inner()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl LogFailures<std::fs::File> {
+    /// Constructs a new `LogFailures` strategy, which appends failures to the
+    /// file at the given path.
+    ///
+    /// The file is created if it does not exist. To log the failures of many
+    /// assertions, open the file once and pass a reference to it to
+    /// [`LogFailures::new`] instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can not be opened for appending.
+    pub fn append_to_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map(Self::new)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<W> FailingStrategy for LogFailures<W>
+where
+    W: std::io::Write,
+{
+    fn do_fail_with(&self, failures: &[AssertFailure]) {
+        // the given failures contain all failures collected by the spec so far
+        let logged = self.logged.get().min(failures.len());
+        let mut writer = self.writer.borrow_mut();
+        for failure in &failures[logged..] {
+            let location = failure
+                .location()
+                .map_or_else(|| "unknown location".to_string(), ToString::to_string);
+            let timestamp = format_utc_timestamp(std::time::SystemTime::now());
+            // a failing log must not stop the test
+            _ = writeln!(writer, "[{timestamp}] {location}\n{failure}");
+        }
+        _ = writer.flush();
+        self.logged.set(failures.len());
+    }
+}
+
+/// Formats the given time as an RFC 3339 timestamp in UTC with milliseconds,
+/// like `2025-04-13T08:31:07.042Z`.
+#[cfg(feature = "std")]
+fn format_utc_timestamp(time: std::time::SystemTime) -> String {
+    let since_epoch = time
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (days, seconds_of_day) = (seconds / 86_400, seconds % 86_400);
+    // civil date from days since 1970-01-01, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60,
+        since_epoch.subsec_millis(),
+    )
+}
+
 /// Used with generic types in expectations where the concrete type is not
 /// relevant for the failure message.
 ///
@@ -1708,16 +4568,48 @@ impl Display for Unknown {
 #[cfg_attr(docsrs, doc(cfg(feature = "panic")))]
 pub struct Code<F>(Rc<RefCell<Option<F>>>);
 
+/// The value of an environment variable at the time it was read.
+///
+/// It is the subject of assertions started with
+/// [`assert_that_env!`](crate::assert_that_env) or
+/// [`verify_that_env!`](crate::verify_that_env).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvVar {
+    name: String,
+    value: Result<String, VarError>,
+}
+
+#[cfg(feature = "std")]
+impl EnvVar {
+    /// Reads the environment variable with the given name.
+    pub fn read(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: env::var(name),
+        }
+    }
+
+    /// Returns the name of the environment variable.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value of the environment variable or the error that
+    /// occurred when reading it.
+    pub const fn value(&self) -> &Result<String, VarError> {
+        &self.value
+    }
+}
+
 #[cfg(feature = "panic")]
 mod code {
     use super::Code;
     use crate::std::cell::RefCell;
     use crate::std::rc::Rc;
 
-    impl<F> From<F> for Code<F>
-    where
-        F: FnOnce(),
-    {
+    impl<F> From<F> for Code<F> {
         fn from(value: F) -> Self {
             Self(Rc::new(RefCell::new(Some(value))))
         }
@@ -1,10 +1,15 @@
 use crate::prelude::*;
-use crate::spec::{AssertFailure, Expression, OwnedLocation};
+#[cfg(feature = "std")]
+use crate::spec::format_debug_log;
+use crate::spec::{AssertFailure, AssertFailureList, Expression, OwnedLocation};
 #[cfg(feature = "colored")]
 use crate::std::any::type_name_of_val;
 use crate::std::{
+    borrow::Cow,
     format,
     string::{String, ToString},
+    vec,
+    vec::Vec,
 };
 
 #[test]
@@ -12,6 +17,20 @@ fn default_of_expression_is_subject() {
     assert_that!(&*Expression::default()).is_equal_to("subject");
 }
 
+#[test]
+fn expression_display_format_replaces_line_breaks_with_spaces() {
+    let expression = Expression::from("vec![1, 2, 3]\n.into_iter()\n.sum::<i32>()");
+
+    assert_that!(format!("{}", expression)).is_equal_to("vec![1, 2, 3] .into_iter() .sum::<i32>()");
+}
+
+#[test]
+fn expression_set_by_assert_that_macro_is_not_allocated() {
+    let spec = assert_that!(7 * 6);
+
+    assert_that!(matches!(spec.expression().0, Cow::Borrowed("7 * 6"))).is_true();
+}
+
 #[test]
 fn location_display_format() {
     let location = Location::new("src/my_module/my_test.rs", 54, 13);
@@ -84,7 +103,9 @@ fn assert_failure_display_format() {
     let failure = AssertFailure {
         description: Some("this thing is the best".to_string()),
         message: "but this thing is the worst\ninstead it should be the best".to_string(),
+        expression: None,
         location: Some(OwnedLocation::new("src/thing_module/thing_test.rs", 54, 13)),
+        highlights: None,
     };
 
     assert_that!(format!("{}", failure)).is_equal_to(
@@ -106,6 +127,70 @@ fn mapping_subject_in_spec() {
         .is_equal_to((12, -64));
 }
 
+#[test]
+fn inspecting_subject_in_spec_passes_the_current_subject() {
+    let mut inspected = Vec::new();
+
+    assert_that(vec![5, 3, 4])
+        .mapping(|mut v| {
+            v.sort_unstable();
+            v
+        })
+        .inspecting(|subject| inspected.extend_from_slice(subject))
+        .contains_exactly([3, 4, 5]);
+
+    assert_eq!(inspected, [3, 4, 5]);
+}
+
+#[test]
+fn verify_inspecting_subject_in_spec_does_not_alter_the_assertion_chain() {
+    let failures = verify_that(42)
+        .named("answer")
+        .inspecting(|_| {})
+        .is_equal_to(41)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected answer to be equal to 41
+   but was: 42
+  expected: 41
+"]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn debug_log_subject_in_spec() {
+    assert_that!(vec![1, 2])
+        .debug_log()
+        .contains_exactly([1, 2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn format_debug_log_with_location() {
+    let location = Location::new("src/my_tests.rs", 17, 5);
+
+    let output = format_debug_log(Some(location), &Expression::from("my_pair"), &(1, "two"));
+
+    assert_eq!(
+        output,
+        r#"[src/my_tests.rs:17:5] my_pair = (
+    1,
+    "two",
+)"#
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn format_debug_log_without_location() {
+    let output = format_debug_log(None, &Expression::from("count"), &7);
+
+    assert_eq!(output, "count = 7");
+}
+
 #[cfg(feature = "float-cmp")]
 #[test]
 fn extracting_from_subject_in_spec() {
@@ -128,6 +213,56 @@ fn extracting_from_subject_in_spec() {
         .is_close_to(0.4519);
 }
 
+#[test]
+fn extracting_each_element_of_subject_in_spec() {
+    struct User {
+        name: String,
+        age: u8,
+    }
+
+    let users = vec![
+        User {
+            name: "alice".to_string(),
+            age: 27,
+        },
+        User {
+            name: "bob".to_string(),
+            age: 31,
+        },
+    ];
+
+    assert_that!(&users)
+        .extracting_each(|u| u.age)
+        .contains_exactly([27, 31]);
+
+    assert_that!(users)
+        .extracting_each(|u| u.name)
+        .contains_exactly(["alice", "bob"]);
+}
+
+#[test]
+fn verify_extracting_each_element_of_subject_fails() {
+    let words = ["lorem", "ipsum", "dolor"];
+
+    let failures = verify_that!(words)
+        .extracting_each(str::len)
+        .contains_exactly([5, 4, 5])
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected the extracted elements of words to contain exactly in order [5, 4, 5]
+       but was: [5, 5, 5]
+      expected: [5, 4, 5]
+       missing: [4]
+         extra: [5]
+  out-of-order: []
+"
+        ]
+    );
+}
+
 #[test]
 fn assert_that_macro_with_owned_string_subject() {
     let input_string = String::from("erat esse sit aliqua");
@@ -192,6 +327,37 @@ fn verify_that_option_is_some_chained_with_has_value_fails_as_none() {
     );
 }
 
+#[test]
+fn check_that_returns_true_if_all_assertions_pass() {
+    let answer = 42;
+
+    let passed = check_that!(answer, .is_positive().is_equal_to(42));
+
+    assert_that(passed).is_true();
+}
+
+#[test]
+fn check_that_returns_false_if_any_assertion_fails() {
+    let answer = 42;
+
+    let passed = check_that!(answer, .is_positive().is_equal_to(41).is_at_most(100));
+
+    assert_that(passed).is_false();
+}
+
+#[test]
+fn check_that_returns_whether_assertions_on_mapped_subject_pass() {
+    let maybe_name = Some("Alice");
+
+    assert_that(check_that!(maybe_name, .some().starts_with("A"))).is_true();
+    assert_that(check_that!(maybe_name, .some().starts_with("B"))).is_false();
+}
+
+#[test]
+fn check_that_returns_false_if_mapping_the_subject_fails() {
+    assert!(!check_that!(None::<&str>, .some().starts_with("A")));
+}
+
 #[test]
 fn verify_that_a_subject_with_custom_description_is_equal_to_fails() {
     let an_answer = 51;
@@ -231,6 +397,160 @@ expected answer to be equal to 42
     );
 }
 
+#[test]
+fn described_as_applies_to_the_next_assertion_only() {
+    let subject = 51;
+
+    let failures = verify_that(subject)
+        .described_as("the answer to all important questions is 42")
+        .is_equal_to(42)
+        .and()
+        .is_less_than(50)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"the answer to all important questions is 42
+expected subject to be equal to 42
+   but was: 51
+  expected: 42
+",
+            r"expected subject to be less than 50
+   but was: 51
+  expected: < 50
+",
+        ]
+    );
+}
+
+#[test]
+fn described_as_is_consumed_by_a_passing_assertion() {
+    let subject = 51;
+
+    let failures = verify_that(subject)
+        .described_as("the answer is positive")
+        .is_positive()
+        .is_equal_to(42)
+        .failures();
+
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].description(), None);
+}
+
+#[test]
+fn describe_all_applies_to_all_chained_assertions() {
+    let subject = 51;
+
+    let failures = verify_that(subject)
+        .describe_all("the answer to all important questions is 42")
+        .is_equal_to(42)
+        .and()
+        .is_less_than(50)
+        .failures();
+
+    assert_eq!(failures.len(), 2);
+    for failure in failures {
+        assert_eq!(
+            failure.description().map(String::as_str),
+            Some("the answer to all important questions is 42")
+        );
+    }
+}
+
+#[test]
+fn described_as_replaces_the_description_for_all_assertions() {
+    let subject = 51;
+
+    let failures = verify_that(subject)
+        .describe_all("the answer")
+        .is_equal_to(42)
+        .described_as("the answer must be small")
+        .is_less_than(50)
+        .is_greater_than(60)
+        .failures();
+
+    let descriptions = failures
+        .iter()
+        .map(|failure| failure.description().map(String::as_str))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        descriptions,
+        [Some("the answer"), Some("the answer must be small"), None]
+    );
+}
+
+#[test]
+fn with_context_appends_the_context_to_each_failure() {
+    let input = "lorem";
+    let factor = 3;
+
+    let failures = verify_that(input.len() * factor)
+        .named("product")
+        .with_context("input", input)
+        .with_context("factor", factor)
+        .is_equal_to(12)
+        .is_less_than(10)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected product to be equal to 12
+   but was: 15
+  expected: 12
+   context:
+     input: "lorem"
+     factor: 3
+"#,
+            r#"expected product to be less than 10
+   but was: 15
+  expected: < 10
+   context:
+     input: "lorem"
+     factor: 3
+"#,
+        ]
+    );
+}
+
+#[test]
+fn with_context_is_appended_to_failures_of_each_element() {
+    let failures = verify_that([1, -2, 3])
+        .with_context("case", 4)
+        .each_element(|element| element.is_positive())
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected subject [1] to be positive
+   but was: -2
+  expected: > 0
+   context:
+     case: 4
+"]
+    );
+}
+
+#[test]
+fn with_context_is_appended_once_to_the_failure_of_a_group() {
+    let failures = verify_that(7)
+        .with_context("case", "odd")
+        .group("checking the number", |spec| spec.is_equal_to(8))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"checking the number:
+  expected subject to be equal to 8
+     but was: 7
+    expected: 8
+   context:
+     case: "odd"
+"#]
+    );
+}
+
 #[test]
 fn soft_assertions_with_chained_assertion_methods() {
     let subject = "the answer to all important questions is 42".to_string();
@@ -259,6 +579,286 @@ fn soft_assertions_panic_once_with_multiple_failure_messages() {
         .soft_panic();
 }
 
+#[test]
+fn group_of_passing_assertions_does_not_fail() {
+    let subject = "Content-Type: text/plain";
+
+    assert_that(subject)
+        .group("parsing the header", |spec| {
+            spec.starts_with("Content-Type").ends_with("text/plain")
+        })
+        .has_length(24);
+}
+
+#[test]
+fn verify_group_of_assertions_fails_with_one_failure_prefixed_with_the_group_description() {
+    let subject = "Content-Type: text/plain";
+
+    let failures = verify_that(subject)
+        .named("header")
+        .group("parsing the header", |spec| {
+            spec.starts_with("Content-Length").ends_with("text/html")
+        })
+        .has_length(23)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"parsing the header:
+  expected header to start with "Content-Length"
+     but was: "Content-Type: text/plain"
+    expected: "Content-Length"
+
+  expected header to end with "text/html"
+     but was: "Content-Type: text/plain"
+    expected: "text/html"
+"#,
+            r"expected header to have a length of 23
+   but was: 24
+  expected: 23
+"
+        ]
+    );
+}
+
+#[test]
+fn verify_nested_groups_of_assertions_fail_with_indented_failures() {
+    let subject = "Content-Type: text/plain";
+
+    let failures = verify_that(subject)
+        .named("header")
+        .described_as("the header is valid")
+        .group("parsing the header", |spec| {
+            spec.group("the name", |spec| spec.starts_with("Content-Length"))
+                .group("the value", |spec| spec.ends_with("text/plain"))
+        })
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"the header is valid
+parsing the header:
+  the name:
+    expected header to start with "Content-Length"
+       but was: "Content-Type: text/plain"
+      expected: "Content-Length"
+"#]
+    );
+}
+
+#[test]
+fn group_restores_the_subject_name_after_the_group() {
+    let failures = verify_that(42)
+        .named("answer")
+        .group("renamed", |spec| spec.named("other").is_equal_to(41))
+        .is_equal_to(43)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"renamed:
+  expected other to be equal to 41
+     but was: 42
+    expected: 41
+",
+            r"expected answer to be equal to 43
+   but was: 42
+  expected: 43
+"
+        ]
+    );
+}
+
+#[test]
+#[should_panic = "parsing the header:\n  \
+    expected header to start with \"Content-Length\"\n     \
+       but was: \"Content-Type: text/plain\"\n    \
+      expected: \"Content-Length\"\n\
+    \n  \
+    expected header to end with \"text/html\"\n     \
+       but was: \"Content-Type: text/plain\"\n    \
+      expected: \"text/html\"\n\
+"]
+fn group_executes_all_assertions_before_panicking() {
+    let header = "Content-Type: text/plain";
+
+    assert_that(header)
+        .named("header")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .group("parsing the header", |spec| {
+            spec.starts_with("Content-Length").ends_with("text/html")
+        });
+}
+
+#[test]
+fn into_result_of_spec_without_failures_is_ok() {
+    let result = verify_that(42).is_positive().is_equal_to(42).into_result();
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn into_result_of_spec_with_failures_is_err_containing_all_failures() {
+    let result = verify_that(-42)
+        .named("answer")
+        .is_positive()
+        .is_equal_to(42)
+        .into_result();
+
+    let Err(error) = result else {
+        panic!("expected the result to be an error");
+    };
+
+    assert_eq!(
+        error.failures(),
+        &[
+            AssertFailure {
+                description: None,
+                message: "expected answer to be positive\n   but was: -42\n  expected: > 0"
+                    .to_string(),
+                expression: Some("answer".to_string()),
+                location: None,
+                highlights: None,
+            },
+            AssertFailure {
+                description: None,
+                message: "expected answer to be equal to 42\n   but was: -42\n  expected: 42"
+                    .to_string(),
+                expression: Some("answer".to_string()),
+                location: None,
+                highlights: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn display_of_assert_failure_list_is_the_same_as_the_soft_panic_message() {
+    let Err(error) = verify_that("the answer to all important questions is 42")
+        .contains("unimportant")
+        .has_at_most_length(41)
+        .into_result()
+    else {
+        panic!("expected the result to be an error");
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "expected subject to contain \"unimportant\"\n   \
+           but was: \"the answer to all important questions is 42\"\n  \
+          expected: \"unimportant\"\n\
+        \n\
+        expected subject to have at most a length of 41\n   \
+           but was: 43\n  \
+          expected: <= 41\n\
+    "
+    );
+}
+
+#[test]
+fn assert_failure_list_can_be_converted_into_a_vec_of_failures() {
+    let Err(error) = verify_that(7).is_equal_to(8).is_equal_to(9).into_result() else {
+        panic!("expected the result to be an error");
+    };
+
+    let expected_failures = error.failures().to_vec();
+
+    assert_eq!(error.clone().into_failures(), expected_failures);
+    assert_eq!(error.into_iter().collect::<Vec<_>>(), expected_failures);
+}
+
+#[test]
+fn assert_failure_list_counts_passed_and_failed_assertions() {
+    let failures = verify_that(7)
+        .is_positive()
+        .is_equal_to(8)
+        .is_at_most(9)
+        .into_failure_list();
+
+    assert_eq!(failures.assertions_count(), 3);
+    assert_eq!(failures.failures().len(), 1);
+}
+
+#[test]
+fn assert_failure_list_of_failures_from_different_subjects_is_grouped_by_subject() {
+    let failures: AssertFailureList = [
+        verify_that(41)
+            .named("answer")
+            .located_at(Location::new("tests/answer.rs", 7, 5))
+            .is_positive()
+            .is_equal_to(42)
+            .is_at_least(42)
+            .into_failure_list(),
+        verify_that("Alice")
+            .named("name")
+            .located_at(Location::new("tests/answer.rs", 9, 5))
+            .starts_with("B")
+            .ends_with("e")
+            .into_failure_list(),
+        verify_that(true)
+            .named("flag")
+            .located_at(Location::new("tests/answer.rs", 11, 5))
+            .is_true()
+            .into_failure_list(),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(
+        failures.to_string(),
+        r#"answer at tests/answer.rs:7:5:
+  expected answer to be equal to 42
+     but was: 41
+    expected: 42
+
+  expected answer to be at least 42
+     but was: 41
+    expected: >= 42
+
+name at tests/answer.rs:9:5:
+  expected name to start with "B"
+     but was: "Alice"
+    expected: "B"
+
+3 of 6 assertions failed
+"#
+    );
+}
+
+#[test]
+#[should_panic = "answer:\n  expected answer to be equal to 42\n"]
+fn soft_panic_on_assert_failure_list_of_different_subjects_panics_with_grouped_report() {
+    let failures: AssertFailureList = [
+        verify_that(41)
+            .named("answer")
+            .is_equal_to(42)
+            .into_failure_list(),
+        verify_that("Alice")
+            .named("name")
+            .starts_with("B")
+            .into_failure_list(),
+    ]
+    .into_iter()
+    .collect();
+
+    failures.soft_panic();
+}
+
+#[test]
+fn soft_panic_on_assert_failure_list_without_failures_does_not_panic() {
+    let failures: AssertFailureList = [
+        verify_that(42).is_equal_to(42).into_failure_list(),
+        verify_that("Bob").starts_with("B").into_failure_list(),
+    ]
+    .into_iter()
+    .collect();
+
+    failures.soft_panic();
+    assert_eq!(failures.assertions_count(), 2);
+}
+
 #[cfg(feature = "colored")]
 #[test]
 fn and_called_on_spec_does_nothing() {
@@ -305,3 +905,733 @@ mod colored {
             .soft_panic();
     }
 }
+
+#[test]
+fn fail_after_collects_failures_up_to_the_threshold() {
+    let failures = verify_that(-7)
+        .named("delta")
+        .failing_after(2)
+        .is_positive()
+        .is_greater_than(-5)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            "expected delta to be positive\n   but was: -7\n  expected: > 0\n",
+            "expected delta to be greater than -5\n   but was: -7\n  expected: > -5\n",
+        ]
+    );
+}
+
+#[test]
+#[should_panic = "expected delta to be positive\n   but was: -7\n  expected: > 0\n\n\
+    expected delta to be greater than -5\n   but was: -7\n  expected: > -5\n\n\
+    expected delta to be equal to 0\n   but was: -7\n  expected: 0\n"]
+fn fail_after_panics_when_the_number_of_failures_exceeds_the_threshold() {
+    verify_that(-7)
+        .named("delta")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .failing_after(2)
+        .is_positive()
+        .is_greater_than(-5)
+        .is_equal_to(0)
+        .is_not_equal_to(-7);
+}
+
+#[test]
+fn fail_after_zero_failures_does_not_panic_if_all_assertions_pass() {
+    let failures = verify_that("ipsum")
+        .failing_after(0)
+        .starts_with("ip")
+        .has_length(5)
+        .failures();
+
+    assert_that!(failures).is_empty();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fail_after_stops_asserting_each_element_when_the_threshold_is_exceeded() {
+    use crate::std::cell::Cell;
+    use crate::std::panic::{AssertUnwindSafe, catch_unwind};
+
+    let asserted = Cell::new(0);
+    let samples = [3, -1, 4, -1, 5, -9, 2, -6];
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        verify_that(samples)
+            .named("samples")
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .failing_after(2)
+            .each_element(|sample| {
+                asserted.set(asserted.get() + 1);
+                sample.is_positive()
+            });
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(asserted.get(), 6);
+}
+
+#[test]
+#[should_panic = "dropped a spec with failures that have never been checked:\n\n\
+    expected delta to be positive\n   but was: -7\n  expected: > 0\n"]
+fn ensure_checked_panics_when_dropped_with_unchecked_failures() {
+    verify_that(-7)
+        .named("delta")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .ensure_checked()
+        .is_positive()
+        .is_less_than(0);
+}
+
+#[test]
+fn ensure_checked_does_not_panic_when_failures_have_been_read() {
+    let failures = verify_that(-7)
+        .named("delta")
+        .ensure_checked()
+        .is_positive()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &["expected delta to be positive\n   but was: -7\n  expected: > 0\n"]
+    );
+}
+
+#[test]
+fn ensure_checked_does_not_panic_when_all_assertions_pass() {
+    verify_that("ipsum")
+        .ensure_checked()
+        .starts_with("ip")
+        .has_length(5);
+}
+
+#[test]
+#[should_panic = "dropped a spec with failures that have never been checked:\n\n\
+    expected delta to be positive\n   but was: -7\n  expected: > 0\n\n\
+    expected delta to be equal to 0\n   but was: -7\n  expected: 0\n"]
+fn ensure_checked_panics_when_failures_occur_after_being_read() {
+    let spec = verify_that(-7)
+        .named("delta")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .ensure_checked()
+        .is_positive();
+
+    assert_eq!(spec.failures().len(), 1);
+
+    _ = spec.is_equal_to(0);
+}
+
+#[test]
+#[should_panic = "expected delta to be positive\n   but was: -7\n  expected: > 0\n"]
+fn ensure_checked_soft_panic_panics_with_the_collected_failures() {
+    verify_that(-7)
+        .named("delta")
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .ensure_checked()
+        .is_positive()
+        .soft_panic();
+}
+
+#[cfg(feature = "std")]
+mod forward_failures {
+    use super::*;
+    use crate::spec::ForwardFailures;
+    use crate::std::sync::{Arc, Mutex, mpsc};
+    use crate::std::thread;
+
+    #[test]
+    fn forward_failures_to_shared_vec_forwards_each_failure_once() {
+        let collector = Arc::new(Mutex::new(Vec::new()));
+
+        let spec = verify_that(-3)
+            .named("balance")
+            .with_failing_strategy(ForwardFailures::new(Arc::clone(&collector)))
+            .is_positive()
+            .is_equal_to(-3)
+            .is_at_least(0);
+
+        let forwarded = collector.lock().unwrap_or_else(|_| unreachable!()).clone();
+        assert_eq!(forwarded, spec.failures());
+        assert_eq!(
+            forwarded
+                .iter()
+                .map(AssertFailure::message)
+                .collect::<Vec<_>>(),
+            &[
+                "expected balance to be positive\n   but was: -3\n  expected: > 0",
+                "expected balance to be at least 0\n   but was: -3\n  expected: >= 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn forward_failures_to_channel_gathers_failures_from_multiple_threads() {
+        let (sender, receiver) = mpsc::channel();
+
+        let handles = (1..=4)
+            .map(|number| {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    _ = verify_that(number)
+                        .named("number")
+                        .with_failing_strategy(ForwardFailures::new(sender))
+                        .is_at_most(2);
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(sender);
+        for handle in handles {
+            handle.join().unwrap_or_else(|_| unreachable!());
+        }
+
+        let mut failures = receiver
+            .iter()
+            .map(|failure| failure.message().to_string())
+            .collect::<Vec<_>>();
+        failures.sort();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected number to be at most 2\n   but was: 3\n  expected: <= 2",
+                "expected number to be at most 2\n   but was: 4\n  expected: <= 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn forward_failures_of_a_group_forwards_the_group_failure() {
+        let collector = Arc::new(Mutex::new(Vec::new()));
+
+        _ = verify_that("Content-Type: text/plain")
+            .named("header")
+            .with_failing_strategy(ForwardFailures::new(Arc::clone(&collector)))
+            .group("parsing the header", |spec| {
+                spec.starts_with("Content-Length").ends_with("text/plain")
+            });
+
+        let forwarded = collector.lock().unwrap_or_else(|_| unreachable!()).clone();
+        assert_eq!(forwarded.len(), 1);
+        assert!(forwarded[0].message().starts_with("parsing the header:\n"));
+    }
+}
+
+#[cfg(feature = "std")]
+mod log_failures {
+    use super::*;
+    use crate::spec::{LogFailures, format_utc_timestamp};
+    use crate::std::time::{Duration, UNIX_EPOCH};
+    use crate::std::{env, fs, process};
+
+    #[test]
+    fn format_utc_timestamp_of_unix_epoch() {
+        assert_eq!(format_utc_timestamp(UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+    }
+
+    #[test]
+    fn format_utc_timestamp_with_milliseconds() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_709_210_096_042);
+
+        assert_eq!(format_utc_timestamp(time), "2024-02-29T12:34:56.042Z");
+    }
+
+    #[test]
+    fn log_failures_appends_each_failure_once_with_timestamp_and_location() {
+        let mut log = Vec::new();
+
+        _ = verify_that(-3)
+            .named("balance")
+            .located_at(Location::new("src/soak_test.rs", 12, 5))
+            .with_failing_strategy(LogFailures::new(&mut log))
+            .is_positive()
+            .is_equal_to(-3)
+            .is_at_least(0);
+
+        let log = String::from_utf8(log).unwrap_or_else(|_| unreachable!());
+        let lines = log.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 10);
+        assert!(lines[0].starts_with('['));
+        assert!(lines[0].ends_with("Z] src/soak_test.rs:12:5"));
+        assert_eq!(
+            &lines[1..5],
+            &[
+                "expected balance to be positive",
+                "   but was: -3",
+                "  expected: > 0",
+                "",
+            ]
+        );
+        assert!(lines[5].ends_with("Z] src/soak_test.rs:12:5"));
+        assert_eq!(
+            &lines[6..],
+            &[
+                "expected balance to be at least 0",
+                "   but was: -3",
+                "  expected: >= 0",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn log_failures_appends_to_file() {
+        let path = env::temp_dir().join(format!("asserting-log-failures-{}.log", process::id()));
+        _ = fs::remove_file(&path);
+
+        for number in 1..=3 {
+            _ = verify_that(number)
+                .named("number")
+                .with_failing_strategy(
+                    LogFailures::append_to_file(&path).unwrap_or_else(|_| unreachable!()),
+                )
+                .is_at_most(1);
+        }
+
+        let log = fs::read_to_string(&path).unwrap_or_else(|_| unreachable!());
+        _ = fs::remove_file(&path);
+        assert_eq!(log.matches("Z] unknown location\n").count(), 2);
+        assert!(log.contains("expected number to be at most 1\n   but was: 2\n"));
+        assert!(log.contains("expected number to be at most 1\n   but was: 3\n"));
+    }
+}
+
+#[derive(Debug)]
+struct Order {
+    customer: String,
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn which_executes_nested_assertions_on_a_property_and_continues_with_the_subject() {
+    let order = Order {
+        customer: "Bob".into(),
+        items: vec!["apple", "pear"],
+    };
+
+    assert_that(order)
+        .which(|o| &o.customer, |c| c.has_length(3).starts_with('B'))
+        .which(|o| &o.items, |i| i.contains("pear"))
+        .satisfies(|o| o.items.len() == 2);
+}
+
+#[test]
+fn verify_which_collects_failures_of_nested_assertions_and_subsequent_assertions() {
+    let order = Order {
+        customer: "Alice".into(),
+        items: vec!["apple"],
+    };
+
+    let failures = verify_that(order)
+        .named("order")
+        .which(|o| &o.customer, |c| c.has_length(3).ends_with('b'))
+        .which(|o| &o.items, |i| i.named("order.items").has_length(1))
+        .satisfies_with_message("expected order to have two items", |o| o.items.len() == 2)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected the extracted property of order to have a length of 3
+   but was: 5
+  expected: 3
+",
+            r#"expected the extracted property of order to end with 'b'
+   but was: "Alice"
+  expected: 'b'
+"#,
+            "expected order to have two items\n",
+        ]
+    );
+}
+
+#[test]
+fn which_keeps_the_description_of_the_original_spec() {
+    let failures = verify_that(Order {
+        customer: "Alice".into(),
+        items: vec![],
+    })
+    .described_as("the order is valid")
+    .which(|o| &o.customer, |c| c.named("customer").is_equal_to("Bob"))
+    .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"the order is valid
+expected customer to be equal to "Bob"
+   but was: "Alice"
+  expected: "Bob"
+"#]
+    );
+}
+
+#[test]
+#[should_panic = "expected the extracted property of subject to be empty\n   but was: [\"apple\"]\n  expected: <empty>\n"]
+fn which_panics_if_nested_assertion_fails() {
+    let subject = Order {
+        customer: "Bob".into(),
+        items: vec!["apple"],
+    };
+
+    assert_that(subject)
+        .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+        .which(|o| &o.items, |i| i.is_empty());
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+    use super::*;
+    use serde_test::{Token, assert_tokens};
+
+    #[test]
+    fn owned_location_can_be_serialized_and_deserialized() {
+        let location = OwnedLocation::new("src/my_module/tests.rs", 17, 9);
+
+        assert_tokens(
+            &location,
+            &[
+                Token::Struct {
+                    name: "OwnedLocation",
+                    len: 3,
+                },
+                Token::Str("file"),
+                Token::Str("src/my_module/tests.rs"),
+                Token::Str("line"),
+                Token::U32(17),
+                Token::Str("column"),
+                Token::U32(9),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn assert_failure_can_be_serialized_and_deserialized() {
+        let failure = AssertFailure {
+            description: Some("the answer is correct".into()),
+            message: "expected answer to be equal to 42".into(),
+            expression: Some("answer".into()),
+            location: None,
+            highlights: None,
+        };
+
+        assert_tokens(
+            &failure,
+            &[
+                Token::Struct {
+                    name: "AssertFailure",
+                    len: 4,
+                },
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("the answer is correct"),
+                Token::Str("message"),
+                Token::Str("expected answer to be equal to 42"),
+                Token::Str("expression"),
+                Token::Some,
+                Token::Str("answer"),
+                Token::Str("location"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn assert_failure_list_can_be_serialized_and_deserialized() {
+        let failure_list = verify_that(41)
+            .named("answer")
+            .located_at(Location {
+                file: "src/lib.rs",
+                line: 42,
+                column: 5,
+            })
+            .is_equal_to(42)
+            .is_positive()
+            .into_failure_list();
+
+        assert_tokens(
+            &failure_list,
+            &[
+                Token::Struct {
+                    name: "AssertFailureList",
+                    len: 2,
+                },
+                Token::Str("failures"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "AssertFailure",
+                    len: 4,
+                },
+                Token::Str("description"),
+                Token::None,
+                Token::Str("message"),
+                Token::Str("expected answer to be equal to 42\n   but was: 41\n  expected: 42"),
+                Token::Str("expression"),
+                Token::Some,
+                Token::Str("answer"),
+                Token::Str("location"),
+                Token::Some,
+                Token::Struct {
+                    name: "OwnedLocation",
+                    len: 3,
+                },
+                Token::Str("file"),
+                Token::Str("src/lib.rs"),
+                Token::Str("line"),
+                Token::U32(42),
+                Token::Str("column"),
+                Token::U32(5),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("passed"),
+                Token::U64(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
+
+#[test]
+fn assert_failure_to_html_escapes_description_and_message() {
+    let failure = AssertFailure {
+        description: Some("checks <T> & 'friends'".into()),
+        message: r#"expected subject to be equal to "<b>""#.into(),
+        expression: Some("subject".into()),
+        location: None,
+        highlights: None,
+    };
+
+    assert_eq!(
+        failure.to_html(),
+        r#"<div class="asserting-failure">
+<p class="asserting-description">checks &lt;T&gt; &amp; &#39;friends&#39;</p>
+<pre>expected subject to be equal to &quot;&lt;b&gt;&quot;</pre>
+</div>"#
+    );
+}
+
+#[test]
+fn assert_failure_to_html_removes_ansi_escape_sequences() {
+    let failure = AssertFailure {
+        description: Some("\u{1b}[1mthe answer\u{1b}[0m".into()),
+        message: "   but was: \u{1b}[31m41\u{1b}[0m\n  expected: \u{1b}[32m42\u{1b}[0m".into(),
+        expression: Some("subject".into()),
+        location: None,
+        highlights: None,
+    };
+
+    assert_eq!(
+        failure.to_html(),
+        r#"<div class="asserting-failure">
+<p class="asserting-description">the answer</p>
+<pre>   but was: 41
+  expected: 42</pre>
+</div>"#
+    );
+}
+
+#[cfg(feature = "colored")]
+#[test]
+fn assert_failure_to_html_renders_differences_as_del_and_ins_elements() {
+    let failures = verify_that("retries: 3")
+        .with_diff_format(crate::colored::DIFF_FORMAT_HTML)
+        .is_equal_to("retries: 5")
+        .failures();
+
+    assert_eq!(
+        failures[0].message(),
+        "expected subject to be equal to \"retries: 5\"\n   but was: \"retries: 3\"\n  expected: \"retries: 5\""
+    );
+    assert_eq!(
+        failures[0].to_html(),
+        r#"<div class="asserting-failure">
+<pre>expected subject to be equal to &quot;retries: 5&quot;
+   but was: &quot;retries: <del>3</del>&quot;
+  expected: &quot;retries: <ins>5</ins>&quot;</pre>
+</div>"#
+    );
+}
+
+#[cfg(feature = "colored")]
+#[test]
+fn assert_failure_to_html_does_not_highlight_plain_text_markers_in_the_subject() {
+    let failures = verify_that("«3»")
+        .with_diff_format(crate::colored::DIFF_FORMAT_HTML)
+        .is_equal_to("«5»")
+        .failures();
+
+    assert_eq!(
+        failures[0].to_html(),
+        r#"<div class="asserting-failure">
+<pre>expected subject to be equal to &quot;«5»&quot;
+   but was: &quot;«<del>3</del>»&quot;
+  expected: &quot;«<ins>5</ins>»&quot;</pre>
+</div>"#
+    );
+}
+
+#[cfg(feature = "colored")]
+#[test]
+fn failures_rendered_in_html_mode_are_displayed_as_html_fragments() {
+    let failures = verify_that(41)
+        .named("answer")
+        .with_diff_format(crate::colored::DIFF_FORMAT_HTML)
+        .is_equal_to(42)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"<div class="asserting-failure">
+<pre>expected answer to be equal to 42
+   but was: 4<del>1</del>
+  expected: 4<ins>2</ins></pre>
+</div>
+"#]
+    );
+}
+
+mod expecting_with_context {
+    use super::*;
+    use crate::spec::{ExpectationWithContext, SpecMetadata};
+
+    struct IsEven;
+
+    impl ExpectationWithContext<i32> for IsEven {
+        fn test(&mut self, subject: &i32) -> bool {
+            subject % 2 == 0
+        }
+
+        fn message(&self, metadata: &SpecMetadata<'_>, actual: &i32, _inverted: bool) -> String {
+            let description = metadata.description.unwrap_or("<none>");
+            let location = metadata
+                .location
+                .map_or_else(|| "<unknown>".to_string(), |location| location.to_string());
+            format!(
+                "expected {} of type {} to be even\n   but was: {actual}\n  description: {description}\n  location: {location}",
+                metadata.expression, metadata.subject_type_name,
+            )
+        }
+    }
+
+    #[test]
+    fn contextual_expectation_passes() {
+        assert_that(42).expecting_with_context(IsEven);
+    }
+
+    #[test]
+    fn contextual_expectation_has_access_to_the_spec_metadata() {
+        let failures = verify_that(41)
+            .named("my_number")
+            .described_as("the number must be even")
+            .located_at(Location::new("src/my_tests.rs", 7, 13))
+            .expecting_with_context(IsEven)
+            .display_failures();
+
+        assert_that!(failures).contains_exactly(["the number must be even\n\
+             expected my_number of type i32 to be even\n   \
+             but was: 41\n  \
+             description: the number must be even\n  \
+             location: src/my_tests.rs:7:13\n"]);
+    }
+}
+
+mod eval_error {
+    use super::*;
+    use crate::expectations::{all, not};
+    use crate::spec::{DiffFormat, EvalError, Expectation, Invertible};
+
+    struct IsMultipleOf(i32);
+
+    impl Expectation<i32> for IsMultipleOf {
+        fn test(&mut self, subject: &i32) -> bool {
+            self.try_test(subject).unwrap_or(false)
+        }
+
+        fn try_test(&mut self, subject: &i32) -> Result<bool, EvalError> {
+            subject
+                .checked_rem(self.0)
+                .map(|remainder| remainder == 0)
+                .ok_or_else(|| EvalError::new("the divisor must not be zero"))
+        }
+
+        fn message(
+            &self,
+            expression: &Expression<'_>,
+            actual: &i32,
+            inverted: bool,
+            _format: &DiffFormat,
+        ) -> String {
+            let not = if inverted { "not " } else { "" };
+            format!(
+                "expected {expression} to {not}be a multiple of {}\n   but was: {actual}",
+                self.0
+            )
+        }
+    }
+
+    impl Invertible for IsMultipleOf {}
+
+    #[test]
+    fn expectation_that_can_be_evaluated_passes() {
+        assert_that(42).expecting(IsMultipleOf(7));
+    }
+
+    #[test]
+    fn verify_expectation_that_can_be_evaluated_fails_with_regular_message() {
+        let failures = verify_that(41)
+            .named("answer")
+            .expecting(IsMultipleOf(7))
+            .display_failures();
+
+        assert_that!(failures)
+            .contains_exactly(["expected answer to be a multiple of 7\n   but was: 41\n"]);
+    }
+
+    #[test]
+    fn verify_expectation_that_can_not_be_evaluated_fails_with_evaluation_error() {
+        let failures = verify_that(42)
+            .named("answer")
+            .expecting(IsMultipleOf(0))
+            .display_failures();
+
+        assert_that!(failures).contains_exactly([
+            "could not evaluate the assertion on answer\n  error: the divisor must not be zero\n",
+        ]);
+    }
+
+    #[test]
+    fn inverted_expectation_that_can_not_be_evaluated_does_not_pass() {
+        let failures = verify_that(42)
+            .named("answer")
+            .expecting(not(IsMultipleOf(0)))
+            .display_failures();
+
+        assert_that!(failures).contains_exactly([
+            "could not evaluate the assertion on answer\n  error: the divisor must not be zero\n",
+        ]);
+    }
+
+    #[test]
+    fn combined_expectations_report_the_evaluation_error_of_any_expectation() {
+        let failures = verify_that(42)
+            .named("answer")
+            .expecting(all((IsMultipleOf(7), IsMultipleOf(0))))
+            .display_failures();
+
+        assert_that!(failures).contains_exactly([
+            "could not evaluate the assertion on answer\n  error: the divisor must not be zero\n",
+        ]);
+    }
+
+    #[test]
+    fn eval_error_displays_its_message() {
+        let error = EvalError::new("the divisor must not be zero");
+
+        assert_that(error.message()).is_equal_to("the divisor must not be zero");
+        assert_that(error.to_string()).is_equal_to("the divisor must not be zero");
+    }
+}
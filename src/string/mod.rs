@@ -1,24 +1,35 @@
 //! Implementation of assertions for `String` and `str` values.
 
-use crate::assertions::{AssertStringContainsAnyOf, AssertStringPattern};
+use crate::assertions::{
+    AssertStringContainsAnyOf, AssertStringOccurrences, AssertStringPattern,
+    AssertStringSimilarity, AssertStringWildcard,
+};
 use crate::colored::{
-    mark_missing, mark_missing_char, mark_missing_string,
-    mark_selected_chars_in_string_as_unexpected, mark_selected_items_in_collection,
-    mark_unexpected_char_in_string, mark_unexpected_string, mark_unexpected_substring_in_string,
+    mark_diff, mark_diff_str, mark_missing, mark_missing_char, mark_missing_string,
+    mark_selected_chars_in_string_as_missing, mark_selected_chars_in_string_as_unexpected,
+    mark_selected_items_in_collection, mark_unexpected_char_in_string, mark_unexpected_string,
+    mark_unexpected_substring_in_string,
 };
 use crate::expectations::{
-    StringContains, StringContainsAnyOf, StringEndsWith, StringStartWith, not, string_contains,
-    string_contains_any_of, string_ends_with, string_starts_with,
+    IsSimilarTo, StringContains, StringContainsAnyOf, StringContainsAtMostNTimes,
+    StringContainsNTimes, StringEndsWith, StringMatchesWildcard, StringStartWith, is_similar_to,
+    not, string_contains, string_contains_any_of, string_contains_at_most_n_times,
+    string_contains_n_times, string_ends_with, string_matches_wildcard, string_starts_with,
+};
+use crate::properties::{
+    CapacityProperty, CharCountProperty, DefinedOrderProperty, IsEmptyProperty, LengthProperty,
 };
-use crate::properties::{CharCountProperty, DefinedOrderProperty, IsEmptyProperty, LengthProperty};
 use crate::spec::{
-    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+    DiffFormat, EvalError, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
 };
 use crate::std::fmt::Debug;
+use crate::std::mem;
+use crate::std::ops::Range;
 use crate::std::str::Chars;
 use crate::std::{
     format,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 use hashbrown::HashSet;
@@ -59,6 +70,12 @@ impl CharCountProperty for String {
     }
 }
 
+impl CapacityProperty for String {
+    fn capacity_property(&self) -> usize {
+        self.capacity()
+    }
+}
+
 impl DefinedOrderProperty for Chars<'_> {}
 
 // We implement `AssertContains` for different `Pattern` types as the
@@ -160,6 +177,8 @@ impl<S> Expectation<S> for StringContains<&str>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().contains(self.expected)
     }
@@ -180,8 +199,9 @@ where
             ("", marked_actual)
         };
         let marked_expected = mark_missing_string(self.expected, format);
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}contain {:?}\n   but was: \"{marked_actual}\"\n  expected: {not}\"{marked_expected}\"",
+            "expected {expression} to {not}contain {:?}\n   but was: {quote}{marked_actual}{quote}\n  expected: {not}{quote}{marked_expected}{quote}",
             self.expected,
         )
     }
@@ -193,6 +213,8 @@ impl<S> Expectation<S> for StringContains<String>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().contains(&self.expected)
     }
@@ -213,8 +235,9 @@ where
             ("", marked_actual)
         };
         let marked_expected = mark_missing_string(&self.expected, format);
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}contain {:?}\n   but was: \"{marked_actual}\"\n  expected: {not}\"{marked_expected}\"",
+            "expected {expression} to {not}contain {:?}\n   but was: {quote}{marked_actual}{quote}\n  expected: {not}{quote}{marked_expected}{quote}",
             self.expected,
         )
     }
@@ -226,6 +249,8 @@ impl<S> Expectation<S> for StringContains<char>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().contains(self.expected)
     }
@@ -246,8 +271,10 @@ where
             ("", marked_actual)
         };
         let marked_expected = mark_missing_char(self.expected, format);
+        let quote = format.string_quote();
+        let char_quote = format.char_quote();
         format!(
-            "expected {expression} to {not}contain {:?}\n   but was: \"{marked_actual}\"\n  expected: {not}'{marked_expected}'",
+            "expected {expression} to {not}contain {:?}\n   but was: {quote}{marked_actual}{quote}\n  expected: {not}{char_quote}{marked_expected}{char_quote}",
             self.expected,
         )
     }
@@ -259,6 +286,8 @@ impl<S> Expectation<S> for StringStartWith<&str>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::starts_with";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().starts_with(self.expected)
     }
@@ -284,8 +313,9 @@ where
             .collect::<String>();
         let marked_actual_start = mark_unexpected_string(&actual_start, format);
         let marked_expected = mark_missing_string(self.expected, format);
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}start with {:?}\n   but was: \"{marked_actual_start}{actual_rest}\"\n  expected: {not}\"{marked_expected}\"",
+            "expected {expression} to {not}start with {:?}\n   but was: {quote}{marked_actual_start}{actual_rest}{quote}\n  expected: {not}{quote}{marked_expected}{quote}",
             self.expected,
         )
     }
@@ -297,6 +327,8 @@ impl<S> Expectation<S> for StringStartWith<String>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::starts_with";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().starts_with(&self.expected)
     }
@@ -322,8 +354,9 @@ where
             .collect::<String>();
         let marked_actual_start = mark_unexpected_string(&actual_start, format);
         let marked_expected = mark_missing_string(&self.expected, format);
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}start with {:?}\n   but was: \"{marked_actual_start}{actual_rest}\"\n  expected: {not}\"{marked_expected}\"",
+            "expected {expression} to {not}start with {:?}\n   but was: {quote}{marked_actual_start}{actual_rest}{quote}\n  expected: {not}{quote}{marked_expected}{quote}",
             self.expected,
         )
     }
@@ -335,6 +368,8 @@ impl<S> Expectation<S> for StringStartWith<char>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::starts_with";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().starts_with(self.expected)
     }
@@ -351,8 +386,10 @@ where
         let actual_rest = actual.as_ref().chars().skip(1).collect::<String>();
         let marked_actual_start = mark_unexpected_string(&actual_first_char, format);
         let marked_expected = mark_missing_char(self.expected, format);
+        let quote = format.string_quote();
+        let char_quote = format.char_quote();
         format!(
-            "expected {expression} to {not}start with {:?}\n   but was: \"{marked_actual_start}{actual_rest}\"\n  expected: {not}'{marked_expected}'",
+            "expected {expression} to {not}start with {:?}\n   but was: {quote}{marked_actual_start}{actual_rest}{quote}\n  expected: {not}{char_quote}{marked_expected}{char_quote}",
             self.expected,
         )
     }
@@ -364,6 +401,8 @@ impl<S> Expectation<S> for StringEndsWith<&str>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::ends_with";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().ends_with(self.expected)
     }
@@ -391,8 +430,9 @@ where
             .collect::<String>();
         let marked_actual_end = mark_unexpected_string(&actual_end, format);
         let marked_expected = mark_missing_string(self.expected, format);
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}end with {:?}\n   but was: \"{actual_start}{marked_actual_end}\"\n  expected: {not}\"{marked_expected}\"",
+            "expected {expression} to {not}end with {:?}\n   but was: {quote}{actual_start}{marked_actual_end}{quote}\n  expected: {not}{quote}{marked_expected}{quote}",
             self.expected,
         )
     }
@@ -404,6 +444,8 @@ impl<S> Expectation<S> for StringEndsWith<String>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::ends_with";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().ends_with(&self.expected)
     }
@@ -431,8 +473,9 @@ where
             .collect::<String>();
         let marked_actual_end = mark_unexpected_string(&actual_end, format);
         let marked_expected = mark_missing_string(&self.expected, format);
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}end with {:?}\n   but was: \"{actual_start}{marked_actual_end}\"\n  expected: {not}\"{marked_expected}\"",
+            "expected {expression} to {not}end with {:?}\n   but was: {quote}{actual_start}{marked_actual_end}{quote}\n  expected: {not}{quote}{marked_expected}{quote}",
             self.expected,
         )
     }
@@ -444,6 +487,8 @@ impl<S> Expectation<S> for StringEndsWith<char>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::ends_with";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().ends_with(self.expected)
     }
@@ -466,8 +511,10 @@ where
         actual_start.pop();
         let marked_actual_end = mark_unexpected_string(&actual_last_char, format);
         let marked_expected = mark_missing_char(self.expected, format);
+        let quote = format.string_quote();
+        let char_quote = format.char_quote();
         format!(
-            "expected {expression} to {not}end with {:?}\n   but was: \"{actual_start}{marked_actual_end}\"\n  expected: {not}'{marked_expected}'",
+            "expected {expression} to {not}end with {:?}\n   but was: {quote}{actual_start}{marked_actual_end}{quote}\n  expected: {not}{char_quote}{marked_expected}{char_quote}",
             self.expected,
         )
     }
@@ -527,6 +574,8 @@ impl<S> Expectation<S> for StringContainsAnyOf<&[char]>
 where
     S: AsRef<str> + Debug,
 {
+    const ASSERTION_ID: &'static str = "asserting::contains_any_of";
+
     fn test(&mut self, subject: &S) -> bool {
         subject.as_ref().contains(self.expected)
     }
@@ -573,8 +622,9 @@ where
             let marked_expected = mark_missing(&self.expected, format);
             ("", marked_actual, marked_expected)
         };
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}contain any of {:?}\n   but was: \"{marked_actual}\"\n  expected: {not}{marked_expected}",
+            "expected {expression} to {not}contain any of {:?}\n   but was: {quote}{marked_actual}{quote}\n  expected: {not}{marked_expected}",
             self.expected,
         )
     }
@@ -632,8 +682,9 @@ where
             let marked_expected = mark_missing(&self.expected, format);
             ("", marked_actual, marked_expected)
         };
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}contain any of {:?}\n   but was: \"{marked_actual}\"\n  expected: {not}{marked_expected}",
+            "expected {expression} to {not}contain any of {:?}\n   but was: {quote}{marked_actual}{quote}\n  expected: {not}{marked_expected}",
             self.expected,
         )
     }
@@ -691,8 +742,9 @@ where
             let marked_expected = mark_missing(&self.expected, format);
             ("", marked_actual, marked_expected)
         };
+        let quote = format.string_quote();
         format!(
-            "expected {expression} to {not}contain any of {:?}\n   but was: \"{marked_actual}\"\n  expected: {not}{marked_expected}",
+            "expected {expression} to {not}contain any of {:?}\n   but was: {quote}{marked_actual}{quote}\n  expected: {not}{marked_expected}",
             self.expected,
         )
     }
@@ -706,7 +758,8 @@ mod regex {
     use crate::colored::{mark_missing_string, mark_unexpected_string};
     use crate::expectations::{StringMatches, not, string_matches};
     use crate::spec::{
-        DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+        DiffFormat, EvalError, Expectation, Expecting, Expression, FailingStrategy, Invertible,
+        Spec,
     };
     use crate::std::fmt::Debug;
     use crate::std::format;
@@ -730,8 +783,19 @@ mod regex {
     where
         S: AsRef<str> + Debug,
     {
+        const ASSERTION_ID: &'static str = "asserting::matches";
+
         fn test(&mut self, subject: &S) -> bool {
-            self.regex.is_match(subject.as_ref())
+            self.try_test(subject).unwrap_or(false)
+        }
+
+        fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+            match &self.error {
+                Some(err) => Err(EvalError::new(format!(
+                    "failed to match string with regex: {err}"
+                ))),
+                None => Ok(self.regex.is_match(subject.as_ref())),
+            }
         }
 
         fn message(
@@ -746,7 +810,7 @@ mod regex {
             } else {
                 ("", "does not match")
             };
-            let regex = self.regex.as_str();
+            let regex = self.pattern;
             let marked_actual = mark_unexpected_string(actual.as_ref(), format);
             let marked_expected = mark_missing_string(regex, format);
             format!(
@@ -758,5 +822,418 @@ mod regex {
     impl Invertible for StringMatches<'_> {}
 }
 
+impl<S, R> AssertStringWildcard for Spec<'_, S, R>
+where
+    S: AsRef<str> + Debug,
+    R: FailingStrategy,
+{
+    fn matches_wildcard(self, pattern: &str) -> Self {
+        self.expecting(string_matches_wildcard(pattern))
+    }
+
+    fn does_not_match_wildcard(self, pattern: &str) -> Self {
+        self.expecting(not(string_matches_wildcard(pattern)))
+    }
+}
+
+impl<S> Expectation<S> for StringMatchesWildcard<'_>
+where
+    S: AsRef<str> + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::matches_wildcard";
+
+    fn test(&mut self, subject: &S) -> bool {
+        unmatched_wildcard_segments(subject.as_ref(), self.pattern).is_empty()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let pattern = self.pattern;
+        let marked_actual = mark_unexpected_string(actual.as_ref(), format);
+        if inverted {
+            let marked_expected = mark_missing_string(pattern, format);
+            return format!(
+                "expected {expression} to not match the wildcard pattern {pattern:?}\n   but was: \"{marked_actual}\"\n  expected: not \"{marked_expected}\""
+            );
+        }
+        let unmatched = unmatched_wildcard_segments(actual.as_ref(), pattern);
+        let pattern_chars = pattern.chars().collect::<Vec<_>>();
+        let selected = unmatched
+            .iter()
+            .flat_map(Clone::clone)
+            .collect::<HashSet<_>>();
+        let marked_expected = mark_selected_chars_in_string_as_missing(pattern, &selected, format);
+        let unmatched = unmatched
+            .into_iter()
+            .map(|segment| pattern_chars[segment].iter().collect::<String>())
+            .collect::<Vec<_>>();
+        format!(
+            "expected {expression} to match the wildcard pattern {pattern:?}\n   but was: \"{marked_actual}\"\n  expected: \"{marked_expected}\"\n unmatched: {unmatched:?}"
+        )
+    }
+}
+
+impl Invertible for StringMatchesWildcard<'_> {}
+
+/// Matches a string against a wildcard pattern, where `*` matches any
+/// sequence of chars and `?` matches exactly one char.
+///
+/// The pattern is split at each `*` into segments. Returns the char ranges of
+/// the segments within the pattern that could not be matched. An empty list
+/// means the string matches the pattern.
+fn unmatched_wildcard_segments(string: &str, pattern: &str) -> Vec<Range<usize>> {
+    let string = string.chars().collect::<Vec<_>>();
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let mut segments = Vec::new();
+    let mut segment_start = 0;
+    for (index, chr) in pattern.iter().enumerate() {
+        if *chr == '*' {
+            segments.push(segment_start..index);
+            segment_start = index + 1;
+        }
+    }
+    segments.push(segment_start..pattern.len());
+
+    let matches_at = |segment: &[char], position: usize| {
+        position + segment.len() <= string.len()
+            && segment
+                .iter()
+                .zip(&string[position..])
+                .all(|(expected, actual)| *expected == '?' || expected == actual)
+    };
+
+    let mut unmatched = Vec::new();
+    let first = segments[0].clone();
+    if segments.len() == 1 {
+        if first.len() != string.len() || !matches_at(&pattern[first.clone()], 0) {
+            unmatched.push(first);
+        }
+        return unmatched;
+    }
+    let last = segments[segments.len() - 1].clone();
+    let last_position = string.len().saturating_sub(last.len());
+    let end = if matches_at(&pattern[last.clone()], last_position) {
+        last_position
+    } else {
+        unmatched.push(last);
+        string.len()
+    };
+    let mut start = if first.len() <= end && matches_at(&pattern[first.clone()], 0) {
+        first.len()
+    } else {
+        unmatched.push(first);
+        0
+    };
+    for segment in &segments[1..segments.len() - 1] {
+        let length = segment.len();
+        let found = (start..=end.saturating_sub(length)).find(|&position| {
+            position + length <= end && matches_at(&pattern[segment.clone()], position)
+        });
+        if let Some(position) = found {
+            start = position + length;
+        } else {
+            unmatched.push(segment.clone());
+        }
+    }
+    unmatched.sort_by_key(|segment| segment.start);
+    unmatched
+}
+
+impl<S, E, R> AssertStringSimilarity<E> for Spec<'_, S, R>
+where
+    S: AsRef<str> + Debug,
+    E: AsRef<str> + Debug,
+    R: FailingStrategy,
+{
+    fn is_similar_to(self, expected: E, max_distance: usize) -> Self {
+        self.expecting(is_similar_to(expected, max_distance))
+    }
+}
+
+impl<S, E> Expectation<S> for IsSimilarTo<E>
+where
+    S: AsRef<str> + Debug,
+    E: AsRef<str> + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_similar_to";
+
+    fn test(&mut self, subject: &S) -> bool {
+        levenshtein_distance(subject.as_ref(), self.expected.as_ref()) <= self.max_distance
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = &self.expected;
+        let max_distance = self.max_distance;
+        let distance = levenshtein_distance(actual.as_ref(), expected.as_ref());
+        let (marked_actual, marked_expected) = if format.is_display_formatting() {
+            mark_diff_str(actual.as_ref(), expected.as_ref(), format)
+        } else {
+            mark_diff(actual, expected, format)
+        };
+        format!(
+            "expected {expression} to {not}be similar to {expected:?}\n  within an edit distance of {max_distance}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}\n  distance: {distance}"
+        )
+    }
+}
+
+impl<E> Invertible for IsSimilarTo<E> {}
+
+impl<S, E, R> AssertStringOccurrences<E> for Spec<'_, S, R>
+where
+    S: AsRef<str> + Debug,
+    E: AsRef<str> + Debug,
+    R: FailingStrategy,
+{
+    fn contains_exactly_once(self, needle: E) -> Self {
+        self.expecting(string_contains_n_times(needle, 1))
+    }
+
+    fn contains_n_times(self, needle: E, times: usize) -> Self {
+        self.expecting(string_contains_n_times(needle, times))
+    }
+
+    fn does_not_contain_more_than(self, needle: E, max_times: usize) -> Self {
+        self.expecting(string_contains_at_most_n_times(needle, max_times))
+    }
+}
+
+impl<S, E> Expectation<S> for StringContainsNTimes<E>
+where
+    S: AsRef<str> + Debug,
+    E: AsRef<str> + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::contains_n_times";
+
+    fn test(&mut self, subject: &S) -> bool {
+        self.try_test(subject).unwrap_or(false)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        count_occurrences(subject.as_ref(), self.needle.as_ref()).map(|found| found == self.times)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let needle = &self.needle;
+        let times = format_times(self.times);
+        let (found, marked_actual) = mark_occurrences(actual.as_ref(), needle.as_ref(), format);
+        let found = format_times(found);
+        let quote = format.string_quote();
+        format!(
+            "expected {expression} to contain {needle:?} exactly {times}, but it was found {found}\n   but was: {quote}{marked_actual}{quote}\n  expected: {needle:?} exactly {times}"
+        )
+    }
+}
+
+impl<S, E> Expectation<S> for StringContainsAtMostNTimes<E>
+where
+    S: AsRef<str> + Debug,
+    E: AsRef<str> + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::does_not_contain_more_than";
+
+    fn test(&mut self, subject: &S) -> bool {
+        self.try_test(subject).unwrap_or(false)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        count_occurrences(subject.as_ref(), self.needle.as_ref())
+            .map(|found| found <= self.max_times)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let needle = &self.needle;
+        let max_times = format_times(self.max_times);
+        let (found, marked_actual) = mark_occurrences(actual.as_ref(), needle.as_ref(), format);
+        let found = format_times(found);
+        let quote = format.string_quote();
+        format!(
+            "expected {expression} to contain {needle:?} not more than {max_times}, but it was found {found}\n   but was: {quote}{marked_actual}{quote}\n  expected: {needle:?} at most {max_times}"
+        )
+    }
+}
+
+/// Counts the non-overlapping occurrences of the needle in the actual string.
+///
+/// An empty needle can not be counted meaningfully and results in an error.
+fn count_occurrences(actual: &str, needle: &str) -> Result<usize, EvalError> {
+    if needle.is_empty() {
+        return Err(EvalError::new("the substring to count must not be empty"));
+    }
+    Ok(actual.matches(needle).count())
+}
+
+fn format_times(times: usize) -> String {
+    if times == 1 {
+        "once".to_string()
+    } else {
+        format!("{times} times")
+    }
+}
+
+/// Counts the occurrences of the needle in the actual string and highlights
+/// each of them.
+fn mark_occurrences(actual: &str, needle: &str, format: &DiffFormat) -> (usize, String) {
+    let mut found = 0;
+    let mut selected = HashSet::new();
+    let needle_char_count = needle.chars().count();
+    for (byte_index, _) in actual.match_indices(needle) {
+        found += 1;
+        let start = actual[..byte_index].chars().count();
+        selected.extend(start..start + needle_char_count);
+    }
+    (
+        found,
+        mark_selected_chars_in_string_as_unexpected(actual, &selected, format),
+    )
+}
+
+/// Calculates the Levenshtein edit distance between two strings on chars.
+fn levenshtein_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<_>>();
+    let mut previous_row = (0..=right.len()).collect::<Vec<_>>();
+    let mut current_row = vec![0; right.len() + 1];
+    for (left_index, left_char) in left.chars().enumerate() {
+        current_row[0] = left_index + 1;
+        for (right_index, right_char) in right.iter().enumerate() {
+            let substitution_cost = usize::from(left_char != *right_char);
+            current_row[right_index + 1] = (previous_row[right_index] + substitution_cost)
+                .min(previous_row[right_index + 1] + 1)
+                .min(current_row[right_index] + 1);
+        }
+        mem::swap(&mut previous_row, &mut current_row);
+    }
+    previous_row[right.len()]
+}
+
+#[cfg(feature = "unicode")]
+mod unicode {
+    use crate::assertions::AssertGraphemes;
+    use crate::colored::{
+        mark_missing, mark_missing_string, mark_unexpected, mark_unexpected_string,
+        mark_unexpected_substring_in_string,
+    };
+    use crate::expectations::{
+        HasGraphemeCount, StringContainsGrapheme, has_grapheme_count, not, string_contains_grapheme,
+    };
+    use crate::spec::{
+        DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+    };
+    use crate::std::fmt::Debug;
+    use crate::std::format;
+    use crate::std::string::String;
+    use unicode_segmentation::UnicodeSegmentation;
+
+    impl<'a, S, R> AssertGraphemes<&'a str> for Spec<'a, S, R>
+    where
+        S: 'a + AsRef<str> + Debug,
+        R: FailingStrategy,
+    {
+        fn has_grapheme_count(self, expected_grapheme_count: usize) -> Self {
+            self.expecting(has_grapheme_count(expected_grapheme_count))
+        }
+
+        fn contains_grapheme(self, expected: &'a str) -> Self {
+            self.expecting(string_contains_grapheme(expected))
+        }
+
+        fn does_not_contain_grapheme(self, expected: &'a str) -> Self {
+            self.expecting(not(string_contains_grapheme(expected)))
+        }
+    }
+
+    impl<S> Expectation<S> for HasGraphemeCount
+    where
+        S: AsRef<str> + Debug,
+    {
+        const ASSERTION_ID: &'static str = "asserting::has_grapheme_count";
+
+        fn test(&mut self, subject: &S) -> bool {
+            subject.as_ref().graphemes(true).count() == self.expected_grapheme_count
+        }
+
+        fn message(
+            &self,
+            expression: &Expression<'_>,
+            actual: &S,
+            inverted: bool,
+            format: &DiffFormat,
+        ) -> String {
+            let not = if inverted { "not " } else { "" };
+            let actual_grapheme_count = actual.as_ref().graphemes(true).count();
+            let marked_actual = mark_unexpected(&actual_grapheme_count, format);
+            let marked_expected = mark_missing(&self.expected_grapheme_count, format);
+            format!(
+                "expected {expression} to {not}have a grapheme count of {}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}",
+                self.expected_grapheme_count
+            )
+        }
+    }
+
+    impl Invertible for HasGraphemeCount {}
+
+    impl<S> Expectation<S> for StringContainsGrapheme<&str>
+    where
+        S: AsRef<str> + Debug,
+    {
+        const ASSERTION_ID: &'static str = "asserting::contains_grapheme";
+
+        fn test(&mut self, subject: &S) -> bool {
+            subject
+                .as_ref()
+                .graphemes(true)
+                .any(|grapheme| grapheme == self.expected)
+        }
+
+        fn message(
+            &self,
+            expression: &Expression<'_>,
+            actual: &S,
+            inverted: bool,
+            format: &DiffFormat,
+        ) -> String {
+            let (not, marked_actual) = if inverted {
+                let marked_actual =
+                    mark_unexpected_substring_in_string(actual.as_ref(), self.expected, format);
+                ("not ", marked_actual)
+            } else {
+                let marked_actual = mark_unexpected_string(actual.as_ref(), format);
+                ("", marked_actual)
+            };
+            let marked_expected = mark_missing_string(self.expected, format);
+            let quote = format.string_quote();
+            format!(
+                "expected {expression} to {not}contain the grapheme {:?}\n   but was: {quote}{marked_actual}{quote}\n  expected: {not}{quote}{marked_expected}{quote}",
+                self.expected,
+            )
+        }
+    }
+
+    impl Invertible for StringContainsGrapheme<&str> {}
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,3 +1,4 @@
+use crate::expectations::{is_similar_to, not};
 use crate::prelude::*;
 use crate::std::string::{String, ToString};
 
@@ -1373,6 +1374,306 @@ fn verify_string_does_not_end_with_char_fails() {
     );
 }
 
+#[test]
+fn string_is_similar_to_str_within_max_distance() {
+    let subject: String = "kitten".to_string();
+
+    assert_that(subject).is_similar_to("sitting", 3);
+}
+
+#[test]
+fn str_is_similar_to_string_within_max_distance() {
+    let subject = "Hello, wrold!";
+
+    assert_that(subject).is_similar_to("Hello, world!".to_string(), 2);
+}
+
+#[test]
+fn equal_strings_are_similar_with_max_distance_zero() {
+    assert_that("asserting").is_similar_to("asserting", 0);
+}
+
+#[test]
+fn string_is_similar_to_empty_string_within_its_char_count() {
+    assert_that("äöü").is_similar_to("", 3);
+    assert_that("").is_similar_to("äöü", 3);
+}
+
+#[test]
+fn edit_distance_is_calculated_on_chars_not_bytes() {
+    assert_that("äöü").is_similar_to("aou", 3);
+}
+
+#[test]
+fn verify_string_is_similar_to_str_fails() {
+    let subject = "kitten";
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .is_similar_to("sitting", 2)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_thing to be similar to "sitting"
+  within an edit distance of 2
+   but was: "kitten"
+  expected: "sitting"
+  distance: 3
+"#]
+    );
+}
+
+#[test]
+fn verify_string_is_not_similar_to_str_fails() {
+    let subject = "kitten";
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .expecting(not(is_similar_to("sitting", 3)))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected my_thing to not be similar to "sitting"
+  within an edit distance of 3
+   but was: "kitten"
+  expected: not "sitting"
+  distance: 3
+"#]
+    );
+}
+
+#[test]
+fn str_matches_wildcard_pattern() {
+    let subject = "report_2024-03.csv";
+
+    assert_that(subject)
+        .matches_wildcard("report_*.csv")
+        .matches_wildcard("report_????-??.csv")
+        .matches_wildcard("*2024*")
+        .matches_wildcard("*")
+        .matches_wildcard("report_2024-03.csv");
+}
+
+#[test]
+fn string_matches_wildcard_pattern_with_multibyte_chars() {
+    let subject: String = "grüße.txt".to_string();
+
+    assert_that(subject).matches_wildcard("gr??e.*");
+}
+
+#[test]
+fn empty_string_matches_only_patterns_that_can_match_nothing() {
+    assert_that("")
+        .matches_wildcard("")
+        .matches_wildcard("**")
+        .does_not_match_wildcard("?")
+        .does_not_match_wildcard("a*");
+}
+
+#[test]
+fn wildcard_pattern_segments_do_not_overlap() {
+    assert_that("abc").does_not_match_wildcard("ab*bc");
+    assert_that("abbc").matches_wildcard("ab*bc");
+}
+
+#[test]
+fn str_does_not_match_wildcard_pattern() {
+    let subject = "report_2024-03.csv";
+
+    assert_that(subject)
+        .does_not_match_wildcard("report_*.txt")
+        .does_not_match_wildcard("report_???-??.csv")
+        .does_not_match_wildcard("report");
+}
+
+#[test]
+fn verify_str_matches_wildcard_fails_and_lists_unmatched_segments() {
+    let subject = "summary_2024-03.txt";
+
+    let failures = verify_that(subject)
+        .named("filename")
+        .matches_wildcard("report_*-03*.csv")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected filename to match the wildcard pattern "report_*-03*.csv"
+   but was: "summary_2024-03.txt"
+  expected: "report_*-03*.csv"
+ unmatched: ["report_", ".csv"]
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_str_matches_wildcard_without_star_fails() {
+    let subject = "report_24.csv";
+
+    let failures = verify_that(subject)
+        .named("filename")
+        .matches_wildcard("report_????.csv")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected filename to match the wildcard pattern "report_????.csv"
+   but was: "report_24.csv"
+  expected: "report_????.csv"
+ unmatched: ["report_????.csv"]
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_str_does_not_match_wildcard_fails() {
+    let subject = "report_2024-03.csv";
+
+    let failures = verify_that(subject)
+        .named("filename")
+        .does_not_match_wildcard("report_*.csv")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected filename to not match the wildcard pattern "report_*.csv"
+   but was: "report_2024-03.csv"
+  expected: not "report_*.csv"
+"#
+        ]
+    );
+}
+
+#[test]
+fn str_contains_substring_exactly_once() {
+    let subject = "takimata lorem dolores";
+
+    assert_that(subject).contains_exactly_once("lorem");
+}
+
+#[test]
+fn string_contains_substring_n_times() {
+    let subject: String = "sed diam sed voluptua diam sed".to_string();
+
+    assert_that(subject).contains_n_times("sed", 3);
+}
+
+#[test]
+fn occurrences_are_counted_without_overlapping() {
+    assert_that("aaaa").contains_n_times("aa", 2);
+}
+
+#[test]
+fn str_contains_substring_zero_times() {
+    assert_that("takimata lorem dolores").contains_n_times(String::from("ipsum"), 0);
+}
+
+#[test]
+fn str_does_not_contain_substring_more_than_max_times() {
+    let subject = "sed diam sed voluptua diam sed";
+
+    assert_that(subject)
+        .does_not_contain_more_than("diam", 2)
+        .does_not_contain_more_than("voluptua", 2)
+        .does_not_contain_more_than("ipsum", 0);
+}
+
+#[test]
+fn verify_str_contains_substring_exactly_once_fails() {
+    let subject = "sed diam sed voluptua";
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .contains_exactly_once("sed")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_thing to contain "sed" exactly once, but it was found 2 times
+   but was: "sed diam sed voluptua"
+  expected: "sed" exactly once
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_string_contains_substring_n_times_fails() {
+    let subject: String = "sed diam sed voluptua".to_string();
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .contains_n_times("diam", 2)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_thing to contain "diam" exactly 2 times, but it was found once
+   but was: "sed diam sed voluptua"
+  expected: "diam" exactly 2 times
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_str_does_not_contain_substring_more_than_max_times_fails() {
+    let subject = "sed diam sed voluptua sed";
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .does_not_contain_more_than("sed", 1)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected my_thing to contain "sed" not more than once, but it was found 3 times
+   but was: "sed diam sed voluptua sed"
+  expected: "sed" at most once
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_str_contains_empty_substring_n_times_fails() {
+    let failures = verify_that("lorem")
+        .named("my_thing")
+        .contains_n_times("", 6)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"could not evaluate the assertion on my_thing
+  error: the substring to count must not be empty
+"]
+    );
+}
+
+#[test]
+fn verify_str_does_not_contain_empty_substring_more_than_max_times_fails() {
+    let failures = verify_that("lorem")
+        .named("my_thing")
+        .does_not_contain_more_than("", 10)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"could not evaluate the assertion on my_thing
+  error: the substring to count must not be empty
+"]
+    );
+}
+
 #[cfg(feature = "regex")]
 mod regex {
     use crate::prelude::*;
@@ -1431,7 +1732,38 @@ mod regex {
     }
 
     #[test]
-    #[should_panic = r"failed to match string with regex: regex parse error:
+    fn verify_string_matches_regex_given_an_invalid_regex_fails_with_evaluation_error() {
+        let failures = verify_that("s3cr3tPass")
+            .named("password")
+            .matches(r"^(?=.*[a-z])[a-zA-Z\d]{8,32}$")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"could not evaluate the assertion on password
+  error: failed to match string with regex: regex parse error:
+    ^(?=.*[a-z])[a-zA-Z\d]{8,32}$
+     ^^^
+error: look-around, including look-ahead and look-behind, is not supported
+"]
+        );
+    }
+
+    #[test]
+    fn verify_string_does_not_match_regex_given_an_invalid_regex_fails_with_evaluation_error() {
+        let failures = verify_that("s3cr3tPass")
+            .named("password")
+            .does_not_match(r"[a-z")
+            .display_failures();
+
+        assert_that(&failures[0]).starts_with(
+            "could not evaluate the assertion on password\n  error: failed to match string with regex: regex parse error:",
+        );
+    }
+
+    #[test]
+    #[should_panic = r"could not evaluate the assertion on password
+  error: failed to match string with regex: regex parse error:
     ^(?=.*[a-z])(?=.*[A-Z])(?=.*\d)[a-zA-Z\d]{8,32}$
      ^^^
 error: look-around, including look-ahead and look-behind, is not supported"]
@@ -1455,11 +1787,171 @@ error: look-around, including look-ahead and look-behind, is not supported"]
     }
 }
 
+#[test]
+fn string_has_capacity() {
+    let mut subject = String::with_capacity(24);
+    subject.push_str("iusto dolor");
+
+    assert_that(&subject).has_capacity(24);
+    assert_that(&subject).has_capacity_at_least(20);
+    assert_that(&subject).has_spare_capacity_of(13);
+}
+
+#[test]
+fn verify_string_has_spare_capacity_of_fails() {
+    let mut subject = String::with_capacity(24);
+    subject.push_str("iusto dolor");
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_spare_capacity_of(24)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to have a spare capacity of 24
+   but was: 13
+  expected: 24
+"]
+    );
+}
+
+#[cfg(feature = "unicode")]
+mod unicode {
+    use crate::prelude::*;
+    use crate::std::string::{String, ToString};
+
+    #[test]
+    fn string_has_grapheme_count() {
+        let subject: String = "thumbs up: \u{1f44d}\u{1f3fd}".to_string();
+
+        assert_that(&subject).has_char_count(13);
+        assert_that(&subject).has_grapheme_count(12);
+    }
+
+    #[test]
+    fn str_with_combining_characters_has_grapheme_count() {
+        let subject = "cafe\u{301} \u{1f1e8}\u{1f1ed}";
+
+        assert_that(subject).has_grapheme_count(6);
+    }
+
+    #[test]
+    fn verify_string_has_grapheme_count_fails() {
+        let subject: String = "thumbs up: \u{1f44d}\u{1f3fd}".to_string();
+
+        let failures = verify_that(subject)
+            .named("my_thing")
+            .has_grapheme_count(13)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected my_thing to have a grapheme count of 13
+   but was: 12
+  expected: 13
+"]
+        );
+    }
+
+    #[test]
+    fn string_contains_grapheme() {
+        let subject: String = "looks good \u{1f44d}\u{1f3fd}".to_string();
+
+        assert_that(subject).contains_grapheme("\u{1f44d}\u{1f3fd}");
+    }
+
+    #[test]
+    fn string_does_not_contain_part_of_a_grapheme() {
+        let subject: String = "looks good \u{1f44d}\u{1f3fd}".to_string();
+
+        assert_that(&subject).contains("\u{1f44d}");
+        assert_that(&subject).does_not_contain_grapheme("\u{1f44d}");
+    }
+
+    #[test]
+    fn verify_string_contains_grapheme_fails() {
+        let subject: String = "looks good \u{1f44d}\u{1f3fd}".to_string();
+
+        let failures = verify_that(subject)
+            .named("my_thing")
+            .contains_grapheme("\u{1f44d}")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected my_thing to contain the grapheme \"\u{1f44d}\"\n   \
+                but was: \"looks good \u{1f44d}\u{1f3fd}\"\n  \
+               expected: \"\u{1f44d}\"\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_string_does_not_contain_grapheme_fails() {
+        let subject: String = "looks good \u{1f44d}\u{1f3fd}".to_string();
+
+        let failures = verify_that(subject)
+            .named("my_thing")
+            .does_not_contain_grapheme("\u{1f44d}\u{1f3fd}")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected my_thing to not contain the grapheme \"\u{1f44d}\u{1f3fd}\"\n   \
+                but was: \"looks good \u{1f44d}\u{1f3fd}\"\n  \
+               expected: not \"\u{1f44d}\u{1f3fd}\"\n\
+            "
+            ]
+        );
+    }
+}
+
 #[cfg(feature = "colored")]
 mod colored {
     use crate::prelude::*;
     use crate::std::string::ToString;
 
+    #[test]
+    fn highlight_unmatched_segments_of_wildcard_pattern() {
+        let failures = verify_that("summary_2024-03.csv")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .matches_wildcard("report_*.csv")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to match the wildcard pattern \"report_*.csv\"\n   \
+                    but was: \"\u{1b}[31msummary_2024-03.csv\u{1b}[0m\"\n  \
+                   expected: \"\u{1b}[34mreport_\u{1b}[0m*.csv\"\n \
+                  unmatched: [\"report_\"]\n\
+                "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_occurrences_for_contains_n_times() {
+        let failures = verify_that("sed diam sed voluptua sed")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .does_not_contain_more_than("sed", 2)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to contain \"sed\" not more than 2 times, but it was found 3 times\n   \
+                    but was: \"\u{1b}[31msed\u{1b}[0m diam \u{1b}[31msed\u{1b}[0m voluptua \u{1b}[31msed\u{1b}[0m\"\n  \
+                   expected: \"sed\" at most 2 times\n\
+                "
+            ]
+        );
+    }
+
     #[test]
     fn highlight_diffs_is_equal_to_for_strings() {
         let failures = verify_that("invidunt wisi facilisis exercitation")
@@ -2041,6 +2533,25 @@ mod colored {
             ]
         );
     }
+    #[test]
+    fn highlight_diffs_string_is_similar_to() {
+        let failures = verify_that("the colour of the fence")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_similar_to("the color of the fence", 0)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to be similar to \"the color of the fence\"\n  \
+                    within an edit distance of 0\n   \
+                    but was: \"the colo\u{1b}[31mu\u{1b}[0mr of the fence\"\n  \
+                   expected: \"the color of the fence\"\n  \
+                   distance: 1\n\
+                "
+            ]
+        );
+    }
 }
 
 #[cfg(all(feature = "colored", feature = "regex"))]
@@ -2090,3 +2601,26 @@ mod colored_regex {
         );
     }
 }
+
+#[cfg(all(feature = "colored", feature = "unicode"))]
+mod colored_unicode {
+    use crate::prelude::*;
+
+    #[test]
+    fn highlight_diffs_is_equal_to_for_strings_does_not_split_graphemes() {
+        let failures = verify_that("thumbs up: \u{1f44d}\u{1f3fd}")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_equal_to("thumbs up: \u{1f44d}\u{1f3ff}")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected subject to be equal to \"thumbs up: \u{1f44d}\u{1f3ff}\"\n   \
+                    but was: \"thumbs up: \u{1b}[31m\u{1f44d}\u{1f3fd}\u{1b}[0m\"\n  \
+                   expected: \"thumbs up: \u{1b}[34m\u{1f44d}\u{1f3ff}\u{1b}[0m\"\n\
+                "
+            ]
+        );
+    }
+}
@@ -0,0 +1,527 @@
+//! Element-wise comparison of collections based on the pretty-printed debug
+//! representation of their elements.
+//!
+//! The pretty debug string (`{:#?}`) of a value is parsed into a tree of
+//! nodes. Comparing the trees of two values yields the paths of the fields
+//! that differ, like `[1].address.city`, together with the differing values.
+//!
+//! As custom `Debug` implementations may produce any output, the parser only
+//! accepts the layout of derived `Debug` implementations, with 4 spaces of
+//! indentation per level. In addition, the parsed tree must render to the same
+//! string as the non-pretty debug string (`{:?}`) of the value. Values whose
+//! debug string does not pass these checks are reported as unparsable.
+
+use crate::colored::{mark_diff_str, mark_missing_string, mark_unexpected_string};
+use crate::spec::DiffFormat;
+use crate::std::fmt::{Debug, Write};
+use crate::std::format;
+use crate::std::string::{String, ToString};
+use crate::std::vec::Vec;
+
+/// Indentation of one level of nesting in pretty-printed debug strings.
+const INDENT: usize = 4;
+
+/// The error returned if the debug string of a structured value can not be
+/// parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnparsableDebug;
+
+/// A node of the tree parsed from a pretty-printed debug string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    /// A value without nested fields or elements, like a number or a string.
+    Leaf(String),
+    /// A value with nested fields or elements, like a struct or a `Vec`.
+    Container {
+        /// The opening part of the value, like `Person {` or `[`.
+        open: String,
+        /// The fields or elements of the value with their field name, if any.
+        children: Vec<(Option<String>, Self)>,
+    },
+}
+
+impl Node {
+    /// Parses the debug string of the given value.
+    ///
+    /// A value whose pretty debug string is a single line is a leaf. For any
+    /// other value, the parsed tree must render to the non-pretty debug string
+    /// of the value.
+    fn from_debug<T>(value: &T) -> Result<Self, UnparsableDebug>
+    where
+        T: Debug + ?Sized,
+    {
+        let pretty_debug = format!("{value:#?}");
+        if !pretty_debug.contains('\n') {
+            return Ok(Self::Leaf(pretty_debug));
+        }
+        Self::parse(&pretty_debug)
+            .filter(|node| node.to_compact_string() == format!("{value:?}"))
+            .ok_or(UnparsableDebug)
+    }
+
+    fn parse(pretty_debug: &str) -> Option<Self> {
+        let mut lines = pretty_debug.lines();
+        let first = lines.next()?;
+        let node = Self::parse_value(first, 0, &mut lines)?;
+        if lines.next().is_some() {
+            return None;
+        }
+        Some(node)
+    }
+
+    fn parse_value(
+        value: &str,
+        depth: usize,
+        lines: &mut dyn Iterator<Item = &str>,
+    ) -> Option<Self> {
+        let Some(closing) = closing_delimiter(value) else {
+            return Some(Self::Leaf(value.to_string()));
+        };
+        let mut children = Vec::new();
+        loop {
+            let line = lines.next()?;
+            if let Some(end) = strip_indentation(line, depth) {
+                if end.strip_suffix(',').unwrap_or(end).starts_with(closing) && end.len() <= 2 {
+                    break;
+                }
+                return None;
+            }
+            let item = strip_indentation(line, depth + 1)?;
+            let (key, item) = if closing == '}' {
+                split_key(item)
+            } else {
+                (None, item)
+            };
+            let child = match item.strip_suffix(',') {
+                Some(leaf) => Self::Leaf(leaf.to_string()),
+                None if closing_delimiter(item).is_some() => {
+                    Self::parse_value(item, depth + 1, lines)?
+                },
+                None => return None,
+            };
+            children.push((key.map(ToString::to_string), child));
+        }
+        Some(Self::Container {
+            open: value.to_string(),
+            children,
+        })
+    }
+
+    const fn is_container(&self) -> bool {
+        matches!(self, Self::Container { .. })
+    }
+
+    /// Renders the node in the same format as the non-pretty debug string.
+    fn render(&self, output: &mut String) {
+        match self {
+            Self::Leaf(value) => output.push_str(value),
+            Self::Container { open, children } => {
+                output.push_str(open);
+                let padded = open.ends_with(" {");
+                if padded {
+                    output.push(' ');
+                }
+                for (index, (key, child)) in children.iter().enumerate() {
+                    if index > 0 {
+                        output.push_str(", ");
+                    }
+                    if let Some(key) = key {
+                        _ = write!(output, "{key}: ");
+                    }
+                    child.render(output);
+                }
+                if padded {
+                    output.push(' ');
+                }
+                output.push(closing_delimiter(open).unwrap_or_default());
+            },
+        }
+    }
+
+    fn to_compact_string(&self) -> String {
+        let mut output = String::new();
+        self.render(&mut output);
+        output
+    }
+}
+
+/// Strips the indentation of the given nesting depth from the line.
+///
+/// Returns `None` if the line is not indented by exactly this depth.
+fn strip_indentation(line: &str, depth: usize) -> Option<&str> {
+    let indentation = depth * INDENT;
+    let (spaces, rest) = line.split_at_checked(indentation)?;
+    (spaces.bytes().all(|byte| byte == b' ') && !rest.is_empty() && !rest.starts_with(' '))
+        .then_some(rest)
+}
+
+/// Splits the key of a struct field or map entry from the item of a line.
+///
+/// Keys that are quoted strings or chars may contain any characters. The key
+/// is `None` if the item has no key, like an element of a set.
+fn split_key(item: &str) -> (Option<&str>, &str) {
+    let key_end = match item.chars().next() {
+        Some(quote @ ('"' | '\'')) => quoted_end(item, quote),
+        _ => item.find(": "),
+    };
+    key_end
+        .and_then(|end| {
+            let (key, rest) = item.split_at(end);
+            rest.strip_prefix(": ").map(|value| (Some(key), value))
+        })
+        .unwrap_or((None, item))
+}
+
+/// Returns the byte index after the closing quote of the quoted string or
+/// char at the start of the item.
+fn quoted_end(item: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (index, char) in item.char_indices().skip(1) {
+        match char {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if char == quote => return Some(index + char.len_utf8()),
+            _ => {},
+        }
+    }
+    None
+}
+
+fn closing_delimiter(value: &str) -> Option<char> {
+    match value.chars().last()? {
+        '{' => Some('}'),
+        '[' => Some(']'),
+        '(' => Some(')'),
+        _ => None,
+    }
+}
+
+/// A difference between the actual and the expected value at some path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    pub path: String,
+    pub actual: String,
+    pub expected: String,
+}
+
+fn child_path(path: &str, open: &str, key: Option<&String>, index: usize) -> String {
+    match key {
+        Some(key) if key.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+            format!("{path}.{key}")
+        },
+        Some(key) => format!("{path}[{key}]"),
+        None if open.ends_with('(') => format!("{path}.{index}"),
+        None => format!("{path}[{index}]"),
+    }
+}
+
+fn collect_differences(path: &str, actual: &Node, expected: &Node, found: &mut Vec<Difference>) {
+    match (actual, expected) {
+        (Node::Leaf(actual_value), Node::Leaf(expected_value)) => {
+            if actual_value != expected_value {
+                found.push(Difference {
+                    path: path.to_string(),
+                    actual: actual_value.clone(),
+                    expected: expected_value.clone(),
+                });
+            }
+        },
+        (
+            Node::Container {
+                open: actual_open,
+                children: actual_children,
+            },
+            Node::Container {
+                open: expected_open,
+                children: expected_children,
+            },
+        ) if actual_open == expected_open
+            && actual_children.len() == expected_children.len()
+            && actual_children
+                .iter()
+                .zip(expected_children)
+                .all(|((actual_key, _), (expected_key, _))| actual_key == expected_key) =>
+        {
+            for (index, ((key, actual_child), (_, expected_child))) in
+                actual_children.iter().zip(expected_children).enumerate()
+            {
+                let path = child_path(path, actual_open, key.as_ref(), index);
+                collect_differences(&path, actual_child, expected_child, found);
+            }
+        },
+        _ => {
+            let actual_value = actual.to_compact_string();
+            let expected_value = expected.to_compact_string();
+            if actual_value != expected_value {
+                found.push(Difference {
+                    path: path.to_string(),
+                    actual: actual_value,
+                    expected: expected_value,
+                });
+            }
+        },
+    }
+}
+
+/// Compares two single elements of a collection at the given index and
+/// returns the differences of their fields.
+///
+/// Returns an empty list if the elements are not structured values, like
+/// structs, tuples or collections.
+///
+/// # Errors
+///
+/// Returns an [`UnparsableDebug`] error if the debug string of any of the
+/// elements can not be parsed.
+pub fn element_differences<T, E>(
+    index: usize,
+    actual: &T,
+    expected: &E,
+) -> Result<Vec<Difference>, UnparsableDebug>
+where
+    T: Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    let actual = Node::from_debug(actual)?;
+    let expected = Node::from_debug(expected)?;
+    let mut found = Vec::new();
+    if actual.is_container() || expected.is_container() {
+        collect_differences(&format!("[{index}]"), &actual, &expected, &mut found);
+    }
+    Ok(found)
+}
+
+/// Compares two collections element by element and returns the differences
+/// of the fields of the elements at the same index.
+///
+/// Returns an empty list if any of the values is not a list-like collection
+/// of structured elements, like structs, tuples or nested collections.
+/// Elements that exist only in one of the collections are not compared.
+///
+/// # Errors
+///
+/// Returns an [`UnparsableDebug`] error if the debug string of any of the
+/// values can not be parsed.
+pub fn collection_differences<T, E>(
+    actual: &T,
+    expected: &E,
+) -> Result<Vec<Difference>, UnparsableDebug>
+where
+    T: Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    let (
+        Node::Container {
+            open: actual_open,
+            children: actual_elements,
+        },
+        Node::Container {
+            open: expected_open,
+            children: expected_elements,
+        },
+    ) = (Node::from_debug(actual)?, Node::from_debug(expected)?)
+    else {
+        return Ok(Vec::new());
+    };
+    if actual_open != "[" || expected_open != "[" {
+        return Ok(Vec::new());
+    }
+    let has_structured_elements = actual_elements
+        .iter()
+        .chain(&expected_elements)
+        .any(|(_, element)| element.is_container());
+    if !has_structured_elements {
+        return Ok(Vec::new());
+    }
+    let mut found = Vec::new();
+    for (index, ((_, actual_element), (_, expected_element))) in
+        actual_elements.iter().zip(&expected_elements).enumerate()
+    {
+        collect_differences(
+            &format!("[{index}]"),
+            actual_element,
+            expected_element,
+            &mut found,
+        );
+    }
+    Ok(found)
+}
+
+/// The differences between two maps, grouped into entries whose key exists
+/// only in one of the maps and entries whose key exists in both maps but with
+/// different values.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MapDifferences {
+    pub only_in_actual: Vec<(String, String)>,
+    pub only_in_expected: Vec<(String, String)>,
+    pub different_values: Vec<Difference>,
+}
+
+/// Parses the entries of a map from its debug string.
+///
+/// Returns `None` if the value is not a map or its debug string can not be
+/// parsed.
+fn map_entries<T>(map: &T) -> Option<Vec<(String, Node)>>
+where
+    T: Debug + ?Sized,
+{
+    match Node::from_debug(map).ok()? {
+        Node::Leaf(value) if value == "{}" => Some(Vec::new()),
+        Node::Container { open, children } if open == "{" => children
+            .into_iter()
+            .map(|(key, value)| key.map(|key| (key, value)))
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Compares two maps entry by entry and returns the keys that exist only in
+/// one of them as well as the differences of the values for keys that exist
+/// in both maps.
+///
+/// Returns `None` if any of the values is not a map or if their debug strings
+/// can not be parsed.
+pub fn map_differences<T, E>(actual: &T, expected: &E) -> Option<MapDifferences>
+where
+    T: Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    let actual_entries = map_entries(actual)?;
+    let expected_entries = map_entries(expected)?;
+    let mut differences = MapDifferences::default();
+    for (key, actual_value) in &actual_entries {
+        match expected_entries
+            .iter()
+            .find(|(expected_key, _)| expected_key == key)
+        {
+            Some((_, expected_value)) => collect_differences(
+                &format!("[{key}]"),
+                actual_value,
+                expected_value,
+                &mut differences.different_values,
+            ),
+            None => differences
+                .only_in_actual
+                .push((key.clone(), actual_value.to_compact_string())),
+        }
+    }
+    for (key, expected_value) in &expected_entries {
+        if !actual_entries
+            .iter()
+            .any(|(actual_key, _)| actual_key == key)
+        {
+            differences
+                .only_in_expected
+                .push((key.clone(), expected_value.to_compact_string()));
+        }
+    }
+    Some(differences)
+}
+
+/// Compares the values of two map entries with the given key and returns the
+/// differences of their fields.
+///
+/// If the values are not structured values or their debug strings can not be
+/// parsed, the whole values are returned as a single difference.
+pub fn entry_differences<K, T, E>(key: &K, actual: &T, expected: &E) -> Vec<Difference>
+where
+    K: Debug + ?Sized,
+    T: Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    let path = format!("[{key:?}]");
+    let mut found = Vec::new();
+    if let (Ok(actual), Ok(expected)) = (Node::from_debug(actual), Node::from_debug(expected)) {
+        collect_differences(&path, &actual, &expected, &mut found);
+    }
+    if found.is_empty() {
+        found.push(Difference {
+            path,
+            actual: format!("{actual:?}"),
+            expected: format!("{expected:?}"),
+        });
+    }
+    found
+}
+
+/// Formats the given map differences as sections of a failure message.
+///
+/// The labels of the sections are aligned on the colon. Sections without
+/// entries are omitted.
+pub fn format_map_differences(differences: &MapDifferences, format: &DiffFormat) -> String {
+    let mut sections = String::new();
+    if !differences.only_in_actual.is_empty() {
+        let entries = format_entries(&differences.only_in_actual);
+        _ = write!(
+            sections,
+            "\n    only in actual: {}",
+            mark_unexpected_string(&entries, format)
+        );
+    }
+    if !differences.only_in_expected.is_empty() {
+        let entries = format_entries(&differences.only_in_expected);
+        _ = write!(
+            sections,
+            "\n  only in expected: {}",
+            mark_missing_string(&entries, format)
+        );
+    }
+    if !differences.different_values.is_empty() {
+        sections.push_str("\n  different values:");
+        for difference in &differences.different_values {
+            let (marked_actual, marked_expected) =
+                mark_diff_str(&difference.actual, &difference.expected, format);
+            _ = write!(
+                sections,
+                "\n    {}: but was {marked_actual}, expected {marked_expected}",
+                difference.path
+            );
+        }
+    }
+    sections
+}
+
+fn format_entries(entries: &[(String, String)]) -> String {
+    let mut output = String::from("{");
+    for (index, (key, value)) in entries.iter().enumerate() {
+        if index > 0 {
+            output.push_str(", ");
+        }
+        _ = write!(output, "{key}: {value}");
+    }
+    output.push('}');
+    output
+}
+
+/// Formats the given differences as a section of a failure message.
+///
+/// Returns an empty string if there are no differences. If the debug strings
+/// of the values could not be parsed, the section says so.
+pub fn format_differences(
+    differences: &Result<Vec<Difference>, UnparsableDebug>,
+    format: &DiffFormat,
+) -> String {
+    let differences = match differences {
+        Ok(differences) if differences.is_empty() => return String::new(),
+        Ok(differences) => differences,
+        Err(UnparsableDebug) => {
+            return String::from(
+                "\n  differences: not available, the debug string could not be parsed",
+            );
+        },
+    };
+    let mut section = String::from("\n  differences:");
+    for difference in differences {
+        let (marked_actual, marked_expected) =
+            mark_diff_str(&difference.actual, &difference.expected, format);
+        _ = write!(
+            section,
+            "\n    {}: but was {marked_actual}, expected {marked_expected}",
+            difference.path
+        );
+    }
+    section
+}
+
+#[cfg(test)]
+mod tests;
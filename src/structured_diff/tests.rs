@@ -0,0 +1,305 @@
+use super::*;
+use crate::prelude::*;
+use crate::std::collections::BTreeMap;
+use crate::std::fmt::{self, Debug};
+use crate::std::{string::ToString, vec, vec::Vec};
+
+#[derive(Debug, PartialEq)]
+struct Address {
+    street: &'static str,
+    city: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+struct Person {
+    name: &'static str,
+    age: u8,
+    address: Address,
+}
+
+fn person(name: &'static str, age: u8, city: &'static str) -> Person {
+    Person {
+        name,
+        age,
+        address: Address {
+            street: "Main Street",
+            city,
+        },
+    }
+}
+
+fn difference(path: &str, actual: &str, expected: &str) -> Difference {
+    Difference {
+        path: path.to_string(),
+        actual: actual.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+#[test]
+fn parse_leaf_value() {
+    let node = Node::parse(&format!("{:#?}", "lorem"));
+
+    assert_that(node).has_value(Node::Leaf("\"lorem\"".to_string()));
+}
+
+#[test]
+fn parse_nested_struct_and_render_it_as_compact_debug_string() {
+    let subject = person("Alice", 30, "Bern");
+
+    let node = Node::parse(&format!("{subject:#?}"));
+
+    assert_that(node.map(|node| node.to_compact_string())).has_value(format!("{subject:?}"));
+}
+
+#[test]
+fn parse_tuple_and_vec_and_render_them_as_compact_debug_string() {
+    let subject = (Some(vec![1, 2]), "ipsum", ());
+
+    let node = Node::parse(&format!("{subject:#?}"));
+
+    assert_that(node.map(|node| node.to_compact_string())).has_value(format!("{subject:?}"));
+}
+
+#[test]
+fn collection_differences_of_vec_of_structs_lists_differing_fields() {
+    let actual = vec![
+        person("Alice", 30, "Bern"),
+        person("Bob", 31, "Zurich"),
+        person("Carol", 42, "Basel"),
+    ];
+    let expected = vec![
+        person("Alice", 30, "Bern"),
+        person("Bob", 30, "Geneva"),
+        person("Carol", 42, "Basel"),
+    ];
+
+    let differences = collection_differences(&actual, &expected);
+
+    assert_that(differences).ok().contains_exactly([
+        difference("[1].age", "31", "30"),
+        difference("[1].address.city", "\"Zurich\"", "\"Geneva\""),
+    ]);
+}
+
+#[test]
+fn collection_differences_of_vec_of_tuples_uses_positional_paths() {
+    let actual = vec![(1, "one"), (2, "two")];
+    let expected = vec![(1, "one"), (2, "zwei")];
+
+    let differences = collection_differences(&actual, &expected);
+
+    assert_that(differences)
+        .ok()
+        .contains_exactly([difference("[1].1", "\"two\"", "\"zwei\"")]);
+}
+
+#[test]
+fn collection_differences_of_vec_of_maps_uses_keys_in_paths() {
+    let actual: Vec<BTreeMap<&str, u8>> = vec![[("a", 1), ("b", 2)].into()];
+    let expected: Vec<BTreeMap<&str, u8>> = vec![[("a", 1), ("b", 3)].into()];
+
+    let differences = collection_differences(&actual, &expected);
+
+    assert_that(differences)
+        .ok()
+        .contains_exactly([difference("[0][\"b\"]", "2", "3")]);
+}
+
+#[test]
+fn collection_differences_reports_whole_values_if_the_shape_differs() {
+    let actual = vec![Some(vec![1, 2])];
+    let expected = vec![Some(vec![1, 2, 3])];
+
+    let differences = collection_differences(&actual, &expected);
+
+    assert_that(differences)
+        .ok()
+        .contains_exactly([difference("[0].0", "[1, 2]", "[1, 2, 3]")]);
+}
+
+#[test]
+fn collection_differences_of_vec_of_plain_values_is_empty() {
+    let differences = collection_differences(&vec![1, 2, 3], &vec![1, 5, 3]);
+
+    assert_that(differences).ok().is_empty();
+}
+
+#[test]
+fn collection_differences_of_single_struct_is_empty() {
+    let differences =
+        collection_differences(&person("Alice", 30, "Bern"), &person("Alice", 31, "Bern"));
+
+    assert_that(differences).ok().is_empty();
+}
+
+#[test]
+fn collection_differences_ignores_elements_that_exist_only_in_one_collection() {
+    let actual = vec![person("Alice", 30, "Bern")];
+    let expected = vec![person("Alice", 31, "Bern"), person("Bob", 30, "Zurich")];
+
+    let differences = collection_differences(&actual, &expected);
+
+    assert_that(differences)
+        .ok()
+        .contains_exactly([difference("[0].age", "30", "31")]);
+}
+
+#[test]
+fn element_differences_uses_the_given_index_in_paths() {
+    let differences = element_differences(
+        3,
+        &person("Alice", 30, "Bern"),
+        &person("Alicia", 30, "Bern"),
+    );
+
+    assert_that(differences).ok().contains_exactly([difference(
+        "[3].name",
+        "\"Alice\"",
+        "\"Alicia\"",
+    )]);
+}
+
+#[test]
+fn format_differences_of_empty_list_is_empty_string() {
+    let section = format_differences(&Ok(vec![]), &DIFF_FORMAT_NO_HIGHLIGHT);
+
+    assert_that(section).is_empty();
+}
+
+#[test]
+fn format_differences_lists_each_difference_on_its_own_line() {
+    let section = format_differences(
+        &Ok(vec![
+            difference("[1].age", "31", "30"),
+            difference("[1].address.city", "\"Zurich\"", "\"Geneva\""),
+        ]),
+        &DIFF_FORMAT_NO_HIGHLIGHT,
+    );
+
+    assert_that(section).is_equal_to(
+        "\n  differences:\
+         \n    [1].age: but was 31, expected 30\
+         \n    [1].address.city: but was \"Zurich\", expected \"Geneva\"",
+    );
+}
+
+#[test]
+fn map_differences_groups_keys_into_missing_extra_and_different_values() {
+    let actual: BTreeMap<&str, u8> = [("a", 1), ("b", 2), ("c", 3)].into();
+    let expected: BTreeMap<&str, u8> = [("a", 1), ("b", 5), ("d", 4)].into();
+
+    let differences = map_differences(&actual, &expected);
+
+    assert_that(differences).has_value(MapDifferences {
+        only_in_actual: vec![("\"c\"".to_string(), "3".to_string())],
+        only_in_expected: vec![("\"d\"".to_string(), "4".to_string())],
+        different_values: vec![difference("[\"b\"]", "2", "5")],
+    });
+}
+
+#[test]
+fn map_differences_of_struct_values_lists_differing_fields() {
+    let actual: BTreeMap<u8, Person> = [(1, person("Alice", 30, "Bern"))].into();
+    let expected: BTreeMap<u8, Person> = [(1, person("Alice", 30, "Basel"))].into();
+
+    let differences = map_differences(&actual, &expected);
+
+    assert_that(differences.map(|differences| differences.different_values)).has_value(vec![
+        difference("[1].address.city", "\"Bern\"", "\"Basel\""),
+    ]);
+}
+
+#[test]
+fn map_differences_of_empty_map_lists_all_keys_as_missing() {
+    let actual: BTreeMap<&str, u8> = BTreeMap::new();
+    let expected: BTreeMap<&str, u8> = [("a", 1)].into();
+
+    let differences = map_differences(&actual, &expected);
+
+    assert_that(differences).has_value(MapDifferences {
+        only_in_actual: vec![],
+        only_in_expected: vec![("\"a\"".to_string(), "1".to_string())],
+        different_values: vec![],
+    });
+}
+
+#[test]
+fn map_differences_of_values_that_are_not_maps_is_none() {
+    assert_that(map_differences(&vec![1, 2], &vec![1, 3])).is_none();
+    assert_that(map_differences(
+        &person("Alice", 30, "Bern"),
+        &person("Alice", 31, "Bern"),
+    ))
+    .is_none();
+}
+
+#[test]
+fn format_map_differences_omits_empty_sections() {
+    let section = format_map_differences(
+        &MapDifferences {
+            only_in_actual: vec![],
+            only_in_expected: vec![
+                ("\"d\"".to_string(), "4".to_string()),
+                ("\"e\"".to_string(), "5".to_string()),
+            ],
+            different_values: vec![difference("[\"b\"]", "2", "5")],
+        },
+        &DIFF_FORMAT_NO_HIGHLIGHT,
+    );
+
+    assert_that(section).is_equal_to(
+        "\n  only in expected: {\"d\": 4, \"e\": 5}\
+         \n  different values:\
+         \n    [\"b\"]: but was 2, expected 5",
+    );
+}
+
+/// A type with a custom `Debug` implementation that does not follow the
+/// layout of derived implementations.
+#[derive(PartialEq)]
+struct Multiline(u8);
+
+impl Debug for Multiline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Multiline {{\nvalue: {}\n}}", self.0)
+    }
+}
+
+#[test]
+fn collection_differences_of_elements_with_custom_debug_layout_is_unparsable() {
+    let differences = collection_differences(&vec![Multiline(1)], &vec![Multiline(2)]);
+
+    assert_that(differences).is_equal_to(Err(UnparsableDebug));
+}
+
+#[test]
+fn collection_differences_of_strings_containing_delimiters() {
+    let differences = collection_differences(
+        &vec![person("Al, {x: 1}", 30, "[Bern]")],
+        &vec![person("Al, {x: 1}", 31, "[Bern]")],
+    );
+
+    assert_that(differences)
+        .ok()
+        .contains_exactly([difference("[0].age", "30", "31")]);
+}
+
+#[test]
+fn map_differences_of_keys_containing_delimiters() {
+    let actual: BTreeMap<&str, Person> = [("a: {b},", person("Alice", 30, "Bern"))].into();
+    let expected: BTreeMap<&str, Person> = [("a: {b},", person("Alice", 31, "Bern"))].into();
+
+    let differences = map_differences(&actual, &expected);
+
+    assert_that(differences.map(|differences| differences.different_values))
+        .has_value(vec![difference("[\"a: {b},\"].age", "30", "31")]);
+}
+
+#[test]
+fn format_differences_of_unparsable_values_says_so() {
+    let section = format_differences(&Err(UnparsableDebug), &DIFF_FORMAT_NO_HIGHLIGHT);
+
+    assert_that(section)
+        .is_equal_to("\n  differences: not available, the debug string could not be parsed");
+}
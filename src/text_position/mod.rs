@@ -0,0 +1,147 @@
+//! Location of the first difference between two texts.
+//!
+//! Highlighted differences of long or multi-line texts are hard to locate, as
+//! the debug string of a text escapes all line breaks. For such texts failure
+//! messages additionally report the line and column of the first difference.
+
+use crate::std::fmt::{self, Debug, Display};
+use crate::std::format;
+use crate::std::string::String;
+
+/// Texts with more characters than this are reported with the position of the
+/// first difference, even if they consist of a single line.
+const MAX_SHORT_TEXT_LENGTH: usize = 80;
+
+/// The 1-based line and column of a character in a text.
+///
+/// Columns count characters, or grapheme clusters if the `unicode` feature is
+/// enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[cfg(not(feature = "unicode"))]
+fn units(text: &str) -> impl Iterator<Item = &str> {
+    text.char_indices()
+        .map(|(index, char)| &text[index..index + char.len_utf8()])
+}
+
+#[cfg(feature = "unicode")]
+fn units(text: &str) -> impl Iterator<Item = &str> {
+    use unicode_segmentation::UnicodeSegmentation;
+    text.graphemes(true)
+}
+
+/// Returns the position of the first character where the two texts differ.
+///
+/// If one text is the beginning of the other, the position right after the
+/// shorter text is returned. Returns `None` if the texts are equal.
+pub fn first_difference_position(actual: &str, expected: &str) -> Option<Position> {
+    let mut actual_units = units(actual);
+    let mut expected_units = units(expected);
+    let mut position = Position { line: 1, column: 1 };
+    loop {
+        match (actual_units.next(), expected_units.next()) {
+            (None, None) => return None,
+            (Some(actual_unit), Some(expected_unit)) if actual_unit == expected_unit => {
+                if actual_unit == "\n" || actual_unit == "\r\n" {
+                    position.line += 1;
+                    position.column = 1;
+                } else {
+                    position.column += 1;
+                }
+            },
+            _ => return Some(position),
+        }
+    }
+}
+
+fn is_long_text(text: &str) -> bool {
+    text.contains('\n') || text.chars().nth(MAX_SHORT_TEXT_LENGTH).is_some()
+}
+
+/// Returns the position of the first difference of two texts if at least one
+/// of them is a multi-line text or too long to be compared at a glance.
+pub fn text_difference_position(actual: &str, expected: &str) -> Option<Position> {
+    if is_long_text(actual) || is_long_text(expected) {
+        first_difference_position(actual, expected)
+    } else {
+        None
+    }
+}
+
+/// Returns the position of the first difference of two values whose debug
+/// strings are string literals, like `String`s or `&str`s.
+///
+/// Returns `None` if any of the values is not debug formatted as a string
+/// literal or none of the texts is long enough (see
+/// [`text_difference_position`]).
+pub fn debug_string_difference_position<T, E>(actual: &T, expected: &E) -> Option<Position>
+where
+    T: Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    let actual = unescape_debug_string(&format!("{actual:?}"))?;
+    let expected = unescape_debug_string(&format!("{expected:?}"))?;
+    text_difference_position(&actual, &expected)
+}
+
+/// Converts the debug string of a string value back into the original text.
+///
+/// Returns `None` if the given debug string is not a string literal.
+pub fn unescape_debug_string(debug: &str) -> Option<String> {
+    let inner = debug.strip_prefix('"')?.strip_suffix('"')?;
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(char) = chars.next() {
+        let unescaped = match char {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                '\\' => '\\',
+                '"' => '"',
+                '\'' => '\'',
+                'u' => {
+                    if chars.next()? != '{' {
+                        return None;
+                    }
+                    let mut code = String::new();
+                    loop {
+                        match chars.next()? {
+                            '}' => break,
+                            digit => code.push(digit),
+                        }
+                    }
+                    char::from_u32(u32::from_str_radix(&code, 16).ok()?)?
+                },
+                _ => return None,
+            },
+            '"' => return None,
+            other => other,
+        };
+        text.push(unescaped);
+    }
+    Some(text)
+}
+
+/// Formats the given position as a line of a failure message.
+///
+/// Returns an empty string if there is no position.
+pub fn format_difference_position(position: Option<Position>) -> String {
+    position.map_or_else(String::new, |position| {
+        format!("\n  first difference at {position}")
+    })
+}
+
+#[cfg(test)]
+mod tests;
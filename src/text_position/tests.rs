@@ -0,0 +1,139 @@
+use super::*;
+use crate::prelude::*;
+use crate::std::string::ToString;
+
+#[test]
+fn first_difference_position_of_equal_texts_is_none() {
+    assert_that(first_difference_position("lorem\nipsum", "lorem\nipsum")).is_none();
+}
+
+#[test]
+fn first_difference_position_in_first_line() {
+    assert_that(first_difference_position("lorem ipsum", "lorem ipxum"))
+        .has_value(Position { line: 1, column: 9 });
+}
+
+#[test]
+fn first_difference_position_in_third_line() {
+    let actual = "lorem ipsum\ndolor sit amet\nconsectetur adipiscing elit";
+    let expected = "lorem ipsum\ndolor sit amet\nconsectetur adipisicing elit";
+
+    assert_that(first_difference_position(actual, expected)).has_value(Position {
+        line: 3,
+        column: 19,
+    });
+}
+
+#[test]
+fn first_difference_position_after_windows_line_breaks() {
+    let actual = "lorem\r\nipsum\r\ndolor";
+    let expected = "lorem\r\nipsum\r\ndolar";
+
+    assert_that(first_difference_position(actual, expected))
+        .has_value(Position { line: 3, column: 4 });
+}
+
+#[test]
+fn first_difference_position_if_actual_is_beginning_of_expected() {
+    assert_that(first_difference_position("lorem\nips", "lorem\nipsum"))
+        .has_value(Position { line: 2, column: 4 });
+}
+
+#[test]
+fn first_difference_position_if_expected_is_beginning_of_actual() {
+    assert_that(first_difference_position("lorem\nipsum\n", "lorem\nipsum"))
+        .has_value(Position { line: 2, column: 6 });
+}
+
+#[test]
+fn first_difference_position_counts_multibyte_characters_as_one_column() {
+    assert_that(first_difference_position(
+        "größer\nkleiner",
+        "größer\nkleinér",
+    ))
+    .has_value(Position { line: 2, column: 6 });
+    assert_that(first_difference_position("größe", "großer"))
+        .has_value(Position { line: 1, column: 3 });
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn first_difference_position_counts_grapheme_clusters_as_one_column() {
+    assert_that(first_difference_position(
+        "the family 👨‍👩‍👧 is here",
+        "the family 👨‍👩‍👧 are here",
+    ))
+    .has_value(Position {
+        line: 1,
+        column: 14,
+    });
+}
+
+#[test]
+fn text_difference_position_of_short_single_line_texts_is_none() {
+    assert_that(text_difference_position("lorem ipsum", "lorem ipxum")).is_none();
+}
+
+#[test]
+fn text_difference_position_of_multi_line_texts() {
+    assert_that(text_difference_position("lorem\nipsum", "lorem\nipxum"))
+        .has_value(Position { line: 2, column: 3 });
+}
+
+#[test]
+fn text_difference_position_of_long_single_line_texts() {
+    let actual = "lorem ipsum dolor sit amet, ".repeat(3) + "consetetur";
+    let expected = "lorem ipsum dolor sit amet, ".repeat(3) + "consectetur";
+
+    assert_that(text_difference_position(&actual, &expected)).has_value(Position {
+        line: 1,
+        column: 90,
+    });
+}
+
+#[test]
+fn debug_string_difference_position_of_strings() {
+    let actual = "lorem\t\"ipsum\"\ndolor \u{1b}sit\\amet".to_string();
+    let expected = "lorem\t\"ipsum\"\ndolor \u{1b}sit\\anet";
+
+    assert_that(debug_string_difference_position(&actual, &expected)).has_value(Position {
+        line: 2,
+        column: 13,
+    });
+}
+
+#[test]
+fn debug_string_difference_position_of_non_strings_is_none() {
+    assert_that(debug_string_difference_position(
+        &["lorem\nipsum"],
+        &["lorem\nipxum"],
+    ))
+    .is_none();
+    assert_that(debug_string_difference_position(
+        &Some("lorem\nipsum"),
+        &Some("lorem\nipxum"),
+    ))
+    .is_none();
+    assert_that(debug_string_difference_position(&'\n', &'a')).is_none();
+}
+
+#[test]
+fn unescape_debug_string_of_string_with_special_characters() {
+    let text = "a\"b'c\\d\ne\rf\tg\0h\u{7f}i\u{200b}j🦀";
+
+    assert_that(unescape_debug_string(&format!("{text:?}"))).has_value(text);
+}
+
+#[test]
+fn format_difference_position_of_some_position() {
+    assert_that(format_difference_position(Some(Position {
+        line: 3,
+        column: 17,
+    })))
+    .is_equal_to("\n  first difference at line 3, column 17");
+}
+
+#[test]
+fn format_difference_position_of_none_is_empty() {
+    assert_that(format_difference_position(None)).is_empty();
+}
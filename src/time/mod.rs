@@ -0,0 +1,50 @@
+//! Implementation of the [`DateTimeProperties`] for the date and date-time
+//! types of the `time` crate.
+
+use crate::properties::DateTimeProperties;
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+impl DateTimeProperties for Date {
+    fn date_property(&self) -> (i32, u8, u8) {
+        (self.year(), u8::from(self.month()), self.day())
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        None
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        self.midnight().assume_utc().unix_timestamp_nanos()
+    }
+}
+
+impl DateTimeProperties for PrimitiveDateTime {
+    fn date_property(&self) -> (i32, u8, u8) {
+        (self.year(), u8::from(self.month()), self.day())
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        Some((self.hour(), self.minute(), self.second(), self.nanosecond()))
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        self.assume_utc().unix_timestamp_nanos()
+    }
+}
+
+impl DateTimeProperties for OffsetDateTime {
+    fn date_property(&self) -> (i32, u8, u8) {
+        (self.year(), u8::from(self.month()), self.day())
+    }
+
+    fn time_of_day_property(&self) -> Option<(u8, u8, u8, u32)> {
+        Some((self.hour(), self.minute(), self.second(), self.nanosecond()))
+    }
+
+    fn unix_timestamp_nanos_property(&self) -> i128 {
+        self.unix_timestamp_nanos()
+    }
+}
+
+#[cfg(test)]
+mod tests;
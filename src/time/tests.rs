@@ -0,0 +1,87 @@
+use crate::prelude::*;
+use crate::std::time::Duration;
+use time::macros::{date, datetime};
+
+#[test]
+fn date_properties() {
+    let subject = date!(2024 - 03 - 15);
+
+    assert_eq!(subject.date_property(), (2024, 3, 15));
+    assert_eq!(subject.time_of_day_property(), None);
+    assert_eq!(
+        subject.unix_timestamp_nanos_property(),
+        1_710_460_800_000_000_000
+    );
+}
+
+#[test]
+fn primitive_date_time_properties() {
+    let subject = datetime!(2024-03-15 10:30:15);
+
+    assert_eq!(subject.date_property(), (2024, 3, 15));
+    assert_eq!(subject.time_of_day_property(), Some((10, 30, 15, 0)));
+    assert_eq!(
+        subject.unix_timestamp_nanos_property(),
+        1_710_498_615_000_000_000
+    );
+}
+
+#[test]
+fn offset_date_time_properties() {
+    let subject = datetime!(2024-03-15 10:30:15 +01:00);
+
+    assert_eq!(subject.date_property(), (2024, 3, 15));
+    assert_eq!(subject.time_of_day_property(), Some((10, 30, 15, 0)));
+    assert_eq!(
+        subject.unix_timestamp_nanos_property(),
+        1_710_495_015_000_000_000
+    );
+}
+
+#[test]
+fn time_date_assertions() {
+    let released_on = date!(2024 - 03 - 15);
+    let published_at = datetime!(2024-03-15 10:30:00 +01:00);
+
+    assert_that(released_on)
+        .is_before_date(date!(2024 - 03 - 16))
+        .is_after_date(datetime!(2024-03-14 23:59:59))
+        .is_same_day_as(published_at)
+        .is_in_year(2024);
+
+    assert_that(published_at)
+        .is_after_date(released_on)
+        .is_within_duration_of(datetime!(2024-03-15 09:30:02 UTC), Duration::from_secs(2));
+}
+
+#[test]
+fn verify_time_offset_date_time_is_before_date_fails() {
+    let failures = verify_that(datetime!(2024-03-15 10:30:00 +01:00))
+        .named("published_at")
+        .is_before_date(date!(2024 - 03 - 15))
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected published_at to be before 2024-03-15
+   but was: 2024-03-15 10:30:00
+  expected: < 2024-03-15
+"]
+    );
+}
+
+#[test]
+fn verify_time_date_is_in_year_fails() {
+    let failures = verify_that(date!(2023 - 12 - 31))
+        .named("released_on")
+        .is_in_year(2024)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected released_on to be in year 2024
+   but was: 2023-12-31
+  expected: 2024
+"]
+    );
+}
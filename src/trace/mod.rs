@@ -0,0 +1,75 @@
+//! Tracing of executed assertions.
+//!
+//! If the environment variable `ASSERTING_TRACE` is set, every executed
+//! assertion prints a single line to stderr, including the passing ones. The
+//! line starts with `PASS` or `FAIL` followed by the first line of the
+//! assertion's message, like:
+//!
+//! ```console
+//! PASS expected numbers to contain 42
+//! ```
+//!
+//! This helps with debugging long chains of assertions and flaky tests by
+//! showing which assertion in a chain was reached last.
+//!
+//! Setting the environment variable to an empty string, `0` or `false`
+//! switches tracing off.
+//!
+//! Tracing can also be switched on with the setting `trace = true` in the
+//! configuration file `asserting.toml`. The environment variable takes
+//! precedence over the configuration file.
+
+use crate::config;
+use crate::env;
+use crate::spec::{EvalError, ExpectationWithContext, SpecMetadata, plain_message};
+use crate::std::format;
+use crate::std::string::String;
+use crate::std::sync::OnceLock;
+
+/// Environment variable to switch on tracing of executed assertions.
+const ENV_VAR_TRACE: &str = "ASSERTING_TRACE";
+
+/// Returns true if tracing is switched on by the environment variable
+/// `ASSERTING_TRACE` or the `trace` setting in the configuration file.
+///
+/// The setting is read once, when the first assertion is executed.
+fn is_trace_enabled() -> bool {
+    static TRACE_ENABLED: OnceLock<bool> = OnceLock::new();
+    *TRACE_ENABLED.get_or_init(|| trace_enabled_by(config::var(ENV_VAR_TRACE)))
+}
+
+/// Returns true if the given value of the trace setting switches tracing on.
+fn trace_enabled_by(setting: Result<String, env::VarError>) -> bool {
+    match setting {
+        Ok(value) => !(value.is_empty() || value == "0" || value.eq_ignore_ascii_case("false")),
+        Err(env::VarError::NotPresent) => false,
+        Err(env::VarError::NotUnicode(_)) => true,
+    }
+}
+
+/// Forms the trace line for an executed assertion from its message.
+fn trace_line(passed: bool, message: &str) -> String {
+    let outcome = if passed { "PASS" } else { "FAIL" };
+    let first_line = message.lines().next().unwrap_or_default();
+    format!("{outcome} {first_line}")
+}
+
+/// Prints the trace line for an executed expectation to stderr, if tracing is
+/// switched on.
+#[allow(clippy::print_stderr)]
+pub fn trace_expectation<S>(
+    expectation: &impl ExpectationWithContext<S>,
+    metadata: &SpecMetadata<'_>,
+    subject: &S,
+    evaluation: &Result<bool, EvalError>,
+) where
+    S: ?Sized,
+{
+    if is_trace_enabled() {
+        let message = plain_message(expectation, metadata, subject, evaluation);
+        eprintln!("{}", trace_line(*evaluation == Ok(true), &message));
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+use super::*;
+use crate::std::ffi::OsString;
+use crate::std::string::ToString;
+
+#[test]
+fn trace_is_disabled_if_env_var_is_not_set() {
+    assert!(!trace_enabled_by(Err(env::VarError::NotPresent)));
+}
+
+#[test]
+fn trace_is_enabled_if_env_var_is_set_to_1() {
+    assert!(trace_enabled_by(Ok("1".to_string())));
+}
+
+#[test]
+fn trace_is_enabled_if_env_var_is_set_to_true() {
+    assert!(trace_enabled_by(Ok("true".to_string())));
+}
+
+#[test]
+fn trace_is_enabled_if_env_var_is_not_unicode() {
+    assert!(trace_enabled_by(Err(env::VarError::NotUnicode(
+        OsString::new()
+    ))));
+}
+
+#[test]
+fn trace_is_disabled_if_env_var_is_set_to_empty_string() {
+    assert!(!trace_enabled_by(Ok(String::new())));
+}
+
+#[test]
+fn trace_is_disabled_if_env_var_is_set_to_0() {
+    assert!(!trace_enabled_by(Ok("0".to_string())));
+}
+
+#[test]
+fn trace_is_disabled_if_env_var_is_set_to_false() {
+    assert!(!trace_enabled_by(Ok("False".to_string())));
+}
+
+#[test]
+fn trace_line_of_passed_assertion_contains_first_line_of_message() {
+    let line = trace_line(
+        true,
+        "expected numbers to contain 42\n   but was: [1, 42]\n  expected: 42",
+    );
+
+    assert_eq!(line, "PASS expected numbers to contain 42");
+}
+
+#[test]
+fn trace_line_of_failed_assertion_contains_first_line_of_message() {
+    let line = trace_line(
+        false,
+        "expected numbers to contain 42\n   but was: [1, 2]\n  expected: 42",
+    );
+
+    assert_eq!(line, "FAIL expected numbers to contain 42");
+}
@@ -0,0 +1,243 @@
+//! Implementation of assertions for the elements of tuples.
+
+use crate::assertions::{AssertPair, AssertTupleElements};
+use crate::colored::{mark_diff, mark_missing, mark_unexpected};
+use crate::derived_spec::DerivedSpec;
+use crate::expectations::{
+    PairAreEqual, PairDiffersByAtMost, PairIsOrderedAscending, not, pair_are_equal,
+    pair_differs_by_at_most, pair_is_ordered_ascending,
+};
+use crate::properties::{CheckedDifferenceProperty, TupleElementProperty};
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::any::type_name;
+use crate::std::fmt::Debug;
+use crate::std::format;
+use crate::std::string::{String, ToString};
+
+impl<'a, S, R> AssertTupleElements<S> for Spec<'a, S, R>
+where
+    R: FailingStrategy,
+{
+    type Element<T> = Spec<'a, T, R>;
+
+    fn first(self) -> Self::Element<<S as TupleElementProperty<0>>::Element>
+    where
+        S: TupleElementProperty<0>,
+    {
+        self.element::<0>()
+    }
+
+    fn second(self) -> Self::Element<<S as TupleElementProperty<1>>::Element>
+    where
+        S: TupleElementProperty<1>,
+    {
+        self.element::<1>()
+    }
+
+    fn element<const N: usize>(self) -> Self::Element<<S as TupleElementProperty<N>>::Element>
+    where
+        S: TupleElementProperty<N>,
+    {
+        self.extracting(N.to_string(), TupleElementProperty::into_element_property)
+    }
+}
+
+impl<'a, O, S> AssertTupleElements<S> for DerivedSpec<'a, O, S> {
+    type Element<T> = DerivedSpec<'a, O, T>;
+
+    fn first(self) -> Self::Element<<S as TupleElementProperty<0>>::Element>
+    where
+        S: TupleElementProperty<0>,
+    {
+        self.element::<0>()
+    }
+
+    fn second(self) -> Self::Element<<S as TupleElementProperty<1>>::Element>
+    where
+        S: TupleElementProperty<1>,
+    {
+        self.element::<1>()
+    }
+
+    fn element<const N: usize>(self) -> Self::Element<<S as TupleElementProperty<N>>::Element>
+    where
+        S: TupleElementProperty<N>,
+    {
+        self.extracting(N.to_string(), TupleElementProperty::into_element_property)
+    }
+}
+
+impl<T, R> AssertPair<T> for Spec<'_, (T, T), R>
+where
+    T: Debug,
+    R: FailingStrategy,
+{
+    fn are_equal(self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.expecting(pair_are_equal())
+    }
+
+    fn are_not_equal(self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.expecting(not(pair_are_equal()))
+    }
+
+    fn are_ordered_ascending(self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.expecting(pair_is_ordered_ascending())
+    }
+
+    fn differ_by_at_most(self, max_difference: T) -> Self
+    where
+        T: PartialOrd + CheckedDifferenceProperty,
+    {
+        self.expecting(pair_differs_by_at_most(max_difference))
+    }
+}
+
+impl<T> Expectation<(T, T)> for PairAreEqual
+where
+    T: PartialEq + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::are_equal";
+
+    fn test(&mut self, subject: &(T, T)) -> bool {
+        subject.0 == subject.1
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &(T, T),
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let (left, right) = actual;
+        if inverted {
+            let marked_left = mark_unexpected(left, format);
+            let marked_right = mark_unexpected(right, format);
+            format!(
+                "expected {expression} to be not equal\n      left: {marked_left}\n     right: {marked_right}"
+            )
+        } else {
+            let (marked_left, marked_right) = mark_diff(left, right, format);
+            format!(
+                "expected {expression} to be equal\n      left: {marked_left}\n     right: {marked_right}"
+            )
+        }
+    }
+}
+
+impl Invertible for PairAreEqual {}
+
+impl<T> Expectation<(T, T)> for PairIsOrderedAscending
+where
+    T: PartialOrd + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::are_ordered_ascending";
+
+    fn test(&mut self, subject: &(T, T)) -> bool {
+        subject.0 <= subject.1
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &(T, T),
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let (left, right) = actual;
+        let marked_left = mark_unexpected(left, format);
+        let marked_right = mark_missing(right, format);
+        format!(
+            "expected {expression} to be ordered ascending (left <= right)\n      left: {marked_left}\n     right: {marked_right}"
+        )
+    }
+}
+
+impl<T> Expectation<(T, T)> for PairDiffersByAtMost<T>
+where
+    T: PartialOrd + CheckedDifferenceProperty + Debug,
+{
+    const ASSERTION_ID: &'static str = "asserting::differ_by_at_most";
+
+    fn test(&mut self, subject: &(T, T)) -> bool {
+        subject
+            .0
+            .checked_difference_property(&subject.1)
+            .is_some_and(|difference| difference <= self.max_difference)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &(T, T),
+        _inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let (left, right) = actual;
+        let max_difference = &self.max_difference;
+        let difference = left.checked_difference_property(right).map_or_else(
+            || format!("more than the maximum value of {}", type_name::<T>()),
+            |difference| format!("{difference:?}"),
+        );
+        let marked_left = mark_unexpected(left, format);
+        let marked_right = mark_unexpected(right, format);
+        format!(
+            "expected {expression} to differ by at most {max_difference:?}, but they differ by {difference}\n      left: {marked_left}\n     right: {marked_right}"
+        )
+    }
+}
+
+macro_rules! impl_tuple_element_property {
+    ( $( $tp_name:ident )+ => $( $index:tt )+ ) => {
+        impl_tuple_element_property! { @each [ $( $tp_name )+ ] $( $index $tp_name )+ }
+    };
+    ( @each $all:tt $( $index:tt $element:ident )+ ) => {
+        $(
+            impl_tuple_element_property! { @one $all $index $element }
+        )+
+    };
+    ( @one [ $( $tp_name:ident )+ ] $index:tt $element:ident ) => {
+        impl<$($tp_name),+> TupleElementProperty<$index> for ($($tp_name,)+) {
+            type Element = $element;
+
+            fn into_element_property(self) -> Self::Element {
+                self.$index
+            }
+        }
+
+        impl<'a, $($tp_name),+> TupleElementProperty<$index> for &'a ($($tp_name,)+) {
+            type Element = &'a $element;
+
+            fn into_element_property(self) -> Self::Element {
+                &self.$index
+            }
+        }
+    };
+}
+
+impl_tuple_element_property! { A1 => 0 }
+impl_tuple_element_property! { A1 A2 => 0 1 }
+impl_tuple_element_property! { A1 A2 A3 => 0 1 2 }
+impl_tuple_element_property! { A1 A2 A3 A4 => 0 1 2 3 }
+impl_tuple_element_property! { A1 A2 A3 A4 A5 => 0 1 2 3 4 }
+impl_tuple_element_property! { A1 A2 A3 A4 A5 A6 => 0 1 2 3 4 5 }
+impl_tuple_element_property! { A1 A2 A3 A4 A5 A6 A7 => 0 1 2 3 4 5 6 }
+impl_tuple_element_property! { A1 A2 A3 A4 A5 A6 A7 A8 => 0 1 2 3 4 5 6 7 }
+impl_tuple_element_property! { A1 A2 A3 A4 A5 A6 A7 A8 A9 => 0 1 2 3 4 5 6 7 8 }
+impl_tuple_element_property! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 => 0 1 2 3 4 5 6 7 8 9 }
+impl_tuple_element_property! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 => 0 1 2 3 4 5 6 7 8 9 10 }
+impl_tuple_element_property! { A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 => 0 1 2 3 4 5 6 7 8 9 10 11 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,222 @@
+use crate::prelude::*;
+use crate::std::string::String;
+use crate::std::vec;
+
+#[test]
+fn pair_first_is_equal_to() {
+    let subject = ("dolores", 7);
+
+    assert_that(subject).first().is_equal_to("dolores");
+}
+
+#[test]
+fn pair_second_is_greater_than() {
+    let subject = ("dolores", 7);
+
+    assert_that(subject).second().is_greater_than(0);
+}
+
+#[test]
+fn triple_element_at_index_2_contains_exactly() {
+    let subject = ('a', 2.5, vec![1, 2, 3]);
+
+    assert_that(subject)
+        .element::<2>()
+        .contains_exactly([1, 2, 3]);
+}
+
+#[test]
+fn borrowed_pair_second_is_equal_to() {
+    let subject = (String::from("facilisi"), -4);
+
+    assert_that(&subject).second().is_equal_to(&-4);
+    assert_that(&subject).first().starts_with("faci");
+}
+
+#[test]
+fn tuple_of_12_elements_element_at_index_11_is_equal_to() {
+    let subject = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, "twelve");
+
+    assert_that(subject).element::<11>().is_equal_to("twelve");
+}
+
+#[test]
+fn extracted_pair_first_is_equal_to() {
+    let subject = vec![(1, 'x'), (2, 'y')];
+
+    assert_that(subject)
+        .extracting_ref("[1]", |v| &v[1])
+        .first()
+        .is_equal_to(2);
+}
+
+#[test]
+fn verify_pair_second_is_greater_than_fails() {
+    let pair = ("dolores", -1);
+
+    let failures = verify_that(pair)
+        .named("pair")
+        .second()
+        .is_greater_than(0)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected pair.1 to be greater than 0
+   but was: -1
+  expected: > 0
+"]
+    );
+}
+
+#[test]
+fn verify_tuple_element_at_index_2_is_equal_to_fails() {
+    let subject = (1, 2, 3);
+
+    let failures = verify_that(subject)
+        .named("triple")
+        .element::<2>()
+        .is_equal_to(4)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected triple.2 to be equal to 4
+   but was: 3
+  expected: 4
+"]
+    );
+}
+
+mod pair {
+    use crate::prelude::*;
+
+    fn sum(values: &[i32]) -> i32 {
+        values.iter().sum()
+    }
+
+    #[test]
+    fn pair_of_equal_values_are_equal() {
+        assert_that!(sum(&[1, 2, 3]), sum(&[3, 3])).are_equal();
+    }
+
+    #[test]
+    fn pair_of_different_values_are_not_equal() {
+        assert_that!(sum(&[1, 2, 3]), sum(&[3, 4])).are_not_equal();
+    }
+
+    #[test]
+    fn pair_of_values_in_ascending_order_are_ordered_ascending() {
+        assert_that!(1.5, 2.5).are_ordered_ascending();
+        assert_that!("alpha", "alpha").are_ordered_ascending();
+    }
+
+    #[test]
+    fn pair_of_values_within_the_margin_differ_by_at_most() {
+        assert_that!(98, 100).differ_by_at_most(2);
+        assert_that!(100, 98).differ_by_at_most(2);
+        assert_that!(0.25, 0.5).differ_by_at_most(0.25);
+    }
+
+    #[test]
+    fn macro_accepts_trailing_comma_after_second_expression() {
+        assert_that!(7, 7,).are_equal();
+    }
+
+    #[test]
+    #[should_panic = "expected sum(&[1, 2]) and sum(&[4]) to be equal\n      left: 3\n     right: 4\n"]
+    fn pair_of_different_values_are_equal_panics() {
+        assert_that!(sum(&[1, 2]), sum(&[4]))
+            .with_diff_format(DIFF_FORMAT_NO_HIGHLIGHT)
+            .are_equal();
+    }
+
+    #[test]
+    fn verify_pair_are_not_equal_fails() {
+        let (left, right) = ("dolor", "dolor");
+
+        let failures = verify_that!(left, right).are_not_equal().display_failures();
+
+        assert_eq!(
+            failures,
+            &[r#"expected left and right to be not equal
+      left: "dolor"
+     right: "dolor"
+"#]
+        );
+    }
+
+    #[test]
+    fn verify_pair_are_ordered_ascending_fails() {
+        let (started_at, finished_at) = (1_350, 1_200);
+
+        let failures = verify_that!(started_at, finished_at)
+            .are_ordered_ascending()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected started_at and finished_at to be ordered ascending (left <= right)
+      left: 1350
+     right: 1200
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_pair_differ_by_at_most_fails() {
+        let (measured, calculated) = (93, 100);
+
+        let failures = verify_that!(measured, calculated)
+            .differ_by_at_most(2)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected measured and calculated to differ by at most 2, but they differ by 7
+      left: 93
+     right: 100
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_pair_differ_by_at_most_with_overflowing_difference_fails() {
+        let failures = verify_that!(i8::MIN, i8::MAX)
+            .differ_by_at_most(i8::MAX)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                r"expected i8::MIN and i8::MAX to differ by at most 127, but they differ by more than the maximum value of i8
+      left: -128
+     right: 127
+"
+            ]
+        );
+    }
+
+    #[test]
+    fn pair_of_extreme_values_differ_by_at_most_the_maximum() {
+        assert_that!(u64::MAX, 0).differ_by_at_most(u64::MAX);
+        assert_that!(i64::MIN, -1).differ_by_at_most(i64::MAX);
+    }
+
+    #[test]
+    fn verify_pair_without_macro_is_named_subject() {
+        let failures = verify_that((1, 2)).are_equal().display_failures();
+
+        assert_eq!(
+            failures,
+            &[r"expected subject to be equal
+      left: 1
+     right: 2
+"]
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Implementation of assertions for `url::Url`.
+
+use crate::assertions::AssertUrl;
+use crate::colored::{mark_diff_str, mark_missing_string, mark_unexpected_string};
+use crate::expectations::{
+    HasHost, HasPath, HasQueryParameter, HasScheme, IsEqualToUrlIgnoringQueryOrder, has_host,
+    has_path, has_query_parameter, has_scheme, is_equal_to_url_ignoring_query_order,
+};
+use crate::spec::{
+    DiffFormat, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::borrow::Borrow;
+use crate::std::format;
+use crate::std::string::{String, ToString};
+use crate::std::vec::Vec;
+use url::Url;
+
+impl<S, R> AssertUrl for Spec<'_, S, R>
+where
+    S: Borrow<Url>,
+    R: FailingStrategy,
+{
+    fn has_scheme(self, expected: impl AsRef<str>) -> Self {
+        self.expecting(has_scheme(expected))
+    }
+
+    fn has_host(self, expected: impl AsRef<str>) -> Self {
+        self.expecting(has_host(expected))
+    }
+
+    fn has_path(self, expected: impl AsRef<str>) -> Self {
+        self.expecting(has_path(expected))
+    }
+
+    fn has_query_parameter(self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        self.expecting(has_query_parameter(name, value))
+    }
+
+    fn is_equal_to_url_ignoring_query_order(self, expected: impl AsRef<str>) -> Self {
+        self.expecting(is_equal_to_url_ignoring_query_order(expected))
+    }
+}
+
+impl<S, E> Expectation<S> for HasScheme<E>
+where
+    S: Borrow<Url>,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_scheme";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.borrow().scheme() == self.expected.as_ref()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected.as_ref();
+        let (marked_actual, marked_expected) =
+            mark_diff_str(actual.borrow().scheme(), expected, format);
+        format!(
+            "expected {expression} to {not}have scheme {expected:?}\n   but was: \"{marked_actual}\"\n  expected: {not}\"{marked_expected}\""
+        )
+    }
+}
+
+impl<E> Invertible for HasScheme<E> {}
+
+impl<S, E> Expectation<S> for HasHost<E>
+where
+    S: Borrow<Url>,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_host";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.borrow().host_str() == Some(self.expected.as_ref())
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected.as_ref();
+        let (marked_actual, marked_expected) = match actual.borrow().host_str() {
+            Some(actual_host) => {
+                let (marked_actual, marked_expected) = mark_diff_str(actual_host, expected, format);
+                (
+                    format!("\"{marked_actual}\""),
+                    format!("\"{marked_expected}\""),
+                )
+            },
+            None => (
+                mark_unexpected_string("no host", format),
+                format!("\"{}\"", mark_missing_string(expected, format)),
+            ),
+        };
+        format!(
+            "expected {expression} to {not}have host {expected:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl<E> Invertible for HasHost<E> {}
+
+impl<S, E> Expectation<S> for HasPath<E>
+where
+    S: Borrow<Url>,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_path";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.borrow().path() == self.expected.as_ref()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected.as_ref();
+        let (marked_actual, marked_expected) =
+            mark_diff_str(actual.borrow().path(), expected, format);
+        format!(
+            "expected {expression} to {not}have path {expected:?}\n   but was: \"{marked_actual}\"\n  expected: {not}\"{marked_expected}\""
+        )
+    }
+}
+
+impl<E> Invertible for HasPath<E> {}
+
+impl<S, N, V> Expectation<S> for HasQueryParameter<N, V>
+where
+    S: Borrow<Url>,
+    N: AsRef<str>,
+    V: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_query_parameter";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let name = self.name.as_ref();
+        let value = self.value.as_ref();
+        subject
+            .borrow()
+            .query_pairs()
+            .any(|(actual_name, actual_value)| actual_name == name && actual_value == value)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let name = self.name.as_ref();
+        let value = self.value.as_ref();
+        let marked_actual = actual
+            .borrow()
+            .query_pairs()
+            .map(|(actual_name, actual_value)| {
+                let pair = format!("{actual_name}={actual_value}");
+                if actual_name == name {
+                    mark_unexpected_string(&pair, format)
+                } else {
+                    pair
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+        let marked_actual = if marked_actual.is_empty() {
+            "no query parameters".to_string()
+        } else {
+            marked_actual
+        };
+        let marked_expected = mark_missing_string(&format!("{name}={value}"), format);
+        format!(
+            "expected {expression} to {not}have query parameter {name:?} with value {value:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl<N, V> Invertible for HasQueryParameter<N, V> {}
+
+/// Returns the percent-decoded query parameters of the given URL sorted by
+/// name and value.
+fn sorted_query_pairs(url: &Url) -> Vec<(String, String)> {
+    let mut pairs = url
+        .query_pairs()
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect::<Vec<_>>();
+    pairs.sort();
+    pairs
+}
+
+/// Returns the given URL with its query parameters sorted.
+fn with_sorted_query(url: &Url) -> Url {
+    let mut sorted = url.clone();
+    if let Some(query) = url.query() {
+        let mut parameters = query.split('&').collect::<Vec<_>>();
+        parameters.sort_unstable();
+        sorted.set_query(Some(&parameters.join("&")));
+    }
+    sorted
+}
+
+/// Returns the given URL without its query.
+fn without_query(url: &Url) -> Url {
+    let mut without_query = url.clone();
+    without_query.set_query(None);
+    without_query
+}
+
+impl<S, E> Expectation<S> for IsEqualToUrlIgnoringQueryOrder<E>
+where
+    S: Borrow<Url>,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_equal_to_url_ignoring_query_order";
+
+    fn test(&mut self, subject: &S) -> bool {
+        let actual = subject.borrow();
+        Url::parse(self.expected.as_ref()).is_ok_and(|expected| {
+            without_query(actual) == without_query(&expected)
+                && sorted_query_pairs(actual) == sorted_query_pairs(&expected)
+        })
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected_string = self.expected.as_ref();
+        match Url::parse(expected_string) {
+            Ok(expected) => {
+                let actual = with_sorted_query(actual.borrow());
+                let expected = with_sorted_query(&expected);
+                let (marked_actual, marked_expected) =
+                    mark_diff_str(actual.as_str(), expected.as_str(), format);
+                format!(
+                    "expected {expression} to be {not}equal to {expected_string:?} ignoring the order of query parameters\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+                )
+            },
+            Err(err) => format!(
+                "expected {expression} to be {not}equal to {expected_string:?} ignoring the order of query parameters\n  but the expected string is not a valid URL: {err}"
+            ),
+        }
+    }
+}
+
+impl<E> Invertible for IsEqualToUrlIgnoringQueryOrder<E> {}
+
+#[cfg(test)]
+mod tests;
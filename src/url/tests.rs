@@ -0,0 +1,228 @@
+use crate::prelude::*;
+use crate::std::string::String;
+use url::Url;
+
+fn url(value: &str) -> Url {
+    Url::parse(value).unwrap_or_else(|err| panic!("invalid url {value:?}: {err}"))
+}
+
+#[test]
+fn url_has_scheme() {
+    assert_that(url("https://example.com/api/v1")).has_scheme("https");
+    assert_that(&url("http://example.com")).has_scheme(String::from("http"));
+}
+
+#[test]
+fn verify_url_has_scheme_fails() {
+    let failures = verify_that(url("http://example.com/api/v1"))
+        .named("endpoint")
+        .has_scheme("https")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected endpoint to have scheme "https"
+   but was: "http"
+  expected: "https"
+"#]
+    );
+}
+
+#[test]
+fn url_has_host() {
+    assert_that(url("https://example.com:8443/api/v1")).has_host("example.com");
+}
+
+#[test]
+fn verify_url_has_host_fails() {
+    let failures = verify_that(url("https://staging.example.com/api/v1"))
+        .named("endpoint")
+        .has_host("example.com")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected endpoint to have host "example.com"
+   but was: "staging.example.com"
+  expected: "example.com"
+"#]
+    );
+}
+
+#[test]
+fn url_has_path() {
+    assert_that(url("https://example.com/api/v1?page=2#top")).has_path("/api/v1");
+    assert_that(url("https://example.com")).has_path("/");
+}
+
+#[test]
+fn verify_url_has_path_fails() {
+    let failures = verify_that(url("https://example.com/api/v2/users"))
+        .named("endpoint")
+        .has_path("/api/v1/users")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r#"expected endpoint to have path "/api/v1/users"
+   but was: "/api/v2/users"
+  expected: "/api/v1/users"
+"#]
+    );
+}
+
+#[test]
+fn url_has_query_parameter() {
+    let subject = url("https://example.com/search?q=hello%20world&page=2&tag=a&tag=b");
+
+    assert_that(&subject)
+        .has_query_parameter("page", "2")
+        .has_query_parameter("q", "hello world")
+        .has_query_parameter("tag", "a")
+        .has_query_parameter("tag", "b");
+}
+
+#[test]
+fn verify_url_has_query_parameter_fails() {
+    let failures = verify_that(url("https://example.com/search?q=rust&page=1&size=20"))
+        .named("endpoint")
+        .has_query_parameter("page", "2")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected endpoint to have query parameter "page" with value "2"
+   but was: q=rust&page=1&size=20
+  expected: page=2
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_url_without_query_has_query_parameter_fails() {
+    let failures = verify_that(url("https://example.com/search"))
+        .named("endpoint")
+        .has_query_parameter("page", "2")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected endpoint to have query parameter "page" with value "2"
+   but was: no query parameters
+  expected: page=2
+"#
+        ]
+    );
+}
+
+#[test]
+fn url_is_equal_to_url_ignoring_query_order() {
+    let subject = url("https://example.com/api/v1?page=2&size=20&sort=name");
+
+    assert_that(&subject).is_equal_to_url_ignoring_query_order(
+        "https://example.com/api/v1?sort=name&size=20&page=2",
+    );
+    assert_that(&subject).is_equal_to_url_ignoring_query_order(url(
+        "https://example.com/api/v1?size=20&page=2&sort=name",
+    ));
+}
+
+#[test]
+fn verify_url_is_equal_to_url_ignoring_query_order_fails() {
+    let failures = verify_that(url("https://example.com/api/v1?size=20&page=2"))
+        .named("endpoint")
+        .is_equal_to_url_ignoring_query_order("https://example.com/api/v1?page=3&size=20")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected endpoint to be equal to "https://example.com/api/v1?page=3&size=20" ignoring the order of query parameters
+   but was: https://example.com/api/v1?page=2&size=20
+  expected: https://example.com/api/v1?page=3&size=20
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_url_is_equal_to_url_ignoring_query_order_fails_for_different_number_of_parameters() {
+    let failures = verify_that(url("https://example.com/api/v1?tag=a&tag=a"))
+        .named("endpoint")
+        .is_equal_to_url_ignoring_query_order("https://example.com/api/v1?tag=a")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected endpoint to be equal to "https://example.com/api/v1?tag=a" ignoring the order of query parameters
+   but was: https://example.com/api/v1?tag=a&tag=a
+  expected: https://example.com/api/v1?tag=a
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_url_is_equal_to_invalid_url_ignoring_query_order_fails() {
+    let failures = verify_that(url("https://example.com/api/v1"))
+        .named("endpoint")
+        .is_equal_to_url_ignoring_query_order("/api/v1")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected endpoint to be equal to "/api/v1" ignoring the order of query parameters
+  but the expected string is not a valid URL: relative URL without a base
+"#
+        ]
+    );
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use super::url;
+    use crate::prelude::*;
+
+    #[test]
+    fn highlight_diffs_url_has_query_parameter_marks_parameters_with_the_same_name() {
+        let failures = verify_that(url("https://example.com/search?q=rust&page=1"))
+            .named("endpoint")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .has_query_parameter("page", "2")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected endpoint to have query parameter \"page\" with value \"2\"\n   \
+                  but was: q=rust&\u{1b}[31mpage=1\u{1b}[0m\n  \
+                 expected: \u{1b}[34mpage=2\u{1b}[0m\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_url_is_equal_to_url_ignoring_query_order() {
+        let failures = verify_that(url("https://example.com/api/v1?size=20&page=2"))
+            .named("endpoint")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_equal_to_url_ignoring_query_order("https://example.com/api/v1?page=3&size=20")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected endpoint to be equal to \"https://example.com/api/v1?page=3&size=20\" ignoring the order of query parameters\n   \
+                  but was: https://example.com/api/v1?page=\u{1b}[31m2\u{1b}[0m&size=20\n  \
+                 expected: https://example.com/api/v1?page=\u{1b}[34m3\u{1b}[0m&size=20\n\
+            "
+            ]
+        );
+    }
+}
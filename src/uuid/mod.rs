@@ -0,0 +1,185 @@
+//! Implementation of assertions for `uuid::Uuid`.
+
+use crate::assertions::AssertUuid;
+use crate::colored::{mark_missing_string, mark_unexpected_string};
+use crate::expectations::{
+    HasVersion, IsEqualToUuidString, IsNil, has_version, is_equal_to_uuid_string, is_nil, not,
+};
+use crate::spec::{
+    DiffFormat, EvalError, Expectation, Expecting, Expression, FailingStrategy, Invertible, Spec,
+};
+use crate::std::borrow::Borrow;
+use crate::std::format;
+use crate::std::string::{String, ToString};
+use crate::std::vec::Vec;
+use uuid::{Uuid, Version};
+
+impl<S, R> AssertUuid for Spec<'_, S, R>
+where
+    S: Borrow<Uuid>,
+    R: FailingStrategy,
+{
+    fn is_nil(self) -> Self {
+        self.expecting(is_nil())
+    }
+
+    fn is_not_nil(self) -> Self {
+        self.expecting(not(is_nil()))
+    }
+
+    fn has_version(self, expected: Version) -> Self {
+        self.expecting(has_version(expected))
+    }
+
+    fn is_equal_to_uuid_string(self, expected: impl AsRef<str>) -> Self {
+        self.expecting(is_equal_to_uuid_string(expected))
+    }
+}
+
+/// Formats the given UUID in the hyphenated form and marks each segment that
+/// differs from the same segment of the other UUID using the given `mark`
+/// function.
+fn mark_differing_segments(
+    uuid: &Uuid,
+    other: &Uuid,
+    mark: fn(&str, &DiffFormat) -> String,
+    format: &DiffFormat,
+) -> String {
+    let formatted = uuid.hyphenated().to_string();
+    let other_formatted = other.hyphenated().to_string();
+    formatted
+        .split('-')
+        .zip(other_formatted.split('-'))
+        .map(|(segment, other_segment)| {
+            if segment == other_segment {
+                segment.to_string()
+            } else {
+                mark(segment, format)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Formats the version of the given UUID or the version number if it is not
+/// a known version.
+fn version_of(uuid: &Uuid) -> String {
+    uuid.get_version().map_or_else(
+        || format!("unknown version {}", uuid.get_version_num()),
+        |version| format!("{version:?}"),
+    )
+}
+
+impl<S> Expectation<S> for IsNil
+where
+    S: Borrow<Uuid>,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_nil";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.borrow().is_nil()
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let actual = actual.borrow();
+        let nil = Uuid::nil();
+        let marked_actual = mark_differing_segments(actual, &nil, mark_unexpected_string, format);
+        let marked_expected = mark_differing_segments(&nil, actual, mark_missing_string, format);
+        format!(
+            "expected {expression} to be {not}nil\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl Invertible for IsNil {}
+
+impl<S> Expectation<S> for HasVersion
+where
+    S: Borrow<Uuid>,
+{
+    const ASSERTION_ID: &'static str = "asserting::has_version";
+
+    fn test(&mut self, subject: &S) -> bool {
+        subject.borrow().get_version() == Some(self.expected)
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected = self.expected;
+        let actual = actual.borrow();
+        let marked_actual = mark_unexpected_string(&version_of(actual), format);
+        let marked_expected = mark_missing_string(&format!("{expected:?}"), format);
+        format!(
+            "expected {expression} to {not}have version {expected:?}\n   but was: {marked_actual} ({actual})\n  expected: {not}{marked_expected}"
+        )
+    }
+}
+
+impl Invertible for HasVersion {}
+
+impl<S, E> Expectation<S> for IsEqualToUuidString<E>
+where
+    S: Borrow<Uuid>,
+    E: AsRef<str>,
+{
+    const ASSERTION_ID: &'static str = "asserting::is_equal_to_uuid_string";
+
+    fn test(&mut self, subject: &S) -> bool {
+        self.try_test(subject).unwrap_or(false)
+    }
+
+    fn try_test(&mut self, subject: &S) -> Result<bool, EvalError> {
+        let expected_string = self.expected.as_ref();
+        Uuid::parse_str(expected_string)
+            .map(|expected| *subject.borrow() == expected)
+            .map_err(|err| {
+                EvalError::new(format!(
+                    "the expected string {expected_string:?} is not a valid UUID: {err}"
+                ))
+            })
+    }
+
+    fn message(
+        &self,
+        expression: &Expression<'_>,
+        actual: &S,
+        inverted: bool,
+        format: &DiffFormat,
+    ) -> String {
+        let not = if inverted { "not " } else { "" };
+        let expected_string = self.expected.as_ref();
+        let actual = actual.borrow();
+        match Uuid::parse_str(expected_string) {
+            Ok(expected) => {
+                let marked_actual =
+                    mark_differing_segments(actual, &expected, mark_unexpected_string, format);
+                let marked_expected =
+                    mark_differing_segments(&expected, actual, mark_missing_string, format);
+                format!(
+                    "expected {expression} to be {not}equal to UUID {expected_string:?}\n   but was: {marked_actual}\n  expected: {not}{marked_expected}"
+                )
+            },
+            Err(err) => format!(
+                "expected {expression} to be {not}equal to UUID {expected_string:?}\n  but the expected string is not a valid UUID: {err}"
+            ),
+        }
+    }
+}
+
+impl<E> Invertible for IsEqualToUuidString<E> {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,193 @@
+use crate::expectations::{is_equal_to_uuid_string, not};
+use crate::prelude::*;
+use crate::std::format;
+use crate::std::string::String;
+use uuid::{Uuid, Version};
+
+const RANDOM_UUID: Uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+const SORT_RAND_UUID: Uuid = Uuid::from_u128(0x0189_0a5d_ac96_774b_bcce_b302_099a_8057);
+
+#[test]
+fn nil_uuid_is_nil() {
+    assert_that(Uuid::nil()).is_nil();
+    assert_that(&Uuid::nil()).is_nil();
+}
+
+#[test]
+fn verify_uuid_is_nil_fails() {
+    let failures = verify_that(RANDOM_UUID)
+        .named("id")
+        .is_nil()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected id to be nil
+   but was: 67e55044-10b1-426f-9247-bb680e5fe0c8
+  expected: 00000000-0000-0000-0000-000000000000
+"]
+    );
+}
+
+#[test]
+fn random_uuid_is_not_nil() {
+    assert_that(RANDOM_UUID).is_not_nil();
+}
+
+#[test]
+fn verify_uuid_is_not_nil_fails() {
+    let failures = verify_that(Uuid::nil())
+        .named("id")
+        .is_not_nil()
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected id to be not nil
+   but was: 00000000-0000-0000-0000-000000000000
+  expected: not 00000000-0000-0000-0000-000000000000
+"]
+    );
+}
+
+#[test]
+fn uuid_has_version() {
+    assert_that(RANDOM_UUID).has_version(Version::Random);
+    assert_that(SORT_RAND_UUID).has_version(Version::SortRand);
+    assert_that(Uuid::nil()).has_version(Version::Nil);
+}
+
+#[test]
+fn verify_uuid_has_version_fails() {
+    let failures = verify_that(SORT_RAND_UUID)
+        .named("id")
+        .has_version(Version::Random)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected id to have version Random
+   but was: SortRand (01890a5d-ac96-774b-bcce-b302099a8057)
+  expected: Random
+"]
+    );
+}
+
+#[test]
+fn uuid_is_equal_to_uuid_string() {
+    assert_that(RANDOM_UUID).is_equal_to_uuid_string("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_that(RANDOM_UUID).is_equal_to_uuid_string("67E55044-10B1-426F-9247-BB680E5FE0C8");
+    assert_that(RANDOM_UUID).is_equal_to_uuid_string("67e5504410b1426f9247bb680e5fe0c8");
+    assert_that(&RANDOM_UUID)
+        .is_equal_to_uuid_string(String::from("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+}
+
+#[test]
+fn verify_uuid_is_equal_to_uuid_string_fails() {
+    let failures = verify_that(RANDOM_UUID)
+        .named("id")
+        .is_equal_to_uuid_string("67e55044-10b1-426f-9248-bb680e5fe0c8")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r#"expected id to be equal to UUID "67e55044-10b1-426f-9248-bb680e5fe0c8"
+   but was: 67e55044-10b1-426f-9247-bb680e5fe0c8
+  expected: 67e55044-10b1-426f-9248-bb680e5fe0c8
+"#
+        ]
+    );
+}
+
+#[test]
+fn verify_uuid_is_equal_to_invalid_uuid_string_fails() {
+    let Err(parse_error) = Uuid::parse_str("67e55044") else {
+        panic!("\"67e55044\" should not be a valid UUID");
+    };
+
+    let failures = verify_that(RANDOM_UUID)
+        .named("id")
+        .is_equal_to_uuid_string("67e55044")
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[format!(
+            "could not evaluate the assertion on id\n  error: the expected string \"67e55044\" is not a valid UUID: {parse_error}\n"
+        )]
+    );
+}
+
+#[test]
+fn verify_uuid_is_not_equal_to_invalid_uuid_string_fails() {
+    let failures = verify_that(RANDOM_UUID)
+        .named("id")
+        .expecting(not(is_equal_to_uuid_string("invalid")))
+        .display_failures();
+
+    assert_that!(&failures).has_length(1);
+    assert_that!(&failures[0]).starts_with(
+        "could not evaluate the assertion on id\n  error: the expected string \"invalid\" is not a valid UUID: ",
+    );
+}
+
+#[cfg(feature = "colored")]
+mod colored {
+    use super::{RANDOM_UUID, SORT_RAND_UUID};
+    use crate::prelude::*;
+    use uuid::Version;
+
+    #[test]
+    fn highlight_diffs_uuid_is_equal_to_uuid_string_highlights_differing_segments() {
+        let failures = verify_that(RANDOM_UUID)
+            .named("id")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_equal_to_uuid_string("67e55044-10b2-426f-9247-bb680e5fe0c9")
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &[
+                "expected id to be equal to UUID \"67e55044-10b2-426f-9247-bb680e5fe0c9\"\n   \
+                  but was: 67e55044-\u{1b}[31m10b1\u{1b}[0m-426f-9247-\u{1b}[31mbb680e5fe0c8\u{1b}[0m\n  \
+                 expected: 67e55044-\u{1b}[34m10b2\u{1b}[0m-426f-9247-\u{1b}[34mbb680e5fe0c9\u{1b}[0m\n\
+            "
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_uuid_is_nil() {
+        let failures = verify_that(RANDOM_UUID)
+            .named("id")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .is_nil()
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected id to be nil\n   \
+                  but was: \u{1b}[31m67e55044\u{1b}[0m-\u{1b}[31m10b1\u{1b}[0m-\u{1b}[31m426f\u{1b}[0m-\u{1b}[31m9247\u{1b}[0m-\u{1b}[31mbb680e5fe0c8\u{1b}[0m\n  \
+                 expected: \u{1b}[34m00000000\u{1b}[0m-\u{1b}[34m0000\u{1b}[0m-\u{1b}[34m0000\u{1b}[0m-\u{1b}[34m0000\u{1b}[0m-\u{1b}[34m000000000000\u{1b}[0m\n\
+            "]
+        );
+    }
+
+    #[test]
+    fn highlight_diffs_uuid_has_version() {
+        let failures = verify_that(SORT_RAND_UUID)
+            .named("id")
+            .with_diff_format(DIFF_FORMAT_RED_BLUE)
+            .has_version(Version::Random)
+            .display_failures();
+
+        assert_eq!(
+            failures,
+            &["expected id to have version Random\n   \
+                  but was: \u{1b}[31mSortRand\u{1b}[0m (01890a5d-ac96-774b-bcce-b302099a8057)\n  \
+                 expected: \u{1b}[34mRandom\u{1b}[0m\n\
+            "]
+        );
+    }
+}
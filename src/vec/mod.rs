@@ -1,6 +1,6 @@
 //! Implementation of assertions for `Vec` values.
 
-use crate::properties::{IsEmptyProperty, LengthProperty};
+use crate::properties::{CapacityProperty, IsEmptyProperty, KeyValuePairsProperty, LengthProperty};
 use crate::std::vec::Vec;
 
 impl<T> IsEmptyProperty for Vec<T> {
@@ -15,5 +15,20 @@ impl<T> LengthProperty for Vec<T> {
     }
 }
 
+impl<T> CapacityProperty for Vec<T> {
+    fn capacity_property(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<K, V> KeyValuePairsProperty for Vec<(K, V)> {
+    type Key = K;
+    type Value = V;
+
+    fn pairs_property(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.iter().map(|(key, value)| (key, value))
+    }
+}
+
 #[cfg(test)]
 mod tests;
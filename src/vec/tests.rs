@@ -149,6 +149,58 @@ fn verify_vec_contains_exactly_in_any_order_fails() {
     );
 }
 
+#[test]
+fn vec_contains_exactly_shuffled() {
+    let subject: Vec<i32> = vec![11, 3, 7, 1, 5, 13];
+
+    assert_that(subject).contains_exactly_shuffled([1, 3, 5, 7, 11, 13], 0x5eed);
+}
+
+#[test]
+fn vec_contains_exactly_shuffled_shuffles_the_subject_deterministically() {
+    let subject: Vec<i32> = (1..=20).collect();
+
+    let shuffled = assert_that(subject.clone())
+        .contains_exactly_shuffled(1..=20, 7)
+        .subject()
+        .clone();
+    let shuffled_again = assert_that(subject.clone())
+        .contains_exactly_shuffled(1..=20, 7)
+        .subject()
+        .clone();
+    let shuffled_with_other_seed = assert_that(subject.clone())
+        .contains_exactly_shuffled(1..=20, 8)
+        .subject()
+        .clone();
+
+    assert_that(&shuffled).is_equal_to(&shuffled_again);
+    assert_that(&shuffled).is_not_equal_to(&subject);
+    assert_that(&shuffled).is_not_equal_to(&shuffled_with_other_seed);
+}
+
+#[test]
+fn verify_vec_contains_exactly_shuffled_fails() {
+    let subject: Vec<i32> = vec![11, 3, 7, 1, 13, 13];
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .contains_exactly_shuffled([1, 3, 5, 7, 11, 13], 0x5eed)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[
+            r"expected my_thing to contain exactly in any order [1, 3, 5, 7, 11, 13]
+   but was: [13, 7, 3, 1, 11, 13]
+  expected: [1, 3, 5, 7, 11, 13]
+   missing: [5]
+     extra: [13]
+      seed: 24301
+"
+        ]
+    );
+}
+
 #[test]
 fn vec_contains_any_of() {
     let subject: Vec<i32> = vec![5, 7, 11, 13, 1, 19, 11, 3, 17, 23, 23, 29, 31, 41, 37, 43];
@@ -715,6 +767,85 @@ fn verify_empty_vec_ends_with_expected_sequence_longer_than_vec_fails() {
     );
 }
 
+#[test]
+fn vec_has_capacity() {
+    let mut subject: Vec<u64> = Vec::with_capacity(16);
+    subject.extend([2, 4, 6, 8]);
+
+    assert_that(subject).has_capacity(16);
+}
+
+#[test]
+fn vec_has_capacity_at_least() {
+    let subject: Vec<u64> = Vec::with_capacity(16);
+
+    assert_that(&subject).has_capacity_at_least(10);
+    assert_that(&subject).has_capacity_at_least(16);
+}
+
+#[test]
+fn vec_has_spare_capacity_of() {
+    let mut subject: Vec<u64> = Vec::with_capacity(16);
+    subject.extend([2, 4, 6, 8]);
+
+    assert_that(subject).has_spare_capacity_of(12);
+}
+
+#[test]
+fn verify_vec_has_capacity_fails() {
+    let subject: Vec<u64> = Vec::with_capacity(16);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_capacity(32)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to have a capacity of 32
+   but was: 16
+  expected: 32
+"]
+    );
+}
+
+#[test]
+fn verify_vec_has_capacity_at_least_fails() {
+    let subject: Vec<u64> = Vec::with_capacity(16);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_capacity_at_least(20)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to have a capacity of at least 20
+   but was: 16
+  expected: >= 20
+"]
+    );
+}
+
+#[test]
+fn verify_vec_has_spare_capacity_of_fails() {
+    let mut subject: Vec<u64> = Vec::with_capacity(16);
+    subject.extend([2, 4, 6, 8, 10]);
+
+    let failures = verify_that(subject)
+        .named("my_thing")
+        .has_spare_capacity_of(12)
+        .display_failures();
+
+    assert_eq!(
+        failures,
+        &[r"expected my_thing to have a spare capacity of 12
+   but was: 11
+  expected: 12
+"]
+    );
+}
+
 #[cfg(feature = "colored")]
 mod colored {
     use super::*;
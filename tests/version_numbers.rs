@@ -8,6 +8,10 @@ mod dummy_extern_uses {
     use asserting as _;
     #[cfg(feature = "bigdecimal")]
     use bigdecimal as _;
+    #[cfg(feature = "bitflags")]
+    use bitflags as _;
+    #[cfg(feature = "chrono")]
+    use chrono as _;
     use fakeenv as _;
     #[cfg(feature = "float-cmp")]
     use float_cmp as _;
@@ -31,7 +35,14 @@ mod dummy_extern_uses {
     use serde_bytes as _;
     #[cfg(feature = "recursive")]
     use serde_core as _;
+    use serde_test as _;
     use time as _;
+    #[cfg(feature = "unicode")]
+    use unicode_segmentation as _;
+    #[cfg(feature = "url")]
+    use url as _;
+    #[cfg(feature = "uuid")]
+    use uuid as _;
 }
 
 #[test]